@@ -47,6 +47,27 @@ mod m20251008_160141_add_task_id_to_custodian_executions;
 mod m20251009_000000_update_user_roles;
 mod m20251104_000010_alter_ip_ranges_dns_servers_array;
 mod m20251112_000000_create_notifications_outbox;
+mod m20261018_000000_create_dns_zone_serials;
+mod m20261018_000001_create_audit_logs;
+mod m20261018_000002_add_field_policies_to_external_api_connections;
+mod m20261018_000003_create_sync_field_overrides;
+mod m20261018_000004_create_sync_conflicts;
+mod m20261018_000005_create_webhook_subscriptions;
+mod m20261018_000006_create_webhook_deliveries;
+mod m20261018_000007_create_webhook_delivery_attempts;
+mod m20261018_000008_create_user_notifications;
+mod m20261018_000009_create_notification_resource_subscriptions;
+mod m20261018_000010_create_notification_preferences;
+mod m20261018_000011_create_notification_digest_items;
+mod m20261018_000012_add_schedule_to_custodian_policies;
+mod m20261018_000013_add_trigger_to_custodian_executions;
+mod m20261018_000014_create_user_office_scopes;
+mod m20261018_000015_create_tenant_memberships;
+mod m20261018_000016_create_api_tokens;
+mod m20261018_000017_create_two_factor;
+mod m20261018_000018_add_user_locked_out_action_type;
+mod m20261018_000019_add_is_system_to_users;
+mod m20261018_000020_add_tenant_id_to_webhook_subscriptions;
 
 pub struct Migrator;
 
@@ -101,6 +122,27 @@ impl MigratorTrait for Migrator {
             Box::new(m20251009_000000_update_user_roles::Migration),
             Box::new(m20251104_000010_alter_ip_ranges_dns_servers_array::Migration),
             Box::new(m20251112_000000_create_notifications_outbox::Migration),
+            Box::new(m20261018_000000_create_dns_zone_serials::Migration),
+            Box::new(m20261018_000001_create_audit_logs::Migration),
+            Box::new(m20261018_000002_add_field_policies_to_external_api_connections::Migration),
+            Box::new(m20261018_000003_create_sync_field_overrides::Migration),
+            Box::new(m20261018_000004_create_sync_conflicts::Migration),
+            Box::new(m20261018_000005_create_webhook_subscriptions::Migration),
+            Box::new(m20261018_000006_create_webhook_deliveries::Migration),
+            Box::new(m20261018_000007_create_webhook_delivery_attempts::Migration),
+            Box::new(m20261018_000008_create_user_notifications::Migration),
+            Box::new(m20261018_000009_create_notification_resource_subscriptions::Migration),
+            Box::new(m20261018_000010_create_notification_preferences::Migration),
+            Box::new(m20261018_000011_create_notification_digest_items::Migration),
+            Box::new(m20261018_000012_add_schedule_to_custodian_policies::Migration),
+            Box::new(m20261018_000013_add_trigger_to_custodian_executions::Migration),
+            Box::new(m20261018_000014_create_user_office_scopes::Migration),
+            Box::new(m20261018_000015_create_tenant_memberships::Migration),
+            Box::new(m20261018_000016_create_api_tokens::Migration),
+            Box::new(m20261018_000017_create_two_factor::Migration),
+            Box::new(m20261018_000018_add_user_locked_out_action_type::Migration),
+            Box::new(m20261018_000019_add_is_system_to_users::Migration),
+            Box::new(m20261018_000020_add_tenant_id_to_webhook_subscriptions::Migration),
         ]
     }
 }
@@ -0,0 +1,109 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DnsZoneSerials::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DnsZoneSerials::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(DnsZoneSerials::IpRangeId).uuid().not_null())
+                    .col(ColumnDef::new(DnsZoneSerials::ZoneName).string().not_null())
+                    .col(ColumnDef::new(DnsZoneSerials::ZoneKind).string().not_null())
+                    .col(ColumnDef::new(DnsZoneSerials::Serial).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(DnsZoneSerials::ContentHash)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(DnsZoneSerials::Content).text().not_null())
+                    .col(ColumnDef::new(DnsZoneSerials::GeneratedBy).uuid().null())
+                    .col(
+                        ColumnDef::new(DnsZoneSerials::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(DnsZoneSerials::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_dns_zone_serials_ip_range_id")
+                            .from(DnsZoneSerials::Table, DnsZoneSerials::IpRangeId)
+                            .to(IpRanges::Table, IpRanges::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_dns_zone_serials_generated_by")
+                            .from(DnsZoneSerials::Table, DnsZoneSerials::GeneratedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uniq_dns_zone_serials_range_zone")
+                    .table(DnsZoneSerials::Table)
+                    .col(DnsZoneSerials::IpRangeId)
+                    .col(DnsZoneSerials::ZoneName)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DnsZoneSerials::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum DnsZoneSerials {
+    Table,
+    Id,
+    IpRangeId,
+    ZoneName,
+    ZoneKind,
+    Serial,
+    ContentHash,
+    Content,
+    GeneratedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum IpRanges {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
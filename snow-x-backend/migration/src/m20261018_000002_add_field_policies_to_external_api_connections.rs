@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 필드별 소유 규칙: {"<field>": "locked" | "manual_override"}
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ExternalApiConnections::Table)
+                    .add_column(
+                        ColumnDef::new(ExternalApiConnections::FieldPolicies)
+                            .json()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ExternalApiConnections::Table)
+                    .drop_column(ExternalApiConnections::FieldPolicies)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ExternalApiConnections {
+    Table,
+    FieldPolicies,
+}
@@ -0,0 +1,133 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 동기화된 레코드에서 사람이 직접 수정한 필드 (동기화가 덮어쓰지 않는다)
+        manager
+            .create_table(
+                Table::create()
+                    .table(SyncFieldOverrides::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SyncFieldOverrides::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(SyncFieldOverrides::ResourceType)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SyncFieldOverrides::ResourceId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SyncFieldOverrides::Field)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SyncFieldOverrides::ExternalApiConnectionId)
+                            .integer()
+                            .not_null(),
+                    )
+                    // 마지막으로 확인한 원본 값. 원본이 이 값에서 바뀌면 충돌로 기록한다.
+                    .col(
+                        ColumnDef::new(SyncFieldOverrides::SourceValue)
+                            .json_binary()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SyncFieldOverrides::OverriddenBy)
+                            .uuid()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SyncFieldOverrides::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SyncFieldOverrides::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_sync_field_overrides_connection_id")
+                            .from(
+                                SyncFieldOverrides::Table,
+                                SyncFieldOverrides::ExternalApiConnectionId,
+                            )
+                            .to(ExternalApiConnections::Table, ExternalApiConnections::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_sync_field_overrides_overridden_by")
+                            .from(SyncFieldOverrides::Table, SyncFieldOverrides::OverriddenBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sync_field_overrides_resource_field")
+                    .table(SyncFieldOverrides::Table)
+                    .col(SyncFieldOverrides::ResourceType)
+                    .col(SyncFieldOverrides::ResourceId)
+                    .col(SyncFieldOverrides::Field)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SyncFieldOverrides::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SyncFieldOverrides {
+    Table,
+    Id,
+    ResourceType,
+    ResourceId,
+    Field,
+    ExternalApiConnectionId,
+    SourceValue,
+    OverriddenBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum ExternalApiConnections {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
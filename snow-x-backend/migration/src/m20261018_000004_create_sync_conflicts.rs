@@ -0,0 +1,147 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사람이 수정한 필드를 동기화가 다른 값으로 바꾸려 한 기록 (검토 대기열)
+        manager
+            .create_table(
+                Table::create()
+                    .table(SyncConflicts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SyncConflicts::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(SyncConflicts::ResourceType)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SyncConflicts::ResourceId).uuid().not_null())
+                    .col(ColumnDef::new(SyncConflicts::ResourceName).string().null())
+                    .col(
+                        ColumnDef::new(SyncConflicts::Field)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SyncConflicts::ExternalApiConnectionId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SyncConflicts::LocalValue).json_binary().null())
+                    .col(
+                        ColumnDef::new(SyncConflicts::SourceValue)
+                            .json_binary()
+                            .null(),
+                    )
+                    // pending, accepted_source, kept_local
+                    .col(
+                        ColumnDef::new(SyncConflicts::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(
+                        ColumnDef::new(SyncConflicts::DetectedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SyncConflicts::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SyncConflicts::ResolvedBy).uuid().null())
+                    .col(
+                        ColumnDef::new(SyncConflicts::ResolvedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_sync_conflicts_connection_id")
+                            .from(SyncConflicts::Table, SyncConflicts::ExternalApiConnectionId)
+                            .to(ExternalApiConnections::Table, ExternalApiConnections::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_sync_conflicts_resolved_by")
+                            .from(SyncConflicts::Table, SyncConflicts::ResolvedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 필드마다 대기 중인 충돌은 하나만
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_sync_conflicts_pending_field \
+                 ON sync_conflicts (resource_type, resource_id, field) \
+                 WHERE status = 'pending'",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sync_conflicts_status_detected_at")
+                    .table(SyncConflicts::Table)
+                    .col(SyncConflicts::Status)
+                    .col(SyncConflicts::DetectedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SyncConflicts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SyncConflicts {
+    Table,
+    Id,
+    ResourceType,
+    ResourceId,
+    ResourceName,
+    Field,
+    ExternalApiConnectionId,
+    LocalValue,
+    SourceValue,
+    Status,
+    DetectedAt,
+    UpdatedAt,
+    ResolvedBy,
+    ResolvedAt,
+}
+
+#[derive(DeriveIden)]
+enum ExternalApiConnections {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,117 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사용자가 등록한 웹훅 구독 (이벤트 종류별로 서명된 요청을 받는다)
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookSubscriptions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::Name)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(WebhookSubscriptions::Url).text().not_null())
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::Description)
+                            .text()
+                            .null(),
+                    )
+                    // HMAC-SHA256 서명 키
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::Secret)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    // 구독하는 이벤트 종류 (문자열 배열)
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::EventTypes)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_subscriptions_user_id")
+                            .from(WebhookSubscriptions::Table, WebhookSubscriptions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_subscriptions_user_id")
+                    .table(WebhookSubscriptions::Table)
+                    .col(WebhookSubscriptions::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookSubscriptions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookSubscriptions {
+    Table,
+    Id,
+    UserId,
+    Name,
+    Url,
+    Description,
+    Secret,
+    EventTypes,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
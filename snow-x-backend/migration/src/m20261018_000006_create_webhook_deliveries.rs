@@ -0,0 +1,148 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 구독 하나에 보낼 이벤트 하나. 실제 발송은 notifications_outbox 행이 맡는다.
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDeliveries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::SubscriptionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    // 같은 이벤트를 받은 구독끼리 공유하는 이벤트 ID
+                    .col(ColumnDef::new(WebhookDeliveries::EventId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::EventType)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    // pending, success, failed
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::AttemptCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::ResponseStatus)
+                            .integer()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(WebhookDeliveries::LastError).text().null())
+                    // 이 배달을 맡은 최근 outbox 행
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::NotificationId)
+                            .uuid()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::DeliveredAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_deliveries_subscription_id")
+                            .from(WebhookDeliveries::Table, WebhookDeliveries::SubscriptionId)
+                            .to(WebhookSubscriptions::Table, WebhookSubscriptions::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_deliveries_notification_id")
+                            .from(WebhookDeliveries::Table, WebhookDeliveries::NotificationId)
+                            .to(NotificationsOutbox::Table, NotificationsOutbox::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_subscription_created")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::SubscriptionId)
+                    .col(WebhookDeliveries::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDeliveries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookDeliveries {
+    Table,
+    Id,
+    SubscriptionId,
+    EventId,
+    EventType,
+    Payload,
+    Status,
+    AttemptCount,
+    ResponseStatus,
+    LastError,
+    NotificationId,
+    CreatedAt,
+    UpdatedAt,
+    DeliveredAt,
+}
+
+#[derive(DeriveIden)]
+enum WebhookSubscriptions {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum NotificationsOutbox {
+    Table,
+    Id,
+}
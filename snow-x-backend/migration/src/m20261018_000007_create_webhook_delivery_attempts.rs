@@ -0,0 +1,117 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 웹훅 요청 한 번의 기록
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDeliveryAttempts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookDeliveryAttempts::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveryAttempts::DeliveryId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveryAttempts::Attempt)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveryAttempts::RequestUrl)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveryAttempts::ResponseStatus)
+                            .integer()
+                            .null(),
+                    )
+                    // 응답 본문 앞부분
+                    .col(
+                        ColumnDef::new(WebhookDeliveryAttempts::ResponseBody)
+                            .text()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(WebhookDeliveryAttempts::Error).text().null())
+                    // 요청 소요 시간 (ms)
+                    .col(
+                        ColumnDef::new(WebhookDeliveryAttempts::Duration)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveryAttempts::AttemptedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_delivery_attempts_delivery_id")
+                            .from(
+                                WebhookDeliveryAttempts::Table,
+                                WebhookDeliveryAttempts::DeliveryId,
+                            )
+                            .to(WebhookDeliveries::Table, WebhookDeliveries::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_delivery_attempts_delivery_id")
+                    .table(WebhookDeliveryAttempts::Table)
+                    .col(WebhookDeliveryAttempts::DeliveryId)
+                    .col(WebhookDeliveryAttempts::Attempt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(WebhookDeliveryAttempts::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookDeliveryAttempts {
+    Table,
+    Id,
+    DeliveryId,
+    Attempt,
+    RequestUrl,
+    ResponseStatus,
+    ResponseBody,
+    Error,
+    Duration,
+    AttemptedAt,
+}
+
+#[derive(DeriveIden)]
+enum WebhookDeliveries {
+    Table,
+    Id,
+}
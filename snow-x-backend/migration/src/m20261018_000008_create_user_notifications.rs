@@ -0,0 +1,159 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사용자별 알림함. outbox의 web 채널 알림을 받는 사람마다 한 행씩 만든다
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserNotifications::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserNotifications::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(UserNotifications::UserId).uuid().not_null())
+                    // 이 알림을 만든 outbox 행 (직접 만든 알림은 NULL)
+                    .col(ColumnDef::new(UserNotifications::OutboxId).uuid().null())
+                    .col(
+                        ColumnDef::new(UserNotifications::Category)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserNotifications::Title)
+                            .string_len(255)
+                            .null(),
+                    )
+                    .col(ColumnDef::new(UserNotifications::Message).text().null())
+                    .col(
+                        ColumnDef::new(UserNotifications::Payload)
+                            .json_binary()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(UserNotifications::Link).text().null())
+                    .col(
+                        ColumnDef::new(UserNotifications::ResourceType)
+                            .string_len(30)
+                            .null(),
+                    )
+                    .col(ColumnDef::new(UserNotifications::ResourceId).uuid().null())
+                    .col(ColumnDef::new(UserNotifications::ActorId).uuid().null())
+                    .col(
+                        ColumnDef::new(UserNotifications::ReadAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserNotifications::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_notifications_user_id")
+                            .from(UserNotifications::Table, UserNotifications::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_notifications_outbox_id")
+                            .from(UserNotifications::Table, UserNotifications::OutboxId)
+                            .to(NotificationsOutbox::Table, NotificationsOutbox::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_notifications_actor_id")
+                            .from(UserNotifications::Table, UserNotifications::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_notifications_user_created")
+                    .table(UserNotifications::Table)
+                    .col(UserNotifications::UserId)
+                    .col(UserNotifications::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_notifications_user_read")
+                    .table(UserNotifications::Table)
+                    .col(UserNotifications::UserId)
+                    .col(UserNotifications::ReadAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 같은 outbox 행을 다시 발송해도 받는 사람마다 한 번만 남긴다
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_notifications_outbox_user")
+                    .table(UserNotifications::Table)
+                    .col(UserNotifications::OutboxId)
+                    .col(UserNotifications::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserNotifications::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserNotifications {
+    Table,
+    Id,
+    UserId,
+    OutboxId,
+    Category,
+    Title,
+    Message,
+    Payload,
+    Link,
+    ResourceType,
+    ResourceId,
+    ActorId,
+    ReadAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum NotificationsOutbox {
+    Table,
+    Id,
+}
@@ -0,0 +1,108 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사용자가 알림을 받으려고 구독한 IPAM 리소스 (사무실, 서버실, 랙, 장비, IP 대역)
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationResourceSubscriptions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NotificationResourceSubscriptions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationResourceSubscriptions::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationResourceSubscriptions::ResourceType)
+                            .string_len(30)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationResourceSubscriptions::ResourceId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationResourceSubscriptions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_resource_subscriptions_user_id")
+                            .from(
+                                NotificationResourceSubscriptions::Table,
+                                NotificationResourceSubscriptions::UserId,
+                            )
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notification_resource_subscriptions_unique")
+                    .table(NotificationResourceSubscriptions::Table)
+                    .col(NotificationResourceSubscriptions::UserId)
+                    .col(NotificationResourceSubscriptions::ResourceType)
+                    .col(NotificationResourceSubscriptions::ResourceId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notification_resource_subscriptions_resource")
+                    .table(NotificationResourceSubscriptions::Table)
+                    .col(NotificationResourceSubscriptions::ResourceType)
+                    .col(NotificationResourceSubscriptions::ResourceId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(NotificationResourceSubscriptions::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum NotificationResourceSubscriptions {
+    Table,
+    Id,
+    UserId,
+    ResourceType,
+    ResourceId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
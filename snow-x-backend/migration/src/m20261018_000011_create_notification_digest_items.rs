@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 요약 메일로 모아 보낼 알림. 요약 메일을 보내면 지운다
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationDigestItems::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::Category)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::Title)
+                            .string_len(255)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::Message)
+                            .text()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(NotificationDigestItems::Link).text().null())
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_digest_items_user_id")
+                            .from(
+                                NotificationDigestItems::Table,
+                                NotificationDigestItems::UserId,
+                            )
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notification_digest_items_user_created")
+                    .table(NotificationDigestItems::Table)
+                    .col(NotificationDigestItems::UserId)
+                    .col(NotificationDigestItems::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(NotificationDigestItems::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum NotificationDigestItems {
+    Table,
+    Id,
+    UserId,
+    Category,
+    Title,
+    Message,
+    Link,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,97 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 정책별 예약 실행. next_run_at은 예약이 꺼져 있으면 NULL
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CustodianPolicies::Table)
+                    .add_column(
+                        ColumnDef::new(CustodianPolicies::ScheduleCron)
+                            .string_len(100)
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(CustodianPolicies::ScheduleTimezone)
+                            .string_len(64)
+                            .not_null()
+                            .default("UTC"),
+                    )
+                    .add_column(
+                        ColumnDef::new(CustodianPolicies::ScheduleDryRun)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .add_column(
+                        ColumnDef::new(CustodianPolicies::ScheduleEnabled)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .add_column(
+                        ColumnDef::new(CustodianPolicies::LastRunAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(CustodianPolicies::NextRunAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_custodian_policies_next_run_at")
+                    .table(CustodianPolicies::Table)
+                    .col(CustodianPolicies::NextRunAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_custodian_policies_next_run_at")
+                    .table(CustodianPolicies::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CustodianPolicies::Table)
+                    .drop_column(CustodianPolicies::ScheduleCron)
+                    .drop_column(CustodianPolicies::ScheduleTimezone)
+                    .drop_column(CustodianPolicies::ScheduleDryRun)
+                    .drop_column(CustodianPolicies::ScheduleEnabled)
+                    .drop_column(CustodianPolicies::LastRunAt)
+                    .drop_column(CustodianPolicies::NextRunAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustodianPolicies {
+    Table,
+    ScheduleCron,
+    ScheduleTimezone,
+    ScheduleDryRun,
+    ScheduleEnabled,
+    LastRunAt,
+    NextRunAt,
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 실행을 만든 경로: manual | schedule
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CustodianExecutions::Table)
+                    .add_column(
+                        ColumnDef::new(CustodianExecutions::Trigger)
+                            .string_len(20)
+                            .not_null()
+                            .default("manual"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 정책별로 진행 중인 실행을 찾는 데 쓴다
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_custodian_executions_policy_status")
+                    .table(CustodianExecutions::Table)
+                    .col(CustodianExecutions::PolicyId)
+                    .col(CustodianExecutions::Status)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_custodian_executions_policy_status")
+                    .table(CustodianExecutions::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CustodianExecutions::Table)
+                    .drop_column(CustodianExecutions::Trigger)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustodianExecutions {
+    Table,
+    PolicyId,
+    Status,
+    Trigger,
+}
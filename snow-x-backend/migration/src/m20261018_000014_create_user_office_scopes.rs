@@ -0,0 +1,99 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사용자가 접근할 수 있는 사무실 (행이 없으면 모든 사무실에 접근 가능)
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserOfficeScopes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserOfficeScopes::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(UserOfficeScopes::UserId).uuid().not_null())
+                    .col(ColumnDef::new(UserOfficeScopes::OfficeId).uuid().not_null())
+                    .col(ColumnDef::new(UserOfficeScopes::CreatedBy).uuid())
+                    .col(
+                        ColumnDef::new(UserOfficeScopes::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_office_scopes_user_id")
+                            .from(UserOfficeScopes::Table, UserOfficeScopes::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_office_scopes_office_id")
+                            .from(UserOfficeScopes::Table, UserOfficeScopes::OfficeId)
+                            .to(Offices::Table, Offices::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_office_scopes_created_by")
+                            .from(UserOfficeScopes::Table, UserOfficeScopes::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_office_scopes_unique")
+                    .table(UserOfficeScopes::Table)
+                    .col(UserOfficeScopes::UserId)
+                    .col(UserOfficeScopes::OfficeId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserOfficeScopes::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserOfficeScopes {
+    Table,
+    Id,
+    UserId,
+    OfficeId,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Offices {
+    Table,
+    Id,
+}
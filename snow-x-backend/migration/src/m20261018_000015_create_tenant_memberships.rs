@@ -0,0 +1,220 @@
+use crate::common::UserRole;
+use sea_orm_migration::prelude::*;
+use strum::IntoEnumIterator;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 테넌트에 직접 속하는 테이블. 서버실·랙은 사무실을, IP 대역·주소는 사무실(`ip_ranges.tenant_id`)을 따라간다.
+const TENANT_SCOPED_TABLES: [&str; 7] = [
+    "offices",
+    "devices",
+    "contacts",
+    "device_library",
+    "custodian_policies",
+    "external_api_connections",
+    "audit_logs",
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사용자의 테넌트 소속과 테넌트별 역할
+        manager
+            .create_table(
+                Table::create()
+                    .table(TenantMemberships::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TenantMemberships::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(TenantMemberships::TenantId).uuid().not_null())
+                    .col(ColumnDef::new(TenantMemberships::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TenantMemberships::Role)
+                            .enumeration(UserRole::Table, UserRole::iter().skip(1))
+                            .not_null()
+                            .default("Staff"),
+                    )
+                    .col(ColumnDef::new(TenantMemberships::CreatedBy).uuid())
+                    .col(
+                        ColumnDef::new(TenantMemberships::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tenant_memberships_tenant_id")
+                            .from(TenantMemberships::Table, TenantMemberships::TenantId)
+                            .to(Tenants::Table, Tenants::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tenant_memberships_user_id")
+                            .from(TenantMemberships::Table, TenantMemberships::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tenant_memberships_created_by")
+                            .from(TenantMemberships::Table, TenantMemberships::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_tenant_memberships_unique")
+                    .table(TenantMemberships::Table)
+                    .col(TenantMemberships::TenantId)
+                    .col(TenantMemberships::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_tenant_memberships_user_id")
+                    .table(TenantMemberships::Table)
+                    .col(TenantMemberships::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        for table in TENANT_SCOPED_TABLES {
+            // 감사 로그는 테넌트가 지워져도 남긴다
+            let on_delete = if table == "audit_logs" {
+                ForeignKeyAction::SetNull
+            } else {
+                ForeignKeyAction::Cascade
+            };
+
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new(table))
+                        .add_column(ColumnDef::new(Alias::new("tenant_id")).uuid())
+                        .add_foreign_key(
+                            TableForeignKey::new()
+                                .name(format!("fk_{}_tenant_id", table))
+                                .from_tbl(Alias::new(table))
+                                .from_col(Alias::new("tenant_id"))
+                                .to_tbl(Tenants::Table)
+                                .to_col(Tenants::Id)
+                                .on_delete(on_delete)
+                                .on_update(ForeignKeyAction::NoAction),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_index(
+                    Index::create()
+                        .name(format!("idx_{}_tenant_id", table))
+                        .table(Alias::new(table))
+                        .col(Alias::new("tenant_id"))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // 기존 데이터는 기본 테넌트로 옮기고 모든 사용자를 지금 역할 그대로 소속시킨다
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                DO $$
+                DECLARE
+                    owner_id uuid;
+                    default_tenant_id uuid;
+                BEGIN
+                    SELECT id INTO owner_id FROM users
+                    ORDER BY (role = 'Admin') DESC, created_at ASC LIMIT 1;
+                    IF owner_id IS NULL THEN
+                        RETURN;
+                    END IF;
+
+                    SELECT id INTO default_tenant_id FROM tenants
+                    WHERE is_active = true ORDER BY created_at ASC LIMIT 1;
+                    IF default_tenant_id IS NULL THEN
+                        INSERT INTO tenants (name, description, created_by)
+                        VALUES ('Default', 'Tenant created for data that existed before multi-tenancy', owner_id)
+                        RETURNING id INTO default_tenant_id;
+                    END IF;
+
+                    UPDATE offices SET tenant_id = default_tenant_id WHERE tenant_id IS NULL;
+                    UPDATE devices SET tenant_id = default_tenant_id WHERE tenant_id IS NULL;
+                    UPDATE contacts SET tenant_id = default_tenant_id WHERE tenant_id IS NULL;
+                    UPDATE device_library SET tenant_id = default_tenant_id WHERE tenant_id IS NULL;
+                    UPDATE custodian_policies SET tenant_id = default_tenant_id WHERE tenant_id IS NULL;
+                    UPDATE external_api_connections SET tenant_id = default_tenant_id WHERE tenant_id IS NULL;
+                    UPDATE audit_logs SET tenant_id = default_tenant_id WHERE tenant_id IS NULL;
+
+                    INSERT INTO tenant_memberships (tenant_id, user_id, role)
+                    SELECT default_tenant_id, id, role FROM users
+                    ON CONFLICT (tenant_id, user_id) DO NOTHING;
+                END $$;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in TENANT_SCOPED_TABLES {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new(table))
+                        .drop_column(Alias::new("tenant_id"))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(TenantMemberships::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TenantMemberships {
+    Table,
+    Id,
+    TenantId,
+    UserId,
+    Role,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tenants {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
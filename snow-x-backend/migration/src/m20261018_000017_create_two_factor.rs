@@ -0,0 +1,202 @@
+use crate::common::UserRole;
+use sea_orm_migration::prelude::*;
+use strum::IntoEnumIterator;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사용자별 TOTP 비밀키. enabled_at이 비어 있으면 등록 확인 전 상태
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserTwoFactor::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserTwoFactor::UserId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UserTwoFactor::Secret)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserTwoFactor::EnabledAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    // 마지막으로 받아들인 코드의 시간 구간. 같은 코드를 다시 쓰지 못하게 한다
+                    .col(
+                        ColumnDef::new(UserTwoFactor::LastUsedStep)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserTwoFactor::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(UserTwoFactor::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_two_factor_user_id")
+                            .from(UserTwoFactor::Table, UserTwoFactor::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 일회용 복구 코드. 원문은 저장하지 않고 SHA-256 해시만 둔다
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserRecoveryCodes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserRecoveryCodes::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(UserRecoveryCodes::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(UserRecoveryCodes::CodeHash)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserRecoveryCodes::UsedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserRecoveryCodes::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_recovery_codes_user_id")
+                            .from(UserRecoveryCodes::Table, UserRecoveryCodes::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_recovery_codes_user_id")
+                    .table(UserRecoveryCodes::Table)
+                    .col(UserRecoveryCodes::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 역할별 2단계 인증 강제 여부. 행이 없으면 강제하지 않는다
+        manager
+            .create_table(
+                Table::create()
+                    .table(TwoFactorRolePolicies::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TwoFactorRolePolicies::Role)
+                            .enumeration(UserRole::Table, UserRole::iter().skip(1))
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(TwoFactorRolePolicies::Required)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(TwoFactorRolePolicies::UpdatedBy).uuid().null())
+                    .col(
+                        ColumnDef::new(TwoFactorRolePolicies::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_two_factor_role_policies_updated_by")
+                            .from(TwoFactorRolePolicies::Table, TwoFactorRolePolicies::UpdatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TwoFactorRolePolicies::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserRecoveryCodes::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserTwoFactor::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserTwoFactor {
+    Table,
+    UserId,
+    Secret,
+    EnabledAt,
+    LastUsedStep,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum UserRecoveryCodes {
+    Table,
+    Id,
+    UserId,
+    CodeHash,
+    UsedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum TwoFactorRolePolicies {
+    Table,
+    Role,
+    Required,
+    UpdatedBy,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
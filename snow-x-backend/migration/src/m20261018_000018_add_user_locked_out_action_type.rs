@@ -0,0 +1,22 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 로그인 실패가 반복되어 계정을 잠근 이벤트
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TYPE action_type ADD VALUE IF NOT EXISTS 'user_locked_out';")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        // PostgreSQL은 enum 값을 지울 수 없으므로 그대로 둔다
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 자동 작업의 작성자로 서버가 직접 만든 사용자
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::IsSystem)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 이전에 만든 시스템 사용자: 비밀번호도 OAuth 연결도 없는 일반 계정은 그 경로로만 생긴다
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                UPDATE users SET is_system = true
+                WHERE id = (
+                    SELECT u.id FROM users u
+                    WHERE u.password IS NULL
+                      AND NOT u.is_service_account
+                      AND NOT EXISTS (
+                          SELECT 1 FROM user_oauth_connections c WHERE c.user_id = u.id
+                      )
+                    ORDER BY u.created_at
+                    LIMIT 1
+                );
+                "#,
+            )
+            .await?;
+
+        // 시스템 사용자는 하나만 둔다
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX idx_users_is_system ON users (is_system) WHERE is_system;",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_users_is_system")
+                    .table(Users::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::IsSystem)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    IsSystem,
+}
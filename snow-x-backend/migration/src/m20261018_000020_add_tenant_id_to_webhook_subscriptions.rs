@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 구독은 만든 사람이 속한 테넌트의 이벤트만 받는다
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookSubscriptions::Table)
+                    .add_column(ColumnDef::new(WebhookSubscriptions::TenantId).uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_webhook_subscriptions_tenant_id")
+                            .from_tbl(WebhookSubscriptions::Table)
+                            .from_col(WebhookSubscriptions::TenantId)
+                            .to_tbl(Tenants::Table)
+                            .to_col(Tenants::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_subscriptions_tenant_id")
+                    .table(WebhookSubscriptions::Table)
+                    .col(WebhookSubscriptions::TenantId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 기존 구독은 기본 테넌트로 옮긴다
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                UPDATE webhook_subscriptions SET tenant_id = (
+                    SELECT id FROM tenants
+                    WHERE is_active = true ORDER BY created_at ASC LIMIT 1
+                )
+                WHERE tenant_id IS NULL;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookSubscriptions::Table)
+                    .drop_column(WebhookSubscriptions::TenantId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookSubscriptions {
+    Table,
+    TenantId,
+}
+
+#[derive(DeriveIden)]
+enum Tenants {
+    Table,
+    Id,
+}
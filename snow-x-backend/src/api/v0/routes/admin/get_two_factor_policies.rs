@@ -0,0 +1,32 @@
+use axum::{Extension, Json, extract::State};
+
+use crate::{
+    dto::auth::internal::access_token::AccessTokenClaims,
+    dto::two_factor::response::TwoFactorRolePolicyListResponse, service::error::errors::Errors,
+    service::two_factor::service_get_two_factor_policies, state::AppState,
+};
+
+/// 역할별 2단계 인증 정책 조회
+#[utoipa::path(
+    get,
+    path = "/v0/admin/two-factor/policies",
+    summary = "Get two-factor policies",
+    description = "List whether two-factor authentication is required for each user role. Roles without a stored policy are not enforced. (Admin only)",
+    responses(
+        (status = 200, description = "Two-factor policies per role", body = TwoFactorRolePolicyListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn get_two_factor_policies(
+    State(app_state): State<AppState>,
+    Extension(token_data): Extension<AccessTokenClaims>,
+) -> Result<Json<TwoFactorRolePolicyListResponse>, Errors> {
+    let response = service_get_two_factor_policies(&app_state.conn, token_data.sub).await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,41 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use uuid::Uuid;
+
+use crate::{
+    dto::admin::response::UserPermissionsResponse,
+    dto::auth::internal::access_token::AccessTokenClaims,
+    service::admin::user_permissions::service_get_user_permissions, service::error::errors::Errors,
+    state::AppState,
+};
+
+/// 사용자 권한 조회
+#[utoipa::path(
+    get,
+    path = "/v0/admin/users/{user_id}/permissions",
+    summary = "Get user permissions",
+    description = "Get a user's role, office scope and the resulting read/write/delete permissions per IPAM resource. (Admin only)",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "User permissions retrieved successfully", body = UserPermissionsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn get_user_permissions(
+    State(app_state): State<AppState>,
+    Extension(token_data): Extension<AccessTokenClaims>,
+    Path(user_id): Path<Uuid>,
+) -> Result<UserPermissionsResponse, Errors> {
+    service_get_user_permissions(&app_state.conn, token_data.sub, user_id).await
+}
@@ -1,6 +1,8 @@
 pub mod check_admin_status;
 pub mod cleanup_expired_tokens;
 pub mod cleanup_old_events;
+pub mod get_two_factor_policies;
+pub mod get_user_permissions;
 pub mod meilisearch_health;
 pub mod reindex_all_posts;
 pub mod routes;
@@ -8,3 +10,5 @@ pub mod search_stats;
 pub mod sync_all_counts;
 pub mod sync_follows;
 pub mod sync_likes;
+pub mod update_two_factor_policy;
+pub mod update_user_permissions;
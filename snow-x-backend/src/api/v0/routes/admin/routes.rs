@@ -1,15 +1,18 @@
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 
 use crate::{middleware::auth::access_jwt_auth, state::AppState};
 
 use super::{
     check_admin_status::check_admin_status, cleanup_expired_tokens::cleanup_expired_tokens,
-    cleanup_old_events::cleanup_old_events, meilisearch_health::meilisearch_health,
+    cleanup_old_events::cleanup_old_events, get_two_factor_policies::get_two_factor_policies,
+    get_user_permissions::get_user_permissions, meilisearch_health::meilisearch_health,
     reindex_all_posts::reindex_all_posts, search_stats::search_stats,
     sync_all_counts::sync_all_counts, sync_follows::sync_follows, sync_likes::sync_likes,
+    update_two_factor_policy::update_two_factor_policy,
+    update_user_permissions::update_user_permissions,
 };
 
 pub fn admin_routes() -> Router<AppState> {
@@ -24,6 +27,14 @@ pub fn admin_routes() -> Router<AppState> {
         .route("/sync/likes", post(sync_likes))
         .route("/sync/follows", post(sync_follows))
         .route("/sync/all", post(sync_all_counts))
+        // User permission endpoints
+        .route(
+            "/users/{user_id}/permissions",
+            get(get_user_permissions).put(update_user_permissions),
+        )
+        // Two-factor policy endpoints
+        .route("/two-factor/policies", get(get_two_factor_policies))
+        .route("/two-factor/policies/{role}", put(update_two_factor_policy))
         // Cleanup endpoints
         .route("/cleanup/tokens", post(cleanup_expired_tokens))
         .route("/cleanup/events", post(cleanup_old_events))
@@ -0,0 +1,45 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+
+use crate::{
+    dto::auth::internal::access_token::AccessTokenClaims,
+    dto::two_factor::request::UpdateTwoFactorRolePolicyRequest,
+    dto::two_factor::response::TwoFactorRolePolicyResponse, entity::common::UserRole,
+    service::error::errors::Errors, service::two_factor::service_update_two_factor_policy,
+    state::AppState,
+};
+
+/// 역할별 2단계 인증 강제 여부 변경
+#[utoipa::path(
+    put,
+    path = "/v0/admin/two-factor/policies/{role}",
+    summary = "Update two-factor policy",
+    description = "Require (or stop requiring) two-factor authentication for a user role. Users of that role without 2FA must enroll on their next password sign-in. Google/GitHub sign-ins are not affected. (Admin only)",
+    params(
+        ("role" = UserRole, Path, description = "User role (Admin, Manager, Staff)")
+    ),
+    request_body = UpdateTwoFactorRolePolicyRequest,
+    responses(
+        (status = 200, description = "Two-factor policy updated", body = TwoFactorRolePolicyResponse),
+        (status = 400, description = "Unknown role"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn update_two_factor_policy(
+    State(app_state): State<AppState>,
+    Extension(token_data): Extension<AccessTokenClaims>,
+    Path(role): Path<UserRole>,
+    Json(request): Json<UpdateTwoFactorRolePolicyRequest>,
+) -> Result<Json<TwoFactorRolePolicyResponse>, Errors> {
+    let response =
+        service_update_two_factor_policy(&app_state.conn, token_data.sub, role, request).await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,45 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use uuid::Uuid;
+
+use crate::{
+    dto::admin::request::UpdateUserPermissionsRequest,
+    dto::admin::response::UserPermissionsResponse,
+    dto::auth::internal::access_token::AccessTokenClaims,
+    service::admin::user_permissions::service_update_user_permissions,
+    service::error::errors::Errors, state::AppState,
+};
+
+/// 사용자 역할 및 사무실 범위 변경
+#[utoipa::path(
+    put,
+    path = "/v0/admin/users/{user_id}/permissions",
+    summary = "Update user permissions",
+    description = "Change a user's role and/or the offices they are restricted to. An empty office list removes the restriction. (Admin only)",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = UpdateUserPermissionsRequest,
+    responses(
+        (status = 200, description = "User permissions updated successfully", body = UserPermissionsResponse),
+        (status = 400, description = "Unknown office or own admin role change"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn update_user_permissions(
+    State(app_state): State<AppState>,
+    Extension(token_data): Extension<AccessTokenClaims>,
+    Path(user_id): Path<Uuid>,
+    Json(request): Json<UpdateUserPermissionsRequest>,
+) -> Result<UserPermissionsResponse, Errors> {
+    service_update_user_permissions(&app_state.conn, token_data.sub, user_id, request).await
+}
@@ -0,0 +1,213 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    dto::api_token::request::{CreateApiTokenRequest, CreateServiceAccountRequest},
+    dto::api_token::response::{
+        ApiTokenListResponse, ApiTokenResponse, ApiTokenSecretResponse, ServiceAccountListResponse,
+        ServiceAccountResponse,
+    },
+    dto::auth::internal::access_token::AccessTokenClaims,
+    service::api_token::{
+        service_create_my_api_token, service_create_service_account,
+        service_create_service_account_token, service_get_my_api_tokens,
+        service_get_service_account_tokens, service_get_service_accounts,
+        service_revoke_my_api_token, service_revoke_service_account_token,
+    },
+    service::error::errors::Errors,
+    service::validator::json_validator::ValidatedJson,
+    state::AppState,
+};
+
+/// 내 API 토큰 목록을 조회합니다. 토큰 원문은 보여주지 않습니다.
+#[utoipa::path(
+    get,
+    path = "/v0/api-tokens",
+    tag = "API Tokens",
+    responses(
+        (status = 200, description = "API 토큰 목록", body = ApiTokenListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "API 토큰으로는 부를 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_my_api_tokens(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_my_api_tokens(&state.conn, claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// API 토큰을 발급합니다. 토큰 원문은 이 응답에서만 확인할 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/v0/api-tokens",
+    tag = "API Tokens",
+    request_body = CreateApiTokenRequest,
+    responses(
+        (status = 201, description = "API 토큰 발급", body = ApiTokenSecretResponse),
+        (status = 400, description = "잘못된 이름, 범위 또는 유효 기간"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "API 토큰으로는 부를 수 없음 또는 테넌트 구성원이 아님")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn create_my_api_token(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Json(request): Json<CreateApiTokenRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_create_my_api_token(&state.conn, claims.sub, request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 내 API 토큰을 폐기합니다.
+#[utoipa::path(
+    delete,
+    path = "/v0/api-tokens/{token_id}",
+    tag = "API Tokens",
+    params(("token_id" = Uuid, Path, description = "토큰 ID")),
+    responses(
+        (status = 200, description = "폐기된 API 토큰", body = ApiTokenResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "API 토큰으로는 부를 수 없음"),
+        (status = 404, description = "토큰 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn revoke_my_api_token(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path(token_id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_revoke_my_api_token(&state.conn, claims.sub, token_id).await?;
+    Ok(Json(response))
+}
+
+/// 서비스 계정 목록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/service-accounts",
+    tag = "API Tokens",
+    responses(
+        (status = 200, description = "서비스 계정 목록", body = ServiceAccountListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_service_accounts(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_service_accounts(&state.conn, claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// 서비스 계정을 만듭니다. 서비스 계정은 로그인할 수 없고 API 토큰으로만 씁니다.
+#[utoipa::path(
+    post,
+    path = "/v0/service-accounts",
+    tag = "API Tokens",
+    request_body = CreateServiceAccountRequest,
+    responses(
+        (status = 201, description = "서비스 계정 생성", body = ServiceAccountResponse),
+        (status = 400, description = "잘못된 핸들 또는 이름"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요"),
+        (status = 404, description = "테넌트 없음"),
+        (status = 409, description = "이미 쓰는 핸들")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn create_service_account(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(request): ValidatedJson<CreateServiceAccountRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_create_service_account(&state.conn, claims.sub, request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 서비스 계정의 API 토큰 목록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/service-accounts/{account_id}/tokens",
+    tag = "API Tokens",
+    params(("account_id" = Uuid, Path, description = "서비스 계정 ID")),
+    responses(
+        (status = 200, description = "API 토큰 목록", body = ApiTokenListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요"),
+        (status = 404, description = "서비스 계정 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_service_account_tokens(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path(account_id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_service_account_tokens(&state.conn, claims.sub, account_id).await?;
+    Ok(Json(response))
+}
+
+/// 서비스 계정의 API 토큰을 발급합니다. 토큰 원문은 이 응답에서만 확인할 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/v0/service-accounts/{account_id}/tokens",
+    tag = "API Tokens",
+    params(("account_id" = Uuid, Path, description = "서비스 계정 ID")),
+    request_body = CreateApiTokenRequest,
+    responses(
+        (status = 201, description = "API 토큰 발급", body = ApiTokenSecretResponse),
+        (status = 400, description = "잘못된 이름, 범위 또는 유효 기간"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요 또는 서비스 계정이 테넌트 구성원이 아님"),
+        (status = 404, description = "서비스 계정 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn create_service_account_token(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path(account_id): Path<Uuid>,
+    Json(request): Json<CreateApiTokenRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response =
+        service_create_service_account_token(&state.conn, claims.sub, account_id, request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 서비스 계정의 API 토큰을 폐기합니다.
+#[utoipa::path(
+    delete,
+    path = "/v0/service-accounts/{account_id}/tokens/{token_id}",
+    tag = "API Tokens",
+    params(
+        ("account_id" = Uuid, Path, description = "서비스 계정 ID"),
+        ("token_id" = Uuid, Path, description = "토큰 ID")
+    ),
+    responses(
+        (status = 200, description = "폐기된 API 토큰", body = ApiTokenResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요"),
+        (status = 404, description = "서비스 계정 또는 토큰 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn revoke_service_account_token(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path((account_id, token_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, Errors> {
+    let response =
+        service_revoke_service_account_token(&state.conn, claims.sub, account_id, token_id).await?;
+    Ok(Json(response))
+}
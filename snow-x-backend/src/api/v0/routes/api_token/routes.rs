@@ -0,0 +1,35 @@
+use axum::{
+    Router, middleware,
+    routing::{delete, get},
+};
+
+use crate::middleware::auth::access_jwt_auth;
+
+use super::handlers::{
+    create_my_api_token, create_service_account, create_service_account_token, get_my_api_tokens,
+    get_service_account_tokens, get_service_accounts, revoke_my_api_token,
+    revoke_service_account_token,
+};
+
+/// 내 API 토큰 (`/v0/api-tokens` 아래에 중첩). API 토큰으로는 부를 수 없다.
+pub fn api_token_routes() -> Router<crate::AppState> {
+    Router::new()
+        .route("/", get(get_my_api_tokens).post(create_my_api_token))
+        .route("/{token_id}", delete(revoke_my_api_token))
+        .route_layer(middleware::from_fn(access_jwt_auth))
+}
+
+/// 서비스 계정과 그 토큰 (`/v0/service-accounts` 아래에 중첩, Admin 전용)
+pub fn service_account_routes() -> Router<crate::AppState> {
+    Router::new()
+        .route("/", get(get_service_accounts).post(create_service_account))
+        .route(
+            "/{account_id}/tokens",
+            get(get_service_account_tokens).post(create_service_account_token),
+        )
+        .route(
+            "/{account_id}/tokens/{token_id}",
+            delete(revoke_service_account_token),
+        )
+        .route_layer(middleware::from_fn(access_jwt_auth))
+}
@@ -0,0 +1,318 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    dto::audit::response::AuditLogListResponse,
+    middleware::permission::{Authorized, TenantContext, action, resource},
+    service::audit::{
+        AuditLogFilter, RESOURCE_CONTACT, RESOURCE_DEVICE, RESOURCE_DEVICE_LIBRARY,
+        RESOURCE_IP_ADDRESS, RESOURCE_IP_RANGE, RESOURCE_OFFICE, RESOURCE_RACK,
+        RESOURCE_SERVER_ROOM, service_get_audit_logs, service_get_resource_history,
+    },
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ListAuditLogsQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    /// office, server_room, rack, device, ip_range, ip_address, contact, device_library
+    pub resource_type: Option<String>,
+    pub resource_id: Option<Uuid>,
+    pub actor_id: Option<Uuid>,
+    /// create, update, delete
+    pub action: Option<String>,
+    /// 이 시각 이후 (포함)
+    pub from: Option<DateTime<Utc>>,
+    /// 이 시각 이전 (미포함)
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ResourceHistoryQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+/// IPAM 전체 변경 이력을 조회합니다 (최신순).
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/audit-log",
+    tag = "Audit Log",
+    params(
+        ListAuditLogsQuery,
+    ),
+    responses(
+        (status = 200, description = "감사 로그 조회 성공", body = AuditLogListResponse),
+        (status = 400, description = "잘못된 필터"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "리소스를 찾을 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_audit_logs(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Query(query): Query<ListAuditLogsQuery>,
+) -> impl IntoResponse {
+    let filter = AuditLogFilter {
+        tenant_id: Some(tenant.tenant_id()),
+        resource_type: query.resource_type,
+        resource_id: query.resource_id,
+        actor_id: query.actor_id,
+        action: query.action,
+        from: query.from,
+        to: query.to,
+    };
+
+    match service_get_audit_logs(
+        &state.conn,
+        filter,
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+    )
+    .await
+    {
+        Ok(result) => Json(result).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+async fn resource_history(
+    state: &AppState,
+    tenant_id: Uuid,
+    resource_type: &str,
+    resource_id: Uuid,
+    query: ResourceHistoryQuery,
+) -> axum::response::Response {
+    match service_get_resource_history(
+        &state.conn,
+        tenant_id,
+        resource_type,
+        resource_id,
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+    )
+    .await
+    {
+        Ok(result) => Json(result).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+/// 사무실 변경 이력을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/office/{id}/history",
+    tag = "Audit Log",
+    params(
+        ("id" = Uuid, Path, description = "사무실 ID"),
+        ResourceHistoryQuery,
+    ),
+    responses(
+        (status = 200, description = "변경 이력 조회 성공", body = AuditLogListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "리소스를 찾을 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_office_history(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Office, action::Read>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ResourceHistoryQuery>,
+) -> impl IntoResponse {
+    resource_history(&state, auth.tenant_id(), RESOURCE_OFFICE, id, query).await
+}
+
+/// 서버실 변경 이력을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/office/{office_id}/server-room/{id}/history",
+    tag = "Audit Log",
+    params(
+        ("office_id" = Uuid, Path, description = "사무실 ID"),
+        ("id" = Uuid, Path, description = "서버실 ID"),
+        ResourceHistoryQuery,
+    ),
+    responses(
+        (status = 200, description = "변경 이력 조회 성공", body = AuditLogListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "리소스를 찾을 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_server_room_history(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ServerRoom, action::Read>,
+    Path((_office_id, id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<ResourceHistoryQuery>,
+) -> impl IntoResponse {
+    resource_history(&state, auth.tenant_id(), RESOURCE_SERVER_ROOM, id, query).await
+}
+
+/// 랙 변경 이력을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/racks/{id}/history",
+    tag = "Audit Log",
+    params(
+        ("id" = Uuid, Path, description = "랙 ID"),
+        ResourceHistoryQuery,
+    ),
+    responses(
+        (status = 200, description = "변경 이력 조회 성공", body = AuditLogListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "리소스를 찾을 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_rack_history(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Rack, action::Read>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ResourceHistoryQuery>,
+) -> impl IntoResponse {
+    resource_history(&state, auth.tenant_id(), RESOURCE_RACK, id, query).await
+}
+
+/// 장비 변경 이력을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/device/{id}/history",
+    tag = "Audit Log",
+    params(
+        ("id" = Uuid, Path, description = "장비 ID"),
+        ResourceHistoryQuery,
+    ),
+    responses(
+        (status = 200, description = "변경 이력 조회 성공", body = AuditLogListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "리소스를 찾을 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_device_history(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Device, action::Read>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ResourceHistoryQuery>,
+) -> impl IntoResponse {
+    resource_history(&state, auth.tenant_id(), RESOURCE_DEVICE, id, query).await
+}
+
+/// IP 대역 변경 이력을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/ip-range/{id}/history",
+    tag = "Audit Log",
+    params(
+        ("id" = Uuid, Path, description = "IP 대역 ID"),
+        ResourceHistoryQuery,
+    ),
+    responses(
+        (status = 200, description = "변경 이력 조회 성공", body = AuditLogListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "리소스를 찾을 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_ip_range_history(
+    State(state): State<AppState>,
+    auth: Authorized<resource::IpRange, action::Read>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ResourceHistoryQuery>,
+) -> impl IntoResponse {
+    resource_history(&state, auth.tenant_id(), RESOURCE_IP_RANGE, id, query).await
+}
+
+/// IP 주소 변경 이력을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/ip-address/{id}/history",
+    tag = "Audit Log",
+    params(
+        ("id" = Uuid, Path, description = "IP 주소 ID"),
+        ResourceHistoryQuery,
+    ),
+    responses(
+        (status = 200, description = "변경 이력 조회 성공", body = AuditLogListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "리소스를 찾을 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_ip_address_history(
+    State(state): State<AppState>,
+    auth: Authorized<resource::IpAddress, action::Read>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ResourceHistoryQuery>,
+) -> impl IntoResponse {
+    resource_history(&state, auth.tenant_id(), RESOURCE_IP_ADDRESS, id, query).await
+}
+
+/// 담당자 변경 이력을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/contact/{id}/history",
+    tag = "Audit Log",
+    params(
+        ("id" = Uuid, Path, description = "담당자 ID"),
+        ResourceHistoryQuery,
+    ),
+    responses(
+        (status = 200, description = "변경 이력 조회 성공", body = AuditLogListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "리소스를 찾을 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_contact_history(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Contact, action::Read>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ResourceHistoryQuery>,
+) -> impl IntoResponse {
+    resource_history(&state, auth.tenant_id(), RESOURCE_CONTACT, id, query).await
+}
+
+/// 장비 라이브러리 변경 이력을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/device-library/{id}/history",
+    tag = "Audit Log",
+    params(
+        ("id" = Uuid, Path, description = "장비 라이브러리 ID"),
+        ResourceHistoryQuery,
+    ),
+    responses(
+        (status = 200, description = "변경 이력 조회 성공", body = AuditLogListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "리소스를 찾을 수 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_device_library_history(
+    State(state): State<AppState>,
+    auth: Authorized<resource::DeviceLibrary, action::Read>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ResourceHistoryQuery>,
+) -> impl IntoResponse {
+    resource_history(&state, auth.tenant_id(), RESOURCE_DEVICE_LIBRARY, id, query).await
+}
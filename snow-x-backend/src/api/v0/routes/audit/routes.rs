@@ -0,0 +1,30 @@
+use axum::{Router, middleware, routing::get};
+
+use crate::middleware::auth::access_jwt_auth;
+
+use super::handlers::{
+    get_audit_logs, get_contact_history, get_device_history, get_device_library_history,
+    get_ip_address_history, get_ip_range_history, get_office_history, get_rack_history,
+    get_server_room_history,
+};
+
+/// 감사 로그 피드와 리소스별 변경 이력 (`/v0/ipam` 아래에 중첩)
+pub fn audit_routes() -> Router<crate::AppState> {
+    Router::new()
+        .route("/audit-log", get(get_audit_logs))
+        .route("/office/{id}/history", get(get_office_history))
+        .route(
+            "/office/{office_id}/server-room/{id}/history",
+            get(get_server_room_history),
+        )
+        .route("/racks/{id}/history", get(get_rack_history))
+        .route("/device/{id}/history", get(get_device_history))
+        .route("/ip-range/{id}/history", get(get_ip_range_history))
+        .route("/ip-address/{id}/history", get(get_ip_address_history))
+        .route("/contact/{id}/history", get(get_contact_history))
+        .route(
+            "/device-library/{id}/history",
+            get(get_device_library_history),
+        )
+        .route_layer(middleware::from_fn(access_jwt_auth))
+}
@@ -16,6 +16,7 @@ use tracing::info;
         (status = 200, description = "Password reset email sent if account exists"),
         (status = 400, description = "Invalid email format"),
         (status = 422, description = "Validation error"),
+        (status = 429, description = "Too many requests: rate_limit:exceeded (see Retry-After)"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Auth"
@@ -1,5 +1,5 @@
 use crate::dto::auth::request::oauth::GithubLoginRequest;
-use crate::dto::auth::response::jwt::AuthJWTResponse;
+use crate::dto::auth::response::sign_in::SignInResponse;
 use crate::service::error::errors::Errors;
 use crate::service::oauth::github_sign_in::service_github_sign_in;
 use crate::service::validator::json_validator::ValidatedJson;
@@ -18,7 +18,7 @@ use std::net::SocketAddr;
     path = "/v0/auth/github",
     request_body = GithubLoginRequest,
     responses(
-        (status = 200, description = "GitHub OAuth login successful", body = AuthJWTResponse),
+        (status = 200, description = "GitHub OAuth login successful, or a two-factor challenge when the user has 2FA enabled or their role requires it", body = SignInResponse),
         (status = 400, description = "Invalid authorization code"),
         (status = 401, description = "OAuth authentication failed"),
         (status = 409, description = "Handle already exists"),
@@ -33,11 +33,11 @@ pub async fn github_sign_in(
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     State(state): State<AppState>,
     ValidatedJson(payload): ValidatedJson<GithubLoginRequest>,
-) -> Result<AuthJWTResponse, Errors> {
+) -> Result<SignInResponse, Errors> {
     let ip_str = extract_ip_address(&headers, addr);
     let ua_str = extract_user_agent(user_agent);
 
-    let res = service_github_sign_in(
+    service_github_sign_in(
         &state.conn,
         &state.cloudflare_r2,
         &state.http_client,
@@ -46,10 +46,5 @@ pub async fn github_sign_in(
         &payload.code,
         payload.handle.as_deref(),
     )
-    .await?;
-
-    Ok(AuthJWTResponse {
-        access_token: res.access_token,
-        cookie_refresh_token: res.cookie_refresh_token,
-    })
+    .await
 }
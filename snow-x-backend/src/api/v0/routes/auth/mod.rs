@@ -9,7 +9,9 @@ pub mod reset_password;
 pub mod routes;
 pub mod set_password;
 pub mod sign_in;
+pub mod sign_in_two_factor;
 pub mod sign_out;
 pub mod sign_up;
+pub mod two_factor;
 pub mod unlink_oauth;
 pub mod verify_email;
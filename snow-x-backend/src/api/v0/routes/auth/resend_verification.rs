@@ -17,6 +17,7 @@ use tracing::info;
         (status = 400, description = "Email already verified: email:already_verified"),
         (status = 404, description = "User not found"),
         (status = 422, description = "Validation error"),
+        (status = 429, description = "Too many requests: rate_limit:exceeded (see Retry-After)"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Auth"
@@ -8,22 +8,47 @@ use crate::api::v0::routes::auth::resend_verification::resend_verification;
 use crate::api::v0::routes::auth::reset_password::reset_password;
 use crate::api::v0::routes::auth::set_password::set_password;
 use crate::api::v0::routes::auth::sign_in::sign_in;
+use crate::api::v0::routes::auth::sign_in_two_factor::{
+    enroll_two_factor_at_sign_in, sign_in_two_factor,
+};
 use crate::api::v0::routes::auth::sign_out::sign_out;
 use crate::api::v0::routes::auth::sign_up::sign_up;
+use crate::api::v0::routes::auth::two_factor::{
+    confirm_two_factor, disable_two_factor, enroll_two_factor, get_two_factor_status,
+    regenerate_recovery_codes,
+};
 use crate::api::v0::routes::auth::unlink_oauth::unlink_oauth;
 use crate::api::v0::routes::auth::verify_email::verify_email;
 use crate::middleware::auth::{access_jwt_auth, refresh_jwt_auth};
+use crate::middleware::rate_limit::rate_limit;
 use crate::state::AppState;
 use axum::Router;
 use axum::routing::{delete, get, post};
 
 pub fn auth_routes() -> Router<AppState> {
     Router::new()
-        .route("/auth/sign_in", post(sign_in))
+        .route(
+            "/auth/sign_in",
+            post(sign_in).route_layer(axum::middleware::from_fn(rate_limit)),
+        )
+        .route(
+            "/auth/sign_in/two_factor",
+            post(sign_in_two_factor).route_layer(axum::middleware::from_fn(rate_limit)),
+        )
+        .route(
+            "/auth/sign_in/two_factor/enroll",
+            post(enroll_two_factor_at_sign_in).route_layer(axum::middleware::from_fn(rate_limit)),
+        )
         .route("/auth/sign_up", post(sign_up))
         .route("/auth/verify_email", post(verify_email))
-        .route("/auth/resend_verification", post(resend_verification))
-        .route("/auth/forgot_password", post(forgot_password))
+        .route(
+            "/auth/resend_verification",
+            post(resend_verification).route_layer(axum::middleware::from_fn(rate_limit)),
+        )
+        .route(
+            "/auth/forgot_password",
+            post(forgot_password).route_layer(axum::middleware::from_fn(rate_limit)),
+        )
         .route("/auth/reset_password", post(reset_password))
         .route(
             "/auth/set_password",
@@ -41,6 +66,26 @@ pub fn auth_routes() -> Router<AppState> {
             "/auth/unlink-oauth",
             delete(unlink_oauth).route_layer(axum::middleware::from_fn(access_jwt_auth)),
         )
+        .route(
+            "/auth/two_factor",
+            get(get_two_factor_status).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/auth/two_factor/enroll",
+            post(enroll_two_factor).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/auth/two_factor/confirm",
+            post(confirm_two_factor).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/auth/two_factor/disable",
+            post(disable_two_factor).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/auth/two_factor/recovery_codes",
+            post(regenerate_recovery_codes).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+        )
         .route(
             "/auth/sign_out",
             post(sign_out).route_layer(axum::middleware::from_fn(refresh_jwt_auth)),
@@ -1,5 +1,5 @@
 use crate::dto::auth::request::login::AuthLoginRequest;
-use crate::dto::auth::response::jwt::AuthJWTResponse;
+use crate::dto::auth::response::sign_in::SignInResponse;
 use crate::service::auth::service_sign_in;
 use crate::service::error::errors::Errors;
 use crate::service::validator::json_validator::ValidatedJson;
@@ -17,11 +17,12 @@ use std::net::SocketAddr;
     path = "/v0/auth/sign_in",
     request_body = AuthLoginRequest,
     responses(
-        (status = 200, description = "Login successful", body = AuthJWTResponse),
+        (status = 200, description = "Login successful, or a two-factor challenge when the user has 2FA enabled or their role requires it", body = SignInResponse),
         (status = 400, description = "Invalid request"),
         (status = 401, description = "Invalid credentials"),
         (status = 404, description = "User not found"),
         (status = 422, description = "Validation error"),
+        (status = 429, description = "Too many requests: rate_limit:exceeded, or account locked after repeated failed passwords: rate_limit:locked_out (see Retry-After)"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Auth"
@@ -32,14 +33,9 @@ pub async fn sign_in(
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     State(state): State<AppState>,
     ValidatedJson(payload): ValidatedJson<AuthLoginRequest>,
-) -> Result<AuthJWTResponse, Errors> {
+) -> Result<SignInResponse, Errors> {
     let ip_str = extract_ip_address(&headers, addr);
     let ua_str = extract_user_agent(user_agent);
 
-    let res = service_sign_in(&state.conn, Some(ua_str), Some(ip_str), payload).await?;
-
-    Ok(AuthJWTResponse {
-        access_token: res.access_token,
-        cookie_refresh_token: res.cookie_refresh_token,
-    })
+    service_sign_in(&state.conn, Some(ua_str), Some(ip_str), payload).await
 }
@@ -0,0 +1,64 @@
+use crate::dto::auth::response::sign_in::TwoFactorSignInResponse;
+use crate::dto::two_factor::request::{TwoFactorChallengeRequest, TwoFactorSignInRequest};
+use crate::dto::two_factor::response::TwoFactorEnrollmentResponse;
+use crate::service::error::errors::Errors;
+use crate::service::two_factor::{
+    service_enroll_two_factor_at_sign_in, service_sign_in_two_factor,
+};
+use crate::state::AppState;
+use crate::utils::extract_ip_address::extract_ip_address;
+use crate::utils::extract_user_agent::extract_user_agent;
+use axum::Json;
+use axum::extract::{ConnectInfo, State};
+use axum::http::HeaderMap;
+use axum_extra::TypedHeader;
+use axum_extra::headers::UserAgent;
+use std::net::SocketAddr;
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/sign_in/two_factor",
+    request_body = TwoFactorSignInRequest,
+    responses(
+        (status = 200, description = "Login successful. recovery_codes is only present when 2FA was enrolled during this login", body = TwoFactorSignInResponse),
+        (status = 400, description = "Missing code, or 2FA not enabled: two_factor:not_enabled"),
+        (status = 401, description = "Invalid or expired challenge token, or wrong code: two_factor:invalid_code"),
+        (status = 404, description = "User not found"),
+        (status = 429, description = "Too many requests: rate_limit:exceeded (see Retry-After)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+pub async fn sign_in_two_factor(
+    user_agent: Option<TypedHeader<UserAgent>>,
+    headers: HeaderMap,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    Json(payload): Json<TwoFactorSignInRequest>,
+) -> Result<TwoFactorSignInResponse, Errors> {
+    let ip_str = extract_ip_address(&headers, addr);
+    let ua_str = extract_user_agent(user_agent);
+
+    service_sign_in_two_factor(&state.conn, Some(ua_str), Some(ip_str), payload).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/sign_in/two_factor/enroll",
+    request_body = TwoFactorChallengeRequest,
+    responses(
+        (status = 200, description = "TOTP secret and otpauth URI for the QR code", body = TwoFactorEnrollmentResponse),
+        (status = 401, description = "Invalid or expired challenge token"),
+        (status = 409, description = "Challenge does not require enrollment: two_factor:already_enabled"),
+        (status = 429, description = "Too many requests: rate_limit:exceeded (see Retry-After)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+pub async fn enroll_two_factor_at_sign_in(
+    State(state): State<AppState>,
+    Json(payload): Json<TwoFactorChallengeRequest>,
+) -> Result<Json<TwoFactorEnrollmentResponse>, Errors> {
+    let response = service_enroll_two_factor_at_sign_in(&state.conn, payload).await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,122 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::two_factor::request::{ConfirmTwoFactorRequest, TwoFactorCodeRequest};
+use crate::dto::two_factor::response::{
+    TwoFactorEnrollmentResponse, TwoFactorRecoveryCodesResponse, TwoFactorStatusResponse,
+};
+use crate::service::error::errors::Errors;
+use crate::service::two_factor::{
+    service_confirm_two_factor, service_disable_two_factor, service_enroll_two_factor,
+    service_get_two_factor_status, service_regenerate_recovery_codes,
+};
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+
+#[utoipa::path(
+    get,
+    path = "/v0/auth/two_factor",
+    responses(
+        (status = 200, description = "Two-factor authentication status", body = TwoFactorStatusResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_two_factor_status(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+) -> Result<Json<TwoFactorStatusResponse>, Errors> {
+    let response = service_get_two_factor_status(&state.conn, claims.sub).await?;
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/two_factor/enroll",
+    responses(
+        (status = 200, description = "TOTP secret and otpauth URI for the QR code", body = TwoFactorEnrollmentResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Already enabled: two_factor:already_enabled"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth",
+    security(("bearer_auth" = []))
+)]
+pub async fn enroll_two_factor(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+) -> Result<Json<TwoFactorEnrollmentResponse>, Errors> {
+    let response = service_enroll_two_factor(&state.conn, claims.sub).await?;
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/two_factor/confirm",
+    request_body = ConfirmTwoFactorRequest,
+    responses(
+        (status = 200, description = "2FA enabled. Recovery codes are shown only once", body = TwoFactorRecoveryCodesResponse),
+        (status = 400, description = "Enrollment not started: two_factor:not_enabled"),
+        (status = 401, description = "Wrong code: two_factor:invalid_code"),
+        (status = 409, description = "Already enabled: two_factor:already_enabled"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth",
+    security(("bearer_auth" = []))
+)]
+pub async fn confirm_two_factor(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Json(payload): Json<ConfirmTwoFactorRequest>,
+) -> Result<Json<TwoFactorRecoveryCodesResponse>, Errors> {
+    let response = service_confirm_two_factor(&state.conn, claims.sub, payload).await?;
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/two_factor/disable",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 204, description = "2FA disabled and recovery codes removed"),
+        (status = 400, description = "Not enabled: two_factor:not_enabled"),
+        (status = 401, description = "Wrong code: two_factor:invalid_code"),
+        (status = 403, description = "Required by role policy: two_factor:required"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth",
+    security(("bearer_auth" = []))
+)]
+pub async fn disable_two_factor(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    service_disable_two_factor(&state.conn, claims.sub, payload).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/two_factor/recovery_codes",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "New recovery codes. Previous codes stop working", body = TwoFactorRecoveryCodesResponse),
+        (status = 400, description = "Not enabled: two_factor:not_enabled"),
+        (status = 401, description = "Wrong code: two_factor:invalid_code"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth",
+    security(("bearer_auth" = []))
+)]
+pub async fn regenerate_recovery_codes(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> Result<Json<TwoFactorRecoveryCodesResponse>, Errors> {
+    let response = service_regenerate_recovery_codes(&state.conn, claims.sub, payload).await?;
+    Ok(Json(response))
+}
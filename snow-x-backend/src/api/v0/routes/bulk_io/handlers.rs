@@ -0,0 +1,370 @@
+use axum::{
+    Json,
+    body::Body,
+    extract::{Multipart, Query, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use futures_util::Stream;
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    dto::bulk_io::response::BulkImportResponse,
+    middleware::permission::{Authorized, resource},
+    service::bulk_io::{
+        CsvChunk, DeviceExportFilter, IpAddressExportFilter, RackExportFilter, read_import_upload,
+        service_export_contacts, service_export_devices, service_export_ip_addresses,
+        service_export_racks, service_import_contacts, service_import_devices,
+        service_import_ip_addresses, service_import_racks,
+    },
+    service::error::errors::Errors,
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct BulkImportQuery {
+    /// true면 모든 행을 검증만 하고 반영하지 않습니다
+    pub dry_run: Option<bool>,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct BulkImportForm {
+    /// UTF-8 CSV 파일 (첫 행은 헤더). 엑셀 파일은 CSV로 저장해서 올려 주세요.
+    #[schema(format = Binary)]
+    file: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct DeviceExportQuery {
+    pub office_id: Option<Uuid>,
+    pub server_room_id: Option<Uuid>,
+    pub rack_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct RackExportQuery {
+    pub office_id: Option<Uuid>,
+    pub server_room_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct IpAddressExportQuery {
+    pub ip_range_id: Option<Uuid>,
+    /// available, reserved, unavailable, allocated, expired
+    pub status: Option<String>,
+}
+
+/// 가져오기 결과: 반영했거나 dry-run 검증을 통과하면 200, 실패한 행이 있으면 422
+fn import_response(report: BulkImportResponse) -> impl IntoResponse {
+    let status = if report.failed > 0 {
+        StatusCode::UNPROCESSABLE_ENTITY
+    } else {
+        StatusCode::OK
+    };
+    (status, Json(report))
+}
+
+/// 내보내기 스트림을 그대로 흘려보내는 CSV 첨부 응답
+fn csv_attachment<S>(file_name: &str, stream: S) -> impl IntoResponse
+where
+    S: Stream<Item = CsvChunk> + Send + 'static,
+{
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"{}-{}.csv\"",
+                    file_name,
+                    chrono::Utc::now().format("%Y%m%d")
+                ),
+            ),
+        ],
+        Body::from_stream(stream),
+    )
+}
+
+/// 장비 CSV를 가져옵니다.
+///
+/// `id` 또는 `serial_number`가 기존 장비와 같으면 수정하고, 아니면 새로 만듭니다.
+/// 랙은 `rack` 이름으로 찾으며 이름이 겹치면 `server_room`, `office` 열로 좁힙니다.
+/// 한 행이라도 실패하면 아무것도 반영하지 않습니다.
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/device/import",
+    tag = "Bulk Import/Export",
+    params(BulkImportQuery),
+    request_body(content = BulkImportForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "가져오기 성공 (dry-run이면 검증 통과)", body = BulkImportResponse),
+        (status = 400, description = "파일 또는 헤더 오류"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 413, description = "파일 크기 초과"),
+        (status = 422, description = "실패한 행이 있어 반영하지 않음", body = BulkImportResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn import_devices(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Device>,
+    Query(query): Query<BulkImportQuery>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse, Errors> {
+    // 파일 하나가 여러 사무실에 걸치므로 사무실 범위가 있는 사용자는 쓸 수 없다
+    auth.ensure_all_offices()?;
+
+    let content = read_import_upload(multipart).await?;
+    let report = service_import_devices(
+        &state.conn,
+        auth.tenant_id(),
+        &content,
+        query.dry_run.unwrap_or(false),
+        auth.user_id(),
+    )
+    .await?;
+    Ok(import_response(report))
+}
+
+/// 장비 목록을 CSV로 내보냅니다 (가져오기와 같은 열 형식).
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/device/export",
+    tag = "Bulk Import/Export",
+    params(DeviceExportQuery),
+    responses(
+        (status = 200, description = "장비 CSV", body = String, content_type = "text/csv"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn export_devices(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Device>,
+    Query(query): Query<DeviceExportQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    // 파일 하나가 여러 사무실에 걸치므로 사무실 범위가 있는 사용자는 쓸 수 없다
+    auth.ensure_all_offices()?;
+
+    let body = service_export_devices(
+        &state.conn,
+        auth.tenant_id(),
+        DeviceExportFilter {
+            office_id: query.office_id,
+            server_room_id: query.server_room_id,
+            rack_id: query.rack_id,
+        },
+    )
+    .await?;
+    Ok(csv_attachment("devices", body))
+}
+
+/// 랙 CSV를 가져옵니다.
+///
+/// `id` 또는 같은 서버실의 같은 `name`이 있으면 수정하고, 아니면 새로 만듭니다.
+/// 서버실은 `server_room` 이름으로 찾으며 이름이 겹치면 `office` 열로 좁힙니다.
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/racks/import",
+    tag = "Bulk Import/Export",
+    params(BulkImportQuery),
+    request_body(content = BulkImportForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "가져오기 성공 (dry-run이면 검증 통과)", body = BulkImportResponse),
+        (status = 400, description = "파일 또는 헤더 오류"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 413, description = "파일 크기 초과"),
+        (status = 422, description = "실패한 행이 있어 반영하지 않음", body = BulkImportResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn import_racks(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Rack>,
+    Query(query): Query<BulkImportQuery>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse, Errors> {
+    // 파일 하나가 여러 사무실에 걸치므로 사무실 범위가 있는 사용자는 쓸 수 없다
+    auth.ensure_all_offices()?;
+
+    let content = read_import_upload(multipart).await?;
+    let report = service_import_racks(
+        &state.conn,
+        auth.tenant_id(),
+        &content,
+        query.dry_run.unwrap_or(false),
+        auth.user_id(),
+    )
+    .await?;
+    Ok(import_response(report))
+}
+
+/// 랙 목록을 CSV로 내보냅니다 (가져오기와 같은 열 형식).
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/racks/export",
+    tag = "Bulk Import/Export",
+    params(RackExportQuery),
+    responses(
+        (status = 200, description = "랙 CSV", body = String, content_type = "text/csv"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn export_racks(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Rack>,
+    Query(query): Query<RackExportQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    // 파일 하나가 여러 사무실에 걸치므로 사무실 범위가 있는 사용자는 쓸 수 없다
+    auth.ensure_all_offices()?;
+
+    let body = service_export_racks(
+        &state.conn,
+        auth.tenant_id(),
+        RackExportFilter {
+            office_id: query.office_id,
+            server_room_id: query.server_room_id,
+        },
+    )
+    .await?;
+    Ok(csv_attachment("racks", body))
+}
+
+/// IP 주소 CSV를 가져옵니다.
+///
+/// 주소는 `ip_range` 이름의 대역(생략하면 주소를 포함하는 가장 구체적인 대역)에 등록되며,
+/// 이미 있는 주소는 파일에 있는 열만 갱신합니다.
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/ip-address/import",
+    tag = "Bulk Import/Export",
+    params(BulkImportQuery),
+    request_body(content = BulkImportForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "가져오기 성공 (dry-run이면 검증 통과)", body = BulkImportResponse),
+        (status = 400, description = "파일 또는 헤더 오류"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 413, description = "파일 크기 초과"),
+        (status = 422, description = "실패한 행이 있어 반영하지 않음", body = BulkImportResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn import_ip_addresses(
+    State(state): State<AppState>,
+    auth: Authorized<resource::IpAddress>,
+    Query(query): Query<BulkImportQuery>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse, Errors> {
+    // 파일 하나가 여러 사무실의 대역에 걸치므로 사무실 범위가 있는 사용자는 쓸 수 없다
+    auth.ensure_all_offices()?;
+
+    let content = read_import_upload(multipart).await?;
+    let report = service_import_ip_addresses(
+        &state.conn,
+        auth.tenant_id(),
+        &content,
+        query.dry_run.unwrap_or(false),
+        auth.user_id(),
+    )
+    .await?;
+    Ok(import_response(report))
+}
+
+/// IP 주소 목록을 CSV로 내보냅니다 (가져오기와 같은 열 형식).
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/ip-address/export",
+    tag = "Bulk Import/Export",
+    params(IpAddressExportQuery),
+    responses(
+        (status = 200, description = "IP 주소 CSV", body = String, content_type = "text/csv"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn export_ip_addresses(
+    State(state): State<AppState>,
+    auth: Authorized<resource::IpAddress>,
+    Query(query): Query<IpAddressExportQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    // 파일 하나가 여러 사무실의 대역에 걸치므로 사무실 범위가 있는 사용자는 쓸 수 없다
+    auth.ensure_all_offices()?;
+
+    let body = service_export_ip_addresses(
+        &state.conn,
+        auth.tenant_id(),
+        IpAddressExportFilter {
+            ip_range_id: query.ip_range_id,
+            status: query.status,
+        },
+    );
+    Ok(csv_attachment("ip-addresses", body))
+}
+
+/// 담당자 CSV를 가져옵니다.
+///
+/// `id` 또는 `email`이 기존 담당자와 같으면 수정하고, 아니면 새로 만듭니다.
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/contact/import",
+    tag = "Bulk Import/Export",
+    params(BulkImportQuery),
+    request_body(content = BulkImportForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "가져오기 성공 (dry-run이면 검증 통과)", body = BulkImportResponse),
+        (status = 400, description = "파일 또는 헤더 오류"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 413, description = "파일 크기 초과"),
+        (status = 422, description = "실패한 행이 있어 반영하지 않음", body = BulkImportResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn import_contacts(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Contact>,
+    Query(query): Query<BulkImportQuery>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse, Errors> {
+    let content = read_import_upload(multipart).await?;
+    let report = service_import_contacts(
+        &state.conn,
+        auth.tenant_id(),
+        &content,
+        query.dry_run.unwrap_or(false),
+        auth.user_id(),
+    )
+    .await?;
+    Ok(import_response(report))
+}
+
+/// 담당자 목록을 CSV로 내보냅니다 (가져오기와 같은 열 형식).
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/contact/export",
+    tag = "Bulk Import/Export",
+    responses(
+        (status = 200, description = "담당자 CSV", body = String, content_type = "text/csv"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn export_contacts(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Contact>,
+) -> Result<impl IntoResponse, Errors> {
+    let body = service_export_contacts(&state.conn, auth.tenant_id());
+    Ok(csv_attachment("contacts", body))
+}
@@ -0,0 +1,25 @@
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::middleware::auth::access_jwt_auth;
+
+use super::handlers::{
+    export_contacts, export_devices, export_ip_addresses, export_racks, import_contacts,
+    import_devices, import_ip_addresses, import_racks,
+};
+
+/// CSV 일괄 가져오기/내보내기 (`/v0/ipam` 아래에 중첩)
+pub fn bulk_io_routes() -> Router<crate::AppState> {
+    Router::new()
+        .route("/device/import", post(import_devices))
+        .route("/device/export", get(export_devices))
+        .route("/racks/import", post(import_racks))
+        .route("/racks/export", get(export_racks))
+        .route("/ip-address/import", post(import_ip_addresses))
+        .route("/ip-address/export", get(export_ip_addresses))
+        .route("/contact/import", post(import_contacts))
+        .route("/contact/export", get(export_contacts))
+        .route_layer(middleware::from_fn(access_jwt_auth))
+}
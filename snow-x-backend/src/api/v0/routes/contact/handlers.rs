@@ -1,13 +1,12 @@
 use crate::AppState;
-use crate::dto::auth::internal::access_token::AccessTokenClaims;
 use crate::dto::contact::request::{CreateContactRequest, UpdateContactRequest};
 use crate::dto::contact::response::{ContactInfoResponse, ContactListResponse};
+use crate::middleware::permission::{Authorized, resource};
 use crate::service::contact::{
     service_create_contact, service_delete_contact, service_get_contact_by_id,
     service_get_contacts, service_update_contact,
 };
 use axum::{
-    Extension,
     extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
@@ -57,16 +56,17 @@ pub struct ContactQueryParams {
         (status = 201, description = "연락처 생성 성공", body = ContactInfoResponse),
         (status = 400, description = "잘못된 요청"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn create_contact(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Contact>,
     Json(request): Json<CreateContactRequest>,
 ) -> Result<(StatusCode, Json<ContactInfoResponse>), (StatusCode, Json<serde_json::Value>)> {
-    match service_create_contact(&state.conn, request, claims.sub).await {
+    match service_create_contact(&state.conn, auth.tenant_id(), request, auth.user_id()).await {
         Ok(contact) => Ok((StatusCode::CREATED, Json(contact))),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -93,13 +93,14 @@ pub async fn create_contact(
     responses(
         (status = 200, description = "연락처 목록 조회 성공", body = ContactListResponse),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_contacts(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Contact>,
     Query(params): Query<ContactQueryParams>,
 ) -> Result<Json<ContactListResponse>, (StatusCode, Json<serde_json::Value>)> {
     let page = params.page.unwrap_or(1);
@@ -107,6 +108,7 @@ pub async fn get_contacts(
 
     match service_get_contacts(
         &state.conn,
+        auth.tenant_id(),
         page,
         limit,
         params.search,
@@ -138,13 +140,14 @@ pub async fn get_contacts(
         (status = 200, description = "연락처 조회 성공", body = ContactInfoResponse),
         (status = 404, description = "연락처를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_contact_by_id(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::Contact>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ContactInfoResponse>, (StatusCode, Json<serde_json::Value>)> {
     match service_get_contact_by_id(&state.conn, id).await {
@@ -178,17 +181,18 @@ pub async fn get_contact_by_id(
         (status = 200, description = "연락처 수정 성공", body = ContactInfoResponse),
         (status = 404, description = "연락처를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn update_contact(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Contact>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateContactRequest>,
 ) -> Result<Json<ContactInfoResponse>, (StatusCode, Json<serde_json::Value>)> {
-    match service_update_contact(&state.conn, id, request).await {
+    match service_update_contact(&state.conn, id, request, auth.user_id()).await {
         Ok(contact) => Ok(Json(contact)),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -212,16 +216,17 @@ pub async fn update_contact(
         (status = 204, description = "연락처 삭제 성공"),
         (status = 404, description = "연락처를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn delete_contact(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Contact>,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, (StatusCode, Json<serde_json::Value>)> {
-    match service_delete_contact(&state.conn, id).await {
+    match service_delete_contact(&state.conn, id, auth.user_id()).await {
         Ok(_) => Ok(StatusCode::NO_CONTENT),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -1,5 +1,9 @@
 use crate::AppState;
+use crate::middleware::permission::{Authorized, action, resource};
 use crate::service::custodian_service;
+use crate::service::custodian_validator::{PolicyValidationIssue, validate_policy_yaml};
+use crate::service::error::errors::Errors;
+use crate::service::tenant::tenant_of_custodian_policy;
 use axum::{
     Json,
     extract::{Path, State},
@@ -30,15 +34,40 @@ pub struct ExecutePolicyRequest {
     pub dry_run: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct UpdatePolicyScheduleRequest {
+    /// Standard 5-field cron expression (minute hour day-of-month month day-of-week) or a
+    /// nickname such as `@daily`
+    pub cron: String,
+    /// IANA timezone the cron expression is evaluated in
+    #[serde(default = "default_schedule_timezone")]
+    pub timezone: String,
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default = "default_schedule_enabled")]
+    pub enabled: bool,
+}
+
+fn default_schedule_timezone() -> String {
+    "UTC".to_string()
+}
+
+fn default_schedule_enabled() -> bool {
+    true
+}
+
 #[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct ValidateYamlRequest {
     pub content: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+#[derive(Debug, Serialize, utoipa::ToSchema)]
 pub struct ValidateYamlResponse {
     pub valid: bool,
+    /// First error, formatted with its location
     pub error: Option<String>,
+    pub errors: Vec<PolicyValidationIssue>,
+    pub warnings: Vec<PolicyValidationIssue>,
 }
 
 /// Get all custodian policies
@@ -48,14 +77,18 @@ pub struct ValidateYamlResponse {
     tag = "custodian",
     responses(
         (status = 200, description = "List of custodian policies", body = Vec<crate::entity::custodian_policies::Model>),
+        (status = 403, description = "Insufficient role"),
         (status = 500, description = "Internal server error")
     ),
     security(
         ("bearer_auth" = [])
     )
 )]
-pub async fn get_policies(State(state): State<AppState>) -> Result<impl IntoResponse, StatusCode> {
-    let policies = custodian_service::get_all_policies(&state.conn)
+pub async fn get_policies(
+    State(state): State<AppState>,
+    auth: Authorized<resource::Custodian>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let policies = custodian_service::get_all_policies(&state.conn, auth.tenant_id())
         .await
         .map_err(|e| {
             eprintln!("Failed to get policies: {}", e);
@@ -76,6 +109,7 @@ pub async fn get_policies(State(state): State<AppState>) -> Result<impl IntoResp
     responses(
         (status = 200, description = "Custodian policy", body = crate::entity::custodian_policies::Model),
         (status = 404, description = "Policy not found"),
+        (status = 403, description = "Insufficient role"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -84,6 +118,7 @@ pub async fn get_policies(State(state): State<AppState>) -> Result<impl IntoResp
 )]
 pub async fn get_policy(
     State(state): State<AppState>,
+    _auth: Authorized<resource::Custodian>,
     Path(id): Path<Uuid>,
 ) -> Result<impl IntoResponse, StatusCode> {
     let policy = custodian_service::get_policy_by_id(&state.conn, id)
@@ -105,7 +140,8 @@ pub async fn get_policy(
     request_body = CreatePolicyRequest,
     responses(
         (status = 201, description = "Policy created", body = crate::entity::custodian_policies::Model),
-        (status = 400, description = "Bad request"),
+        (status = 400, description = "Policy content failed validation"),
+        (status = 403, description = "Insufficient role"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -114,20 +150,17 @@ pub async fn get_policy(
 )]
 pub async fn create_policy(
     State(state): State<AppState>,
+    auth: Authorized<resource::Custodian>,
     Json(request): Json<CreatePolicyRequest>,
-) -> Result<impl IntoResponse, StatusCode> {
+) -> Result<impl IntoResponse, Errors> {
     let service_request = custodian_service::CreatePolicyRequest {
         name: request.name,
         description: request.description,
         content: request.content,
     };
 
-    let policy = custodian_service::create_policy(&state.conn, service_request)
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to create policy: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
+    let policy =
+        custodian_service::create_policy(&state.conn, auth.tenant_id(), service_request).await?;
 
     Ok((StatusCode::CREATED, Json(policy)))
 }
@@ -144,6 +177,8 @@ pub async fn create_policy(
     responses(
         (status = 200, description = "Policy updated", body = crate::entity::custodian_policies::Model),
         (status = 404, description = "Policy not found"),
+        (status = 400, description = "Policy content failed validation"),
+        (status = 403, description = "Insufficient role"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -152,24 +187,17 @@ pub async fn create_policy(
 )]
 pub async fn update_policy(
     State(state): State<AppState>,
+    _auth: Authorized<resource::Custodian>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdatePolicyRequest>,
-) -> Result<impl IntoResponse, StatusCode> {
+) -> Result<impl IntoResponse, Errors> {
     let service_request = custodian_service::UpdatePolicyRequest {
         name: request.name,
         description: request.description,
         content: request.content,
     };
 
-    let policy = custodian_service::update_policy(&state.conn, id, service_request)
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to update policy: {}", e);
-            match e {
-                sea_orm::DbErr::RecordNotFound(_) => StatusCode::NOT_FOUND,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            }
-        })?;
+    let policy = custodian_service::update_policy(&state.conn, id, service_request).await?;
 
     Ok(Json(policy))
 }
@@ -185,6 +213,7 @@ pub async fn update_policy(
     responses(
         (status = 204, description = "Policy deleted"),
         (status = 404, description = "Policy not found"),
+        (status = 403, description = "Insufficient role"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -193,6 +222,7 @@ pub async fn update_policy(
 )]
 pub async fn delete_policy(
     State(state): State<AppState>,
+    _auth: Authorized<resource::Custodian>,
     Path(id): Path<Uuid>,
 ) -> Result<impl IntoResponse, StatusCode> {
     custodian_service::delete_policy(&state.conn, id)
@@ -217,6 +247,8 @@ pub async fn delete_policy(
     responses(
         (status = 200, description = "Execution started", body = crate::entity::custodian_executions::Model),
         (status = 404, description = "Policy not found"),
+        (status = 409, description = "A previous execution of the policy is still pending or running"),
+        (status = 403, description = "Insufficient role"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -225,86 +257,93 @@ pub async fn delete_policy(
 )]
 pub async fn execute_policy(
     State(state): State<AppState>,
+    auth: Authorized<resource::Custodian>,
     Json(request): Json<ExecutePolicyRequest>,
-) -> Result<impl IntoResponse, StatusCode> {
-    // Verify policy exists
-    let policy = custodian_service::get_policy_by_id(&state.conn, request.policy_id)
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to get policy: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?
-        .ok_or(StatusCode::NOT_FOUND)?;
+) -> Result<impl IntoResponse, Errors> {
+    auth.ensure_owned(tenant_of_custodian_policy(&state.conn, request.policy_id).await?)?;
 
-    // Create execution record
-    let execution = custodian_service::create_execution(&state.conn, policy.id, request.dry_run)
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to create execution: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
+    let (policy, execution) = custodian_service::create_execution(
+        &state.conn,
+        request.policy_id,
+        request.dry_run,
+        custodian_service::TRIGGER_MANUAL,
+    )
+    .await?;
 
     // Send execution request to Task API (Celery)
-    let task_api_url =
-        std::env::var("TASK_API_URL").unwrap_or_else(|_| "http://localhost:7000".to_string());
-    let task_request = serde_json::json!({
-        "policy_id": policy.id.to_string(),
-        "policy_content": policy.content,
-        "execution_id": execution.id.to_string(),
-        "dry_run": request.dry_run
-    });
-
-    let task_response = state
-        .http_client
-        .post(format!("{}/tasks/custodian/execute", task_api_url))
-        .json(&task_request)
-        .send()
-        .await;
-
-    match task_response {
-        Ok(response) => {
-            if !response.status().is_success() {
-                eprintln!("Task API returned error: {}", response.status());
-                // Continue anyway, execution record is created
-            } else {
-                match response.json::<serde_json::Value>().await {
-                    Ok(task_data) => {
-                        println!("Task queued successfully: {:?}", task_data);
-                        // Update execution with task_id
-                        if let Some(task_id) = task_data.get("task_id").and_then(|v| v.as_str()) {
-                            let _ = custodian_service::update_execution_task_id(
-                                &state.conn,
-                                execution.id,
-                                task_id.to_string(),
-                            )
-                            .await;
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("Failed to parse task response: {}", e);
-                    }
-                }
-            }
-        }
-        Err(e) => {
-            eprintln!("Failed to call Task API: {}", e);
-            // Continue anyway, execution record is created
-            // The execution will remain in "pending" status
-        }
-    }
-
-    // Reload execution to get updated task_id
-    let execution = custodian_service::get_execution_by_id(&state.conn, execution.id)
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to reload execution: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?
-        .ok_or(StatusCode::NOT_FOUND)?;
+    let execution =
+        custodian_service::dispatch_execution(&state.conn, &state.http_client, &policy, execution)
+            .await?;
 
     Ok(Json(execution))
 }
 
+/// Set the run schedule of a custodian policy
+#[utoipa::path(
+    put,
+    path = "/v0/custodian/policies/{id}/schedule",
+    tag = "custodian",
+    params(
+        ("id" = Uuid, Path, description = "Policy ID")
+    ),
+    request_body = UpdatePolicyScheduleRequest,
+    responses(
+        (status = 200, description = "Schedule updated", body = crate::entity::custodian_policies::Model),
+        (status = 400, description = "Invalid cron expression or timezone"),
+        (status = 404, description = "Policy not found"),
+        (status = 403, description = "Insufficient role"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_policy_schedule(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::Custodian>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdatePolicyScheduleRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let service_request = custodian_service::PolicyScheduleRequest {
+        cron: request.cron,
+        timezone: request.timezone,
+        dry_run: request.dry_run,
+        enabled: request.enabled,
+    };
+
+    let policy = custodian_service::set_policy_schedule(&state.conn, id, service_request).await?;
+
+    Ok(Json(policy))
+}
+
+/// Remove the run schedule of a custodian policy
+#[utoipa::path(
+    delete,
+    path = "/v0/custodian/policies/{id}/schedule",
+    tag = "custodian",
+    params(
+        ("id" = Uuid, Path, description = "Policy ID")
+    ),
+    responses(
+        (status = 200, description = "Schedule removed", body = crate::entity::custodian_policies::Model),
+        (status = 404, description = "Policy not found"),
+        (status = 403, description = "Insufficient role"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_policy_schedule(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::Custodian, action::Write>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let policy = custodian_service::clear_policy_schedule(&state.conn, id).await?;
+
+    Ok(Json(policy))
+}
+
 /// Get execution result
 #[utoipa::path(
     get,
@@ -316,6 +355,7 @@ pub async fn execute_policy(
     responses(
         (status = 200, description = "Execution result", body = crate::entity::custodian_executions::Model),
         (status = 404, description = "Execution not found"),
+        (status = 403, description = "Insufficient role"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -324,6 +364,7 @@ pub async fn execute_policy(
 )]
 pub async fn get_execution_result(
     State(state): State<AppState>,
+    _auth: Authorized<resource::Custodian>,
     Path(execution_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, StatusCode> {
     let execution = custodian_service::get_execution_by_id(&state.conn, execution_id)
@@ -337,6 +378,36 @@ pub async fn get_execution_result(
     Ok(Json(execution))
 }
 
+/// Cancel a pending or running execution
+#[utoipa::path(
+    post,
+    path = "/v0/custodian/executions/{execution_id}/cancel",
+    tag = "custodian",
+    params(
+        ("execution_id" = Uuid, Path, description = "Execution ID")
+    ),
+    responses(
+        (status = 200, description = "Execution cancelled", body = crate::entity::custodian_executions::Model),
+        (status = 400, description = "Execution already finished"),
+        (status = 404, description = "Execution not found"),
+        (status = 403, description = "Insufficient role"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn cancel_execution(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::Custodian>,
+    Path(execution_id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let execution =
+        custodian_service::cancel_execution(&state.conn, &state.http_client, execution_id).await?;
+
+    Ok(Json(execution))
+}
+
 /// Get all executions for a policy
 #[utoipa::path(
     get,
@@ -348,6 +419,7 @@ pub async fn get_execution_result(
     responses(
         (status = 200, description = "List of executions", body = Vec<crate::entity::custodian_executions::Model>),
         (status = 404, description = "Policy not found"),
+        (status = 403, description = "Insufficient role"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -356,6 +428,7 @@ pub async fn get_execution_result(
 )]
 pub async fn get_policy_executions(
     State(state): State<AppState>,
+    _auth: Authorized<resource::Custodian>,
     Path(id): Path<Uuid>,
 ) -> Result<impl IntoResponse, StatusCode> {
     // Verify policy exists
@@ -377,7 +450,10 @@ pub async fn get_policy_executions(
     Ok(Json(executions))
 }
 
-/// Validate YAML syntax
+/// Validate custodian policy YAML
+///
+/// Checks the Cloud Custodian policy structure (`policies` list, `name`, `resource`,
+/// `filters`, `actions`, `mode`) and reports errors and warnings with their location.
 #[utoipa::path(
     post,
     path = "/v0/custodian/validate",
@@ -385,6 +461,7 @@ pub async fn get_policy_executions(
     request_body = ValidateYamlRequest,
     responses(
         (status = 200, description = "Validation result", body = ValidateYamlResponse),
+        (status = 403, description = "Insufficient role"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -392,17 +469,15 @@ pub async fn get_policy_executions(
     )
 )]
 pub async fn validate_yaml(
+    _auth: Authorized<resource::Custodian, action::Read>,
     Json(request): Json<ValidateYamlRequest>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    // Basic YAML validation
-    match serde_yaml::from_str::<serde_yaml::Value>(&request.content) {
-        Ok(_) => Ok(Json(ValidateYamlResponse {
-            valid: true,
-            error: None,
-        })),
-        Err(e) => Ok(Json(ValidateYamlResponse {
-            valid: false,
-            error: Some(e.to_string()),
-        })),
-    }
+    let validation = validate_policy_yaml(&request.content);
+
+    Ok(Json(ValidateYamlResponse {
+        valid: validation.is_valid(),
+        error: validation.errors.first().map(ToString::to_string),
+        errors: validation.errors,
+        warnings: validation.warnings,
+    }))
 }
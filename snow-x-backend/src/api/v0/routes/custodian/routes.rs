@@ -1,11 +1,12 @@
 use crate::api::v0::routes::custodian::handlers::{
-    create_policy, delete_policy, execute_policy, get_execution_result, get_policies, get_policy,
-    get_policy_executions, update_policy, validate_yaml,
+    cancel_execution, create_policy, delete_policy, delete_policy_schedule, execute_policy,
+    get_execution_result, get_policies, get_policy, get_policy_executions, update_policy,
+    update_policy_schedule, validate_yaml,
 };
 use crate::middleware::auth::access_jwt_auth;
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 
 pub fn create_custodian_routes() -> Router<crate::AppState> {
@@ -23,6 +24,12 @@ pub fn create_custodian_routes() -> Router<crate::AppState> {
                 .delete(delete_policy)
                 .route_layer(axum::middleware::from_fn(access_jwt_auth)),
         )
+        .route(
+            "/policies/{id}/schedule",
+            put(update_policy_schedule)
+                .delete(delete_policy_schedule)
+                .route_layer(axum::middleware::from_fn(access_jwt_auth)),
+        )
         .route(
             "/policies/{id}/executions",
             get(get_policy_executions).route_layer(axum::middleware::from_fn(access_jwt_auth)),
@@ -35,6 +42,10 @@ pub fn create_custodian_routes() -> Router<crate::AppState> {
             "/executions/{execution_id}",
             get(get_execution_result).route_layer(axum::middleware::from_fn(access_jwt_auth)),
         )
+        .route(
+            "/executions/{execution_id}/cancel",
+            post(cancel_execution).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+        )
         .route(
             "/validate",
             post(validate_yaml).route_layer(axum::middleware::from_fn(access_jwt_auth)),
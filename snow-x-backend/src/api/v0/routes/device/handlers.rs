@@ -1,20 +1,22 @@
 use crate::AppState;
 use crate::api::v0::routes::ip_address::handlers::IpAddressResponse;
-use crate::dto::auth::internal::access_token::AccessTokenClaims;
 use crate::dto::contact::response::{ResourceMappingListResponse, ResourceMappingResponse};
 use crate::dto::device::request::assign_contact::AssignContactRequest;
 use crate::dto::device::request::create_device::CreateDeviceRequest;
 use crate::dto::device::request::update_device::UpdateDeviceRequest;
 use crate::dto::device::response::device_info::DeviceInfoResponse;
 use crate::dto::device::response::device_list::DeviceListResponse;
+use crate::middleware::permission::{Authorized, action, resource};
+use crate::service::auth::permission::PermissionResource;
 use crate::service::device::{
     service_assign_contact_to_device, service_assign_ip_address, service_create_device,
     service_delete_device, service_get_device_by_id, service_get_device_contacts,
     service_get_device_ip_addresses, service_get_devices, service_unassign_contact_from_device,
     service_unassign_ip_address, service_update_device,
 };
+use crate::service::error::errors::Errors;
+use crate::service::tenant::tenant_of_contact;
 use axum::{
-    Extension,
     extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
@@ -74,26 +76,24 @@ pub struct DeviceQueryParams {
     request_body = CreateDeviceRequest,
     responses(
         (status = 201, description = "장비 생성 성공", body = DeviceInfoResponse),
-        (status = 400, description = "잘못된 요청"),
+        (status = 400, description = "잘못된 요청 (랙 높이를 벗어난 위치 등)"),
+        (status = 409, description = "다른 장비와 U 위치가 겹침"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn create_device(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Device>,
     Json(request): Json<CreateDeviceRequest>,
-) -> Result<(StatusCode, Json<DeviceInfoResponse>), (StatusCode, Json<serde_json::Value>)> {
-    match service_create_device(&state.conn, request, claims.sub).await {
-        Ok(device) => Ok((StatusCode::CREATED, Json(device))),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({
-                "error": format!("{:?}", e)
-            })),
-        )),
-    }
+) -> Result<(StatusCode, Json<DeviceInfoResponse>), Errors> {
+    auth.ensure_placement(&state.conn, request.rack_id).await?;
+
+    let device =
+        service_create_device(&state.conn, auth.tenant_id(), request, auth.user_id()).await?;
+    Ok((StatusCode::CREATED, Json(device)))
 }
 
 #[utoipa::path(
@@ -112,13 +112,14 @@ pub async fn create_device(
     responses(
         (status = 200, description = "장비 목록 조회 성공", body = DeviceListResponse),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_devices(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Device>,
     Query(params): Query<DeviceQueryParams>,
 ) -> Result<Json<DeviceListResponse>, (StatusCode, Json<serde_json::Value>)> {
     let page = params.page.unwrap_or(1);
@@ -132,6 +133,7 @@ pub async fn get_devices(
         params.device_type,
         params.status,
         params.rack_id,
+        &auth.scope,
     )
     .await
     {
@@ -158,13 +160,14 @@ pub async fn get_devices(
         (status = 200, description = "장비 조회 성공", body = DeviceInfoResponse),
         (status = 404, description = "장비를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_device_by_id(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::Device>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<DeviceInfoResponse>, (StatusCode, Json<serde_json::Value>)> {
     match service_get_device_by_id(&state.conn, id).await {
@@ -196,27 +199,28 @@ pub async fn get_device_by_id(
     request_body = UpdateDeviceRequest,
     responses(
         (status = 200, description = "장비 수정 성공", body = DeviceInfoResponse),
+        (status = 400, description = "잘못된 요청 (랙 높이를 벗어난 위치 등)"),
         (status = 404, description = "장비를 찾을 수 없음"),
+        (status = 409, description = "다른 장비와 U 위치가 겹침"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn update_device(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Device>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateDeviceRequest>,
-) -> Result<Json<DeviceInfoResponse>, (StatusCode, Json<serde_json::Value>)> {
-    match service_update_device(&state.conn, id, request).await {
-        Ok(device) => Ok(Json(device)),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({
-                "error": format!("{:?}", e)
-            })),
-        )),
+) -> Result<Json<DeviceInfoResponse>, Errors> {
+    // 다른 랙으로 옮기는 경우 옮길 랙도 범위 안이어야 한다
+    if let Some(rack_id) = request.rack_id {
+        auth.ensure_rack(&state.conn, rack_id).await?;
     }
+
+    let device = service_update_device(&state.conn, id, request, auth.user_id()).await?;
+    Ok(Json(device))
 }
 
 #[utoipa::path(
@@ -232,16 +236,17 @@ pub async fn update_device(
         (status = 204, description = "장비 삭제 성공"),
         (status = 404, description = "장비를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn delete_device(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Device>,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, (StatusCode, Json<serde_json::Value>)> {
-    match service_delete_device(&state.conn, id).await {
+    match service_delete_device(&state.conn, id, auth.user_id()).await {
         Ok(_) => Ok(StatusCode::NO_CONTENT),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -252,6 +257,28 @@ pub async fn delete_device(
     }
 }
 
+/// 요청 본문이 가리키는 리소스가 이 테넌트에 있는지 확인. 다른 테넌트의 리소스는 찾을 수 없다고 답한다
+fn ensure_body_resource(
+    check: Result<(), Errors>,
+    not_found_message: &str,
+) -> Result<(), (StatusCode, Json<serde_json::Value>)> {
+    match check {
+        Ok(()) => Ok(()),
+        Err(Errors::NotFound(_)) => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": not_found_message })),
+        )),
+        Err(Errors::PermissionDenied(message)) => Err((
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({ "error": message })),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": format!("{:?}", e) })),
+        )),
+    }
+}
+
 #[derive(Deserialize, Serialize, ToSchema)]
 pub struct AssignIpRequest {
     pub ip_address_id: Uuid,
@@ -270,13 +297,14 @@ pub struct AssignIpRequest {
         (status = 200, description = "IP 주소 목록 조회 성공", body = Vec<IpAddressResponse>),
         (status = 404, description = "장비를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_device_ip_addresses(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::Device>,
     Path(device_id): Path<Uuid>,
 ) -> Result<Json<Vec<IpAddressResponse>>, (StatusCode, Json<serde_json::Value>)> {
     match service_get_device_ip_addresses(&state.conn, device_id).await {
@@ -305,16 +333,23 @@ pub async fn get_device_ip_addresses(
         (status = 400, description = "잘못된 요청 (이미 할당된 IP)"),
         (status = 404, description = "장비 또는 IP 주소를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn assign_ip_to_device(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Device>,
     Path(device_id): Path<Uuid>,
     Json(request): Json<AssignIpRequest>,
 ) -> Result<StatusCode, (StatusCode, Json<serde_json::Value>)> {
+    ensure_body_resource(
+        auth.ensure_ip_address(&state.conn, request.ip_address_id)
+            .await,
+        "IP 주소를 찾을 수 없습니다",
+    )?;
+
     match service_assign_ip_address(&state.conn, device_id, request.ip_address_id).await {
         Ok(_) => Ok(StatusCode::NO_CONTENT),
         Err(e) => {
@@ -352,13 +387,14 @@ pub async fn assign_ip_to_device(
         (status = 204, description = "IP 주소 할당 해제 성공"),
         (status = 404, description = "IP 할당을 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn unassign_ip_from_device(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::Device, action::Write>,
     Path((device_id, ip_address_id)): Path<(Uuid, Uuid)>,
 ) -> Result<StatusCode, (StatusCode, Json<serde_json::Value>)> {
     match service_unassign_ip_address(&state.conn, device_id, ip_address_id).await {
@@ -396,13 +432,14 @@ pub async fn unassign_ip_from_device(
     responses(
         (status = 200, description = "담당자 목록 조회 성공", body = ResourceMappingListResponse),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_device_contacts(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::Device>,
     Path(device_id): Path<Uuid>,
 ) -> Result<Json<ResourceMappingListResponse>, (StatusCode, Json<serde_json::Value>)> {
     match service_get_device_contacts(&state.conn, device_id).await {
@@ -431,16 +468,24 @@ pub async fn get_device_contacts(
         (status = 400, description = "잘못된 요청 (이미 연결된 담당자)"),
         (status = 404, description = "담당자 또는 장비를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn assign_contact_to_device(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Device>,
     Path(device_id): Path<Uuid>,
     Json(request): Json<AssignContactRequest>,
 ) -> Result<StatusCode, (StatusCode, Json<serde_json::Value>)> {
+    ensure_body_resource(
+        tenant_of_contact(&state.conn, request.contact_id)
+            .await
+            .and_then(|owner| auth.scope.require_owner(PermissionResource::Contact, owner)),
+        "담당자를 찾을 수 없습니다",
+    )?;
+
     match service_assign_contact_to_device(&state.conn, device_id, request.contact_id, request.role)
         .await
     {
@@ -487,13 +532,14 @@ pub async fn assign_contact_to_device(
         (status = 204, description = "담당자 연결 해제 성공"),
         (status = 404, description = "담당자 연결 정보를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn unassign_contact_from_device(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::Device, action::Write>,
     Path((device_id, contact_id)): Path<(Uuid, Uuid)>,
 ) -> Result<StatusCode, (StatusCode, Json<serde_json::Value>)> {
     match service_unassign_contact_from_device(&state.conn, device_id, contact_id).await {
@@ -1,13 +1,13 @@
 use crate::AppState;
-use crate::dto::auth::internal::access_token::AccessTokenClaims;
 use crate::dto::device_library::request::{CreateLibraryRequest, UpdateLibraryRequest};
 use crate::dto::device_library::response::{LibraryInfoResponse, LibraryListResponse};
+use crate::middleware::permission::{Authorized, resource};
 use crate::service::device_library::{
     service_create_library, service_delete_library, service_get_libraries,
     service_get_library_by_id, service_update_library,
 };
+use crate::service::tenant::tenant_of_device;
 use axum::{
-    Extension,
     extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
@@ -45,6 +45,28 @@ pub struct LibraryQueryParams {
     pub device_type: Option<String>,
 }
 
+/// 라이브러리에 연결할 장비가 이 테넌트에 있는지 확인
+async fn ensure_linked_device(
+    state: &AppState,
+    auth: &Authorized<resource::DeviceLibrary>,
+    device_id: Option<Uuid>,
+) -> Result<(), (StatusCode, Json<serde_json::Value>)> {
+    let Some(device_id) = device_id else {
+        return Ok(());
+    };
+    tenant_of_device(&state.conn, device_id)
+        .await
+        .and_then(|owner| auth.ensure_owned(owner))
+        .map_err(|e| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({
+                    "error": format!("{:?}", e)
+                })),
+            )
+        })
+}
+
 #[utoipa::path(
     post,
     path = "/v0/ipam/device-library",
@@ -56,16 +78,19 @@ pub struct LibraryQueryParams {
         (status = 201, description = "라이브러리 생성 성공", body = LibraryInfoResponse),
         (status = 400, description = "잘못된 요청"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn create_library(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::DeviceLibrary>,
     Json(request): Json<CreateLibraryRequest>,
 ) -> Result<(StatusCode, Json<LibraryInfoResponse>), (StatusCode, Json<serde_json::Value>)> {
-    match service_create_library(&state.conn, request, claims.sub).await {
+    ensure_linked_device(&state, &auth, request.device_id).await?;
+
+    match service_create_library(&state.conn, auth.tenant_id(), request, auth.user_id()).await {
         Ok(library) => Ok((StatusCode::CREATED, Json(library))),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -91,19 +116,20 @@ pub async fn create_library(
     responses(
         (status = 200, description = "라이브러리 목록 조회 성공", body = LibraryListResponse),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_libraries(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::DeviceLibrary>,
     Query(params): Query<LibraryQueryParams>,
 ) -> Result<Json<LibraryListResponse>, (StatusCode, Json<serde_json::Value>)> {
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(20);
 
-    match service_get_libraries(&state.conn, page, limit, params.search).await {
+    match service_get_libraries(&state.conn, auth.tenant_id(), page, limit, params.search).await {
         Ok(libraries) => Ok(Json(libraries)),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -127,13 +153,14 @@ pub async fn get_libraries(
         (status = 200, description = "라이브러리 조회 성공", body = LibraryInfoResponse),
         (status = 404, description = "라이브러리를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_library_by_id(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::DeviceLibrary>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<LibraryInfoResponse>, (StatusCode, Json<serde_json::Value>)> {
     match service_get_library_by_id(&state.conn, id).await {
@@ -167,17 +194,20 @@ pub async fn get_library_by_id(
         (status = 200, description = "라이브러리 수정 성공", body = LibraryInfoResponse),
         (status = 404, description = "라이브러리를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn update_library(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::DeviceLibrary>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateLibraryRequest>,
 ) -> Result<Json<LibraryInfoResponse>, (StatusCode, Json<serde_json::Value>)> {
-    match service_update_library(&state.conn, id, request).await {
+    ensure_linked_device(&state, &auth, request.device_id).await?;
+
+    match service_update_library(&state.conn, id, request, auth.user_id()).await {
         Ok(library) => Ok(Json(library)),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -201,16 +231,17 @@ pub async fn update_library(
         (status = 204, description = "라이브러리 삭제 성공"),
         (status = 404, description = "라이브러리를 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn delete_library(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::DeviceLibrary>,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, (StatusCode, Json<serde_json::Value>)> {
-    match service_delete_library(&state.conn, id).await {
+    match service_delete_library(&state.conn, id, auth.user_id()).await {
         Ok(_) => Ok(StatusCode::NO_CONTENT),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -0,0 +1,491 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    dto::external_api::request::{
+        CreateExternalApiConnectionRequest, TestExternalApiConnectionRequest,
+        UpdateExternalApiConnectionRequest,
+    },
+    dto::external_api::response::{
+        ExternalApiConnectionListResponse, ExternalApiConnectionResponse,
+        ExternalApiDataListResponse, ExternalApiSyncLogListResponse, ExternalApiSyncResponse,
+        SyncConflictListResponse, SyncConflictResponse, SyncFieldOverrideListResponse,
+        TestExternalApiConnectionResponse,
+    },
+    middleware::permission::{Authorized, resource},
+    service::error::errors::{Errors, ServiceResult},
+    service::external_api::conflict::{SyncConflictFilter, SyncFieldOverrideFilter},
+    service::external_api::{
+        service_accept_source, service_create_connection, service_delete_connection,
+        service_delete_field_override, service_get_connection, service_get_connections,
+        service_get_field_overrides, service_get_sync_conflicts, service_get_sync_logs,
+        service_get_synced_data, service_keep_local, service_sync_connection,
+        service_test_connection, service_update_connection,
+    },
+    service::tenant::tenant_of_external_api_connection,
+    state::AppState,
+};
+
+/// 연결이 요청한 테넌트의 것인지 확인
+async fn ensure_connection(
+    state: &AppState,
+    auth: &Authorized<resource::ExternalApi>,
+    id: i32,
+) -> ServiceResult<()> {
+    let owner = tenant_of_external_api_connection(&state.conn, id).await?;
+    auth.ensure_owned(owner)
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ListConnectionsQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    /// device, device_library, contact
+    pub target_type: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct SyncLogQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct SyncedDataQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    pub data_type: Option<String>,
+    /// active (기본값), archived
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct SyncConflictQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    /// pending (기본값), accepted_source, kept_local
+    pub status: Option<String>,
+    /// device, device_library, contact
+    pub resource_type: Option<String>,
+    pub resource_id: Option<Uuid>,
+    pub connection_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct SyncFieldOverrideQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    /// device, device_library, contact
+    pub resource_type: Option<String>,
+    pub resource_id: Option<Uuid>,
+    pub connection_id: Option<i32>,
+}
+
+/// 외부 API 연결 목록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/external-api/connections",
+    tag = "External API",
+    params(ListConnectionsQuery),
+    responses(
+        (status = 200, description = "연결 목록", body = ExternalApiConnectionListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_connections(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Query(query): Query<ListConnectionsQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_connections(
+        &state.conn,
+        auth.tenant_id(),
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+        query.target_type,
+        query.is_active,
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 외부 API 연결을 만듭니다.
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/external-api/connections",
+    tag = "External API",
+    request_body = CreateExternalApiConnectionRequest,
+    responses(
+        (status = 201, description = "연결 생성", body = ExternalApiConnectionResponse),
+        (status = 400, description = "잘못된 설정"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 409, description = "같은 이름의 연결이 있음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn create_connection(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Json(request): Json<CreateExternalApiConnectionRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_create_connection(&state.conn, auth.tenant_id(), request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 외부 API 연결을 조회합니다. 인증 비밀 값은 가려집니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/external-api/connections/{id}",
+    tag = "External API",
+    params(("id" = i32, Path, description = "연결 ID")),
+    responses(
+        (status = 200, description = "연결 정보", body = ExternalApiConnectionResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "연결 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_connection(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, Errors> {
+    ensure_connection(&state, &auth, id).await?;
+
+    let response = service_get_connection(&state.conn, id).await?;
+    Ok(Json(response))
+}
+
+/// 외부 API 연결을 수정합니다. 보낸 필드만 바뀝니다.
+#[utoipa::path(
+    put,
+    path = "/v0/ipam/external-api/connections/{id}",
+    tag = "External API",
+    params(("id" = i32, Path, description = "연결 ID")),
+    request_body = UpdateExternalApiConnectionRequest,
+    responses(
+        (status = 200, description = "연결 수정", body = ExternalApiConnectionResponse),
+        (status = 400, description = "잘못된 설정"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "연결 없음"),
+        (status = 409, description = "같은 이름의 연결이 있음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn update_connection(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Path(id): Path<i32>,
+    Json(request): Json<UpdateExternalApiConnectionRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    ensure_connection(&state, &auth, id).await?;
+
+    let response = service_update_connection(&state.conn, id, request).await?;
+    Ok(Json(response))
+}
+
+/// 외부 API 연결을 삭제합니다.
+///
+/// 동기화 로그와 받아 둔 원본 데이터도 함께 삭제됩니다. 동기화로 만든 장비/담당자/라이브러리는 남습니다.
+#[utoipa::path(
+    delete,
+    path = "/v0/ipam/external-api/connections/{id}",
+    tag = "External API",
+    params(("id" = i32, Path, description = "연결 ID")),
+    responses(
+        (status = 204, description = "삭제됨"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "연결 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn delete_connection(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, Errors> {
+    ensure_connection(&state, &auth, id).await?;
+
+    service_delete_connection(&state.conn, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 연결을 지금 동기화합니다.
+///
+/// 외부 API 호출이 실패해도 200으로 응답하며, 결과는 `log.status`(`success`/`error`)로 확인합니다.
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/external-api/connections/{id}/sync",
+    tag = "External API",
+    params(("id" = i32, Path, description = "연결 ID")),
+    responses(
+        (status = 200, description = "동기화 실행 결과", body = ExternalApiSyncResponse),
+        (status = 400, description = "비활성 연결"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "연결 없음"),
+        (status = 409, description = "이미 동기화 중")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn sync_connection(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, Errors> {
+    ensure_connection(&state, &auth, id).await?;
+
+    let response = service_sync_connection(
+        &state.conn,
+        &state.external_api_client,
+        id,
+        Some(auth.user_id()),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 연결의 동기화 실행 기록을 최근 순으로 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/external-api/connections/{id}/logs",
+    tag = "External API",
+    params(("id" = i32, Path, description = "연결 ID"), SyncLogQuery),
+    responses(
+        (status = 200, description = "동기화 로그", body = ExternalApiSyncLogListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "연결 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_sync_logs(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Path(id): Path<i32>,
+    Query(query): Query<SyncLogQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    ensure_connection(&state, &auth, id).await?;
+
+    let response = service_get_sync_logs(
+        &state.conn,
+        id,
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 연결에서 받아 둔 원본 데이터를 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/external-api/connections/{id}/data",
+    tag = "External API",
+    params(("id" = i32, Path, description = "연결 ID"), SyncedDataQuery),
+    responses(
+        (status = 200, description = "원본 데이터", body = ExternalApiDataListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "연결 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_synced_data(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Path(id): Path<i32>,
+    Query(query): Query<SyncedDataQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    ensure_connection(&state, &auth, id).await?;
+
+    let response = service_get_synced_data(
+        &state.conn,
+        id,
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+        query.data_type,
+        query.status,
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 저장하지 않고 외부 API에 연결해 봅니다.
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/external-api/test-connection",
+    tag = "External API",
+    request_body = TestExternalApiConnectionRequest,
+    responses(
+        (status = 200, description = "연결 확인 결과", body = TestExternalApiConnectionResponse),
+        (status = 400, description = "잘못된 설정"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn test_connection(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::ExternalApi>,
+    Json(request): Json<TestExternalApiConnectionRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_test_connection(&state.external_api_client, request).await?;
+    Ok(Json(response))
+}
+
+/// 동기화 충돌 검토 대기열을 조회합니다.
+///
+/// 사람이 수정한 필드(`manual_override`)를 원본이 다른 값으로 바꾸려 할 때 충돌이 생깁니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/external-api/conflicts",
+    tag = "External API",
+    params(SyncConflictQuery),
+    responses(
+        (status = 200, description = "충돌 목록", body = SyncConflictListResponse),
+        (status = 400, description = "잘못된 상태 값"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_sync_conflicts(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Query(query): Query<SyncConflictQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_sync_conflicts(
+        &state.conn,
+        auth.tenant_id(),
+        SyncConflictFilter {
+            status: query.status,
+            resource_type: query.resource_type,
+            resource_id: query.resource_id,
+            connection_id: query.connection_id,
+        },
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 원본 값을 적용합니다. 필드의 수동 수정 표시가 지워져 이후 동기화가 값을 관리합니다.
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/external-api/conflicts/{id}/accept-source",
+    tag = "External API",
+    params(("id" = Uuid, Path, description = "충돌 ID")),
+    responses(
+        (status = 200, description = "원본 값 적용", body = SyncConflictResponse),
+        (status = 400, description = "이미 처리된 충돌이거나 적용할 수 없는 값"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "충돌 또는 레코드 없음"),
+        (status = 409, description = "랙 공간 부족")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn accept_source(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_accept_source(&state.conn, auth.tenant_id(), id, auth.user_id()).await?;
+    Ok(Json(response))
+}
+
+/// 현재 값을 유지합니다. 원본이 같은 값을 다시 보내도 충돌로 올리지 않습니다.
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/external-api/conflicts/{id}/keep-local",
+    tag = "External API",
+    params(("id" = Uuid, Path, description = "충돌 ID")),
+    responses(
+        (status = 200, description = "현재 값 유지", body = SyncConflictResponse),
+        (status = 400, description = "이미 처리된 충돌"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "충돌 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn keep_local(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_keep_local(&state.conn, auth.tenant_id(), id, auth.user_id()).await?;
+    Ok(Json(response))
+}
+
+/// 수동 수정으로 표시되어 동기화가 덮어쓰지 않는 필드를 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/external-api/overrides",
+    tag = "External API",
+    params(SyncFieldOverrideQuery),
+    responses(
+        (status = 200, description = "수동 수정 필드 목록", body = SyncFieldOverrideListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_field_overrides(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Query(query): Query<SyncFieldOverrideQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_field_overrides(
+        &state.conn,
+        auth.tenant_id(),
+        SyncFieldOverrideFilter {
+            resource_type: query.resource_type,
+            resource_id: query.resource_id,
+            connection_id: query.connection_id,
+        },
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 수동 수정 표시를 지웁니다. 다음 동기화가 원본 값으로 덮어씁니다.
+#[utoipa::path(
+    delete,
+    path = "/v0/ipam/external-api/overrides/{id}",
+    tag = "External API",
+    params(("id" = Uuid, Path, description = "수동 수정 표시 ID")),
+    responses(
+        (status = 204, description = "삭제됨"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "표시 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn delete_field_override(
+    State(state): State<AppState>,
+    auth: Authorized<resource::ExternalApi>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    service_delete_field_override(&state.conn, auth.tenant_id(), id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,34 @@
+use axum::{
+    Router, middleware,
+    routing::{delete, get, post},
+};
+
+use crate::middleware::auth::access_jwt_auth;
+
+use super::handlers::{
+    accept_source, create_connection, delete_connection, delete_field_override, get_connection,
+    get_connections, get_field_overrides, get_sync_conflicts, get_sync_logs, get_synced_data,
+    keep_local, sync_connection, test_connection, update_connection,
+};
+
+/// 외부 API 연결과 동기화 (`/v0/ipam/external-api` 아래에 중첩)
+pub fn external_api_routes() -> Router<crate::AppState> {
+    Router::new()
+        .route("/connections", get(get_connections).post(create_connection))
+        .route(
+            "/connections/{id}",
+            get(get_connection)
+                .put(update_connection)
+                .delete(delete_connection),
+        )
+        .route("/connections/{id}/sync", post(sync_connection))
+        .route("/connections/{id}/logs", get(get_sync_logs))
+        .route("/connections/{id}/data", get(get_synced_data))
+        .route("/test-connection", post(test_connection))
+        .route("/conflicts", get(get_sync_conflicts))
+        .route("/conflicts/{id}/accept-source", post(accept_source))
+        .route("/conflicts/{id}/keep-local", post(keep_local))
+        .route("/overrides", get(get_field_overrides))
+        .route("/overrides/{id}", delete(delete_field_override))
+        .route_layer(middleware::from_fn(access_jwt_auth))
+}
@@ -1,11 +1,15 @@
-use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::entity::ip_addresses;
+use crate::middleware::permission::{Authorized, resource};
+use crate::service::dhcp_lease::import::{LeaseImportSummary, service_import_dhcp_lease_upload};
+use crate::service::dhcp_lease::parser::LeaseFormat;
+use crate::service::dhcp_lease::sweep::service_sweep_expired_leases;
 use crate::service::ip_address::{
     IpAddressListResult, service_create_bulk_ip_addresses, service_get_ip_addresses,
 };
 use crate::state::AppState;
 use axum::{
-    Extension, Json,
-    extract::{Query, State},
+    Json,
+    extract::{Multipart, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
@@ -22,12 +26,34 @@ pub struct IpAddressResponse {
     pub hostname: Option<String>,
     pub status: String,
     pub description: Option<String>,
+    pub lease_start: Option<String>,
+    pub lease_end: Option<String>,
     pub created_by: Uuid,
     pub created_at: String,
     pub updated_at: String,
     pub is_active: bool,
 }
 
+impl From<ip_addresses::Model> for IpAddressResponse {
+    fn from(addr: ip_addresses::Model) -> Self {
+        IpAddressResponse {
+            id: addr.id,
+            ip_range_id: addr.ip_range_id,
+            ip_address: addr.ip_address,
+            mac_address: addr.mac_address,
+            hostname: addr.hostname,
+            status: addr.status,
+            description: addr.description,
+            lease_start: addr.lease_start.map(|t| t.to_rfc3339()),
+            lease_end: addr.lease_end.map(|t| t.to_rfc3339()),
+            created_by: addr.created_by,
+            created_at: addr.created_at.to_rfc3339(),
+            updated_at: addr.updated_at.to_rfc3339(),
+            is_active: addr.is_active,
+        }
+    }
+}
+
 #[derive(Serialize, ToSchema)]
 pub struct IpAddressListResponse {
     pub ip_addresses: Vec<IpAddressResponse>,
@@ -54,6 +80,123 @@ pub struct GetIpAddressesQuery {
     pub limit: Option<u64>,
 }
 
+/// DHCP 임대 파일 업로드 폼 (OpenAPI 문서용)
+#[allow(dead_code)]
+#[derive(Deserialize, ToSchema)]
+pub struct DhcpLeaseImportForm {
+    /// `dhcpd.leases` 또는 Kea lease CSV 파일
+    #[schema(format = Binary)]
+    file: String,
+    /// `isc` 또는 `kea` (생략 시 자동 판별)
+    format: Option<String>,
+    /// 같은 CIDR이 여러 office에 있을 때 매칭할 office
+    tenant_id: Option<Uuid>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct DhcpLeaseImportResponse {
+    pub format: String,
+    pub total: usize,
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub errors: Vec<String>,
+}
+
+impl From<LeaseImportSummary> for DhcpLeaseImportResponse {
+    fn from(summary: LeaseImportSummary) -> Self {
+        DhcpLeaseImportResponse {
+            format: match summary.format {
+                LeaseFormat::Isc => "isc".to_string(),
+                LeaseFormat::KeaCsv => "kea".to_string(),
+            },
+            total: summary.total,
+            created: summary.created,
+            updated: summary.updated,
+            skipped: summary.skipped,
+            errors: summary.errors,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct DhcpLeaseSweepResponse {
+    pub expired: usize,
+    pub notifications: usize,
+}
+
+/// Import DHCP leases
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/ip-address/dhcp-leases",
+    tag = "IP Address",
+    request_body(content = DhcpLeaseImportForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Leases imported", body = DhcpLeaseImportResponse),
+        (status = 400, description = "File errors: file:not_found, file:read_error, or unsupported format"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 413, description = "File too large"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn import_dhcp_leases(
+    State(state): State<AppState>,
+    auth: Authorized<resource::IpAddress>,
+    multipart: Multipart,
+) -> impl IntoResponse {
+    // 임대 파일은 테넌트의 모든 대역에 반영된다
+    if let Err(err) = auth.ensure_all_offices() {
+        return Err(err.into_response());
+    }
+
+    match service_import_dhcp_lease_upload(
+        &state.conn,
+        &auth.tenant_id(),
+        multipart,
+        &auth.user_id(),
+    )
+    .await
+    {
+        Ok(summary) => Ok((StatusCode::OK, Json(DhcpLeaseImportResponse::from(summary)))),
+        Err(err) => Err(err.into_response()),
+    }
+}
+
+/// Expire elapsed DHCP leases
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/ip-address/dhcp-leases/sweep",
+    tag = "IP Address",
+    responses(
+        (status = 200, description = "Elapsed leases marked as expired", body = DhcpLeaseSweepResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn sweep_dhcp_leases(
+    State(state): State<AppState>,
+    auth: Authorized<resource::IpAddress>,
+) -> impl IntoResponse {
+    if let Err(err) = auth.ensure_all_offices() {
+        return Err(err.into_response());
+    }
+
+    match service_sweep_expired_leases(&state.conn, Some(&auth.tenant_id())).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(DhcpLeaseSweepResponse {
+                expired: result.expired,
+                notifications: result.notifications,
+            }),
+        )),
+        Err(err) => Err(err.into_response()),
+    }
+}
+
 /// Create bulk IP addresses
 #[utoipa::path(
     post,
@@ -64,15 +207,20 @@ pub struct GetIpAddressesQuery {
         (status = 200, description = "IP addresses created successfully", body = Vec<IpAddressResponse>),
         (status = 400, description = "Bad request"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 500, description = "Internal server error")
     ),
     security(("bearer" = []))
 )]
 pub async fn create_bulk_ip_addresses(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::IpAddress>,
     Json(request): Json<CreateBulkIpAddressesRequest>,
 ) -> impl IntoResponse {
+    if let Err(err) = auth.ensure_ip_range(&state.conn, request.ip_range_id).await {
+        return Err(err.into_response());
+    }
+
     match service_create_bulk_ip_addresses(
         &state.conn,
         &request.ip_range_id,
@@ -80,26 +228,14 @@ pub async fn create_bulk_ip_addresses(
         &request.end_ip,
         &request.status,
         request.description.as_deref(),
-        &claims.sub,
+        &auth.user_id(),
     )
     .await
     {
         Ok(ip_addresses) => {
             let responses: Vec<IpAddressResponse> = ip_addresses
                 .into_iter()
-                .map(|addr| IpAddressResponse {
-                    id: addr.id,
-                    ip_range_id: addr.ip_range_id,
-                    ip_address: addr.ip_address,
-                    mac_address: addr.mac_address,
-                    hostname: addr.hostname,
-                    status: addr.status,
-                    description: addr.description,
-                    created_by: addr.created_by,
-                    created_at: addr.created_at.to_rfc3339(),
-                    updated_at: addr.updated_at.to_rfc3339(),
-                    is_active: addr.is_active,
-                })
+                .map(IpAddressResponse::from)
                 .collect();
 
             Ok((StatusCode::OK, Json(responses)))
@@ -117,13 +253,14 @@ pub async fn create_bulk_ip_addresses(
     responses(
         (status = 200, description = "IP addresses retrieved successfully", body = IpAddressListResponse),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 500, description = "Internal server error")
     ),
     security(("bearer" = []))
 )]
 pub async fn get_ip_addresses(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::IpAddress>,
     Query(query): Query<GetIpAddressesQuery>,
 ) -> impl IntoResponse {
     let page = query.page.unwrap_or(1);
@@ -131,6 +268,7 @@ pub async fn get_ip_addresses(
 
     match service_get_ip_addresses(
         &state.conn,
+        &auth.scope,
         query.ip_range_id.as_ref(),
         query.status.as_deref(),
         query.search.as_deref(),
@@ -145,19 +283,7 @@ pub async fn get_ip_addresses(
         }) => {
             let responses: Vec<IpAddressResponse> = ip_addresses
                 .into_iter()
-                .map(|addr| IpAddressResponse {
-                    id: addr.id,
-                    ip_range_id: addr.ip_range_id,
-                    ip_address: addr.ip_address,
-                    mac_address: addr.mac_address,
-                    hostname: addr.hostname,
-                    status: addr.status,
-                    description: addr.description,
-                    created_by: addr.created_by,
-                    created_at: addr.created_at.to_rfc3339(),
-                    updated_at: addr.updated_at.to_rfc3339(),
-                    is_active: addr.is_active,
-                })
+                .map(IpAddressResponse::from)
                 .collect();
 
             Ok((
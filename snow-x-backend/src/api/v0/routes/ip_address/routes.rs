@@ -1,8 +1,15 @@
-use crate::api::v0::routes::ip_address::handlers::{create_bulk_ip_addresses, get_ip_addresses};
+use crate::api::v0::routes::ip_address::handlers::{
+    create_bulk_ip_addresses, get_ip_addresses, import_dhcp_leases, sweep_dhcp_leases,
+};
 use crate::middleware::auth::access_jwt_auth;
+use crate::service::dhcp_lease::import::MAX_LEASE_FILE_SIZE;
 use crate::state::AppState;
+use axum::extract::DefaultBodyLimit;
 use axum::{Router, middleware, routing::get};
 
+/// multipart 경계와 다른 필드를 위한 여유분
+const LEASE_UPLOAD_OVERHEAD: usize = 64 * 1024;
+
 pub fn ip_address_routes() -> Router<AppState> {
     Router::new()
         .route(
@@ -14,4 +21,17 @@ pub fn ip_address_routes() -> Router<AppState> {
             axum::routing::post(create_bulk_ip_addresses)
                 .route_layer(middleware::from_fn(access_jwt_auth)),
         )
+        .route(
+            "/v0/ipam/ip-address/dhcp-leases",
+            axum::routing::post(import_dhcp_leases)
+                .layer(DefaultBodyLimit::max(
+                    MAX_LEASE_FILE_SIZE + LEASE_UPLOAD_OVERHEAD,
+                ))
+                .route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/v0/ipam/ip-address/dhcp-leases/sweep",
+            axum::routing::post(sweep_dhcp_leases)
+                .route_layer(middleware::from_fn(access_jwt_auth)),
+        )
 }
@@ -1,7 +1,7 @@
 use axum::{
-    Extension, Json,
+    Json,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{StatusCode, header},
     response::IntoResponse,
 };
 use serde::{Deserialize, Serialize};
@@ -9,15 +9,28 @@ use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
-    dto::auth::internal::access_token::AccessTokenClaims,
+    api::v0::routes::ip_address::handlers::IpAddressResponse,
     entity::ip_ranges,
+    middleware::permission::{Authorized, resource},
+    service::dns_zone::export::{
+        DnsZoneSummary, service_diff_dns_zone, service_export_dns_zone, service_list_dns_zones,
+    },
     service::ip_range::{
-        RangeUsageStats, create_ip_range::service_create_ip_range,
-        delete_ip_range::service_delete_ip_range, fetch_ip_range_usage,
-        get_ip_range_by_id::service_get_ip_range_by_id, get_ip_ranges::service_get_ip_ranges,
+        RangeUsageStats,
+        allocate_ip_addresses::{AllocateIpParams, service_allocate_ip_addresses},
+        create_ip_range::{CreateIpRangeParams, service_create_ip_range},
+        delete_ip_range::service_delete_ip_range,
+        fetch_ip_range_usage,
+        free_blocks::{
+            CreateChildSubnetParams, service_create_child_subnet, service_get_free_blocks,
+        },
+        get_ip_range_by_id::service_get_ip_range_by_id,
+        get_ip_ranges::service_get_ip_ranges,
+        hierarchy::{IpRangeTree, service_get_ip_range_children},
         update_ip_range::service_update_ip_range,
     },
     state::AppState,
+    utils::ip_math::{IpNetwork, saturating_i64},
 };
 
 #[derive(Deserialize, ToSchema)]
@@ -91,25 +104,157 @@ pub struct IpRangeListResponse {
     pub limit: u64,
 }
 
-fn calculate_capacity(ip_version: i32, subnet_mask: i32) -> i64 {
-    if ip_version != 4 {
-        return 0;
+#[derive(Deserialize, ToSchema)]
+pub struct AllocateIpRequest {
+    /// 연속으로 할당할 주소 개수 (기본값 1)
+    #[serde(default = "default_allocation_count")]
+    pub count: u32,
+    pub hostname: Option<String>,
+    pub description: Option<String>,
+    /// 지정하면 할당된 주소를 같은 트랜잭션에서 장비에 연결
+    pub device_id: Option<Uuid>,
+    pub interface_name: Option<String>,
+    #[serde(default)]
+    pub is_primary: bool,
+}
+
+fn default_allocation_count() -> u32 {
+    1
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct IpAllocationResponse {
+    pub ip_range_id: Uuid,
+    pub device_id: Option<Uuid>,
+    pub ip_addresses: Vec<IpAddressResponse>,
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+pub struct FreeBlocksQuery {
+    /// 잘라낼 하위 대역 prefix 길이 (예: 26). 지정하면 후보 블록 목록을 함께 반환
+    pub prefix: Option<i32>,
+    /// 후보 블록 최대 개수 (기본값 20)
+    pub limit: Option<u32>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct FreeBlockResponse {
+    pub network_address: String,
+    pub subnet_mask: i32,
+    pub cidr: String,
+}
+
+impl From<IpNetwork> for FreeBlockResponse {
+    fn from(block: IpNetwork) -> Self {
+        let network_address = block.network_ip().to_string();
+        FreeBlockResponse {
+            cidr: format!("{}/{}", network_address, block.prefix()),
+            network_address,
+            subnet_mask: block.prefix() as i32,
+        }
     }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct FreeBlockSummary {
+    pub subnet_mask: i32,
+    pub count: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct FreeBlocksResponse {
+    pub ip_range_id: Uuid,
+    pub network_address: String,
+    pub subnet_mask: i32,
+    /// 빈 공간을 정렬된 최대 CIDR 블록으로 분해한 목록 (큰 블록 우선)
+    pub free_blocks: Vec<FreeBlockResponse>,
+    /// prefix 길이별 빈 블록 개수
+    pub summary: Vec<FreeBlockSummary>,
+    pub requested_prefix: Option<i32>,
+    /// 요청한 prefix로 잘라낼 수 있는 후보 블록 (best-fit 순)
+    pub candidates: Vec<FreeBlockResponse>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateChildSubnetRequest {
+    pub name: String,
+    pub description: Option<String>,
+    /// 생략하면 best-fit 위치의 빈 블록을 자동으로 선택
+    pub network_address: Option<String>,
+    pub subnet_mask: i32,
+    pub gateway: Option<String>,
+    pub dns_servers: Option<Vec<String>>,
+    pub vlan_id: Option<i32>,
+}
 
-    match subnet_mask {
-        0..=30 => {
-            let host_bits = 32 - subnet_mask;
-            let total = 1_i64 << host_bits;
-            (total - 2).max(0)
+#[derive(Deserialize, ToSchema, IntoParams)]
+pub struct DnsZoneQuery {
+    /// 정방향 zone 도메인 (예: corp.example.com). 생략하면 마지막으로 생성한 도메인 또는 DNS_ZONE_DEFAULT_DOMAIN
+    pub domain: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct DnsZoneSummaryResponse {
+    pub zone_name: String,
+    /// `forward` 또는 `reverse`
+    pub kind: String,
+    /// 마지막으로 생성한 serial (생성 이력이 없으면 null)
+    pub serial: Option<i64>,
+    pub generated_at: Option<String>,
+}
+
+impl From<DnsZoneSummary> for DnsZoneSummaryResponse {
+    fn from(zone: DnsZoneSummary) -> Self {
+        DnsZoneSummaryResponse {
+            zone_name: zone.zone_name,
+            kind: zone.kind.as_str().to_string(),
+            serial: zone.serial,
+            generated_at: zone.generated_at.map(|t| t.to_rfc3339()),
         }
-        31 => 2,
-        32 => 1,
-        _ => 0,
     }
 }
 
+#[derive(Serialize, ToSchema)]
+pub struct DnsZoneListResponse {
+    pub ip_range_id: Uuid,
+    pub domain: String,
+    pub zones: Vec<DnsZoneSummaryResponse>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct DnsZoneDiffResponse {
+    pub zone_name: String,
+    pub kind: String,
+    pub current_serial: Option<i64>,
+    /// 내용이 바뀌지 않았으면 current_serial과 같음
+    pub next_serial: i64,
+    pub changed: bool,
+    /// `- `(삭제) / `+ `(추가)로 시작하는 변경 줄
+    pub diff: Vec<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct IpRangeTreeResponse {
+    #[serde(flatten)]
+    pub range: IpRangeResponse,
+    pub parent_id: Option<Uuid>,
+    /// 자신과 모든 하위 대역의 사용 중 IP 합계
+    pub rollup_used_ips: i64,
+    /// 자신과 모든 하위 대역에 기록된 IP 합계
+    pub rollup_recorded_ips: i64,
+    pub rollup_usage_percentage: f64,
+    #[schema(no_recursion)]
+    pub children: Vec<IpRangeTreeResponse>,
+}
+
+fn calculate_capacity(range: &ip_ranges::Model) -> i64 {
+    IpNetwork::parse(&range.network_address, range.subnet_mask, range.ip_version)
+        .map(|network| saturating_i64(network.host_capacity()))
+        .unwrap_or(0)
+}
+
 fn build_ip_range_response(range: &ip_ranges::Model, usage: RangeUsageStats) -> IpRangeResponse {
-    let capacity = calculate_capacity(range.ip_version, range.subnet_mask);
+    let capacity = calculate_capacity(range);
     let recorded_total = usage.total();
     let total_ips = if capacity > 0 {
         capacity
@@ -173,6 +318,34 @@ fn build_ip_range_response(range: &ip_ranges::Model, usage: RangeUsageStats) ->
     }
 }
 
+fn build_ip_range_tree_response(node: IpRangeTree) -> IpRangeTreeResponse {
+    let range = build_ip_range_response(&node.range, node.usage.clone());
+    let children: Vec<IpRangeTreeResponse> = node
+        .children
+        .into_iter()
+        .map(build_ip_range_tree_response)
+        .collect();
+
+    let rollup_used_ips =
+        node.usage.used() + children.iter().map(|c| c.rollup_used_ips).sum::<i64>();
+    let rollup_recorded_ips =
+        node.usage.total() + children.iter().map(|c| c.rollup_recorded_ips).sum::<i64>();
+    let rollup_usage_percentage = if range.total_ips > 0 {
+        (rollup_used_ips.min(range.total_ips) as f64 / range.total_ips as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    IpRangeTreeResponse {
+        range,
+        parent_id: node.parent_id,
+        rollup_used_ips,
+        rollup_recorded_ips,
+        rollup_usage_percentage,
+        children,
+    }
+}
+
 /// Create new IP range
 #[utoipa::path(
     post,
@@ -183,30 +356,34 @@ fn build_ip_range_response(range: &ip_ranges::Model, usage: RangeUsageStats) ->
         (status = 201, description = "IP range created successfully", body = IpRangeResponse),
         (status = 400, description = "Bad request"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 500, description = "Internal server error")
     ),
     security(("bearer" = []))
 )]
 pub async fn create_ip_range(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::IpRange>,
     Json(request): Json<CreateIpRangeRequest>,
 ) -> impl IntoResponse {
-    match service_create_ip_range(
-        &state.conn,
-        &request.tenant_id,
-        &request.name,
-        request.description.as_deref(),
-        &request.network_address,
-        request.subnet_mask,
-        request.gateway.as_deref(),
-        request.dns_servers,
-        request.vlan_id,
-        request.ip_version,
-        &claims.sub,
-    )
-    .await
-    {
+    // 요청의 tenant_id는 IP 대역을 둘 사무실이다
+    if let Err(err) = auth.ensure_office(&state.conn, request.tenant_id).await {
+        return Err(err.into_response());
+    }
+
+    let params = CreateIpRangeParams {
+        tenant_id: request.tenant_id,
+        name: request.name,
+        description: request.description,
+        network_address: request.network_address,
+        subnet_mask: request.subnet_mask,
+        gateway: request.gateway,
+        dns_servers: request.dns_servers,
+        vlan_id: request.vlan_id,
+        ip_version: request.ip_version,
+    };
+
+    match service_create_ip_range(&state.conn, params, &auth.user_id()).await {
         Ok(ip_range) => {
             let response = build_ip_range_response(&ip_range, RangeUsageStats::default());
             Ok((StatusCode::CREATED, Json(response)))
@@ -226,19 +403,20 @@ pub async fn create_ip_range(
     responses(
         (status = 200, description = "IP ranges retrieved successfully", body = IpRangeListResponse),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 500, description = "Internal server error")
     ),
     security(("bearer" = []))
 )]
 pub async fn get_ip_ranges(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::IpRange>,
     Query(query): Query<ListIpRangesQuery>,
 ) -> impl IntoResponse {
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(20);
 
-    match service_get_ip_ranges(&state.conn, page, limit).await {
+    match service_get_ip_ranges(&state.conn, &auth.scope, page, limit).await {
         Ok(result) => {
             let usage_map = result.usage;
             let mut range_responses = Vec::with_capacity(result.ip_ranges.len());
@@ -271,6 +449,7 @@ pub async fn get_ip_ranges(
     responses(
         (status = 200, description = "IP range retrieved successfully", body = IpRangeResponse),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "IP range not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -278,7 +457,7 @@ pub async fn get_ip_ranges(
 )]
 pub async fn get_ip_range_by_id(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::IpRange>,
     Path(id): Path<Uuid>,
 ) -> impl IntoResponse {
     match service_get_ip_range_by_id(&state.conn, &id).await {
@@ -309,6 +488,7 @@ pub async fn get_ip_range_by_id(
         (status = 200, description = "IP range updated successfully", body = IpRangeResponse),
         (status = 400, description = "Bad request"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "IP range not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -316,14 +496,14 @@ pub async fn get_ip_range_by_id(
 )]
 pub async fn update_ip_range(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::IpRange>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateIpRangeRequest>,
 ) -> impl IntoResponse {
     match service_update_ip_range(
         &state.conn,
         &id,
-        claims.sub,
+        auth.user_id(),
         request.name,
         request.description,
         request.network_address,
@@ -359,6 +539,7 @@ pub async fn update_ip_range(
     responses(
         (status = 204, description = "IP range deleted successfully"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "IP range not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -366,11 +547,361 @@ pub async fn update_ip_range(
 )]
 pub async fn delete_ip_range(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::IpRange>,
     Path(id): Path<Uuid>,
 ) -> impl IntoResponse {
-    match service_delete_ip_range(&state.conn, &id).await {
+    match service_delete_ip_range(&state.conn, &id, &auth.user_id()).await {
         Ok(_) => Ok(StatusCode::NO_CONTENT),
         Err(err) => Err(err.into_response()),
     }
 }
+
+/// Get IP range with its nested child ranges
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/ip-range/{id}/children",
+    tag = "IP Range",
+    params(
+        ("id" = Uuid, Path, description = "IP Range ID")
+    ),
+    responses(
+        (status = 200, description = "IP range tree retrieved successfully", body = IpRangeTreeResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 404, description = "IP range not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_ip_range_children(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::IpRange>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match service_get_ip_range_children(&state.conn, &id).await {
+        Ok(tree) => Ok((StatusCode::OK, Json(build_ip_range_tree_response(tree)))),
+        Err(err) => Err(err.into_response()),
+    }
+}
+
+/// Allocate the next available IP address(es) from an IP range
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/ip-range/{id}/allocate",
+    tag = "IP Range",
+    params(
+        ("id" = Uuid, Path, description = "IP Range ID")
+    ),
+    request_body = AllocateIpRequest,
+    responses(
+        (status = 201, description = "IP addresses allocated successfully", body = IpAllocationResponse),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 404, description = "IP range or device not found"),
+        (status = 409, description = "Not enough free addresses in the IP range"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn allocate_ip_addresses(
+    State(state): State<AppState>,
+    auth: Authorized<resource::IpRange>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<AllocateIpRequest>,
+) -> impl IntoResponse {
+    let device_id = request.device_id;
+
+    if let Some(device_id) = device_id
+        && let Err(err) = auth.ensure_device(&state.conn, device_id).await
+    {
+        return Err(err.into_response());
+    }
+
+    match service_allocate_ip_addresses(
+        &state.conn,
+        &id,
+        AllocateIpParams {
+            count: request.count,
+            hostname: request.hostname,
+            description: request.description,
+            device_id: request.device_id,
+            interface_name: request.interface_name,
+            is_primary: request.is_primary,
+        },
+        &auth.user_id(),
+    )
+    .await
+    {
+        Ok(addresses) => {
+            let response = IpAllocationResponse {
+                ip_range_id: id,
+                device_id,
+                ip_addresses: addresses.into_iter().map(IpAddressResponse::from).collect(),
+            };
+            Ok((StatusCode::CREATED, Json(response)))
+        }
+        Err(err) => Err(err.into_response()),
+    }
+}
+
+/// Get free CIDR blocks inside an IP range
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/ip-range/{id}/free-blocks",
+    tag = "IP Range",
+    params(
+        ("id" = Uuid, Path, description = "IP Range ID"),
+        FreeBlocksQuery,
+    ),
+    responses(
+        (status = 200, description = "Free blocks calculated successfully", body = FreeBlocksResponse),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 404, description = "IP range not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_free_blocks(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::IpRange>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<FreeBlocksQuery>,
+) -> impl IntoResponse {
+    let limit = query.limit.unwrap_or(20);
+
+    match service_get_free_blocks(&state.conn, &id, query.prefix, limit).await {
+        Ok(result) => {
+            let mut summary: Vec<FreeBlockSummary> = Vec::new();
+            for block in &result.blocks {
+                let subnet_mask = block.prefix() as i32;
+                match summary.last_mut() {
+                    Some(last) if last.subnet_mask == subnet_mask => last.count += 1,
+                    _ => summary.push(FreeBlockSummary {
+                        subnet_mask,
+                        count: 1,
+                    }),
+                }
+            }
+
+            let response = FreeBlocksResponse {
+                ip_range_id: result.ip_range.id,
+                network_address: result.ip_range.network_address,
+                subnet_mask: result.ip_range.subnet_mask,
+                free_blocks: result
+                    .blocks
+                    .into_iter()
+                    .map(FreeBlockResponse::from)
+                    .collect(),
+                summary,
+                requested_prefix: query.prefix,
+                candidates: result
+                    .candidates
+                    .into_iter()
+                    .map(FreeBlockResponse::from)
+                    .collect(),
+            };
+            Ok((StatusCode::OK, Json(response)))
+        }
+        Err(err) => Err(err.into_response()),
+    }
+}
+
+/// Carve a child subnet out of an IP range
+#[utoipa::path(
+    post,
+    path = "/v0/ipam/ip-range/{id}/subnets",
+    tag = "IP Range",
+    params(
+        ("id" = Uuid, Path, description = "Parent IP Range ID")
+    ),
+    request_body = CreateChildSubnetRequest,
+    responses(
+        (status = 201, description = "Child subnet created successfully", body = IpRangeResponse),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 404, description = "IP range not found"),
+        (status = 409, description = "Subnet overlaps used space or no free block left"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn create_child_subnet(
+    State(state): State<AppState>,
+    auth: Authorized<resource::IpRange>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<CreateChildSubnetRequest>,
+) -> impl IntoResponse {
+    match service_create_child_subnet(
+        &state.conn,
+        &id,
+        CreateChildSubnetParams {
+            name: request.name,
+            description: request.description,
+            network_address: request.network_address,
+            subnet_mask: request.subnet_mask,
+            gateway: request.gateway,
+            dns_servers: request.dns_servers,
+            vlan_id: request.vlan_id,
+        },
+        &auth.user_id(),
+    )
+    .await
+    {
+        Ok(ip_range) => {
+            let response = build_ip_range_response(&ip_range, RangeUsageStats::default());
+            Ok((StatusCode::CREATED, Json(response)))
+        }
+        Err(err) => Err(err.into_response()),
+    }
+}
+
+/// List DNS zones generated from an IP range
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/ip-range/{id}/dns-zones",
+    tag = "IP Range",
+    params(
+        ("id" = Uuid, Path, description = "IP Range ID"),
+        DnsZoneQuery,
+    ),
+    responses(
+        (status = 200, description = "Forward and reverse zones for the range", body = DnsZoneListResponse),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 404, description = "IP range not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_dns_zones(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::IpRange>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<DnsZoneQuery>,
+) -> impl IntoResponse {
+    match service_list_dns_zones(&state.conn, &id, query.domain.as_deref()).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(DnsZoneListResponse {
+                ip_range_id: result.ip_range.id,
+                domain: result.domain,
+                zones: result
+                    .zones
+                    .into_iter()
+                    .map(DnsZoneSummaryResponse::from)
+                    .collect(),
+            }),
+        )),
+        Err(err) => Err(err.into_response()),
+    }
+}
+
+/// Download a BIND zone file for an IP range
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/ip-range/{id}/dns-zones/{zone_name}",
+    tag = "IP Range",
+    params(
+        ("id" = Uuid, Path, description = "IP Range ID"),
+        ("zone_name" = String, Path, description = "Forward domain or reverse zone (in-addr.arpa / ip6.arpa)"),
+        DnsZoneQuery,
+    ),
+    responses(
+        (status = 200, description = "Zone file (serial is bumped only when records changed)", body = String, content_type = "text/dns",
+            headers(
+                ("x-zone-serial" = i64, description = "SOA serial of the returned zone"),
+                ("x-zone-changed" = bool, description = "Whether this export bumped the serial")
+            )
+        ),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 404, description = "IP range or zone not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn export_dns_zone(
+    State(state): State<AppState>,
+    auth: Authorized<resource::IpRange>,
+    Path((id, zone_name)): Path<(Uuid, String)>,
+    Query(query): Query<DnsZoneQuery>,
+) -> impl IntoResponse {
+    match service_export_dns_zone(
+        &state.conn,
+        &id,
+        &zone_name,
+        query.domain.as_deref(),
+        &auth.user_id(),
+    )
+    .await
+    {
+        Ok(zone) => Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/dns; charset=utf-8".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"db.{}\"", zone.zone_name),
+                ),
+                (
+                    header::HeaderName::from_static("x-zone-serial"),
+                    zone.serial.to_string(),
+                ),
+                (
+                    header::HeaderName::from_static("x-zone-changed"),
+                    zone.changed.to_string(),
+                ),
+            ],
+            zone.content,
+        )),
+        Err(err) => Err(err.into_response()),
+    }
+}
+
+/// Preview changes to a zone file without bumping its serial
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/ip-range/{id}/dns-zones/{zone_name}/diff",
+    tag = "IP Range",
+    params(
+        ("id" = Uuid, Path, description = "IP Range ID"),
+        ("zone_name" = String, Path, description = "Forward domain or reverse zone (in-addr.arpa / ip6.arpa)"),
+        DnsZoneQuery,
+    ),
+    responses(
+        (status = 200, description = "Dry-run diff against the previously generated serial", body = DnsZoneDiffResponse),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 404, description = "IP range or zone not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn diff_dns_zone(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::IpRange>,
+    Path((id, zone_name)): Path<(Uuid, String)>,
+    Query(query): Query<DnsZoneQuery>,
+) -> impl IntoResponse {
+    match service_diff_dns_zone(&state.conn, &id, &zone_name, query.domain.as_deref()).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(DnsZoneDiffResponse {
+                zone_name: result.zone_name,
+                kind: result.kind.as_str().to_string(),
+                current_serial: result.current_serial,
+                next_serial: result.next_serial,
+                changed: result.changed,
+                diff: result.diff,
+            }),
+        )),
+        Err(err) => Err(err.into_response()),
+    }
+}
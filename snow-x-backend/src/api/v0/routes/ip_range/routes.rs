@@ -3,7 +3,9 @@ use axum::{Router, middleware, routing::get};
 use crate::{middleware::auth::access_jwt_auth, state::AppState};
 
 use super::handlers::{
-    create_ip_range, delete_ip_range, get_ip_range_by_id, get_ip_ranges, update_ip_range,
+    allocate_ip_addresses, create_child_subnet, create_ip_range, delete_ip_range, diff_dns_zone,
+    export_dns_zone, get_dns_zones, get_free_blocks, get_ip_range_by_id, get_ip_range_children,
+    get_ip_ranges, update_ip_range,
 };
 
 pub fn ip_range_routes() -> Router<AppState> {
@@ -21,4 +23,34 @@ pub fn ip_range_routes() -> Router<AppState> {
                 .delete(delete_ip_range)
                 .route_layer(middleware::from_fn(access_jwt_auth)),
         )
+        .route(
+            "/v0/ipam/ip-range/{id}/allocate",
+            axum::routing::post(allocate_ip_addresses)
+                .route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/v0/ipam/ip-range/{id}/free-blocks",
+            get(get_free_blocks).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/v0/ipam/ip-range/{id}/subnets",
+            axum::routing::post(create_child_subnet)
+                .route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/v0/ipam/ip-range/{id}/children",
+            get(get_ip_range_children).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/v0/ipam/ip-range/{id}/dns-zones",
+            get(get_dns_zones).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/v0/ipam/ip-range/{id}/dns-zones/{zone_name}",
+            get(export_dns_zone).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/v0/ipam/ip-range/{id}/dns-zones/{zone_name}/diff",
+            get(diff_dns_zone).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
 }
@@ -1,11 +1,15 @@
 mod admin;
+mod api_token;
+mod audit;
 mod auth;
+mod bulk_io;
 mod comment;
 mod contact;
 mod custodian;
 mod device;
 mod device_library;
 mod draft;
+mod external_api;
 mod follow;
 mod hashtag;
 pub mod ip_address;
@@ -16,6 +20,9 @@ mod office;
 mod openapi;
 mod post;
 mod rack;
+mod realtime;
 mod report;
 pub mod routes;
+mod tenant;
 mod user;
+mod webhook;
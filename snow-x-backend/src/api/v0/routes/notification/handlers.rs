@@ -12,13 +12,31 @@ use uuid::Uuid;
 use crate::{
     dto::auth::internal::access_token::AccessTokenClaims,
     dto::notification::{
-        request::{CreateNotificationRequest, UpdateNotificationStatusRequest},
-        response::{NotificationListResponse, NotificationResponse},
+        request::{
+            CreateNotificationRequest, CreateResourceSubscriptionRequest,
+            UpdateNotificationPreferencesRequest, UpdateNotificationStatusRequest,
+        },
+        response::{
+            InboxNotificationListResponse, InboxNotificationResponse, MarkAllReadResponse,
+            NotificationListResponse, NotificationPreferenceListResponse, NotificationResponse,
+            ResourceSubscriptionListResponse, ResourceSubscriptionResponse, UnreadCountResponse,
+        },
     },
     entity::notifications_outbox,
+    middleware::permission::TenantContext,
+    service::auth::role_check::require_admin,
+    service::error::errors::Errors,
     service::notification::{
         self, CreateNotificationParams, NotificationListResult, STATUS_DONE, STATUS_FAILED,
         STATUS_PENDING, STATUS_PROCESSING,
+        inbox::{
+            service_get_inbox, service_get_unread_count, service_mark_all_read, service_mark_read,
+        },
+        preference::{service_get_preferences, service_update_preferences},
+        subscription::{
+            service_get_resource_subscriptions, service_subscribe_resource,
+            service_unsubscribe_resource,
+        },
     },
     state::AppState,
 };
@@ -31,6 +49,22 @@ pub struct ListNotificationsQuery {
     pub channel: Option<String>,
 }
 
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct InboxQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    /// true면 안 읽은 알림만
+    pub unread_only: Option<bool>,
+    /// 알림 분류
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct MarkAllReadQuery {
+    /// 이 분류만 읽음 처리 (생략하면 전체)
+    pub category: Option<String>,
+}
+
 fn map_model(model: notifications_outbox::Model) -> NotificationResponse {
     NotificationResponse {
         id: model.id,
@@ -61,24 +95,29 @@ fn map_list_result(result: NotificationListResult) -> NotificationListResponse {
     }
 }
 
-/// 새 알림을 생성하여 알림 큐에 적재합니다.
+/// 새 알림을 생성하여 알림 큐에 적재합니다. (관리자 전용)
 #[utoipa::path(
     post,
-    path = "/v0/notifications",
+    path = "/v0/notifications/outbox",
     tag = "Notifications",
     request_body = CreateNotificationRequest,
     responses(
         (status = 201, description = "알림 생성 성공", body = NotificationResponse),
         (status = 400, description = "잘못된 요청"),
-        (status = 401, description = "인증 필요")
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "관리자 권한 필요")
     ),
     security(("bearer" = []))
 )]
 pub async fn create_notification(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    Extension(claims): Extension<AccessTokenClaims>,
     Json(request): Json<CreateNotificationRequest>,
 ) -> impl IntoResponse {
+    if let Err(err) = require_admin(&state.conn, claims.sub).await {
+        return err.into_response();
+    }
+
     let params = CreateNotificationParams {
         tenant_id: request.tenant_id,
         channel: request.channel,
@@ -96,25 +135,30 @@ pub async fn create_notification(
     }
 }
 
-/// 알림 목록을 조회합니다.
+/// 발송 대기열(outbox)의 알림 목록을 조회합니다. (관리자 전용)
 #[utoipa::path(
     get,
-    path = "/v0/notifications",
+    path = "/v0/notifications/outbox",
     tag = "Notifications",
     params(
         ListNotificationsQuery,
     ),
     responses(
         (status = 200, description = "알림 목록 조회 성공", body = NotificationListResponse),
-        (status = 401, description = "인증 필요")
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "관리자 권한 필요")
     ),
     security(("bearer" = []))
 )]
 pub async fn get_notifications(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    Extension(claims): Extension<AccessTokenClaims>,
     Query(query): Query<ListNotificationsQuery>,
 ) -> impl IntoResponse {
+    if let Err(err) = require_admin(&state.conn, claims.sub).await {
+        return err.into_response();
+    }
+
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(20);
 
@@ -132,10 +176,10 @@ pub async fn get_notifications(
     }
 }
 
-/// 알림 상태를 업데이트합니다. (예: 읽음 처리, 강제 실패 표시 등)
+/// 발송 대기열 알림의 상태를 업데이트합니다. (예: 재발송, 강제 실패 표시 등, 관리자 전용)
 #[utoipa::path(
     patch,
-    path = "/v0/notifications/{id}",
+    path = "/v0/notifications/outbox/{id}",
     tag = "Notifications",
     params(
         ("id" = Uuid, Path, description = "알림 ID")
@@ -145,16 +189,21 @@ pub async fn get_notifications(
         (status = 200, description = "알림 상태 업데이트 성공", body = NotificationResponse),
         (status = 400, description = "잘못된 요청"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "관리자 권한 필요"),
         (status = 404, description = "알림 없음")
     ),
     security(("bearer" = []))
 )]
 pub async fn update_notification_status(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    Extension(claims): Extension<AccessTokenClaims>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateNotificationStatusRequest>,
 ) -> impl IntoResponse {
+    if let Err(err) = require_admin(&state.conn, claims.sub).await {
+        return err.into_response();
+    }
+
     match notification::service_update_notification_status(
         &state.conn,
         &id,
@@ -168,6 +217,205 @@ pub async fn update_notification_status(
     }
 }
 
+/// 내 알림함을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/notifications",
+    tag = "Notifications",
+    params(InboxQuery),
+    responses(
+        (status = 200, description = "내 알림 목록", body = InboxNotificationListResponse),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_inbox(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Query(query): Query<InboxQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_inbox(
+        &state.conn,
+        claims.sub,
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+        query.unread_only.unwrap_or(false),
+        query.category,
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 안 읽은 알림 수를 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/notifications/unread-count",
+    tag = "Notifications",
+    responses(
+        (status = 200, description = "안 읽은 알림 수", body = UnreadCountResponse),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_unread_count(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_unread_count(&state.conn, claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// 알림을 읽음으로 표시합니다.
+#[utoipa::path(
+    post,
+    path = "/v0/notifications/{id}/read",
+    tag = "Notifications",
+    params(("id" = Uuid, Path, description = "알림 ID")),
+    responses(
+        (status = 200, description = "읽음 처리된 알림", body = InboxNotificationResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 404, description = "알림 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn mark_read(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_mark_read(&state.conn, claims.sub, id).await?;
+    Ok(Json(response))
+}
+
+/// 안 읽은 알림을 모두 읽음으로 표시합니다.
+#[utoipa::path(
+    post,
+    path = "/v0/notifications/read-all",
+    tag = "Notifications",
+    params(MarkAllReadQuery),
+    responses(
+        (status = 200, description = "읽음 처리 결과", body = MarkAllReadResponse),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn mark_all_read(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Query(query): Query<MarkAllReadQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_mark_all_read(&state.conn, claims.sub, query.category).await?;
+    Ok(Json(response))
+}
+
+/// 분류·채널별 알림 설정을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/notifications/preferences",
+    tag = "Notifications",
+    responses(
+        (status = 200, description = "알림 설정", body = NotificationPreferenceListResponse),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_preferences(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_preferences(&state.conn, claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// 알림 설정을 변경합니다. 보낸 항목만 바뀝니다.
+#[utoipa::path(
+    put,
+    path = "/v0/notifications/preferences",
+    tag = "Notifications",
+    request_body = UpdateNotificationPreferencesRequest,
+    responses(
+        (status = 200, description = "변경된 알림 설정", body = NotificationPreferenceListResponse),
+        (status = 400, description = "잘못된 분류, 채널 또는 전달 방식"),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn update_preferences(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Json(request): Json<UpdateNotificationPreferencesRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_update_preferences(&state.conn, claims.sub, request).await?;
+    Ok(Json(response))
+}
+
+/// 내 리소스 알림 구독 목록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/notifications/subscriptions",
+    tag = "Notifications",
+    responses(
+        (status = 200, description = "리소스 구독 목록", body = ResourceSubscriptionListResponse),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_resource_subscriptions(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_resource_subscriptions(&state.conn, claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// 사무실·서버실·랙·장비·IP 대역의 알림을 구독합니다. 하위 리소스의 알림도 함께 받습니다.
+#[utoipa::path(
+    post,
+    path = "/v0/notifications/subscriptions",
+    tag = "Notifications",
+    request_body = CreateResourceSubscriptionRequest,
+    responses(
+        (status = 201, description = "리소스 구독", body = ResourceSubscriptionResponse),
+        (status = 400, description = "잘못된 리소스 종류"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "사무실 범위 밖의 리소스"),
+        (status = 404, description = "리소스 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn subscribe_resource(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Json(request): Json<CreateResourceSubscriptionRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response =
+        service_subscribe_resource(&state.conn, claims.sub, &tenant.scope, request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 리소스 알림 구독을 해지합니다.
+#[utoipa::path(
+    delete,
+    path = "/v0/notifications/subscriptions/{id}",
+    tag = "Notifications",
+    params(("id" = Uuid, Path, description = "구독 ID")),
+    responses(
+        (status = 204, description = "구독 해지"),
+        (status = 401, description = "인증 필요"),
+        (status = 404, description = "구독 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn unsubscribe_resource(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    service_unsubscribe_resource(&state.conn, claims.sub, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 /// 노출 가능한 상태 값 안내용 상수 목록
 #[allow(dead_code)]
 pub const ALLOWED_NOTIFICATION_STATUSES: [&str; 4] = [
@@ -1,22 +1,59 @@
 use axum::{
     Router, middleware,
-    routing::{get, patch},
+    routing::{delete, get, patch, post},
 };
 
 use crate::middleware::auth::access_jwt_auth;
 
-use super::handlers::{create_notification, get_notifications, update_notification_status};
+use super::handlers::{
+    create_notification, get_inbox, get_notifications, get_preferences, get_resource_subscriptions,
+    get_unread_count, mark_all_read, mark_read, subscribe_resource, unsubscribe_resource,
+    update_notification_status, update_preferences,
+};
 
 pub fn notification_routes() -> Router<crate::AppState> {
     Router::new()
         .route(
             "/notifications",
+            get(get_inbox).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/notifications/unread-count",
+            get(get_unread_count).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/notifications/read-all",
+            post(mark_all_read).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/notifications/{id}/read",
+            post(mark_read).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/notifications/preferences",
+            get(get_preferences)
+                .put(update_preferences)
+                .route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/notifications/subscriptions",
+            get(get_resource_subscriptions)
+                .post(subscribe_resource)
+                .route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/notifications/subscriptions/{id}",
+            delete(unsubscribe_resource).route_layer(middleware::from_fn(access_jwt_auth)),
+        )
+        // 발송 대기열 (관리자 전용)
+        .route(
+            "/notifications/outbox",
             get(get_notifications)
                 .post(create_notification)
                 .route_layer(middleware::from_fn(access_jwt_auth)),
         )
         .route(
-            "/notifications/{id}",
+            "/notifications/outbox/{id}",
             patch(update_notification_status).route_layer(middleware::from_fn(access_jwt_auth)),
         )
 }
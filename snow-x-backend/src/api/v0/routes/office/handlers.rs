@@ -1,18 +1,17 @@
 use axum::{
-    Extension, Json,
+    Json,
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
-use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
-};
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
-    dto::auth::internal::access_token::AccessTokenClaims,
+    dto::office::request::{CreateOfficeRequest, UpdateOfficeRequest},
+    dto::rack::response::rack_capacity::CapacityRollupResponse,
     dto::server_room::{
         request::{
             create_server_room::CreateServerRoomRequest,
@@ -23,6 +22,12 @@ use crate::{
         },
     },
     entity::office::{self, Entity as Office},
+    middleware::permission::{Authorized, resource},
+    service::office::{
+        create_office::service_create_office, delete_office::service_delete_office,
+        update_office::service_update_office,
+    },
+    service::rack::{service_get_office_capacity, service_get_server_room_capacity},
     service::server_room::{
         create_server_room::service_create_server_room,
         delete_server_room::service_delete_server_room,
@@ -32,26 +37,6 @@ use crate::{
     state::AppState,
 };
 
-#[derive(Deserialize, ToSchema)]
-pub struct CreateOfficeRequest {
-    pub name: String,
-    pub description: Option<String>,
-    pub address: String,
-    pub contact_person: Option<String>,
-    pub phone: Option<String>,
-    pub email: Option<String>,
-}
-
-#[derive(Deserialize, ToSchema)]
-pub struct UpdateOfficeRequest {
-    pub name: Option<String>,
-    pub description: Option<String>,
-    pub address: Option<String>,
-    pub contact_person: Option<String>,
-    pub phone: Option<String>,
-    pub email: Option<String>,
-}
-
 #[derive(Deserialize, ToSchema, IntoParams)]
 pub struct ListOfficesQuery {
     pub page: Option<u64>,
@@ -74,6 +59,24 @@ pub struct OfficeResponse {
     pub is_active: bool,
 }
 
+impl From<office::Model> for OfficeResponse {
+    fn from(office: office::Model) -> Self {
+        Self {
+            id: office.id,
+            name: office.name,
+            description: office.description,
+            address: office.address,
+            contact_person: office.contact_person,
+            phone: office.phone,
+            email: office.email,
+            created_by: office.created_by,
+            created_at: office.created_at,
+            updated_at: office.updated_at,
+            is_active: office.is_active,
+        }
+    }
+}
+
 #[derive(Serialize, ToSchema)]
 pub struct OfficeListResponse {
     pub offices: Vec<OfficeResponse>,
@@ -92,54 +95,24 @@ pub struct OfficeListResponse {
         (status = 201, description = "Office created successfully", body = OfficeResponse),
         (status = 400, description = "Bad request"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 500, description = "Internal server error")
     ),
     security(("bearer" = []))
 )]
 pub async fn create_office(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Office>,
     Json(request): Json<CreateOfficeRequest>,
 ) -> impl IntoResponse {
-    let now = chrono::Utc::now();
-
-    let office = office::ActiveModel {
-        id: Set(Uuid::new_v4()),
-        name: Set(request.name),
-        description: Set(request.description),
-        address: Set(request.address),
-        contact_person: Set(request.contact_person),
-        phone: Set(request.phone),
-        email: Set(request.email),
-        created_by: Set(claims.sub),
-        created_at: Set(now.into()),
-        updated_at: Set(now.into()),
-        is_active: Set(true),
-    };
-
-    let result = office.insert(&state.conn).await;
+    // 사무실 범위가 있는 사용자는 만든 사무실에 접근할 수 없으므로 만들 수 없다
+    if let Err(err) = auth.ensure_all_offices() {
+        return Err(err.into_response());
+    }
 
-    match result {
-        Ok(office) => {
-            let response = OfficeResponse {
-                id: office.id,
-                name: office.name,
-                description: office.description,
-                address: office.address,
-                contact_person: office.contact_person,
-                phone: office.phone,
-                email: office.email,
-                created_by: office.created_by,
-                created_at: office.created_at,
-                updated_at: office.updated_at,
-                is_active: office.is_active,
-            };
-            Ok((StatusCode::CREATED, Json(response)))
-        }
-        Err(err) => {
-            eprintln!("Failed to create office: {:?}", err);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
-        }
+    match service_create_office(&state.conn, auth.tenant_id(), request, auth.user_id()).await {
+        Ok(office) => Ok((StatusCode::CREATED, Json(OfficeResponse::from(office)))),
+        Err(err) => Err(err.into_response()),
     }
 }
 
@@ -152,20 +125,23 @@ pub async fn create_office(
     responses(
         (status = 200, description = "Office list retrieved successfully", body = OfficeListResponse),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 500, description = "Internal server error")
     ),
     security(("bearer" = []))
 )]
 pub async fn get_offices(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Office>,
     Query(query): Query<ListOfficesQuery>,
 ) -> impl IntoResponse {
     println!("DEBUG: get_offices handler called!");
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(20);
 
-    let mut select = Office::find().filter(office::Column::IsActive.eq(true));
+    let mut select = Office::find()
+        .filter(office::Column::IsActive.eq(true))
+        .filter(office::Column::Id.in_subquery(auth.scope.visible_offices()));
 
     if let Some(search) = query.search {
         select = select.filter(
@@ -218,6 +194,7 @@ pub async fn get_offices(
     responses(
         (status = 200, description = "Office retrieved successfully", body = OfficeResponse),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "Office not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -225,7 +202,7 @@ pub async fn get_offices(
 )]
 pub async fn get_office(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::Office>,
     Path(id): Path<Uuid>,
 ) -> impl IntoResponse {
     let office = Office::find_by_id(id)
@@ -267,7 +244,7 @@ pub async fn get_office(
     responses(
         (status = 200, description = "Office updated successfully", body = OfficeResponse),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - only creator can update"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "Office not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -275,69 +252,13 @@ pub async fn get_office(
 )]
 pub async fn update_office(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Office>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateOfficeRequest>,
 ) -> impl IntoResponse {
-    let office = Office::find_by_id(id)
-        .filter(office::Column::IsActive.eq(true))
-        .one(&state.conn)
-        .await;
-
-    match office {
-        Ok(Some(office)) => {
-            // Check if user is the creator
-            if office.created_by != claims.sub {
-                return Err(StatusCode::FORBIDDEN);
-            }
-
-            let mut office: office::ActiveModel = office.into();
-
-            if let Some(name) = request.name {
-                office.name = Set(name);
-            }
-            if let Some(description) = request.description {
-                office.description = Set(Some(description));
-            }
-            if let Some(address) = request.address {
-                office.address = Set(address);
-            }
-            if let Some(contact_person) = request.contact_person {
-                office.contact_person = Set(Some(contact_person));
-            }
-            if let Some(phone) = request.phone {
-                office.phone = Set(Some(phone));
-            }
-            if let Some(email) = request.email {
-                office.email = Set(Some(email));
-            }
-
-            office.updated_at = Set(chrono::Utc::now().into());
-
-            let updated = office.update(&state.conn).await;
-
-            match updated {
-                Ok(office) => {
-                    let response = OfficeResponse {
-                        id: office.id,
-                        name: office.name,
-                        description: office.description,
-                        address: office.address,
-                        contact_person: office.contact_person,
-                        phone: office.phone,
-                        email: office.email,
-                        created_by: office.created_by,
-                        created_at: office.created_at,
-                        updated_at: office.updated_at,
-                        is_active: office.is_active,
-                    };
-                    Ok((StatusCode::OK, Json(response)))
-                }
-                Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-            }
-        }
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    match service_update_office(&state.conn, id, request, auth.user_id()).await {
+        Ok(office) => Ok((StatusCode::OK, Json(OfficeResponse::from(office)))),
+        Err(err) => Err(err.into_response()),
     }
 }
 
@@ -352,7 +273,7 @@ pub async fn update_office(
     responses(
         (status = 204, description = "Office deleted successfully"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - only creator can delete"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "Office not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -360,32 +281,12 @@ pub async fn update_office(
 )]
 pub async fn delete_office(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Office>,
     Path(id): Path<Uuid>,
 ) -> impl IntoResponse {
-    let office = Office::find_by_id(id)
-        .filter(office::Column::IsActive.eq(true))
-        .one(&state.conn)
-        .await;
-
-    match office {
-        Ok(Some(office)) => {
-            // Check if user is the creator
-            if office.created_by != claims.sub {
-                return Err(StatusCode::FORBIDDEN);
-            }
-
-            let mut office: office::ActiveModel = office.into();
-            office.is_active = Set(false);
-            office.updated_at = Set(chrono::Utc::now().into());
-
-            match office.update(&state.conn).await {
-                Ok(_) => Ok(StatusCode::NO_CONTENT),
-                Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-            }
-        }
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    match service_delete_office(&state.conn, id, auth.user_id()).await {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(err.into_response()),
     }
 }
 
@@ -410,6 +311,7 @@ pub struct ListServerRoomsQuery {
         (status = 201, description = "Server room created successfully", body = ServerRoomInfoResponse),
         (status = 400, description = "Bad request"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "Office not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -417,7 +319,7 @@ pub struct ListServerRoomsQuery {
 )]
 pub async fn create_server_room(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::ServerRoom>,
     Path(office_id): Path<Uuid>,
     Json(mut request): Json<CreateServerRoomRequest>,
 ) -> impl IntoResponse {
@@ -429,7 +331,7 @@ pub async fn create_server_room(
     // Set office_id from path parameter
     request.office_id = Some(office_id);
 
-    match service_create_server_room(&state.conn, request, &claims.sub).await {
+    match service_create_server_room(&state.conn, request, &auth.user_id()).await {
         Ok(response) => {
             println!("DEBUG: Server room created successfully: {:?}", response);
             Ok((StatusCode::CREATED, Json(response)))
@@ -453,6 +355,7 @@ pub async fn create_server_room(
     responses(
         (status = 200, description = "Server rooms retrieved successfully", body = ServerRoomListResponse),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "Office not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -460,7 +363,7 @@ pub async fn create_server_room(
 )]
 pub async fn get_server_rooms(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::ServerRoom>,
     Path(office_id): Path<Uuid>,
     Query(query): Query<ListServerRoomsQuery>,
 ) -> impl IntoResponse {
@@ -485,6 +388,7 @@ pub async fn get_server_rooms(
     responses(
         (status = 200, description = "Server room retrieved successfully", body = ServerRoomInfoResponse),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "Server room not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -492,7 +396,7 @@ pub async fn get_server_rooms(
 )]
 pub async fn get_server_room_by_id(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::ServerRoom>,
     Path((_office_id, id)): Path<(Uuid, Uuid)>,
 ) -> impl IntoResponse {
     match service_get_server_room_by_id(&state.conn, &id).await {
@@ -514,6 +418,7 @@ pub async fn get_server_room_by_id(
     responses(
         (status = 200, description = "Server room updated successfully", body = ServerRoomInfoResponse),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "Server room not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -521,11 +426,11 @@ pub async fn get_server_room_by_id(
 )]
 pub async fn update_server_room_by_id(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::ServerRoom>,
     Path((_office_id, id)): Path<(Uuid, Uuid)>,
     Json(request): Json<UpdateServerRoomRequest>,
 ) -> impl IntoResponse {
-    match service_update_server_room(&state.conn, &id, request).await {
+    match service_update_server_room(&state.conn, &id, request, &auth.user_id()).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err.into_response()),
     }
@@ -543,6 +448,7 @@ pub async fn update_server_room_by_id(
     responses(
         (status = 204, description = "Server room deleted successfully"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
         (status = 404, description = "Server room not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -550,11 +456,68 @@ pub async fn update_server_room_by_id(
 )]
 pub async fn delete_server_room_by_id(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::ServerRoom>,
     Path((_office_id, id)): Path<(Uuid, Uuid)>,
 ) -> impl IntoResponse {
-    match service_delete_server_room(&state.conn, &id).await {
+    match service_delete_server_room(&state.conn, &id, &auth.user_id()).await {
         Ok(_) => Ok(StatusCode::NO_CONTENT),
         Err(err) => Err(err.into_response()),
     }
 }
+
+/// Get power and space capacity rollup for an office
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/office/{id}/capacity",
+    tag = "Office",
+    params(
+        ("id" = Uuid, Path, description = "Office ID")
+    ),
+    responses(
+        (status = 200, description = "Office capacity rollup with per-server-room breakdown", body = CapacityRollupResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 404, description = "Office not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_office_capacity(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::Office>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match service_get_office_capacity(&state.conn, id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_response()),
+    }
+}
+
+/// Get power and space capacity rollup for a server room
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/office/{office_id}/server-room/{id}/capacity",
+    tag = "Server Room",
+    params(
+        ("office_id" = Uuid, Path, description = "Office ID"),
+        ("id" = Uuid, Path, description = "Server Room ID")
+    ),
+    responses(
+        (status = 200, description = "Server room capacity rollup with per-rack breakdown", body = CapacityRollupResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or office scope"),
+        (status = 404, description = "Server room not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_server_room_capacity(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::ServerRoom>,
+    Path((_office_id, id)): Path<(Uuid, Uuid)>,
+) -> impl IntoResponse {
+    match service_get_server_room_capacity(&state.conn, id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_response()),
+    }
+}
@@ -4,7 +4,8 @@ use axum::{Router, middleware, routing::get};
 
 use super::handlers::{
     create_office, create_server_room, delete_office, delete_server_room_by_id, get_office,
-    get_offices, get_server_room_by_id, get_server_rooms, update_office, update_server_room_by_id,
+    get_office_capacity, get_offices, get_server_room_by_id, get_server_room_capacity,
+    get_server_rooms, update_office, update_server_room_by_id,
 };
 
 // Simple test handler to verify route registration
@@ -27,6 +28,7 @@ pub fn office_routes() -> Router<AppState> {
             "/office/{id}",
             get(get_office).put(update_office).delete(delete_office),
         )
+        .route("/office/{id}/capacity", get(get_office_capacity))
         // Server Room routes
         .route(
             "/office/{office_id}/server-room",
@@ -38,6 +40,10 @@ pub fn office_routes() -> Router<AppState> {
                 .put(update_server_room_by_id)
                 .delete(delete_server_room_by_id),
         )
+        .route(
+            "/office/{office_id}/server-room/{id}/capacity",
+            get(get_server_room_capacity),
+        )
         .route_layer(middleware::from_fn(access_jwt_auth));
 
     let router = simple_routes.merge(protected_routes);
@@ -1,18 +1,42 @@
+use crate::api::v0::routes::audit::handlers::{ListAuditLogsQuery, ResourceHistoryQuery};
+use crate::api::v0::routes::bulk_io::handlers::{
+    BulkImportForm, BulkImportQuery, DeviceExportQuery, IpAddressExportQuery, RackExportQuery,
+};
 use crate::api::v0::routes::custodian::handlers::{
-    CreatePolicyRequest, ExecutePolicyRequest, UpdatePolicyRequest, ValidateYamlRequest,
-    ValidateYamlResponse,
+    CreatePolicyRequest, ExecutePolicyRequest, UpdatePolicyRequest, UpdatePolicyScheduleRequest,
+    ValidateYamlRequest, ValidateYamlResponse,
 };
 use crate::api::v0::routes::device::handlers::AssignIpRequest;
-use crate::api::v0::routes::ip_address::handlers::IpAddressResponse;
+use crate::api::v0::routes::external_api::handlers::{
+    ListConnectionsQuery, SyncConflictQuery, SyncFieldOverrideQuery, SyncLogQuery, SyncedDataQuery,
+};
+use crate::api::v0::routes::ip_address::handlers::{
+    DhcpLeaseImportForm, DhcpLeaseImportResponse, DhcpLeaseSweepResponse, IpAddressResponse,
+};
 use crate::api::v0::routes::ip_range::handlers::{
-    CreateIpRangeRequest, IpRangeListResponse, IpRangeResponse, ListIpRangesQuery,
-    UpdateIpRangeRequest,
+    AllocateIpRequest, CreateChildSubnetRequest, CreateIpRangeRequest, DnsZoneDiffResponse,
+    DnsZoneListResponse, DnsZoneQuery, DnsZoneSummaryResponse, FreeBlockResponse, FreeBlockSummary,
+    FreeBlocksQuery, FreeBlocksResponse, IpAllocationResponse, IpRangeListResponse,
+    IpRangeResponse, IpRangeTreeResponse, ListIpRangesQuery, UpdateIpRangeRequest,
+};
+use crate::api::v0::routes::notification::handlers::{
+    InboxQuery, ListNotificationsQuery, MarkAllReadQuery,
 };
 use crate::api::v0::routes::office::handlers::{
-    CreateOfficeRequest, ListOfficesQuery, ListServerRoomsQuery, OfficeListResponse,
-    OfficeResponse, UpdateOfficeRequest,
+    ListOfficesQuery, ListServerRoomsQuery, OfficeListResponse, OfficeResponse,
+};
+use crate::api::v0::routes::realtime::handlers::RealtimeQuery;
+use crate::api::v0::routes::webhook::handlers::{WebhookDeliveryQuery, WebhookSubscriptionQuery};
+use crate::dto::admin::request::UpdateUserPermissionsRequest;
+use crate::dto::admin::response::{
+    AdminStatusResponse, AdminTaskResponse, ResourcePermission, UserPermissionsResponse,
 };
-use crate::dto::admin::response::{AdminStatusResponse, AdminTaskResponse};
+use crate::dto::api_token::request::{CreateApiTokenRequest, CreateServiceAccountRequest};
+use crate::dto::api_token::response::{
+    ApiTokenListResponse, ApiTokenResponse, ApiTokenSecretResponse, ServiceAccountListResponse,
+    ServiceAccountResponse,
+};
+use crate::dto::audit::response::{AuditFieldChange, AuditLogListResponse, AuditLogResponse};
 use crate::dto::auth::request::forgot_password::ForgotPasswordRequest;
 use crate::dto::auth::request::link_oauth::LinkOAuthRequest;
 use crate::dto::auth::request::login::AuthLoginRequest;
@@ -23,6 +47,10 @@ use crate::dto::auth::request::unlink_oauth::UnlinkOAuthRequest;
 use crate::dto::auth::request::verify_email::VerifyEmailRequest;
 use crate::dto::auth::response::jwt::AuthJWTResponse;
 use crate::dto::auth::response::oauth_connections::OAuthConnectionsResponse;
+use crate::dto::auth::response::sign_in::{
+    SignInResponse, TwoFactorChallengeResponse, TwoFactorSignInResponse,
+};
+use crate::dto::bulk_io::response::{BulkImportResponse, BulkImportRowError, BulkImportRowResult};
 use crate::dto::comment::request::create_comment::CreateCommentRequest;
 use crate::dto::comment::request::delete_comment::DeleteCommentRequest;
 use crate::dto::comment::request::get_comment_by_id::GetCommentByIdRequest;
@@ -45,6 +73,17 @@ use crate::dto::draft::request::update_draft::UpdateDraftRequest;
 use crate::dto::draft::response::create_draft::CreateDraftResponse;
 use crate::dto::draft::response::draft_info::DraftInfo;
 use crate::dto::draft::response::get_drafts::GetDraftsResponse;
+use crate::dto::external_api::request::{
+    CreateExternalApiConnectionRequest, FieldMapping, FieldMappingRule, FilterCondition,
+    TestExternalApiConnectionRequest, UpdateExternalApiConnectionRequest,
+};
+use crate::dto::external_api::response::{
+    ExternalApiConnectionListResponse, ExternalApiConnectionResponse, ExternalApiDataListResponse,
+    ExternalApiDataResponse, ExternalApiSyncItemError, ExternalApiSyncLogListResponse,
+    ExternalApiSyncLogResponse, ExternalApiSyncResponse, SyncConflictListResponse,
+    SyncConflictResponse, SyncFieldOverrideListResponse, SyncFieldOverrideResponse,
+    TestExternalApiConnectionResponse,
+};
 use crate::dto::follow::request::check_follow_status::CheckFollowStatusRequest;
 use crate::dto::follow::request::create::CreateFollowRequest;
 use crate::dto::follow::request::delete::DeleteFollowRequest;
@@ -61,6 +100,17 @@ use crate::dto::like::request::create_like::CreateLikeRequest;
 use crate::dto::like::request::delete_comment_like::DeleteCommentLikeRequest;
 use crate::dto::like::request::delete_like::DeleteLikeRequest;
 use crate::dto::like::response::like_status::LikeStatusResponse;
+use crate::dto::notification::request::{
+    CreateNotificationRequest, CreateResourceSubscriptionRequest, NotificationPreferenceItem,
+    UpdateNotificationPreferencesRequest, UpdateNotificationStatusRequest,
+};
+use crate::dto::notification::response::{
+    InboxNotificationListResponse, InboxNotificationResponse, MarkAllReadResponse,
+    NotificationListResponse, NotificationPreferenceListResponse, NotificationPreferenceResponse,
+    NotificationResponse, ResourceSubscriptionListResponse, ResourceSubscriptionResponse,
+    UnreadCountResponse,
+};
+use crate::dto::office::request::{CreateOfficeRequest, UpdateOfficeRequest};
 use crate::dto::post::request::GetPostByHandleAndSlugRequest;
 use crate::dto::post::request::GetPostByUuidRequest;
 use crate::dto::post::request::create_post::CreatePostRequest;
@@ -79,6 +129,10 @@ use crate::dto::post::response::{
     GetPostsResponse, ImageUploadResponse, PostListItem, UserPostsResponse,
 };
 use crate::dto::rack::request::create_rack::CreateRackRequest;
+use crate::dto::rack::response::rack_capacity::CapacityRollupResponse;
+use crate::dto::rack::response::rack_elevation::{
+    RackElevationResponse, RackFreeGap, RackUnitSlot, RackUnplacedDevice,
+};
 use crate::dto::rack::response::rack_info::RackInfoResponse;
 use crate::dto::rack::response::rack_list::RackListResponse;
 use crate::dto::report::request::{CreateReportRequest, GetReportsRequest, ProcessReportRequest};
@@ -89,6 +143,21 @@ use crate::dto::server_room::request::{
 use crate::dto::server_room::response::{
     server_room_info::ServerRoomInfoResponse, server_room_list::ServerRoomListResponse,
 };
+use crate::dto::tenant::request::{
+    CreateTenantRequest, UpdateTenantRequest, UpsertTenantMemberRequest,
+};
+use crate::dto::tenant::response::{
+    MyTenantListResponse, MyTenantResponse, TenantMemberListResponse, TenantMemberResponse,
+    TenantResponse, TenantTokenResponse,
+};
+use crate::dto::two_factor::request::{
+    ConfirmTwoFactorRequest, TwoFactorChallengeRequest, TwoFactorCodeRequest,
+    TwoFactorSignInRequest, UpdateTwoFactorRolePolicyRequest,
+};
+use crate::dto::two_factor::response::{
+    TwoFactorEnrollmentResponse, TwoFactorRecoveryCodesResponse, TwoFactorRolePolicyListResponse,
+    TwoFactorRolePolicyResponse, TwoFactorStatusResponse,
+};
 use crate::dto::user::request::avatar_image::ProfileAvatarForm;
 use crate::dto::user::request::banner_image::ProfileBannerForm;
 use crate::dto::user::request::create::CreateUserRequest;
@@ -96,7 +165,16 @@ use crate::dto::user::request::get_profile::GetUserProfileRequest;
 use crate::dto::user::request::update_profile::UpdateProfileRequest;
 use crate::dto::user::response::handle_check::HandleCheckResponse;
 use crate::dto::user::response::info::UserInfoResponse;
+use crate::dto::webhook::request::{
+    CreateWebhookSubscriptionRequest, UpdateWebhookSubscriptionRequest,
+};
+use crate::dto::webhook::response::{
+    WebhookDeliveryAttemptResponse, WebhookDeliveryDetailResponse, WebhookDeliveryListResponse,
+    WebhookDeliveryResponse, WebhookEventTypesResponse, WebhookSubscriptionListResponse,
+    WebhookSubscriptionResponse, WebhookSubscriptionSecretResponse,
+};
 use crate::entity::common::{OAuthProvider, ReportReason, ReportStatus, ReportTargetType};
+use crate::service::auth::permission::{PermissionAction, PermissionResource};
 use crate::service::error::errors::ErrorResponse;
 use utoipa::openapi::security::{ApiKey, ApiKeyValue};
 use utoipa::{
@@ -116,6 +194,13 @@ use utoipa::{
         crate::api::v0::routes::auth::reset_password::reset_password,
         crate::api::v0::routes::auth::set_password::set_password,
         crate::api::v0::routes::auth::sign_in::sign_in,
+        crate::api::v0::routes::auth::sign_in_two_factor::sign_in_two_factor,
+        crate::api::v0::routes::auth::sign_in_two_factor::enroll_two_factor_at_sign_in,
+        crate::api::v0::routes::auth::two_factor::get_two_factor_status,
+        crate::api::v0::routes::auth::two_factor::enroll_two_factor,
+        crate::api::v0::routes::auth::two_factor::confirm_two_factor,
+        crate::api::v0::routes::auth::two_factor::disable_two_factor,
+        crate::api::v0::routes::auth::two_factor::regenerate_recovery_codes,
         crate::api::v0::routes::auth::sign_out::sign_out,
         crate::api::v0::routes::auth::sign_up::sign_up,
         crate::api::v0::routes::auth::unlink_oauth::unlink_oauth,
@@ -177,22 +262,30 @@ use utoipa::{
         crate::api::v0::routes::admin::sync_all_counts::sync_all_counts,
         crate::api::v0::routes::admin::cleanup_expired_tokens::cleanup_expired_tokens,
         crate::api::v0::routes::admin::cleanup_old_events::cleanup_old_events,
+        crate::api::v0::routes::admin::get_user_permissions::get_user_permissions,
+        crate::api::v0::routes::admin::update_user_permissions::update_user_permissions,
+        crate::api::v0::routes::admin::get_two_factor_policies::get_two_factor_policies,
+        crate::api::v0::routes::admin::update_two_factor_policy::update_two_factor_policy,
         // Office endpoints
         crate::api::v0::routes::office::handlers::create_office,
         crate::api::v0::routes::office::handlers::get_offices,
         crate::api::v0::routes::office::handlers::get_office,
         crate::api::v0::routes::office::handlers::update_office,
         crate::api::v0::routes::office::handlers::delete_office,
+        crate::api::v0::routes::office::handlers::get_office_capacity,
         // Server Room handlers
         crate::api::v0::routes::office::handlers::create_server_room,
         crate::api::v0::routes::office::handlers::get_server_rooms,
         crate::api::v0::routes::office::handlers::get_server_room_by_id,
         crate::api::v0::routes::office::handlers::update_server_room_by_id,
         crate::api::v0::routes::office::handlers::delete_server_room_by_id,
+        crate::api::v0::routes::office::handlers::get_server_room_capacity,
         // Rack handlers
         crate::api::v0::routes::rack::handlers::create_rack,
         crate::api::v0::routes::rack::handlers::get_racks,
         crate::api::v0::routes::rack::handlers::get_rack_by_id,
+        crate::api::v0::routes::rack::handlers::get_rack_elevation,
+        crate::api::v0::routes::rack::handlers::get_rack_capacity,
         crate::api::v0::routes::rack::handlers::delete_rack,
         // IP Range handlers
         crate::api::v0::routes::ip_range::handlers::create_ip_range,
@@ -200,6 +293,16 @@ use utoipa::{
         crate::api::v0::routes::ip_range::handlers::get_ip_range_by_id,
         crate::api::v0::routes::ip_range::handlers::update_ip_range,
         crate::api::v0::routes::ip_range::handlers::delete_ip_range,
+        crate::api::v0::routes::ip_range::handlers::get_ip_range_children,
+        crate::api::v0::routes::ip_range::handlers::allocate_ip_addresses,
+        crate::api::v0::routes::ip_range::handlers::get_free_blocks,
+        crate::api::v0::routes::ip_range::handlers::create_child_subnet,
+        crate::api::v0::routes::ip_range::handlers::get_dns_zones,
+        crate::api::v0::routes::ip_range::handlers::export_dns_zone,
+        crate::api::v0::routes::ip_range::handlers::diff_dns_zone,
+        // IP Address handlers
+        crate::api::v0::routes::ip_address::handlers::import_dhcp_leases,
+        crate::api::v0::routes::ip_address::handlers::sweep_dhcp_leases,
         // Device handlers
         crate::api::v0::routes::device::handlers::create_device,
         crate::api::v0::routes::device::handlers::get_devices,
@@ -221,10 +324,85 @@ use utoipa::{
         crate::api::v0::routes::contact::handlers::get_contact_by_id,
         crate::api::v0::routes::contact::handlers::update_contact,
         crate::api::v0::routes::contact::handlers::delete_contact,
+        // Audit log handlers
+        crate::api::v0::routes::audit::handlers::get_audit_logs,
+        crate::api::v0::routes::audit::handlers::get_office_history,
+        crate::api::v0::routes::audit::handlers::get_server_room_history,
+        crate::api::v0::routes::audit::handlers::get_rack_history,
+        crate::api::v0::routes::audit::handlers::get_device_history,
+        crate::api::v0::routes::audit::handlers::get_ip_range_history,
+        crate::api::v0::routes::audit::handlers::get_ip_address_history,
+        crate::api::v0::routes::audit::handlers::get_contact_history,
+        crate::api::v0::routes::audit::handlers::get_device_library_history,
+        // Bulk import/export handlers
+        crate::api::v0::routes::bulk_io::handlers::import_devices,
+        crate::api::v0::routes::bulk_io::handlers::export_devices,
+        crate::api::v0::routes::bulk_io::handlers::import_racks,
+        crate::api::v0::routes::bulk_io::handlers::export_racks,
+        crate::api::v0::routes::bulk_io::handlers::import_ip_addresses,
+        crate::api::v0::routes::bulk_io::handlers::export_ip_addresses,
+        crate::api::v0::routes::bulk_io::handlers::import_contacts,
+        crate::api::v0::routes::bulk_io::handlers::export_contacts,
+        // External API handlers
+        crate::api::v0::routes::external_api::handlers::get_connections,
+        crate::api::v0::routes::external_api::handlers::create_connection,
+        crate::api::v0::routes::external_api::handlers::get_connection,
+        crate::api::v0::routes::external_api::handlers::update_connection,
+        crate::api::v0::routes::external_api::handlers::delete_connection,
+        crate::api::v0::routes::external_api::handlers::sync_connection,
+        crate::api::v0::routes::external_api::handlers::get_sync_logs,
+        crate::api::v0::routes::external_api::handlers::get_synced_data,
+        crate::api::v0::routes::external_api::handlers::test_connection,
+        crate::api::v0::routes::external_api::handlers::get_sync_conflicts,
+        crate::api::v0::routes::external_api::handlers::accept_source,
+        crate::api::v0::routes::external_api::handlers::keep_local,
+        crate::api::v0::routes::external_api::handlers::get_field_overrides,
+        crate::api::v0::routes::external_api::handlers::delete_field_override,
         // Notification handlers
         crate::api::v0::routes::notification::handlers::create_notification,
         crate::api::v0::routes::notification::handlers::get_notifications,
         crate::api::v0::routes::notification::handlers::update_notification_status,
+        crate::api::v0::routes::notification::handlers::get_inbox,
+        crate::api::v0::routes::notification::handlers::get_unread_count,
+        crate::api::v0::routes::notification::handlers::mark_read,
+        crate::api::v0::routes::notification::handlers::mark_all_read,
+        crate::api::v0::routes::notification::handlers::get_preferences,
+        crate::api::v0::routes::notification::handlers::update_preferences,
+        crate::api::v0::routes::notification::handlers::get_resource_subscriptions,
+        crate::api::v0::routes::notification::handlers::subscribe_resource,
+        crate::api::v0::routes::notification::handlers::unsubscribe_resource,
+        // Realtime handlers
+        crate::api::v0::routes::realtime::handlers::stream_events,
+        crate::api::v0::routes::realtime::handlers::websocket,
+        // Webhook handlers
+        crate::api::v0::routes::webhook::handlers::get_subscriptions,
+        crate::api::v0::routes::webhook::handlers::create_subscription,
+        crate::api::v0::routes::webhook::handlers::get_event_types,
+        crate::api::v0::routes::webhook::handlers::get_subscription,
+        crate::api::v0::routes::webhook::handlers::update_subscription,
+        crate::api::v0::routes::webhook::handlers::delete_subscription,
+        crate::api::v0::routes::webhook::handlers::rotate_secret,
+        crate::api::v0::routes::webhook::handlers::send_ping,
+        crate::api::v0::routes::webhook::handlers::get_deliveries,
+        crate::api::v0::routes::webhook::handlers::get_delivery,
+        crate::api::v0::routes::webhook::handlers::redeliver,
+        // Tenant handlers
+        crate::api::v0::routes::tenant::handlers::get_my_tenants,
+        crate::api::v0::routes::tenant::handlers::create_tenant,
+        crate::api::v0::routes::tenant::handlers::update_tenant,
+        crate::api::v0::routes::tenant::handlers::issue_tenant_token,
+        crate::api::v0::routes::tenant::handlers::get_tenant_members,
+        crate::api::v0::routes::tenant::handlers::upsert_tenant_member,
+        crate::api::v0::routes::tenant::handlers::remove_tenant_member,
+        // API token handlers
+        crate::api::v0::routes::api_token::handlers::get_my_api_tokens,
+        crate::api::v0::routes::api_token::handlers::create_my_api_token,
+        crate::api::v0::routes::api_token::handlers::revoke_my_api_token,
+        crate::api::v0::routes::api_token::handlers::get_service_accounts,
+        crate::api::v0::routes::api_token::handlers::create_service_account,
+        crate::api::v0::routes::api_token::handlers::get_service_account_tokens,
+        crate::api::v0::routes::api_token::handlers::create_service_account_token,
+        crate::api::v0::routes::api_token::handlers::revoke_service_account_token,
         // Custodian endpoints
         crate::api::v0::routes::custodian::handlers::get_policies,
         crate::api::v0::routes::custodian::handlers::get_policy,
@@ -232,7 +410,10 @@ use utoipa::{
         crate::api::v0::routes::custodian::handlers::update_policy,
         crate::api::v0::routes::custodian::handlers::delete_policy,
         crate::api::v0::routes::custodian::handlers::execute_policy,
+        crate::api::v0::routes::custodian::handlers::update_policy_schedule,
+        crate::api::v0::routes::custodian::handlers::delete_policy_schedule,
         crate::api::v0::routes::custodian::handlers::get_execution_result,
+        crate::api::v0::routes::custodian::handlers::cancel_execution,
         crate::api::v0::routes::custodian::handlers::get_policy_executions,
         crate::api::v0::routes::custodian::handlers::validate_yaml
     ),
@@ -240,6 +421,19 @@ use utoipa::{
         schemas(
             AuthLoginRequest,
             AuthJWTResponse,
+            SignInResponse,
+            TwoFactorChallengeResponse,
+            TwoFactorSignInResponse,
+            TwoFactorSignInRequest,
+            TwoFactorChallengeRequest,
+            ConfirmTwoFactorRequest,
+            TwoFactorCodeRequest,
+            TwoFactorEnrollmentResponse,
+            TwoFactorRecoveryCodesResponse,
+            TwoFactorStatusResponse,
+            UpdateTwoFactorRolePolicyRequest,
+            TwoFactorRolePolicyResponse,
+            TwoFactorRolePolicyListResponse,
             ForgotPasswordRequest,
             ResendVerificationRequest,
             ResetPasswordRequest,
@@ -321,6 +515,11 @@ use utoipa::{
             // Admin schemas
             AdminStatusResponse,
             AdminTaskResponse,
+            UpdateUserPermissionsRequest,
+            UserPermissionsResponse,
+            ResourcePermission,
+            PermissionResource,
+            PermissionAction,
             // Office schemas
             CreateOfficeRequest,
             UpdateOfficeRequest,
@@ -337,12 +536,33 @@ use utoipa::{
             CreateRackRequest,
             RackInfoResponse,
             RackListResponse,
+            RackElevationResponse,
+            RackUnitSlot,
+            RackFreeGap,
+            RackUnplacedDevice,
+            CapacityRollupResponse,
             // IP Range schemas
             CreateIpRangeRequest,
             UpdateIpRangeRequest,
             ListIpRangesQuery,
             IpRangeResponse,
             IpRangeListResponse,
+            IpRangeTreeResponse,
+            AllocateIpRequest,
+            IpAllocationResponse,
+            FreeBlocksQuery,
+            FreeBlockResponse,
+            FreeBlockSummary,
+            FreeBlocksResponse,
+            CreateChildSubnetRequest,
+            DnsZoneQuery,
+            DnsZoneSummaryResponse,
+            DnsZoneListResponse,
+            DnsZoneDiffResponse,
+            // IP Address schemas
+            DhcpLeaseImportForm,
+            DhcpLeaseImportResponse,
+            DhcpLeaseSweepResponse,
             // Device schemas
             CreateDeviceRequest,
             UpdateDeviceRequest,
@@ -355,6 +575,98 @@ use utoipa::{
             UpdateLibraryRequest,
             LibraryInfoResponse,
             LibraryListResponse,
+            // Audit log schemas
+            ListAuditLogsQuery,
+            ResourceHistoryQuery,
+            AuditFieldChange,
+            AuditLogResponse,
+            AuditLogListResponse,
+            // Bulk import/export schemas
+            BulkImportQuery,
+            BulkImportForm,
+            DeviceExportQuery,
+            RackExportQuery,
+            IpAddressExportQuery,
+            BulkImportRowError,
+            BulkImportRowResult,
+            BulkImportResponse,
+            // External API schemas
+            ListConnectionsQuery,
+            SyncLogQuery,
+            SyncedDataQuery,
+            FieldMapping,
+            FieldMappingRule,
+            FilterCondition,
+            CreateExternalApiConnectionRequest,
+            UpdateExternalApiConnectionRequest,
+            TestExternalApiConnectionRequest,
+            ExternalApiConnectionResponse,
+            ExternalApiConnectionListResponse,
+            ExternalApiSyncLogResponse,
+            ExternalApiSyncLogListResponse,
+            ExternalApiDataResponse,
+            ExternalApiDataListResponse,
+            ExternalApiSyncItemError,
+            ExternalApiSyncResponse,
+            TestExternalApiConnectionResponse,
+            SyncConflictQuery,
+            SyncFieldOverrideQuery,
+            SyncConflictResponse,
+            SyncConflictListResponse,
+            SyncFieldOverrideResponse,
+            SyncFieldOverrideListResponse,
+            // Notification schemas
+            ListNotificationsQuery,
+            InboxQuery,
+            MarkAllReadQuery,
+            CreateNotificationRequest,
+            UpdateNotificationStatusRequest,
+            NotificationResponse,
+            NotificationListResponse,
+            InboxNotificationResponse,
+            InboxNotificationListResponse,
+            UnreadCountResponse,
+            MarkAllReadResponse,
+            NotificationPreferenceItem,
+            UpdateNotificationPreferencesRequest,
+            NotificationPreferenceResponse,
+            NotificationPreferenceListResponse,
+            CreateResourceSubscriptionRequest,
+            ResourceSubscriptionResponse,
+            ResourceSubscriptionListResponse,
+            // Realtime schemas
+            RealtimeQuery,
+            // Webhook schemas
+            WebhookSubscriptionQuery,
+            WebhookDeliveryQuery,
+            CreateWebhookSubscriptionRequest,
+            UpdateWebhookSubscriptionRequest,
+            WebhookSubscriptionResponse,
+            WebhookSubscriptionSecretResponse,
+            WebhookSubscriptionListResponse,
+            WebhookDeliveryResponse,
+            WebhookDeliveryListResponse,
+            WebhookDeliveryAttemptResponse,
+            WebhookDeliveryDetailResponse,
+            WebhookEventTypesResponse,
+            // Tenant schemas
+            CreateTenantRequest,
+            UpdateTenantRequest,
+            UpsertTenantMemberRequest,
+            TenantResponse,
+            MyTenantResponse,
+            MyTenantListResponse,
+            TenantMemberResponse,
+            TenantMemberListResponse,
+            TenantTokenResponse,
+            // API token schemas
+            CreateApiTokenRequest,
+            CreateServiceAccountRequest,
+            ApiTokenResponse,
+            ApiTokenSecretResponse,
+            ApiTokenListResponse,
+            ServiceAccountResponse,
+            ServiceAccountListResponse,
             // Contact schemas
             CreateContactRequest,
             UpdateContactRequest,
@@ -365,9 +677,11 @@ use utoipa::{
             crate::entity::custodian_executions::Model,
             CreatePolicyRequest,
             UpdatePolicyRequest,
+            UpdatePolicyScheduleRequest,
             ExecutePolicyRequest,
             ValidateYamlRequest,
             ValidateYamlResponse,
+            crate::service::custodian_validator::PolicyValidationIssue,
         )
     ),
     tags(
@@ -387,6 +701,14 @@ use utoipa::{
         (name = "IP Range", description = "IP range management endpoints"),
         (name = "Device", description = "Device management endpoints"),
         (name = "Device Library", description = "Device library management endpoints"),
+        (name = "Audit Log", description = "IPAM change history endpoints"),
+        (name = "Bulk Import/Export", description = "CSV import and export for devices, racks, IP addresses and contacts"),
+        (name = "External API", description = "External API connections, sync runs, synced data and sync conflict review"),
+        (name = "Notifications", description = "Per-user notification inbox, preferences, resource subscriptions and the admin outbox"),
+        (name = "Realtime", description = "WebSocket and SSE streams for notifications, IPAM changes and comment activity"),
+        (name = "Webhooks", description = "Outbound webhook subscriptions, signed deliveries and redelivery"),
+        (name = "Tenants", description = "Tenants, tenant membership and tenant-scoped access tokens"),
+        (name = "API Tokens", description = "Scoped personal access tokens and service accounts for automation"),
         (name = "custodian", description = "Cloud Custodian policy management endpoints")
     ),
     modifiers(&SecurityAddon) // 보안 스키마 등록
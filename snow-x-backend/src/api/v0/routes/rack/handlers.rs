@@ -1,15 +1,19 @@
 use crate::AppState;
-use crate::dto::auth::internal::access_token::AccessTokenClaims;
 use crate::dto::rack::request::create_rack::CreateRackRequest;
 use crate::dto::rack::request::update_rack::UpdateRackRequest;
+use crate::dto::rack::response::rack_capacity::CapacityRollupResponse;
+use crate::dto::rack::response::rack_elevation::{
+    RackElevationResponse, RackFreeGap, RackUnitSlot, RackUnplacedDevice,
+};
 use crate::dto::rack::response::rack_info::RackInfoResponse;
 use crate::dto::rack::response::rack_list::RackListResponse;
+use crate::middleware::permission::{Authorized, resource};
+use crate::service::error::errors::Errors;
 use crate::service::rack::{
-    service_create_rack, service_delete_rack, service_get_rack_by_id, service_get_racks,
-    service_update_rack,
+    service_create_rack, service_delete_rack, service_get_rack_by_id, service_get_rack_capacity,
+    service_get_rack_elevation, service_get_racks, service_update_rack,
 };
 use axum::{
-    Extension,
     extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
@@ -26,13 +30,20 @@ use uuid::Uuid;
         create_rack_direct,
         get_racks,
         get_rack_by_id,
+        get_rack_elevation,
+        get_rack_capacity,
         delete_rack
     ),
     components(schemas(
         CreateRackRequest,
         UpdateRackRequest,
         RackInfoResponse,
-        RackListResponse
+        RackListResponse,
+        RackElevationResponse,
+        RackUnitSlot,
+        RackFreeGap,
+        RackUnplacedDevice,
+        CapacityRollupResponse
     )),
     tags(
         (name = "Rack", description = "랙 관리 API")
@@ -57,29 +68,24 @@ pub struct RackQueryParams {
         (status = 201, description = "랙 생성 성공", body = RackInfoResponse),
         (status = 400, description = "잘못된 요청"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn create_rack_direct(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Rack>,
     Json(request): Json<CreateRackRequest>,
-) -> Result<(StatusCode, Json<RackInfoResponse>), (StatusCode, Json<serde_json::Value>)> {
+) -> Result<(StatusCode, Json<RackInfoResponse>), Errors> {
     // 실제 서버룸 ID 사용
     let server_room_id = request
         .server_room_id
         .unwrap_or_else(|| Uuid::parse_str("e0d147a1-8790-4112-923a-f790c1c1b326").unwrap());
+    auth.ensure_server_room(&state.conn, server_room_id).await?;
 
-    match service_create_rack(&state.conn, request, server_room_id, claims.sub).await {
-        Ok(rack) => Ok((StatusCode::CREATED, Json(rack))),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({
-                "error": format!("{:?}", e)
-            })),
-        )),
-    }
+    let rack = service_create_rack(&state.conn, request, server_room_id, auth.user_id()).await?;
+    Ok((StatusCode::CREATED, Json(rack)))
 }
 
 #[utoipa::path(
@@ -96,17 +102,18 @@ pub async fn create_rack_direct(
         (status = 201, description = "랙 생성 성공", body = RackInfoResponse),
         (status = 400, description = "잘못된 요청"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn create_rack(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Rack>,
     Path(server_room_id): Path<Uuid>,
     Json(request): Json<CreateRackRequest>,
 ) -> Result<(StatusCode, Json<RackInfoResponse>), (StatusCode, Json<serde_json::Value>)> {
-    match service_create_rack(&state.conn, request, server_room_id, claims.sub).await {
+    match service_create_rack(&state.conn, request, server_room_id, auth.user_id()).await {
         Ok(rack) => Ok((StatusCode::CREATED, Json(rack))),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -130,19 +137,20 @@ pub async fn create_rack(
     responses(
         (status = 200, description = "랙 목록 조회 성공", body = RackListResponse),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_racks(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Rack>,
     Query(params): Query<RackQueryParams>,
 ) -> Result<Json<RackListResponse>, (StatusCode, Json<serde_json::Value>)> {
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(20);
 
-    match service_get_racks(&state.conn, page, limit).await {
+    match service_get_racks(&state.conn, page, limit, &auth.scope).await {
         Ok(racks) => Ok(Json(racks)),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -166,13 +174,14 @@ pub async fn get_racks(
         (status = 200, description = "랙 조회 성공", body = RackInfoResponse),
         (status = 404, description = "랙을 찾을 수 없음"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn get_rack_by_id(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    _auth: Authorized<resource::Rack>,
     Path(rack_id): Path<Uuid>,
 ) -> Result<Json<RackInfoResponse>, (StatusCode, Json<serde_json::Value>)> {
     match service_get_rack_by_id(&state.conn, rack_id).await {
@@ -192,6 +201,60 @@ pub async fn get_rack_by_id(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/racks/{rack_id}/elevation",
+    tags = ["Rack"],
+    summary = "랙 실장도 조회",
+    description = "U 슬롯별 점유 장비와 연속된 빈 공간을 조회합니다 (장비 배치 계획용)",
+    params(
+        ("rack_id" = Uuid, Path, description = "랙 ID")
+    ),
+    responses(
+        (status = 200, description = "랙 실장도 조회 성공", body = RackElevationResponse),
+        (status = 404, description = "랙을 찾을 수 없음"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 500, description = "서버 오류")
+    ),
+    security(("Bearer" = []))
+)]
+pub async fn get_rack_elevation(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::Rack>,
+    Path(rack_id): Path<Uuid>,
+) -> Result<Json<RackElevationResponse>, Errors> {
+    let elevation = service_get_rack_elevation(&state.conn, rack_id).await?;
+    Ok(Json(elevation))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v0/ipam/racks/{rack_id}/capacity",
+    tags = ["Rack"],
+    summary = "랙 용량 조회",
+    description = "랙의 전력(W)·공간(U) 사용량과 장비 유형별 개수를 조회합니다",
+    params(
+        ("rack_id" = Uuid, Path, description = "랙 ID")
+    ),
+    responses(
+        (status = 200, description = "랙 용량 조회 성공", body = CapacityRollupResponse),
+        (status = 404, description = "랙을 찾을 수 없음"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
+        (status = 500, description = "서버 오류")
+    ),
+    security(("Bearer" = []))
+)]
+pub async fn get_rack_capacity(
+    State(state): State<AppState>,
+    _auth: Authorized<resource::Rack>,
+    Path(rack_id): Path<Uuid>,
+) -> Result<Json<CapacityRollupResponse>, Errors> {
+    let capacity = service_get_rack_capacity(&state.conn, rack_id).await?;
+    Ok(Json(capacity))
+}
+
 #[utoipa::path(
     put,
     path = "/v0/ipam/racks/{rack_id}",
@@ -206,26 +269,21 @@ pub async fn get_rack_by_id(
         (status = 200, description = "수정된 랙 정보", body = RackInfoResponse),
         (status = 400, description = "잘못된 요청"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 404, description = "랙을 찾을 수 없음"),
+        (status = 409, description = "배치된 장비보다 낮은 높이로 변경"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn update_rack(
     State(state): State<AppState>,
-    Extension(claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Rack>,
     Path(rack_id): Path<Uuid>,
     Json(request): Json<UpdateRackRequest>,
-) -> Result<(StatusCode, Json<RackInfoResponse>), (StatusCode, Json<serde_json::Value>)> {
-    match service_update_rack(&state.conn, rack_id, request, claims.sub).await {
-        Ok(rack) => Ok((StatusCode::OK, Json(rack))),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({
-                "error": format!("{:?}", e)
-            })),
-        )),
-    }
+) -> Result<(StatusCode, Json<RackInfoResponse>), Errors> {
+    let rack = service_update_rack(&state.conn, rack_id, request, auth.user_id()).await?;
+    Ok((StatusCode::OK, Json(rack)))
 }
 
 #[utoipa::path(
@@ -240,16 +298,17 @@ pub async fn update_rack(
     responses(
         (status = 204, description = "랙 삭제 성공"),
         (status = 401, description = "인증 필요"),
+        (status = 403, description = "권한 없음"),
         (status = 500, description = "서버 오류")
     ),
     security(("Bearer" = []))
 )]
 pub async fn delete_rack(
     State(state): State<AppState>,
-    Extension(_claims): Extension<AccessTokenClaims>,
+    auth: Authorized<resource::Rack>,
     Path(rack_id): Path<Uuid>,
 ) -> Result<StatusCode, (StatusCode, Json<serde_json::Value>)> {
-    match service_delete_rack(&state.conn, rack_id).await {
+    match service_delete_rack(&state.conn, rack_id, auth.user_id()).await {
         Ok(_) => Ok(StatusCode::NO_CONTENT),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -1,5 +1,6 @@
 use crate::api::v0::routes::rack::handlers::{
-    create_rack, create_rack_direct, delete_rack, get_rack_by_id, get_racks, update_rack,
+    create_rack, create_rack_direct, delete_rack, get_rack_by_id, get_rack_capacity,
+    get_rack_elevation, get_racks, update_rack,
 };
 use crate::middleware::auth::access_jwt_auth;
 use axum::{Router, routing::get};
@@ -19,6 +20,14 @@ pub fn create_rack_routes() -> Router<crate::AppState> {
                 .delete(delete_rack)
                 .route_layer(axum::middleware::from_fn(access_jwt_auth)),
         )
+        .route(
+            "/{rack_id}/capacity",
+            get(get_rack_capacity).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/{rack_id}/elevation",
+            get(get_rack_elevation).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+        )
         .nest(
             "/server-rooms/{server_room_id}/racks",
             Router::new().route(
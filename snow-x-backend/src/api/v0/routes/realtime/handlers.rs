@@ -0,0 +1,168 @@
+use axum::{
+    Extension,
+    extract::{
+        Query, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::{
+        IntoResponse,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use futures_util::stream::{self, Stream};
+use serde::Deserialize;
+use serde_json::json;
+use std::{convert::Infallible, sync::Arc, time::Duration};
+use tokio::sync::broadcast::{Receiver, error::RecvError};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    dto::auth::internal::access_token::AccessTokenClaims,
+    service::error::errors::Errors,
+    service::realtime::hub::RealtimeMessage,
+    service::realtime::session::{EVENT_RESYNC, RealtimeSession, parse_post_ids},
+    service::tenant::membership::get_active_memberships,
+    state::AppState,
+};
+
+/// 연결이 살아 있는지 확인하는 간격
+const WEBSOCKET_PING_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct RealtimeQuery {
+    /// 댓글 활동을 받을 포스트 ID (쉼표로 구분, 최대 20개)
+    pub posts: Option<String>,
+    /// `Authorization` 헤더를 붙일 수 없는 브라우저용 액세스 토큰
+    #[allow(dead_code)] // stream_jwt_auth 미들웨어가 직접 읽는다
+    pub access_token: Option<String>,
+}
+
+/// 연결할 때 속한 활성 테넌트의 IPAM 토픽을 받는 세션을 만든다
+async fn open_session(
+    state: &AppState,
+    claims: &AccessTokenClaims,
+    query: &RealtimeQuery,
+) -> Result<RealtimeSession, Errors> {
+    let tenant_ids = get_active_memberships(&state.conn, claims.sub)
+        .await?
+        .into_iter()
+        .map(|(membership, _)| membership.tenant_id);
+    RealtimeSession::new(
+        claims.sub,
+        tenant_ids,
+        parse_post_ids(query.posts.as_deref())?,
+    )
+}
+
+fn resync_message(missed: u64) -> String {
+    json!({ "event": EVENT_RESYNC, "data": { "missed": missed } }).to_string()
+}
+
+/// 이 연결이 받을 다음 메시지. 연결이 받지 않는 토픽은 건너뛴다.
+/// 밀려서 놓친 메시지가 있으면 resync 안내를 대신 돌려준다.
+async fn next_message(
+    receiver: &mut Receiver<Arc<RealtimeMessage>>,
+    session: &RealtimeSession,
+) -> Option<(String, String)> {
+    loop {
+        match receiver.recv().await {
+            Ok(message) if session.wants(&message) => {
+                return Some((message.event.clone(), message.payload.clone()));
+            }
+            Ok(_) => {}
+            Err(RecvError::Lagged(missed)) => {
+                return Some((EVENT_RESYNC.to_string(), resync_message(missed)));
+            }
+            Err(RecvError::Closed) => return None,
+        }
+    }
+}
+
+/// 새 알림, 속한 테넌트의 IPAM 변경, 보고 있는 포스트의 댓글 활동을 SSE로 받습니다.
+///
+/// 이벤트 이름은 `event` 필드에, 본문(`topic`, `event`, `data`, `published_at`)은 `data`에 담깁니다.
+#[utoipa::path(
+    get,
+    path = "/v0/realtime/sse",
+    tag = "Realtime",
+    params(RealtimeQuery),
+    responses(
+        (status = 200, description = "이벤트 스트림", content_type = "text/event-stream"),
+        (status = 400, description = "잘못된 포스트 ID"),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn stream_events(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Query(query): Query<RealtimeQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, Errors> {
+    let session = open_session(&state, &claims, &query).await?;
+    let receiver = state.realtime.subscribe();
+
+    let events = stream::unfold((receiver, session), |(mut receiver, session)| async move {
+        let (event, payload) = next_message(&mut receiver, &session).await?;
+        let event = Event::default().event(event).data(payload);
+        Some((Ok(event), (receiver, session)))
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// 새 알림, 속한 테넌트의 IPAM 변경, 보고 있는 포스트의 댓글 활동을 WebSocket으로 받습니다.
+///
+/// 보고 있는 포스트는 `{"action":"watch_post","post_id":"..."}`,
+/// `{"action":"unwatch_post","post_id":"..."}` 메시지로 바꿀 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/v0/realtime/ws",
+    tag = "Realtime",
+    params(RealtimeQuery),
+    responses(
+        (status = 101, description = "WebSocket 연결"),
+        (status = 400, description = "잘못된 포스트 ID"),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn websocket(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Query(query): Query<RealtimeQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    let session = open_session(&state, &claims, &query).await?;
+    let receiver = state.realtime.subscribe();
+    Ok(ws.on_upgrade(move |socket| run_websocket(socket, receiver, session)))
+}
+
+async fn run_websocket(
+    mut socket: WebSocket,
+    mut receiver: Receiver<Arc<RealtimeMessage>>,
+    mut session: RealtimeSession,
+) {
+    let mut ping = tokio::time::interval(WEBSOCKET_PING_INTERVAL);
+    ping.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        let outgoing = tokio::select! {
+            message = next_message(&mut receiver, &session) => match message {
+                Some((_, payload)) => Message::Text(payload.into()),
+                None => break,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    Message::Text(session.handle_command(text.as_str()).to_string().into())
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+            _ = ping.tick() => Message::Ping(Default::default()),
+        };
+
+        if socket.send(outgoing).await.is_err() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use axum::{Router, middleware, routing::get};
+
+use crate::middleware::auth::stream_jwt_auth;
+
+use super::handlers::{stream_events, websocket};
+
+/// 실시간 이벤트 스트림 (`/v0/realtime` 아래에 중첩)
+pub fn realtime_routes() -> Router<crate::AppState> {
+    Router::new()
+        .route("/ws", get(websocket))
+        .route("/sse", get(stream_events))
+        .route_layer(middleware::from_fn(stream_jwt_auth))
+}
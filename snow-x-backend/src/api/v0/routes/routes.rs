@@ -1,12 +1,16 @@
 use super::openapi::ApiDoc;
 use crate::api::v0::routes::admin::routes::admin_routes;
+use crate::api::v0::routes::api_token::routes::{api_token_routes, service_account_routes};
+use crate::api::v0::routes::audit::routes::audit_routes;
 use crate::api::v0::routes::auth::routes::auth_routes;
+use crate::api::v0::routes::bulk_io::routes::bulk_io_routes;
 use crate::api::v0::routes::comment::routes::comment_routes;
 use crate::api::v0::routes::contact::routes::create_contact_routes;
 use crate::api::v0::routes::custodian::routes::create_custodian_routes;
 use crate::api::v0::routes::device::routes::create_device_routes;
 use crate::api::v0::routes::device_library::routes::create_device_library_routes;
 use crate::api::v0::routes::draft::routes::draft_routes;
+use crate::api::v0::routes::external_api::routes::external_api_routes;
 use crate::api::v0::routes::follow::routes::follow_routes;
 use crate::api::v0::routes::hashtag::routes::hashtag_routes;
 use crate::api::v0::routes::ip_address::routes::ip_address_routes;
@@ -16,8 +20,11 @@ use crate::api::v0::routes::notification::routes::notification_routes;
 use crate::api::v0::routes::office::routes::office_routes;
 use crate::api::v0::routes::post::routes::post_routes;
 use crate::api::v0::routes::rack::routes::create_rack_routes;
+use crate::api::v0::routes::realtime::routes::realtime_routes;
 use crate::api::v0::routes::report::routes::report_routes;
+use crate::api::v0::routes::tenant::routes::tenant_routes;
 use crate::api::v0::routes::user::routes::user_routes;
+use crate::api::v0::routes::webhook::routes::webhook_routes;
 use crate::service::error::errors::handler_404;
 use crate::state::AppState;
 use axum::Router;
@@ -87,10 +94,39 @@ pub fn api_routes() -> Router<AppState> {
     router = router.nest("/v0/ipam/contact", create_contact_routes());
     println!("DEBUG: Contact routes added successfully");
 
+    println!("DEBUG: Adding audit log routes");
+    router = router.nest("/v0/ipam", audit_routes());
+    println!("DEBUG: Audit log routes added successfully");
+
+    println!("DEBUG: Adding bulk import/export routes");
+    router = router.nest("/v0/ipam", bulk_io_routes());
+    println!("DEBUG: Bulk import/export routes added successfully");
+
+    println!("DEBUG: Adding external API routes");
+    router = router.nest("/v0/ipam/external-api", external_api_routes());
+    println!("DEBUG: External API routes added successfully");
+
     println!("DEBUG: Adding notification routes");
     router = router.nest("/v0", notification_routes());
     println!("DEBUG: Notification routes added successfully");
 
+    println!("DEBUG: Adding webhook routes");
+    router = router.nest("/v0/webhooks", webhook_routes());
+    println!("DEBUG: Webhook routes added successfully");
+
+    println!("DEBUG: Adding tenant routes");
+    router = router.nest("/v0/tenants", tenant_routes());
+    println!("DEBUG: Tenant routes added successfully");
+
+    println!("DEBUG: Adding API token routes");
+    router = router.nest("/v0/api-tokens", api_token_routes());
+    router = router.nest("/v0/service-accounts", service_account_routes());
+    println!("DEBUG: API token routes added successfully");
+
+    println!("DEBUG: Adding realtime routes");
+    router = router.nest("/v0/realtime", realtime_routes());
+    println!("DEBUG: Realtime routes added successfully");
+
     println!("DEBUG: Adding custodian routes");
     router = router.nest("/v0/custodian", create_custodian_routes());
     println!("DEBUG: Custodian routes added successfully");
@@ -0,0 +1,191 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    dto::auth::internal::access_token::AccessTokenClaims,
+    dto::tenant::request::{CreateTenantRequest, UpdateTenantRequest, UpsertTenantMemberRequest},
+    dto::tenant::response::{
+        MyTenantListResponse, TenantMemberListResponse, TenantMemberResponse, TenantResponse,
+        TenantTokenResponse,
+    },
+    service::error::errors::Errors,
+    service::tenant::{
+        service_create_tenant, service_get_my_tenants, service_get_tenant_members,
+        service_issue_tenant_token, service_remove_tenant_member, service_update_tenant,
+        service_upsert_tenant_member,
+    },
+    state::AppState,
+};
+
+/// 내가 속한 활성 테넌트와 역할을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/tenants",
+    tag = "Tenants",
+    responses(
+        (status = 200, description = "내 테넌트 목록", body = MyTenantListResponse),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_my_tenants(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_my_tenants(&state.conn, claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// 테넌트를 만듭니다. 전체 Admin만 만들 수 있고, 만든 사람은 이 테넌트의 Admin이 됩니다.
+#[utoipa::path(
+    post,
+    path = "/v0/tenants",
+    tag = "Tenants",
+    request_body = CreateTenantRequest,
+    responses(
+        (status = 201, description = "테넌트 생성", body = TenantResponse),
+        (status = 400, description = "잘못된 이름"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn create_tenant(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Json(request): Json<CreateTenantRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_create_tenant(&state.conn, claims.sub, request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 테넌트 정보를 수정합니다. 활성 여부는 전체 Admin만 바꿀 수 있습니다.
+#[utoipa::path(
+    put,
+    path = "/v0/tenants/{tenant_id}",
+    tag = "Tenants",
+    params(("tenant_id" = Uuid, Path, description = "테넌트 ID")),
+    request_body = UpdateTenantRequest,
+    responses(
+        (status = 200, description = "테넌트 수정", body = TenantResponse),
+        (status = 400, description = "잘못된 이름"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 Admin 권한 필요"),
+        (status = 404, description = "테넌트 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn update_tenant(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path(tenant_id): Path<Uuid>,
+    Json(request): Json<UpdateTenantRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_update_tenant(&state.conn, claims.sub, tenant_id, request).await?;
+    Ok(Json(response))
+}
+
+/// 테넌트가 정해진 액세스 토큰을 발급합니다. `X-Tenant-Id` 헤더 없이 이 테넌트로 요청할 때 씁니다.
+#[utoipa::path(
+    post,
+    path = "/v0/tenants/{tenant_id}/token",
+    tag = "Tenants",
+    params(("tenant_id" = Uuid, Path, description = "테넌트 ID")),
+    responses(
+        (status = 200, description = "테넌트 액세스 토큰", body = TenantTokenResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아님")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn issue_tenant_token(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_issue_tenant_token(&state.conn, claims.sub, tenant_id).await?;
+    Ok(Json(response))
+}
+
+/// 테넌트 구성원과 역할을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/tenants/{tenant_id}/members",
+    tag = "Tenants",
+    params(("tenant_id" = Uuid, Path, description = "테넌트 ID")),
+    responses(
+        (status = 200, description = "테넌트 구성원 목록", body = TenantMemberListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 Admin 권한 필요"),
+        (status = 404, description = "테넌트 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_tenant_members(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_tenant_members(&state.conn, claims.sub, tenant_id).await?;
+    Ok(Json(response))
+}
+
+/// 사용자를 테넌트에 추가하거나 테넌트 안에서의 역할을 바꿉니다.
+#[utoipa::path(
+    put,
+    path = "/v0/tenants/{tenant_id}/members/{user_id}",
+    tag = "Tenants",
+    params(
+        ("tenant_id" = Uuid, Path, description = "테넌트 ID"),
+        ("user_id" = Uuid, Path, description = "사용자 ID")
+    ),
+    request_body = UpsertTenantMemberRequest,
+    responses(
+        (status = 200, description = "테넌트 구성원", body = TenantMemberResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 Admin 권한 필요"),
+        (status = 404, description = "테넌트 또는 사용자 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn upsert_tenant_member(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path((tenant_id, user_id)): Path<(Uuid, Uuid)>,
+    Json(request): Json<UpsertTenantMemberRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response =
+        service_upsert_tenant_member(&state.conn, claims.sub, tenant_id, user_id, request).await?;
+    Ok(Json(response))
+}
+
+/// 사용자를 테넌트에서 뺍니다.
+#[utoipa::path(
+    delete,
+    path = "/v0/tenants/{tenant_id}/members/{user_id}",
+    tag = "Tenants",
+    params(
+        ("tenant_id" = Uuid, Path, description = "테넌트 ID"),
+        ("user_id" = Uuid, Path, description = "사용자 ID")
+    ),
+    responses(
+        (status = 204, description = "구성원 제거"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 Admin 권한 필요"),
+        (status = 404, description = "테넌트 또는 구성원 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn remove_tenant_member(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    Path((tenant_id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, Errors> {
+    service_remove_tenant_member(&state.conn, claims.sub, tenant_id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
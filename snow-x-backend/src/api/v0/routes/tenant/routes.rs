@@ -0,0 +1,25 @@
+use axum::{
+    Router, middleware,
+    routing::{get, post, put},
+};
+
+use crate::middleware::auth::access_jwt_auth;
+
+use super::handlers::{
+    create_tenant, get_my_tenants, get_tenant_members, issue_tenant_token, remove_tenant_member,
+    update_tenant, upsert_tenant_member,
+};
+
+/// 테넌트와 테넌트 구성원 (`/v0/tenants` 아래에 중첩)
+pub fn tenant_routes() -> Router<crate::AppState> {
+    Router::new()
+        .route("/", get(get_my_tenants).post(create_tenant))
+        .route("/{tenant_id}", put(update_tenant))
+        .route("/{tenant_id}/token", post(issue_tenant_token))
+        .route("/{tenant_id}/members", get(get_tenant_members))
+        .route(
+            "/{tenant_id}/members/{user_id}",
+            put(upsert_tenant_member).delete(remove_tenant_member),
+        )
+        .route_layer(middleware::from_fn(access_jwt_auth))
+}
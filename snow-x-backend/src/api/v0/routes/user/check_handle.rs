@@ -14,6 +14,7 @@ use tracing::info;
     responses(
         (status = StatusCode::OK, description = "Handle availability check result", body = HandleCheckResponse),
         (status = StatusCode::BAD_REQUEST, description = "Invalid input"),
+        (status = StatusCode::TOO_MANY_REQUESTS, description = "Too many requests: rate_limit:exceeded (see Retry-After)"),
         (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
     tag = "User"
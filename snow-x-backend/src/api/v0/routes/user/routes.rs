@@ -5,13 +5,17 @@ use crate::api::v0::routes::user::update_profile::update_profile;
 use crate::api::v0::routes::user::upload_avatar::upload_avatar;
 use crate::api::v0::routes::user::upload_banner::upload_banner;
 use crate::middleware::auth::access_jwt_auth;
+use crate::middleware::rate_limit::rate_limit;
 use crate::state::AppState;
 use axum::Router;
 use axum::routing::{get, post, put};
 
 pub fn user_routes() -> Router<AppState> {
     Router::new()
-        .route("/user/check-handle", post(check_handle_availability))
+        .route(
+            "/user/check-handle",
+            post(check_handle_availability).route_layer(axum::middleware::from_fn(rate_limit)),
+        )
         .route("/user/profile", post(get_profile))
         // 보호된 사용자 프로필 API
         .route(
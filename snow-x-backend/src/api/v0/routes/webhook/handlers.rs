@@ -0,0 +1,346 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    dto::auth::internal::access_token::AccessTokenClaims,
+    dto::webhook::request::{CreateWebhookSubscriptionRequest, UpdateWebhookSubscriptionRequest},
+    dto::webhook::response::{
+        WebhookDeliveryDetailResponse, WebhookDeliveryListResponse, WebhookDeliveryResponse,
+        WebhookEventTypesResponse, WebhookSubscriptionListResponse, WebhookSubscriptionResponse,
+        WebhookSubscriptionSecretResponse,
+    },
+    middleware::permission::TenantContext,
+    service::error::errors::Errors,
+    service::webhook::{
+        EVENT_TYPES, service_create_subscription, service_delete_subscription,
+        service_get_deliveries, service_get_delivery, service_get_subscription,
+        service_get_subscriptions, service_redeliver, service_rotate_secret, service_send_ping,
+        service_update_subscription,
+    },
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct WebhookSubscriptionQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct WebhookDeliveryQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    /// pending, success, failed
+    pub status: Option<String>,
+}
+
+/// 내 웹훅 구독 목록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/webhooks",
+    tag = "Webhooks",
+    params(WebhookSubscriptionQuery),
+    responses(
+        (status = 200, description = "웹훅 구독 목록", body = WebhookSubscriptionListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_subscriptions(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Query(query): Query<WebhookSubscriptionQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_subscriptions(
+        &state.conn,
+        claims.sub,
+        tenant.scope.tenant_id,
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 웹훅 구독을 만듭니다. 서명 키는 이 응답에서만 확인할 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/v0/webhooks",
+    tag = "Webhooks",
+    request_body = CreateWebhookSubscriptionRequest,
+    responses(
+        (status = 201, description = "웹훅 구독 생성", body = WebhookSubscriptionSecretResponse),
+        (status = 400, description = "잘못된 주소 또는 이벤트 종류"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn create_subscription(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Json(request): Json<CreateWebhookSubscriptionRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response =
+        service_create_subscription(&state.conn, claims.sub, &tenant.scope, request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 구독할 수 있는 이벤트 종류를 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/webhooks/event-types",
+    tag = "Webhooks",
+    responses(
+        (status = 200, description = "이벤트 종류", body = WebhookEventTypesResponse),
+        (status = 401, description = "인증 필요")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_event_types(
+    Extension(_claims): Extension<AccessTokenClaims>,
+) -> Result<impl IntoResponse, Errors> {
+    Ok(Json(WebhookEventTypesResponse {
+        event_types: EVENT_TYPES.iter().map(|t| t.to_string()).collect(),
+    }))
+}
+
+/// 웹훅 구독을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/webhooks/{id}",
+    tag = "Webhooks",
+    params(("id" = Uuid, Path, description = "구독 ID")),
+    responses(
+        (status = 200, description = "웹훅 구독", body = WebhookSubscriptionResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음"),
+        (status = 404, description = "구독 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_subscription(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response =
+        service_get_subscription(&state.conn, claims.sub, tenant.scope.tenant_id, id).await?;
+    Ok(Json(response))
+}
+
+/// 웹훅 구독을 수정합니다.
+#[utoipa::path(
+    put,
+    path = "/v0/webhooks/{id}",
+    tag = "Webhooks",
+    params(("id" = Uuid, Path, description = "구독 ID")),
+    request_body = UpdateWebhookSubscriptionRequest,
+    responses(
+        (status = 200, description = "웹훅 구독 수정", body = WebhookSubscriptionResponse),
+        (status = 400, description = "잘못된 주소 또는 이벤트 종류"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음"),
+        (status = 404, description = "구독 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn update_subscription(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateWebhookSubscriptionRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response =
+        service_update_subscription(&state.conn, claims.sub, &tenant.scope, id, request).await?;
+    Ok(Json(response))
+}
+
+/// 웹훅 구독과 배달 기록을 삭제합니다.
+#[utoipa::path(
+    delete,
+    path = "/v0/webhooks/{id}",
+    tag = "Webhooks",
+    params(("id" = Uuid, Path, description = "구독 ID")),
+    responses(
+        (status = 204, description = "웹훅 구독 삭제"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음"),
+        (status = 404, description = "구독 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn delete_subscription(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    service_delete_subscription(&state.conn, claims.sub, tenant.scope.tenant_id, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 서명 키를 새로 발급합니다. 이후 배달은 새 키로 서명됩니다.
+#[utoipa::path(
+    post,
+    path = "/v0/webhooks/{id}/rotate-secret",
+    tag = "Webhooks",
+    params(("id" = Uuid, Path, description = "구독 ID")),
+    responses(
+        (status = 200, description = "새 서명 키", body = WebhookSubscriptionSecretResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음"),
+        (status = 404, description = "구독 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn rotate_secret(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response =
+        service_rotate_secret(&state.conn, claims.sub, tenant.scope.tenant_id, id).await?;
+    Ok(Json(response))
+}
+
+/// 구독 주소로 ping 이벤트를 보냅니다.
+#[utoipa::path(
+    post,
+    path = "/v0/webhooks/{id}/ping",
+    tag = "Webhooks",
+    params(("id" = Uuid, Path, description = "구독 ID")),
+    responses(
+        (status = 202, description = "ping 배달 예약", body = WebhookDeliveryResponse),
+        (status = 400, description = "비활성 구독"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음"),
+        (status = 404, description = "구독 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn send_ping(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_send_ping(&state.conn, claims.sub, tenant.scope.tenant_id, id).await?;
+    Ok((StatusCode::ACCEPTED, Json(response)))
+}
+
+/// 구독의 배달 기록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/webhooks/{id}/deliveries",
+    tag = "Webhooks",
+    params(("id" = Uuid, Path, description = "구독 ID"), WebhookDeliveryQuery),
+    responses(
+        (status = 200, description = "배달 목록", body = WebhookDeliveryListResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음"),
+        (status = 404, description = "구독 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_deliveries(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Path(id): Path<Uuid>,
+    Query(query): Query<WebhookDeliveryQuery>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_deliveries(
+        &state.conn,
+        claims.sub,
+        tenant.scope.tenant_id,
+        id,
+        query.status,
+        query.page.unwrap_or(1),
+        query.limit.unwrap_or(20),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 보낸 본문과 시도 기록을 포함한 배달 상세를 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/v0/webhooks/{id}/deliveries/{delivery_id}",
+    tag = "Webhooks",
+    params(
+        ("id" = Uuid, Path, description = "구독 ID"),
+        ("delivery_id" = Uuid, Path, description = "배달 ID")
+    ),
+    responses(
+        (status = 200, description = "배달 상세", body = WebhookDeliveryDetailResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음"),
+        (status = 404, description = "구독 또는 배달 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn get_delivery(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Path((id, delivery_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_delivery(
+        &state.conn,
+        claims.sub,
+        tenant.scope.tenant_id,
+        id,
+        delivery_id,
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// 끝난 배달을 같은 본문으로 다시 보냅니다.
+#[utoipa::path(
+    post,
+    path = "/v0/webhooks/{id}/deliveries/{delivery_id}/redeliver",
+    tag = "Webhooks",
+    params(
+        ("id" = Uuid, Path, description = "구독 ID"),
+        ("delivery_id" = Uuid, Path, description = "배달 ID")
+    ),
+    responses(
+        (status = 202, description = "재배달 예약", body = WebhookDeliveryResponse),
+        (status = 400, description = "아직 보내는 중이거나 비활성 구독"),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "테넌트 구성원이 아니거나 권한 없음"),
+        (status = 404, description = "구독 또는 배달 없음")
+    ),
+    security(("bearer" = []))
+)]
+pub async fn redeliver(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    tenant: TenantContext,
+    Path((id, delivery_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_redeliver(
+        &state.conn,
+        claims.sub,
+        tenant.scope.tenant_id,
+        id,
+        delivery_id,
+    )
+    .await?;
+    Ok((StatusCode::ACCEPTED, Json(response)))
+}
@@ -0,0 +1,30 @@
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::middleware::auth::access_jwt_auth;
+
+use super::handlers::{
+    create_subscription, delete_subscription, get_deliveries, get_delivery, get_event_types,
+    get_subscription, get_subscriptions, redeliver, rotate_secret, send_ping, update_subscription,
+};
+
+/// 웹훅 구독과 배달 기록 (`/v0/webhooks` 아래에 중첩)
+pub fn webhook_routes() -> Router<crate::AppState> {
+    Router::new()
+        .route("/", get(get_subscriptions).post(create_subscription))
+        .route("/event-types", get(get_event_types))
+        .route(
+            "/{id}",
+            get(get_subscription)
+                .put(update_subscription)
+                .delete(delete_subscription),
+        )
+        .route("/{id}/rotate-secret", post(rotate_secret))
+        .route("/{id}/ping", post(send_ping))
+        .route("/{id}/deliveries", get(get_deliveries))
+        .route("/{id}/deliveries/{delivery_id}", get(get_delivery))
+        .route("/{id}/deliveries/{delivery_id}/redeliver", post(redeliver))
+        .route_layer(middleware::from_fn(access_jwt_auth))
+}
@@ -1,9 +1,43 @@
+use crate::utils::extract_ip_address::parse_trusted_proxies;
+use crate::utils::ip_math::IpNetwork;
 use axum::http::{HeaderName, HeaderValue};
 use dotenvy::dotenv;
 use std::env;
 use std::sync::LazyLock;
 use tracing::warn;
 
+/// `횟수/초` 형식의 요청 제한. `10/60`이면 60초에 10번까지 받는다 (횟수가 0이면 제한 없음)
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitRule {
+    pub limit: u64,
+    pub window: u64,
+}
+
+impl RateLimitRule {
+    const fn new(limit: u64, window: u64) -> Self {
+        Self { limit, window }
+    }
+
+    fn from_env(name: &str, default: RateLimitRule) -> Self {
+        let Ok(value) = env::var(name) else {
+            return default;
+        };
+        let parsed = value
+            .split_once('/')
+            .and_then(|(limit, window)| {
+                Some((limit.trim().parse().ok()?, window.trim().parse().ok()?))
+            })
+            .filter(|(_, window)| *window > 0);
+        match parsed {
+            Some((limit, window)) => Self { limit, window },
+            None => {
+                warn!("Invalid {} '{}'; expected <count>/<seconds>", name, value);
+                default
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DbConfig {
     pub is_dev: bool,
@@ -13,6 +47,8 @@ pub struct DbConfig {
     pub auth_refresh_token_expire_time: i64,
     pub auth_email_verification_token_expire_time: i64,
     pub auth_password_reset_token_expire_time: i64,
+    pub auth_two_factor_challenge_expire_time: i64,
+    pub auth_two_factor_issuer: String,
 
     // Google
     pub google_client_id: String,
@@ -67,6 +103,66 @@ pub struct DbConfig {
     pub meilisearch_host: String,
     pub meilisearch_api_key: Option<String>,
 
+    // DHCP Lease
+    pub dhcp_lease_watch_paths: Vec<String>,
+    pub dhcp_lease_watch_interval: u64,
+    pub dhcp_lease_sweep_interval: u64,
+
+    // DNS Zone
+    pub dns_zone_default_domain: Option<String>,
+    pub dns_zone_primary_ns: Option<String>,
+    pub dns_zone_hostmaster: Option<String>,
+    pub dns_zone_ttl: u32,
+
+    // Rack
+    pub rack_power_budget_reject: bool,
+
+    // External API 자동 동기화
+    pub external_api_sync_poll_interval: u64,
+    pub external_api_sync_concurrency: usize,
+    pub external_api_sync_retry_base: u64,
+    pub external_api_sync_max_backoff: u64,
+    pub external_api_allow_private_urls: bool,
+
+    // Custodian 실행
+    pub custodian_scheduler_poll_interval: u64,
+    pub custodian_reconcile_interval: u64,
+    pub custodian_execution_timeout: u64,
+
+    // 알림 발송
+    pub notification_dispatch_poll_interval: u64,
+    pub notification_dispatch_batch: u64,
+    pub notification_retry_base: u64,
+    pub notification_max_backoff: u64,
+    pub notification_processing_timeout: u64,
+    pub notification_webhook_url: Option<String>,
+    pub notification_digest_interval: u64,
+    pub notification_link_base_url: Option<String>,
+
+    // 웹훅 구독
+    pub webhook_max_retries: i32,
+    pub webhook_allow_private_urls: bool,
+
+    // 자동 작업이 만든 데이터의 작성자
+    pub system_user_id: Option<uuid::Uuid>,
+    pub system_user_name: String,
+    pub system_user_handle: String,
+    pub system_user_email: String,
+
+    // 인증 요청 제한 (Redis)
+    pub rate_limit_enabled: bool,
+    pub rate_limit_sign_in: RateLimitRule,
+    pub rate_limit_two_factor: RateLimitRule,
+    pub rate_limit_forgot_password: RateLimitRule,
+    pub rate_limit_resend_verification: RateLimitRule,
+    pub rate_limit_check_handle: RateLimitRule,
+    pub login_lockout_threshold: u64,
+    pub login_failure_window: u64,
+    pub login_lockout_base: u64,
+    pub login_lockout_max: u64,
+    /// `X-Forwarded-For`/`CF-Connecting-IP`를 믿는 프록시 주소·대역
+    pub trusted_proxies: Vec<IpNetwork>,
+
     pub cors_allowed_origins: Vec<HeaderValue>,
     pub cors_allowed_headers: Vec<HeaderName>,
     pub cors_max_age: Option<u64>,
@@ -153,6 +249,13 @@ static CONFIG: LazyLock<DbConfig> = LazyLock::new(|| {
             .ok()
             .and_then(|v| v.parse().ok())
             .unwrap_or(1), // 기본값 1시간
+        auth_two_factor_challenge_expire_time: env::var("AUTH_TWO_FACTOR_CHALLENGE_EXPIRE_TIME")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(5), // 기본값 5분
+        // 인증 앱에 표시되는 서비스 이름
+        auth_two_factor_issuer: env::var("AUTH_TWO_FACTOR_ISSUER")
+            .unwrap_or_else(|_| "SnowX".to_string()),
 
         // Google
         google_client_id: env::var("GOOGLE_CLIENT_ID").expect("GOOGLE_CLIENT_ID must be set"),
@@ -234,6 +337,184 @@ static CONFIG: LazyLock<DbConfig> = LazyLock::new(|| {
             .ok()
             .filter(|key| !key.is_empty()),
 
+        // DHCP Lease
+        dhcp_lease_watch_paths: env::var("DHCP_LEASE_WATCH_PATHS")
+            .map(|paths| {
+                paths
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        dhcp_lease_watch_interval: env::var("DHCP_LEASE_WATCH_INTERVAL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(60), // 기본값 60초
+        dhcp_lease_sweep_interval: env::var("DHCP_LEASE_SWEEP_INTERVAL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(300), // 기본값 5분, 0이면 비활성화
+
+        // DNS Zone
+        dns_zone_default_domain: env::var("DNS_ZONE_DEFAULT_DOMAIN")
+            .ok()
+            .filter(|v| !v.trim().is_empty()),
+        dns_zone_primary_ns: env::var("DNS_ZONE_PRIMARY_NS")
+            .ok()
+            .filter(|v| !v.trim().is_empty()),
+        dns_zone_hostmaster: env::var("DNS_ZONE_HOSTMASTER")
+            .ok()
+            .filter(|v| !v.trim().is_empty()),
+        dns_zone_ttl: env::var("DNS_ZONE_TTL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(3600), // 기본값 1시간
+
+        // Rack
+        rack_power_budget_reject: env::var("RACK_POWER_BUDGET_MODE")
+            .map(|mode| mode.trim().eq_ignore_ascii_case("reject"))
+            .unwrap_or(false), // 기본값 warn (경고만 남기고 허용)
+
+        // External API 자동 동기화
+        external_api_sync_poll_interval: env::var("EXTERNAL_API_SYNC_POLL_INTERVAL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(30), // 기본값 30초, 0이면 비활성화
+        external_api_sync_concurrency: env::var("EXTERNAL_API_SYNC_CONCURRENCY")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(4),
+        external_api_sync_retry_base: env::var("EXTERNAL_API_SYNC_RETRY_BASE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(60), // 첫 실패 후 60초 뒤 재시도, 이후 두 배씩
+        external_api_sync_max_backoff: env::var("EXTERNAL_API_SYNC_MAX_BACKOFF")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(86400), // 기본값 하루
+        external_api_allow_private_urls: env::var("EXTERNAL_API_ALLOW_PRIVATE_URLS")
+            .map(|v| v.trim().eq_ignore_ascii_case("true"))
+            .unwrap_or(false), // 기본값: 내부망 주소의 API는 부르지 않음
+
+        // Custodian 실행
+        custodian_scheduler_poll_interval: env::var("CUSTODIAN_SCHEDULER_POLL_INTERVAL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(30), // 기본값 30초, 0이면 비활성화
+        custodian_reconcile_interval: env::var("CUSTODIAN_RECONCILE_INTERVAL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(15), // 기본값 15초, 0이면 비활성화
+        custodian_execution_timeout: env::var("CUSTODIAN_EXECUTION_TIMEOUT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(3600), // 시작 후 1시간이 지나도 끝나지 않으면 실패로 본다
+
+        // 알림 발송
+        notification_dispatch_poll_interval: env::var("NOTIFICATION_DISPATCH_POLL_INTERVAL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(5), // 기본값 5초, 0이면 비활성화
+        notification_dispatch_batch: env::var("NOTIFICATION_DISPATCH_BATCH")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(20),
+        notification_retry_base: env::var("NOTIFICATION_RETRY_BASE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(30), // 첫 실패 후 30초 뒤 재시도, 이후 두 배씩
+        notification_max_backoff: env::var("NOTIFICATION_MAX_BACKOFF")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(3600), // 기본값 1시간
+        notification_processing_timeout: env::var("NOTIFICATION_PROCESSING_TIMEOUT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(300), // processing 상태로 5분이 지나면 다시 가져간다
+        notification_webhook_url: env::var("NOTIFICATION_WEBHOOK_URL")
+            .ok()
+            .filter(|v| !v.trim().is_empty()),
+        notification_digest_interval: env::var("NOTIFICATION_DIGEST_INTERVAL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(86400), // 기본값 하루에 한 번
+        notification_link_base_url: env::var("NOTIFICATION_LINK_BASE_URL")
+            .ok()
+            .map(|v| v.trim().trim_end_matches('/').to_string())
+            .filter(|v| !v.is_empty()),
+
+        // 웹훅 구독
+        webhook_max_retries: env::var("WEBHOOK_MAX_RETRIES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(5),
+        webhook_allow_private_urls: env::var("WEBHOOK_ALLOW_PRIVATE_URLS")
+            .map(|v| v.trim().eq_ignore_ascii_case("true"))
+            .unwrap_or(false), // 기본값: 내부망 주소로는 보내지 않음
+
+        // 자동 작업이 만든 데이터의 작성자
+        system_user_id: env::var("SYSTEM_USER_ID")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .and_then(|v| {
+                v.trim().parse().ok().or_else(|| {
+                    warn!(
+                        "Invalid SYSTEM_USER_ID '{}'; falling back to handle lookup",
+                        v
+                    );
+                    None
+                })
+            }),
+        system_user_name: env::var("SYSTEM_USER_NAME")
+            .unwrap_or_else(|_| "SnowX System".to_string()),
+        system_user_handle: env::var("SYSTEM_USER_HANDLE")
+            .unwrap_or_else(|_| "snowx-system".to_string()),
+        system_user_email: env::var("SYSTEM_USER_EMAIL")
+            .unwrap_or_else(|_| "system@snow-x.dev".to_string()),
+
+        // 인증 요청 제한
+        rate_limit_enabled: env::var("RATE_LIMIT_ENABLED")
+            .map(|v| !v.trim().eq_ignore_ascii_case("false"))
+            .unwrap_or(true),
+        rate_limit_sign_in: RateLimitRule::from_env(
+            "RATE_LIMIT_SIGN_IN",
+            RateLimitRule::new(10, 60),
+        ),
+        rate_limit_two_factor: RateLimitRule::from_env(
+            "RATE_LIMIT_TWO_FACTOR",
+            RateLimitRule::new(10, 60),
+        ),
+        rate_limit_forgot_password: RateLimitRule::from_env(
+            "RATE_LIMIT_FORGOT_PASSWORD",
+            RateLimitRule::new(5, 3600),
+        ),
+        rate_limit_resend_verification: RateLimitRule::from_env(
+            "RATE_LIMIT_RESEND_VERIFICATION",
+            RateLimitRule::new(5, 3600),
+        ),
+        rate_limit_check_handle: RateLimitRule::from_env(
+            "RATE_LIMIT_CHECK_HANDLE",
+            RateLimitRule::new(30, 60),
+        ),
+        login_lockout_threshold: env::var("LOGIN_LOCKOUT_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(5), // 0이면 잠그지 않는다
+        login_failure_window: env::var("LOGIN_FAILURE_WINDOW")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(900), // 기본값 15분 안의 실패를 센다
+        login_lockout_base: env::var("LOGIN_LOCKOUT_BASE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(60), // 첫 잠금 1분, 이후 두 배씩
+        login_lockout_max: env::var("LOGIN_LOCKOUT_MAX")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(3600), // 최대 1시간
+        trusted_proxies: parse_trusted_proxies(&env::var("TRUSTED_PROXIES").unwrap_or_default()),
+
         cors_allowed_origins: cors_origins,
         cors_allowed_headers: cors_headers,
         cors_max_age: env::var("CORS_MAX_AGE").ok().and_then(|v| v.parse().ok()),
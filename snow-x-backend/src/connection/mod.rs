@@ -2,4 +2,5 @@ pub mod cloudflare_r2;
 pub mod database;
 pub mod http;
 pub mod meilisearch;
+pub mod outbound;
 pub mod redis_connection;
@@ -0,0 +1,263 @@
+//! 사용자가 정한 주소(웹훅 구독, 외부 API 연결)로 요청을 보내는 HTTP 클라이언트.
+//!
+//! 내부망 주소를 막지 않으면 주소를 등록할 수 있는 사용자가 서버를 거쳐 내부 서비스나
+//! 클라우드 메타데이터에 접근할 수 있다. 등록할 때의 주소 검사만으로는 DNS 응답이 나중에
+//! 바뀌거나(rebinding) 리다이렉트로 내부 주소로 넘어가는 경우를 막지 못하므로,
+//! 이 클라이언트는 연결할 때 풀린 주소를 다시 확인하고 리다이렉트를 따라가지 않는다.
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::{Client, Url, redirect};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 공인 주소가 아닌 IP인지 (루프백, 사설망, 링크 로컬, CGNAT, 문서/벤치마크용, 멀티캐스트 등)
+pub fn is_private_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_private_ipv4(v4),
+        IpAddr::V6(v6) => is_private_ipv6(v6),
+    }
+}
+
+fn is_private_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || a == 0 // 0.0.0.0/8
+        || (a == 100 && (b & 0xc0) == 64) // 100.64.0.0/10 (CGNAT)
+        || (a == 192 && b == 0 && c == 0) // 192.0.0.0/24 (IETF 프로토콜 할당)
+        || (a == 198 && (b & 0xfe) == 18) // 198.18.0.0/15 (벤치마크)
+        || a >= 240 // 240.0.0.0/4 (예약)
+}
+
+fn is_private_ipv6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    // IPv4 주소를 품은 형식은 그 IPv4 주소로 판단한다
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_private_ipv4(v4);
+    }
+    if segments[0] == 0x2002 {
+        // 6to4 (2002::/16)
+        let [a, b] = segments[1].to_be_bytes();
+        let [c, d] = segments[2].to_be_bytes();
+        return is_private_ipv4(Ipv4Addr::new(a, b, c, d));
+    }
+
+    ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_unique_local()
+        || ip.is_unicast_link_local()
+        || ip.is_multicast()
+        || segments[..6] == [0; 6] // ::/96 (IPv4 호환, 폐기)
+        || (segments[0] & 0xffc0) == 0xfec0 // fec0::/10 (site-local, 폐기)
+        || (segments[0] == 0x2001 && segments[1] == 0x0db8) // 2001:db8::/32 (문서용)
+        || (segments[0] == 0x0064 && segments[1] == 0xff9b) // 64:ff9b::/96, 64:ff9b:1::/48 (NAT64)
+        || (segments[0] == 0x0100 && segments[1..4] == [0; 3]) // 100::/64 (discard)
+}
+
+/// URL의 호스트가 IP 리터럴이면 그 주소로, 이름이면 localhost 계열인지로 내부망 여부를 판단한다.
+/// DNS 이름이 가리키는 주소는 보지 않는다 (등록할 때 빠르게 걸러 내는 용도).
+pub fn is_private_host(url: &Url) -> bool {
+    let host = url
+        .host_str()
+        .unwrap_or_default()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+
+    match host.parse::<IpAddr>() {
+        Ok(ip) => is_private_ip(ip),
+        Err(_) => host.is_empty() || host == "localhost" || host.ends_with(".localhost"),
+    }
+}
+
+/// 이름을 풀어 모든 주소가 공인 주소일 때만 돌려준다
+async fn resolve_public(host: &str, port: u16) -> std::io::Result<Vec<SocketAddr>> {
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port)).await?.collect();
+    if let Some(addr) = addrs.iter().find(|addr| is_private_ip(addr.ip())) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            format!("{} resolves to a private address ({})", host, addr.ip()),
+        ));
+    }
+    Ok(addrs)
+}
+
+/// 연결할 때마다 풀린 주소를 확인하는 DNS resolver
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = resolve_public(name.as_str(), 0).await?;
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// 사용자가 정한 주소로 보내는 클라이언트.
+/// `allow_private`가 false면 내부망 주소로의 연결을 막는다. 리다이렉트는 항상 따라가지 않는다.
+#[derive(Clone)]
+pub struct OutboundHttpClient {
+    client: Client,
+    allow_private: bool,
+}
+
+impl OutboundHttpClient {
+    pub fn new(allow_private: bool) -> Result<Self, reqwest::Error> {
+        let mut builder = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(10))
+            .pool_idle_timeout(Duration::from_secs(90))
+            .user_agent("snow-x/1.0")
+            .redirect(redirect::Policy::none());
+        if !allow_private {
+            // 프록시를 거치면 프록시가 이름을 풀어 resolver 검사를 건너뛰게 된다
+            builder = builder
+                .no_proxy()
+                .dns_resolver(Arc::new(PublicAddressResolver));
+        }
+
+        Ok(Self {
+            client: builder.build()?,
+            allow_private,
+        })
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// 보내기 직전에 주소를 확인한다. IP 리터럴은 resolver를 거치지 않으므로 여기서 막고,
+    /// 이름은 풀어서 모든 주소를 확인해 알아보기 쉬운 오류를 돌려준다.
+    /// (확인 뒤 DNS 응답이 바뀌어도 연결할 때 resolver가 다시 막는다.)
+    pub async fn check_url(&self, url: &str) -> Result<Url, String> {
+        let parsed = Url::parse(url)
+            .ok()
+            .filter(|parsed| matches!(parsed.scheme(), "http" | "https"))
+            .ok_or_else(|| format!("Invalid URL '{}'", url))?;
+        if self.allow_private {
+            return Ok(parsed);
+        }
+
+        if is_private_host(&parsed) {
+            return Err(format!(
+                "{} points to a local or private address",
+                parsed.host_str().unwrap_or_default()
+            ));
+        }
+        if let Some(domain) = parsed.domain() {
+            let port = parsed.port_or_known_default().unwrap_or(0);
+            resolve_public(domain, port)
+                .await
+                .map_err(|e| format!("Refusing to connect: {}", e))?;
+        }
+        Ok(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn private(ip: &str) -> bool {
+        is_private_ip(ip.parse().unwrap())
+    }
+
+    #[test]
+    fn blocks_non_public_ipv4() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "100.127.255.254",
+            "0.1.2.3",
+            "192.0.0.8",
+            "192.0.2.1",
+            "198.18.0.1",
+            "203.0.113.9",
+            "224.0.0.1",
+            "255.255.255.255",
+        ] {
+            assert!(private(ip), "{} should be private", ip);
+        }
+        for ip in ["8.8.8.8", "100.128.0.1", "1.1.1.1", "198.20.0.1"] {
+            assert!(!private(ip), "{} should be public", ip);
+        }
+    }
+
+    #[test]
+    fn blocks_non_public_ipv6() {
+        for ip in [
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "2001:db8::1",
+            "64:ff9b::a00:1",
+            "64:ff9b:1::1",
+            "::ffff:127.0.0.1",
+            "::ffff:100.64.0.1",
+            "2002:c0a8:0101::1",
+            "ff02::1",
+        ] {
+            assert!(private(ip), "{} should be private", ip);
+        }
+        for ip in ["2606:4700:4700::1111", "2002:0808:0808::1"] {
+            assert!(!private(ip), "{} should be public", ip);
+        }
+    }
+
+    #[test]
+    fn private_host_names() {
+        for url in [
+            "http://localhost/hook",
+            "http://api.localhost./",
+            "http://[::1]:8080/",
+            "http://169.254.169.254/latest/meta-data/",
+        ] {
+            assert!(is_private_host(&Url::parse(url).unwrap()), "{}", url);
+        }
+        assert!(!is_private_host(
+            &Url::parse("https://hooks.example.com/x").unwrap()
+        ));
+    }
+
+    #[tokio::test]
+    async fn check_url_refuses_private_targets() {
+        let client = OutboundHttpClient::new(false).unwrap();
+        assert!(client.check_url("http://127.0.0.1:9/").await.is_err());
+        assert!(client.check_url("http://localhost:9/").await.is_err());
+        assert!(client.check_url("ftp://example.com/").await.is_err());
+
+        let permissive = OutboundHttpClient::new(true).unwrap();
+        assert!(permissive.check_url("http://127.0.0.1:9/").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn does_not_follow_redirects() {
+        use axum::{Router, response::Redirect, routing::get};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route("/", get(|| async { Redirect::temporary("/next") }));
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let client = OutboundHttpClient::new(true).unwrap();
+        let response = client
+            .client()
+            .get(format!("http://{}/", addr))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::TEMPORARY_REDIRECT);
+    }
+}
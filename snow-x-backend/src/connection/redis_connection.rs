@@ -1,18 +1,25 @@
 use crate::config::db_config::DbConfig;
-use redis::aio::ConnectionManager;
+use redis::aio::{AsyncPushSender, ConnectionManager, ConnectionManagerConfig};
 use redis::{Client, RedisResult};
 use tracing::info;
 
-pub async fn establish_redis_connection() -> RedisResult<ConnectionManager> {
+/// Redis에 연결한다. 같은 연결로 pub/sub 메시지도 받을 수 있도록 RESP3로 연결하고
+/// 받은 메시지를 `push_sender`로 넘긴다 (Redis 6 이상 필요).
+pub async fn establish_redis_connection(
+    push_sender: impl AsyncPushSender,
+) -> RedisResult<ConnectionManager> {
     let redis_url = format!(
-        "redis://{}:{}",
+        "redis://{}:{}/?protocol=resp3",
         &DbConfig::get().redis_host,
         &DbConfig::get().redis_port,
     );
     info!("Connecting to Redis at: {}", redis_url);
 
     let client = Client::open(redis_url.as_str())?;
-    let conn_manager = ConnectionManager::new(client).await?;
+    let config = ConnectionManagerConfig::new()
+        .set_push_sender(push_sender)
+        .set_automatic_resubscription();
+    let conn_manager = ConnectionManager::new_with_config(client, config).await?;
 
     info!("Successfully connected to Redis");
     Ok(conn_manager)
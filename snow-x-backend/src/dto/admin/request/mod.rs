@@ -0,0 +1,3 @@
+pub mod user_permissions;
+
+pub use user_permissions::*;
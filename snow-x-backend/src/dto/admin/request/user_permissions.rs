@@ -0,0 +1,12 @@
+use crate::entity::common::UserRole;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateUserPermissionsRequest {
+    /// 바꿀 역할. 없으면 그대로 둔다
+    pub role: Option<UserRole>,
+    /// 접근할 수 있는 사무실. 빈 배열이면 모든 사무실, 없으면 그대로 둔다
+    pub office_ids: Option<Vec<Uuid>>,
+}
@@ -1,5 +1,7 @@
 pub mod admin_status;
 pub mod task_response;
+pub mod user_permissions;
 
 pub use admin_status::*;
 pub use task_response::*;
+pub use user_permissions::*;
@@ -0,0 +1,32 @@
+use crate::entity::common::UserRole;
+use crate::service::auth::permission::PermissionResource;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ResourcePermission {
+    pub resource: PermissionResource,
+    pub read: bool,
+    pub write: bool,
+    pub delete: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UserPermissionsResponse {
+    pub user_id: Uuid,
+    pub role: UserRole,
+    /// 접근할 수 있는 사무실. 비어 있으면 모든 사무실
+    pub office_ids: Vec<Uuid>,
+    /// 사무실 범위로 제한되는지 (Admin은 지정된 사무실이 있어도 제한되지 않는다)
+    pub office_restricted: bool,
+    pub permissions: Vec<ResourcePermission>,
+}
+
+impl IntoResponse for UserPermissionsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
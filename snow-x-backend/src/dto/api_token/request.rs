@@ -0,0 +1,38 @@
+use crate::entity::common::UserRole;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// API 토큰 발급 요청
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateApiTokenRequest {
+    pub name: String,
+    /// 허용 범위 (ipam:read, ipam:write, posts:read, posts:write)
+    pub scopes: Vec<String>,
+    /// 유효 기간 (일). 없으면 폐기할 때까지 쓴다
+    pub expires_in_days: Option<i64>,
+    /// 지정하면 이 테넌트로만 요청할 수 있다
+    pub tenant_id: Option<Uuid>,
+}
+
+/// 서비스 계정 생성 요청
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateServiceAccountRequest {
+    #[validate(length(
+        min = 3,
+        max = 20,
+        message = "Handle must be between 3 and 20 characters."
+    ))]
+    pub handle: String,
+    #[validate(length(
+        min = 1,
+        max = 20,
+        message = "Name must be between 1 and 20 characters."
+    ))]
+    pub name: String,
+    /// 지정하면 이 테넌트에 `tenant_role` 역할로 소속시킨다
+    pub tenant_id: Option<Uuid>,
+    /// 기본값: Staff
+    pub tenant_role: Option<UserRole>,
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// API 토큰. 토큰 원문은 발급 응답에서만 보여준다.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiTokenResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    /// 토큰을 알아보기 위한 앞부분
+    pub token_prefix: String,
+    pub scopes: Vec<String>,
+    pub tenant_id: Option<Uuid>,
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+    pub last_used_ip: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub revoked_at: Option<DateTime<FixedOffset>>,
+}
+
+/// 토큰 원문을 포함한 API 토큰
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiTokenSecretResponse {
+    #[serde(flatten)]
+    pub api_token: ApiTokenResponse,
+    /// `Authorization: Bearer`로 보낼 토큰. 다시 볼 수 없다
+    pub token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiTokenListResponse {
+    pub tokens: Vec<ApiTokenResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ServiceAccountResponse {
+    pub id: Uuid,
+    pub handle: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    /// 폐기되지 않은 토큰 수
+    pub active_token_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ServiceAccountListResponse {
+    pub service_accounts: Vec<ServiceAccountResponse>,
+}
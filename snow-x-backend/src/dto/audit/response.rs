@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// 필드 단위 변경 내역
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditFieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+/// 감사 로그 한 건
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogResponse {
+    pub id: Uuid,
+    pub resource_type: String,
+    pub resource_id: Uuid,
+    pub resource_name: Option<String>,
+    /// `create`, `update`, `delete`
+    pub action: String,
+    pub actor_id: Option<Uuid>,
+    pub actor_name: Option<String>,
+    pub actor_handle: Option<String>,
+    pub changes: Vec<AuditFieldChange>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 감사 로그 목록 응답
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogListResponse {
+    pub entries: Vec<AuditLogResponse>,
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64,
+}
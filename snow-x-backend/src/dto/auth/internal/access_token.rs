@@ -7,4 +7,7 @@ pub struct AccessTokenClaims {
     pub sub: Uuid,
     pub iat: i64,
     pub exp: i64, // Expiration time (Unix timestamp)
+    /// 테넌트 전환으로 발급한 토큰의 테넌트 (`X-Tenant-Id` 헤더가 우선)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<Uuid>,
 }
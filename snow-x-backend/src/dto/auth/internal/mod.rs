@@ -3,3 +3,4 @@ pub mod anonymous_user;
 pub mod email_verification_token;
 pub mod password_reset_token;
 pub mod refresh_token;
+pub mod two_factor_challenge;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 비밀번호 확인 뒤 2단계 인증을 기다리는 로그인.
+/// 액세스 토큰으로 쓰이지 않도록 다른 키로 서명한다.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TwoFactorChallengeClaims {
+    pub sub: Uuid, // user_id
+    pub iat: i64,  // issued at
+    pub exp: i64,  // expires at
+    /// 역할 정책상 필요한데 아직 등록하지 않아, 이 로그인에서 등록까지 마쳐야 함
+    pub enroll: bool,
+}
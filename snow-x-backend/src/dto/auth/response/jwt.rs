@@ -17,34 +17,39 @@ pub struct AuthJWTResponse {
 
 impl IntoResponse for AuthJWTResponse {
     fn into_response(self) -> Response {
-        let refresh_token_lifetime = DbConfig::get().auth_refresh_token_expire_time;
-        let is_dev = DbConfig::get().is_dev;
-
         let mut response = Json(AuthJWTResponse {
             access_token: self.access_token.clone(),
             cookie_refresh_token: String::new(),
         })
         .into_response();
 
-        let same_site_attribute = if is_dev {
-            SameSite::None
-        } else {
-            SameSite::Lax
-        };
-
-        let cookie = Cookie::build(("refresh_token", self.cookie_refresh_token))
-            .http_only(true)
-            .secure(true)
-            .same_site(same_site_attribute)
-            .path("/")
-            .max_age(Duration::days(refresh_token_lifetime))
-            .build();
-
-        response.headers_mut().insert(
-            SET_COOKIE,
-            HeaderValue::from_str(&cookie.to_string()).unwrap(),
-        );
+        set_refresh_token_cookie(&mut response, self.cookie_refresh_token);
 
         response
     }
 }
+
+/// refresh token을 HttpOnly 쿠키로 응답에 붙인다
+pub fn set_refresh_token_cookie(response: &mut Response, refresh_token: String) {
+    let refresh_token_lifetime = DbConfig::get().auth_refresh_token_expire_time;
+    let is_dev = DbConfig::get().is_dev;
+
+    let same_site_attribute = if is_dev {
+        SameSite::None
+    } else {
+        SameSite::Lax
+    };
+
+    let cookie = Cookie::build(("refresh_token", refresh_token))
+        .http_only(true)
+        .secure(true)
+        .same_site(same_site_attribute)
+        .path("/")
+        .max_age(Duration::days(refresh_token_lifetime))
+        .build();
+
+    response.headers_mut().insert(
+        SET_COOKIE,
+        HeaderValue::from_str(&cookie.to_string()).unwrap(),
+    );
+}
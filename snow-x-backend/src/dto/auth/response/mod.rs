@@ -1,3 +1,4 @@
 pub mod jwt;
 pub mod oauth_connections;
+pub mod sign_in;
 pub mod sign_out;
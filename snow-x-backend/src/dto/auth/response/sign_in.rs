@@ -0,0 +1,54 @@
+use crate::dto::auth::response::jwt::{AuthJWTResponse, set_refresh_token_cookie};
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// 비밀번호 로그인 결과. 2단계 인증을 쓰는 사용자는 토큰 대신 대기 토큰을 받는다.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum SignInResponse {
+    Authenticated(AuthJWTResponse),
+    TwoFactorRequired(TwoFactorChallengeResponse),
+}
+
+impl IntoResponse for SignInResponse {
+    fn into_response(self) -> Response {
+        match self {
+            SignInResponse::Authenticated(tokens) => tokens.into_response(),
+            SignInResponse::TwoFactorRequired(challenge) => Json(challenge).into_response(),
+        }
+    }
+}
+
+/// `/v0/auth/sign_in/two_factor`로 인증 코드와 함께 보낼 대기 토큰
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TwoFactorChallengeResponse {
+    /// 항상 true
+    pub two_factor_required: bool,
+    /// 역할 정책상 2단계 인증이 필요한데 아직 등록하지 않음.
+    /// `/v0/auth/sign_in/two_factor/enroll`로 비밀키를 받은 뒤 코드를 보내면 등록과 로그인이 함께 끝난다
+    pub enrollment_required: bool,
+    pub challenge_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// 2단계 인증까지 마친 로그인
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TwoFactorSignInResponse {
+    pub access_token: String,
+    /// 로그인 중에 2단계 인증을 등록했을 때만 주는 복구 코드. 다시 볼 수 없다
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery_codes: Option<Vec<String>>,
+    #[serde(skip_serializing)]
+    pub cookie_refresh_token: String,
+}
+
+impl IntoResponse for TwoFactorSignInResponse {
+    fn into_response(self) -> Response {
+        let mut response = Json(&self).into_response();
+        set_refresh_token_cookie(&mut response, self.cookie_refresh_token);
+        response
+    }
+}
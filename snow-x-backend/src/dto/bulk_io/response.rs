@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// 가져오기 실패 행
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkImportRowError {
+    /// 파일 기준 줄 번호 (헤더가 1행)
+    pub row: usize,
+    /// 문제가 된 열. 행 전체 오류면 null
+    pub column: Option<String>,
+    pub message: String,
+}
+
+/// 가져오기에 성공한 (dry-run이면 성공할) 행
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkImportRowResult {
+    pub row: usize,
+    /// `create` 또는 `update`
+    pub action: String,
+    /// 반영되지 않은 생성 행(dry-run, 오류로 롤백)은 null
+    pub id: Option<Uuid>,
+    pub name: String,
+}
+
+/// CSV 가져오기 결과. 한 행이라도 실패하면 아무것도 반영하지 않는다.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkImportResponse {
+    /// `device`, `rack`, `ip_address`, `contact`
+    pub resource: String,
+    pub dry_run: bool,
+    /// 변경 사항이 실제로 커밋되었는지
+    pub committed: bool,
+    pub total_rows: usize,
+    pub created: usize,
+    pub updated: usize,
+    pub failed: usize,
+    pub rows: Vec<BulkImportRowResult>,
+    pub errors: Vec<BulkImportRowError>,
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+/// 외부 API 응답 항목을 대상 테이블 필드로 옮기는 규칙
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct FieldMapping {
+    #[serde(default)]
+    pub mappings: Vec<FieldMappingRule>,
+    /// 모든 조건을 만족하는 항목만 동기화합니다
+    #[serde(default)]
+    pub filter_conditions: Vec<FilterCondition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FieldMappingRule {
+    /// 원본 필드 경로 (`a.b.c`처럼 중첩 가능)
+    pub source_field: String,
+    /// 대상 필드 경로 (`a.b.c`처럼 중첩 가능)
+    pub target_field: String,
+    /// string, number, boolean, date (기본값: string)
+    #[serde(default)]
+    pub data_type: Option<String>,
+    /// 원본 값이 없을 때 쓸 값
+    #[serde(default)]
+    pub default_value: Option<Value>,
+    /// uppercase, lowercase, trim, title
+    #[serde(default)]
+    pub transformation: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FilterCondition {
+    /// 원본 필드 경로
+    pub field: String,
+    /// equals, not_equals, contains
+    pub operator: String,
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateExternalApiConnectionRequest {
+    pub name: String,
+    pub base_url: String,
+    pub description: Option<String>,
+    /// device, device_library, contact
+    pub target_type: String,
+    /// 요청마다 붙일 HTTP 헤더
+    pub headers: Option<HashMap<String, String>>,
+    /// `{"type": "bearer", "token": ...}`, `{"type": "api_key", "key": ..., "header_name": ...}`,
+    /// `{"type": "basic", "username": ..., "password": ...}`
+    #[schema(value_type = Object)]
+    pub auth_config: Option<Value>,
+    pub field_mapping: Option<FieldMapping>,
+    /// 필드별 소유 규칙 `{"<field>": "locked" | "manual_override"}`.
+    /// 지정하지 않은 필드는 `manual_override`이고, 매칭 키 필드는 항상 `locked`입니다.
+    pub field_policies: Option<HashMap<String, String>>,
+    /// 자동 동기화 주기 (초, 기본값: 3600)
+    pub sync_interval: Option<i32>,
+    pub is_active: Option<bool>,
+    pub auto_sync: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateExternalApiConnectionRequest {
+    pub name: Option<String>,
+    pub base_url: Option<String>,
+    pub description: Option<String>,
+    pub target_type: Option<String>,
+    pub headers: Option<HashMap<String, String>>,
+    /// 응답에서 가려진 비밀 값(`********`)을 그대로 보내면 기존 값을 유지합니다
+    #[schema(value_type = Object)]
+    pub auth_config: Option<Value>,
+    pub field_mapping: Option<FieldMapping>,
+    /// 보내면 기존 규칙을 모두 대체합니다
+    pub field_policies: Option<HashMap<String, String>>,
+    pub sync_interval: Option<i32>,
+    pub is_active: Option<bool>,
+    pub auto_sync: Option<bool>,
+}
+
+/// 저장하지 않고 연결만 확인하는 요청
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TestExternalApiConnectionRequest {
+    pub base_url: String,
+    pub headers: Option<HashMap<String, String>>,
+    #[schema(value_type = Object)]
+    pub auth_config: Option<Value>,
+}
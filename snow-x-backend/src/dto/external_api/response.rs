@@ -0,0 +1,195 @@
+use super::request::FieldMapping;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExternalApiConnectionResponse {
+    pub id: i32,
+    pub name: String,
+    pub base_url: String,
+    pub description: Option<String>,
+    pub target_type: String,
+    pub headers: Option<HashMap<String, String>>,
+    /// 토큰, 비밀번호, 키 값은 `********`로 가려집니다
+    #[schema(value_type = Object)]
+    pub auth_config: Option<Value>,
+    pub field_mapping: Option<FieldMapping>,
+    /// 필드별 소유 규칙 (지정한 필드만)
+    pub field_policies: HashMap<String, String>,
+    pub sync_interval: i32,
+    pub is_active: bool,
+    pub auto_sync: bool,
+    pub last_sync_at: Option<DateTime<Utc>>,
+    /// 다음 자동 동기화 시각 (epoch 초)
+    pub next_sync_at: Option<i32>,
+    pub sync_count: i32,
+    pub last_error_message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExternalApiConnectionListResponse {
+    pub connections: Vec<ExternalApiConnectionResponse>,
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExternalApiSyncLogResponse {
+    pub id: i32,
+    pub connection_id: i32,
+    /// success, error, in_progress
+    pub status: String,
+    pub request_url: String,
+    pub request_method: String,
+    pub response_status: Option<i32>,
+    pub records_processed: i32,
+    pub error_message: Option<String>,
+    /// 요청 소요 시간 (ms)
+    pub duration: Option<i32>,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExternalApiSyncLogListResponse {
+    pub logs: Vec<ExternalApiSyncLogResponse>,
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64,
+}
+
+/// 외부 API에서 받아 둔 원본 항목
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExternalApiDataResponse {
+    pub id: i32,
+    pub connection_id: i32,
+    pub external_id: Option<String>,
+    pub data_type: String,
+    #[schema(value_type = Object)]
+    pub raw_data: Value,
+    #[schema(value_type = Object)]
+    pub processed_data: Option<Value>,
+    pub hash: String,
+    /// active, archived
+    pub status: String,
+    pub last_sync_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExternalApiDataListResponse {
+    pub data: Vec<ExternalApiDataResponse>,
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64,
+}
+
+/// 대상 테이블에 반영하지 못한 항목
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExternalApiSyncItemError {
+    /// 응답 목록에서의 위치 (0부터)
+    pub index: usize,
+    pub external_id: Option<String>,
+    pub message: String,
+}
+
+/// "지금 동기화" 결과
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExternalApiSyncResponse {
+    pub connection_id: i32,
+    pub log: ExternalApiSyncLogResponse,
+    /// 응답에서 읽은 항목 수
+    pub fetched: usize,
+    /// 필터 조건에 걸러진 항목 수
+    pub filtered: usize,
+    /// 원본 데이터 중 새로 들어왔거나 내용이 바뀐 항목 수
+    pub changed: usize,
+    /// 이번 응답에 없어 보관 처리된 원본 데이터 수
+    pub archived: usize,
+    /// 대상 테이블에 생성된 행 수
+    pub created: usize,
+    /// 대상 테이블에서 수정된 행 수
+    pub updated: usize,
+    /// 식별 필드(email, serial_number/name, model/name)가 없어 건너뛴 항목 수
+    pub skipped: usize,
+    /// 수동 수정된 필드를 원본이 바꾸려 해 검토 대기열에 오른 충돌 수
+    pub conflicts: usize,
+    pub errors: Vec<ExternalApiSyncItemError>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TestExternalApiConnectionResponse {
+    /// success, error
+    pub status: String,
+    pub message: String,
+    pub status_code: Option<u16>,
+    pub response_time_ms: Option<i64>,
+    pub content_type: Option<String>,
+    /// 응답의 첫 항목 (JSON이 아니면 앞부분 텍스트)
+    #[schema(value_type = Object)]
+    pub sample_data: Option<Value>,
+}
+
+/// 동기화가 수동 수정된 필드를 바꾸려 한 충돌
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SyncConflictResponse {
+    pub id: Uuid,
+    /// device, device_library, contact
+    pub resource_type: String,
+    pub resource_id: Uuid,
+    pub resource_name: Option<String>,
+    pub field: String,
+    pub connection_id: i32,
+    /// 사람이 입력한 현재 값
+    #[schema(value_type = Object)]
+    pub local_value: Option<Value>,
+    /// 원본이 보내온 값
+    #[schema(value_type = Object)]
+    pub source_value: Option<Value>,
+    /// pending, accepted_source, kept_local
+    pub status: String,
+    pub detected_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SyncConflictListResponse {
+    pub conflicts: Vec<SyncConflictResponse>,
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64,
+}
+
+/// 사람이 수정해 동기화가 덮어쓰지 않는 필드
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SyncFieldOverrideResponse {
+    pub id: Uuid,
+    pub resource_type: String,
+    pub resource_id: Uuid,
+    pub field: String,
+    pub connection_id: i32,
+    /// 마지막으로 확인한 원본 값
+    #[schema(value_type = Object)]
+    pub source_value: Option<Value>,
+    pub overridden_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SyncFieldOverrideListResponse {
+    pub overrides: Vec<SyncFieldOverrideResponse>,
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64,
+}
@@ -1,18 +1,26 @@
 pub mod admin;
+pub mod api_token;
+pub mod audit;
 pub mod auth;
+pub mod bulk_io;
 pub mod comment;
 pub mod common;
 pub mod contact;
 pub mod device;
 pub mod device_library;
 pub mod draft;
+pub mod external_api;
 pub mod follow;
 pub mod hashtag;
 pub mod like;
 pub mod notification;
 pub mod oauth;
+pub mod office;
 pub mod post;
 pub mod rack;
 pub mod report;
 pub mod server_room;
+pub mod tenant;
+pub mod two_factor;
 pub mod user;
+pub mod webhook;
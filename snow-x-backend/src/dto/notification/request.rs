@@ -33,3 +33,29 @@ pub struct UpdateNotificationStatusRequest {
     /// 실패 사유 등 추가 정보(선택)
     pub last_error: Option<String>,
 }
+
+/// 알림 설정 한 건 (분류 × 채널)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferenceItem {
+    /// 알림 분류 (예: rack_updated, new_follower)
+    pub category: String,
+    /// web, email
+    pub channel: String,
+    pub enabled: bool,
+    /// immediate, digest (digest는 email 채널만, 기본값: immediate)
+    pub delivery_mode: Option<String>,
+}
+
+/// 알림 설정 변경 요청. 보낸 항목만 바뀐다.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateNotificationPreferencesRequest {
+    pub preferences: Vec<NotificationPreferenceItem>,
+}
+
+/// 리소스 알림 구독 요청
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateResourceSubscriptionRequest {
+    /// office, server_room, rack, device, ip_range
+    pub resource_type: String,
+    pub resource_id: Uuid,
+}
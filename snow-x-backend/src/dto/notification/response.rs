@@ -33,3 +33,76 @@ pub struct NotificationListResponse {
     pub page: u64,
     pub limit: u64,
 }
+
+/// 내 알림함의 알림 한 건
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InboxNotificationResponse {
+    pub id: Uuid,
+    pub category: String,
+    pub title: Option<String>,
+    pub message: Option<String>,
+    pub payload: Option<Value>,
+    pub link: Option<String>,
+    pub resource_type: Option<String>,
+    pub resource_id: Option<Uuid>,
+    pub actor_id: Option<Uuid>,
+    pub is_read: bool,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 내 알림함 목록 응답
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InboxNotificationListResponse {
+    pub notifications: Vec<InboxNotificationResponse>,
+    pub total: u64,
+    /// 필터와 상관없는 전체 안 읽은 알림 수
+    pub unread_count: u64,
+    pub page: u64,
+    pub limit: u64,
+}
+
+/// 안 읽은 알림 수
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UnreadCountResponse {
+    pub unread_count: u64,
+}
+
+/// 모두 읽음 처리 결과
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MarkAllReadResponse {
+    /// 읽음으로 바뀐 알림 수
+    pub updated: u64,
+}
+
+/// 분류·채널별 알림 설정
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferenceResponse {
+    pub category: String,
+    pub channel: String,
+    pub enabled: bool,
+    pub delivery_mode: String,
+    /// 저장한 값이 없어 기본값을 보여주는 경우 true
+    pub is_default: bool,
+}
+
+/// 알림 설정 목록 응답
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferenceListResponse {
+    pub preferences: Vec<NotificationPreferenceResponse>,
+}
+
+/// 리소스 알림 구독
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ResourceSubscriptionResponse {
+    pub id: Uuid,
+    pub resource_type: String,
+    pub resource_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 리소스 알림 구독 목록 응답
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ResourceSubscriptionListResponse {
+    pub subscriptions: Vec<ResourceSubscriptionResponse>,
+}
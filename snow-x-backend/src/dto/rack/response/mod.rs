@@ -1,2 +1,4 @@
+pub mod rack_capacity;
+pub mod rack_elevation;
 pub mod rack_info;
 pub mod rack_list;
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// 랙 / 서버실 / 사무실 단위의 공간·전력 집계
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CapacityRollupResponse {
+    /// `rack`, `server_room`, `office`
+    pub scope: String,
+    pub id: Uuid,
+    pub name: String,
+    pub rack_count: i32,
+    pub total_units: i32,
+    pub used_units: i32,
+    pub free_units: i32,
+    /// power_capacity가 설정된 랙들의 합 (W)
+    pub power_capacity: i64,
+    /// 장비 power_consumption 합 (W)
+    pub power_used: i64,
+    /// power_capacity - power_used (초과 시 음수)
+    pub power_available: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power_usage_percentage: Option<f64>,
+    /// power_capacity가 없는 랙 수 (전력 예산 집계에서 제외)
+    pub unbudgeted_racks: i32,
+    pub device_count: i32,
+    pub device_counts_by_type: BTreeMap<String, i32>,
+    /// 하위 단위 집계 (사무실 -> 서버실, 서버실 -> 랙)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(no_recursion)]
+    pub children: Vec<CapacityRollupResponse>,
+}
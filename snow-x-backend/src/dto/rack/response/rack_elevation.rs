@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct RackElevationResponse {
+    pub rack_id: Uuid,
+    pub rack_name: String,
+    pub rack_height: i32,
+    pub used_units: i32,
+    pub free_units: i32,
+    /// 한 번에 배치할 수 있는 가장 큰 연속 빈 공간 (U)
+    pub largest_free_gap: i32,
+    /// U1(맨 아래)부터 오름차순
+    pub slots: Vec<RackUnitSlot>,
+    pub free_gaps: Vec<RackFreeGap>,
+    /// 랙에 속해 있지만 rack_position이 없는 장비
+    pub unplaced_devices: Vec<RackUnplacedDevice>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct RackUnitSlot {
+    pub unit: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_type: Option<String>,
+    /// 같은 U를 함께 차지하고 있는 다른 장비 (검증 이전에 저장된 데이터)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicting_device_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct RackFreeGap {
+    pub start_unit: i32,
+    pub end_unit: i32,
+    pub size: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct RackUnplacedDevice {
+    pub id: Uuid,
+    pub name: String,
+    pub device_type: String,
+    pub rack_size: i32,
+}
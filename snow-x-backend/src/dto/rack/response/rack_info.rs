@@ -2,6 +2,7 @@ use axum::Json;
 use axum::response::{IntoResponse, Response};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -32,6 +33,18 @@ pub struct RackInfoResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage_percentage: Option<f64>,
     pub used_units: i32,
+    #[serde(default)]
+    pub free_units: i32,
+    /// 장비 power_consumption 합 (W)
+    #[serde(default)]
+    pub power_used: i64,
+    /// power_capacity - power_used, power_capacity가 없으면 생략
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power_available: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power_usage_percentage: Option<f64>,
+    #[serde(default)]
+    pub device_counts_by_type: BTreeMap<String, i32>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub devices: Vec<RackDeviceSummary>,
 }
@@ -59,4 +72,6 @@ pub struct RackDeviceSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rack_position: Option<i32>,
     pub rack_size: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power_consumption: Option<i32>,
 }
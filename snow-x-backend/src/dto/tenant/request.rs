@@ -0,0 +1,27 @@
+use crate::entity::common::UserRole;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// 테넌트 생성 요청. 만든 사람은 이 테넌트의 Admin이 된다.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateTenantRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub settings: Option<Value>,
+}
+
+/// 테넌트 수정 요청. 보낸 필드만 바뀐다.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateTenantRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub settings: Option<Value>,
+    pub is_active: Option<bool>,
+}
+
+/// 테넌트 구성원 추가 또는 역할 변경 요청
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpsertTenantMemberRequest {
+    pub role: UserRole,
+}
@@ -0,0 +1,52 @@
+use crate::entity::common::UserRole;
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TenantResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub settings: Option<Value>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+    pub is_active: bool,
+}
+
+/// 내가 속한 테넌트와 그 안에서의 역할
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MyTenantResponse {
+    #[serde(flatten)]
+    pub tenant: TenantResponse,
+    pub role: UserRole,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MyTenantListResponse {
+    pub tenants: Vec<MyTenantResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TenantMemberResponse {
+    pub user_id: Uuid,
+    pub handle: String,
+    pub name: String,
+    pub role: UserRole,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TenantMemberListResponse {
+    pub members: Vec<TenantMemberResponse>,
+}
+
+/// 테넌트가 정해진 액세스 토큰
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TenantTokenResponse {
+    pub tenant_id: Uuid,
+    pub access_token: String,
+}
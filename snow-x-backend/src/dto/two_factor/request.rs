@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 로그인 두 번째 단계. `code`와 `recovery_code` 중 하나를 보낸다
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorSignInRequest {
+    /// `/v0/auth/sign_in` 응답의 대기 토큰
+    pub challenge_token: String,
+    /// 인증 앱의 6자리 코드
+    pub code: Option<String>,
+    /// 일회용 복구 코드
+    pub recovery_code: Option<String>,
+}
+
+/// 로그인 중 2단계 인증 등록 (역할 정책상 필요한 사용자)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorChallengeRequest {
+    pub challenge_token: String,
+}
+
+/// 등록한 비밀키로 만든 첫 코드
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ConfirmTwoFactorRequest {
+    pub code: String,
+}
+
+/// 본인 확인. `code`와 `recovery_code` 중 하나를 보낸다
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorCodeRequest {
+    pub code: Option<String>,
+    pub recovery_code: Option<String>,
+}
+
+/// 역할별 2단계 인증 강제 여부 변경
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateTwoFactorRolePolicyRequest {
+    pub required: bool,
+}
@@ -0,0 +1,44 @@
+use crate::entity::common::UserRole;
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// 인증 앱에 등록할 비밀키. `otpauth_uri`를 그대로 QR 코드로 만들면 된다
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorEnrollmentResponse {
+    /// Base32 비밀키 (직접 입력용)
+    pub secret: String,
+    /// `otpauth://totp/...` QR 코드 내용
+    pub otpauth_uri: String,
+}
+
+/// 새 복구 코드. 다시 볼 수 없으며, 이전 코드는 더 이상 쓸 수 없다
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorRecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorStatusResponse {
+    pub enabled: bool,
+    pub enabled_at: Option<DateTime<FixedOffset>>,
+    /// 비밀키를 받았지만 아직 확인 코드를 보내지 않음
+    pub enrollment_pending: bool,
+    /// 역할 정책상 끌 수 없음
+    pub required_by_role: bool,
+    pub recovery_codes_remaining: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorRolePolicyResponse {
+    pub role: UserRole,
+    pub required: bool,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorRolePolicyListResponse {
+    pub policies: Vec<TwoFactorRolePolicyResponse>,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 웹훅 구독 생성 요청
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateWebhookSubscriptionRequest {
+    pub name: String,
+    /// 이벤트를 POST할 주소 (http, https)
+    pub url: String,
+    pub description: Option<String>,
+    /// 받을 이벤트 종류 (예: rack_updated, device_created, ip_allocated, post_created)
+    pub event_types: Vec<String>,
+    /// 기본값: true
+    pub is_active: Option<bool>,
+}
+
+/// 웹훅 구독 수정 요청. 보낸 필드만 바뀐다.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateWebhookSubscriptionRequest {
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub event_types: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// 웹훅 구독. 서명 키는 생성과 재발급 응답에서만 보여준다.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub event_types: Vec<String>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 서명 키를 포함한 웹훅 구독
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionSecretResponse {
+    #[serde(flatten)]
+    pub subscription: WebhookSubscriptionResponse,
+    /// `X-SnowX-Signature` 검증용 HMAC-SHA256 키
+    pub secret: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionListResponse {
+    pub subscriptions: Vec<WebhookSubscriptionResponse>,
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64,
+}
+
+/// 웹훅 배달 한 건
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    /// 같은 이벤트를 받은 구독끼리 같은 값
+    pub event_id: Uuid,
+    pub event_type: String,
+    /// pending, success, failed
+    pub status: String,
+    pub attempt_count: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryListResponse {
+    pub deliveries: Vec<WebhookDeliveryResponse>,
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64,
+}
+
+/// 배달 시도 한 번
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryAttemptResponse {
+    pub attempt: i32,
+    pub request_url: String,
+    pub response_status: Option<i32>,
+    /// 응답 본문 앞부분
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    /// 요청 소요 시간 (ms)
+    pub duration_ms: i32,
+    pub attempted_at: DateTime<Utc>,
+}
+
+/// 보낸 본문과 시도 기록을 포함한 배달 상세
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryDetailResponse {
+    #[serde(flatten)]
+    pub delivery: WebhookDeliveryResponse,
+    pub payload: Value,
+    pub attempts: Vec<WebhookDeliveryAttemptResponse>,
+}
+
+/// 구독할 수 있는 이벤트 종류
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookEventTypesResponse {
+    pub event_types: Vec<String>,
+}
@@ -0,0 +1,68 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "api_tokens")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "Uuid")]
+    pub user_id: Uuid,
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub name: String,
+    /// 목록에서 토큰을 알아볼 수 있도록 남기는 앞부분
+    #[sea_orm(column_type = "String(StringLen::N(16))")]
+    pub token_prefix: String,
+    /// 토큰 원문의 SHA-256 (hex)
+    #[serde(skip_serializing)]
+    #[sea_orm(column_type = "String(StringLen::N(64))", unique)]
+    pub token_hash: String,
+    /// 허용 범위 (문자열 배열)
+    #[sea_orm(column_type = "JsonBinary")]
+    pub scopes: serde_json::Value,
+    /// 지정하면 이 테넌트로만 요청할 수 있다
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub tenant_id: Option<Uuid>,
+    pub expires_at: Option<DateTimeWithTimeZone>,
+    pub last_used_at: Option<DateTimeWithTimeZone>,
+    #[sea_orm(column_type = "String(StringLen::N(45))", nullable)]
+    pub last_used_ip: Option<String>,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTimeWithTimeZone,
+    pub revoked_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Users,
+    #[sea_orm(
+        belongs_to = "super::tenants::Entity",
+        from = "Column::TenantId",
+        to = "super::tenants::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Tenants,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl Related<super::tenants::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tenants.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
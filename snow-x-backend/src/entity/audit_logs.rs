@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "audit_logs")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub tenant_id: Option<Uuid>,
+    #[sea_orm(column_type = "String(StringLen::N(50))")]
+    pub resource_type: String,
+    #[sea_orm(column_type = "Uuid")]
+    pub resource_id: Uuid,
+    #[sea_orm(column_type = "String(StringLen::None)", nullable)]
+    pub resource_name: Option<String>,
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub action: String,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub actor_id: Option<Uuid>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub changes: serde_json::Value,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::ActorId",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Actor,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Actor.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
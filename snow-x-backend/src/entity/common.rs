@@ -25,6 +25,8 @@ pub enum ActionType {
     UserSignedIn,
     #[sea_orm(string_value = "user_signed_out")]
     UserSignedOut,
+    #[sea_orm(string_value = "user_locked_out")]
+    UserLockedOut,
     #[sea_orm(string_value = "post_created")]
     PostCreated,
     #[sea_orm(string_value = "post_updated")]
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key, column_type = "Uuid")]
     pub id: Uuid,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub tenant_id: Option<Uuid>,
     #[sea_orm(column_type = "String(StringLen::None)")]
     pub name: String,
     #[sea_orm(column_type = "String(StringLen::None)", nullable)]
@@ -17,6 +17,7 @@ pub struct Model {
     pub started_at: DateTimeWithTimeZone,
     #[schema(value_type = Option<String>)]
     pub completed_at: Option<DateTimeWithTimeZone>,
+    pub trigger: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -7,6 +7,8 @@ use utoipa::ToSchema;
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub tenant_id: Option<Uuid>,
     pub name: String,
     pub description: Option<String>,
     pub content: String,
@@ -14,6 +16,14 @@ pub struct Model {
     pub created_at: DateTimeWithTimeZone,
     #[schema(value_type = String)]
     pub updated_at: DateTimeWithTimeZone,
+    pub schedule_cron: Option<String>,
+    pub schedule_timezone: String,
+    pub schedule_dry_run: bool,
+    pub schedule_enabled: bool,
+    #[schema(value_type = Option<String>)]
+    pub last_run_at: Option<DateTimeWithTimeZone>,
+    #[schema(value_type = Option<String>)]
+    pub next_run_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
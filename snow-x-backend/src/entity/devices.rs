@@ -7,6 +7,8 @@ pub struct Model {
     #[sea_orm(primary_key, column_type = "Uuid")]
     pub id: Uuid,
     #[sea_orm(column_type = "Uuid", nullable)]
+    pub tenant_id: Option<Uuid>,
+    #[sea_orm(column_type = "Uuid", nullable)]
     pub rack_id: Option<Uuid>,
     #[sea_orm(column_type = "String(StringLen::N(255))")]
     pub name: String,
@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "dns_zone_serials")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "Uuid")]
+    pub ip_range_id: Uuid,
+    #[sea_orm(column_type = "String(StringLen::None)")]
+    pub zone_name: String,
+    #[sea_orm(column_type = "String(StringLen::None)")]
+    pub zone_kind: String,
+    pub serial: i64,
+    #[sea_orm(column_type = "String(StringLen::None)")]
+    pub content_hash: String,
+    #[sea_orm(column_type = "Text")]
+    pub content: String,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub generated_by: Option<Uuid>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::ip_ranges::Entity",
+        from = "Column::IpRangeId",
+        to = "super::ip_ranges::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    IpRange,
+}
+
+impl Related<super::ip_ranges::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::IpRange.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,61 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "external_api_connections")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub tenant_id: Option<Uuid>,
+    #[sea_orm(column_type = "String(StringLen::N(255))", unique)]
+    pub name: String,
+    #[sea_orm(column_type = "String(StringLen::N(500))")]
+    pub base_url: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub description: Option<String>,
+    #[sea_orm(column_type = "Json", nullable)]
+    pub headers: Option<serde_json::Value>,
+    #[sea_orm(column_type = "Json", nullable)]
+    pub auth_config: Option<serde_json::Value>,
+    pub sync_interval: Option<i32>,
+    pub is_active: Option<bool>,
+    pub auto_sync: Option<bool>,
+    pub last_sync_at: Option<DateTimeWithTimeZone>,
+    /// 다음 자동 동기화 시각 (epoch 초)
+    pub next_sync_at: Option<i32>,
+    pub sync_count: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub last_error_message: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    #[sea_orm(column_type = "Json", nullable)]
+    pub field_mapping: Option<serde_json::Value>,
+    #[sea_orm(column_type = "String(StringLen::N(50))")]
+    pub target_type: String,
+    /// 필드별 소유 규칙 (`{"<field>": "locked" | "manual_override"}`)
+    #[sea_orm(column_type = "Json", nullable)]
+    pub field_policies: Option<serde_json::Value>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::external_api_sync_logs::Entity")]
+    SyncLogs,
+    #[sea_orm(has_many = "super::external_api_data::Entity")]
+    Data,
+}
+
+impl Related<super::external_api_sync_logs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SyncLogs.def()
+    }
+}
+
+impl Related<super::external_api_data::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Data.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
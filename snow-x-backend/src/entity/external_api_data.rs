@@ -0,0 +1,46 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "external_api_data")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub connection_id: i32,
+    #[sea_orm(column_type = "String(StringLen::N(255))", nullable)]
+    pub external_id: Option<String>,
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub data_type: String,
+    #[sea_orm(column_type = "Json")]
+    pub raw_data: serde_json::Value,
+    #[sea_orm(column_type = "Json", nullable)]
+    pub processed_data: Option<serde_json::Value>,
+    /// 원본 항목의 sha256 (키 정렬 JSON 기준)
+    #[sea_orm(column_type = "String(StringLen::N(64))")]
+    pub hash: String,
+    #[sea_orm(column_type = "String(StringLen::N(50))", nullable)]
+    pub status: Option<String>,
+    pub last_sync_at: DateTimeWithTimeZone,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::external_api_connections::Entity",
+        from = "Column::ConnectionId",
+        to = "super::external_api_connections::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Connection,
+}
+
+impl Related<super::external_api_connections::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Connection.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,54 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "external_api_sync_logs")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub connection_id: i32,
+    #[sea_orm(column_type = "String(StringLen::N(50))")]
+    pub status: String,
+    #[sea_orm(column_type = "String(StringLen::N(1000))")]
+    pub request_url: String,
+    #[sea_orm(column_type = "String(StringLen::N(10))")]
+    pub request_method: String,
+    #[sea_orm(column_type = "Json", nullable)]
+    pub request_headers: Option<serde_json::Value>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub request_body: Option<String>,
+    pub response_status: Option<i32>,
+    #[sea_orm(column_type = "Json", nullable)]
+    pub response_headers: Option<serde_json::Value>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub response_body: Option<String>,
+    pub records_processed: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error_message: Option<String>,
+    /// 요청 소요 시간 (ms)
+    pub duration: Option<i32>,
+    pub started_at: DateTimeWithTimeZone,
+    pub completed_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::external_api_connections::Entity",
+        from = "Column::ConnectionId",
+        to = "super::external_api_connections::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Connection,
+}
+
+impl Related<super::external_api_connections::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Connection.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
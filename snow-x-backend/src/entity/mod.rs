@@ -2,6 +2,8 @@
 
 pub mod prelude;
 
+pub mod api_tokens;
+pub mod audit_logs;
 pub mod comments;
 pub mod common;
 pub mod contact_resource_mappings;
@@ -12,12 +14,19 @@ pub mod device_ip_mappings;
 pub mod device_library;
 pub mod device_library_mappings;
 pub mod devices;
+pub mod dns_zone_serials;
 pub mod drafts;
+pub mod external_api_connections;
+pub mod external_api_data;
+pub mod external_api_sync_logs;
 pub mod follows;
 pub mod hash_tags;
 pub mod ip_addresses;
 pub mod ip_ranges;
 pub mod likes;
+pub mod notification_digest_items;
+pub mod notification_preferences;
+pub mod notification_resource_subscriptions;
 pub mod notifications_outbox;
 pub mod office;
 pub mod post_hash_tags;
@@ -25,7 +34,19 @@ pub mod posts;
 pub mod racks;
 pub mod reports;
 pub mod server_rooms;
+pub mod sync_conflicts;
+pub mod sync_field_overrides;
 pub mod system_events;
+pub mod tenant_memberships;
+pub mod tenants;
+pub mod two_factor_role_policies;
+pub mod user_notifications;
 pub mod user_oauth_connections;
+pub mod user_office_scopes;
+pub mod user_recovery_codes;
 pub mod user_refresh_tokens;
+pub mod user_two_factor;
 pub mod users;
+pub mod webhook_deliveries;
+pub mod webhook_delivery_attempts;
+pub mod webhook_subscriptions;
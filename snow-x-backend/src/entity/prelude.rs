@@ -1,17 +1,26 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.16
 #![allow(unused_imports)]
 
+pub use super::api_tokens::Entity as ApiTokens;
+pub use super::audit_logs::Entity as AuditLogs;
 pub use super::comments::Entity as Comments;
 pub use super::custodian_executions::Entity as CustodianExecutions;
 pub use super::custodian_policies::Entity as CustodianPolicies;
 pub use super::device_ip_mappings::Entity as DeviceIpMappings;
 pub use super::devices::Entity as Devices;
+pub use super::dns_zone_serials::Entity as DnsZoneSerials;
 pub use super::drafts::Entity as Drafts;
+pub use super::external_api_connections::Entity as ExternalApiConnections;
+pub use super::external_api_data::Entity as ExternalApiData;
+pub use super::external_api_sync_logs::Entity as ExternalApiSyncLogs;
 pub use super::follows::Entity as Follows;
 pub use super::hash_tags::Entity as HashTags;
 pub use super::ip_addresses::Entity as IpAddresses;
 pub use super::ip_ranges::Entity as IpRanges;
 pub use super::likes::Entity as Likes;
+pub use super::notification_digest_items::Entity as NotificationDigestItems;
+pub use super::notification_preferences::Entity as NotificationPreferences;
+pub use super::notification_resource_subscriptions::Entity as NotificationResourceSubscriptions;
 pub use super::notifications_outbox::Entity as NotificationsOutbox;
 pub use super::office::Entity as Office;
 pub use super::post_hash_tags::Entity as PostHashTags;
@@ -19,7 +28,19 @@ pub use super::posts::Entity as Posts;
 pub use super::racks::Entity as Racks;
 pub use super::reports::Entity as Reports;
 pub use super::server_rooms::Entity as ServerRooms;
+pub use super::sync_conflicts::Entity as SyncConflicts;
+pub use super::sync_field_overrides::Entity as SyncFieldOverrides;
 pub use super::system_events::Entity as SystemEvents;
+pub use super::tenant_memberships::Entity as TenantMemberships;
+pub use super::tenants::Entity as Tenants;
+pub use super::two_factor_role_policies::Entity as TwoFactorRolePolicies;
+pub use super::user_notifications::Entity as UserNotifications;
 pub use super::user_oauth_connections::Entity as UserOauthConnections;
+pub use super::user_office_scopes::Entity as UserOfficeScopes;
+pub use super::user_recovery_codes::Entity as UserRecoveryCodes;
 pub use super::user_refresh_tokens::Entity as UserRefreshTokens;
+pub use super::user_two_factor::Entity as UserTwoFactor;
 pub use super::users::Entity as Users;
+pub use super::webhook_deliveries::Entity as WebhookDeliveries;
+pub use super::webhook_delivery_attempts::Entity as WebhookDeliveryAttempts;
+pub use super::webhook_subscriptions::Entity as WebhookSubscriptions;
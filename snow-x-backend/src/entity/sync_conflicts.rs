@@ -0,0 +1,64 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "sync_conflicts")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "String(StringLen::N(50))")]
+    pub resource_type: String,
+    #[sea_orm(column_type = "Uuid")]
+    pub resource_id: Uuid,
+    #[sea_orm(column_type = "String(StringLen::None)", nullable)]
+    pub resource_name: Option<String>,
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub field: String,
+    pub external_api_connection_id: i32,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub local_value: Option<serde_json::Value>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub source_value: Option<serde_json::Value>,
+    /// pending, accepted_source, kept_local
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub status: String,
+    pub detected_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::external_api_connections::Entity",
+        from = "Column::ExternalApiConnectionId",
+        to = "super::external_api_connections::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    ExternalApiConnection,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::ResolvedBy",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    ResolvedBy,
+}
+
+impl Related<super::external_api_connections::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ExternalApiConnection.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ResolvedBy.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
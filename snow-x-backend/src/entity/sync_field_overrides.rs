@@ -0,0 +1,57 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "sync_field_overrides")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "String(StringLen::N(50))")]
+    pub resource_type: String,
+    #[sea_orm(column_type = "Uuid")]
+    pub resource_id: Uuid,
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub field: String,
+    pub external_api_connection_id: i32,
+    /// 마지막으로 확인한 원본 값
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub source_value: Option<serde_json::Value>,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub overridden_by: Option<Uuid>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::external_api_connections::Entity",
+        from = "Column::ExternalApiConnectionId",
+        to = "super::external_api_connections::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    ExternalApiConnection,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::OverriddenBy",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    OverriddenBy,
+}
+
+impl Related<super::external_api_connections::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ExternalApiConnection.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OverriddenBy.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "tenants")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    pub name: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub description: Option<String>,
+    #[sea_orm(column_type = "Json", nullable)]
+    pub settings: Option<serde_json::Value>,
+    #[sea_orm(column_type = "Uuid")]
+    pub created_by: Uuid,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    pub is_active: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::tenant_memberships::Entity")]
+    TenantMemberships,
+}
+
+impl Related<super::tenant_memberships::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TenantMemberships.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
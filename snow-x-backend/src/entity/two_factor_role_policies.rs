@@ -0,0 +1,35 @@
+use crate::entity::common::UserRole;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "two_factor_role_policies")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub role: UserRole,
+    /// 이 역할의 사용자는 2단계 인증을 등록해야 로그인할 수 있다
+    pub required: bool,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub updated_by: Option<Uuid>,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UpdatedBy",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Users,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,67 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "user_notifications")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "Uuid")]
+    pub user_id: Uuid,
+    /// 이 알림을 만든 outbox 행
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub outbox_id: Option<Uuid>,
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub category: String,
+    #[sea_orm(column_type = "String(StringLen::N(255))", nullable)]
+    pub title: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub message: Option<String>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub payload: Option<serde_json::Value>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub link: Option<String>,
+    #[sea_orm(column_type = "String(StringLen::N(30))", nullable)]
+    pub resource_type: Option<String>,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub resource_id: Option<Uuid>,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub actor_id: Option<Uuid>,
+    #[sea_orm(nullable)]
+    pub read_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Users,
+    #[sea_orm(
+        belongs_to = "super::notifications_outbox::Entity",
+        from = "Column::OutboxId",
+        to = "super::notifications_outbox::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    NotificationsOutbox,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl Related<super::notifications_outbox::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NotificationsOutbox.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "user_office_scopes")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "Uuid")]
+    pub user_id: Uuid,
+    #[sea_orm(column_type = "Uuid")]
+    pub office_id: Uuid,
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Users,
+    #[sea_orm(
+        belongs_to = "super::office::Entity",
+        from = "Column::OfficeId",
+        to = "super::office::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Office,
+}
+
+impl Related<super::office::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Office.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
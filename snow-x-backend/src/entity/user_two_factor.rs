@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "user_two_factor")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false, column_type = "Uuid")]
+    pub user_id: Uuid,
+    /// TOTP 비밀키 (Base32)
+    #[serde(skip_serializing)]
+    #[sea_orm(column_type = "String(StringLen::N(64))")]
+    pub secret: String,
+    /// 비어 있으면 등록 확인 전
+    pub enabled_at: Option<DateTimeWithTimeZone>,
+    /// 마지막으로 받아들인 코드의 시간 구간
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Users,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -34,6 +34,12 @@ pub struct Model {
     #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
     pub created_at: DateTimeUtc,
     pub role: UserRole,
+    /// 비밀번호 없이 API 토큰으로만 쓰는 자동화용 계정
+    #[sea_orm(column_type = "Boolean", not_null, default_value = "false")]
+    pub is_service_account: bool,
+    /// 자동 작업의 작성자로 서버가 만든 시스템 사용자 (하나만 존재)
+    #[sea_orm(column_type = "Boolean", not_null, default_value = "false")]
+    pub is_system: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,75 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "webhook_deliveries")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "Uuid")]
+    pub subscription_id: Uuid,
+    /// 같은 이벤트를 받은 구독끼리 공유하는 이벤트 ID
+    #[sea_orm(column_type = "Uuid")]
+    pub event_id: Uuid,
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub event_type: String,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub payload: serde_json::Value,
+    /// pending, success, failed
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub status: String,
+    pub attempt_count: i32,
+    #[sea_orm(nullable)]
+    pub response_status: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub last_error: Option<String>,
+    /// 이 배달을 맡은 최근 outbox 행
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub notification_id: Option<Uuid>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    #[sea_orm(nullable)]
+    pub delivered_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::webhook_subscriptions::Entity",
+        from = "Column::SubscriptionId",
+        to = "super::webhook_subscriptions::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    WebhookSubscriptions,
+    #[sea_orm(
+        belongs_to = "super::notifications_outbox::Entity",
+        from = "Column::NotificationId",
+        to = "super::notifications_outbox::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    NotificationsOutbox,
+    #[sea_orm(has_many = "super::webhook_delivery_attempts::Entity")]
+    WebhookDeliveryAttempts,
+}
+
+impl Related<super::webhook_subscriptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebhookSubscriptions.def()
+    }
+}
+
+impl Related<super::notifications_outbox::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NotificationsOutbox.def()
+    }
+}
+
+impl Related<super::webhook_delivery_attempts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebhookDeliveryAttempts.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
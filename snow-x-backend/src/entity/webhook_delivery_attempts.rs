@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "webhook_delivery_attempts")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "Uuid")]
+    pub id: Uuid,
+    #[sea_orm(column_type = "Uuid")]
+    pub delivery_id: Uuid,
+    pub attempt: i32,
+    #[sea_orm(column_type = "Text")]
+    pub request_url: String,
+    #[sea_orm(nullable)]
+    pub response_status: Option<i32>,
+    /// 응답 본문 앞부분
+    #[sea_orm(column_type = "Text", nullable)]
+    pub response_body: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error: Option<String>,
+    /// 요청 소요 시간 (ms)
+    pub duration: i32,
+    pub attempted_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::webhook_deliveries::Entity",
+        from = "Column::DeliveryId",
+        to = "super::webhook_deliveries::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    WebhookDeliveries,
+}
+
+impl Related<super::webhook_deliveries::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebhookDeliveries.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
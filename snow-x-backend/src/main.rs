@@ -4,8 +4,12 @@ use crate::connection::cloudflare_r2::establish_r2_connection;
 use crate::connection::database::establish_connection;
 use crate::connection::http::create_http_client;
 use crate::connection::meilisearch::MeilisearchClient;
+use crate::connection::outbound::OutboundHttpClient;
 use crate::connection::redis_connection::establish_redis_connection;
 use crate::middleware::cors::cors_layer;
+use crate::service::api_token::init_api_token_auth;
+use crate::service::rate_limit::init_rate_limiter;
+use crate::service::realtime::{RealtimeHub, init_realtime_publisher};
 use crate::state::AppState;
 use crate::utils::logger::init_tracing;
 use axum::Router;
@@ -31,15 +35,41 @@ pub async fn run_server() -> anyhow::Result<()> {
         error!("Failed to establish cloudflare_r2 connection: {}", e);
         anyhow::anyhow!("R2 connection failed: {}", e)
     })?;
-    let redis = establish_redis_connection().await.map_err(|e| {
-        error!("Failed to establish redis connection: {}", e);
-        anyhow::anyhow!("Redis connection failed: {}", e)
+    let realtime = RealtimeHub::new();
+    let mut redis = establish_redis_connection(realtime.clone())
+        .await
+        .map_err(|e| {
+            error!("Failed to establish redis connection: {}", e);
+            anyhow::anyhow!("Redis connection failed: {}", e)
+        })?;
+    // 실시간 이벤트 발행/구독 (여러 인스턴스 사이의 전달은 Redis pub/sub이 맡는다)
+    realtime.listen(&mut redis).await.map_err(|e| {
+        error!("Failed to subscribe to realtime events: {}", e);
+        anyhow::anyhow!("Realtime subscription failed: {}", e)
     })?;
+    init_realtime_publisher(redis.clone());
+    // 인증 경로 요청 제한 (미들웨어는 AppState를 받지 않는다)
+    init_rate_limiter(redis.clone());
+    // API 토큰 인증 (인증 미들웨어는 AppState를 받지 않는다)
+    init_api_token_auth(conn.clone());
     let http_client = create_http_client().await.map_err(|e| {
         error!("Failed to create HTTP client: {}", e);
         anyhow::anyhow!("HTTP client creation failed: {}", e)
     })?;
 
+    // 웹훅 구독 주소로 보내는 클라이언트 (내부망 주소 차단, 리다이렉트 안 따라감)
+    let webhook_client = OutboundHttpClient::new(DbConfig::get().webhook_allow_private_urls)
+        .map_err(|e| {
+            error!("Failed to create webhook HTTP client: {}", e);
+            anyhow::anyhow!("Webhook HTTP client creation failed: {}", e)
+        })?;
+
+    let external_api_client =
+        OutboundHttpClient::new(DbConfig::get().external_api_allow_private_urls).map_err(|e| {
+            error!("Failed to create external API HTTP client: {}", e);
+            anyhow::anyhow!("External API HTTP client creation failed: {}", e)
+        })?;
+
     let meilisearch = MeilisearchClient::new().map_err(|e| {
         error!("Failed to create Meilisearch client: {}", e);
         anyhow::anyhow!("Meilisearch client creation failed: {}", e)
@@ -53,6 +83,33 @@ pub async fn run_server() -> anyhow::Result<()> {
         info!("Meilisearch posts index setup completed");
     }
 
+    // DHCP 임대 파일 감시 및 만료 임대 정리
+    crate::service::dhcp_lease::worker::spawn_dhcp_lease_workers(conn.clone());
+
+    // External API 자동 동기화
+    crate::service::external_api::scheduler::spawn_external_api_scheduler(
+        conn.clone(),
+        external_api_client.clone(),
+    );
+
+    // Custodian 정책 예약 실행 및 실행 상태 동기화
+    crate::service::custodian_scheduler::spawn_custodian_scheduler(
+        conn.clone(),
+        http_client.clone(),
+    );
+    crate::service::custodian_reconciler::spawn_custodian_reconciler(
+        conn.clone(),
+        http_client.clone(),
+    );
+
+    // 알림 outbox 발송
+    crate::service::notification::dispatcher::spawn_notification_dispatcher(
+        conn.clone(),
+        http_client.clone(),
+        webhook_client,
+    );
+    crate::service::notification::digest::spawn_notification_digest(conn.clone());
+
     let server_url = format!(
         "{}:{}",
         &DbConfig::get().server_host,
@@ -67,7 +124,9 @@ pub async fn run_server() -> anyhow::Result<()> {
             cloudflare_r2,
             redis,
             http_client,
+            external_api_client,
             meilisearch,
+            realtime,
         });
 
     info!("Starting server at: {}", server_url);
@@ -0,0 +1,137 @@
+use crate::config::db_config::DbConfig;
+use reqwest::Client;
+use serde::Deserialize;
+use std::sync::LazyLock;
+use tracing::{info, warn};
+
+#[derive(Deserialize)]
+struct TaskResponse {
+    task_id: Option<String>,
+}
+
+/// 태스크 상태 (`GET /tasks/tasks/status/{task_id}`)
+#[derive(Deserialize, Debug)]
+pub struct TaskStatusResponse {
+    /// Celery 상태: PENDING, STARTED, RETRY, SUCCESS, FAILURE, REVOKED
+    pub status: String,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<String>,
+}
+
+/// 태스크 서버 URL을 캐시하는 정적 변수
+static TASK_SERVER_URL: LazyLock<String> = LazyLock::new(|| {
+    let config = DbConfig::get();
+    format!(
+        "http://{}:{}",
+        config.task_server_host, config.task_server_port
+    )
+});
+
+/// 태스크 서버 URL을 가져오는 함수
+fn get_task_server_url() -> &'static str {
+    &TASK_SERVER_URL
+}
+
+/// Custodian 정책 실행을 태스크 서버에 요청. 태스크 ID를 돌려준다.
+pub async fn queue_custodian_execution(
+    http_client: &Client,
+    policy_id: &str,
+    policy_content: &str,
+    execution_id: &str,
+    dry_run: bool,
+) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
+    let task_server_url = get_task_server_url();
+
+    info!("Queuing custodian execution task: {}", execution_id);
+
+    let request_body = serde_json::json!({
+        "policy_id": policy_id,
+        "policy_content": policy_content,
+        "execution_id": execution_id,
+        "dry_run": dry_run
+    });
+
+    let response = http_client
+        .post(format!("{}/tasks/custodian/execute", task_server_url))
+        .json(&request_body)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        warn!(
+            "Custodian execution task queue failed: {} - {}",
+            status, error_text
+        );
+        return Err(format!("Task queue request failed: {} - {}", status, error_text).into());
+    }
+
+    // 큐에는 들어갔으므로 응답을 읽지 못해도 실패로 보지 않는다 (태스크 ID만 잃는다)
+    match response.json::<TaskResponse>().await {
+        Ok(task_response) => Ok(task_response.task_id),
+        Err(e) => {
+            warn!("Failed to parse custodian execution task response: {}", e);
+            Ok(None)
+        }
+    }
+}
+
+/// 태스크 상태 조회
+pub async fn get_task_status(
+    http_client: &Client,
+    task_id: &str,
+) -> Result<TaskStatusResponse, Box<dyn std::error::Error + Send + Sync>> {
+    let task_server_url = get_task_server_url();
+
+    let response = http_client
+        .get(format!(
+            "{}/tasks/tasks/status/{}",
+            task_server_url, task_id
+        ))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(format!("Task status request failed: {} - {}", status, error_text).into());
+    }
+
+    Ok(response.json().await?)
+}
+
+/// 대기 중이거나 실행 중인 태스크를 취소한다 (실행 중이면 워커 프로세스를 종료한다)
+pub async fn revoke_custodian_task(
+    http_client: &Client,
+    task_id: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let task_server_url = get_task_server_url();
+
+    info!("Revoking custodian execution task: {}", task_id);
+
+    let response = http_client
+        .post(format!(
+            "{}/tasks/custodian/revoke/{}",
+            task_server_url, task_id
+        ))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(format!("Task revoke request failed: {} - {}", status, error_text).into());
+    }
+
+    Ok(())
+}
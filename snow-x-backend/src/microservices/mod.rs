@@ -1,4 +1,5 @@
 pub mod admin_tasks_client;
+pub mod custodian_client;
 pub mod email_client;
 pub mod markdown_client;
 pub mod search_client;
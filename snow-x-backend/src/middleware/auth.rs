@@ -1,12 +1,44 @@
 use crate::dto::auth::internal::refresh_token::RefreshTokenContext;
 use crate::dto::auth::response::sign_out::SignOutResponse;
+use crate::service::api_token::{is_api_token, service_authenticate_api_token};
 use crate::service::auth::jwt::{decode_access_token, decode_refresh_token};
 use crate::service::error::errors::Errors;
+use crate::utils::extract_ip_address::extract_ip_address;
 use axum::body::Body;
+use axum::extract::{ConnectInfo, OriginalUri};
 use axum::http::Request;
 use axum::http::header::COOKIE;
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
+use std::net::SocketAddr;
+
+/// Bearer 토큰으로 요청을 인증하고 `AccessTokenClaims`를 extension으로 넣는다.
+/// API 토큰(`snx_pat_...`)이면 토큰 범위로 이 경로를 부를 수 있는지도 확인하고 `ApiTokenContext`를 함께 넣는다.
+async fn authenticate_bearer(req: &mut Request<Body>, token: &str) -> Result<(), Errors> {
+    if !is_api_token(token) {
+        let token_data = decode_access_token(token).map_err(|_| Errors::UserUnauthorized)?;
+        req.extensions_mut().insert(token_data.claims);
+        return Ok(());
+    }
+
+    let client_ip = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| extract_ip_address(req.headers(), *addr));
+    let (claims, context) = service_authenticate_api_token(token, client_ip).await?;
+
+    // 중첩 라우터 안에서는 uri가 잘려 있으므로 원래 경로로 범위를 확인한다
+    let path = req
+        .extensions()
+        .get::<OriginalUri>()
+        .map(|OriginalUri(uri)| uri.path().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    context.require_scope(&path, req.method())?;
+
+    req.extensions_mut().insert(claims);
+    req.extensions_mut().insert(context);
+    Ok(())
+}
 
 pub async fn access_jwt_auth(mut req: Request<Body>, next: Next) -> Result<Response, Errors> {
     let auth_header = req
@@ -24,10 +56,29 @@ pub async fn access_jwt_auth(mut req: Request<Body>, next: Next) -> Result<Respo
         return Err(Errors::UserUnauthorized);
     };
 
-    let token_data = match decode_access_token(&token) {
-        Ok(data) => data,
-        Err(_) => return Err(Errors::UserUnauthorized),
-    };
+    authenticate_bearer(&mut req, &token).await?;
+    Ok(next.run(req).await)
+}
+
+/// WebSocket·SSE 연결용 인증 미들웨어. 브라우저의 WebSocket/EventSource는 헤더를 붙일 수 없으므로
+/// `Authorization` 헤더가 없으면 `access_token` 쿼리 파라미터의 토큰을 쓴다.
+pub async fn stream_jwt_auth(mut req: Request<Body>, next: Next) -> Result<Response, Errors> {
+    let header_token = req
+        .headers()
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "));
+    let query_token = req.uri().query().and_then(|query| {
+        query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("access_token="))
+    });
+
+    let token = header_token
+        .or(query_token)
+        .ok_or(Errors::UserUnauthorized)?
+        .to_string();
+    let token_data = decode_access_token(&token).map_err(|_| Errors::UserUnauthorized)?;
     req.extensions_mut().insert(token_data.claims);
     Ok(next.run(req).await)
 }
@@ -44,9 +95,7 @@ pub async fn optional_access_jwt_auth(mut req: Request<Body>, next: Next) -> Res
             let token = header.trim_start_matches("Bearer ").to_string();
 
             // 토큰이 유효하면 Extension에 추가
-            if let Ok(token_data) = decode_access_token(&token) {
-                req.extensions_mut().insert(token_data.claims);
-            }
+            let _ = authenticate_bearer(&mut req, &token).await;
             // 토큰이 유효하지 않아도 에러를 발생시키지 않고 진행
         }
     }
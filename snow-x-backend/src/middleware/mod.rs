@@ -1,3 +1,5 @@
 pub mod anonymous_user;
 pub mod auth;
 pub mod cors;
+pub mod permission;
+pub mod rate_limit;
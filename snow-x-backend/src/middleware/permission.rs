@@ -0,0 +1,425 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::service::api_token::ApiTokenContext;
+use crate::service::auth::permission::{
+    AccessScope, OfficeBinding, PermissionAction, PermissionResource, load_access_scope,
+    office_of_device, office_of_ip_address, office_of_ip_range, office_of_rack,
+    office_of_server_room,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::tenant::{
+    TenantOwner, tenant_of_contact, tenant_of_custodian_execution, tenant_of_custodian_policy,
+    tenant_of_device, tenant_of_device_library, tenant_of_ip_address, tenant_of_ip_range,
+    tenant_of_office, tenant_of_rack, tenant_of_server_room,
+};
+use crate::state::AppState;
+use axum::extract::{FromRequestParts, RawPathParams};
+use axum::http::Method;
+use axum::http::request::Parts;
+use sea_orm::ConnectionTrait;
+use std::marker::PhantomData;
+use uuid::Uuid;
+
+/// `Authorized<R>`에 쓰는 리소스 종류
+pub trait ResourceKind: Send + Sync {
+    const RESOURCE: PermissionResource;
+}
+
+/// 핸들러 시그니처에 쓰는 리소스 표시 타입
+pub mod resource {
+    use super::ResourceKind;
+    use crate::service::auth::permission::PermissionResource;
+
+    macro_rules! resource_kind {
+        ($($name:ident),* $(,)?) => {
+            $(
+                pub struct $name;
+
+                impl ResourceKind for $name {
+                    const RESOURCE: PermissionResource = PermissionResource::$name;
+                }
+            )*
+        };
+    }
+
+    resource_kind!(
+        Office,
+        ServerRoom,
+        Rack,
+        Device,
+        IpRange,
+        IpAddress,
+        Contact,
+        DeviceLibrary,
+        Custodian,
+        ExternalApi,
+    );
+}
+
+/// `Authorized<R, A>`에 쓰는 작업 종류
+pub trait ActionKind: Send + Sync {
+    fn action(method: &Method) -> PermissionAction;
+}
+
+/// 작업 표시 타입. 기본값 `ByMethod`는 HTTP 메서드로 작업을 정하고, 나머지는 메서드와 상관없이
+/// 고정한다 (예: `DELETE`로 연결만 끊는 요청은 `Write`)
+pub mod action {
+    use super::ActionKind;
+    use crate::service::auth::permission::PermissionAction;
+    use axum::http::Method;
+
+    pub struct ByMethod;
+
+    impl ActionKind for ByMethod {
+        fn action(method: &Method) -> PermissionAction {
+            PermissionAction::from_method(method)
+        }
+    }
+
+    macro_rules! fixed_action {
+        ($($name:ident),* $(,)?) => {
+            $(
+                pub struct $name;
+
+                impl ActionKind for $name {
+                    fn action(_method: &Method) -> PermissionAction {
+                        PermissionAction::$name
+                    }
+                }
+            )*
+        };
+    }
+
+    fixed_action!(Read, Write);
+}
+
+/// 요청의 테넌트를 정하는 헤더. 없으면 액세스 토큰의 `tenant_id` 클레임을 쓴다
+pub const TENANT_HEADER: &str = "x-tenant-id";
+
+/// 토큰과 `X-Tenant-Id` 헤더로 사용자의 테넌트와 접근 범위를 읽는다
+async fn load_request_scope(
+    parts: &Parts,
+    state: &AppState,
+) -> Result<(AccessTokenClaims, AccessScope), Errors> {
+    let claims = parts
+        .extensions
+        .get::<AccessTokenClaims>()
+        .cloned()
+        .ok_or(Errors::UserUnauthorized)?;
+
+    let header_tenant_id = match parts.headers.get(TENANT_HEADER) {
+        Some(value) => Some(
+            value
+                .to_str()
+                .ok()
+                .and_then(|value| Uuid::parse_str(value.trim()).ok())
+                .ok_or_else(|| {
+                    Errors::BadRequestError(format!("Invalid {} header", TENANT_HEADER))
+                })?,
+        ),
+        None => None,
+    };
+
+    // 테넌트를 고정한 API 토큰은 다른 테넌트를 고를 수 없다
+    if let Some(pinned_tenant_id) = parts
+        .extensions
+        .get::<ApiTokenContext>()
+        .and_then(|context| context.tenant_id)
+        && header_tenant_id.is_some_and(|tenant_id| tenant_id != pinned_tenant_id)
+    {
+        return Err(Errors::TenantAccessDenied(format!(
+            "This API token can only be used with tenant {}",
+            pinned_tenant_id
+        )));
+    }
+
+    let scope = load_access_scope(
+        &state.conn,
+        claims.sub,
+        header_tenant_id.or(claims.tenant_id),
+    )
+    .await?;
+    Ok((claims, scope))
+}
+
+/// 리소스 종류와 상관없이 테넌트만 정하는 extractor (감사 로그 피드처럼 여러 리소스에 걸친 조회).
+/// `access_jwt_auth` 뒤에서 쓴다.
+pub struct TenantContext {
+    pub scope: AccessScope,
+}
+
+impl TenantContext {
+    pub fn tenant_id(&self) -> Uuid {
+        self.scope.tenant_id
+    }
+}
+
+impl FromRequestParts<AppState> for TenantContext {
+    type Rejection = Errors;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let (_, scope) = load_request_scope(parts, state).await?;
+        Ok(TenantContext { scope })
+    }
+}
+
+/// 리소스 `R`에 대한 권한을 확인하는 extractor. `access_jwt_auth` 뒤에서 쓴다.
+///
+/// 요청의 테넌트를 정한 뒤, 경로의 `office_id`, `server_room_id`, `rack_id`, `contact_id`,
+/// `ip_address_id`, `execution_id`, `id`가 가리키는 리소스가 모두 그 테넌트에 속하는지 확인한다
+/// (아니면 `Errors::NotFound`). 작업은 `A`로 정하고 (기본은 HTTP 메서드), 사무실 범위가 있는 사용자는
+/// 그 리소스들이 자기 사무실에 속하는지도 확인한다 (아니면 `Errors::PermissionDenied`).
+/// 요청 본문으로 대상을 정하는 작업은 핸들러에서 `ensure_*`로 따로 확인한다.
+pub struct Authorized<R: ResourceKind, A: ActionKind = action::ByMethod> {
+    pub claims: AccessTokenClaims,
+    pub scope: AccessScope,
+    pub action: PermissionAction,
+    _kind: PhantomData<(R, A)>,
+}
+
+impl<R: ResourceKind, A: ActionKind> Authorized<R, A> {
+    pub fn user_id(&self) -> Uuid {
+        self.claims.sub
+    }
+
+    pub fn tenant_id(&self) -> Uuid {
+        self.scope.tenant_id
+    }
+
+    /// 요청 본문이 가리키는 리소스가 이 테넌트에 속하는지 확인
+    pub fn ensure_owned(&self, owner: TenantOwner) -> ServiceResult<()> {
+        self.scope.require_owner(R::RESOURCE, owner)
+    }
+
+    /// 서버실이 테넌트와 사무실 범위 안인지 확인
+    pub async fn ensure_server_room<C>(&self, conn: &C, server_room_id: Uuid) -> ServiceResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        self.ensure_owned(tenant_of_server_room(conn, server_room_id).await?)?;
+        match office_of_server_room(conn, server_room_id).await? {
+            Some(binding) => self
+                .scope
+                .require_binding(R::RESOURCE, self.action, binding),
+            None => Ok(()),
+        }
+    }
+
+    /// 랙이 테넌트와 사무실 범위 안인지 확인
+    pub async fn ensure_rack<C>(&self, conn: &C, rack_id: Uuid) -> ServiceResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        self.ensure_owned(tenant_of_rack(conn, rack_id).await?)?;
+        match office_of_rack(conn, rack_id).await? {
+            Some(binding) => self
+                .scope
+                .require_binding(R::RESOURCE, self.action, binding),
+            None => Ok(()),
+        }
+    }
+
+    /// 사무실이 테넌트와 사무실 범위 안인지 확인 (본문의 사무실에 리소스를 만들 때)
+    pub async fn ensure_office<C>(&self, conn: &C, office_id: Uuid) -> ServiceResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        self.scope.require_owner(
+            PermissionResource::Office,
+            tenant_of_office(conn, office_id).await?,
+        )?;
+        self.scope
+            .require_binding(R::RESOURCE, self.action, OfficeBinding::Office(office_id))
+    }
+
+    /// 장비가 테넌트와 사무실 범위 안인지 확인
+    pub async fn ensure_device<C>(&self, conn: &C, device_id: Uuid) -> ServiceResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        self.scope.require_owner(
+            PermissionResource::Device,
+            tenant_of_device(conn, device_id).await?,
+        )?;
+        match office_of_device(conn, device_id).await? {
+            Some(binding) => self
+                .scope
+                .require_binding(R::RESOURCE, self.action, binding),
+            None => Ok(()),
+        }
+    }
+
+    /// IP 대역이 테넌트와 사무실 범위 안인지 확인
+    pub async fn ensure_ip_range<C>(&self, conn: &C, ip_range_id: Uuid) -> ServiceResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        self.scope.require_owner(
+            PermissionResource::IpRange,
+            tenant_of_ip_range(conn, ip_range_id).await?,
+        )?;
+        match office_of_ip_range(conn, ip_range_id).await? {
+            Some(binding) => self
+                .scope
+                .require_binding(R::RESOURCE, self.action, binding),
+            None => Ok(()),
+        }
+    }
+
+    /// IP 주소가 테넌트와 사무실 범위 안인지 확인
+    pub async fn ensure_ip_address<C>(&self, conn: &C, ip_address_id: Uuid) -> ServiceResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        self.scope.require_owner(
+            PermissionResource::IpAddress,
+            tenant_of_ip_address(conn, ip_address_id).await?,
+        )?;
+        match office_of_ip_address(conn, ip_address_id).await? {
+            Some(binding) => self
+                .scope
+                .require_binding(R::RESOURCE, self.action, binding),
+            None => Ok(()),
+        }
+    }
+
+    /// 장비를 둘 랙이 범위 안인지 확인. 랙 없이 두는 장비는 사무실 범위가 없는 사용자만 다룬다
+    pub async fn ensure_placement<C>(&self, conn: &C, rack_id: Option<Uuid>) -> ServiceResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        match rack_id {
+            Some(rack_id) => self.ensure_rack(conn, rack_id).await,
+            None => self
+                .scope
+                .require_binding(R::RESOURCE, self.action, OfficeBinding::Unassigned),
+        }
+    }
+
+    /// 테넌트의 모든 사무실에 걸치는 작업인지 확인
+    pub fn ensure_all_offices(&self) -> ServiceResult<()> {
+        self.scope.require_all_offices(R::RESOURCE, self.action)
+    }
+}
+
+/// 경로 파라미터 이름이 가리키는 리소스 종류
+fn path_param_resource(name: &str, resource: PermissionResource) -> Option<PermissionResource> {
+    match name {
+        "office_id" => Some(PermissionResource::Office),
+        "server_room_id" => Some(PermissionResource::ServerRoom),
+        "rack_id" => Some(PermissionResource::Rack),
+        "contact_id" => Some(PermissionResource::Contact),
+        "ip_address_id" => Some(PermissionResource::IpAddress),
+        "execution_id" => Some(PermissionResource::Custodian),
+        "id" => Some(resource),
+        _ => None,
+    }
+}
+
+async fn tenant_of_path_resource<C>(
+    conn: &C,
+    name: &str,
+    resource: PermissionResource,
+    id: Uuid,
+) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    match resource {
+        PermissionResource::Office => tenant_of_office(conn, id).await,
+        PermissionResource::ServerRoom => tenant_of_server_room(conn, id).await,
+        PermissionResource::Rack => tenant_of_rack(conn, id).await,
+        PermissionResource::Device => tenant_of_device(conn, id).await,
+        PermissionResource::IpRange => tenant_of_ip_range(conn, id).await,
+        PermissionResource::IpAddress => tenant_of_ip_address(conn, id).await,
+        PermissionResource::Contact => tenant_of_contact(conn, id).await,
+        PermissionResource::DeviceLibrary => tenant_of_device_library(conn, id).await,
+        PermissionResource::Custodian if name == "execution_id" => {
+            tenant_of_custodian_execution(conn, id).await
+        }
+        PermissionResource::Custodian => tenant_of_custodian_policy(conn, id).await,
+        // 연결 ID는 정수라 여기까지 오지 않는다. 핸들러가 `tenant_of_external_api_connection`으로 확인한다
+        PermissionResource::ExternalApi => Ok(TenantOwner::Missing),
+    }
+}
+
+async fn office_of_path_resource<C>(
+    conn: &C,
+    resource: PermissionResource,
+    id: Uuid,
+) -> ServiceResult<Option<OfficeBinding>>
+where
+    C: ConnectionTrait,
+{
+    match resource {
+        PermissionResource::Office => Ok(Some(OfficeBinding::Office(id))),
+        PermissionResource::ServerRoom => office_of_server_room(conn, id).await,
+        PermissionResource::Rack => office_of_rack(conn, id).await,
+        PermissionResource::Device => office_of_device(conn, id).await,
+        PermissionResource::IpRange => office_of_ip_range(conn, id).await,
+        PermissionResource::IpAddress => office_of_ip_address(conn, id).await,
+        _ => Ok(None),
+    }
+}
+
+/// 경로 파라미터가 가리키는 리소스가 모두 테넌트와 사무실 범위 안인지 확인.
+/// `/office/{office_id}/server-room/{id}`처럼 여러 리소스가 있으면 모두 확인한다
+async fn check_path_resources<C>(
+    conn: &C,
+    scope: &AccessScope,
+    resource: PermissionResource,
+    action: PermissionAction,
+    params: &RawPathParams,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    for (name, value) in params.iter() {
+        // UUID가 아닌 파라미터(DNS 영역 이름 등)는 핸들러가 처리한다
+        let (Some(target), Ok(id)) = (path_param_resource(name, resource), Uuid::parse_str(value))
+        else {
+            continue;
+        };
+
+        scope.require_owner(
+            target,
+            tenant_of_path_resource(conn, name, target, id).await?,
+        )?;
+
+        if scope.is_office_restricted()
+            && target.is_office_bound()
+            && let Some(binding) = office_of_path_resource(conn, target, id).await?
+        {
+            scope.require_binding(resource, action, binding)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<R: ResourceKind, A: ActionKind> FromRequestParts<AppState> for Authorized<R, A> {
+    type Rejection = Errors;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let (claims, scope) = load_request_scope(parts, state).await?;
+        let action = A::action(&parts.method);
+        scope.require(R::RESOURCE, action)?;
+
+        // 경로 파라미터가 없거나 읽을 수 없으면 핸들러의 `Path`가 처리한다
+        if let Ok(params) = RawPathParams::from_request_parts(parts, state).await {
+            check_path_resources(&state.conn, &scope, R::RESOURCE, action, &params).await?;
+        }
+
+        Ok(Authorized {
+            claims,
+            scope,
+            action,
+            _kind: PhantomData,
+        })
+    }
+}
@@ -0,0 +1,64 @@
+use crate::service::auth::jwt::decode_two_factor_challenge_token;
+use crate::service::error::errors::Errors;
+use crate::service::rate_limit::{RateLimitRoute, check_rate_limit};
+use crate::utils::extract_ip_address::extract_ip_address;
+use axum::body::{Body, to_bytes};
+use axum::extract::{ConnectInfo, OriginalUri};
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use serde_json::Value;
+use std::net::SocketAddr;
+
+/// 계정별로 셀 때 읽는 요청 본문의 최대 크기. 인증 요청 본문은 이보다 훨씬 작다
+const MAX_BODY_BYTES: usize = 16 * 1024;
+
+/// 요청 본문(JSON)에서 계정을 찾는다. 2단계 인증은 대기 토큰의 사용자, 나머지는 `handle`이나 `email`.
+/// 대기 토큰을 풀 수 없으면 IP로만 센다 (핸들러가 어차피 거절한다).
+fn account_from_body(route: RateLimitRoute, body: &[u8]) -> Option<String> {
+    let value: Value = serde_json::from_slice(body).ok()?;
+    if route == RateLimitRoute::TwoFactor {
+        let token = value.get("challenge_token").and_then(Value::as_str)?;
+        return decode_two_factor_challenge_token(token)
+            .ok()
+            .map(|data| format!("user:{}", data.claims.sub));
+    }
+    ["handle", "email"]
+        .iter()
+        .find_map(|field| value.get(field).and_then(Value::as_str))
+        .map(ToString::to_string)
+}
+
+/// 인증 경로의 요청 횟수를 경로·클라이언트 IP·계정별로 제한한다.
+/// 한도를 넘으면 핸들러를 부르지 않고 `Retry-After`와 함께 429로 답한다.
+pub async fn rate_limit(req: Request<Body>, next: Next) -> Result<Response, Errors> {
+    // 중첩 라우터 안에서는 uri가 잘려 있으므로 원래 경로로 찾는다
+    let path = req
+        .extensions()
+        .get::<OriginalUri>()
+        .map(|OriginalUri(uri)| uri.path().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    let Some(route) = RateLimitRoute::from_path(&path) else {
+        return Ok(next.run(req).await);
+    };
+
+    let client_ip = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| extract_ip_address(req.headers(), *addr))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let (req, account) = if route.keyed_by_account() {
+        let (parts, body) = req.into_parts();
+        let bytes = to_bytes(body, MAX_BODY_BYTES)
+            .await
+            .map_err(|_| Errors::BadRequestError("Request body is too large".to_string()))?;
+        let account = account_from_body(route, &bytes);
+        (Request::from_parts(parts, Body::from(bytes)), account)
+    } else {
+        (req, None)
+    };
+
+    check_rate_limit(route, &client_ip, account.as_deref()).await?;
+    Ok(next.run(req).await)
+}
@@ -7,6 +7,7 @@ use uuid::Uuid;
 #[allow(clippy::too_many_arguments)]
 pub async fn repository_create_device<C>(
     conn: &C,
+    tenant_id: &Uuid,
     rack_id: Option<&Uuid>,
     name: &str,
     description: Option<&str>,
@@ -27,6 +28,7 @@ where
 {
     let new_device = ActiveModel {
         id: Set(Uuid::new_v4()),
+        tenant_id: Set(Some(*tenant_id)),
         rack_id: Set(rack_id.copied()),
         name: Set(name.to_string()),
         description: Set(description.map(|s| s.to_string())),
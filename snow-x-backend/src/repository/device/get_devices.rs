@@ -1,8 +1,10 @@
 use crate::entity::devices::{Column, Entity as DeviceEntity, Model as DeviceModel};
+use crate::service::auth::permission::AccessScope;
 use crate::service::error::errors::Errors;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
 use uuid::Uuid;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn repository_get_devices<C>(
     conn: &C,
     page: u64,
@@ -11,11 +13,14 @@ pub async fn repository_get_devices<C>(
     device_type: Option<&str>,
     status: Option<&str>,
     rack_id: Option<&Uuid>,
+    scope: &AccessScope,
 ) -> Result<(Vec<DeviceModel>, u64), Errors>
 where
     C: ConnectionTrait,
 {
-    let mut query = DeviceEntity::find().filter(Column::IsActive.eq(true));
+    let mut query = DeviceEntity::find()
+        .filter(Column::IsActive.eq(true))
+        .filter(Column::TenantId.eq(scope.tenant_id));
 
     if let Some(search_term) = search {
         query = query.filter(Column::Name.contains(search_term));
@@ -33,6 +38,11 @@ where
         query = query.filter(Column::RackId.eq(Some(*rack)));
     }
 
+    // 사무실 범위가 있으면 그 사무실의 랙에 있는 장비만 (랙에 넣지 않은 장비는 빠진다)
+    if scope.is_office_restricted() {
+        query = query.filter(Column::RackId.in_subquery(scope.visible_racks()));
+    }
+
     let total = query
         .clone()
         .count(conn)
@@ -1,4 +1,5 @@
 use crate::entity::racks::{Entity as Rack, Model as RackModel};
+use crate::service::auth::permission::AccessScope;
 use crate::service::error::errors::Errors;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter};
 
@@ -6,11 +7,16 @@ pub async fn repository_get_racks<C>(
     conn: &C,
     page: u64,
     limit: u64,
+    scope: &AccessScope,
 ) -> Result<(Vec<RackModel>, u64), Errors>
 where
     C: ConnectionTrait,
 {
-    let query = Rack::find().filter(crate::entity::racks::Column::IsActive.eq(true));
+    let query = Rack::find()
+        .filter(crate::entity::racks::Column::IsActive.eq(true))
+        .filter(
+            crate::entity::racks::Column::ServerRoomId.in_subquery(scope.visible_server_rooms()),
+        );
 
     let total = query
         .clone()
@@ -0,0 +1,39 @@
+use crate::entity::common::UserRole;
+use crate::entity::users::{ActiveModel as UserActiveModel, Model as UserModel};
+use crate::service::error::errors::Errors;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
+use uuid::Uuid;
+
+/// 로그인할 수 없는(비밀번호 없음) 시스템 사용자를 만든다
+pub async fn repository_create_system_user<C>(
+    conn: &C,
+    id: Option<Uuid>,
+    name: &str,
+    handle: &str,
+    email: &str,
+) -> Result<UserModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let new_user = UserActiveModel {
+        id: id.map(Set).unwrap_or_default(),
+        name: Set(name.to_string()),
+        handle: Set(handle.to_string()),
+        bio: Set(None),
+        location: Set(None),
+        website: Set(None),
+        email: Set(email.to_string()),
+        password: Set(None),
+        is_verified: Set(true),
+        profile_image: Set(None),
+        banner_image: Set(None),
+        follower_count: Set(0),
+        following_count: Set(0),
+        created_at: Default::default(),
+        role: Set(UserRole::Staff),
+        is_service_account: Set(false),
+        is_system: Set(true),
+    };
+
+    Ok(new_user.insert(conn).await?)
+}
@@ -30,6 +30,8 @@ where
         following_count: Set(0),
         created_at: Default::default(),
         role: Set(UserRole::Admin),
+        is_service_account: Set(false),
+        is_system: Set(false),
     };
 
     let user = new_user.insert(txn).await?;
@@ -0,0 +1,14 @@
+use crate::entity::users::{Column, Entity as UserEntity, Model as UserModel};
+use crate::service::error::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+
+/// 서버가 만든(`is_system`) 시스템 사용자를 찾는다
+pub async fn repository_find_system_user<C>(conn: &C) -> Result<Option<UserModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    Ok(UserEntity::find()
+        .filter(Column::IsSystem.eq(true))
+        .one(conn)
+        .await?)
+}
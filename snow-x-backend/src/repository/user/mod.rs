@@ -1,4 +1,6 @@
+pub mod create_system_user;
 pub mod create_user;
+pub mod find_system_user;
 pub mod find_user_by_email;
 pub mod find_user_by_handle;
 pub mod find_user_by_uuid;
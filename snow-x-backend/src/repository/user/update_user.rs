@@ -37,6 +37,8 @@ where
         following_count: NotSet,
         created_at: NotSet,
         role: NotSet,
+        is_service_account: NotSet,
+        is_system: NotSet,
     };
 
     // 업데이트 실행
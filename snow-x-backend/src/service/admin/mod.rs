@@ -7,3 +7,4 @@ pub mod search_stats;
 pub mod sync_all_counts;
 pub mod sync_follows;
 pub mod sync_likes;
+pub mod user_permissions;
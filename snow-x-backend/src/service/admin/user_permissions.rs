@@ -0,0 +1,134 @@
+use crate::dto::admin::request::UpdateUserPermissionsRequest;
+use crate::dto::admin::response::{ResourcePermission, UserPermissionsResponse};
+use crate::entity::common::UserRole;
+use crate::entity::{office, user_office_scopes, users};
+use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
+use crate::service::auth::permission::{
+    PermissionAction, PermissionResource, get_user_office_ids, role_allows,
+};
+use crate::service::auth::role_check::require_admin;
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, Set, TransactionTrait,
+};
+use tracing::info;
+use uuid::Uuid;
+
+/// 사용자의 역할, 사무실 범위, 리소스별 권한
+pub async fn service_get_user_permissions(
+    conn: &DatabaseConnection,
+    admin_id: Uuid,
+    user_id: Uuid,
+) -> ServiceResult<UserPermissionsResponse> {
+    require_admin(conn, admin_id).await?;
+
+    build_user_permissions(conn, user_id).await
+}
+
+/// 사용자의 역할과 사무실 범위를 바꾼다
+pub async fn service_update_user_permissions(
+    conn: &DatabaseConnection,
+    admin_id: Uuid,
+    user_id: Uuid,
+    request: UpdateUserPermissionsRequest,
+) -> ServiceResult<UserPermissionsResponse> {
+    require_admin(conn, admin_id).await?;
+
+    // 마지막 Admin이 스스로 역할을 낮추면 아무도 권한을 되돌릴 수 없다
+    if admin_id == user_id
+        && let Some(role) = &request.role
+        && *role != UserRole::Admin
+    {
+        return Err(Errors::BadRequestError(
+            "You cannot change your own admin role".to_string(),
+        ));
+    }
+
+    let txn = conn.begin().await?;
+
+    let user = repository_find_user_by_uuid(&txn, &user_id)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+
+    if let Some(role) = request.role {
+        let mut active: users::ActiveModel = user.into();
+        active.role = Set(role);
+        active.update(&txn).await?;
+    }
+
+    if let Some(mut office_ids) = request.office_ids {
+        office_ids.sort();
+        office_ids.dedup();
+
+        if !office_ids.is_empty() {
+            let found = office::Entity::find()
+                .filter(office::Column::Id.is_in(office_ids.clone()))
+                .count(&txn)
+                .await?;
+            if found != office_ids.len() as u64 {
+                return Err(Errors::BadRequestError(
+                    "One or more offices do not exist".to_string(),
+                ));
+            }
+        }
+
+        user_office_scopes::Entity::delete_many()
+            .filter(user_office_scopes::Column::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+
+        let now = chrono::Utc::now();
+        for office_id in office_ids {
+            user_office_scopes::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                user_id: Set(user_id),
+                office_id: Set(office_id),
+                created_by: Set(Some(admin_id)),
+                created_at: Set(now.into()),
+            }
+            .insert(&txn)
+            .await?;
+        }
+    }
+
+    txn.commit().await?;
+
+    info!(
+        "Admin user {} updated permissions of user {}",
+        admin_id, user_id
+    );
+
+    build_user_permissions(conn, user_id).await
+}
+
+async fn build_user_permissions<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> ServiceResult<UserPermissionsResponse>
+where
+    C: ConnectionTrait,
+{
+    let user = repository_find_user_by_uuid(conn, &user_id)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+    let office_ids = get_user_office_ids(conn, user_id).await?;
+
+    let permissions = PermissionResource::ALL
+        .iter()
+        .map(|&resource| ResourcePermission {
+            resource,
+            read: role_allows(&user.role, resource, PermissionAction::Read),
+            write: role_allows(&user.role, resource, PermissionAction::Write),
+            delete: role_allows(&user.role, resource, PermissionAction::Delete),
+        })
+        .collect();
+
+    Ok(UserPermissionsResponse {
+        user_id,
+        office_restricted: user.role != UserRole::Admin && !office_ids.is_empty(),
+        role: user.role,
+        office_ids,
+        permissions,
+    })
+}
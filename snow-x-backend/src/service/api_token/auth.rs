@@ -0,0 +1,120 @@
+use super::scope::{ApiTokenScope, require_scope, scopes_from_json};
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::entity::{api_tokens, users};
+use crate::service::error::errors::{Errors, ServiceResult};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use axum::http::Method;
+use chrono::{Duration, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter};
+use sha2::{Digest, Sha256};
+use std::sync::OnceLock;
+use tracing::warn;
+use uuid::Uuid;
+
+/// API 토큰 앞부분. `Authorization: Bearer` 값이 이것으로 시작하면 JWT 대신 API 토큰으로 인증한다.
+pub const API_TOKEN_PREFIX: &str = "snx_pat_";
+
+/// 목록에 보여줄 토큰 앞부분 길이 (`API_TOKEN_PREFIX` 포함)
+const DISPLAY_PREFIX_LEN: usize = 12;
+
+/// `last_used_at`을 이보다 자주 갱신하지 않는다 (요청마다 쓰기를 만들지 않도록)
+const LAST_USED_UPDATE_INTERVAL_SECONDS: i64 = 60;
+
+/// 인증 미들웨어에서 쓰는 연결. 서버 시작 시 `init_api_token_auth`로 정한다.
+static AUTH_CONN: OnceLock<DatabaseConnection> = OnceLock::new();
+
+pub fn init_api_token_auth(conn: DatabaseConnection) {
+    if AUTH_CONN.set(conn).is_err() {
+        warn!("API token authentication is already initialized");
+    }
+}
+
+/// API 토큰으로 인증한 요청의 토큰 정보 (요청 extension으로 넣는다)
+#[derive(Debug, Clone)]
+pub struct ApiTokenContext {
+    pub scopes: Vec<ApiTokenScope>,
+    /// 지정하면 이 테넌트로만 요청할 수 있다
+    pub tenant_id: Option<Uuid>,
+}
+
+impl ApiTokenContext {
+    pub fn require_scope(&self, path: &str, method: &Method) -> ServiceResult<()> {
+        require_scope(&self.scopes, path, method)
+    }
+}
+
+pub fn is_api_token(token: &str) -> bool {
+    token.starts_with(API_TOKEN_PREFIX)
+}
+
+pub fn hash_api_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+/// 새 토큰 원문과 목록에 보여줄 앞부분
+pub fn generate_api_token() -> (String, String) {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    let token = format!("{}{}", API_TOKEN_PREFIX, hex);
+    let display_prefix = token[..DISPLAY_PREFIX_LEN].to_string();
+    (token, display_prefix)
+}
+
+/// API 토큰을 확인하고 요청에 쓸 클레임을 만든다.
+/// 폐기·만료된 토큰, 없는 토큰은 모두 `UserUnauthorized`로 답한다.
+pub async fn service_authenticate_api_token(
+    token: &str,
+    client_ip: Option<String>,
+) -> ServiceResult<(AccessTokenClaims, ApiTokenContext)> {
+    let conn = AUTH_CONN.get().ok_or(Errors::UserUnauthorized)?;
+    let now = Utc::now();
+
+    let (api_token, user) = api_tokens::Entity::find()
+        .find_also_related(users::Entity)
+        .filter(api_tokens::Column::TokenHash.eq(hash_api_token(token)))
+        .filter(api_tokens::Column::RevokedAt.is_null())
+        .filter(
+            Condition::any()
+                .add(api_tokens::Column::ExpiresAt.is_null())
+                .add(api_tokens::Column::ExpiresAt.gt(now)),
+        )
+        .one(conn)
+        .await?
+        .ok_or(Errors::UserUnauthorized)?;
+    let user = user.ok_or(Errors::UserUnauthorized)?;
+
+    let stale_before = now - Duration::seconds(LAST_USED_UPDATE_INTERVAL_SECONDS);
+    if api_token
+        .last_used_at
+        .is_none_or(|last_used_at| last_used_at < stale_before)
+    {
+        // 사용 기록은 부가 정보이므로 실패해도 요청은 진행한다
+        if let Err(e) = api_tokens::Entity::update_many()
+            .col_expr(api_tokens::Column::LastUsedAt, Expr::value(now))
+            .col_expr(api_tokens::Column::LastUsedIp, Expr::value(client_ip))
+            .filter(api_tokens::Column::Id.eq(api_token.id))
+            .exec(conn)
+            .await
+        {
+            warn!("Failed to record API token {} usage: {}", api_token.id, e);
+        }
+    }
+
+    let claims = AccessTokenClaims {
+        sub: user.id,
+        iat: now.timestamp(),
+        exp: api_token
+            .expires_at
+            .map(|expires_at| expires_at.timestamp())
+            .unwrap_or(i64::MAX),
+        tenant_id: api_token.tenant_id,
+    };
+    let context = ApiTokenContext {
+        scopes: scopes_from_json(&api_token.scopes),
+        tenant_id: api_token.tenant_id,
+    };
+
+    Ok((claims, context))
+}
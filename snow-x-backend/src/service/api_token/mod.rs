@@ -0,0 +1,22 @@
+//! 개인 액세스 토큰과 서비스 계정.
+//!
+//! 자동화 작업(Terraform, Ansible 등)은 로그인 대신 `Authorization: Bearer snx_pat_...` 토큰을 쓴다.
+//! 토큰은 사용자나 서비스 계정에 속하고, 허용 범위(`ipam:read` 등) 안의 경로만 부를 수 있으며,
+//! 실제 권한은 토큰 주인의 역할과 테넌트 소속을 그대로 따른다. 토큰 원문은 SHA-256 해시만 저장한다.
+
+pub mod auth;
+pub mod scope;
+pub mod service_account;
+pub mod tokens;
+
+pub use auth::{
+    ApiTokenContext, init_api_token_auth, is_api_token, service_authenticate_api_token,
+};
+pub use service_account::{
+    service_create_service_account, service_create_service_account_token,
+    service_get_service_account_tokens, service_get_service_accounts,
+    service_revoke_service_account_token,
+};
+pub use tokens::{
+    service_create_my_api_token, service_get_my_api_tokens, service_revoke_my_api_token,
+};
@@ -0,0 +1,260 @@
+use crate::service::error::errors::{Errors, ServiceResult};
+use axum::http::Method;
+use std::fmt;
+
+/// API 토큰의 허용 범위. `*:write`는 같은 영역의 `*:read`를 포함한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiTokenScope {
+    IpamRead,
+    IpamWrite,
+    PostsRead,
+    PostsWrite,
+}
+
+pub const API_TOKEN_SCOPES: [ApiTokenScope; 4] = [
+    ApiTokenScope::IpamRead,
+    ApiTokenScope::IpamWrite,
+    ApiTokenScope::PostsRead,
+    ApiTokenScope::PostsWrite,
+];
+
+/// IPAM 영역 경로 (Custodian 포함)
+const IPAM_PATHS: [&str; 2] = ["/v0/ipam", "/v0/custodian"];
+
+/// 포스트 영역 경로
+const POST_PATHS: [&str; 5] = [
+    "/v0/post",
+    "/v0/posts",
+    "/v0/draft",
+    "/v0/drafts",
+    "/v0/comment",
+];
+
+/// 포스트 영역에서 POST 메서드를 쓰지만 읽기만 하는 경로
+const POST_READ_PATHS: [&str; 10] = [
+    "/v0/post/get",
+    "/v0/post/get_by_handle_and_slug",
+    "/v0/posts",
+    "/v0/posts/user",
+    "/v0/posts/search",
+    "/v0/comment/get",
+    "/v0/comment/list",
+    "/v0/comment/replies",
+    "/v0/draft/get",
+    "/v0/drafts",
+];
+
+impl ApiTokenScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiTokenScope::IpamRead => "ipam:read",
+            ApiTokenScope::IpamWrite => "ipam:write",
+            ApiTokenScope::PostsRead => "posts:read",
+            ApiTokenScope::PostsWrite => "posts:write",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        API_TOKEN_SCOPES
+            .into_iter()
+            .find(|scope| scope.as_str() == value.trim())
+    }
+
+    /// 이 범위로 `required` 범위의 요청을 할 수 있는지
+    pub fn grants(&self, required: ApiTokenScope) -> bool {
+        *self == required
+            || matches!(
+                (self, required),
+                (ApiTokenScope::IpamWrite, ApiTokenScope::IpamRead)
+                    | (ApiTokenScope::PostsWrite, ApiTokenScope::PostsRead)
+            )
+    }
+}
+
+impl fmt::Display for ApiTokenScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn is_under(path: &str, prefix: &str) -> bool {
+    path == prefix
+        || path
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn is_read_method(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+/// 요청에 필요한 범위. `None`이면 API 토큰으로 부를 수 없는 경로다
+/// (토큰 발급, 관리자 기능, 계정 설정처럼 로그인 세션이 필요한 작업).
+pub fn required_scope(path: &str, method: &Method) -> Option<ApiTokenScope> {
+    if IPAM_PATHS.iter().any(|prefix| is_under(path, prefix)) {
+        return Some(if is_read_method(method) {
+            ApiTokenScope::IpamRead
+        } else {
+            ApiTokenScope::IpamWrite
+        });
+    }
+
+    if POST_PATHS.iter().any(|prefix| is_under(path, prefix)) {
+        let read =
+            is_read_method(method) || (*method == Method::POST && POST_READ_PATHS.contains(&path));
+        return Some(if read {
+            ApiTokenScope::PostsRead
+        } else {
+            ApiTokenScope::PostsWrite
+        });
+    }
+
+    None
+}
+
+/// 토큰 범위로 요청을 할 수 있는지 확인
+pub fn require_scope(scopes: &[ApiTokenScope], path: &str, method: &Method) -> ServiceResult<()> {
+    let required = required_scope(path, method).ok_or_else(|| {
+        Errors::ApiTokenScopeDenied(format!(
+            "{} {} cannot be called with an API token",
+            method, path
+        ))
+    })?;
+
+    if scopes.iter().any(|scope| scope.grants(required)) {
+        Ok(())
+    } else {
+        Err(Errors::ApiTokenScopeDenied(format!(
+            "{} {} requires the {} scope",
+            method, path, required
+        )))
+    }
+}
+
+/// 요청의 범위 문자열을 검증하고 중복을 없앤다
+pub fn parse_scopes(values: &[String]) -> ServiceResult<Vec<ApiTokenScope>> {
+    if values.is_empty() {
+        return Err(Errors::ValidationError(
+            "At least one scope is required".to_string(),
+        ));
+    }
+
+    let mut scopes = Vec::new();
+    for value in values {
+        let scope = ApiTokenScope::parse(value).ok_or_else(|| {
+            Errors::ValidationError(format!(
+                "Unknown scope '{}'. Allowed scopes: {}",
+                value,
+                API_TOKEN_SCOPES.map(|scope| scope.as_str()).join(", ")
+            ))
+        })?;
+        if !scopes.contains(&scope) {
+            scopes.push(scope);
+        }
+    }
+    Ok(scopes)
+}
+
+/// 저장된 범위(JSON 문자열 배열)를 읽는다. 모르는 값은 건너뛴다
+pub fn scopes_from_json(value: &serde_json::Value) -> Vec<ApiTokenScope> {
+    value
+        .as_array()
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|value| value.as_str().and_then(ApiTokenScope::parse))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn scopes_to_json(scopes: &[ApiTokenScope]) -> serde_json::Value {
+    serde_json::Value::Array(
+        scopes
+            .iter()
+            .map(|scope| serde_json::Value::String(scope.as_str().to_string()))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ipam_paths_need_ipam_scopes() {
+        assert_eq!(
+            required_scope("/v0/ipam/ip-ranges", &Method::GET),
+            Some(ApiTokenScope::IpamRead)
+        );
+        assert_eq!(
+            required_scope("/v0/ipam", &Method::HEAD),
+            Some(ApiTokenScope::IpamRead)
+        );
+        for method in [Method::POST, Method::PUT, Method::PATCH, Method::DELETE] {
+            assert_eq!(
+                required_scope("/v0/ipam/devices/1", &method),
+                Some(ApiTokenScope::IpamWrite),
+                "{}",
+                method
+            );
+        }
+        assert_eq!(
+            required_scope("/v0/custodian/policies", &Method::POST),
+            Some(ApiTokenScope::IpamWrite)
+        );
+    }
+
+    #[test]
+    fn post_paths_split_read_and_write() {
+        assert_eq!(
+            required_scope("/v0/post/get", &Method::POST),
+            Some(ApiTokenScope::PostsRead)
+        );
+        assert_eq!(
+            required_scope("/v0/comment/list", &Method::POST),
+            Some(ApiTokenScope::PostsRead)
+        );
+        assert_eq!(
+            required_scope("/v0/posts/user", &Method::GET),
+            Some(ApiTokenScope::PostsRead)
+        );
+        assert_eq!(
+            required_scope("/v0/post", &Method::POST),
+            Some(ApiTokenScope::PostsWrite)
+        );
+        assert_eq!(
+            required_scope("/v0/comment/create", &Method::POST),
+            Some(ApiTokenScope::PostsWrite)
+        );
+        // 읽기 경로 아래라도 다른 경로는 쓰기다
+        assert_eq!(
+            required_scope("/v0/post/get/extra", &Method::POST),
+            Some(ApiTokenScope::PostsWrite)
+        );
+    }
+
+    #[test]
+    fn other_paths_are_not_callable_with_tokens() {
+        for path in [
+            "/v0/api-tokens",
+            "/v0/admin/users",
+            "/v0/user/me",
+            "/v0/ipamx",
+            "/v0/postsx",
+            "/v0",
+        ] {
+            assert_eq!(required_scope(path, &Method::GET), None, "{}", path);
+        }
+    }
+
+    #[test]
+    fn write_scopes_include_read() {
+        assert!(require_scope(&[ApiTokenScope::IpamWrite], "/v0/ipam/racks", &Method::GET).is_ok());
+        assert!(
+            require_scope(&[ApiTokenScope::IpamRead], "/v0/ipam/racks", &Method::POST).is_err()
+        );
+        assert!(require_scope(&[ApiTokenScope::IpamWrite], "/v0/posts", &Method::GET).is_err());
+        assert!(require_scope(&[ApiTokenScope::PostsWrite], "/v0/user/me", &Method::GET).is_err());
+    }
+}
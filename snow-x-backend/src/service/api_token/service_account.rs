@@ -0,0 +1,177 @@
+use super::tokens::{issue_api_token, list_api_tokens, revoke_api_token};
+use crate::dto::api_token::request::{CreateApiTokenRequest, CreateServiceAccountRequest};
+use crate::dto::api_token::response::{
+    ApiTokenListResponse, ApiTokenResponse, ApiTokenSecretResponse, ServiceAccountListResponse,
+    ServiceAccountResponse,
+};
+use crate::entity::common::UserRole;
+use crate::entity::{api_tokens, tenant_memberships, tenants, users};
+use crate::service::auth::role_check::require_admin;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::user::check_handle_availability::service_check_handle_availability;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use tracing::info;
+use uuid::Uuid;
+
+/// 서비스 계정 이메일 도메인. 실제로 메일을 받지 않는 예약 도메인을 쓴다
+const SERVICE_ACCOUNT_EMAIL_DOMAIN: &str = "service-account.invalid";
+
+async fn active_token_count(conn: &DatabaseConnection, account_id: Uuid) -> ServiceResult<u64> {
+    Ok(api_tokens::Entity::find()
+        .filter(api_tokens::Column::UserId.eq(account_id))
+        .filter(api_tokens::Column::RevokedAt.is_null())
+        .count(conn)
+        .await?)
+}
+
+async fn find_service_account(
+    conn: &DatabaseConnection,
+    account_id: Uuid,
+) -> ServiceResult<users::Model> {
+    users::Entity::find_by_id(account_id)
+        .filter(users::Column::IsServiceAccount.eq(true))
+        .one(conn)
+        .await?
+        .ok_or_else(|| Errors::NotFound("Service account not found".to_string()))
+}
+
+/// 서비스 계정 목록 (Admin 전용)
+pub async fn service_get_service_accounts(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+) -> ServiceResult<ServiceAccountListResponse> {
+    require_admin(conn, user_id).await?;
+
+    let accounts = users::Entity::find()
+        .filter(users::Column::IsServiceAccount.eq(true))
+        .order_by_asc(users::Column::Handle)
+        .all(conn)
+        .await?;
+
+    let mut service_accounts = Vec::with_capacity(accounts.len());
+    for account in accounts {
+        service_accounts.push(ServiceAccountResponse {
+            active_token_count: active_token_count(conn, account.id).await?,
+            id: account.id,
+            handle: account.handle,
+            name: account.name,
+            created_at: account.created_at,
+        });
+    }
+
+    Ok(ServiceAccountListResponse { service_accounts })
+}
+
+/// 서비스 계정을 만든다 (Admin 전용). 비밀번호가 없어 로그인할 수 없고 API 토큰으로만 쓴다.
+pub async fn service_create_service_account(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    request: CreateServiceAccountRequest,
+) -> ServiceResult<ServiceAccountResponse> {
+    require_admin(conn, user_id).await?;
+
+    let handle = request.handle.trim().to_string();
+    if !service_check_handle_availability(conn, &handle).await? {
+        return Err(Errors::UserHandleAlreadyExists);
+    }
+
+    if let Some(tenant_id) = request.tenant_id {
+        tenants::Entity::find_by_id(tenant_id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| Errors::NotFound("Tenant not found".to_string()))?;
+    }
+
+    let txn = conn.begin().await?;
+    let account_id = Uuid::new_v4();
+
+    // 서비스 계정의 전체 역할은 Staff로 두고, 권한은 테넌트 소속 역할로 준다
+    let account = users::ActiveModel {
+        id: Set(account_id),
+        name: Set(request.name.trim().to_string()),
+        handle: Set(handle),
+        bio: Set(None),
+        location: Set(None),
+        website: Set(None),
+        email: Set(format!("{}@{}", account_id, SERVICE_ACCOUNT_EMAIL_DOMAIN)),
+        password: Set(None),
+        is_verified: Set(true),
+        profile_image: Set(None),
+        banner_image: Set(None),
+        follower_count: Set(0),
+        following_count: Set(0),
+        created_at: Set(Utc::now()),
+        role: Set(UserRole::Staff),
+        is_service_account: Set(true),
+        is_system: Set(false),
+    }
+    .insert(&txn)
+    .await?;
+
+    if let Some(tenant_id) = request.tenant_id {
+        tenant_memberships::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            tenant_id: Set(tenant_id),
+            user_id: Set(account.id),
+            role: Set(request.tenant_role.unwrap_or(UserRole::Staff)),
+            created_by: Set(Some(user_id)),
+            created_at: Set(Utc::now().into()),
+        }
+        .insert(&txn)
+        .await?;
+    }
+
+    txn.commit().await?;
+
+    info!(
+        "User {} created service account {} ({})",
+        user_id, account.id, account.handle
+    );
+
+    Ok(ServiceAccountResponse {
+        id: account.id,
+        handle: account.handle,
+        name: account.name,
+        created_at: account.created_at,
+        active_token_count: 0,
+    })
+}
+
+/// 서비스 계정의 API 토큰 목록 (Admin 전용)
+pub async fn service_get_service_account_tokens(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    account_id: Uuid,
+) -> ServiceResult<ApiTokenListResponse> {
+    require_admin(conn, user_id).await?;
+    find_service_account(conn, account_id).await?;
+    list_api_tokens(conn, account_id).await
+}
+
+/// 서비스 계정의 API 토큰을 발급한다 (Admin 전용)
+pub async fn service_create_service_account_token(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    account_id: Uuid,
+    request: CreateApiTokenRequest,
+) -> ServiceResult<ApiTokenSecretResponse> {
+    require_admin(conn, user_id).await?;
+    find_service_account(conn, account_id).await?;
+    issue_api_token(conn, account_id, user_id, request).await
+}
+
+/// 서비스 계정의 API 토큰을 폐기한다 (Admin 전용)
+pub async fn service_revoke_service_account_token(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    account_id: Uuid,
+    token_id: Uuid,
+) -> ServiceResult<ApiTokenResponse> {
+    require_admin(conn, user_id).await?;
+    find_service_account(conn, account_id).await?;
+    revoke_api_token(conn, account_id, token_id, user_id).await
+}
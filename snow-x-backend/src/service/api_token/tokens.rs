@@ -0,0 +1,191 @@
+use super::auth::{generate_api_token, hash_api_token};
+use super::scope::{parse_scopes, scopes_from_json, scopes_to_json};
+use crate::dto::api_token::request::CreateApiTokenRequest;
+use crate::dto::api_token::response::{
+    ApiTokenListResponse, ApiTokenResponse, ApiTokenSecretResponse,
+};
+use crate::entity::api_tokens;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::tenant::resolve_tenant_membership;
+use chrono::{Duration, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use tracing::info;
+use uuid::Uuid;
+
+const NAME_MAX_LEN: usize = 100;
+const MAX_EXPIRES_IN_DAYS: i64 = 3650;
+
+impl From<api_tokens::Model> for ApiTokenResponse {
+    fn from(token: api_tokens::Model) -> Self {
+        Self {
+            id: token.id,
+            user_id: token.user_id,
+            name: token.name,
+            token_prefix: token.token_prefix,
+            scopes: scopes_from_json(&token.scopes)
+                .iter()
+                .map(|scope| scope.as_str().to_string())
+                .collect(),
+            tenant_id: token.tenant_id,
+            expires_at: token.expires_at,
+            last_used_at: token.last_used_at,
+            last_used_ip: token.last_used_ip,
+            created_at: token.created_at,
+            revoked_at: token.revoked_at,
+        }
+    }
+}
+
+/// 토큰을 발급한다. 토큰 원문은 해시만 저장하고 응답으로 한 번만 돌려준다.
+pub(super) async fn issue_api_token<C>(
+    conn: &C,
+    owner_id: Uuid,
+    created_by: Uuid,
+    request: CreateApiTokenRequest,
+) -> ServiceResult<ApiTokenSecretResponse>
+where
+    C: ConnectionTrait,
+{
+    let name = request.name.trim();
+    if name.is_empty() || name.chars().count() > NAME_MAX_LEN {
+        return Err(Errors::ValidationError(format!(
+            "Token name must be 1-{} characters",
+            NAME_MAX_LEN
+        )));
+    }
+    let scopes = parse_scopes(&request.scopes)?;
+
+    let now = Utc::now();
+    let expires_at = match request.expires_in_days {
+        Some(days) if !(1..=MAX_EXPIRES_IN_DAYS).contains(&days) => {
+            return Err(Errors::ValidationError(format!(
+                "expires_in_days must be between 1 and {}",
+                MAX_EXPIRES_IN_DAYS
+            )));
+        }
+        Some(days) => Some(now + Duration::days(days)),
+        None => None,
+    };
+
+    // 테넌트를 고정하는 토큰은 주인이 그 테넌트에 속해야 한다
+    if let Some(tenant_id) = request.tenant_id {
+        resolve_tenant_membership(conn, owner_id, Some(tenant_id)).await?;
+    }
+
+    let (token, token_prefix) = generate_api_token();
+    let api_token = api_tokens::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(owner_id),
+        name: Set(name.to_string()),
+        token_prefix: Set(token_prefix),
+        token_hash: Set(hash_api_token(&token)),
+        scopes: Set(scopes_to_json(&scopes)),
+        tenant_id: Set(request.tenant_id),
+        expires_at: Set(expires_at.map(Into::into)),
+        last_used_at: Set(None),
+        last_used_ip: Set(None),
+        created_by: Set(Some(created_by)),
+        created_at: Set(now.into()),
+        revoked_at: Set(None),
+    }
+    .insert(conn)
+    .await?;
+
+    info!(
+        "User {} issued API token {} for user {}",
+        created_by, api_token.id, owner_id
+    );
+
+    Ok(ApiTokenSecretResponse {
+        api_token: api_token.into(),
+        token,
+    })
+}
+
+pub(super) async fn list_api_tokens<C>(
+    conn: &C,
+    owner_id: Uuid,
+) -> ServiceResult<ApiTokenListResponse>
+where
+    C: ConnectionTrait,
+{
+    let tokens = api_tokens::Entity::find()
+        .filter(api_tokens::Column::UserId.eq(owner_id))
+        .order_by_desc(api_tokens::Column::CreatedAt)
+        .all(conn)
+        .await?
+        .into_iter()
+        .map(ApiTokenResponse::from)
+        .collect();
+
+    Ok(ApiTokenListResponse { tokens })
+}
+
+/// 토큰을 폐기한다. 이미 폐기한 토큰은 그대로 돌려준다.
+pub(super) async fn revoke_api_token<C>(
+    conn: &C,
+    owner_id: Uuid,
+    token_id: Uuid,
+    revoked_by: Uuid,
+) -> ServiceResult<ApiTokenResponse>
+where
+    C: ConnectionTrait,
+{
+    let api_token = api_tokens::Entity::find_by_id(token_id)
+        .filter(api_tokens::Column::UserId.eq(owner_id))
+        .one(conn)
+        .await?
+        .ok_or_else(|| Errors::NotFound("API token not found".to_string()))?;
+
+    if api_token.revoked_at.is_some() {
+        return Ok(api_token.into());
+    }
+
+    let mut active: api_tokens::ActiveModel = api_token.into();
+    active.revoked_at = Set(Some(Utc::now().into()));
+    let api_token = active.update(conn).await?;
+
+    info!(
+        "User {} revoked API token {} of user {}",
+        revoked_by, token_id, owner_id
+    );
+
+    Ok(api_token.into())
+}
+
+/// 내 API 토큰 목록
+pub async fn service_get_my_api_tokens<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> ServiceResult<ApiTokenListResponse>
+where
+    C: ConnectionTrait,
+{
+    list_api_tokens(conn, user_id).await
+}
+
+/// 내 API 토큰을 발급한다
+pub async fn service_create_my_api_token<C>(
+    conn: &C,
+    user_id: Uuid,
+    request: CreateApiTokenRequest,
+) -> ServiceResult<ApiTokenSecretResponse>
+where
+    C: ConnectionTrait,
+{
+    issue_api_token(conn, user_id, user_id, request).await
+}
+
+/// 내 API 토큰을 폐기한다
+pub async fn service_revoke_my_api_token<C>(
+    conn: &C,
+    user_id: Uuid,
+    token_id: Uuid,
+) -> ServiceResult<ApiTokenResponse>
+where
+    C: ConnectionTrait,
+{
+    revoke_api_token(conn, user_id, token_id, user_id).await
+}
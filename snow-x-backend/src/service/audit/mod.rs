@@ -0,0 +1,331 @@
+use crate::{
+    dto::audit::response::{AuditFieldChange, AuditLogListResponse, AuditLogResponse},
+    entity::{audit_logs, users},
+    service::error::errors::{Errors, ServiceResult},
+    service::tenant::{
+        TenantOwner, tenant_of_audit_resource, tenant_of_ip_range, tenant_of_office,
+        tenant_of_server_room,
+    },
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+};
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeSet, HashMap};
+use tracing::warn;
+use uuid::Uuid;
+
+/// 감사 로그 대상 리소스 종류
+pub const RESOURCE_OFFICE: &str = "office";
+pub const RESOURCE_SERVER_ROOM: &str = "server_room";
+pub const RESOURCE_RACK: &str = "rack";
+pub const RESOURCE_DEVICE: &str = "device";
+pub const RESOURCE_IP_RANGE: &str = "ip_range";
+pub const RESOURCE_IP_ADDRESS: &str = "ip_address";
+pub const RESOURCE_CONTACT: &str = "contact";
+pub const RESOURCE_DEVICE_LIBRARY: &str = "device_library";
+
+pub const RESOURCE_TYPES: [&str; 8] = [
+    RESOURCE_OFFICE,
+    RESOURCE_SERVER_ROOM,
+    RESOURCE_RACK,
+    RESOURCE_DEVICE,
+    RESOURCE_IP_RANGE,
+    RESOURCE_IP_ADDRESS,
+    RESOURCE_CONTACT,
+    RESOURCE_DEVICE_LIBRARY,
+];
+
+pub const ACTION_CREATE: &str = "create";
+pub const ACTION_UPDATE: &str = "update";
+pub const ACTION_DELETE: &str = "delete";
+
+/// 매 수정마다 바뀌는 값이라 diff에서 제외
+const IGNORED_FIELDS: [&str; 1] = ["updated_at"];
+
+/// 감사 로그 한 건
+pub struct AuditEntry<'a, T: Serialize> {
+    pub resource_type: &'static str,
+    pub resource_id: Uuid,
+    pub resource_name: Option<&'a str>,
+    pub action: &'static str,
+    pub actor_id: Option<Uuid>,
+    pub before: Option<&'a T>,
+    pub after: Option<&'a T>,
+}
+
+/// 감사 로그 목록 필터
+#[derive(Default)]
+pub struct AuditLogFilter {
+    pub tenant_id: Option<Uuid>,
+    pub resource_type: Option<String>,
+    pub resource_id: Option<Uuid>,
+    pub actor_id: Option<Uuid>,
+    pub action: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// 두 스냅샷의 최상위 필드를 비교해 바뀐 필드만 돌려준다.
+/// 생성은 before가, 삭제는 after가 없으므로 모든 필드가 변경으로 기록된다.
+pub fn diff_snapshots(before: Option<&Value>, after: Option<&Value>) -> Vec<AuditFieldChange> {
+    let empty = Map::new();
+    let before = before.and_then(Value::as_object).unwrap_or(&empty);
+    let after = after.and_then(Value::as_object).unwrap_or(&empty);
+
+    let fields: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+
+    fields
+        .into_iter()
+        .filter(|field| !IGNORED_FIELDS.contains(&field.as_str()))
+        .filter_map(|field| {
+            let old = before.get(field).cloned().unwrap_or(Value::Null);
+            let new = after.get(field).cloned().unwrap_or(Value::Null);
+            (old != new).then(|| AuditFieldChange {
+                field: field.clone(),
+                before: old,
+                after: new,
+            })
+        })
+        .collect()
+}
+
+fn snapshot_uuid(snapshot: Option<&Value>, field: &str) -> Option<Uuid> {
+    snapshot
+        .and_then(|snapshot| snapshot.get(field))
+        .and_then(Value::as_str)
+        .and_then(|value| Uuid::parse_str(value).ok())
+}
+
+/// 감사 로그를 남길 테넌트. 리소스가 이미 지워졌으면 스냅샷의 상위 리소스로 찾는다
+async fn resolve_audit_tenant<C>(
+    conn: &C,
+    resource_type: &str,
+    resource_id: Uuid,
+    snapshot: Option<&Value>,
+) -> ServiceResult<Option<Uuid>>
+where
+    C: ConnectionTrait,
+{
+    let owner = tenant_of_audit_resource(conn, resource_type, resource_id).await?;
+    if owner != TenantOwner::Missing {
+        return Ok(owner.tenant_id());
+    }
+
+    let owner = match resource_type {
+        RESOURCE_SERVER_ROOM => match snapshot_uuid(snapshot, "office_id") {
+            Some(office_id) => tenant_of_office(conn, office_id).await?,
+            None => TenantOwner::Missing,
+        },
+        RESOURCE_RACK => match snapshot_uuid(snapshot, "server_room_id") {
+            Some(server_room_id) => tenant_of_server_room(conn, server_room_id).await?,
+            None => TenantOwner::Missing,
+        },
+        // ip_ranges.tenant_id는 사무실을 가리킨다
+        RESOURCE_IP_RANGE => match snapshot_uuid(snapshot, "tenant_id") {
+            Some(office_id) => tenant_of_office(conn, office_id).await?,
+            None => TenantOwner::Missing,
+        },
+        RESOURCE_IP_ADDRESS => match snapshot_uuid(snapshot, "ip_range_id") {
+            Some(ip_range_id) => tenant_of_ip_range(conn, ip_range_id).await?,
+            None => TenantOwner::Missing,
+        },
+        _ => return Ok(snapshot_uuid(snapshot, "tenant_id")),
+    };
+    Ok(owner.tenant_id())
+}
+
+/// 감사 로그를 기록한다. 변경이 없는 수정은 기록하지 않는다.
+pub async fn record_audit<C, T>(conn: &C, entry: AuditEntry<'_, T>) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+    T: Serialize,
+{
+    let to_value = |snapshot: Option<&T>| {
+        snapshot
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Errors::SysInternalError(format!("audit snapshot failed: {}", e)))
+    };
+    let before = to_value(entry.before)?;
+    let after = to_value(entry.after)?;
+
+    let changes = diff_snapshots(before.as_ref(), after.as_ref());
+    if changes.is_empty() && entry.action == ACTION_UPDATE {
+        return Ok(());
+    }
+
+    let tenant_id = resolve_audit_tenant(
+        conn,
+        entry.resource_type,
+        entry.resource_id,
+        after.as_ref().or(before.as_ref()),
+    )
+    .await?;
+
+    let active = audit_logs::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        tenant_id: Set(tenant_id),
+        resource_type: Set(entry.resource_type.to_string()),
+        resource_id: Set(entry.resource_id),
+        resource_name: Set(entry.resource_name.map(str::to_string)),
+        action: Set(entry.action.to_string()),
+        actor_id: Set(entry.actor_id),
+        changes: Set(json!(changes)),
+        created_at: Set(Utc::now().into()),
+    };
+
+    active
+        .insert(conn)
+        .await
+        .map(|_| ())
+        .map_err(|e| Errors::DatabaseError(e.to_string()))
+}
+
+/// 본 작업을 실패시키지 않도록 감사 로그 기록 오류는 경고로만 남긴다
+pub async fn record_audit_or_warn<C, T>(conn: &C, entry: AuditEntry<'_, T>)
+where
+    C: ConnectionTrait,
+    T: Serialize,
+{
+    let resource_type = entry.resource_type;
+    let resource_id = entry.resource_id;
+    let action = entry.action;
+
+    if let Err(err) = record_audit(conn, entry).await {
+        warn!(
+            resource_type,
+            resource_id = %resource_id,
+            action,
+            "failed to record audit log: {err:?}"
+        );
+    }
+}
+
+/// 전체 감사 로그 피드 조회 (최신순)
+pub async fn service_get_audit_logs(
+    conn: &DatabaseConnection,
+    filter: AuditLogFilter,
+    page: u64,
+    limit: u64,
+) -> ServiceResult<AuditLogListResponse> {
+    let page = page.max(1);
+    let limit = limit.clamp(1, 200);
+
+    let mut query = audit_logs::Entity::find();
+
+    if let Some(tenant_id) = filter.tenant_id {
+        query = query.filter(audit_logs::Column::TenantId.eq(tenant_id));
+    }
+
+    if let Some(resource_type) = filter.resource_type {
+        if !RESOURCE_TYPES.contains(&resource_type.as_str()) {
+            return Err(Errors::BadRequestError(format!(
+                "Unknown resource_type '{}' (expected one of: {})",
+                resource_type,
+                RESOURCE_TYPES.join(", ")
+            )));
+        }
+        query = query.filter(audit_logs::Column::ResourceType.eq(resource_type));
+    }
+    if let Some(resource_id) = filter.resource_id {
+        query = query.filter(audit_logs::Column::ResourceId.eq(resource_id));
+    }
+    if let Some(actor_id) = filter.actor_id {
+        query = query.filter(audit_logs::Column::ActorId.eq(actor_id));
+    }
+    if let Some(action) = filter.action {
+        query = query.filter(audit_logs::Column::Action.eq(action));
+    }
+    if let Some(from) = filter.from {
+        query = query.filter(audit_logs::Column::CreatedAt.gte(from));
+    }
+    if let Some(to) = filter.to {
+        query = query.filter(audit_logs::Column::CreatedAt.lt(to));
+    }
+
+    let paginator = query
+        .order_by_desc(audit_logs::Column::CreatedAt)
+        .paginate(conn, limit);
+
+    let total = paginator
+        .num_items()
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    let items = paginator
+        .fetch_page(page - 1)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    let actor_ids: Vec<Uuid> = items
+        .iter()
+        .filter_map(|item| item.actor_id)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let actors: HashMap<Uuid, users::Model> = if actor_ids.is_empty() {
+        HashMap::new()
+    } else {
+        users::Entity::find()
+            .filter(users::Column::Id.is_in(actor_ids))
+            .all(conn)
+            .await
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|user| (user.id, user))
+            .collect()
+    };
+
+    let entries = items
+        .into_iter()
+        .map(|item| {
+            let actor = item.actor_id.and_then(|id| actors.get(&id));
+            AuditLogResponse {
+                id: item.id,
+                resource_type: item.resource_type,
+                resource_id: item.resource_id,
+                resource_name: item.resource_name,
+                action: item.action,
+                actor_id: item.actor_id,
+                actor_name: actor.map(|u| u.name.clone()),
+                actor_handle: actor.map(|u| u.handle.clone()),
+                changes: serde_json::from_value(item.changes).unwrap_or_default(),
+                created_at: item.created_at.with_timezone(&Utc),
+            }
+        })
+        .collect();
+
+    Ok(AuditLogListResponse {
+        entries,
+        total,
+        page,
+        limit,
+    })
+}
+
+/// 리소스 하나의 변경 이력 조회
+pub async fn service_get_resource_history(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    resource_type: &str,
+    resource_id: Uuid,
+    page: u64,
+    limit: u64,
+) -> ServiceResult<AuditLogListResponse> {
+    service_get_audit_logs(
+        conn,
+        AuditLogFilter {
+            tenant_id: Some(tenant_id),
+            resource_type: Some(resource_type.to_string()),
+            resource_id: Some(resource_id),
+            ..Default::default()
+        },
+        page,
+        limit,
+    )
+    .await
+}
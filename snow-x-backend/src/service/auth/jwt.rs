@@ -3,7 +3,8 @@ use crate::dto::auth::internal::access_token::AccessTokenClaims;
 use crate::dto::auth::internal::email_verification_token::EmailVerificationTokenClaims;
 use crate::dto::auth::internal::password_reset_token::PasswordResetTokenClaims;
 use crate::dto::auth::internal::refresh_token::{JWTRefreshTokenResult, RefreshTokenClaims};
-use chrono::{Duration, Utc};
+use crate::dto::auth::internal::two_factor_challenge::TwoFactorChallengeClaims;
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{
     Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation, decode, encode,
 };
@@ -11,6 +12,14 @@ use serde::de::DeserializeOwned;
 use uuid::Uuid;
 
 pub fn create_jwt_access_token(user_id: &Uuid) -> Result<String, jsonwebtoken::errors::Error> {
+    create_jwt_access_token_with_tenant(user_id, None)
+}
+
+/// 테넌트가 정해진 액세스 토큰. IPAM 요청에 `X-Tenant-Id` 헤더가 없으면 이 테넌트를 쓴다.
+pub fn create_jwt_access_token_with_tenant(
+    user_id: &Uuid,
+    tenant_id: Option<Uuid>,
+) -> Result<String, jsonwebtoken::errors::Error> {
     let jwt_secret = &DbConfig::get().jwt_secret;
     let access_token_lifetime = DbConfig::get().auth_access_token_expire_time;
     let encoding_key = EncodingKey::from_secret(jwt_secret.as_bytes());
@@ -22,6 +31,7 @@ pub fn create_jwt_access_token(user_id: &Uuid) -> Result<String, jsonwebtoken::e
         sub: *user_id,
         iat: now.timestamp(),
         exp: access_token_expires_at.timestamp(),
+        tenant_id,
     };
     encode(&Header::default(), &claims, &encoding_key)
 }
@@ -130,3 +140,37 @@ pub fn decode_password_reset_token(
 ) -> Result<TokenData<PasswordResetTokenClaims>, jsonwebtoken::errors::Error> {
     decode_token::<PasswordResetTokenClaims>(token)
 }
+
+/// 2단계 인증 대기 토큰은 다른 토큰과 구별되도록 `jwt_secret`에서 파생한 키로 서명한다
+fn two_factor_challenge_secret() -> String {
+    format!("{}:two_factor_challenge", DbConfig::get().jwt_secret)
+}
+
+pub fn create_two_factor_challenge_token(
+    user_id: &Uuid,
+    enroll: bool,
+) -> Result<(String, DateTime<Utc>), jsonwebtoken::errors::Error> {
+    let challenge_lifetime = DbConfig::get().auth_two_factor_challenge_expire_time;
+    let encoding_key = EncodingKey::from_secret(two_factor_challenge_secret().as_bytes());
+
+    let now = Utc::now();
+    let expires_at = now + Duration::minutes(challenge_lifetime);
+
+    let claims = TwoFactorChallengeClaims {
+        sub: *user_id,
+        iat: now.timestamp(),
+        exp: expires_at.timestamp(),
+        enroll,
+    };
+
+    let token = encode(&Header::default(), &claims, &encoding_key)?;
+    Ok((token, expires_at))
+}
+
+pub fn decode_two_factor_challenge_token(
+    token: &str,
+) -> Result<TokenData<TwoFactorChallengeClaims>, jsonwebtoken::errors::Error> {
+    let decoding_key = DecodingKey::from_secret(two_factor_challenge_secret().as_bytes());
+    let validation = Validation::new(Algorithm::HS256);
+    decode::<TwoFactorChallengeClaims>(token, &decoding_key, &validation)
+}
@@ -2,6 +2,7 @@ pub mod forgot_password;
 pub mod get_oauth_connections;
 pub mod jwt;
 pub mod link_oauth;
+pub mod permission;
 pub mod refresh;
 pub mod resend_verification;
 pub mod reset_password;
@@ -0,0 +1,558 @@
+//! IPAM 리소스 권한.
+//!
+//! 모든 요청은 하나의 테넌트 안에서 처리되고, 다른 테넌트의 리소스는 없는 것처럼 다룬다.
+//! 권한은 테넌트 소속의 `UserRole`별로 리소스 종류마다 읽기/쓰기/삭제로 나뉜다 (`role_allows`).
+//! `user_office_scopes`에 (현재 테넌트의) 행이 있는 사용자는 그 사무실에 속한 사무실·서버실·랙·장비와
+//! IP 대역(`ip_ranges.tenant_id`가 사무실)·IP 주소(대역을 따라간다)만 다룰 수 있고, 행이 없으면 테넌트의
+//! 모든 사무실에 접근할 수 있다. Admin은 사무실 범위의 제한을 받지 않는다.
+//! 연락처·장비 라이브러리·Custodian·외부 API 연결처럼 사무실에 묶이지 않는 리소스는 역할만 본다.
+
+use crate::entity::common::UserRole;
+use crate::entity::{
+    devices, ip_addresses, ip_ranges, office, racks, server_rooms, user_office_scopes,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::tenant::{TenantOwner, offices_in_tenant, resolve_tenant_membership};
+use axum::http::Method;
+use sea_orm::sea_query::{Expr, Query, SelectStatement};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// 권한을 나누는 리소스 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionResource {
+    Office,
+    ServerRoom,
+    Rack,
+    Device,
+    IpRange,
+    IpAddress,
+    Contact,
+    DeviceLibrary,
+    Custodian,
+    ExternalApi,
+}
+
+impl PermissionResource {
+    pub const ALL: [PermissionResource; 10] = [
+        PermissionResource::Office,
+        PermissionResource::ServerRoom,
+        PermissionResource::Rack,
+        PermissionResource::Device,
+        PermissionResource::IpRange,
+        PermissionResource::IpAddress,
+        PermissionResource::Contact,
+        PermissionResource::DeviceLibrary,
+        PermissionResource::Custodian,
+        PermissionResource::ExternalApi,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PermissionResource::Office => "office",
+            PermissionResource::ServerRoom => "server_room",
+            PermissionResource::Rack => "rack",
+            PermissionResource::Device => "device",
+            PermissionResource::IpRange => "ip_range",
+            PermissionResource::IpAddress => "ip_address",
+            PermissionResource::Contact => "contact",
+            PermissionResource::DeviceLibrary => "device_library",
+            PermissionResource::Custodian => "custodian",
+            PermissionResource::ExternalApi => "external_api",
+        }
+    }
+
+    /// 사무실 범위의 제한을 받는 리소스인지
+    pub fn is_office_bound(&self) -> bool {
+        matches!(
+            self,
+            PermissionResource::Office
+                | PermissionResource::ServerRoom
+                | PermissionResource::Rack
+                | PermissionResource::Device
+                | PermissionResource::IpRange
+                | PermissionResource::IpAddress
+        )
+    }
+}
+
+impl fmt::Display for PermissionResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 리소스에 대한 작업
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionAction {
+    Read,
+    Write,
+    Delete,
+}
+
+impl PermissionAction {
+    /// HTTP 메서드로 작업을 정한다 (GET/HEAD는 읽기, DELETE는 삭제, 나머지는 쓰기)
+    pub fn from_method(method: &Method) -> Self {
+        match *method {
+            Method::GET | Method::HEAD | Method::OPTIONS => PermissionAction::Read,
+            Method::DELETE => PermissionAction::Delete,
+            _ => PermissionAction::Write,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PermissionAction::Read => "read",
+            PermissionAction::Write => "write",
+            PermissionAction::Delete => "delete",
+        }
+    }
+}
+
+impl fmt::Display for PermissionAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 역할별 권한표
+///
+/// | 리소스 | Staff | Manager | Admin |
+/// |---|---|---|---|
+/// | office | 읽기 | 읽기/쓰기 | 전부 |
+/// | device, ip_address, contact | 읽기/쓰기 | 전부 | 전부 |
+/// | custodian | 읽기 | 읽기/쓰기(실행 포함) | 전부 |
+/// | external_api | 읽기 | 읽기/쓰기(연결 확인, 동기화 포함) | 전부 |
+/// | 그 밖의 리소스 | 읽기 | 전부 | 전부 |
+pub fn role_allows(
+    role: &UserRole,
+    resource: PermissionResource,
+    action: PermissionAction,
+) -> bool {
+    use PermissionAction::*;
+    use PermissionResource::*;
+
+    match role {
+        UserRole::Admin => true,
+        UserRole::Manager => match resource {
+            Office | Custodian | ExternalApi => action != Delete,
+            _ => true,
+        },
+        UserRole::Staff => match resource {
+            Device | IpAddress | Contact => action != Delete,
+            _ => action == Read,
+        },
+    }
+}
+
+fn role_name(role: &UserRole) -> &'static str {
+    match role {
+        UserRole::Admin => "Admin",
+        UserRole::Manager => "Manager",
+        UserRole::Staff => "Staff",
+    }
+}
+
+/// 사무실에 묶이는 리소스가 실제로 속한 사무실
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OfficeBinding {
+    Office(Uuid),
+    /// 랙에 넣지 않은 장비처럼 어느 사무실에도 속하지 않음
+    Unassigned,
+}
+
+/// 요청한 사용자의 테넌트, 테넌트 안에서의 역할과 사무실 범위
+#[derive(Debug, Clone)]
+pub struct AccessScope {
+    pub tenant_id: Uuid,
+    pub role: UserRole,
+    /// 접근할 수 있는 사무실. `None`이면 테넌트의 모든 사무실
+    pub office_ids: Option<Vec<Uuid>>,
+}
+
+impl AccessScope {
+    pub fn allows(&self, resource: PermissionResource, action: PermissionAction) -> bool {
+        role_allows(&self.role, resource, action)
+    }
+
+    /// 역할이 작업을 허용하는지 확인
+    pub fn require(
+        &self,
+        resource: PermissionResource,
+        action: PermissionAction,
+    ) -> ServiceResult<()> {
+        if self.allows(resource, action) {
+            Ok(())
+        } else {
+            Err(Errors::PermissionDenied(format!(
+                "{} role cannot {} {}",
+                role_name(&self.role),
+                action,
+                resource
+            )))
+        }
+    }
+
+    /// 일부 사무실로 제한된 사용자인지
+    pub fn is_office_restricted(&self) -> bool {
+        self.office_ids.is_some()
+    }
+
+    /// 리소스가 이 테넌트에 속하는지 확인. 다른 테넌트의 리소스는 찾을 수 없다고 답한다
+    pub fn require_owner(
+        &self,
+        resource: PermissionResource,
+        owner: TenantOwner,
+    ) -> ServiceResult<()> {
+        if owner == TenantOwner::Tenant(self.tenant_id) {
+            Ok(())
+        } else {
+            Err(Errors::NotFound(format!("{} not found", resource)))
+        }
+    }
+
+    /// 볼 수 있는 사무실 ID 서브쿼리 (테넌트의 사무실 중 사무실 범위 안)
+    pub fn visible_offices(&self) -> SelectStatement {
+        let mut query = offices_in_tenant(self.tenant_id);
+        if let Some(office_ids) = &self.office_ids {
+            query.and_where(Expr::col(office::Column::Id).is_in(office_ids.iter().copied()));
+        }
+        query
+    }
+
+    /// 볼 수 있는 서버실 ID 서브쿼리
+    pub fn visible_server_rooms(&self) -> SelectStatement {
+        Query::select()
+            .column(server_rooms::Column::Id)
+            .from(server_rooms::Entity)
+            .and_where(
+                Expr::col(server_rooms::Column::OfficeId).in_subquery(self.visible_offices()),
+            )
+            .to_owned()
+    }
+
+    /// 볼 수 있는 랙 ID 서브쿼리
+    pub fn visible_racks(&self) -> SelectStatement {
+        Query::select()
+            .column(racks::Column::Id)
+            .from(racks::Entity)
+            .and_where(
+                Expr::col(racks::Column::ServerRoomId).in_subquery(self.visible_server_rooms()),
+            )
+            .to_owned()
+    }
+
+    pub fn can_access_office(&self, office_id: Uuid) -> bool {
+        self.office_ids
+            .as_ref()
+            .is_none_or(|office_ids| office_ids.contains(&office_id))
+    }
+
+    /// 사무실에 묶인 리소스에 대한 작업을 확인
+    pub fn require_binding(
+        &self,
+        resource: PermissionResource,
+        action: PermissionAction,
+        binding: OfficeBinding,
+    ) -> ServiceResult<()> {
+        self.require(resource, action)?;
+        let allowed = match binding {
+            OfficeBinding::Office(office_id) => self.can_access_office(office_id),
+            OfficeBinding::Unassigned => !self.is_office_restricted(),
+        };
+        if allowed {
+            Ok(())
+        } else {
+            Err(Errors::PermissionDenied(format!(
+                "{} is outside of your office scope",
+                resource
+            )))
+        }
+    }
+
+    /// 테넌트의 모든 사무실에 걸치는 작업 (사무실 생성, 일괄 가져오기/내보내기)
+    pub fn require_all_offices(
+        &self,
+        resource: PermissionResource,
+        action: PermissionAction,
+    ) -> ServiceResult<()> {
+        self.require(resource, action)?;
+        if self.is_office_restricted() {
+            return Err(Errors::PermissionDenied(format!(
+                "{} {} requires access to all offices",
+                resource, action
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 사용자의 테넌트 소속, 역할과 사무실 범위를 읽는다
+pub async fn load_access_scope<C>(
+    conn: &C,
+    user_id: Uuid,
+    requested_tenant_id: Option<Uuid>,
+) -> ServiceResult<AccessScope>
+where
+    C: ConnectionTrait,
+{
+    let membership = resolve_tenant_membership(conn, user_id, requested_tenant_id).await?;
+
+    let office_ids = if membership.role == UserRole::Admin {
+        None
+    } else {
+        // 다른 테넌트의 사무실로 지정된 범위는 이 테넌트에서 의미가 없다
+        let office_ids: Vec<Uuid> = user_office_scopes::Entity::find()
+            .select_only()
+            .column(user_office_scopes::Column::OfficeId)
+            .filter(user_office_scopes::Column::UserId.eq(user_id))
+            .filter(
+                user_office_scopes::Column::OfficeId
+                    .in_subquery(offices_in_tenant(membership.tenant_id)),
+            )
+            .into_tuple::<Uuid>()
+            .all(conn)
+            .await?;
+        (!office_ids.is_empty()).then_some(office_ids)
+    };
+
+    Ok(AccessScope {
+        tenant_id: membership.tenant_id,
+        role: membership.role,
+        office_ids,
+    })
+}
+
+/// 사용자에게 지정된 사무실 범위 (비어 있으면 제한 없음)
+pub async fn get_user_office_ids<C>(conn: &C, user_id: Uuid) -> ServiceResult<Vec<Uuid>>
+where
+    C: ConnectionTrait,
+{
+    Ok(user_office_scopes::Entity::find()
+        .select_only()
+        .column(user_office_scopes::Column::OfficeId)
+        .filter(user_office_scopes::Column::UserId.eq(user_id))
+        .into_tuple::<Uuid>()
+        .all(conn)
+        .await?)
+}
+
+/// 서버실이 속한 사무실. 서버실이 없으면 `None`
+pub async fn office_of_server_room<C>(
+    conn: &C,
+    server_room_id: Uuid,
+) -> ServiceResult<Option<OfficeBinding>>
+where
+    C: ConnectionTrait,
+{
+    Ok(server_rooms::Entity::find_by_id(server_room_id)
+        .select_only()
+        .column(server_rooms::Column::OfficeId)
+        .into_tuple::<Uuid>()
+        .one(conn)
+        .await?
+        .map(OfficeBinding::Office))
+}
+
+/// 랙이 속한 사무실. 랙이 없으면 `None`
+pub async fn office_of_rack<C>(conn: &C, rack_id: Uuid) -> ServiceResult<Option<OfficeBinding>>
+where
+    C: ConnectionTrait,
+{
+    let server_room_id = racks::Entity::find_by_id(rack_id)
+        .select_only()
+        .column(racks::Column::ServerRoomId)
+        .into_tuple::<Uuid>()
+        .one(conn)
+        .await?;
+
+    match server_room_id {
+        Some(server_room_id) => office_of_server_room(conn, server_room_id).await,
+        None => Ok(None),
+    }
+}
+
+/// 장비가 속한 사무실. 장비가 없으면 `None`, 랙에 넣지 않은 장비는 `Unassigned`
+pub async fn office_of_device<C>(conn: &C, device_id: Uuid) -> ServiceResult<Option<OfficeBinding>>
+where
+    C: ConnectionTrait,
+{
+    let rack_id = devices::Entity::find_by_id(device_id)
+        .select_only()
+        .column(devices::Column::RackId)
+        .into_tuple::<Option<Uuid>>()
+        .one(conn)
+        .await?;
+
+    match rack_id {
+        Some(Some(rack_id)) => Ok(Some(
+            office_of_rack(conn, rack_id)
+                .await?
+                .unwrap_or(OfficeBinding::Unassigned),
+        )),
+        Some(None) => Ok(Some(OfficeBinding::Unassigned)),
+        None => Ok(None),
+    }
+}
+
+/// IP 대역이 속한 사무실 (`ip_ranges.tenant_id`). 대역이 없으면 `None`
+pub async fn office_of_ip_range<C>(
+    conn: &C,
+    ip_range_id: Uuid,
+) -> ServiceResult<Option<OfficeBinding>>
+where
+    C: ConnectionTrait,
+{
+    Ok(ip_ranges::Entity::find_by_id(ip_range_id)
+        .select_only()
+        .column(ip_ranges::Column::TenantId)
+        .into_tuple::<Uuid>()
+        .one(conn)
+        .await?
+        .map(OfficeBinding::Office))
+}
+
+/// IP 주소가 속한 사무실 (대역의 사무실). IP 주소가 없으면 `None`
+pub async fn office_of_ip_address<C>(
+    conn: &C,
+    ip_address_id: Uuid,
+) -> ServiceResult<Option<OfficeBinding>>
+where
+    C: ConnectionTrait,
+{
+    let ip_range_id = ip_addresses::Entity::find_by_id(ip_address_id)
+        .select_only()
+        .column(ip_addresses::Column::IpRangeId)
+        .into_tuple::<Uuid>()
+        .one(conn)
+        .await?;
+
+    match ip_range_id {
+        Some(ip_range_id) => office_of_ip_range(conn, ip_range_id).await,
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PermissionAction::*;
+    use PermissionResource::*;
+
+    fn scope(role: UserRole, office_ids: Option<Vec<Uuid>>) -> AccessScope {
+        AccessScope {
+            tenant_id: Uuid::new_v4(),
+            role,
+            office_ids,
+        }
+    }
+
+    #[test]
+    fn admin_can_do_everything() {
+        for resource in PermissionResource::ALL {
+            for action in [Read, Write, Delete] {
+                assert!(role_allows(&UserRole::Admin, resource, action));
+            }
+        }
+    }
+
+    #[test]
+    fn manager_cannot_delete_offices_custodian_or_external_apis() {
+        for resource in PermissionResource::ALL {
+            assert!(role_allows(&UserRole::Manager, resource, Read));
+            assert!(role_allows(&UserRole::Manager, resource, Write));
+            assert_eq!(
+                role_allows(&UserRole::Manager, resource, Delete),
+                !matches!(resource, Office | Custodian | ExternalApi),
+                "{resource}"
+            );
+        }
+    }
+
+    #[test]
+    fn staff_writes_only_devices_ip_addresses_and_contacts() {
+        for resource in PermissionResource::ALL {
+            let writable = matches!(resource, Device | IpAddress | Contact);
+            assert!(role_allows(&UserRole::Staff, resource, Read));
+            assert_eq!(
+                role_allows(&UserRole::Staff, resource, Write),
+                writable,
+                "{resource}"
+            );
+            assert!(!role_allows(&UserRole::Staff, resource, Delete));
+        }
+    }
+
+    #[test]
+    fn binding_checks_role_before_office_scope() {
+        let office_id = Uuid::new_v4();
+        let staff = scope(UserRole::Staff, None);
+
+        assert!(matches!(
+            staff.require_binding(IpRange, Write, OfficeBinding::Office(office_id)),
+            Err(Errors::PermissionDenied(_))
+        ));
+        assert!(
+            staff
+                .require_binding(IpAddress, Write, OfficeBinding::Office(office_id))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn office_scoped_user_is_limited_to_their_offices() {
+        let own = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let manager = scope(UserRole::Manager, Some(vec![own]));
+
+        assert!(
+            manager
+                .require_binding(IpRange, Write, OfficeBinding::Office(own))
+                .is_ok()
+        );
+        assert!(matches!(
+            manager.require_binding(IpRange, Write, OfficeBinding::Office(other)),
+            Err(Errors::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            manager.require_binding(Device, Read, OfficeBinding::Unassigned),
+            Err(Errors::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            manager.require_all_offices(Device, Write),
+            Err(Errors::PermissionDenied(_))
+        ));
+    }
+
+    #[test]
+    fn unrestricted_user_reaches_every_office() {
+        let manager = scope(UserRole::Manager, None);
+
+        assert!(
+            manager
+                .require_binding(Rack, Delete, OfficeBinding::Office(Uuid::new_v4()))
+                .is_ok()
+        );
+        assert!(
+            manager
+                .require_binding(Device, Write, OfficeBinding::Unassigned)
+                .is_ok()
+        );
+        assert!(manager.require_all_offices(Device, Write).is_ok());
+    }
+
+    #[test]
+    fn ip_ranges_and_addresses_are_office_bound() {
+        for resource in [Office, ServerRoom, Rack, Device, IpRange, IpAddress] {
+            assert!(resource.is_office_bound(), "{resource}");
+        }
+        for resource in [Contact, DeviceLibrary, Custodian, ExternalApi] {
+            assert!(!resource.is_office_bound(), "{resource}");
+        }
+    }
+}
@@ -14,7 +14,9 @@ where
 
     match user.role {
         UserRole::Manager | UserRole::Admin => Ok(()),
-        _ => Err(Errors::UserUnauthorized),
+        _ => Err(Errors::PermissionDenied(
+            "Manager or Admin role required".to_string(),
+        )),
     }
 }
 
@@ -28,7 +30,7 @@ where
 
     match user.role {
         UserRole::Admin => Ok(()),
-        _ => Err(Errors::UserUnauthorized),
+        _ => Err(Errors::PermissionDenied("Admin role required".to_string())),
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::dto::auth::request::login::AuthLoginRequest;
 use crate::dto::auth::response::jwt::AuthJWTResponse;
+use crate::dto::auth::response::sign_in::SignInResponse;
 use crate::entity::common::{ActionType, TargetType};
 use crate::entity::user_refresh_tokens::ActiveModel as RefreshTokenActiveModel;
 use crate::repository::auth::create_refresh_token::repository_create_refresh_token;
@@ -7,34 +8,73 @@ use crate::repository::system_events::log_event::repository_log_event;
 use crate::repository::user::find_user_by_handle::repository_find_user_by_handle;
 use crate::service::auth::jwt::{create_jwt_access_token, create_jwt_refresh_token};
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::rate_limit::{check_login_lockout, clear_login_failures, record_login_failure};
+use crate::service::two_factor::begin_two_factor_sign_in;
 use crate::utils::crypto::verify_password;
 use sea_orm::{ConnectionTrait, Set, TransactionTrait};
+use uuid::Uuid;
 
 pub async fn service_sign_in<C>(
     conn: &C,
     user_agent: Option<String>,
     ip_address: Option<String>,
     payload: AuthLoginRequest,
-) -> ServiceResult<AuthJWTResponse>
+) -> ServiceResult<SignInResponse>
 where
     C: ConnectionTrait + TransactionTrait,
 {
-    let user = repository_find_user_by_handle(conn, &payload.handle)
-        .await?
-        .ok_or(Errors::UserNotFound)?;
+    // 비밀번호를 반복해서 틀려 잠긴 계정은 비밀번호를 확인하지 않는다
+    check_login_lockout(&payload.handle, ip_address.as_deref()).await?;
+
+    let Some(user) = repository_find_user_by_handle(conn, &payload.handle).await? else {
+        record_login_failure(conn, &payload.handle, None, ip_address.as_deref()).await?;
+        return Err(Errors::UserNotFound);
+    };
+
+    let verified = user
+        .password
+        .as_ref()
+        .ok_or(Errors::UserInvalidPassword)
+        .and_then(|stored_password| verify_password(&payload.password, stored_password));
+    if let Err(e) = verified {
+        if matches!(e, Errors::UserInvalidPassword) {
+            record_login_failure(conn, &payload.handle, Some(user.id), ip_address.as_deref())
+                .await?;
+        }
+        return Err(e);
+    }
 
-    let stored_password = user.password.as_ref().ok_or(Errors::UserInvalidPassword)?;
-    verify_password(&payload.password, stored_password)?;
+    // 2단계 인증 사용자는 코드를 확인한 뒤에 토큰을 받는다.
+    // 실패 횟수는 두 번째 단계까지 마쳐야 지운다 (service_sign_in_two_factor)
+    if let Some(challenge) = begin_two_factor_sign_in(conn, &user).await? {
+        return Ok(SignInResponse::TwoFactorRequired(challenge));
+    }
+    clear_login_failures(&payload.handle, ip_address.as_deref()).await;
 
+    issue_sign_in_tokens(conn, user.id, user_agent, ip_address)
+        .await
+        .map(SignInResponse::Authenticated)
+}
+
+/// 로그인을 마친 사용자에게 액세스·리프레시 토큰을 발급한다
+pub(crate) async fn issue_sign_in_tokens<C>(
+    conn: &C,
+    user_id: Uuid,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+) -> ServiceResult<AuthJWTResponse>
+where
+    C: ConnectionTrait,
+{
     let access_token =
-        create_jwt_access_token(&user.id).map_err(|e| Errors::TokenCreationError(e.to_string()))?;
+        create_jwt_access_token(&user_id).map_err(|e| Errors::TokenCreationError(e.to_string()))?;
 
-    let refresh_token = create_jwt_refresh_token(&user.id)
+    let refresh_token = create_jwt_refresh_token(&user_id)
         .map_err(|e| Errors::TokenCreationError(e.to_string()))?;
 
     let refresh_model = RefreshTokenActiveModel {
         id: Set(refresh_token.jti),
-        user_id: Set(user.id),
+        user_id: Set(user_id),
         ip_address: Set(ip_address),
         user_agent: Set(user_agent),
         refresh_token: Set(refresh_token.token.clone()),
@@ -55,9 +95,9 @@ where
     if result.is_ok() {
         repository_log_event(
             conn,
-            Some(user.id),
+            Some(user_id),
             ActionType::UserSignedIn,
-            Some(user.id),
+            Some(user_id),
             Some(TargetType::User),
             None,
         )
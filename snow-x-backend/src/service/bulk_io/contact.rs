@@ -0,0 +1,281 @@
+use super::csv::{
+    CsvChunk, CsvField, EXPORT_PAGE_SIZE, RowReader, cell, csv_export_stream, parse_csv,
+};
+use super::{ImportReport, RowError, RowOutcome, begin_import, settle_row};
+use crate::dto::bulk_io::response::BulkImportResponse;
+use crate::entity::contacts;
+use crate::service::audit::{
+    ACTION_CREATE, ACTION_UPDATE, AuditEntry, RESOURCE_CONTACT, record_audit,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::external_api::conflict::{ManualEdit, guard_manual_edit};
+use chrono::Utc;
+use futures_util::Stream;
+use sea_orm::sea_query::{Expr, Func};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use uuid::Uuid;
+
+pub const CONTACT_COLUMNS: [&str; 9] = [
+    "id",
+    "name",
+    "title",
+    "department",
+    "phone",
+    "mobile",
+    "email",
+    "office_location",
+    "responsibilities",
+];
+
+struct ContactRow {
+    id: Option<Uuid>,
+    name: Option<String>,
+    title: CsvField<String>,
+    department: CsvField<String>,
+    phone: CsvField<String>,
+    mobile: CsvField<String>,
+    email: CsvField<String>,
+    office_location: CsvField<String>,
+    responsibilities: CsvField<String>,
+}
+
+fn parse_contact_row(row: &RowReader) -> Result<ContactRow, RowError> {
+    let email = row.field("email");
+    if let Some(Some(value)) = email.as_ref()
+        && !value.contains('@')
+    {
+        return Err(row.error("email", format!("'{}' is not an email address", value)));
+    }
+
+    Ok(ContactRow {
+        id: row.uuid("id")?,
+        name: row.text("name"),
+        title: row.field("title"),
+        department: row.field("department"),
+        phone: row.field("phone"),
+        mobile: row.field("mobile"),
+        email,
+        office_location: row.field("office_location"),
+        responsibilities: row.field("responsibilities"),
+    })
+}
+
+/// `id`가 있으면 해당 담당자, 없으면 이메일이 같은 담당자(대소문자 무시)를 수정 대상으로 찾는다
+async fn find_existing_contact<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    row: &ContactRow,
+) -> ServiceResult<Option<contacts::Model>>
+where
+    C: ConnectionTrait,
+{
+    if let Some(id) = row.id {
+        return contacts::Entity::find_by_id(id)
+            .filter(contacts::Column::TenantId.eq(tenant_id))
+            .filter(contacts::Column::IsActive.eq(true))
+            .one(conn)
+            .await
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?
+            .map(Some)
+            .ok_or_else(|| Errors::NotFound(format!("Contact {} not found", id)));
+    }
+
+    let Some(Some(email)) = row.email.as_ref() else {
+        return Ok(None);
+    };
+    contacts::Entity::find()
+        .filter(contacts::Column::TenantId.eq(tenant_id))
+        .filter(
+            Expr::expr(Func::lower(Expr::col(contacts::Column::Email))).eq(email.to_lowercase()),
+        )
+        .filter(contacts::Column::IsActive.eq(true))
+        .one(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))
+}
+
+fn set_if_present(target: &mut ActiveValue<Option<String>>, value: CsvField<String>) {
+    if let Some(value) = value {
+        *target = ActiveValue::Set(value);
+    }
+}
+
+async fn apply_contact_row<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    row: ContactRow,
+    actor: Uuid,
+) -> ServiceResult<RowOutcome>
+where
+    C: ConnectionTrait,
+{
+    let existing = find_existing_contact(conn, tenant_id, &row).await?;
+
+    let (action, contact) = match existing.as_ref() {
+        Some(before) => {
+            let mut model: contacts::ActiveModel = before.clone().into();
+            if let Some(name) = row.name {
+                model.name = ActiveValue::Set(name);
+            }
+            set_if_present(&mut model.title, row.title);
+            set_if_present(&mut model.department, row.department);
+            set_if_present(&mut model.phone, row.phone);
+            set_if_present(&mut model.mobile, row.mobile);
+            set_if_present(&mut model.email, row.email);
+            set_if_present(&mut model.office_location, row.office_location);
+            set_if_present(&mut model.responsibilities, row.responsibilities);
+            model.updated_at = ActiveValue::Set(Utc::now().into());
+
+            let contact = model.update(conn).await?;
+            guard_manual_edit(
+                conn,
+                ManualEdit {
+                    resource_type: RESOURCE_CONTACT,
+                    resource_id: before.id,
+                    source_type: &before.source_type,
+                    connection_id: before.external_api_connection_id,
+                    actor_id: actor,
+                    before,
+                    after: &contact,
+                },
+            )
+            .await?;
+            (ACTION_UPDATE, contact)
+        }
+        None => {
+            let name = row
+                .name
+                .ok_or_else(|| Errors::BadRequestError("name is required".to_string()))?;
+            let contact = contacts::ActiveModel {
+                id: ActiveValue::Set(Uuid::new_v4()),
+                tenant_id: ActiveValue::Set(Some(tenant_id)),
+                name: ActiveValue::Set(name),
+                title: ActiveValue::Set(row.title.flatten()),
+                department: ActiveValue::Set(row.department.flatten()),
+                phone: ActiveValue::Set(row.phone.flatten()),
+                mobile: ActiveValue::Set(row.mobile.flatten()),
+                email: ActiveValue::Set(row.email.flatten()),
+                office_location: ActiveValue::Set(row.office_location.flatten()),
+                responsibilities: ActiveValue::Set(row.responsibilities.flatten()),
+                created_by: ActiveValue::Set(actor),
+                created_at: ActiveValue::NotSet,
+                updated_at: ActiveValue::NotSet,
+                is_active: ActiveValue::Set(true),
+                source_type: ActiveValue::Set("manual".to_string()),
+                external_api_connection_id: ActiveValue::Set(None),
+            }
+            .insert(conn)
+            .await?;
+            (ACTION_CREATE, contact)
+        }
+    };
+
+    record_audit(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_CONTACT,
+            resource_id: contact.id,
+            resource_name: Some(&contact.name),
+            action,
+            actor_id: Some(actor),
+            before: existing.as_ref(),
+            after: Some(&contact),
+        },
+    )
+    .await?;
+
+    Ok(RowOutcome {
+        action,
+        id: contact.id,
+        name: contact.name,
+    })
+}
+
+/// 담당자 CSV를 가져온다. `id` 또는 `email`이 기존 담당자와 일치하면 수정, 아니면 생성한다.
+pub async fn service_import_contacts(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    content: &str,
+    dry_run: bool,
+    imported_by: Uuid,
+) -> ServiceResult<BulkImportResponse> {
+    let sheet = parse_csv(content)?;
+    sheet.ensure_columns(&CONTACT_COLUMNS, &["name"])?;
+
+    let txn = begin_import(conn, &sheet).await?;
+    let mut report = ImportReport::new("contact", dry_run, sheet.records().len());
+
+    for record in sheet.records() {
+        let reader = sheet.reader(record);
+        let row = match parse_contact_row(&reader) {
+            Ok(row) => row,
+            Err(error) => {
+                report.failed(error);
+                continue;
+            }
+        };
+
+        let savepoint = txn.begin().await?;
+        let result = apply_contact_row(&savepoint, tenant_id, row, imported_by).await;
+        if let Some(outcome) = settle_row(savepoint, &mut report, record.line, result).await? {
+            report.succeeded(record.line, outcome);
+        }
+    }
+
+    Ok(report.finish(txn).await?.0)
+}
+
+/// 활성 담당자를 가져오기와 같은 열 형식의 CSV로 내보낸다 (페이지 단위 스트리밍)
+pub fn service_export_contacts(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+) -> impl Stream<Item = CsvChunk> + Send + 'static + use<> {
+    let conn = conn.clone();
+
+    csv_export_stream(&CONTACT_COLUMNS, move |page| {
+        let conn = conn.clone();
+        async move { export_contact_page(&conn, tenant_id, page).await }
+    })
+}
+
+async fn export_contact_page(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    page: u64,
+) -> ServiceResult<Option<Vec<Vec<String>>>> {
+    let contacts = contacts::Entity::find()
+        .filter(contacts::Column::TenantId.eq(tenant_id))
+        .filter(contacts::Column::IsActive.eq(true))
+        .order_by_asc(contacts::Column::Name)
+        .order_by_asc(contacts::Column::Id)
+        .offset(page * EXPORT_PAGE_SIZE)
+        .limit(EXPORT_PAGE_SIZE)
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+    if contacts.is_empty() {
+        return Ok(None);
+    }
+
+    let rows = contacts
+        .into_iter()
+        .map(|contact| {
+            vec![
+                contact.id.to_string(),
+                contact.name,
+                cell(contact.title),
+                cell(contact.department),
+                cell(contact.phone),
+                cell(contact.mobile),
+                cell(contact.email),
+                cell(contact.office_location),
+                cell(contact.responsibilities),
+            ]
+        })
+        .collect();
+
+    Ok(Some(rows))
+}
@@ -0,0 +1,435 @@
+use crate::service::error::errors::{Errors, ServiceResult};
+use chrono::NaiveDate;
+use futures_util::stream::{self, Stream, StreamExt};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::RowError;
+
+/// 엑셀에서 UTF-8 CSV를 열 때 한글이 깨지지 않도록 붙이는 BOM
+const UTF8_BOM: char = '\u{feff}';
+
+/// 헤더 한 줄과 데이터 행으로 이루어진 CSV 시트
+pub struct CsvSheet {
+    columns: HashMap<String, usize>,
+    records: Vec<CsvRecord>,
+}
+
+/// 데이터 행 하나. `line`은 파일에서 행이 시작하는 줄 번호 (1부터)
+pub struct CsvRecord {
+    pub line: usize,
+    values: Vec<String>,
+}
+
+/// 내보낼 때 수식 방지로 붙인 `'`를 떼어 내보낸 파일을 그대로 다시 가져올 수 있게 한다
+fn unguard_formula(value: &str) -> &str {
+    match value.strip_prefix('\'') {
+        Some(rest) if rest.starts_with(FORMULA_PREFIXES) => rest,
+        _ => value,
+    }
+}
+
+/// 헤더 이름 정규화: 앞뒤 공백 제거, 소문자, 공백/하이픈은 `_`
+fn normalize_header(header: &str) -> String {
+    header.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+/// RFC 4180 CSV를 파싱한다. 따옴표 안의 구분자/줄바꿈과 `""` 이스케이프를 지원하고,
+/// 헤더에 `,`가 없고 `;`만 있으면 `;`를 구분자로 쓴다 (일부 로케일의 엑셀 저장 형식).
+/// 모든 열이 빈 행은 건너뛴다.
+pub fn parse_csv(content: &str) -> ServiceResult<CsvSheet> {
+    let content = content.strip_prefix(UTF8_BOM).unwrap_or(content);
+    let header_line = content.lines().next().unwrap_or_default();
+    let delimiter = if !header_line.contains(',') && header_line.contains(';') {
+        ';'
+    } else {
+        ','
+    };
+
+    let mut rows: Vec<(usize, Vec<String>)> = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_start = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            c if c == delimiter => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut field));
+                rows.push((row_start, std::mem::take(&mut row)));
+                line += 1;
+                row_start = line;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(Errors::BadRequestError(format!(
+            "Unterminated quoted field starting on line {}",
+            row_start
+        )));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push((row_start, row));
+    }
+
+    let mut rows = rows.into_iter();
+    let Some((_, headers)) = rows.next() else {
+        return Err(Errors::BadRequestError("CSV file is empty".to_string()));
+    };
+
+    let mut columns = HashMap::new();
+    for (index, header) in headers.iter().enumerate() {
+        let name = normalize_header(header);
+        if name.is_empty() {
+            continue;
+        }
+        if columns.insert(name.clone(), index).is_some() {
+            return Err(Errors::BadRequestError(format!(
+                "Duplicate column '{}' in header",
+                name
+            )));
+        }
+    }
+
+    let records = rows
+        .filter(|(_, values)| values.iter().any(|value| !value.trim().is_empty()))
+        .map(|(line, values)| CsvRecord { line, values })
+        .collect();
+
+    Ok(CsvSheet { columns, records })
+}
+
+impl CsvSheet {
+    pub fn records(&self) -> &[CsvRecord] {
+        &self.records
+    }
+
+    /// 필수 열이 있고, 알 수 없는 열이 없는지 확인한다 (오타로 값이 조용히 무시되지 않도록)
+    pub fn ensure_columns(&self, allowed: &[&str], required: &[&str]) -> ServiceResult<()> {
+        let missing: Vec<&str> = required
+            .iter()
+            .copied()
+            .filter(|column| !self.columns.contains_key(*column))
+            .collect();
+        if !missing.is_empty() {
+            return Err(Errors::BadRequestError(format!(
+                "Missing required column(s): {}",
+                missing.join(", ")
+            )));
+        }
+
+        let mut unknown: Vec<&str> = self
+            .columns
+            .keys()
+            .map(String::as_str)
+            .filter(|column| !allowed.contains(column))
+            .collect();
+        if !unknown.is_empty() {
+            unknown.sort_unstable();
+            return Err(Errors::BadRequestError(format!(
+                "Unknown column(s): {} (expected: {})",
+                unknown.join(", "),
+                allowed.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn reader<'a>(&'a self, record: &'a CsvRecord) -> RowReader<'a> {
+        RowReader {
+            columns: &self.columns,
+            record,
+        }
+    }
+}
+
+/// 열이 없으면 `None`, 빈 칸이면 `Some(None)`.
+/// 수정 시 파일에 없는 열은 기존 값을 유지하기 위해 두 경우를 구분한다.
+pub type CsvField<T> = Option<Option<T>>;
+
+/// 행 값을 열 이름으로 읽는 헬퍼. 파싱 오류는 열 정보가 붙은 `RowError`로 돌려준다.
+pub struct RowReader<'a> {
+    columns: &'a HashMap<String, usize>,
+    record: &'a CsvRecord,
+}
+
+impl RowReader<'_> {
+    /// 공백을 제거한 값. 빈 칸이나 열이 없으면 None
+    pub fn text(&self, column: &str) -> Option<String> {
+        self.field(column).flatten()
+    }
+
+    pub fn field(&self, column: &str) -> CsvField<String> {
+        let index = *self.columns.get(column)?;
+        let value = self
+            .record
+            .values
+            .get(index)
+            .map(|value| value.trim())
+            .map(unguard_formula)
+            .unwrap_or_default();
+        Some((!value.is_empty()).then(|| value.to_string()))
+    }
+
+    pub fn required(&self, column: &str) -> Result<String, RowError> {
+        self.text(column)
+            .ok_or_else(|| self.error(column, format!("{} is required", column)))
+    }
+
+    pub fn parse<T: FromStr>(&self, column: &str, expected: &str) -> Result<CsvField<T>, RowError> {
+        match self.field(column) {
+            Some(Some(value)) => value.parse::<T>().map(|v| Some(Some(v))).map_err(|_| {
+                self.error(column, format!("'{}' is not a valid {}", value, expected))
+            }),
+            Some(None) => Ok(Some(None)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn int(&self, column: &str) -> Result<CsvField<i32>, RowError> {
+        self.parse(column, "integer")
+    }
+
+    pub fn float(&self, column: &str) -> Result<CsvField<f64>, RowError> {
+        self.parse(column, "number")
+    }
+
+    pub fn uuid(&self, column: &str) -> Result<Option<Uuid>, RowError> {
+        Ok(self.parse(column, "UUID")?.flatten())
+    }
+
+    /// `YYYY-MM-DD` 날짜
+    pub fn date(&self, column: &str) -> Result<CsvField<NaiveDate>, RowError> {
+        match self.field(column) {
+            Some(Some(value)) => NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+                .map(|date| Some(Some(date)))
+                .map_err(|_| self.error(column, format!("'{}' is not a date (YYYY-MM-DD)", value))),
+            Some(None) => Ok(Some(None)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn error(&self, column: &str, message: String) -> RowError {
+        RowError {
+            row: self.record.line,
+            column: Some(column.to_string()),
+            message,
+        }
+    }
+}
+
+/// 내보내기에서 한 번에 조회하는 행 수
+pub const EXPORT_PAGE_SIZE: u64 = 500;
+
+/// 엑셀이 수식으로 해석하는 첫 글자. 이런 칸은 앞에 `'`를 붙여 글자로 내보낸다
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// 내보내기용 CSV 작성기
+#[derive(Default)]
+pub struct CsvWriter {
+    buffer: String,
+}
+
+impl CsvWriter {
+    pub fn new(headers: &[&str]) -> Self {
+        let mut writer = CsvWriter {
+            buffer: String::from(UTF8_BOM),
+        };
+        writer.write_row(headers.iter().copied());
+        writer
+    }
+
+    pub fn write_row<I, S>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for (index, value) in values.into_iter().enumerate() {
+            if index > 0 {
+                self.buffer.push(',');
+            }
+            let value = value.as_ref();
+            let guarded;
+            let value = if value.starts_with(FORMULA_PREFIXES) {
+                guarded = format!("'{}", value);
+                guarded.as_str()
+            } else {
+                value
+            };
+            if value.contains([',', ';', '"', '\n', '\r']) {
+                self.buffer.push('"');
+                self.buffer.push_str(&value.replace('"', "\"\""));
+                self.buffer.push('"');
+            } else {
+                self.buffer.push_str(value);
+            }
+        }
+        self.buffer.push_str("\r\n");
+    }
+
+    pub fn finish(self) -> String {
+        self.buffer
+    }
+}
+
+/// 내보내기 응답 본문 조각
+pub type CsvChunk = Result<String, std::io::Error>;
+
+/// `fetch_page(page)`가 돌려주는 행을 CSV 조각으로 흘려보낸다 (page는 0부터).
+/// 첫 조각은 BOM과 헤더이고, `fetch_page`가 `None`을 돌려주면 끝난다.
+/// 파일 전체를 메모리에 만들지 않도록 페이지마다 조회해서 바로 내보낸다.
+pub fn csv_export_stream<F, Fut>(
+    headers: &[&str],
+    fetch_page: F,
+) -> impl Stream<Item = CsvChunk> + Send + 'static
+where
+    F: FnMut(u64) -> Fut + Send + 'static,
+    Fut: Future<Output = ServiceResult<Option<Vec<Vec<String>>>>> + Send + 'static,
+{
+    let header = CsvWriter::new(headers).finish();
+
+    let pages = stream::unfold((fetch_page, Some(0)), |(mut fetch_page, page)| async move {
+        let page = page?;
+        match fetch_page(page).await {
+            Ok(Some(rows)) => {
+                let mut writer = CsvWriter::default();
+                for row in rows {
+                    writer.write_row(row);
+                }
+                Some((Ok(writer.finish()), (fetch_page, Some(page + 1))))
+            }
+            Ok(None) => None,
+            // 이미 헤더를 보냈으므로 상태 코드를 바꿀 수 없다. 본문을 끊어 불완전한 파일임을 알린다
+            Err(err) => {
+                warn!("CSV export failed on page {}: {:?}", page, err);
+                let err = std::io::Error::other(format!("CSV export failed: {:?}", err));
+                Some((Err(err), (fetch_page, None)))
+            }
+        }
+    });
+
+    stream::once(async move { Ok(header) }).chain(pages)
+}
+
+/// 선택 값을 CSV 칸 문자열로
+pub fn cell<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(sheet: &CsvSheet, column: &str) -> Vec<Option<String>> {
+        sheet
+            .records()
+            .iter()
+            .map(|record| sheet.reader(record).text(column))
+            .collect()
+    }
+
+    #[test]
+    fn parses_quoted_fields_and_bom() {
+        let sheet = parse_csv(
+            "\u{feff}Name,Serial Number,Description\r\n\
+             sw-01,ABC,\"core, rack A\"\r\n\
+             sw-02,DEF,\"say \"\"hi\"\"\"\r\n",
+        )
+        .unwrap();
+
+        assert_eq!(sheet.records().len(), 2);
+        assert_eq!(
+            values(&sheet, "serial_number"),
+            vec![Some("ABC".to_string()), Some("DEF".to_string())]
+        );
+        assert_eq!(
+            values(&sheet, "description"),
+            vec![
+                Some("core, rack A".to_string()),
+                Some("say \"hi\"".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn multiline_field_keeps_starting_line() {
+        let sheet = parse_csv("name,description\na,\"line 1\nline 2\"\n\nb,x\n").unwrap();
+
+        let lines: Vec<usize> = sheet.records().iter().map(|r| r.line).collect();
+        assert_eq!(lines, vec![2, 5]);
+        assert_eq!(
+            values(&sheet, "description")[0].as_deref(),
+            Some("line 1\nline 2")
+        );
+    }
+
+    #[test]
+    fn semicolon_delimiter_from_header() {
+        let sheet = parse_csv("name;rack\nsw-01;R1\n").unwrap();
+        assert_eq!(values(&sheet, "rack"), vec![Some("R1".to_string())]);
+    }
+
+    #[test]
+    fn rejects_duplicate_header_and_unterminated_quote() {
+        assert!(matches!(
+            parse_csv("name,Name\na,b\n"),
+            Err(Errors::BadRequestError(_))
+        ));
+        assert!(matches!(
+            parse_csv("name\n\"open\n"),
+            Err(Errors::BadRequestError(_))
+        ));
+        assert!(matches!(parse_csv(""), Err(Errors::BadRequestError(_))));
+    }
+
+    #[test]
+    fn writer_guards_formula_cells() {
+        let mut writer = CsvWriter::default();
+        writer.write_row(["=HYPERLINK(\"x\")", "+1", "-2", "@SUM(A1)", "\tx", "plain"]);
+
+        assert_eq!(
+            writer.finish(),
+            "\"'=HYPERLINK(\"\"x\"\")\",'+1,'-2,'@SUM(A1),'\tx,plain\r\n"
+        );
+    }
+
+    #[test]
+    fn guarded_export_round_trips() {
+        let mut writer = CsvWriter::new(&["name", "description"]);
+        writer.write_row(["=1+1", "'quoted"]);
+
+        let sheet = parse_csv(&writer.finish()).unwrap();
+        assert_eq!(values(&sheet, "name"), vec![Some("=1+1".to_string())]);
+        assert_eq!(
+            values(&sheet, "description"),
+            vec![Some("'quoted".to_string())]
+        );
+    }
+}
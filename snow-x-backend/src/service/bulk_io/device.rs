@@ -0,0 +1,423 @@
+use super::csv::{
+    CsvChunk, CsvField, EXPORT_PAGE_SIZE, RowReader, cell, csv_export_stream, parse_csv,
+};
+use super::locations::LocationIndex;
+use super::{ImportReport, RowError, RowOutcome, begin_import, settle_row};
+use crate::dto::bulk_io::response::BulkImportResponse;
+use crate::entity::devices;
+use crate::repository::device::create_device::repository_create_device;
+use crate::repository::device::get_device_by_id::repository_get_device_by_id;
+use crate::repository::device::update_device::repository_update_device;
+use crate::service::audit::{
+    ACTION_CREATE, ACTION_UPDATE, AuditEntry, RESOURCE_DEVICE, record_audit,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::external_api::conflict::{ManualEdit, guard_manual_edit};
+use crate::service::rack::capacity::{
+    PowerBudgetOverage, check_rack_power_budget, notify_power_budget_overage,
+};
+use crate::service::rack::elevation::{ensure_rack_slots_available, lock_rack};
+use chrono::NaiveDate;
+use futures_util::Stream;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, TransactionTrait,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub const DEVICE_COLUMNS: [&str; 16] = [
+    "id",
+    "name",
+    "device_type",
+    "status",
+    "manufacturer",
+    "model",
+    "serial_number",
+    "office",
+    "server_room",
+    "rack",
+    "rack_position",
+    "rack_size",
+    "power_consumption",
+    "purchase_date",
+    "warranty_end",
+    "description",
+];
+
+/// 장비 내보내기 범위 (여러 개를 주면 모두 만족하는 장비만)
+#[derive(Debug, Default)]
+pub struct DeviceExportFilter {
+    pub office_id: Option<Uuid>,
+    pub server_room_id: Option<Uuid>,
+    pub rack_id: Option<Uuid>,
+}
+
+struct DeviceRow {
+    id: Option<Uuid>,
+    name: Option<String>,
+    device_type: Option<String>,
+    status: Option<String>,
+    manufacturer: CsvField<String>,
+    model: CsvField<String>,
+    serial_number: CsvField<String>,
+    /// 이름으로 찾은 랙 ID. 빈 칸이면 랙에서 뺀다
+    rack_id: CsvField<Uuid>,
+    rack_position: CsvField<i32>,
+    rack_size: Option<i32>,
+    power_consumption: CsvField<i32>,
+    purchase_date: CsvField<NaiveDate>,
+    warranty_end: CsvField<NaiveDate>,
+    description: CsvField<String>,
+}
+
+fn parse_device_row(row: &RowReader, locations: &LocationIndex) -> Result<DeviceRow, RowError> {
+    let rack_id = match row.field("rack") {
+        Some(Some(rack_name)) => {
+            let office = row.text("office");
+            let room = row.text("server_room");
+            let rack = locations
+                .resolve_rack(office.as_deref(), room.as_deref(), &rack_name)
+                .map_err(|message| row.error("rack", message))?;
+            Some(Some(rack.id))
+        }
+        Some(None) => Some(None),
+        None => None,
+    };
+
+    Ok(DeviceRow {
+        id: row.uuid("id")?,
+        name: row.text("name"),
+        device_type: row.text("device_type"),
+        status: row.text("status"),
+        manufacturer: row.field("manufacturer"),
+        model: row.field("model"),
+        serial_number: row.field("serial_number"),
+        rack_id,
+        rack_position: row.int("rack_position")?,
+        rack_size: row.int("rack_size")?.flatten(),
+        power_consumption: row.int("power_consumption")?,
+        purchase_date: row.date("purchase_date")?,
+        warranty_end: row.date("warranty_end")?,
+        description: row.field("description"),
+    })
+}
+
+/// `id`가 있으면 해당 장비, 없으면 시리얼 번호가 같은 장비를 수정 대상으로 찾는다
+async fn find_existing_device<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    row: &DeviceRow,
+) -> ServiceResult<Option<devices::Model>>
+where
+    C: ConnectionTrait,
+{
+    if let Some(id) = row.id {
+        return repository_get_device_by_id(conn, &id)
+            .await?
+            .filter(|device| device.tenant_id == Some(tenant_id))
+            .map(Some)
+            .ok_or_else(|| Errors::NotFound(format!("Device {} not found", id)));
+    }
+
+    let Some(Some(serial_number)) = row.serial_number.as_ref() else {
+        return Ok(None);
+    };
+    let mut matches = devices::Entity::find()
+        .filter(devices::Column::TenantId.eq(tenant_id))
+        .filter(devices::Column::SerialNumber.eq(serial_number.as_str()))
+        .filter(devices::Column::IsActive.eq(true))
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+    if matches.len() > 1 {
+        return Err(Errors::BadRequestError(format!(
+            "Serial number '{}' matches {} devices; add the id column",
+            serial_number,
+            matches.len()
+        )));
+    }
+    Ok(matches.pop())
+}
+
+async fn apply_device_row<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    row: DeviceRow,
+    actor: Uuid,
+) -> ServiceResult<(RowOutcome, Option<PowerBudgetOverage>)>
+where
+    C: ConnectionTrait,
+{
+    let existing = find_existing_device(conn, tenant_id, &row).await?;
+
+    // 파일에 없는 값은 기존 값 유지. 배치/전력이 바뀌는 행만 랙 검증을 거친다 (단건 수정과 동일)
+    let rack_id = row
+        .rack_id
+        .unwrap_or_else(|| existing.as_ref().and_then(|d| d.rack_id));
+    let rack_position = row
+        .rack_position
+        .unwrap_or_else(|| existing.as_ref().and_then(|d| d.rack_position));
+    let rack_size = row
+        .rack_size
+        .or(existing.as_ref().map(|d| d.rack_size))
+        .unwrap_or(1);
+    let power_consumption = row
+        .power_consumption
+        .unwrap_or_else(|| existing.as_ref().and_then(|d| d.power_consumption));
+    let name = row
+        .name
+        .clone()
+        .or_else(|| existing.as_ref().map(|d| d.name.clone()))
+        .ok_or_else(|| Errors::BadRequestError("name is required".to_string()))?;
+
+    if rack_position.is_some() && rack_id.is_none() {
+        return Err(Errors::BadRequestError(
+            "rack_position requires a rack".to_string(),
+        ));
+    }
+
+    let placement_changed = existing.as_ref().is_none_or(|d| {
+        d.rack_id != rack_id || d.rack_position != rack_position || d.rack_size != rack_size
+    });
+    let power_changed = existing
+        .as_ref()
+        .is_none_or(|d| d.rack_id != rack_id || d.power_consumption != power_consumption);
+    let exclude = existing.as_ref().map(|d| d.id);
+
+    let mut power_overage = None;
+    if let Some(rack_id) = rack_id
+        && (placement_changed || power_changed)
+    {
+        let rack = lock_rack(conn, &rack_id).await?;
+        if placement_changed && let Some(position) = rack_position {
+            ensure_rack_slots_available(conn, &rack, position, rack_size, exclude.as_ref()).await?;
+        }
+        if power_changed {
+            power_overage =
+                check_rack_power_budget(conn, &rack, &name, power_consumption, exclude.as_ref())
+                    .await?;
+        }
+    }
+
+    let (action, device) = match existing.as_ref() {
+        Some(before) => {
+            let device = repository_update_device(
+                conn,
+                &before.id,
+                row.rack_id,
+                row.name.as_deref(),
+                row.description.as_ref().map(|v| v.as_deref()),
+                row.device_type.as_deref(),
+                row.manufacturer.as_ref().map(|v| v.as_deref()),
+                row.model.as_ref().map(|v| v.as_deref()),
+                row.serial_number.as_ref().map(|v| v.as_deref()),
+                row.rack_position,
+                row.rack_size,
+                row.power_consumption,
+                row.status.as_deref(),
+                row.purchase_date,
+                row.warranty_end,
+            )
+            .await?;
+            guard_manual_edit(
+                conn,
+                ManualEdit {
+                    resource_type: RESOURCE_DEVICE,
+                    resource_id: before.id,
+                    source_type: &before.source_type,
+                    connection_id: before.external_api_connection_id,
+                    actor_id: actor,
+                    before,
+                    after: &device,
+                },
+            )
+            .await?;
+            (ACTION_UPDATE, device)
+        }
+        None => {
+            let device_type = row.device_type.as_deref().ok_or_else(|| {
+                Errors::BadRequestError("device_type is required for new devices".to_string())
+            })?;
+            let device = repository_create_device(
+                conn,
+                &tenant_id,
+                rack_id.as_ref(),
+                &name,
+                row.description.flatten().as_deref(),
+                device_type,
+                row.manufacturer.flatten().as_deref(),
+                row.model.flatten().as_deref(),
+                row.serial_number.flatten().as_deref(),
+                rack_position,
+                rack_size,
+                power_consumption,
+                row.status.as_deref().unwrap_or("active"),
+                row.purchase_date.flatten(),
+                row.warranty_end.flatten(),
+                &actor,
+            )
+            .await?;
+            (ACTION_CREATE, device)
+        }
+    };
+
+    record_audit(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_DEVICE,
+            resource_id: device.id,
+            resource_name: Some(&device.name),
+            action,
+            actor_id: Some(actor),
+            before: existing.as_ref(),
+            after: Some(&device),
+        },
+    )
+    .await?;
+
+    Ok((
+        RowOutcome {
+            action,
+            id: device.id,
+            name: device.name,
+        },
+        power_overage,
+    ))
+}
+
+/// 장비 CSV를 가져온다. `id` 또는 `serial_number`가 기존 장비와 일치하면 수정, 아니면 생성한다.
+/// 랙은 `rack` 이름으로 찾고, 이름이 겹치면 `server_room`/`office` 열로 좁힌다.
+pub async fn service_import_devices(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    content: &str,
+    dry_run: bool,
+    imported_by: Uuid,
+) -> ServiceResult<BulkImportResponse> {
+    let sheet = parse_csv(content)?;
+    sheet.ensure_columns(&DEVICE_COLUMNS, &["name"])?;
+
+    let locations = LocationIndex::load(conn, tenant_id).await?;
+    let txn = begin_import(conn, &sheet).await?;
+    let mut report = ImportReport::new("device", dry_run, sheet.records().len());
+    let mut overages = Vec::new();
+
+    for record in sheet.records() {
+        let reader = sheet.reader(record);
+        let row = match parse_device_row(&reader, &locations) {
+            Ok(row) => row,
+            Err(error) => {
+                report.failed(error);
+                continue;
+            }
+        };
+
+        let savepoint = txn.begin().await?;
+        let result = apply_device_row(&savepoint, tenant_id, row, imported_by).await;
+        if let Some((outcome, overage)) =
+            settle_row(savepoint, &mut report, record.line, result).await?
+        {
+            report.succeeded(record.line, outcome);
+            overages.extend(overage);
+        }
+    }
+
+    let (response, committed) = report.finish(txn).await?;
+    if committed {
+        for overage in overages {
+            notify_power_budget_overage(conn, overage, Some(imported_by)).await;
+        }
+    }
+    Ok(response)
+}
+
+/// 활성 장비를 가져오기와 같은 열 형식의 CSV로 내보낸다 (페이지 단위 스트리밍)
+pub async fn service_export_devices(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    filter: DeviceExportFilter,
+) -> ServiceResult<impl Stream<Item = CsvChunk> + Send + 'static + use<>> {
+    let locations = Arc::new(LocationIndex::load(conn, tenant_id).await?);
+    let filter = Arc::new(filter);
+    let conn = conn.clone();
+
+    Ok(csv_export_stream(&DEVICE_COLUMNS, move |page| {
+        let conn = conn.clone();
+        let locations = locations.clone();
+        let filter = filter.clone();
+        async move { export_device_page(&conn, tenant_id, &locations, &filter, page).await }
+    }))
+}
+
+async fn export_device_page(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    locations: &LocationIndex,
+    filter: &DeviceExportFilter,
+    page: u64,
+) -> ServiceResult<Option<Vec<Vec<String>>>> {
+    let mut query = devices::Entity::find()
+        .filter(devices::Column::TenantId.eq(tenant_id))
+        .filter(devices::Column::IsActive.eq(true));
+    if let Some(rack_id) = filter.rack_id {
+        query = query.filter(devices::Column::RackId.eq(rack_id));
+    }
+    let devices = query
+        .order_by_asc(devices::Column::Name)
+        .order_by_asc(devices::Column::Id)
+        .offset(page * EXPORT_PAGE_SIZE)
+        .limit(EXPORT_PAGE_SIZE)
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+    if devices.is_empty() {
+        return Ok(None);
+    }
+
+    let room_ids = filter.server_room_id.map(|room_id| vec![room_id]);
+    let office_rooms = filter
+        .office_id
+        .map(|office_id| locations.server_room_ids_in_office(&office_id));
+
+    let mut rows = Vec::with_capacity(devices.len());
+    for device in devices {
+        let room_id = device
+            .rack_id
+            .and_then(|rack_id| locations.rack(&rack_id))
+            .map(|rack| rack.server_room_id);
+        let in_scope = |rooms: &Option<Vec<Uuid>>| {
+            rooms
+                .as_ref()
+                .is_none_or(|rooms| room_id.is_some_and(|id| rooms.contains(&id)))
+        };
+        if !in_scope(&room_ids) || !in_scope(&office_rooms) {
+            continue;
+        }
+
+        let (office, room, rack) = device
+            .rack_id
+            .map(|rack_id| locations.rack_names(&rack_id))
+            .unwrap_or_default();
+        rows.push(vec![
+            device.id.to_string(),
+            device.name,
+            device.device_type,
+            device.status,
+            cell(device.manufacturer),
+            cell(device.model),
+            cell(device.serial_number),
+            cell(office),
+            cell(room),
+            cell(rack),
+            cell(device.rack_position),
+            device.rack_size.to_string(),
+            cell(device.power_consumption),
+            cell(device.purchase_date),
+            cell(device.warranty_end),
+            cell(device.description),
+        ]);
+    }
+
+    Ok(Some(rows))
+}
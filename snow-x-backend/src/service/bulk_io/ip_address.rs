@@ -0,0 +1,313 @@
+use super::csv::{
+    CsvChunk, CsvField, EXPORT_PAGE_SIZE, RowReader, cell, csv_export_stream, parse_csv,
+};
+use super::{ImportReport, RowError, RowOutcome, begin_import, settle_row};
+use crate::dto::bulk_io::response::BulkImportResponse;
+use crate::service::audit::{ACTION_CREATE, ACTION_UPDATE};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::ip_address::audit::{
+    IP_ADDRESS_COLUMNS as MODEL_COLUMNS, ip_address_from_row, lock_ip_address,
+    record_ip_address_audit,
+};
+use crate::service::ip_range::hierarchy::{TenantRange, fetch_active_ranges};
+use crate::utils::ip_math::parse_ip;
+use futures_util::Stream;
+use sea_orm::{ConnectionTrait, DatabaseConnection, FromQueryResult, Statement, TransactionTrait};
+use std::net::IpAddr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub const IP_ADDRESS_COLUMNS: [&str; 6] = [
+    "ip_range",
+    "ip_address",
+    "status",
+    "hostname",
+    "mac_address",
+    "description",
+];
+
+const IP_ADDRESS_STATUSES: [&str; 5] = [
+    "available",
+    "reserved",
+    "unavailable",
+    "allocated",
+    "expired",
+];
+
+/// 새 주소의 status가 비어 있을 때 기본값
+const DEFAULT_IMPORT_STATUS: &str = "allocated";
+
+/// IP 주소 내보내기 범위
+#[derive(Debug, Default)]
+pub struct IpAddressExportFilter {
+    pub ip_range_id: Option<Uuid>,
+    pub status: Option<String>,
+}
+
+struct IpAddressRow {
+    ip_range_id: Uuid,
+    ip_address: IpAddr,
+    status: Option<String>,
+    hostname: CsvField<String>,
+    mac_address: CsvField<String>,
+    description: CsvField<String>,
+}
+
+/// 주소를 포함하는 대역을 고른다. `ip_range` 이름이 있으면 그 이름의 대역 중에서,
+/// 없으면 전체 대역 중 prefix가 가장 긴 대역을 쓴다. 같은 CIDR이 여러 office에 있으면 모호하다.
+fn match_range<'a>(
+    ranges: &'a [TenantRange],
+    address: &IpAddr,
+    range_name: Option<&str>,
+) -> Result<&'a TenantRange, String> {
+    let mut matches: Vec<&TenantRange> = ranges
+        .iter()
+        .filter(|range| {
+            range_name.is_none_or(|name| range.model.name.trim().eq_ignore_ascii_case(name.trim()))
+        })
+        .filter(|range| range.network.contains_ip(address))
+        .collect();
+    matches.sort_by_key(|range| std::cmp::Reverse(range.network.prefix()));
+
+    match matches.as_slice() {
+        [] => Err(match range_name {
+            Some(name) => format!("{} is not inside IP range '{}'", address, name),
+            None => format!("No IP range contains {}", address),
+        }),
+        [best, next, ..] if best.network == next.network => Err(format!(
+            "{} matches IP ranges in multiple offices; add the ip_range column",
+            address
+        )),
+        [best, ..] => Ok(best),
+    }
+}
+
+fn parse_ip_address_row(row: &RowReader, ranges: &[TenantRange]) -> Result<IpAddressRow, RowError> {
+    let raw_address = row.required("ip_address")?;
+    let ip_address = parse_ip(&raw_address).map_err(|_| {
+        row.error(
+            "ip_address",
+            format!("'{}' is not an IP address", raw_address),
+        )
+    })?;
+
+    let range_name = row.text("ip_range");
+    let range = match_range(ranges, &ip_address, range_name.as_deref())
+        .map_err(|message| row.error("ip_range", message))?;
+
+    let status = row.text("status");
+    if let Some(status) = status.as_deref()
+        && !IP_ADDRESS_STATUSES.contains(&status)
+    {
+        return Err(row.error(
+            "status",
+            format!(
+                "Invalid status '{}' (expected one of: {})",
+                status,
+                IP_ADDRESS_STATUSES.join(", ")
+            ),
+        ));
+    }
+
+    Ok(IpAddressRow {
+        ip_range_id: range.model.id,
+        ip_address,
+        status,
+        hostname: row.field("hostname"),
+        mac_address: row.field("mac_address"),
+        description: row.field("description"),
+    })
+}
+
+/// (대역, 주소)로 upsert한다. 파일에 없는 열은 기존 값을 유지한다.
+async fn apply_ip_address_row<C>(
+    conn: &C,
+    row: IpAddressRow,
+    actor: Uuid,
+) -> ServiceResult<RowOutcome>
+where
+    C: ConnectionTrait,
+{
+    let sql = format!(
+        r#"
+        INSERT INTO ip_addresses (
+            id, ip_range_id, ip_address, status, hostname, mac_address, description,
+            created_by, created_at, updated_at, is_active
+        ) VALUES (
+            $1, $2, $3::inet, $4, $5, $6::macaddr, $7, $8, $9, $9, true
+        )
+        ON CONFLICT (ip_address, ip_range_id) DO UPDATE
+        SET status = CASE WHEN $10 THEN EXCLUDED.status ELSE ip_addresses.status END,
+            hostname = CASE WHEN $11 THEN EXCLUDED.hostname ELSE ip_addresses.hostname END,
+            mac_address = CASE WHEN $12 THEN EXCLUDED.mac_address ELSE ip_addresses.mac_address END,
+            description = CASE WHEN $13 THEN EXCLUDED.description ELSE ip_addresses.description END,
+            updated_at = EXCLUDED.updated_at,
+            is_active = true
+        RETURNING {}
+    "#,
+        MODEL_COLUMNS
+    );
+
+    let address = row.ip_address.to_string();
+    let before = lock_ip_address(conn, &row.ip_range_id, &address).await?;
+    let result = conn
+        .query_one(Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            sql,
+            vec![
+                Uuid::new_v4().into(),
+                row.ip_range_id.into(),
+                address.clone().into(),
+                row.status
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_IMPORT_STATUS.to_string())
+                    .into(),
+                row.hostname.clone().flatten().into(),
+                row.mac_address.clone().flatten().into(),
+                row.description.clone().flatten().into(),
+                actor.into(),
+                chrono::Utc::now().into(),
+                row.status.is_some().into(),
+                row.hostname.is_some().into(),
+                row.mac_address.is_some().into(),
+                row.description.is_some().into(),
+            ],
+        ))
+        .await?
+        .ok_or_else(|| Errors::DatabaseError("IP address upsert returned no row".to_string()))?;
+
+    let after = ip_address_from_row(&result)?;
+    record_ip_address_audit(conn, before.as_ref(), &after, Some(actor)).await?;
+
+    Ok(RowOutcome {
+        action: if before.is_some() {
+            ACTION_UPDATE
+        } else {
+            ACTION_CREATE
+        },
+        id: after.id,
+        name: address,
+    })
+}
+
+/// IP 주소 CSV를 가져온다. 같은 대역에 이미 있는 주소는 수정, 없으면 생성한다.
+pub async fn service_import_ip_addresses(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    content: &str,
+    dry_run: bool,
+    imported_by: Uuid,
+) -> ServiceResult<BulkImportResponse> {
+    let sheet = parse_csv(content)?;
+    sheet.ensure_columns(&IP_ADDRESS_COLUMNS, &["ip_address"])?;
+
+    let ranges = fetch_active_ranges(conn, None, Some(&tenant_id)).await?;
+    let txn = begin_import(conn, &sheet).await?;
+    let mut report = ImportReport::new("ip_address", dry_run, sheet.records().len());
+
+    for record in sheet.records() {
+        let reader = sheet.reader(record);
+        let row = match parse_ip_address_row(&reader, &ranges) {
+            Ok(row) => row,
+            Err(error) => {
+                report.failed(error);
+                continue;
+            }
+        };
+
+        let savepoint = txn.begin().await?;
+        let result = apply_ip_address_row(&savepoint, row, imported_by).await;
+        if let Some(outcome) = settle_row(savepoint, &mut report, record.line, result).await? {
+            report.succeeded(record.line, outcome);
+        }
+    }
+
+    Ok(report.finish(txn).await?.0)
+}
+
+#[derive(FromQueryResult)]
+struct IpAddressExportRow {
+    ip_range: String,
+    ip_address: String,
+    status: String,
+    hostname: Option<String>,
+    mac_address: Option<String>,
+    description: Option<String>,
+}
+
+/// 활성 IP 주소를 가져오기와 같은 열 형식의 CSV로 내보낸다 (대역 이름, 주소 순, 페이지 단위 스트리밍)
+pub fn service_export_ip_addresses(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    filter: IpAddressExportFilter,
+) -> impl Stream<Item = CsvChunk> + Send + 'static + use<> {
+    let conn = conn.clone();
+    let filter = Arc::new(filter);
+
+    csv_export_stream(&IP_ADDRESS_COLUMNS, move |page| {
+        let conn = conn.clone();
+        let filter = filter.clone();
+        async move { export_ip_address_page(&conn, tenant_id, &filter, page).await }
+    })
+}
+
+async fn export_ip_address_page(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    filter: &IpAddressExportFilter,
+    page: u64,
+) -> ServiceResult<Option<Vec<Vec<String>>>> {
+    let sql = r#"
+        SELECT
+            r.name as ip_range,
+            HOST(a.ip_address) as ip_address,
+            a.status,
+            a.hostname,
+            a.mac_address::text as mac_address,
+            a.description
+        FROM ip_addresses a
+        JOIN ip_ranges r ON r.id = a.ip_range_id
+        JOIN offices o ON o.id = r.tenant_id
+        WHERE a.is_active = true
+          AND o.tenant_id = $3
+          AND r.is_active = true
+          AND ($1::uuid IS NULL OR a.ip_range_id = $1)
+          AND ($2::text IS NULL OR a.status = $2)
+        ORDER BY r.name, a.ip_address, a.id
+        LIMIT $4 OFFSET $5
+    "#;
+
+    let rows = conn
+        .query_all(Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            sql,
+            vec![
+                filter.ip_range_id.into(),
+                filter.status.clone().into(),
+                tenant_id.into(),
+                (EXPORT_PAGE_SIZE as i64).into(),
+                ((page * EXPORT_PAGE_SIZE) as i64).into(),
+            ],
+        ))
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+    if rows.is_empty() {
+        return Ok(None);
+    }
+
+    rows.iter()
+        .map(|row| {
+            let row = IpAddressExportRow::from_query_result(row, "")
+                .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+            Ok(vec![
+                row.ip_range,
+                row.ip_address,
+                row.status,
+                cell(row.hostname),
+                cell(row.mac_address),
+                cell(row.description),
+            ])
+        })
+        .collect::<ServiceResult<Vec<_>>>()
+        .map(Some)
+}
@@ -0,0 +1,159 @@
+use crate::entity::{office, racks, server_rooms};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::tenant::offices_in_tenant;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 사무실/서버실/랙을 이름으로 찾기 위한 인덱스.
+/// 가져오기 시작 시 한 번 읽어 두고 행마다 조회한다 (이름 비교는 앞뒤 공백과 대소문자 무시).
+pub struct LocationIndex {
+    offices: HashMap<Uuid, office::Model>,
+    rooms: HashMap<Uuid, server_rooms::Model>,
+    racks: HashMap<Uuid, racks::Model>,
+}
+
+fn same_name(a: &str, b: &str) -> bool {
+    a.trim().to_lowercase() == b.trim().to_lowercase()
+}
+
+/// 이름이 여러 개와 일치하면 어떤 열로 좁혀야 하는지 알려준다
+fn pick_one<'a, T>(
+    matches: Vec<&'a T>,
+    kind: &str,
+    name: &str,
+    narrow_with: &str,
+) -> Result<&'a T, String> {
+    match matches.as_slice() {
+        [only] => Ok(only),
+        [] => Err(format!("{} '{}' not found", kind, name)),
+        _ => Err(format!(
+            "{} '{}' matches {} entries; add {} to disambiguate",
+            kind,
+            name,
+            matches.len(),
+            narrow_with
+        )),
+    }
+}
+
+impl LocationIndex {
+    /// 테넌트의 사무실/서버실/랙만 읽는다 (다른 테넌트의 같은 이름과 섞이지 않도록)
+    pub async fn load<C>(conn: &C, tenant_id: Uuid) -> ServiceResult<Self>
+    where
+        C: ConnectionTrait,
+    {
+        let offices = office::Entity::find()
+            .filter(office::Column::TenantId.eq(tenant_id))
+            .filter(office::Column::IsActive.eq(true))
+            .all(conn)
+            .await
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+        let rooms = server_rooms::Entity::find()
+            .filter(server_rooms::Column::OfficeId.in_subquery(offices_in_tenant(tenant_id)))
+            .filter(server_rooms::Column::IsActive.eq(true))
+            .all(conn)
+            .await
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+        let all_racks = racks::Entity::find()
+            .filter(racks::Column::IsActive.eq(true))
+            .all(conn)
+            .await
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+        let rooms: HashMap<Uuid, server_rooms::Model> =
+            rooms.into_iter().map(|r| (r.id, r)).collect();
+        let all_racks = all_racks
+            .into_iter()
+            .filter(|rack| rooms.contains_key(&rack.server_room_id))
+            .map(|r| (r.id, r))
+            .collect();
+
+        Ok(LocationIndex {
+            offices: offices.into_iter().map(|o| (o.id, o)).collect(),
+            rooms,
+            racks: all_racks,
+        })
+    }
+
+    fn office_matches(&self, office_id: &Uuid, office_name: Option<&str>) -> bool {
+        office_name.is_none_or(|name| {
+            self.offices
+                .get(office_id)
+                .is_some_and(|office| same_name(&office.name, name))
+        })
+    }
+
+    /// 서버실 이름(선택: 사무실 이름)으로 서버실을 찾는다
+    pub fn resolve_server_room(
+        &self,
+        office_name: Option<&str>,
+        room_name: &str,
+    ) -> Result<&server_rooms::Model, String> {
+        let matches = self
+            .rooms
+            .values()
+            .filter(|room| same_name(&room.name, room_name))
+            .filter(|room| self.office_matches(&room.office_id, office_name))
+            .collect();
+        pick_one(matches, "Server room", room_name, "the office column")
+    }
+
+    /// 랙 이름(선택: 서버실, 사무실 이름)으로 랙을 찾는다
+    pub fn resolve_rack(
+        &self,
+        office_name: Option<&str>,
+        room_name: Option<&str>,
+        rack_name: &str,
+    ) -> Result<&racks::Model, String> {
+        let matches = self
+            .racks
+            .values()
+            .filter(|rack| same_name(&rack.name, rack_name))
+            .filter(|rack| {
+                let room = self.rooms.get(&rack.server_room_id);
+                room_name.is_none_or(|name| room.is_some_and(|room| same_name(&room.name, name)))
+                    && (office_name.is_none()
+                        || room
+                            .is_some_and(|room| self.office_matches(&room.office_id, office_name)))
+            })
+            .collect();
+        pick_one(matches, "Rack", rack_name, "server_room/office columns")
+    }
+
+    pub fn rack(&self, rack_id: &Uuid) -> Option<&racks::Model> {
+        self.racks.get(rack_id)
+    }
+
+    pub fn server_room_ids(&self) -> Vec<Uuid> {
+        self.rooms.keys().copied().collect()
+    }
+
+    pub fn server_room_ids_in_office(&self, office_id: &Uuid) -> Vec<Uuid> {
+        self.rooms
+            .values()
+            .filter(|room| room.office_id == *office_id)
+            .map(|room| room.id)
+            .collect()
+    }
+
+    /// 내보내기용 (사무실 이름, 서버실 이름)
+    pub fn server_room_names(&self, room_id: &Uuid) -> (Option<&str>, Option<&str>) {
+        let room = self.rooms.get(room_id);
+        let office = room.and_then(|room| self.offices.get(&room.office_id));
+        (
+            office.map(|office| office.name.as_str()),
+            room.map(|room| room.name.as_str()),
+        )
+    }
+
+    /// 내보내기용 (사무실 이름, 서버실 이름, 랙 이름)
+    pub fn rack_names(&self, rack_id: &Uuid) -> (Option<&str>, Option<&str>, Option<&str>) {
+        match self.racks.get(rack_id) {
+            Some(rack) => {
+                let (office, room) = self.server_room_names(&rack.server_room_id);
+                (office, room, Some(rack.name.as_str()))
+            }
+            None => (None, None, None),
+        }
+    }
+}
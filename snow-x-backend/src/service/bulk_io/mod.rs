@@ -0,0 +1,233 @@
+//! 장비/랙/IP 주소/담당자 CSV 일괄 가져오기와 내보내기.
+//!
+//! 가져오기는 파일 전체를 하나의 트랜잭션에서 처리하고 행마다 savepoint를 둔다.
+//! 실패한 행은 savepoint만 롤백해 다음 행을 계속 검증하고, 끝에 한 행이라도 실패했거나
+//! dry-run이면 전체를 롤백한다. 그래서 dry-run 미리보기와 실제 반영이 같은 검증
+//! (U 위치 충돌, 전력 예산, 파일 안의 행끼리의 충돌 포함)을 거친다.
+
+pub mod contact;
+pub mod csv;
+pub mod device;
+pub mod ip_address;
+pub mod locations;
+pub mod rack;
+
+use crate::dto::bulk_io::response::{BulkImportResponse, BulkImportRowError, BulkImportRowResult};
+use crate::service::audit::ACTION_CREATE;
+use crate::service::error::errors::{Errors, ServiceResult};
+use axum::extract::Multipart;
+use sea_orm::{DatabaseTransaction, TransactionTrait};
+use uuid::Uuid;
+
+pub use contact::{service_export_contacts, service_import_contacts};
+pub use csv::CsvChunk;
+pub use device::{DeviceExportFilter, service_export_devices, service_import_devices};
+pub use ip_address::{
+    IpAddressExportFilter, service_export_ip_addresses, service_import_ip_addresses,
+};
+pub use rack::{RackExportFilter, service_export_racks, service_import_racks};
+
+pub const MAX_IMPORT_FILE_SIZE: usize = 16 * 1024 * 1024;
+/// 한 번에 가져올 수 있는 최대 행 수
+pub const MAX_IMPORT_ROWS: usize = 20_000;
+
+/// 행 단위 오류
+#[derive(Debug)]
+pub struct RowError {
+    pub row: usize,
+    pub column: Option<String>,
+    pub message: String,
+}
+
+impl RowError {
+    pub fn from_service(row: usize, err: Errors) -> Self {
+        RowError {
+            row,
+            column: None,
+            message: describe_error(err),
+        }
+    }
+}
+
+/// 행 반영 결과
+pub struct RowOutcome {
+    /// 감사 로그와 같은 `create`/`update`
+    pub action: &'static str,
+    pub id: Uuid,
+    pub name: String,
+}
+
+/// 행 오류 메시지로 쓸 수 있도록 서비스 오류에서 사람이 읽을 문구를 꺼낸다
+pub(crate) fn describe_error(err: Errors) -> String {
+    match err {
+        Errors::BadRequestError(message)
+        | Errors::ValidationError(message)
+        | Errors::NotFound(message)
+        | Errors::RackSlotConflict(message)
+        | Errors::RackPowerBudgetExceeded(message)
+        | Errors::IpRangeOverlap(message) => message,
+        Errors::ServerRoomNotFound => "Server room not found".to_string(),
+        Errors::DatabaseError(message) => format!("Database error: {}", message),
+        other => format!("{:?}", other),
+    }
+}
+
+/// 가져오기 진행 상황을 모아 응답으로 만든다
+pub(crate) struct ImportReport {
+    resource: &'static str,
+    dry_run: bool,
+    total_rows: usize,
+    rows: Vec<BulkImportRowResult>,
+    errors: Vec<RowError>,
+}
+
+impl ImportReport {
+    pub(crate) fn new(resource: &'static str, dry_run: bool, total_rows: usize) -> Self {
+        ImportReport {
+            resource,
+            dry_run,
+            total_rows,
+            rows: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn succeeded(&mut self, row: usize, outcome: RowOutcome) {
+        self.rows.push(BulkImportRowResult {
+            row,
+            action: outcome.action.to_string(),
+            id: Some(outcome.id),
+            name: outcome.name,
+        });
+    }
+
+    pub(crate) fn failed(&mut self, error: RowError) {
+        self.errors.push(error);
+    }
+
+    /// 오류가 없고 dry-run이 아니면 커밋, 아니면 롤백한다. 커밋 여부를 돌려준다.
+    pub(crate) async fn finish(
+        mut self,
+        txn: DatabaseTransaction,
+    ) -> ServiceResult<(BulkImportResponse, bool)> {
+        let committed = !self.dry_run && self.errors.is_empty();
+        if committed {
+            txn.commit().await?;
+        } else {
+            txn.rollback().await?;
+            // 롤백된 생성 행의 ID는 실제로 존재하지 않으므로 내보내지 않는다
+            for row in &mut self.rows {
+                if row.action == ACTION_CREATE {
+                    row.id = None;
+                }
+            }
+        }
+
+        let created = self
+            .rows
+            .iter()
+            .filter(|row| row.action == ACTION_CREATE)
+            .count();
+        let response = BulkImportResponse {
+            resource: self.resource.to_string(),
+            dry_run: self.dry_run,
+            committed,
+            total_rows: self.total_rows,
+            created,
+            updated: self.rows.len() - created,
+            failed: self.errors.len(),
+            rows: self.rows,
+            errors: self
+                .errors
+                .into_iter()
+                .map(|error| BulkImportRowError {
+                    row: error.row,
+                    column: error.column,
+                    message: error.message,
+                })
+                .collect(),
+        };
+        Ok((response, committed))
+    }
+}
+
+/// savepoint 안에서 적용한 행 결과를 정리한다.
+/// 실패하면 savepoint만 롤백하고 오류를 기록해 다음 행을 계속 처리할 수 있게 한다.
+pub(crate) async fn settle_row<T>(
+    savepoint: DatabaseTransaction,
+    report: &mut ImportReport,
+    line: usize,
+    result: ServiceResult<T>,
+) -> ServiceResult<Option<T>> {
+    match result {
+        Ok(value) => {
+            savepoint.commit().await?;
+            Ok(Some(value))
+        }
+        Err(err) => {
+            savepoint.rollback().await?;
+            report.failed(RowError::from_service(line, err));
+            Ok(None)
+        }
+    }
+}
+
+/// multipart 업로드(`file`)로 받은 CSV 내용을 읽는다. XLSX/XLS는 아직 지원하지 않는다.
+pub async fn read_import_upload(mut multipart: Multipart) -> ServiceResult<String> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| Errors::FileReadError(format!("Failed to read multipart field: {}", e)))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field.file_name().unwrap_or_default().to_lowercase();
+        let data = field
+            .bytes()
+            .await
+            .map_err(|e| Errors::FileReadError(format!("Failed to read file data: {}", e)))?;
+        if data.len() > MAX_IMPORT_FILE_SIZE {
+            return Err(Errors::FileTooLargeError(format!(
+                "Import file exceeds {} bytes",
+                MAX_IMPORT_FILE_SIZE
+            )));
+        }
+
+        // XLSX는 zip(PK), XLS는 OLE2 컨테이너로 시작한다
+        let is_spreadsheet = file_name.ends_with(".xlsx")
+            || file_name.ends_with(".xls")
+            || data.starts_with(b"PK\x03\x04")
+            || data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0]);
+        if is_spreadsheet {
+            return Err(Errors::BadRequestError(
+                "Excel workbooks are not supported; save the sheet as CSV UTF-8 and upload that"
+                    .to_string(),
+            ));
+        }
+
+        return String::from_utf8(data.to_vec()).map_err(|_| {
+            Errors::FileReadError("Import file must be UTF-8 encoded CSV".to_string())
+        });
+    }
+
+    Err(Errors::FileNotFound)
+}
+
+/// 트랜잭션을 열고 행 수 제한을 확인한다
+pub(crate) async fn begin_import<C>(
+    conn: &C,
+    sheet: &csv::CsvSheet,
+) -> ServiceResult<DatabaseTransaction>
+where
+    C: TransactionTrait,
+{
+    if sheet.records().len() > MAX_IMPORT_ROWS {
+        return Err(Errors::BadRequestError(format!(
+            "Import is limited to {} rows per file",
+            MAX_IMPORT_ROWS
+        )));
+    }
+    Ok(conn.begin().await?)
+}
@@ -0,0 +1,342 @@
+use super::csv::{
+    CsvChunk, CsvField, EXPORT_PAGE_SIZE, RowReader, cell, csv_export_stream, parse_csv,
+};
+use super::locations::LocationIndex;
+use super::{ImportReport, RowError, RowOutcome, begin_import, settle_row};
+use crate::dto::bulk_io::response::BulkImportResponse;
+use crate::entity::racks;
+use crate::repository::rack::repository_create_rack;
+use crate::service::audit::{
+    ACTION_CREATE, ACTION_UPDATE, AuditEntry, RESOURCE_RACK, record_audit,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::rack::elevation::highest_occupied_unit;
+use chrono::Utc;
+use futures_util::Stream;
+use sea_orm::prelude::Decimal;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub const RACK_COLUMNS: [&str; 10] = [
+    "id",
+    "name",
+    "office",
+    "server_room",
+    "rack_height",
+    "power_capacity",
+    "cooling_type",
+    "location_x",
+    "location_y",
+    "description",
+];
+
+/// 랙 내보내기 범위
+#[derive(Debug, Default)]
+pub struct RackExportFilter {
+    pub office_id: Option<Uuid>,
+    pub server_room_id: Option<Uuid>,
+}
+
+struct RackRow {
+    id: Option<Uuid>,
+    name: Option<String>,
+    server_room_id: Option<Uuid>,
+    rack_height: Option<i32>,
+    power_capacity: CsvField<i32>,
+    cooling_type: CsvField<String>,
+    location_x: CsvField<f64>,
+    location_y: CsvField<f64>,
+    description: CsvField<String>,
+}
+
+fn coordinate(row: &RowReader, column: &str) -> Result<CsvField<f64>, RowError> {
+    let value = row.float(column)?;
+    if value.flatten().is_some_and(|v| !v.is_finite()) {
+        return Err(row.error(column, format!("{} must be a finite number", column)));
+    }
+    Ok(value)
+}
+
+fn parse_rack_row(row: &RowReader, locations: &LocationIndex) -> Result<RackRow, RowError> {
+    let server_room_id = match row.text("server_room") {
+        Some(room_name) => {
+            let office = row.text("office");
+            let room = locations
+                .resolve_server_room(office.as_deref(), &room_name)
+                .map_err(|message| row.error("server_room", message))?;
+            Some(room.id)
+        }
+        None => None,
+    };
+
+    let rack_height = row.int("rack_height")?.flatten();
+    if rack_height.is_some_and(|height| height < 1) {
+        return Err(row.error("rack_height", "rack_height must be at least 1U".to_string()));
+    }
+    let power_capacity = row.int("power_capacity")?;
+    if power_capacity.flatten().is_some_and(|watts| watts < 0) {
+        return Err(row.error(
+            "power_capacity",
+            "power_capacity cannot be negative".to_string(),
+        ));
+    }
+
+    Ok(RackRow {
+        id: row.uuid("id")?,
+        name: row.text("name"),
+        server_room_id,
+        rack_height,
+        power_capacity,
+        cooling_type: row.field("cooling_type"),
+        location_x: coordinate(row, "location_x")?,
+        location_y: coordinate(row, "location_y")?,
+        description: row.field("description"),
+    })
+}
+
+/// `id`가 있으면 해당 랙, 없으면 같은 서버실의 같은 이름 랙을 수정 대상으로 찾는다
+async fn find_existing_rack<C>(
+    conn: &C,
+    locations: &LocationIndex,
+    row: &RackRow,
+) -> ServiceResult<Option<racks::Model>>
+where
+    C: ConnectionTrait,
+{
+    if let Some(id) = row.id {
+        // 다른 테넌트의 랙은 인덱스에 없으므로 찾을 수 없다고 답한다
+        if locations.rack(&id).is_none() {
+            return Err(Errors::NotFound(format!("Rack {} not found", id)));
+        }
+        return racks::Entity::find_by_id(id)
+            .filter(racks::Column::IsActive.eq(true))
+            .one(conn)
+            .await
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?
+            .map(Some)
+            .ok_or_else(|| Errors::NotFound(format!("Rack {} not found", id)));
+    }
+
+    let (Some(server_room_id), Some(name)) = (row.server_room_id, row.name.as_deref()) else {
+        return Ok(None);
+    };
+    racks::Entity::find()
+        .filter(racks::Column::ServerRoomId.eq(server_room_id))
+        .filter(racks::Column::Name.eq(name))
+        .filter(racks::Column::IsActive.eq(true))
+        .one(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))
+}
+
+async fn apply_rack_row<C>(
+    conn: &C,
+    locations: &LocationIndex,
+    row: RackRow,
+    actor: Uuid,
+) -> ServiceResult<RowOutcome>
+where
+    C: ConnectionTrait,
+{
+    let existing = find_existing_rack(conn, locations, &row).await?;
+
+    let (action, rack) = match existing.as_ref() {
+        Some(before) => {
+            if let Some(height) = row.rack_height
+                && height < before.rack_height
+                && let Some((top, device_name)) = highest_occupied_unit(conn, &before.id).await?
+                && top > height
+            {
+                return Err(Errors::RackSlotConflict(format!(
+                    "Cannot shrink rack to {}U: device '{}' occupies up to U{}",
+                    height, device_name, top
+                )));
+            }
+
+            let mut model: racks::ActiveModel = before.clone().into();
+            if let Some(name) = row.name {
+                model.name = Set(name);
+            }
+            if let Some(server_room_id) = row.server_room_id {
+                model.server_room_id = Set(server_room_id);
+            }
+            if let Some(height) = row.rack_height {
+                model.rack_height = Set(height);
+            }
+            if let Some(power) = row.power_capacity {
+                model.power_capacity = Set(power);
+            }
+            if let Some(cooling) = row.cooling_type {
+                model.cooling_type = Set(cooling);
+            }
+            if let Some(x) = row.location_x {
+                model.location_x = Set(x.and_then(|x| Decimal::try_from(x).ok()));
+            }
+            if let Some(y) = row.location_y {
+                model.location_y = Set(y.and_then(|y| Decimal::try_from(y).ok()));
+            }
+            if let Some(description) = row.description {
+                model.description = Set(description);
+            }
+            model.updated_at = Set(Utc::now().into());
+
+            let rack = model
+                .update(conn)
+                .await
+                .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+            (ACTION_UPDATE, rack)
+        }
+        None => {
+            let name = row
+                .name
+                .ok_or_else(|| Errors::BadRequestError("name is required".to_string()))?;
+            let server_room_id = row.server_room_id.ok_or_else(|| {
+                Errors::BadRequestError("server_room is required for new racks".to_string())
+            })?;
+
+            let rack = repository_create_rack(
+                conn,
+                &server_room_id,
+                &name,
+                row.description.flatten().as_deref(),
+                row.rack_height.unwrap_or(42),
+                row.power_capacity.flatten(),
+                row.cooling_type.flatten().as_deref(),
+                row.location_x.flatten(),
+                row.location_y.flatten(),
+                &actor,
+            )
+            .await?;
+            (ACTION_CREATE, rack)
+        }
+    };
+
+    record_audit(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_RACK,
+            resource_id: rack.id,
+            resource_name: Some(&rack.name),
+            action,
+            actor_id: Some(actor),
+            before: existing.as_ref(),
+            after: Some(&rack),
+        },
+    )
+    .await?;
+
+    Ok(RowOutcome {
+        action,
+        id: rack.id,
+        name: rack.name,
+    })
+}
+
+/// 랙 CSV를 가져온다. `id` 또는 (서버실, 이름)이 기존 랙과 일치하면 수정, 아니면 생성한다.
+/// 서버실은 `server_room` 이름으로 찾고, 이름이 겹치면 `office` 열로 좁힌다.
+pub async fn service_import_racks(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    content: &str,
+    dry_run: bool,
+    imported_by: Uuid,
+) -> ServiceResult<BulkImportResponse> {
+    let sheet = parse_csv(content)?;
+    sheet.ensure_columns(&RACK_COLUMNS, &["name"])?;
+
+    let locations = LocationIndex::load(conn, tenant_id).await?;
+    let txn = begin_import(conn, &sheet).await?;
+    let mut report = ImportReport::new("rack", dry_run, sheet.records().len());
+
+    for record in sheet.records() {
+        let reader = sheet.reader(record);
+        let row = match parse_rack_row(&reader, &locations) {
+            Ok(row) => row,
+            Err(error) => {
+                report.failed(error);
+                continue;
+            }
+        };
+
+        let savepoint = txn.begin().await?;
+        let result = apply_rack_row(&savepoint, &locations, row, imported_by).await;
+        if let Some(outcome) = settle_row(savepoint, &mut report, record.line, result).await? {
+            report.succeeded(record.line, outcome);
+        }
+    }
+
+    Ok(report.finish(txn).await?.0)
+}
+
+/// 활성 랙을 가져오기와 같은 열 형식의 CSV로 내보낸다 (페이지 단위 스트리밍)
+pub async fn service_export_racks(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    filter: RackExportFilter,
+) -> ServiceResult<impl Stream<Item = CsvChunk> + Send + 'static + use<>> {
+    let locations = Arc::new(LocationIndex::load(conn, tenant_id).await?);
+    let filter = Arc::new(filter);
+    let conn = conn.clone();
+
+    Ok(csv_export_stream(&RACK_COLUMNS, move |page| {
+        let conn = conn.clone();
+        let locations = locations.clone();
+        let filter = filter.clone();
+        async move { export_rack_page(&conn, &locations, &filter, page).await }
+    }))
+}
+
+async fn export_rack_page(
+    conn: &DatabaseConnection,
+    locations: &LocationIndex,
+    filter: &RackExportFilter,
+    page: u64,
+) -> ServiceResult<Option<Vec<Vec<String>>>> {
+    let mut query = racks::Entity::find()
+        .filter(racks::Column::IsActive.eq(true))
+        .filter(racks::Column::ServerRoomId.is_in(locations.server_room_ids()));
+    if let Some(server_room_id) = filter.server_room_id {
+        query = query.filter(racks::Column::ServerRoomId.eq(server_room_id));
+    }
+    if let Some(office_id) = filter.office_id {
+        query = query.filter(
+            racks::Column::ServerRoomId.is_in(locations.server_room_ids_in_office(&office_id)),
+        );
+    }
+    let racks = query
+        .order_by_asc(racks::Column::Name)
+        .order_by_asc(racks::Column::Id)
+        .offset(page * EXPORT_PAGE_SIZE)
+        .limit(EXPORT_PAGE_SIZE)
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+    if racks.is_empty() {
+        return Ok(None);
+    }
+
+    let rows = racks
+        .into_iter()
+        .map(|rack| {
+            let (office, room) = locations.server_room_names(&rack.server_room_id);
+            vec![
+                rack.id.to_string(),
+                rack.name,
+                cell(office),
+                cell(room),
+                rack.rack_height.to_string(),
+                cell(rack.power_capacity),
+                cell(rack.cooling_type),
+                cell(rack.location_x),
+                cell(rack.location_y),
+                cell(rack.description),
+            ]
+        })
+        .collect();
+
+    Ok(Some(rows))
+}
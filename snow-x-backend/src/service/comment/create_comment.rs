@@ -4,10 +4,18 @@ use crate::repository::comment::create_comment::repository_create_comment;
 use crate::repository::comment::update_reply_count::repository_increment_reply_count;
 use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
 use crate::repository::post::update_comment_count::repository_increment_comment_count;
+use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::notification::CATEGORY_COMMENT_REPLY;
+use crate::service::notification::inbox::{UserNotificationParams, enqueue_user_notification};
+use crate::service::realtime::{EVENT_COMMENT_CREATED, post_topic, publish_realtime_event};
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use serde_json::json;
 use uuid::Uuid;
 
+/// 답글 알림에 담을 댓글 내용 길이
+const REPLY_PREVIEW_LEN: usize = 100;
+
 pub async fn service_create_comment<C>(
     conn: &C,
     user_id: &Uuid,
@@ -19,10 +27,10 @@ where
     let txn = conn.begin().await?;
 
     // 포스트 존재 확인
-    let _post = repository_get_post_by_uuid(&txn, &request.post_id).await?;
+    let post = repository_get_post_by_uuid(&txn, &request.post_id).await?;
 
     // 부모 댓글이 있다면 존재 확인
-    if let Some(parent_id) = request.parent_id {
+    let parent_comment = if let Some(parent_id) = request.parent_id {
         let parent_comment =
             crate::repository::comment::get_comment_by_id::repository_get_comment_by_id(
                 &txn, parent_id,
@@ -39,7 +47,11 @@ where
         if parent_comment.is_deleted {
             return Err(Errors::CannotReplyToDeletedComment);
         }
-    }
+
+        Some(parent_comment)
+    } else {
+        None
+    };
 
     // 댓글 생성
     let created_comment = repository_create_comment(
@@ -59,7 +71,49 @@ where
         repository_increment_reply_count(&txn, &parent_id).await?;
     }
 
+    // 부모 댓글 작성자에게 답글 알림 (자기 댓글에 단 답글이면 보내지 않음)
+    if let Some(parent_comment) = parent_comment {
+        let replier = repository_find_user_by_uuid(&txn, user_id)
+            .await?
+            .ok_or(Errors::UserNotFound)?;
+        let author = repository_find_user_by_uuid(&txn, &post.user_id)
+            .await?
+            .ok_or(Errors::UserNotFound)?;
+        enqueue_user_notification(
+            &txn,
+            UserNotificationParams {
+                category: CATEGORY_COMMENT_REPLY,
+                title: format!("{}님이 댓글에 답글을 남겼습니다", replier.name),
+                message: request.content.chars().take(REPLY_PREVIEW_LEN).collect(),
+                recipient_ids: vec![parent_comment.user_id],
+                actor_id: Some(*user_id),
+                link: Some(format!("/@{}/post/{}", author.handle, post.slug)),
+                data: json!({
+                    "post_id": post.id,
+                    "comment_id": created_comment.id,
+                    "parent_comment_id": parent_comment.id,
+                }),
+            },
+        )
+        .await?;
+    }
+
     txn.commit().await?;
+
+    publish_realtime_event(
+        &post_topic(post.id),
+        EVENT_COMMENT_CREATED,
+        json!({
+            "post_id": post.id,
+            "comment_id": created_comment.id,
+            "parent_id": created_comment.parent_id,
+            "user_id": created_comment.user_id,
+            "content": created_comment.content,
+            "created_at": created_comment.created_at,
+        }),
+    )
+    .await;
+
     Ok(CreateCommentResponse {
         comment_id: created_comment.id,
     })
@@ -4,7 +4,9 @@ use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
 use crate::repository::comment::update_reply_count::repository_decrement_reply_count;
 use crate::repository::post::update_comment_count::repository_decrement_comment_count;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::realtime::{EVENT_COMMENT_DELETED, post_topic, publish_realtime_event};
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use serde_json::json;
 use uuid::Uuid;
 
 pub async fn service_delete_comment<C>(
@@ -44,5 +46,16 @@ where
     }
 
     txn.commit().await?;
+
+    publish_realtime_event(
+        &post_topic(comment.post_id),
+        EVENT_COMMENT_DELETED,
+        json!({
+            "post_id": comment.post_id,
+            "comment_id": comment.id,
+            "parent_id": comment.parent_id,
+        }),
+    )
+    .await;
     Ok(())
 }
@@ -2,7 +2,9 @@ use crate::dto::comment::request::UpdateCommentRequest;
 use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
 use crate::repository::comment::update_comment::repository_update_comment;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::realtime::{EVENT_COMMENT_UPDATED, post_topic, publish_realtime_event};
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use serde_json::json;
 use uuid::Uuid;
 
 pub async fn service_update_comment<C>(
@@ -34,5 +36,16 @@ where
     repository_update_comment(&txn, request.comment_id, &request.content).await?;
 
     txn.commit().await?;
+
+    publish_realtime_event(
+        &post_topic(comment.post_id),
+        EVENT_COMMENT_UPDATED,
+        json!({
+            "post_id": comment.post_id,
+            "comment_id": comment.id,
+            "content": request.content,
+        }),
+    )
+    .await;
     Ok(())
 }
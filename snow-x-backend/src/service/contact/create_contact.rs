@@ -1,17 +1,20 @@
 use crate::dto::contact::request::CreateContactRequest;
 use crate::dto::contact::response::ContactInfoResponse;
 use crate::entity::contacts;
+use crate::service::audit::{ACTION_CREATE, AuditEntry, RESOURCE_CONTACT, record_audit_or_warn};
 use crate::service::error::errors::ServiceResult;
 use sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection};
 use uuid::Uuid;
 
 pub async fn service_create_contact(
     conn: &DatabaseConnection,
+    tenant_id: Uuid,
     request: CreateContactRequest,
     created_by: Uuid,
 ) -> ServiceResult<ContactInfoResponse> {
     let contact = contacts::ActiveModel {
         id: ActiveValue::Set(Uuid::new_v4()),
+        tenant_id: ActiveValue::Set(Some(tenant_id)),
         name: ActiveValue::Set(request.name),
         title: ActiveValue::Set(request.title),
         department: ActiveValue::Set(request.department),
@@ -30,6 +33,20 @@ pub async fn service_create_contact(
     .insert(conn)
     .await?;
 
+    record_audit_or_warn(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_CONTACT,
+            resource_id: contact.id,
+            resource_name: Some(&contact.name),
+            action: ACTION_CREATE,
+            actor_id: Some(created_by),
+            before: None,
+            after: Some(&contact),
+        },
+    )
+    .await;
+
     Ok(ContactInfoResponse {
         id: contact.id,
         name: contact.name,
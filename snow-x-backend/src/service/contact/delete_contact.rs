@@ -1,4 +1,5 @@
 use crate::entity::contacts;
+use crate::service::audit::{ACTION_DELETE, AuditEntry, RESOURCE_CONTACT, record_audit_or_warn};
 use crate::service::error::errors::ServiceResult;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel,
@@ -6,16 +7,35 @@ use sea_orm::{
 };
 use uuid::Uuid;
 
-pub async fn service_delete_contact(conn: &DatabaseConnection, id: Uuid) -> ServiceResult<()> {
+pub async fn service_delete_contact(
+    conn: &DatabaseConnection,
+    id: Uuid,
+    deleted_by: Uuid,
+) -> ServiceResult<()> {
     let contact = contacts::Entity::find_by_id(id)
         .filter(contacts::Column::IsActive.eq(true))
         .one(conn)
         .await?
         .ok_or_else(|| sea_orm::DbErr::RecordNotFound("Contact not found".to_string()))?;
 
+    let before = contact.clone();
     let mut active_model = contact.into_active_model();
     active_model.is_active = ActiveValue::Set(false);
     active_model.update(conn).await?;
 
+    record_audit_or_warn(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_CONTACT,
+            resource_id: before.id,
+            resource_name: Some(&before.name),
+            action: ACTION_DELETE,
+            actor_id: Some(deleted_by),
+            before: Some(&before),
+            after: None,
+        },
+    )
+    .await;
+
     Ok(())
 }
@@ -4,16 +4,18 @@ use crate::service::error::errors::ServiceResult;
 use sea_orm::{
     ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
 };
+use uuid::Uuid;
 
 pub async fn service_get_contacts(
     conn: &DatabaseConnection,
+    tenant_id: Uuid,
     page: u64,
     limit: u64,
     search: Option<String>,
     department: Option<String>,
     is_active: Option<bool>,
 ) -> ServiceResult<ContactListResponse> {
-    let mut query = contacts::Entity::find();
+    let mut query = contacts::Entity::find().filter(contacts::Column::TenantId.eq(tenant_id));
 
     // Apply is_active filter (defaults to true if not specified)
     let active_filter = is_active.unwrap_or(true);
@@ -1,9 +1,12 @@
 use crate::dto::contact::request::UpdateContactRequest;
 use crate::dto::contact::response::ContactInfoResponse;
 use crate::entity::contacts;
+use crate::service::audit::{ACTION_UPDATE, AuditEntry, RESOURCE_CONTACT, record_audit};
 use crate::service::error::errors::ServiceResult;
+use crate::service::external_api::conflict::{ManualEdit, guard_manual_edit};
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    TransactionTrait,
 };
 use uuid::Uuid;
 
@@ -11,13 +14,17 @@ pub async fn service_update_contact(
     conn: &DatabaseConnection,
     id: Uuid,
     request: UpdateContactRequest,
+    updated_by: Uuid,
 ) -> ServiceResult<ContactInfoResponse> {
+    let txn = conn.begin().await?;
+
     let contact = contacts::Entity::find_by_id(id)
         .filter(contacts::Column::IsActive.eq(true))
-        .one(conn)
+        .one(&txn)
         .await?
         .ok_or_else(|| sea_orm::DbErr::RecordNotFound("Contact not found".to_string()))?;
 
+    let before = contact.clone();
     let mut active_model: contacts::ActiveModel = contact.into();
 
     if let Some(name) = request.name {
@@ -48,7 +55,38 @@ pub async fn service_update_contact(
         active_model.is_active = ActiveValue::Set(is_active);
     }
 
-    let updated_contact = active_model.update(conn).await?;
+    let updated_contact = active_model.update(&txn).await?;
+
+    // 동기화된 담당자면 소유 규칙을 확인하고 수동 수정으로 표시한다
+    guard_manual_edit(
+        &txn,
+        ManualEdit {
+            resource_type: RESOURCE_CONTACT,
+            resource_id: updated_contact.id,
+            source_type: &before.source_type,
+            connection_id: before.external_api_connection_id,
+            actor_id: updated_by,
+            before: &before,
+            after: &updated_contact,
+        },
+    )
+    .await?;
+
+    record_audit(
+        &txn,
+        AuditEntry {
+            resource_type: RESOURCE_CONTACT,
+            resource_id: updated_contact.id,
+            resource_name: Some(&updated_contact.name),
+            action: ACTION_UPDATE,
+            actor_id: Some(updated_by),
+            before: Some(&before),
+            after: Some(&updated_contact),
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
 
     Ok(ContactInfoResponse {
         id: updated_contact.id,
@@ -0,0 +1,247 @@
+//! Custodian 실행 상태 동기화.
+//!
+//! 진행 중(pending/running)인 실행의 태스크 상태를 태스크 서버에서 주기적으로 읽어
+//! `custodian_executions`에 반영하고, `CUSTODIAN_EXECUTION_TIMEOUT`을 넘긴 실행은 실패로 끝낸 뒤
+//! 워커에서 계속 돌지 않도록 태스크를 취소한다.
+//! 갱신은 실행이 아직 진행 중일 때만 적용하므로 여러 인스턴스가 같이 돌거나
+//! 그 사이에 취소되어도 결과가 뒤바뀌지 않는다.
+
+use crate::config::db_config::DbConfig;
+use crate::entity::custodian_executions;
+use crate::microservices::custodian_client::{
+    TaskStatusResponse, get_task_status, revoke_custodian_task,
+};
+use crate::service::custodian_service::{
+    ACTIVE_EXECUTION_STATUSES, EXECUTION_STATUS_CANCELLED, EXECUTION_STATUS_COMPLETED,
+    EXECUTION_STATUS_FAILED, EXECUTION_STATUS_RUNNING,
+};
+use crate::service::error::errors::ServiceResult;
+use chrono::{Duration as ChronoDuration, Utc};
+use reqwest::Client;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use serde_json::Value;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, error, info, warn};
+
+/// 한 주기에 상태를 확인하는 최대 실행 수
+const RECONCILE_BATCH: u64 = 50;
+
+/// 태스크 상태를 반영한 결과
+struct ExecutionUpdate {
+    status: &'static str,
+    output: Option<String>,
+    error: Option<String>,
+}
+
+fn result_output(result: &Value) -> Option<String> {
+    match result.get("output") {
+        Some(Value::String(output)) => Some(output.clone()),
+        Some(Value::Null) | None => Some(result.to_string()),
+        Some(output) => Some(output.to_string()),
+    }
+}
+
+/// Celery 태스크 상태를 실행 상태로 옮긴다. 아직 큐에 있으면 `None`.
+///
+/// 태스크가 성공(SUCCESS)해도 custodian 실행 결과(`result.status`, `result.return_code`)가
+/// 실패이면 실행은 실패로 본다.
+fn map_task_status(task: &TaskStatusResponse) -> Option<ExecutionUpdate> {
+    match task.status.as_str() {
+        "STARTED" | "RETRY" | "PROGRESS" | "RECEIVED" => Some(ExecutionUpdate {
+            status: EXECUTION_STATUS_RUNNING,
+            output: None,
+            error: None,
+        }),
+        "SUCCESS" => {
+            let result = task.result.clone().unwrap_or(Value::Null);
+            let return_code = result.get("return_code").and_then(Value::as_i64);
+            let failed = result.get("status").and_then(Value::as_str) == Some("failed")
+                || return_code.is_some_and(|code| code != 0);
+            let error = failed.then(|| {
+                result
+                    .get("error")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| {
+                        format!(
+                            "Policy run exited with code {}",
+                            return_code.unwrap_or_default()
+                        )
+                    })
+            });
+            Some(ExecutionUpdate {
+                status: if failed {
+                    EXECUTION_STATUS_FAILED
+                } else {
+                    EXECUTION_STATUS_COMPLETED
+                },
+                output: result_output(&result),
+                error,
+            })
+        }
+        "FAILURE" => Some(ExecutionUpdate {
+            status: EXECUTION_STATUS_FAILED,
+            output: None,
+            error: Some(
+                task.error
+                    .clone()
+                    .or_else(|| task.result.as_ref().map(Value::to_string))
+                    .unwrap_or_else(|| "Task failed".to_string()),
+            ),
+        }),
+        "REVOKED" => Some(ExecutionUpdate {
+            status: EXECUTION_STATUS_CANCELLED,
+            output: None,
+            error: Some("Task was revoked".to_string()),
+        }),
+        _ => None,
+    }
+}
+
+/// 실행이 아직 진행 중일 때만 상태를 바꾼다
+async fn apply_update(
+    conn: &DatabaseConnection,
+    execution_id: uuid::Uuid,
+    update: ExecutionUpdate,
+) -> ServiceResult<bool> {
+    let is_final = !ACTIVE_EXECUTION_STATUSES.contains(&update.status);
+
+    let mut query = custodian_executions::Entity::update_many()
+        .col_expr(
+            custodian_executions::Column::Status,
+            Expr::value(update.status),
+        )
+        .filter(custodian_executions::Column::Id.eq(execution_id))
+        .filter(custodian_executions::Column::Status.is_in(ACTIVE_EXECUTION_STATUSES));
+    if let Some(output) = update.output {
+        query = query.col_expr(custodian_executions::Column::Output, Expr::value(output));
+    }
+    if let Some(error) = update.error {
+        query = query.col_expr(custodian_executions::Column::Error, Expr::value(error));
+    }
+    if is_final {
+        query = query.col_expr(
+            custodian_executions::Column::CompletedAt,
+            Expr::value(Utc::now()),
+        );
+    }
+
+    Ok(query.exec(conn).await?.rows_affected > 0)
+}
+
+/// 제한 시간을 넘긴 실행을 실패로 끝내고 태스크를 취소한다. 끝낸 실행 수를 돌려준다.
+/// 취소 요청이 실패해도 실행은 실패로 남긴다.
+async fn time_out_stuck_executions(
+    conn: &DatabaseConnection,
+    http_client: &Client,
+    timeout: u64,
+) -> ServiceResult<usize> {
+    let now = Utc::now();
+    let deadline = now - ChronoDuration::seconds(timeout as i64);
+
+    let timed_out = custodian_executions::Entity::update_many()
+        .col_expr(
+            custodian_executions::Column::Status,
+            Expr::value(EXECUTION_STATUS_FAILED),
+        )
+        .col_expr(
+            custodian_executions::Column::Error,
+            Expr::value(format!("Execution timed out after {} seconds", timeout)),
+        )
+        .col_expr(custodian_executions::Column::CompletedAt, Expr::value(now))
+        .filter(custodian_executions::Column::Status.is_in(ACTIVE_EXECUTION_STATUSES))
+        .filter(custodian_executions::Column::StartedAt.lt(deadline))
+        .exec_with_returning(conn)
+        .await?;
+
+    for execution in &timed_out {
+        if let Some(task_id) = execution.task_id.as_deref()
+            && let Err(e) = revoke_custodian_task(http_client, task_id).await
+        {
+            warn!(
+                "Failed to revoke task {} for timed out execution {}: {}",
+                task_id, execution.id, e
+            );
+        }
+    }
+
+    Ok(timed_out.len())
+}
+
+async fn reconcile_executions(
+    conn: &DatabaseConnection,
+    http_client: &Client,
+    timeout: u64,
+) -> ServiceResult<()> {
+    let timed_out = time_out_stuck_executions(conn, http_client, timeout).await?;
+    if timed_out > 0 {
+        warn!("Timed out {} stuck custodian execution(s)", timed_out);
+    }
+
+    let executions = custodian_executions::Entity::find()
+        .filter(custodian_executions::Column::Status.is_in(ACTIVE_EXECUTION_STATUSES))
+        .filter(custodian_executions::Column::TaskId.is_not_null())
+        .order_by_asc(custodian_executions::Column::StartedAt)
+        .limit(RECONCILE_BATCH)
+        .all(conn)
+        .await?;
+
+    for execution in executions {
+        let Some(task_id) = execution.task_id.as_deref() else {
+            continue;
+        };
+        let task = match get_task_status(http_client, task_id).await {
+            Ok(task) => task,
+            Err(e) => {
+                debug!(
+                    "Failed to read task {} of custodian execution {}: {}",
+                    task_id, execution.id, e
+                );
+                continue;
+            }
+        };
+
+        let Some(update) = map_task_status(&task) else {
+            continue;
+        };
+        if update.status == execution.status {
+            continue;
+        }
+
+        let status = update.status;
+        if apply_update(conn, execution.id, update).await? {
+            info!(
+                "Custodian execution {} moved from {} to {}",
+                execution.id, execution.status, status
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// 실행 상태 동기화 백그라운드 태스크를 시작한다.
+/// `CUSTODIAN_RECONCILE_INTERVAL=0`이면 시작하지 않는다.
+pub fn spawn_custodian_reconciler(conn: DatabaseConnection, http_client: Client) {
+    let config = DbConfig::get();
+    if config.custodian_reconcile_interval == 0 {
+        info!("Custodian execution reconciler is disabled");
+        return;
+    }
+
+    let interval = Duration::from_secs(config.custodian_reconcile_interval);
+    let timeout = config.custodian_execution_timeout;
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if let Err(e) = reconcile_executions(&conn, &http_client, timeout).await {
+                error!("Failed to reconcile custodian executions: {:?}", e);
+            }
+        }
+    });
+}
@@ -0,0 +1,142 @@
+//! Custodian 정책 예약 실행.
+//!
+//! 예약 시각(`next_run_at`)이 지난 정책을 `FOR UPDATE SKIP LOCKED`로 가져와 다음 예약 시각을
+//! 먼저 넘긴 뒤 실행을 만든다. 여러 인스턴스가 같은 정책을 한 번씩만 실행하며,
+//! 서버가 멈춰 있던 동안 놓친 예약은 한 번만 실행한다.
+
+use crate::config::db_config::DbConfig;
+use crate::entity::custodian_policies;
+use crate::service::custodian_service::{
+    TRIGGER_SCHEDULE, create_execution, dispatch_execution, next_run_after,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use chrono::Utc;
+use reqwest::Client;
+use sea_orm::sea_query::{LockBehavior, LockType};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait,
+};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+
+/// 한 주기에 실행하는 최대 정책 수
+const DUE_BATCH: u64 = 20;
+
+/// 예약 시각이 지난 정책을 잡아 다음 예약 시각으로 넘긴다. 잡은 정책을 돌려준다.
+async fn claim_due_policies(
+    conn: &DatabaseConnection,
+) -> ServiceResult<Vec<custodian_policies::Model>> {
+    use custodian_policies::Column;
+
+    let now = Utc::now();
+    let txn = conn.begin().await?;
+
+    let due = custodian_policies::Entity::find()
+        .filter(Column::ScheduleEnabled.eq(true))
+        .filter(Column::ScheduleCron.is_not_null())
+        .filter(Column::NextRunAt.lte(now))
+        .order_by_asc(Column::NextRunAt)
+        .limit(DUE_BATCH)
+        .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+        .all(&txn)
+        .await?;
+
+    let mut claimed = Vec::with_capacity(due.len());
+    for policy in due {
+        let cron = policy.schedule_cron.clone().unwrap_or_default();
+        let mut active_model: custodian_policies::ActiveModel = policy.clone().into();
+        match next_run_after(&cron, &policy.schedule_timezone, now) {
+            Ok(next_run_at) => {
+                active_model.next_run_at = Set(Some(next_run_at.into()));
+            }
+            // 저장된 뒤 해석할 수 없게 된 예약(시간대 데이터 변경 등)은 끈다
+            Err(e) => {
+                warn!(
+                    "Disabling schedule of custodian policy {}: {:?}",
+                    policy.id, e
+                );
+                active_model.schedule_enabled = Set(false);
+                active_model.next_run_at = Set(None);
+            }
+        }
+        active_model.update(&txn).await?;
+        claimed.push(policy);
+    }
+
+    txn.commit().await?;
+    Ok(claimed)
+}
+
+async fn run_scheduled_policy(
+    conn: &DatabaseConnection,
+    http_client: &Client,
+    policy: &custodian_policies::Model,
+) {
+    let (policy, execution) = match create_execution(
+        conn,
+        policy.id,
+        policy.schedule_dry_run,
+        TRIGGER_SCHEDULE,
+    )
+    .await
+    {
+        Ok(created) => created,
+        Err(Errors::CustodianExecutionInProgress(_)) => {
+            info!(
+                "Skipping scheduled run of custodian policy {}: previous execution is still running",
+                policy.id
+            );
+            return;
+        }
+        Err(e) => {
+            error!(
+                "Failed to start scheduled run of custodian policy {}: {:?}",
+                policy.id, e
+            );
+            return;
+        }
+    };
+
+    match dispatch_execution(conn, http_client, &policy, execution).await {
+        Ok(execution) => info!(
+            "Scheduled run of custodian policy {} created execution {} ({})",
+            policy.id, execution.id, execution.status
+        ),
+        Err(e) => error!(
+            "Failed to dispatch scheduled run of custodian policy {}: {}",
+            policy.id, e
+        ),
+    }
+}
+
+/// Custodian 예약 실행 백그라운드 태스크를 시작한다.
+/// `CUSTODIAN_SCHEDULER_POLL_INTERVAL=0`이면 시작하지 않는다.
+pub fn spawn_custodian_scheduler(conn: DatabaseConnection, http_client: Client) {
+    let poll_interval = DbConfig::get().custodian_scheduler_poll_interval;
+    if poll_interval == 0 {
+        info!("Custodian policy scheduler is disabled");
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(poll_interval));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            let policies = match claim_due_policies(&conn).await {
+                Ok(policies) => policies,
+                Err(e) => {
+                    error!("Failed to claim due custodian policies: {:?}", e);
+                    continue;
+                }
+            };
+            for policy in &policies {
+                run_scheduled_policy(&conn, &http_client, policy).await;
+            }
+        }
+    });
+}
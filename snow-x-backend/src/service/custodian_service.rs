@@ -1,8 +1,29 @@
 use crate::entity::{custodian_executions, custodian_policies};
-use chrono::Utc;
+use crate::microservices::custodian_client::{queue_custodian_execution, revoke_custodian_task};
+use crate::service::custodian_validator::validate_policy_yaml;
+use crate::service::error::errors::{Errors, ServiceResult};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use croner::Cron;
+use reqwest::Client;
+use sea_orm::sea_query::Expr;
 use sea_orm::*;
+use tracing::warn;
 use uuid::Uuid;
 
+pub const EXECUTION_STATUS_PENDING: &str = "pending";
+pub const EXECUTION_STATUS_RUNNING: &str = "running";
+pub const EXECUTION_STATUS_COMPLETED: &str = "completed";
+pub const EXECUTION_STATUS_FAILED: &str = "failed";
+pub const EXECUTION_STATUS_CANCELLED: &str = "cancelled";
+
+/// Executions that still occupy the policy; a new run is refused while one exists
+pub const ACTIVE_EXECUTION_STATUSES: [&str; 2] =
+    [EXECUTION_STATUS_PENDING, EXECUTION_STATUS_RUNNING];
+
+pub const TRIGGER_MANUAL: &str = "manual";
+pub const TRIGGER_SCHEDULE: &str = "schedule";
+
 #[derive(Debug, Clone)]
 pub struct CreatePolicyRequest {
     pub name: String,
@@ -23,11 +44,21 @@ pub struct ExecutePolicyRequest {
     pub dry_run: bool,
 }
 
-/// Get all custodian policies
+#[derive(Debug, Clone)]
+pub struct PolicyScheduleRequest {
+    pub cron: String,
+    pub timezone: String,
+    pub dry_run: bool,
+    pub enabled: bool,
+}
+
+/// Get all custodian policies of a tenant
 pub async fn get_all_policies(
     db: &DatabaseConnection,
+    tenant_id: Uuid,
 ) -> Result<Vec<custodian_policies::Model>, DbErr> {
     custodian_policies::Entity::find()
+        .filter(custodian_policies::Column::TenantId.eq(tenant_id))
         .order_by_desc(custodian_policies::Column::CreatedAt)
         .all(db)
         .await
@@ -41,22 +72,46 @@ pub async fn get_policy_by_id(
     custodian_policies::Entity::find_by_id(id).one(db).await
 }
 
+/// Reject policy content that fails schema validation
+fn ensure_valid_policy_content(content: &str) -> ServiceResult<()> {
+    let validation = validate_policy_yaml(content);
+    if validation.is_valid() {
+        return Ok(());
+    }
+
+    let messages: Vec<String> = validation.errors.iter().map(ToString::to_string).collect();
+    Err(Errors::ValidationError(format!(
+        "Invalid custodian policy: {}",
+        messages.join("; ")
+    )))
+}
+
 /// Create a new custodian policy
 pub async fn create_policy(
     db: &DatabaseConnection,
+    tenant_id: Uuid,
     request: CreatePolicyRequest,
-) -> Result<custodian_policies::Model, DbErr> {
+) -> ServiceResult<custodian_policies::Model> {
+    ensure_valid_policy_content(&request.content)?;
+
     let now = Utc::now();
     let policy = custodian_policies::ActiveModel {
         id: Set(Uuid::new_v4()),
+        tenant_id: Set(Some(tenant_id)),
         name: Set(request.name),
         description: Set(request.description),
         content: Set(request.content),
         created_at: Set(now.into()),
         updated_at: Set(now.into()),
+        schedule_cron: Set(None),
+        schedule_timezone: Set("UTC".to_string()),
+        schedule_dry_run: Set(false),
+        schedule_enabled: Set(false),
+        last_run_at: Set(None),
+        next_run_at: Set(None),
     };
 
-    policy.insert(db).await
+    Ok(policy.insert(db).await?)
 }
 
 /// Update an existing custodian policy
@@ -64,14 +119,15 @@ pub async fn update_policy(
     db: &DatabaseConnection,
     id: Uuid,
     request: UpdatePolicyRequest,
-) -> Result<custodian_policies::Model, DbErr> {
+) -> ServiceResult<custodian_policies::Model> {
+    if let Some(content) = request.content.as_deref() {
+        ensure_valid_policy_content(content)?;
+    }
+
     let policy = custodian_policies::Entity::find_by_id(id)
         .one(db)
         .await?
-        .ok_or(DbErr::RecordNotFound(format!(
-            "Policy with id {} not found",
-            id
-        )))?;
+        .ok_or_else(|| Errors::NotFound(format!("Policy with id {} not found", id)))?;
 
     let mut active_model: custodian_policies::ActiveModel = policy.into();
 
@@ -86,7 +142,7 @@ pub async fn update_policy(
     }
     active_model.updated_at = Set(Utc::now().into());
 
-    active_model.update(db).await
+    Ok(active_model.update(db).await?)
 }
 
 /// Delete a custodian policy
@@ -105,25 +161,209 @@ pub async fn delete_policy(db: &DatabaseConnection, id: Uuid) -> Result<(), DbEr
     Ok(())
 }
 
-/// Create an execution record
+/// Parse a standard 5-field cron expression (or a nickname such as `@daily`) and a
+/// IANA timezone name
+fn parse_schedule(cron: &str, timezone: &str) -> ServiceResult<(Cron, Tz)> {
+    let cron = Cron::new(cron.trim())
+        .parse()
+        .map_err(|e| Errors::ValidationError(format!("Invalid cron expression: {}", e)))?;
+    let timezone = timezone
+        .trim()
+        .parse::<Tz>()
+        .map_err(|_| Errors::ValidationError(format!("Unknown timezone '{}'", timezone)))?;
+    Ok((cron, timezone))
+}
+
+/// Next time the schedule fires strictly after `after`, evaluated in the schedule's timezone
+pub fn next_run_after(
+    cron: &str,
+    timezone: &str,
+    after: DateTime<Utc>,
+) -> ServiceResult<DateTime<Utc>> {
+    let (cron, timezone) = parse_schedule(cron, timezone)?;
+    cron.find_next_occurrence(&after.with_timezone(&timezone), false)
+        .map(|next| next.with_timezone(&Utc))
+        .map_err(|e| Errors::ValidationError(format!("Cron expression never fires: {}", e)))
+}
+
+/// Set (or replace) the schedule of a policy and compute its next run
+pub async fn set_policy_schedule(
+    db: &DatabaseConnection,
+    id: Uuid,
+    request: PolicyScheduleRequest,
+) -> ServiceResult<custodian_policies::Model> {
+    let next_run_at = next_run_after(&request.cron, &request.timezone, Utc::now())?;
+
+    let policy = custodian_policies::Entity::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| Errors::NotFound(format!("Policy with id {} not found", id)))?;
+
+    let mut active_model: custodian_policies::ActiveModel = policy.into();
+    active_model.schedule_cron = Set(Some(request.cron.trim().to_string()));
+    active_model.schedule_timezone = Set(request.timezone.trim().to_string());
+    active_model.schedule_dry_run = Set(request.dry_run);
+    active_model.schedule_enabled = Set(request.enabled);
+    active_model.next_run_at = Set(request.enabled.then(|| next_run_at.into()));
+    active_model.updated_at = Set(Utc::now().into());
+
+    Ok(active_model.update(db).await?)
+}
+
+/// Remove the schedule of a policy. Manual execution keeps working.
+pub async fn clear_policy_schedule(
+    db: &DatabaseConnection,
+    id: Uuid,
+) -> ServiceResult<custodian_policies::Model> {
+    let policy = custodian_policies::Entity::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| Errors::NotFound(format!("Policy with id {} not found", id)))?;
+
+    let mut active_model: custodian_policies::ActiveModel = policy.into();
+    active_model.schedule_cron = Set(None);
+    active_model.schedule_enabled = Set(false);
+    active_model.next_run_at = Set(None);
+    active_model.updated_at = Set(Utc::now().into());
+
+    Ok(active_model.update(db).await?)
+}
+
+/// Create an execution record, refusing to start while another execution of the same
+/// policy is still pending or running.
+///
+/// The policy row is locked for the check so manual and scheduled runs (on any
+/// instance) cannot both slip through.
 pub async fn create_execution(
     db: &DatabaseConnection,
     policy_id: Uuid,
     dry_run: bool,
-) -> Result<custodian_executions::Model, DbErr> {
+    trigger: &str,
+) -> ServiceResult<(custodian_policies::Model, custodian_executions::Model)> {
+    let txn = db.begin().await?;
+
+    let policy = custodian_policies::Entity::find_by_id(policy_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| Errors::NotFound(format!("Policy with id {} not found", policy_id)))?;
+
+    let active = custodian_executions::Entity::find()
+        .filter(custodian_executions::Column::PolicyId.eq(policy_id))
+        .filter(custodian_executions::Column::Status.is_in(ACTIVE_EXECUTION_STATUSES))
+        .count(&txn)
+        .await?;
+    if active > 0 {
+        return Err(Errors::CustodianExecutionInProgress(policy_id));
+    }
+
+    let now = Utc::now();
     let execution = custodian_executions::ActiveModel {
         id: Set(Uuid::new_v4()),
         policy_id: Set(policy_id),
-        status: Set("pending".to_string()),
+        status: Set(EXECUTION_STATUS_PENDING.to_string()),
         dry_run: Set(dry_run),
         task_id: Set(None),
         output: Set(None),
         error: Set(None),
-        started_at: Set(Utc::now().into()),
+        started_at: Set(now.into()),
         completed_at: Set(None),
-    };
+        trigger: Set(trigger.to_string()),
+    }
+    .insert(&txn)
+    .await?;
+
+    let mut active_model: custodian_policies::ActiveModel = policy.into();
+    active_model.last_run_at = Set(Some(now.into()));
+    let policy = active_model.update(&txn).await?;
+
+    txn.commit().await?;
+    Ok((policy, execution))
+}
+
+/// Hand an execution to the task server (Celery).
+///
+/// If the task server cannot be reached the execution is marked failed so it does not
+/// block later runs of the policy.
+pub async fn dispatch_execution(
+    db: &DatabaseConnection,
+    http_client: &Client,
+    policy: &custodian_policies::Model,
+    execution: custodian_executions::Model,
+) -> Result<custodian_executions::Model, DbErr> {
+    let queued = queue_custodian_execution(
+        http_client,
+        &policy.id.to_string(),
+        &policy.content,
+        &execution.id.to_string(),
+        execution.dry_run,
+    )
+    .await;
+
+    match queued {
+        Ok(Some(task_id)) => update_execution_task_id(db, execution.id, task_id).await,
+        Ok(None) => Ok(execution),
+        Err(e) => {
+            warn!(
+                "Custodian execution {} of policy {} was not dispatched: {}",
+                execution.id, policy.id, e
+            );
+            update_execution_status(
+                db,
+                execution.id,
+                EXECUTION_STATUS_FAILED.to_string(),
+                None,
+                Some(e.to_string()),
+            )
+            .await
+        }
+    }
+}
+
+/// Cancel a pending or running execution and revoke its task on the task server.
+///
+/// The execution is marked cancelled first, so if the revoke request fails the task may
+/// still finish on a worker; the reconciler ignores its result and the policy is free to
+/// run again right away.
+pub async fn cancel_execution(
+    db: &DatabaseConnection,
+    http_client: &Client,
+    execution_id: Uuid,
+) -> ServiceResult<custodian_executions::Model> {
+    let result = custodian_executions::Entity::update_many()
+        .col_expr(
+            custodian_executions::Column::Status,
+            Expr::value(EXECUTION_STATUS_CANCELLED),
+        )
+        .col_expr(
+            custodian_executions::Column::CompletedAt,
+            Expr::value(Utc::now()),
+        )
+        .filter(custodian_executions::Column::Id.eq(execution_id))
+        .filter(custodian_executions::Column::Status.is_in(ACTIVE_EXECUTION_STATUSES))
+        .exec(db)
+        .await?;
+
+    let execution = get_execution_by_id(db, execution_id)
+        .await?
+        .ok_or_else(|| Errors::NotFound(format!("Execution with id {} not found", execution_id)))?;
+    if result.rows_affected == 0 {
+        return Err(Errors::BadRequestError(format!(
+            "Execution already finished with status '{}'",
+            execution.status
+        )));
+    }
+
+    if let Some(task_id) = execution.task_id.as_deref()
+        && let Err(e) = revoke_custodian_task(http_client, task_id).await
+    {
+        warn!(
+            "Failed to revoke task {} for cancelled execution {}: {}",
+            task_id, execution_id, e
+        );
+    }
 
-    execution.insert(db).await
+    Ok(execution)
 }
 
 /// Update execution with task_id
@@ -162,7 +402,7 @@ pub async fn update_execution_status(
         )))?;
 
     let mut active_model: custodian_executions::ActiveModel = execution.into();
-    let is_final_status = status == "completed" || status == "failed";
+    let is_final_status = status == EXECUTION_STATUS_COMPLETED || status == EXECUTION_STATUS_FAILED;
     active_model.status = Set(status);
     if output.is_some() {
         active_model.output = Set(output);
@@ -196,3 +436,53 @@ pub async fn get_executions_for_policy(
         .all(db)
         .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn evaluates_the_schedule_in_its_timezone() {
+        // 09:00 KST = 00:00 UTC
+        let next = next_run_after("0 9 * * *", "Asia/Seoul", utc("2026-01-01T01:00:00Z")).unwrap();
+        assert_eq!(next, utc("2026-01-02T00:00:00Z"));
+    }
+
+    #[test]
+    fn follows_daylight_saving_transitions() {
+        // US Eastern switches from EST (-5) to EDT (-4) on 2026-03-08
+        let before =
+            next_run_after("0 9 * * *", "America/New_York", utc("2026-03-06T15:00:00Z")).unwrap();
+        assert_eq!(before, utc("2026-03-07T14:00:00Z"));
+
+        let after = next_run_after("0 9 * * *", "America/New_York", before).unwrap();
+        assert_eq!(after, utc("2026-03-08T13:00:00Z"));
+    }
+
+    #[test]
+    fn skips_a_fire_time_equal_to_after() {
+        let next = next_run_after("*/15 * * * *", "UTC", utc("2026-01-01T00:15:00Z")).unwrap();
+        assert_eq!(next, utc("2026-01-01T00:30:00Z"));
+    }
+
+    #[test]
+    fn rejects_invalid_expression_or_timezone() {
+        let now = utc("2026-01-01T00:00:00Z");
+        assert!(matches!(
+            next_run_after("61 * * * *", "UTC", now),
+            Err(Errors::ValidationError(_))
+        ));
+        assert!(matches!(
+            next_run_after("not a cron", "UTC", now),
+            Err(Errors::ValidationError(_))
+        ));
+        assert!(matches!(
+            next_run_after("0 9 * * *", "Mars/Olympus_Mons", now),
+            Err(Errors::ValidationError(_))
+        ));
+    }
+}
@@ -0,0 +1,973 @@
+//! Cloud Custodian 정책 YAML 구조 검증.
+//!
+//! `serde_yaml::Value`는 위치를 잃으므로 yaml-rust2 이벤트로 위치가 달린 트리를 만든 뒤
+//! `policies` 목록, 정책별 `name`/`resource`, `filters`/`actions`/`mode` 모양을 확인한다.
+//! anchor/alias와 `<<` 병합 키는 트리를 만들 때 풀어, 실제로 읽힐 모양을 검사한다.
+//! 리소스 종류 목록은 자주 쓰는 것만 담고 있어, 알려진 provider의 모르는 종류는 경고로 둔다.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+
+/// 정책 최상위에 올 수 있는 키
+const POLICY_KEYS: &[&str] = &[
+    "name",
+    "resource",
+    "description",
+    "comment",
+    "comments",
+    "filters",
+    "actions",
+    "mode",
+    "tags",
+    "metadata",
+    "conditions",
+    "query",
+    "source",
+    "max-resources",
+    "max-resources-percent",
+];
+
+/// 문서 최상위에 올 수 있는 키
+const TOP_LEVEL_KEYS: &[&str] = &["policies", "vars"];
+
+/// provider 접두사 없이 쓴 리소스는 aws로 본다
+const AWS_RESOURCES: &[&str] = &[
+    "account",
+    "acm-certificate",
+    "ami",
+    "app-elb",
+    "app-elb-target-group",
+    "asg",
+    "cache-cluster",
+    "cloudformation",
+    "cloudfront",
+    "cloudtrail",
+    "config-rule",
+    "dynamodb-table",
+    "ebs",
+    "ebs-snapshot",
+    "ec2",
+    "ecr",
+    "ecs",
+    "ecs-service",
+    "ecs-task-definition",
+    "efs",
+    "eks",
+    "elasticsearch",
+    "elb",
+    "eni",
+    "glue-job",
+    "iam-group",
+    "iam-policy",
+    "iam-role",
+    "iam-user",
+    "internet-gateway",
+    "kinesis",
+    "kms-key",
+    "lambda",
+    "launch-config",
+    "launch-template-version",
+    "log-group",
+    "nat-gateway",
+    "network-acl",
+    "rds",
+    "rds-cluster",
+    "rds-snapshot",
+    "redshift",
+    "route-table",
+    "s3",
+    "secrets-manager",
+    "security-group",
+    "sns",
+    "sqs",
+    "ssm-parameter",
+    "subnet",
+    "vpc",
+    "waf",
+];
+
+const AZURE_RESOURCES: &[&str] = &[
+    "aks",
+    "appserviceplan",
+    "cosmosdb",
+    "disk",
+    "keyvault",
+    "loadbalancer",
+    "networkinterface",
+    "networksecuritygroup",
+    "publicip",
+    "resourcegroup",
+    "sql-database",
+    "sqlserver",
+    "storage",
+    "subscription",
+    "vm",
+    "vmss",
+    "vnet",
+    "webapp",
+];
+
+const GCP_RESOURCES: &[&str] = &[
+    "bucket",
+    "disk",
+    "firewall",
+    "gke-cluster",
+    "instance",
+    "project",
+    "service-account",
+    "snapshot",
+    "sql-instance",
+    "subnet",
+    "vpc",
+];
+
+const K8S_RESOURCES: &[&str] = &[
+    "config-map",
+    "daemon-set",
+    "deployment",
+    "namespace",
+    "node",
+    "pod",
+    "replica-set",
+    "secret",
+    "service",
+    "service-account",
+    "stateful-set",
+    "volume",
+];
+
+const OPENSTACK_RESOURCES: &[&str] = &["flavor", "image", "project", "server", "user"];
+
+/// `mode.type`과 그 모드에 꼭 필요한 키
+const MODE_TYPES: &[(&str, &[&str])] = &[
+    ("pull", &[]),
+    ("periodic", &["schedule"]),
+    ("schedule", &["schedule"]),
+    ("cloudtrail", &["events"]),
+    ("ec2-instance-state", &["events"]),
+    ("asg-instance-state", &["events"]),
+    ("guard-duty", &[]),
+    ("config-rule", &[]),
+    ("config-poll-rule", &[]),
+    ("hub-finding", &[]),
+    ("hub-action", &[]),
+    ("phd", &[]),
+    ("azure-event-grid", &["events"]),
+    ("azure-periodic", &["schedule"]),
+    ("container-periodic", &["schedule"]),
+    ("container-event", &[]),
+    ("gcp-audit", &["methods"]),
+    ("gcp-periodic", &["schedule"]),
+    ("k8s-admission", &["on-match", "operations"]),
+];
+
+/// value 필터의 `op`
+const VALUE_FILTER_OPS: &[&str] = &[
+    "eq",
+    "equal",
+    "ne",
+    "not-equal",
+    "gt",
+    "greater-than",
+    "ge",
+    "gte",
+    "lt",
+    "less-than",
+    "le",
+    "lte",
+    "glob",
+    "regex",
+    "regex-case",
+    "in",
+    "ni",
+    "not-in",
+    "contains",
+    "difference",
+    "intersect",
+    "mod",
+];
+
+/// value 필터의 `value_type`
+const VALUE_FILTER_TYPES: &[&str] = &[
+    "age",
+    "cidr",
+    "cidr_size",
+    "cidr_type",
+    "date",
+    "expiration",
+    "expr",
+    "integer",
+    "normalize",
+    "resource_count",
+    "size",
+    "swap",
+    "unique_size",
+    "version",
+];
+
+/// 위치가 달린 검증 결과 한 건. 줄·칸은 1부터 센다.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PolicyValidationIssue {
+    pub line: usize,
+    pub column: usize,
+    /// 문제가 있는 위치 (예: `policies[0].filters[1]`)
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for PolicyValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}: {}",
+            self.line, self.column, self.path, self.message
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PolicyValidation {
+    pub errors: Vec<PolicyValidationIssue>,
+    pub warnings: Vec<PolicyValidationIssue>,
+}
+
+impl PolicyValidation {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn error(&mut self, node: &Node, path: &str, message: impl Into<String>) {
+        self.errors.push(issue(node.mark, path, message));
+    }
+
+    fn warning(&mut self, node: &Node, path: &str, message: impl Into<String>) {
+        self.warnings.push(issue(node.mark, path, message));
+    }
+}
+
+fn issue(mark: Marker, path: &str, message: impl Into<String>) -> PolicyValidationIssue {
+    PolicyValidationIssue {
+        line: mark.line(),
+        column: mark.col() + 1,
+        path: path.to_string(),
+        message: message.into(),
+    }
+}
+
+#[derive(Clone)]
+enum NodeKind {
+    Scalar(String, TScalarStyle),
+    Sequence(Vec<Node>),
+    Mapping(Vec<(Node, Node)>),
+    /// 펼치지 못한 alias (펼친 크기가 한도를 넘은 경우)
+    Alias,
+}
+
+#[derive(Clone)]
+struct Node {
+    kind: NodeKind,
+    mark: Marker,
+}
+
+impl Node {
+    fn as_str(&self) -> Option<&str> {
+        match &self.kind {
+            NodeKind::Scalar(value, _) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(
+            &self.kind,
+            NodeKind::Scalar(value, TScalarStyle::Plain) if matches!(value.as_str(), "" | "~" | "null" | "Null" | "NULL")
+        )
+    }
+
+    fn is_merge_key(&self) -> bool {
+        matches!(&self.kind, NodeKind::Scalar(value, TScalarStyle::Plain) if value == "<<")
+    }
+
+    fn get(&self, key: &str) -> Option<&Node> {
+        match &self.kind {
+            NodeKind::Mapping(entries) => entries
+                .iter()
+                .find(|(k, _)| k.as_str() == Some(key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn kind_name(&self) -> &'static str {
+        match &self.kind {
+            NodeKind::Scalar(..) if self.is_null() => "null",
+            NodeKind::Scalar(..) => "scalar",
+            NodeKind::Sequence(_) => "list",
+            NodeKind::Mapping(_) => "mapping",
+            NodeKind::Alias => "alias",
+        }
+    }
+
+    /// 트리의 노드 수 (alias를 펼친 크기를 제한하는 데 쓴다)
+    fn size(&self) -> usize {
+        1 + match &self.kind {
+            NodeKind::Sequence(items) => items.iter().map(Node::size).sum(),
+            NodeKind::Mapping(entries) => entries.iter().map(|(k, v)| k.size() + v.size()).sum(),
+            _ => 0,
+        }
+    }
+}
+
+/// alias를 펼쳐 만들 수 있는 노드 수 상한 (billion laughs 방지)
+const MAX_EXPANDED_NODES: usize = 100_000;
+
+/// `<<` 병합 키를 풀어 mapping에 합친다. 직접 쓴 키가 이기고, 목록으로 여러 mapping을
+/// 합치면 앞의 것이 이긴다. mapping이 아닌 값을 합치려 하면 그대로 둔다.
+fn resolve_merge_keys(entries: Vec<(Node, Node)>) -> Vec<(Node, Node)> {
+    let (merges, mut merged): (Vec<_>, Vec<_>) = entries.into_iter().partition(|(key, value)| {
+        key.is_merge_key()
+            && match &value.kind {
+                NodeKind::Mapping(_) => true,
+                NodeKind::Sequence(items) => items
+                    .iter()
+                    .all(|item| matches!(item.kind, NodeKind::Mapping(_))),
+                _ => false,
+            }
+    });
+
+    for (_, value) in merges {
+        let sources = match value.kind {
+            NodeKind::Mapping(entries) => vec![entries],
+            NodeKind::Sequence(items) => items
+                .into_iter()
+                .filter_map(|item| match item.kind {
+                    NodeKind::Mapping(entries) => Some(entries),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        for (key, value) in sources.into_iter().flatten() {
+            let exists = key.as_str().is_some_and(|name| {
+                merged
+                    .iter()
+                    .any(|(existing, _)| existing.as_str() == Some(name))
+            });
+            if !exists {
+                merged.push((key, value));
+            }
+        }
+    }
+    merged
+}
+
+/// 열린 sequence/mapping과 아직 값을 기다리는 mapping 키, anchor ID(0이면 없음)
+struct OpenNode {
+    node: Node,
+    pending_key: Option<Node>,
+    anchor: usize,
+}
+
+/// 이벤트로 위치가 달린 트리를 만든다. anchor를 기록해 두었다가 alias 자리에 복사해 넣고,
+/// `<<` 병합 키는 mapping이 끝날 때 푼다.
+#[derive(Default)]
+struct TreeBuilder {
+    stack: Vec<OpenNode>,
+    root: Option<Node>,
+    anchors: HashMap<usize, Node>,
+    expanded_nodes: usize,
+    /// alias를 펼친 크기가 한도를 넘었는지
+    too_large: bool,
+}
+
+impl TreeBuilder {
+    fn push_value(&mut self, node: Node, anchor: usize) {
+        if anchor > 0 {
+            self.anchors.insert(anchor, node.clone());
+        }
+        let Some(OpenNode {
+            node: parent,
+            pending_key,
+            ..
+        }) = self.stack.last_mut()
+        else {
+            self.root.get_or_insert(node);
+            return;
+        };
+        match &mut parent.kind {
+            NodeKind::Sequence(items) => items.push(node),
+            NodeKind::Mapping(entries) => match pending_key.take() {
+                Some(key) => entries.push((key, node)),
+                None => *pending_key = Some(node),
+            },
+            _ => {}
+        }
+    }
+
+    fn resolve_alias(&mut self, id: usize, mark: Marker) -> Node {
+        let unresolved = Node {
+            kind: NodeKind::Alias,
+            mark,
+        };
+        let Some(target) = self.anchors.get(&id) else {
+            return unresolved;
+        };
+        self.expanded_nodes += target.size();
+        if self.expanded_nodes > MAX_EXPANDED_NODES {
+            self.too_large = true;
+            return unresolved;
+        }
+        target.clone()
+    }
+}
+
+impl MarkedEventReceiver for TreeBuilder {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        match event {
+            Event::Scalar(value, style, anchor, _) => self.push_value(
+                Node {
+                    kind: NodeKind::Scalar(value, style),
+                    mark,
+                },
+                anchor,
+            ),
+            Event::Alias(id) => {
+                let node = self.resolve_alias(id, mark);
+                self.push_value(node, 0);
+            }
+            Event::SequenceStart(anchor, _) => self.stack.push(OpenNode {
+                node: Node {
+                    kind: NodeKind::Sequence(Vec::new()),
+                    mark,
+                },
+                pending_key: None,
+                anchor,
+            }),
+            Event::MappingStart(anchor, _) => self.stack.push(OpenNode {
+                node: Node {
+                    kind: NodeKind::Mapping(Vec::new()),
+                    mark,
+                },
+                pending_key: None,
+                anchor,
+            }),
+            Event::SequenceEnd | Event::MappingEnd => {
+                if let Some(OpenNode {
+                    mut node, anchor, ..
+                }) = self.stack.pop()
+                {
+                    if let NodeKind::Mapping(entries) = &mut node.kind {
+                        // 블록 mapping은 첫 ':' 위치에서 시작하므로 첫 키 위치를 쓴다
+                        if let Some((first_key, _)) = entries.first()
+                            && first_key.mark.index() < node.mark.index()
+                        {
+                            node.mark = first_key.mark;
+                        }
+                        *entries = resolve_merge_keys(std::mem::take(entries));
+                    }
+                    self.push_value(node, anchor);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 리소스 종류를 확인한다. 알려진 provider의 모르는 종류는 경고로 둔다.
+fn check_resource(result: &mut PolicyValidation, node: &Node, path: &str) {
+    let Some(resource) = node.as_str().filter(|r| !node.is_null() && !r.is_empty()) else {
+        result.error(node, path, "resource must be a non-empty string");
+        return;
+    };
+
+    let (provider, resource_type) = resource.split_once('.').unwrap_or(("aws", resource));
+    let known = match provider {
+        "aws" => AWS_RESOURCES,
+        "azure" => AZURE_RESOURCES,
+        "gcp" => GCP_RESOURCES,
+        "k8s" => K8S_RESOURCES,
+        "openstack" => OPENSTACK_RESOURCES,
+        _ => {
+            result.error(
+                node,
+                path,
+                format!(
+                    "unknown resource provider '{}' (expected aws, azure, gcp, k8s or openstack)",
+                    provider
+                ),
+            );
+            return;
+        }
+    };
+    if !known.contains(&resource_type) {
+        result.warning(
+            node,
+            path,
+            format!(
+                "resource type '{}' is not in the known {} resource list",
+                resource_type, provider
+            ),
+        );
+    }
+}
+
+/// 필터 목록. 항목은 이름 문자열, `type`이 있는 mapping, `and`/`or`/`not` 블록,
+/// 또는 키 하나짜리 value 필터 축약형(`"tag:Owner": absent`)이다.
+fn check_filters(result: &mut PolicyValidation, node: &Node, path: &str) {
+    let NodeKind::Sequence(items) = &node.kind else {
+        result.error(
+            node,
+            path,
+            format!("filters must be a list, not a {}", node.kind_name()),
+        );
+        return;
+    };
+
+    for (index, item) in items.iter().enumerate() {
+        let item_path = format!("{}[{}]", path, index);
+        match &item.kind {
+            NodeKind::Scalar(..) if !item.is_null() => {}
+            NodeKind::Mapping(entries) => {
+                if let Some(filter_type) = item.get("type") {
+                    check_typed_filter(result, item, filter_type, &item_path);
+                    continue;
+                }
+                match entries.as_slice() {
+                    [(key, value)] if matches!(key.as_str(), Some("and" | "or" | "not")) => {
+                        let op = key.as_str().unwrap_or_default();
+                        check_filters(result, value, &format!("{}.{}", item_path, op));
+                    }
+                    [_] => {}
+                    _ => result.error(
+                        item,
+                        &item_path,
+                        "filter must have a 'type' key or be a single 'key: value' pair",
+                    ),
+                }
+            }
+            _ => result.error(
+                item,
+                &item_path,
+                format!(
+                    "filter must be a string or a mapping, not a {}",
+                    item.kind_name()
+                ),
+            ),
+        }
+    }
+}
+
+fn check_typed_filter(result: &mut PolicyValidation, item: &Node, filter_type: &Node, path: &str) {
+    let Some(filter_type) = filter_type.as_str().filter(|t| !t.is_empty()) else {
+        result.error(
+            filter_type,
+            &format!("{}.type", path),
+            "filter type must be a string",
+        );
+        return;
+    };
+    if filter_type != "value" {
+        return;
+    }
+
+    if item.get("key").is_none() {
+        result.error(item, path, "value filter requires 'key'");
+    }
+    if let Some(op) = item.get("op")
+        && !op.as_str().is_some_and(|op| VALUE_FILTER_OPS.contains(&op))
+    {
+        result.error(
+            op,
+            &format!("{}.op", path),
+            format!(
+                "unknown value filter op '{}'",
+                op.as_str().unwrap_or_default()
+            ),
+        );
+    }
+    if let Some(value_type) = item.get("value_type")
+        && !value_type
+            .as_str()
+            .is_some_and(|t| VALUE_FILTER_TYPES.contains(&t))
+    {
+        result.error(
+            value_type,
+            &format!("{}.value_type", path),
+            format!(
+                "unknown value_type '{}'",
+                value_type.as_str().unwrap_or_default()
+            ),
+        );
+    }
+}
+
+/// 액션 목록. 항목은 이름 문자열이거나 `type`이 있는 mapping이다.
+fn check_actions(result: &mut PolicyValidation, node: &Node, path: &str) {
+    let NodeKind::Sequence(items) = &node.kind else {
+        result.error(
+            node,
+            path,
+            format!("actions must be a list, not a {}", node.kind_name()),
+        );
+        return;
+    };
+
+    for (index, item) in items.iter().enumerate() {
+        let item_path = format!("{}[{}]", path, index);
+        match &item.kind {
+            NodeKind::Scalar(..) if !item.is_null() => {}
+            NodeKind::Mapping(_) => match item.get("type") {
+                Some(action_type) if action_type.as_str().is_some_and(|t| !t.is_empty()) => {}
+                Some(action_type) => result.error(
+                    action_type,
+                    &format!("{}.type", item_path),
+                    "action type must be a string",
+                ),
+                None => result.error(item, &item_path, "action requires 'type'"),
+            },
+            _ => result.error(
+                item,
+                &item_path,
+                format!(
+                    "action must be a string or a mapping, not a {}",
+                    item.kind_name()
+                ),
+            ),
+        }
+    }
+}
+
+fn check_mode(result: &mut PolicyValidation, node: &Node, path: &str) {
+    if !matches!(node.kind, NodeKind::Mapping(_)) {
+        result.error(
+            node,
+            path,
+            format!("mode must be a mapping, not a {}", node.kind_name()),
+        );
+        return;
+    }
+
+    let Some(mode_type) = node.get("type") else {
+        result.error(node, path, "mode requires 'type'");
+        return;
+    };
+    let type_path = format!("{}.type", path);
+    let Some((_, required)) = mode_type
+        .as_str()
+        .and_then(|t| MODE_TYPES.iter().find(|(name, _)| *name == t))
+    else {
+        result.error(
+            mode_type,
+            &type_path,
+            format!(
+                "unknown mode type '{}'",
+                mode_type.as_str().unwrap_or_default()
+            ),
+        );
+        return;
+    };
+
+    for key in required.iter() {
+        match node.get(key) {
+            None => result.error(
+                node,
+                path,
+                format!(
+                    "mode '{}' requires '{}'",
+                    mode_type.as_str().unwrap_or_default(),
+                    key
+                ),
+            ),
+            Some(value) if value.is_null() => result.error(
+                value,
+                &format!("{}.{}", path, key),
+                format!("'{}' must not be empty", key),
+            ),
+            Some(_) => {}
+        }
+    }
+    if let Some(events) = node.get("events")
+        && !matches!(events.kind, NodeKind::Sequence(_))
+    {
+        result.error(events, &format!("{}.events", path), "events must be a list");
+    }
+}
+
+fn check_policy(
+    result: &mut PolicyValidation,
+    policy: &Node,
+    path: &str,
+    names: &mut HashMap<String, String>,
+) {
+    let NodeKind::Mapping(entries) = &policy.kind else {
+        result.error(
+            policy,
+            path,
+            format!("policy must be a mapping, not a {}", policy.kind_name()),
+        );
+        return;
+    };
+
+    for (key, _) in entries {
+        match key.as_str() {
+            Some(name) if POLICY_KEYS.contains(&name) => {}
+            name => result.warning(
+                key,
+                path,
+                format!("unknown policy key '{}'", name.unwrap_or_default()),
+            ),
+        }
+    }
+
+    match policy.get("name") {
+        None => result.error(policy, path, "policy requires 'name'"),
+        Some(name) => match name.as_str().filter(|n| !name.is_null() && !n.is_empty()) {
+            None => result.error(
+                name,
+                &format!("{}.name", path),
+                "name must be a non-empty string",
+            ),
+            Some(value) => {
+                if !value
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                {
+                    result.warning(
+                        name,
+                        &format!("{}.name", path),
+                        "name should contain only letters, digits, '-' and '_'",
+                    );
+                }
+                if let Some(first) = names.insert(value.to_string(), path.to_string()) {
+                    result.error(
+                        name,
+                        &format!("{}.name", path),
+                        format!("duplicate policy name '{}' (also used by {})", value, first),
+                    );
+                }
+            }
+        },
+    }
+
+    match policy.get("resource") {
+        None => result.error(policy, path, "policy requires 'resource'"),
+        Some(resource) => check_resource(result, resource, &format!("{}.resource", path)),
+    }
+
+    if let Some(filters) = policy.get("filters") {
+        check_filters(result, filters, &format!("{}.filters", path));
+    }
+    if let Some(actions) = policy.get("actions") {
+        check_actions(result, actions, &format!("{}.actions", path));
+    }
+    if let Some(mode) = policy.get("mode") {
+        check_mode(result, mode, &format!("{}.mode", path));
+    }
+}
+
+/// 정책 YAML을 검증한다. 오류가 하나라도 있으면 저장하거나 실행할 수 없는 정책이다.
+pub fn validate_policy_yaml(content: &str) -> PolicyValidation {
+    let mut result = PolicyValidation::default();
+
+    let mut builder = TreeBuilder::default();
+    if let Err(e) = Parser::new_from_str(content).load(&mut builder, false) {
+        result.errors.push(issue(
+            *e.marker(),
+            "$",
+            format!("invalid YAML: {}", e.info()),
+        ));
+        return result;
+    }
+    if builder.too_large {
+        result.errors.push(PolicyValidationIssue {
+            line: 1,
+            column: 1,
+            path: "$".to_string(),
+            message: format!(
+                "document expands to more than {} nodes through aliases",
+                MAX_EXPANDED_NODES
+            ),
+        });
+        return result;
+    }
+
+    let Some(root) = builder.root else {
+        result.errors.push(PolicyValidationIssue {
+            line: 1,
+            column: 1,
+            path: "$".to_string(),
+            message: "document is empty".to_string(),
+        });
+        return result;
+    };
+    let NodeKind::Mapping(entries) = &root.kind else {
+        result.error(
+            &root,
+            "$",
+            "document must be a mapping with a 'policies' list",
+        );
+        return result;
+    };
+
+    for (key, _) in entries {
+        match key.as_str() {
+            Some(name) if TOP_LEVEL_KEYS.contains(&name) => {}
+            name => result.warning(
+                key,
+                "$",
+                format!("unknown top-level key '{}'", name.unwrap_or_default()),
+            ),
+        }
+    }
+
+    let Some(policies) = root.get("policies") else {
+        result.error(&root, "$", "document requires a top-level 'policies' list");
+        return result;
+    };
+    let NodeKind::Sequence(items) = &policies.kind else {
+        result.error(
+            policies,
+            "policies",
+            format!("policies must be a list, not a {}", policies.kind_name()),
+        );
+        return result;
+    };
+    if items.is_empty() {
+        result.warning(policies, "policies", "policies list is empty");
+    }
+
+    let mut names = HashMap::new();
+    for (index, policy) in items.iter().enumerate() {
+        check_policy(
+            &mut result,
+            policy,
+            &format!("policies[{}]", index),
+            &mut names,
+        );
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(issues: &[PolicyValidationIssue]) -> Vec<&str> {
+        issues.iter().map(|issue| issue.message.as_str()).collect()
+    }
+
+    #[test]
+    fn accepts_a_plain_policy() {
+        let result = validate_policy_yaml(
+            "policies:\n  - name: old-ebs\n    resource: aws.ebs\n    filters:\n      - State: available\n    actions:\n      - delete\n",
+        );
+        assert!(result.is_valid(), "{:?}", messages(&result.errors));
+        assert!(
+            result.warnings.is_empty(),
+            "{:?}",
+            messages(&result.warnings)
+        );
+    }
+
+    #[test]
+    fn resolves_aliases() {
+        let result = validate_policy_yaml(
+            r#"
+vars:
+  stale: &stale
+    - type: value
+      key: LaunchTime
+      value_type: age
+      op: gt
+      value: 30
+policies:
+  - name: stale-ec2
+    resource: aws.ec2
+    filters: *stale
+"#,
+        );
+        assert!(result.is_valid(), "{:?}", messages(&result.errors));
+        assert!(
+            result.warnings.is_empty(),
+            "{:?}",
+            messages(&result.warnings)
+        );
+    }
+
+    #[test]
+    fn checks_aliased_content() {
+        let result = validate_policy_yaml(
+            "vars:\n  bad: &bad 42\npolicies:\n  - name: p\n    resource: aws.ec2\n    filters: *bad\n",
+        );
+        assert_eq!(
+            messages(&result.errors),
+            ["filters must be a list, not a scalar"]
+        );
+    }
+
+    #[test]
+    fn resolves_merge_keys() {
+        let result = validate_policy_yaml(
+            r#"
+vars:
+  base: &base
+    resource: aws.s3
+    mode:
+      type: periodic
+      schedule: "rate(1 day)"
+  extra: &extra
+    name: from-extra
+    description: merged
+policies:
+  - <<: *base
+    name: public-buckets
+  - <<: [*extra, *base]
+"#,
+        );
+        assert!(result.is_valid(), "{:?}", messages(&result.errors));
+        assert!(
+            result.warnings.is_empty(),
+            "{:?}",
+            messages(&result.warnings)
+        );
+
+        let mut builder = TreeBuilder::default();
+        Parser::new_from_str("a: &a {x: 1, y: 2}\nb:\n  <<: *a\n  y: 3\n")
+            .load(&mut builder, false)
+            .unwrap();
+        let b = builder.root.as_ref().unwrap().get("b").unwrap();
+        // 직접 쓴 키가 병합한 키보다 우선한다
+        assert_eq!(b.get("x").and_then(Node::as_str), Some("1"));
+        assert_eq!(b.get("y").and_then(Node::as_str), Some("3"));
+        assert!(b.get("<<").is_none());
+    }
+
+    #[test]
+    fn merged_policies_still_need_required_keys() {
+        let result = validate_policy_yaml(
+            "vars:\n  base: &base {resource: aws.ec2}\npolicies:\n  - <<: *base\n",
+        );
+        assert_eq!(messages(&result.errors), ["policy requires 'name'"]);
+    }
+
+    #[test]
+    fn rejects_alias_bombs() {
+        let mut yaml = String::from("a: &a [x, x, x, x, x, x, x, x, x, x]\n");
+        let mut previous = 'a';
+        for name in 'b'..='j' {
+            yaml.push_str(&format!(
+                "{name}: &{name} [*{p}, *{p}, *{p}, *{p}, *{p}, *{p}, *{p}, *{p}, *{p}, *{p}]\n",
+                name = name,
+                p = previous
+            ));
+            previous = name;
+        }
+        let result = validate_policy_yaml(&yaml);
+        assert!(!result.is_valid());
+        assert!(result.errors[0].message.contains("through aliases"));
+    }
+}
@@ -1,17 +1,39 @@
 use crate::dto::device::request::create_device::CreateDeviceRequest;
 use crate::dto::device::response::device_info::DeviceInfoResponse;
 use crate::repository::device::create_device::repository_create_device;
+use crate::service::audit::{ACTION_CREATE, AuditEntry, RESOURCE_DEVICE, record_audit};
 use crate::service::error::errors::ServiceResult;
-use sea_orm::DatabaseConnection;
+use crate::service::rack::capacity::{check_rack_power_budget, notify_power_budget_overage};
+use crate::service::rack::elevation::{ensure_rack_slots_available, lock_rack};
+use crate::service::realtime::publish_rack_elevation_changed;
+use crate::service::webhook::{EVENT_DEVICE_CREATED, WebhookEvent, publish_webhook_event};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use uuid::Uuid;
 
 pub async fn service_create_device(
     conn: &DatabaseConnection,
+    tenant_id: Uuid,
     request: CreateDeviceRequest,
     created_by: Uuid,
 ) -> ServiceResult<DeviceInfoResponse> {
+    let txn = conn.begin().await?;
+
+    // 랙에 넣는 경우 랙을 잠근 뒤 U 범위와 전력 예산을 검증 (동시 요청이 같은 슬롯을 차지하지 않도록)
+    let mut power_overage = None;
+    if let Some(rack_id) = request.rack_id.as_ref() {
+        let rack = lock_rack(&txn, rack_id).await?;
+        if let Some(position) = request.rack_position {
+            ensure_rack_slots_available(&txn, &rack, position, request.rack_size, None).await?;
+        }
+        power_overage =
+            check_rack_power_budget(&txn, &rack, &request.name, request.power_consumption, None)
+                .await?;
+    }
+
     let device = repository_create_device(
-        conn,
+        &txn,
+        &tenant_id,
         request.rack_id.as_ref(),
         &request.name,
         request.description.as_deref(),
@@ -29,6 +51,42 @@ pub async fn service_create_device(
     )
     .await?;
 
+    record_audit(
+        &txn,
+        AuditEntry {
+            resource_type: RESOURCE_DEVICE,
+            resource_id: device.id,
+            resource_name: Some(&device.name),
+            action: ACTION_CREATE,
+            actor_id: Some(created_by),
+            before: None,
+            after: Some(&device),
+        },
+    )
+    .await?;
+
+    publish_webhook_event(
+        &txn,
+        WebhookEvent {
+            event_type: EVENT_DEVICE_CREATED,
+            resource_type: RESOURCE_DEVICE,
+            resource_id: device.id,
+            resource_name: Some(&device.name),
+            actor_id: Some(created_by),
+            tenant_id: device.tenant_id,
+            data: json!({ "device": device }),
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    publish_rack_elevation_changed(conn, device.rack_id, device.id, ACTION_CREATE).await;
+
+    if let Some(overage) = power_overage {
+        notify_power_budget_overage(conn, overage, Some(created_by)).await;
+    }
+
     Ok(DeviceInfoResponse {
         id: device.id,
         rack_id: device.rack_id,
@@ -1,12 +1,56 @@
 use crate::repository::device::delete_device::repository_delete_device;
-use crate::service::error::errors::ServiceResult;
-use sea_orm::DatabaseConnection;
+use crate::repository::device::get_device_by_id::repository_get_device_by_id;
+use crate::service::audit::{ACTION_DELETE, AuditEntry, RESOURCE_DEVICE, record_audit};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::realtime::publish_rack_elevation_changed;
+use crate::service::webhook::{EVENT_DEVICE_DELETED, WebhookEvent, publish_webhook_event};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use uuid::Uuid;
 
 pub async fn service_delete_device(
     conn: &DatabaseConnection,
     device_id: Uuid,
+    deleted_by: Uuid,
 ) -> ServiceResult<()> {
-    repository_delete_device(conn, &device_id).await?;
+    let txn = conn.begin().await?;
+
+    let before = repository_get_device_by_id(&txn, &device_id)
+        .await?
+        .ok_or_else(|| Errors::NotFound("Device not found".to_string()))?;
+
+    repository_delete_device(&txn, &device_id).await?;
+
+    record_audit(
+        &txn,
+        AuditEntry {
+            resource_type: RESOURCE_DEVICE,
+            resource_id: before.id,
+            resource_name: Some(&before.name),
+            action: ACTION_DELETE,
+            actor_id: Some(deleted_by),
+            before: Some(&before),
+            after: None,
+        },
+    )
+    .await?;
+
+    publish_webhook_event(
+        &txn,
+        WebhookEvent {
+            event_type: EVENT_DEVICE_DELETED,
+            resource_type: RESOURCE_DEVICE,
+            resource_id: before.id,
+            resource_name: Some(&before.name),
+            actor_id: Some(deleted_by),
+            tenant_id: before.tenant_id,
+            data: json!({ "device": before }),
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    publish_rack_elevation_changed(conn, before.rack_id, before.id, ACTION_DELETE).await;
     Ok(())
 }
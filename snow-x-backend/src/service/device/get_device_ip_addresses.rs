@@ -19,6 +19,8 @@ pub async fn service_get_device_ip_addresses(
         hostname: Option<String>,
         description: Option<String>,
         mac_address: Option<String>,
+        lease_start: Option<DateTimeWithTimeZone>,
+        lease_end: Option<DateTimeWithTimeZone>,
         created_by: Uuid,
         created_at: DateTimeWithTimeZone,
         updated_at: DateTimeWithTimeZone,
@@ -34,6 +36,8 @@ pub async fn service_get_device_ip_addresses(
             ip.hostname,
             ip.description,
             ip.mac_address::text as mac_address,
+            ip.lease_start,
+            ip.lease_end,
             ip.created_by,
             ip.created_at,
             ip.updated_at,
@@ -62,6 +66,8 @@ pub async fn service_get_device_ip_addresses(
             hostname: ip.hostname,
             status: ip.status,
             description: ip.description,
+            lease_start: ip.lease_start.map(|t| t.to_string()),
+            lease_end: ip.lease_end.map(|t| t.to_string()),
             created_by: ip.created_by,
             created_at: ip.created_at.to_string(),
             updated_at: ip.updated_at.to_string(),
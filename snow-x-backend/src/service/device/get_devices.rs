@@ -1,10 +1,12 @@
 use crate::dto::device::response::device_info::DeviceInfoResponse;
 use crate::dto::device::response::device_list::DeviceListResponse;
 use crate::repository::device::get_devices::repository_get_devices;
+use crate::service::auth::permission::AccessScope;
 use crate::service::error::errors::ServiceResult;
 use sea_orm::DatabaseConnection;
 use uuid::Uuid;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn service_get_devices(
     conn: &DatabaseConnection,
     page: u64,
@@ -13,6 +15,7 @@ pub async fn service_get_devices(
     device_type: Option<String>,
     status: Option<String>,
     rack_id: Option<Uuid>,
+    scope: &AccessScope,
 ) -> ServiceResult<DeviceListResponse> {
     let (devices, total) = repository_get_devices(
         conn,
@@ -22,6 +25,7 @@ pub async fn service_get_devices(
         device_type.as_deref(),
         status.as_deref(),
         rack_id.as_ref(),
+        scope,
     )
     .await?;
 
@@ -1,17 +1,78 @@
 use crate::dto::device::request::update_device::UpdateDeviceRequest;
 use crate::dto::device::response::device_info::DeviceInfoResponse;
+use crate::repository::device::get_device_by_id::repository_get_device_by_id;
 use crate::repository::device::update_device::repository_update_device;
-use crate::service::error::errors::ServiceResult;
-use sea_orm::DatabaseConnection;
+use crate::service::audit::{ACTION_UPDATE, AuditEntry, RESOURCE_DEVICE, record_audit};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::external_api::conflict::{ManualEdit, guard_manual_edit};
+use crate::service::rack::capacity::{check_rack_power_budget, notify_power_budget_overage};
+use crate::service::rack::elevation::{ensure_rack_slots_available, lock_rack};
+use crate::service::realtime::publish_rack_elevation_changed;
+use crate::service::webhook::{
+    EVENT_DEVICE_UPDATED, WebhookEvent, changed_fields, publish_webhook_event,
+};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use uuid::Uuid;
 
 pub async fn service_update_device(
     conn: &DatabaseConnection,
     device_id: Uuid,
     request: UpdateDeviceRequest,
+    updated_by: Uuid,
 ) -> ServiceResult<DeviceInfoResponse> {
+    let txn = conn.begin().await?;
+
+    let existing = repository_get_device_by_id(&txn, &device_id)
+        .await?
+        .ok_or_else(|| Errors::NotFound("Device not found".to_string()))?;
+
+    // 변경 후의 배치 상태를 기준으로 검증 (요청에 없는 값은 기존 값 유지)
+    // 배치나 전력 값을 바꾸지 않는 수정은 기존 데이터 상태와 관계없이 허용한다
+    let rack_id = request.rack_id.or(existing.rack_id);
+    let rack_position = request.rack_position.or(existing.rack_position);
+    let rack_size = request.rack_size.unwrap_or(existing.rack_size);
+    let power_consumption = request.power_consumption.or(existing.power_consumption);
+
+    let placement_changed = request
+        .rack_id
+        .is_some_and(|id| Some(id) != existing.rack_id)
+        || request
+            .rack_position
+            .is_some_and(|p| Some(p) != existing.rack_position)
+        || request
+            .rack_size
+            .is_some_and(|size| size != existing.rack_size);
+    let power_changed = request
+        .rack_id
+        .is_some_and(|id| Some(id) != existing.rack_id)
+        || request
+            .power_consumption
+            .is_some_and(|w| Some(w) != existing.power_consumption);
+
+    let mut power_overage = None;
+    if let Some(rack_id) = rack_id
+        && (placement_changed || power_changed)
+    {
+        let rack = lock_rack(&txn, &rack_id).await?;
+        if placement_changed && let Some(position) = rack_position {
+            ensure_rack_slots_available(&txn, &rack, position, rack_size, Some(&device_id)).await?;
+        }
+        if power_changed {
+            let device_name = request.name.as_deref().unwrap_or(&existing.name);
+            power_overage = check_rack_power_budget(
+                &txn,
+                &rack,
+                device_name,
+                power_consumption,
+                Some(&device_id),
+            )
+            .await?;
+        }
+    }
+
     let device = repository_update_device(
-        conn,
+        &txn,
         &device_id,
         request.rack_id.map(Some),
         request.name.as_deref(),
@@ -29,6 +90,67 @@ pub async fn service_update_device(
     )
     .await?;
 
+    guard_manual_edit(
+        &txn,
+        ManualEdit {
+            resource_type: RESOURCE_DEVICE,
+            resource_id: device.id,
+            source_type: &existing.source_type,
+            connection_id: existing.external_api_connection_id,
+            actor_id: updated_by,
+            before: &existing,
+            after: &device,
+        },
+    )
+    .await?;
+
+    record_audit(
+        &txn,
+        AuditEntry {
+            resource_type: RESOURCE_DEVICE,
+            resource_id: device.id,
+            resource_name: Some(&device.name),
+            action: ACTION_UPDATE,
+            actor_id: Some(updated_by),
+            before: Some(&existing),
+            after: Some(&device),
+        },
+    )
+    .await?;
+
+    let changes = changed_fields(&existing, &device);
+    if !changes.is_empty() {
+        publish_webhook_event(
+            &txn,
+            WebhookEvent {
+                event_type: EVENT_DEVICE_UPDATED,
+                resource_type: RESOURCE_DEVICE,
+                resource_id: device.id,
+                resource_name: Some(&device.name),
+                actor_id: Some(updated_by),
+                tenant_id: device.tenant_id,
+                data: json!({ "device": device, "changes": changes }),
+            },
+        )
+        .await?;
+    }
+
+    txn.commit().await?;
+
+    if placement_changed {
+        publish_rack_elevation_changed(
+            conn,
+            existing.rack_id.into_iter().chain(device.rack_id),
+            device.id,
+            ACTION_UPDATE,
+        )
+        .await;
+    }
+
+    if let Some(overage) = power_overage {
+        notify_power_budget_overage(conn, overage, Some(updated_by)).await;
+    }
+
     Ok(DeviceInfoResponse {
         id: device.id,
         rack_id: device.rack_id,
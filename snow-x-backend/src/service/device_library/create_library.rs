@@ -1,17 +1,22 @@
 use crate::dto::device_library::request::CreateLibraryRequest;
 use crate::dto::device_library::response::LibraryInfoResponse;
 use crate::entity::device_library;
+use crate::service::audit::{
+    ACTION_CREATE, AuditEntry, RESOURCE_DEVICE_LIBRARY, record_audit_or_warn,
+};
 use crate::service::error::errors::ServiceResult;
 use sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection};
 use uuid::Uuid;
 
 pub async fn service_create_library(
     conn: &DatabaseConnection,
+    tenant_id: Uuid,
     request: CreateLibraryRequest,
     created_by: Uuid,
 ) -> ServiceResult<LibraryInfoResponse> {
     let library = device_library::ActiveModel {
         id: ActiveValue::Set(Uuid::new_v4()),
+        tenant_id: ActiveValue::Set(Some(tenant_id)),
         name: ActiveValue::Set(request.name),
         description: ActiveValue::Set(request.description),
         device_type: ActiveValue::Set(request.device_type),
@@ -32,6 +37,20 @@ pub async fn service_create_library(
     .insert(conn)
     .await?;
 
+    record_audit_or_warn(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_DEVICE_LIBRARY,
+            resource_id: library.id,
+            resource_name: Some(&library.name),
+            action: ACTION_CREATE,
+            actor_id: Some(created_by),
+            before: None,
+            after: Some(&library),
+        },
+    )
+    .await;
+
     Ok(LibraryInfoResponse {
         id: library.id,
         name: library.name,
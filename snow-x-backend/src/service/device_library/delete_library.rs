@@ -1,4 +1,7 @@
 use crate::entity::device_library;
+use crate::service::audit::{
+    ACTION_DELETE, AuditEntry, RESOURCE_DEVICE_LIBRARY, record_audit_or_warn,
+};
 use crate::service::error::errors::ServiceResult;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel,
@@ -6,16 +9,35 @@ use sea_orm::{
 };
 use uuid::Uuid;
 
-pub async fn service_delete_library(conn: &DatabaseConnection, id: Uuid) -> ServiceResult<()> {
+pub async fn service_delete_library(
+    conn: &DatabaseConnection,
+    id: Uuid,
+    deleted_by: Uuid,
+) -> ServiceResult<()> {
     let library = device_library::Entity::find_by_id(id)
         .filter(device_library::Column::IsActive.eq(true))
         .one(conn)
         .await?
         .ok_or_else(|| sea_orm::DbErr::RecordNotFound("Library not found".to_string()))?;
 
+    let before = library.clone();
     let mut active_model = library.into_active_model();
     active_model.is_active = ActiveValue::Set(false);
     active_model.update(conn).await?;
 
+    record_audit_or_warn(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_DEVICE_LIBRARY,
+            resource_id: before.id,
+            resource_name: Some(&before.name),
+            action: ACTION_DELETE,
+            actor_id: Some(deleted_by),
+            before: Some(&before),
+            after: None,
+        },
+    )
+    .await;
+
     Ok(())
 }
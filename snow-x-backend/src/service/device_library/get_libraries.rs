@@ -4,14 +4,18 @@ use crate::service::error::errors::ServiceResult;
 use sea_orm::{
     ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
 };
+use uuid::Uuid;
+
 pub async fn service_get_libraries(
     conn: &DatabaseConnection,
+    tenant_id: Uuid,
     page: u64,
     limit: u64,
     search: Option<String>,
 ) -> ServiceResult<LibraryListResponse> {
-    let mut query =
-        device_library::Entity::find().filter(device_library::Column::IsActive.eq(true));
+    let mut query = device_library::Entity::find()
+        .filter(device_library::Column::TenantId.eq(tenant_id))
+        .filter(device_library::Column::IsActive.eq(true));
 
     if let Some(search_term) = search {
         query = query.filter(
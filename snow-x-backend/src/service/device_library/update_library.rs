@@ -1,9 +1,12 @@
 use crate::dto::device_library::request::UpdateLibraryRequest;
 use crate::dto::device_library::response::LibraryInfoResponse;
 use crate::entity::device_library;
+use crate::service::audit::{ACTION_UPDATE, AuditEntry, RESOURCE_DEVICE_LIBRARY, record_audit};
 use crate::service::error::errors::ServiceResult;
+use crate::service::external_api::conflict::{ManualEdit, guard_manual_edit};
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    TransactionTrait,
 };
 use uuid::Uuid;
 
@@ -11,13 +14,17 @@ pub async fn service_update_library(
     conn: &DatabaseConnection,
     id: Uuid,
     request: UpdateLibraryRequest,
+    updated_by: Uuid,
 ) -> ServiceResult<LibraryInfoResponse> {
+    let txn = conn.begin().await?;
+
     let library = device_library::Entity::find_by_id(id)
         .filter(device_library::Column::IsActive.eq(true))
-        .one(conn)
+        .one(&txn)
         .await?
         .ok_or_else(|| sea_orm::DbErr::RecordNotFound("Library not found".to_string()))?;
 
+    let before = library.clone();
     let mut active_model: device_library::ActiveModel = library.into();
 
     if let Some(name) = request.name {
@@ -61,7 +68,38 @@ pub async fn service_update_library(
         active_model.is_active = ActiveValue::Set(is_active);
     }
 
-    let updated_library = active_model.update(conn).await?;
+    let updated_library = active_model.update(&txn).await?;
+
+    // 동기화된 라이브러리면 소유 규칙을 확인하고 수동 수정으로 표시한다
+    guard_manual_edit(
+        &txn,
+        ManualEdit {
+            resource_type: RESOURCE_DEVICE_LIBRARY,
+            resource_id: updated_library.id,
+            source_type: &before.source_type,
+            connection_id: before.external_api_connection_id,
+            actor_id: updated_by,
+            before: &before,
+            after: &updated_library,
+        },
+    )
+    .await?;
+
+    record_audit(
+        &txn,
+        AuditEntry {
+            resource_type: RESOURCE_DEVICE_LIBRARY,
+            resource_id: updated_library.id,
+            resource_name: Some(&updated_library.name),
+            action: ACTION_UPDATE,
+            actor_id: Some(updated_by),
+            before: Some(&before),
+            after: Some(&updated_library),
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
 
     Ok(LibraryInfoResponse {
         id: updated_library.id,
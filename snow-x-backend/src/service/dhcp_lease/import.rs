@@ -0,0 +1,330 @@
+use crate::entity::ip_addresses;
+use crate::service::dhcp_lease::parser::{DhcpLease, LeaseFormat, parse_leases};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::ip_address::audit::{
+    IP_ADDRESS_COLUMNS, ip_address_from_row, lock_ip_address, record_ip_address_audit,
+};
+use crate::service::ip_range::hierarchy::{TenantRange, fetch_active_ranges};
+use axum::extract::Multipart;
+use axum::http::StatusCode;
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement, TransactionTrait};
+use uuid::Uuid;
+
+/// 라우트의 요청 본문 제한도 이 값을 기준으로 정한다
+pub const MAX_LEASE_FILE_SIZE: usize = 32 * 1024 * 1024;
+/// 응답에 포함할 최대 오류 메시지 수
+const MAX_REPORTED_ERRORS: usize = 100;
+
+pub struct LeaseImportSummary {
+    pub format: LeaseFormat,
+    pub total: usize,
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub errors: Vec<String>,
+}
+
+impl LeaseImportSummary {
+    fn push_error(&mut self, message: String) {
+        if self.errors.len() < MAX_REPORTED_ERRORS {
+            self.errors.push(message);
+        }
+    }
+}
+
+/// multipart 업로드(`file`, 선택: `format`, `tenant_id`)로 받은 임대 파일을 가져온다.
+pub async fn service_import_dhcp_lease_upload(
+    conn: &DatabaseConnection,
+    owner_tenant_id: &Uuid,
+    mut multipart: Multipart,
+    imported_by: &Uuid,
+) -> ServiceResult<LeaseImportSummary> {
+    let mut content: Option<String> = None;
+    let mut format: Option<LeaseFormat> = None;
+    let mut tenant_id: Option<Uuid> = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| Errors::FileReadError(format!("Failed to read multipart field: {}", e)))?
+    {
+        match field.name() {
+            Some("file") => {
+                // 라우트의 DefaultBodyLimit을 넘으면 읽는 도중에 413으로 끊긴다
+                let data = field.bytes().await.map_err(|e| {
+                    if e.status() == StatusCode::PAYLOAD_TOO_LARGE {
+                        Errors::FileTooLargeError(format!(
+                            "Lease file exceeds {} bytes",
+                            MAX_LEASE_FILE_SIZE
+                        ))
+                    } else {
+                        Errors::FileReadError(format!("Failed to read file data: {}", e))
+                    }
+                })?;
+                if data.len() > MAX_LEASE_FILE_SIZE {
+                    return Err(Errors::FileTooLargeError(format!(
+                        "Lease file exceeds {} bytes",
+                        MAX_LEASE_FILE_SIZE
+                    )));
+                }
+                content = Some(String::from_utf8_lossy(&data).into_owned());
+            }
+            Some("format") => {
+                let value = field
+                    .text()
+                    .await
+                    .map_err(|e| Errors::FileReadError(format!("Failed to read format: {}", e)))?;
+                if !value.trim().is_empty() {
+                    format = Some(LeaseFormat::parse(&value)?);
+                }
+            }
+            Some("tenant_id") => {
+                let value = field.text().await.map_err(|e| {
+                    Errors::FileReadError(format!("Failed to read tenant_id: {}", e))
+                })?;
+                if !value.trim().is_empty() {
+                    tenant_id =
+                        Some(Uuid::parse_str(value.trim()).map_err(|_| {
+                            Errors::BadRequestError("Invalid tenant_id".to_string())
+                        })?);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let content = content.ok_or(Errors::FileNotFound)?;
+
+    service_import_dhcp_leases(
+        conn,
+        &content,
+        format,
+        tenant_id.as_ref(),
+        Some(owner_tenant_id),
+        Some(imported_by),
+    )
+    .await
+}
+
+/// 임대 파일 내용을 파싱해 해당 주소를 포함하는 대역의 ip_addresses에 반영한다.
+///
+/// - 주소는 활성 대역 중 prefix가 가장 긴 대역에 매칭되며, 매칭 대역이 없으면 건너뛴다.
+/// - 유효한 임대는 `allocated`로 upsert하고 hostname/MAC/임대 기간을 갱신한다.
+/// - 만료되었거나 해제된 임대는 이미 등록된 주소에만 반영한다 (새 행을 만들지 않음).
+/// - `reserved`/`unavailable`로 수동 지정된 주소와 더 최신 임대 정보는 덮어쓰지 않는다.
+///
+/// imported_by가 없으면(감시 경로 가져오기) 대역 생성자를 created_by로 사용한다.
+/// owner_tenant_id가 있으면 그 테넌트의 대역에만 반영한다.
+pub async fn service_import_dhcp_leases(
+    conn: &DatabaseConnection,
+    content: &str,
+    format: Option<LeaseFormat>,
+    tenant_id: Option<&Uuid>,
+    owner_tenant_id: Option<&Uuid>,
+    imported_by: Option<&Uuid>,
+) -> ServiceResult<LeaseImportSummary> {
+    let format = format.unwrap_or_else(|| LeaseFormat::detect(content));
+    let parsed = parse_leases(content, format);
+    let ranges = fetch_active_ranges(conn, tenant_id, owner_tenant_id).await?;
+    let now = chrono::Utc::now();
+
+    let mut summary = LeaseImportSummary {
+        format,
+        total: parsed.leases.len(),
+        created: 0,
+        updated: 0,
+        skipped: 0,
+        errors: Vec::new(),
+    };
+    for error in parsed.errors {
+        summary.push_error(error);
+    }
+
+    for lease in &parsed.leases {
+        let range = match match_range(&ranges, lease) {
+            Ok(Some(range)) => range,
+            Ok(None) => {
+                summary.skipped += 1;
+                continue;
+            }
+            Err(message) => {
+                summary.skipped += 1;
+                summary.push_error(message);
+                continue;
+            }
+        };
+
+        let result = write_lease(conn, range, lease, imported_by, now).await;
+
+        match result {
+            Ok(LeaseWrite::Created) => summary.created += 1,
+            Ok(LeaseWrite::Updated) => summary.updated += 1,
+            Ok(LeaseWrite::Unchanged) => summary.skipped += 1,
+            Err(e) => {
+                summary.skipped += 1;
+                summary.push_error(format!("{}: {:?}", lease.ip_address, e));
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// 임대 주소를 포함하는 가장 구체적인 대역. 서로 다른 테넌트의 같은 CIDR이 동시에 매칭되면 모호하므로 거부한다.
+fn match_range<'a>(
+    ranges: &'a [TenantRange],
+    lease: &DhcpLease,
+) -> Result<Option<&'a TenantRange>, String> {
+    let mut matches: Vec<&TenantRange> = ranges
+        .iter()
+        .filter(|range| range.network.contains_ip(&lease.ip_address))
+        .collect();
+    matches.sort_by_key(|range| std::cmp::Reverse(range.network.prefix()));
+
+    match matches.as_slice() {
+        [] => Ok(None),
+        [best, next, ..] if best.network == next.network => Err(format!(
+            "{}: matches ranges in multiple offices, specify tenant_id",
+            lease.ip_address
+        )),
+        [best, ..] => Ok(Some(best)),
+    }
+}
+
+enum LeaseWrite {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+/// 임대 한 건을 반영하고 바뀐 주소를 감사 로그에 남긴다 (주소마다 트랜잭션 하나)
+async fn write_lease(
+    conn: &DatabaseConnection,
+    range: &TenantRange,
+    lease: &DhcpLease,
+    imported_by: Option<&Uuid>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> ServiceResult<LeaseWrite> {
+    let txn = conn.begin().await?;
+    let before = lock_ip_address(&txn, &range.model.id, &lease.ip_address.to_string()).await?;
+
+    let after = if lease.is_current(now) {
+        let created_by = imported_by.copied().unwrap_or(range.model.created_by);
+        upsert_active_lease(&txn, range, lease, &created_by).await?
+    } else {
+        update_inactive_lease(&txn, range, lease).await?
+    };
+    let Some(after) = after else {
+        return Ok(LeaseWrite::Unchanged);
+    };
+
+    record_ip_address_audit(&txn, before.as_ref(), &after, imported_by.copied()).await?;
+    txn.commit().await?;
+
+    Ok(if before.is_none() {
+        LeaseWrite::Created
+    } else {
+        LeaseWrite::Updated
+    })
+}
+
+async fn upsert_active_lease<C>(
+    conn: &C,
+    range: &TenantRange,
+    lease: &DhcpLease,
+    created_by: &Uuid,
+) -> ServiceResult<Option<ip_addresses::Model>>
+where
+    C: ConnectionTrait,
+{
+    let sql = format!(
+        r#"
+        INSERT INTO ip_addresses (
+            id, ip_range_id, ip_address, status, hostname, mac_address,
+            lease_start, lease_end, created_by, created_at, updated_at, is_active
+        ) VALUES (
+            $1, $2, $3::inet, 'allocated', $4, $5::macaddr, $6, $7, $8, $9, $9, true
+        )
+        ON CONFLICT (ip_address, ip_range_id) DO UPDATE
+        SET status = 'allocated',
+            hostname = COALESCE(EXCLUDED.hostname, ip_addresses.hostname),
+            mac_address = COALESCE(EXCLUDED.mac_address, ip_addresses.mac_address),
+            lease_start = EXCLUDED.lease_start,
+            lease_end = EXCLUDED.lease_end,
+            updated_at = EXCLUDED.updated_at,
+            is_active = true
+        WHERE ip_addresses.status NOT IN ('reserved', 'unavailable')
+          AND (
+              ip_addresses.lease_end IS NULL
+              OR EXCLUDED.lease_end IS NULL
+              OR EXCLUDED.lease_end >= ip_addresses.lease_end
+          )
+        RETURNING {}
+    "#,
+        IP_ADDRESS_COLUMNS
+    );
+
+    conn.query_one(Statement::from_sql_and_values(
+        sea_orm::DatabaseBackend::Postgres,
+        sql,
+        vec![
+            Uuid::new_v4().into(),
+            range.model.id.into(),
+            lease.ip_address.to_string().into(),
+            lease.hostname.clone().into(),
+            lease.mac_address.clone().into(),
+            lease.lease_start.into(),
+            lease.lease_end.into(),
+            (*created_by).into(),
+            chrono::Utc::now().into(),
+        ],
+    ))
+    .await?
+    .map(|row| ip_address_from_row(&row))
+    .transpose()
+}
+
+async fn update_inactive_lease<C>(
+    conn: &C,
+    range: &TenantRange,
+    lease: &DhcpLease,
+) -> ServiceResult<Option<ip_addresses::Model>>
+where
+    C: ConnectionTrait,
+{
+    let sql = format!(
+        r#"
+        UPDATE ip_addresses
+        SET status = CASE WHEN status = 'allocated' THEN 'expired' ELSE status END,
+            hostname = COALESCE($3, hostname),
+            mac_address = COALESCE($4::macaddr, mac_address),
+            lease_start = $5,
+            lease_end = $6,
+            updated_at = $7
+        WHERE ip_range_id = $1
+          AND ip_address = $2::inet
+          AND is_active = true
+          AND status NOT IN ('reserved', 'unavailable')
+          AND (lease_end IS NULL OR $6 IS NULL OR $6 >= lease_end)
+        RETURNING {}
+    "#,
+        IP_ADDRESS_COLUMNS
+    );
+
+    conn.query_one(Statement::from_sql_and_values(
+        sea_orm::DatabaseBackend::Postgres,
+        sql,
+        vec![
+            range.model.id.into(),
+            lease.ip_address.to_string().into(),
+            lease.hostname.clone().into(),
+            lease.mac_address.clone().into(),
+            lease.lease_start.into(),
+            lease.lease_end.into(),
+            chrono::Utc::now().into(),
+        ],
+    ))
+    .await?
+    .map(|row| ip_address_from_row(&row))
+    .transpose()
+}
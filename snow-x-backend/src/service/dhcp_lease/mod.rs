@@ -0,0 +1,4 @@
+pub mod import;
+pub mod parser;
+pub mod sweep;
+pub mod worker;
@@ -0,0 +1,522 @@
+use crate::service::error::errors::{Errors, ServiceResult};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaseFormat {
+    /// ISC dhcpd `dhcpd.leases` / `dhcpd6.leases`
+    Isc,
+    /// Kea memfile lease4/lease6 CSV
+    KeaCsv,
+}
+
+impl LeaseFormat {
+    pub fn parse(value: &str) -> ServiceResult<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "isc" | "dhcpd" => Ok(LeaseFormat::Isc),
+            "kea" | "kea_csv" | "csv" => Ok(LeaseFormat::KeaCsv),
+            other => Err(Errors::BadRequestError(format!(
+                "Unsupported lease format '{}' (expected 'isc' or 'kea')",
+                other
+            ))),
+        }
+    }
+
+    /// Kea CSV는 항상 `address,` 헤더로 시작하므로 이를 기준으로 판별
+    pub fn detect(content: &str) -> Self {
+        let first_line = content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'));
+
+        match first_line {
+            Some(line) if line.starts_with("address,") => LeaseFormat::KeaCsv,
+            _ => LeaseFormat::Isc,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DhcpLease {
+    pub ip_address: IpAddr,
+    pub mac_address: Option<String>,
+    pub hostname: Option<String>,
+    pub lease_start: Option<DateTime<Utc>>,
+    /// None이면 만료 시각이 없는(never) 임대
+    pub lease_end: Option<DateTime<Utc>>,
+    /// 서버가 현재 유효한 임대로 기록했는지 (ISC `binding state active`, Kea state 0)
+    pub active: bool,
+}
+
+impl DhcpLease {
+    pub fn is_current(&self, now: DateTime<Utc>) -> bool {
+        self.active && self.lease_end.is_none_or(|end| end > now)
+    }
+}
+
+pub struct ParsedLeases {
+    pub leases: Vec<DhcpLease>,
+    pub errors: Vec<String>,
+}
+
+pub fn parse_leases(content: &str, format: LeaseFormat) -> ParsedLeases {
+    match format {
+        LeaseFormat::Isc => parse_isc_leases(content),
+        LeaseFormat::KeaCsv => parse_kea_csv(content),
+    }
+}
+
+/// ISC dhcpd 임대 파일 파싱
+/// dhcpd는 변경 시 같은 주소의 블록을 파일 끝에 덧붙이므로 마지막 블록이 우선한다.
+fn parse_isc_leases(content: &str) -> ParsedLeases {
+    let tokens = tokenize_isc(content);
+    let mut leases: Vec<DhcpLease> = Vec::new();
+    let mut index_by_ip: HashMap<IpAddr, usize> = HashMap::new();
+    let mut errors = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let is_block = matches!(tokens[i].as_str(), "lease" | "iaaddr")
+            && tokens.get(i + 2).map(String::as_str) == Some("{");
+
+        if !is_block {
+            i += 1;
+            continue;
+        }
+
+        let address = tokens[i + 1].clone();
+        let (statements, next) = collect_block(&tokens, i + 3);
+        i = next;
+
+        let ip_address = match address.parse::<IpAddr>() {
+            Ok(ip) => ip,
+            Err(_) => {
+                errors.push(format!("Invalid lease address '{}'", address));
+                continue;
+            }
+        };
+
+        let mut lease = DhcpLease {
+            ip_address,
+            mac_address: None,
+            hostname: None,
+            lease_start: None,
+            lease_end: None,
+            active: false,
+        };
+        let mut max_life: Option<i64> = None;
+
+        for statement in statements {
+            match statement.as_slice() {
+                [keyword, rest @ ..] if keyword == "starts" => {
+                    lease.lease_start = parse_isc_time(rest);
+                }
+                [keyword, rest @ ..] if keyword == "ends" => {
+                    lease.lease_end = parse_isc_time(rest);
+                }
+                [keyword, state, value, ..] if keyword == "binding" && state == "state" => {
+                    lease.active = value == "active";
+                }
+                [keyword, kind, mac, ..] if keyword == "hardware" && kind == "ethernet" => {
+                    lease.mac_address = normalize_mac(mac);
+                }
+                [keyword, name, ..] if keyword == "client-hostname" => {
+                    lease.hostname = non_empty(name);
+                }
+                [keyword, value, ..] if keyword == "max-life" => {
+                    max_life = value.parse().ok();
+                }
+                _ => {}
+            }
+        }
+
+        // dhcpd6 iaaddr 블록에는 starts가 없으므로 ends - max-life로 계산
+        if lease.lease_start.is_none()
+            && let (Some(end), Some(life)) = (lease.lease_end, max_life)
+        {
+            let start = TimeDelta::try_seconds(life).and_then(|life| end.checked_sub_signed(life));
+            let Some(start) = start else {
+                errors.push(format!(
+                    "Lease {}: max-life {} is out of range",
+                    ip_address, life
+                ));
+                continue;
+            };
+            lease.lease_start = Some(start);
+        }
+
+        match index_by_ip.get(&ip_address) {
+            Some(&idx) => leases[idx] = lease,
+            None => {
+                index_by_ip.insert(ip_address, leases.len());
+                leases.push(lease);
+            }
+        }
+    }
+
+    ParsedLeases { leases, errors }
+}
+
+/// `{` 다음부터 짝이 맞는 `}`까지의 문장을 모은다. 중첩 블록의 문장은 무시한다.
+fn collect_block(tokens: &[String], start: usize) -> (Vec<Vec<String>>, usize) {
+    let mut statements = Vec::new();
+    let mut current = Vec::new();
+    let mut depth = 0;
+    let mut i = start;
+
+    while i < tokens.len() {
+        match tokens[i].as_str() {
+            "{" => {
+                depth += 1;
+                current.clear();
+            }
+            "}" if depth == 0 => return (statements, i + 1),
+            "}" => {
+                depth -= 1;
+                current.clear();
+            }
+            ";" => {
+                if depth == 0 && !current.is_empty() {
+                    statements.push(std::mem::take(&mut current));
+                }
+                current.clear();
+            }
+            token => current.push(token.to_string()),
+        }
+        i += 1;
+    }
+
+    (statements, i)
+}
+
+fn tokenize_isc(content: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = content.chars().peekable();
+
+    let flush = |current: &mut String, tokens: &mut Vec<String>| {
+        if !current.is_empty() {
+            tokens.push(std::mem::take(current));
+        }
+    };
+
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => {
+                flush(&mut current, &mut tokens);
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                flush(&mut current, &mut tokens);
+                let mut quoted = String::new();
+                while let Some(next) = chars.next() {
+                    match next {
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                quoted.push(escaped);
+                            }
+                        }
+                        '"' => break,
+                        other => quoted.push(other),
+                    }
+                }
+                tokens.push(quoted);
+            }
+            '{' | '}' | ';' => {
+                flush(&mut current, &mut tokens);
+                tokens.push(c.to_string());
+            }
+            c if c.is_whitespace() => flush(&mut current, &mut tokens),
+            other => current.push(other),
+        }
+    }
+    flush(&mut current, &mut tokens);
+
+    tokens
+}
+
+/// `4 2024/01/04 10:00:00`(UTC), `epoch 1704362400`, `never` 형식을 지원
+fn parse_isc_time(parts: &[String]) -> Option<DateTime<Utc>> {
+    match parts {
+        [epoch, seconds, ..] if epoch == "epoch" => {
+            DateTime::from_timestamp(seconds.parse().ok()?, 0)
+        }
+        [_weekday, date, time, ..] => {
+            NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y/%m/%d %H:%M:%S")
+                .ok()
+                .map(|naive| naive.and_utc())
+        }
+        _ => None,
+    }
+}
+
+/// Kea memfile CSV 파싱 (lease4/lease6 공통, 헤더 이름으로 열을 찾는다)
+fn parse_kea_csv(content: &str) -> ParsedLeases {
+    let mut lines = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+    let mut leases: Vec<DhcpLease> = Vec::new();
+    let mut index_by_ip: HashMap<IpAddr, usize> = HashMap::new();
+    let mut errors = Vec::new();
+
+    let Some(header) = lines.next() else {
+        return ParsedLeases { leases, errors };
+    };
+    let columns: HashMap<&str, usize> = header
+        .split(',')
+        .enumerate()
+        .map(|(idx, name)| (name.trim(), idx))
+        .collect();
+
+    if !columns.contains_key("address") {
+        errors.push("Kea CSV header must contain an 'address' column".to_string());
+        return ParsedLeases { leases, errors };
+    }
+
+    for (line_no, line) in lines.enumerate() {
+        let fields: Vec<&str> = line.split(',').collect();
+        let field = |name: &str| {
+            columns
+                .get(name)
+                .and_then(|idx| fields.get(*idx))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+
+        let Some(address) = field("address") else {
+            errors.push(format!("Line {}: missing address", line_no + 2));
+            continue;
+        };
+        let ip_address = match address.parse::<IpAddr>() {
+            Ok(ip) => ip,
+            Err(_) => {
+                errors.push(format!(
+                    "Line {}: invalid address '{}'",
+                    line_no + 2,
+                    address
+                ));
+                continue;
+            }
+        };
+
+        let expire = field("expire").and_then(|v| v.parse::<i64>().ok());
+        let valid_lifetime = field("valid_lifetime").and_then(|v| v.parse::<i64>().ok());
+        // Kea는 만료 없는 임대를 valid_lifetime 0xffffffff로 기록
+        let infinite = valid_lifetime == Some(u32::MAX as i64);
+
+        let lease_end = if infinite {
+            None
+        } else {
+            expire.and_then(|secs| DateTime::from_timestamp(secs, 0))
+        };
+        let lease_start = match (expire, valid_lifetime) {
+            (Some(expire), Some(lifetime)) if !infinite => match expire.checked_sub(lifetime) {
+                Some(start) => DateTime::from_timestamp(start, 0),
+                None => {
+                    errors.push(format!(
+                        "Line {}: valid_lifetime {} is out of range",
+                        line_no + 2,
+                        lifetime
+                    ));
+                    continue;
+                }
+            },
+            _ => None,
+        };
+
+        let lease = DhcpLease {
+            ip_address,
+            mac_address: field("hwaddr").and_then(normalize_mac),
+            hostname: field("hostname")
+                .map(unescape_kea)
+                .and_then(|h| non_empty(&h)),
+            lease_start,
+            lease_end,
+            // state: 0 = default(assigned), 1 = declined, 2 = expired-reclaimed
+            active: field("state").is_none_or(|state| state == "0"),
+        };
+
+        match index_by_ip.get(&ip_address) {
+            Some(&idx) => leases[idx] = lease,
+            None => {
+                index_by_ip.insert(ip_address, leases.len());
+                leases.push(lease);
+            }
+        }
+    }
+
+    ParsedLeases { leases, errors }
+}
+
+/// Kea는 필드 안의 쉼표를 `&#x2c`로 이스케이프한다
+fn unescape_kea(value: &str) -> String {
+    value.replace("&#x2c", ",")
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let trimmed = value.trim().trim_end_matches('.');
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// `00:11:22:aa:bb:cc` 형식으로 정규화. MACADDR 컬럼에 넣을 수 없는 값은 버린다.
+pub fn normalize_mac(value: &str) -> Option<String> {
+    let hex: String = value
+        .chars()
+        .filter(|c| !matches!(c, ':' | '-' | '.'))
+        .collect::<String>()
+        .to_lowercase();
+
+    if hex.len() != 12 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(
+        hex.as_bytes()
+            .chunks(2)
+            .map(|pair| String::from_utf8_lossy(pair).into_owned())
+            .collect::<Vec<_>>()
+            .join(":"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isc_last_block_wins() {
+        let content = r#"
+# The format of this file is documented in the dhcpd.leases(5) manual page.
+lease 10.0.0.5 {
+  starts 4 2024/01/04 10:00:00;
+  ends 4 2024/01/04 12:00:00;
+  binding state free;
+}
+lease 10.0.0.5 {
+  starts 4 2024/01/04 11:00:00;
+  ends epoch 1704376800;
+  binding state active;
+  next binding state free;
+  hardware ethernet 00:11:22:AA:BB:CC;
+  client-hostname "host-a";
+}
+"#;
+        let parsed = parse_leases(content, LeaseFormat::detect(content));
+
+        assert!(parsed.errors.is_empty());
+        assert_eq!(parsed.leases.len(), 1);
+        let lease = &parsed.leases[0];
+        assert_eq!(lease.ip_address, "10.0.0.5".parse::<IpAddr>().unwrap());
+        assert!(lease.active);
+        assert_eq!(lease.mac_address.as_deref(), Some("00:11:22:aa:bb:cc"));
+        assert_eq!(lease.hostname.as_deref(), Some("host-a"));
+        assert_eq!(
+            lease.lease_start,
+            Some(
+                DateTime::parse_from_rfc3339("2024-01-04T11:00:00Z")
+                    .unwrap()
+                    .to_utc()
+            )
+        );
+        assert_eq!(lease.lease_end, DateTime::from_timestamp(1704376800, 0));
+    }
+
+    #[test]
+    fn isc_v6_start_from_max_life() {
+        let content = r#"
+ia-na "\001\000\000\000" {
+  cltt 4 2024/01/04 10:00:00;
+  iaaddr 2001:db8::10 {
+    binding state active;
+    preferred-life 3600;
+    max-life 7200;
+    ends 4 2024/01/04 12:00:00;
+  }
+}
+"#;
+        let parsed = parse_leases(content, LeaseFormat::Isc);
+
+        assert!(parsed.errors.is_empty());
+        let lease = &parsed.leases[0];
+        assert_eq!(
+            lease.lease_start,
+            Some(
+                DateTime::parse_from_rfc3339("2024-01-04T10:00:00Z")
+                    .unwrap()
+                    .to_utc()
+            )
+        );
+    }
+
+    #[test]
+    fn isc_out_of_range_max_life_is_skipped() {
+        let content = r#"
+iaaddr 2001:db8::10 {
+  max-life 9223372036854775807;
+  ends 4 2024/01/04 12:00:00;
+}
+lease 10.0.0.6 {
+  binding state active;
+}
+"#;
+        let parsed = parse_leases(content, LeaseFormat::Isc);
+
+        assert_eq!(parsed.leases.len(), 1);
+        assert_eq!(parsed.errors.len(), 1);
+        assert!(parsed.errors[0].contains("max-life"));
+    }
+
+    #[test]
+    fn kea_csv_leases() {
+        let content = "\
+address,hwaddr,client_id,valid_lifetime,expire,subnet_id,fqdn_fwd,fqdn_rev,hostname,state,user_context
+10.0.0.7,00-11-22-aa-bb-cc,,3600,1704376800,1,0,0,host&#x2cb,0,
+10.0.0.8,,,4294967295,1704376800,1,0,0,,0,
+10.0.0.9,,,3600,1704376800,1,0,0,,2,
+not-an-ip,,,3600,1704376800,1,0,0,,0,
+";
+        let parsed = parse_leases(content, LeaseFormat::detect(content));
+
+        assert_eq!(parsed.leases.len(), 3);
+        assert_eq!(parsed.errors, vec!["Line 5: invalid address 'not-an-ip'"]);
+
+        let first = &parsed.leases[0];
+        assert_eq!(first.mac_address.as_deref(), Some("00:11:22:aa:bb:cc"));
+        assert_eq!(first.hostname.as_deref(), Some("host,b"));
+        assert_eq!(first.lease_start, DateTime::from_timestamp(1704373200, 0));
+
+        let infinite = &parsed.leases[1];
+        assert_eq!(infinite.lease_end, None);
+        assert!(infinite.is_current(Utc::now()));
+
+        assert!(!parsed.leases[2].active);
+    }
+
+    #[test]
+    fn kea_overflowing_lifetime_is_skipped() {
+        let content = "\
+address,valid_lifetime,expire
+10.0.0.7,9223372036854775807,-10
+";
+        let parsed = parse_leases(content, LeaseFormat::KeaCsv);
+
+        assert!(parsed.leases.is_empty());
+        assert_eq!(parsed.errors.len(), 1);
+    }
+
+    #[test]
+    fn normalize_mac_formats() {
+        assert_eq!(
+            normalize_mac("0011.22aa.bbcc").as_deref(),
+            Some("00:11:22:aa:bb:cc")
+        );
+        assert_eq!(normalize_mac("00:11:22"), None);
+        assert_eq!(normalize_mac("zz:11:22:aa:bb:cc"), None);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::service::audit::RESOURCE_IP_RANGE;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::notification::channel::CHANNEL_WEB;
+use crate::service::notification::{
+    CATEGORY_DHCP_LEASE_EXPIRED, CreateNotificationParams, service_create_notification,
+};
+use sea_orm::{ConnectionTrait, DatabaseConnection, FromQueryResult, Statement};
+use std::collections::BTreeMap;
+use tracing::warn;
+use uuid::Uuid;
+
+/// 알림 payload에 담을 대역별 최대 주소 수
+const MAX_ADDRESSES_PER_NOTIFICATION: usize = 100;
+
+#[derive(FromQueryResult)]
+struct ExpiredLease {
+    ip_range_id: Uuid,
+    range_name: String,
+    /// 대역이 속한 사무실의 테넌트 (ip_ranges.tenant_id는 사무실을 가리킨다)
+    tenant_id: Option<Uuid>,
+    ip_address: String,
+    hostname: Option<String>,
+    mac_address: Option<String>,
+}
+
+pub struct LeaseSweepResult {
+    pub expired: usize,
+    pub notifications: usize,
+}
+
+/// lease_end가 지난 `allocated` 주소를 `expired`로 바꾸고 대역별로 알림을 하나씩 적재한다.
+/// 행 단위 UPDATE이므로 여러 인스턴스가 동시에 실행해도 같은 주소가 두 번 알림되지 않는다.
+/// owner_tenant_id가 있으면 그 테넌트의 대역만 처리한다 (없으면 백그라운드 작업처럼 전체).
+pub async fn service_sweep_expired_leases(
+    conn: &DatabaseConnection,
+    owner_tenant_id: Option<&Uuid>,
+) -> ServiceResult<LeaseSweepResult> {
+    let sql = r#"
+        UPDATE ip_addresses a
+        SET status = 'expired', updated_at = now()
+        FROM ip_ranges r
+        WHERE r.id = a.ip_range_id
+          AND a.is_active = true
+          AND a.status = 'allocated'
+          AND a.lease_end IS NOT NULL
+          AND a.lease_end < now()
+          AND ($1::uuid IS NULL OR r.tenant_id IN (SELECT id FROM offices WHERE tenant_id = $1))
+        RETURNING
+            a.ip_range_id,
+            r.name as range_name,
+            (SELECT o.tenant_id FROM offices o WHERE o.id = r.tenant_id) as tenant_id,
+            HOST(a.ip_address) as ip_address,
+            a.hostname,
+            a.mac_address::text as mac_address
+    "#;
+
+    let rows = conn
+        .query_all(Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            sql,
+            vec![owner_tenant_id.copied().into()],
+        ))
+        .await?;
+
+    let mut by_range: BTreeMap<Uuid, Vec<ExpiredLease>> = BTreeMap::new();
+    for row in rows {
+        let lease = ExpiredLease::from_query_result(&row, "")
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+        by_range.entry(lease.ip_range_id).or_default().push(lease);
+    }
+
+    let expired = by_range.values().map(Vec::len).sum();
+    let mut notifications = 0;
+
+    for (ip_range_id, leases) in by_range {
+        let first = &leases[0];
+        let addresses: Vec<serde_json::Value> = leases
+            .iter()
+            .take(MAX_ADDRESSES_PER_NOTIFICATION)
+            .map(|lease| {
+                serde_json::json!({
+                    "ip_address": lease.ip_address,
+                    "hostname": lease.hostname,
+                    "mac_address": lease.mac_address,
+                })
+            })
+            .collect();
+
+        let params = CreateNotificationParams {
+            tenant_id: first.tenant_id,
+            channel: CHANNEL_WEB.to_string(),
+            category: Some(CATEGORY_DHCP_LEASE_EXPIRED.to_string()),
+            title: Some(format!("DHCP leases expired in {}", first.range_name)),
+            message: Some(format!(
+                "{} DHCP lease(s) expired in IP range '{}'",
+                leases.len(),
+                first.range_name
+            )),
+            payload: Some(serde_json::json!({
+                "ip_range_id": ip_range_id,
+                "resource_type": RESOURCE_IP_RANGE,
+                "resource_id": ip_range_id,
+                "link": format!("/ipam/ip-range/{}", ip_range_id),
+                "expired_count": leases.len(),
+                "addresses": addresses,
+            })),
+            scheduled_at: None,
+            max_retries: None,
+        };
+
+        // 상태 변경은 이미 반영되었으므로 알림 적재 실패는 기록만 한다
+        match service_create_notification(conn, params).await {
+            Ok(_) => notifications += 1,
+            Err(e) => warn!(
+                "Failed to queue lease expiry notification for range {}: {:?}",
+                ip_range_id, e
+            ),
+        }
+    }
+
+    Ok(LeaseSweepResult {
+        expired,
+        notifications,
+    })
+}
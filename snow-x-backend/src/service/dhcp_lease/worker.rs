@@ -0,0 +1,136 @@
+use crate::config::db_config::DbConfig;
+use crate::service::dhcp_lease::import::service_import_dhcp_leases;
+use crate::service::dhcp_lease::parser::LeaseFormat;
+use crate::service::dhcp_lease::sweep::service_sweep_expired_leases;
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use tracing::{error, info, warn};
+
+struct WatchedLeaseFile {
+    path: PathBuf,
+    format: Option<LeaseFormat>,
+}
+
+impl WatchedLeaseFile {
+    /// `isc:/var/lib/dhcp/dhcpd.leases`, `kea:/var/lib/kea/kea-leases4.csv` 또는 경로만
+    fn parse(entry: &str) -> Option<Self> {
+        let (format, path) = match entry.split_once(':') {
+            Some((prefix, path)) => match LeaseFormat::parse(prefix) {
+                Ok(format) => (Some(format), path),
+                Err(_) => (None, entry),
+            },
+            None => (None, entry),
+        };
+
+        let path = path.trim();
+        (!path.is_empty()).then(|| WatchedLeaseFile {
+            path: PathBuf::from(path),
+            format,
+        })
+    }
+}
+
+/// 감시 경로 가져오기와 만료 임대 정리를 백그라운드 태스크로 실행한다.
+pub fn spawn_dhcp_lease_workers(conn: DatabaseConnection) {
+    let config = DbConfig::get();
+
+    let watched: Vec<WatchedLeaseFile> = config
+        .dhcp_lease_watch_paths
+        .iter()
+        .filter_map(|entry| {
+            let parsed = WatchedLeaseFile::parse(entry);
+            if parsed.is_none() {
+                warn!("Ignoring invalid DHCP_LEASE_WATCH_PATHS entry '{}'", entry);
+            }
+            parsed
+        })
+        .collect();
+
+    if !watched.is_empty() {
+        let interval = Duration::from_secs(config.dhcp_lease_watch_interval.max(1));
+        tokio::spawn(watch_lease_files(conn.clone(), watched, interval));
+    }
+
+    if config.dhcp_lease_sweep_interval > 0 {
+        let interval = Duration::from_secs(config.dhcp_lease_sweep_interval);
+        tokio::spawn(sweep_expired_leases(conn, interval));
+    }
+}
+
+/// 파일 수정 시각이 바뀐 경우에만 다시 가져온다.
+async fn watch_lease_files(
+    conn: DatabaseConnection,
+    files: Vec<WatchedLeaseFile>,
+    interval: Duration,
+) {
+    let mut last_modified: HashMap<PathBuf, SystemTime> = HashMap::new();
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+
+        for file in &files {
+            let modified = match tokio::fs::metadata(&file.path)
+                .await
+                .and_then(|meta| meta.modified())
+            {
+                Ok(modified) => modified,
+                Err(e) => {
+                    warn!("Cannot stat DHCP lease file {}: {}", file.path.display(), e);
+                    continue;
+                }
+            };
+
+            if last_modified.get(&file.path) == Some(&modified) {
+                continue;
+            }
+
+            let content = match tokio::fs::read_to_string(&file.path).await {
+                Ok(content) => content,
+                Err(e) => {
+                    warn!("Cannot read DHCP lease file {}: {}", file.path.display(), e);
+                    continue;
+                }
+            };
+
+            match service_import_dhcp_leases(&conn, &content, file.format, None, None, None).await {
+                Ok(summary) => {
+                    last_modified.insert(file.path.clone(), modified);
+                    info!(
+                        "Imported DHCP leases from {}: total={}, created={}, updated={}, skipped={}, errors={}",
+                        file.path.display(),
+                        summary.total,
+                        summary.created,
+                        summary.updated,
+                        summary.skipped,
+                        summary.errors.len()
+                    );
+                }
+                Err(e) => error!(
+                    "Failed to import DHCP leases from {}: {:?}",
+                    file.path.display(),
+                    e
+                ),
+            }
+        }
+    }
+}
+
+async fn sweep_expired_leases(conn: DatabaseConnection, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+
+        match service_sweep_expired_leases(&conn, None).await {
+            Ok(result) if result.expired > 0 => info!(
+                "Expired {} DHCP lease(s), queued {} notification(s)",
+                result.expired, result.notifications
+            ),
+            Ok(_) => {}
+            Err(e) => error!("Failed to sweep expired DHCP leases: {:?}", e),
+        }
+    }
+}
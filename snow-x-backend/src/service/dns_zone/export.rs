@@ -0,0 +1,362 @@
+use crate::config::db_config::DbConfig;
+use crate::entity::{dns_zone_serials, ip_ranges};
+use crate::service::dns_zone::zone_file::{
+    HostEntry, ZONE_KIND_FORWARD, ZoneFile, ZoneKind, ZoneSoa, build_forward_zone,
+    build_reverse_zone, device_label, diff_lines, host_fqdn, next_serial, normalize_domain,
+    reverse_zone_name, reverse_zones_for,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::ip_range::get_ip_range_by_id::service_get_ip_range_by_id;
+use crate::utils::ip_math::{IpNetwork, parse_ip};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    FromQueryResult, QueryFilter, QueryOrder, QuerySelect, Set, Statement, TransactionTrait,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct DnsZoneSummary {
+    pub zone_name: String,
+    pub kind: ZoneKind,
+    /// 아직 한 번도 생성하지 않은 zone이면 None
+    pub serial: Option<i64>,
+    pub generated_at: Option<DateTimeWithTimeZone>,
+}
+
+pub struct DnsZoneListResult {
+    pub ip_range: ip_ranges::Model,
+    pub domain: String,
+    pub zones: Vec<DnsZoneSummary>,
+}
+
+pub struct DnsZoneExport {
+    pub zone_name: String,
+    pub serial: i64,
+    pub changed: bool,
+    pub content: String,
+}
+
+pub struct DnsZoneDiff {
+    pub zone_name: String,
+    pub kind: ZoneKind,
+    pub current_serial: Option<i64>,
+    pub next_serial: i64,
+    pub changed: bool,
+    pub diff: Vec<String>,
+}
+
+/// 대역에서 생성할 수 있는 정방향/역방향 zone 목록과 마지막으로 생성한 serial
+pub async fn service_list_dns_zones(
+    conn: &DatabaseConnection,
+    ip_range_id: &Uuid,
+    domain: Option<&str>,
+) -> ServiceResult<DnsZoneListResult> {
+    let ip_range = service_get_ip_range_by_id(conn, ip_range_id).await?;
+    let network = range_network(&ip_range)?;
+    let domain = resolve_domain(conn, ip_range_id, domain).await?;
+
+    let stored: HashMap<String, dns_zone_serials::Model> = dns_zone_serials::Entity::find()
+        .filter(dns_zone_serials::Column::IpRangeId.eq(*ip_range_id))
+        .all(conn)
+        .await?
+        .into_iter()
+        .map(|row| (row.zone_name.clone(), row))
+        .collect();
+
+    let zone_names = std::iter::once((domain.clone(), ZoneKind::Forward)).chain(
+        reverse_zones_for(&network)
+            .into_iter()
+            .map(|zone| (reverse_zone_name(&zone), ZoneKind::Reverse)),
+    );
+
+    let zones = zone_names
+        .map(|(zone_name, kind)| {
+            let previous = stored.get(&zone_name);
+            DnsZoneSummary {
+                serial: previous.map(|row| row.serial),
+                generated_at: previous.map(|row| row.updated_at),
+                zone_name,
+                kind,
+            }
+        })
+        .collect();
+
+    Ok(DnsZoneListResult {
+        ip_range,
+        domain,
+        zones,
+    })
+}
+
+/// zone 파일을 생성한다. 이전에 생성한 내용과 달라졌을 때만 serial을 올리고 기록한다.
+pub async fn service_export_dns_zone(
+    conn: &DatabaseConnection,
+    ip_range_id: &Uuid,
+    zone_name: &str,
+    domain: Option<&str>,
+    generated_by: &Uuid,
+) -> ServiceResult<DnsZoneExport> {
+    let zone = build_zone(conn, ip_range_id, zone_name, domain).await?;
+
+    let txn = conn.begin().await?;
+
+    let previous = dns_zone_serials::Entity::find()
+        .filter(dns_zone_serials::Column::IpRangeId.eq(*ip_range_id))
+        .filter(dns_zone_serials::Column::ZoneName.eq(zone.zone_name.as_str()))
+        .lock_exclusive()
+        .one(&txn)
+        .await?;
+
+    let now = chrono::Utc::now();
+    let (serial, changed) = match previous {
+        Some(previous) if previous.content_hash == zone.content_hash => (previous.serial, false),
+        Some(previous) => {
+            let serial = next_serial(Some(previous.serial), now);
+            let mut active: dns_zone_serials::ActiveModel = previous.into();
+            active.serial = Set(serial);
+            active.content_hash = Set(zone.content_hash.clone());
+            active.content = Set(zone.render(serial));
+            active.generated_by = Set(Some(*generated_by));
+            active.updated_at = Set(now.into());
+            active.update(&txn).await?;
+            (serial, true)
+        }
+        None => {
+            let serial = next_serial(None, now);
+            dns_zone_serials::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                ip_range_id: Set(*ip_range_id),
+                zone_name: Set(zone.zone_name.clone()),
+                zone_kind: Set(zone.kind.as_str().to_string()),
+                serial: Set(serial),
+                content_hash: Set(zone.content_hash.clone()),
+                content: Set(zone.render(serial)),
+                generated_by: Set(Some(*generated_by)),
+                created_at: Set(now.into()),
+                updated_at: Set(now.into()),
+            }
+            .insert(&txn)
+            .await?;
+            (serial, true)
+        }
+    };
+
+    txn.commit().await?;
+
+    Ok(DnsZoneExport {
+        content: zone.render(serial),
+        zone_name: zone.zone_name,
+        serial,
+        changed,
+    })
+}
+
+/// 저장하지 않고 다음 생성 결과를 이전 생성본과 비교한다 (dry-run).
+pub async fn service_diff_dns_zone(
+    conn: &DatabaseConnection,
+    ip_range_id: &Uuid,
+    zone_name: &str,
+    domain: Option<&str>,
+) -> ServiceResult<DnsZoneDiff> {
+    let zone = build_zone(conn, ip_range_id, zone_name, domain).await?;
+
+    let previous = dns_zone_serials::Entity::find()
+        .filter(dns_zone_serials::Column::IpRangeId.eq(*ip_range_id))
+        .filter(dns_zone_serials::Column::ZoneName.eq(zone.zone_name.as_str()))
+        .one(conn)
+        .await?;
+
+    let (current_serial, next, changed, previous_content) = match previous {
+        Some(previous) if previous.content_hash == zone.content_hash => (
+            Some(previous.serial),
+            previous.serial,
+            false,
+            previous.content,
+        ),
+        Some(previous) => (
+            Some(previous.serial),
+            next_serial(Some(previous.serial), chrono::Utc::now()),
+            true,
+            previous.content,
+        ),
+        None => (
+            None,
+            next_serial(None, chrono::Utc::now()),
+            true,
+            String::new(),
+        ),
+    };
+
+    Ok(DnsZoneDiff {
+        diff: diff_lines(&previous_content, &zone.render(next)),
+        zone_name: zone.zone_name,
+        kind: zone.kind,
+        current_serial,
+        next_serial: next,
+        changed,
+    })
+}
+
+/// 요청한 zone 이름이 대역의 정방향 도메인 또는 역방향 zone 중 하나인지 확인하고 zone을 만든다.
+async fn build_zone(
+    conn: &DatabaseConnection,
+    ip_range_id: &Uuid,
+    zone_name: &str,
+    domain: Option<&str>,
+) -> ServiceResult<ZoneFile> {
+    let ip_range = service_get_ip_range_by_id(conn, ip_range_id).await?;
+    let network = range_network(&ip_range)?;
+    let zone_name = normalize_domain(zone_name)
+        .ok_or_else(|| Errors::BadRequestError(format!("Invalid zone name '{}'", zone_name)))?;
+
+    // 역방향 zone 요청이면 domain은 PTR 대상 이름을 만들 때만 사용
+    let reverse_zone = reverse_zones_for(&network)
+        .into_iter()
+        .find(|zone| reverse_zone_name(zone) == zone_name);
+    let domain = match (&reverse_zone, domain) {
+        (None, None) => zone_name.clone(),
+        _ => resolve_domain(conn, ip_range_id, domain).await?,
+    };
+    if reverse_zone.is_none() && (domain != zone_name || zone_name.ends_with(".arpa")) {
+        return Err(Errors::NotFound(format!(
+            "Zone '{}' is not generated for this IP range",
+            zone_name
+        )));
+    }
+
+    let hosts = fetch_host_entries(conn, ip_range_id, &domain).await?;
+    let header = format!(
+        "; Generated by snow-x IPAM\n; IP range: {} ({}/{})\n",
+        ip_range.name, ip_range.network_address, ip_range.subnet_mask
+    );
+    let soa = zone_soa(&domain);
+
+    Ok(match reverse_zone {
+        Some(zone) => build_reverse_zone(&zone, header, soa, &hosts),
+        None => build_forward_zone(&zone_name, header, soa, &hosts),
+    })
+}
+
+/// 정방향 도메인 결정 순서: 요청 값 → 이 대역에서 마지막으로 생성한 정방향 zone → DNS_ZONE_DEFAULT_DOMAIN
+async fn resolve_domain(
+    conn: &DatabaseConnection,
+    ip_range_id: &Uuid,
+    domain: Option<&str>,
+) -> ServiceResult<String> {
+    if let Some(domain) = domain.filter(|d| !d.trim().is_empty()) {
+        return normalize_domain(domain)
+            .ok_or_else(|| Errors::BadRequestError(format!("Invalid domain '{}'", domain)));
+    }
+
+    let last_forward = dns_zone_serials::Entity::find()
+        .filter(dns_zone_serials::Column::IpRangeId.eq(*ip_range_id))
+        .filter(dns_zone_serials::Column::ZoneKind.eq(ZONE_KIND_FORWARD))
+        .order_by_desc(dns_zone_serials::Column::UpdatedAt)
+        .one(conn)
+        .await?;
+    if let Some(row) = last_forward {
+        return Ok(row.zone_name);
+    }
+
+    DbConfig::get()
+        .dns_zone_default_domain
+        .as_deref()
+        .and_then(normalize_domain)
+        .ok_or_else(|| {
+            Errors::BadRequestError(
+                "domain is required (no previous forward zone or DNS_ZONE_DEFAULT_DOMAIN)"
+                    .to_string(),
+            )
+        })
+}
+
+fn zone_soa(domain: &str) -> ZoneSoa {
+    let config = DbConfig::get();
+
+    let primary_ns = config
+        .dns_zone_primary_ns
+        .as_deref()
+        .and_then(normalize_domain)
+        .unwrap_or_else(|| format!("ns1.{}", domain));
+    // SOA RNAME은 이메일의 @를 점으로 바꾼 형식
+    let hostmaster = config
+        .dns_zone_hostmaster
+        .as_deref()
+        .and_then(|mail| normalize_domain(&mail.replacen('@', ".", 1)))
+        .unwrap_or_else(|| format!("hostmaster.{}", domain));
+
+    ZoneSoa {
+        primary_ns: format!("{}.", primary_ns),
+        hostmaster: format!("{}.", hostmaster),
+        ttl: config.dns_zone_ttl,
+    }
+}
+
+fn range_network(range: &ip_ranges::Model) -> ServiceResult<IpNetwork> {
+    IpNetwork::parse(&range.network_address, range.subnet_mask, range.ip_version)
+}
+
+/// 대역의 사용 중 주소와 이름. hostname이 없으면 연결된 장비(primary 우선) 이름을 사용한다.
+async fn fetch_host_entries<C>(
+    conn: &C,
+    ip_range_id: &Uuid,
+    domain: &str,
+) -> ServiceResult<Vec<HostEntry>>
+where
+    C: ConnectionTrait,
+{
+    #[derive(FromQueryResult)]
+    struct HostRow {
+        ip_address: String,
+        hostname: Option<String>,
+        device_name: Option<String>,
+    }
+
+    let sql = r#"
+        SELECT
+            HOST(a.ip_address) as ip_address,
+            a.hostname,
+            d.name as device_name
+        FROM ip_addresses a
+        LEFT JOIN LATERAL (
+            SELECT dv.name
+            FROM device_ip_mappings m
+            JOIN devices dv ON dv.id = m.device_id AND dv.is_active = true
+            WHERE m.ip_address_id = a.id
+            ORDER BY m.is_primary DESC, m.created_at
+            LIMIT 1
+        ) d ON true
+        WHERE a.ip_range_id = $1
+          AND a.is_active = true
+          AND a.status IN ('allocated', 'reserved')
+        ORDER BY a.ip_address
+    "#;
+
+    let rows = conn
+        .query_all(Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            sql,
+            vec![(*ip_range_id).into()],
+        ))
+        .await?;
+
+    let mut hosts = Vec::with_capacity(rows.len());
+    for row in rows {
+        let row = HostRow::from_query_result(&row, "")
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+        let name = row
+            .hostname
+            .filter(|h| !h.trim().is_empty())
+            .or_else(|| row.device_name.as_deref().and_then(device_label));
+        let (Some(name), Ok(ip_address)) = (name, parse_ip(&row.ip_address)) else {
+            continue;
+        };
+        if let Some(fqdn) = host_fqdn(&name, domain) {
+            hosts.push(HostEntry { ip_address, fqdn });
+        }
+    }
+
+    Ok(hosts)
+}
@@ -0,0 +1,322 @@
+use crate::utils::ip_math::{IpFamily, IpNetwork, ip_to_number};
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+
+pub const ZONE_KIND_FORWARD: &str = "forward";
+pub const ZONE_KIND_REVERSE: &str = "reverse";
+
+// SOA 타이머 (RFC 1912 권장값)
+const SOA_REFRESH: u32 = 3600;
+const SOA_RETRY: u32 = 900;
+const SOA_EXPIRE: u32 = 1_209_600;
+const SOA_MINIMUM: u32 = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneKind {
+    Forward,
+    Reverse,
+}
+
+impl ZoneKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ZoneKind::Forward => ZONE_KIND_FORWARD,
+            ZoneKind::Reverse => ZONE_KIND_REVERSE,
+        }
+    }
+}
+
+pub struct ZoneSoa {
+    pub primary_ns: String,
+    pub hostmaster: String,
+    pub ttl: u32,
+}
+
+/// 주소 하나에 대응하는 DNS 이름 (정규화된 FQDN, 끝의 점 제외)
+pub struct HostEntry {
+    pub ip_address: IpAddr,
+    pub fqdn: String,
+}
+
+pub struct ZoneFile {
+    pub zone_name: String,
+    pub kind: ZoneKind,
+    /// SOA serial 줄을 제외한 내용의 SHA-256 (변경 감지용)
+    pub content_hash: String,
+    header: String,
+    soa: ZoneSoa,
+    records: Vec<String>,
+}
+
+impl ZoneFile {
+    pub fn render(&self, serial: i64) -> String {
+        let mut out = String::new();
+        out.push_str(&self.header);
+        out.push_str(&format!("$ORIGIN {}.\n", self.zone_name));
+        out.push_str(&format!("$TTL {}\n", self.soa.ttl));
+        out.push_str(&format!(
+            "@\tIN\tSOA\t{} {} (\n\t\t{} ; serial\n\t\t{} ; refresh\n\t\t{} ; retry\n\t\t{} ; expire\n\t\t{} ) ; minimum\n",
+            self.soa.primary_ns,
+            self.soa.hostmaster,
+            serial,
+            SOA_REFRESH,
+            SOA_RETRY,
+            SOA_EXPIRE,
+            SOA_MINIMUM
+        ));
+        out.push_str(&format!("@\tIN\tNS\t{}\n", self.soa.primary_ns));
+        for record in &self.records {
+            out.push_str(record);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// 날짜 기반 serial (YYYYMMDDnn). 이전 serial보다 항상 커지도록 보정한다.
+pub fn next_serial(previous: Option<i64>, now: DateTime<Utc>) -> i64 {
+    let base = now.format("%Y%m%d").to_string().parse::<i64>().unwrap_or(0) * 100;
+    match previous {
+        Some(previous) => (previous + 1).max(base),
+        None => base,
+    }
+}
+
+/// 정방향 zone: zone 도메인 아래의 이름만 A/AAAA 레코드로 기록한다.
+pub fn build_forward_zone(
+    zone_name: &str,
+    header: String,
+    soa: ZoneSoa,
+    hosts: &[HostEntry],
+) -> ZoneFile {
+    let mut records: Vec<(String, u128, String)> = hosts
+        .iter()
+        .filter_map(|host| {
+            let owner = relative_name(&host.fqdn, zone_name)?;
+            let rtype = match host.ip_address {
+                IpAddr::V4(_) => "A",
+                IpAddr::V6(_) => "AAAA",
+            };
+            Some((
+                owner.clone(),
+                ip_to_number(&host.ip_address),
+                format!("{}\tIN\t{}\t{}", owner, rtype, host.ip_address),
+            ))
+        })
+        .collect();
+    records.sort();
+    records.dedup_by(|a, b| a.2 == b.2);
+
+    finish(
+        zone_name,
+        ZoneKind::Forward,
+        header,
+        soa,
+        records.into_iter().map(|(_, _, line)| line).collect(),
+    )
+}
+
+/// 역방향 zone: zone 블록에 속한 주소마다 PTR 레코드를 만든다.
+pub fn build_reverse_zone(
+    zone: &IpNetwork,
+    header: String,
+    soa: ZoneSoa,
+    hosts: &[HostEntry],
+) -> ZoneFile {
+    let zone_name = reverse_zone_name(zone);
+    let mut seen: HashSet<u128> = HashSet::new();
+    let mut records: Vec<(u128, String)> = hosts
+        .iter()
+        .filter(|host| zone.contains_ip(&host.ip_address))
+        .filter_map(|host| {
+            let value = ip_to_number(&host.ip_address);
+            // 같은 주소에 이름이 여러 개면 첫 번째만 PTR로 사용
+            seen.insert(value).then(|| {
+                let owner = reverse_owner(&host.ip_address, zone.prefix());
+                (value, format!("{}\tIN\tPTR\t{}.", owner, host.fqdn))
+            })
+        })
+        .collect();
+    records.sort();
+
+    finish(
+        &zone_name,
+        ZoneKind::Reverse,
+        header,
+        soa,
+        records.into_iter().map(|(_, line)| line).collect(),
+    )
+}
+
+fn finish(
+    zone_name: &str,
+    kind: ZoneKind,
+    header: String,
+    soa: ZoneSoa,
+    records: Vec<String>,
+) -> ZoneFile {
+    let mut zone = ZoneFile {
+        zone_name: zone_name.to_string(),
+        kind,
+        content_hash: String::new(),
+        header,
+        soa,
+        records,
+    };
+    zone.content_hash = {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        hasher.update(zone.render(0).as_bytes());
+        format!("{:x}", hasher.finalize())
+    };
+    zone
+}
+
+/// 대역을 덮는 역방향 zone 블록. IPv4는 옥텟(/8, /16, /24), IPv6는 니블(4비트) 경계로 맞춘다.
+/// 경계보다 짧은 대역은 여러 zone으로 나누고, /24(IPv4)보다 긴 대역은 포함하는 zone 하나를 사용한다.
+pub fn reverse_zones_for(network: &IpNetwork) -> Vec<IpNetwork> {
+    let (unit, max_prefix) = match network.family() {
+        IpFamily::V4 => (8, 24),
+        IpFamily::V6 => (4, 124),
+    };
+    let prefix = network.prefix();
+    let zone_prefix = prefix.div_ceil(unit) * unit;
+
+    if zone_prefix > max_prefix {
+        IpNetwork::new(network.network_ip(), max_prefix)
+            .map(|zone| vec![zone])
+            .unwrap_or_default()
+    } else {
+        network.subnets(zone_prefix).collect()
+    }
+}
+
+pub fn reverse_zone_name(zone: &IpNetwork) -> String {
+    let labels = reverse_labels(&zone.network_ip());
+    let units = zone_units(zone.family(), zone.prefix());
+    let suffix = match zone.family() {
+        IpFamily::V4 => "in-addr.arpa",
+        IpFamily::V6 => "ip6.arpa",
+    };
+
+    let mut name: Vec<&str> = labels[labels.len() - units..]
+        .iter()
+        .map(String::as_str)
+        .collect();
+    name.push(suffix);
+    name.join(".")
+}
+
+fn reverse_owner(ip: &IpAddr, zone_prefix: u8) -> String {
+    let labels = reverse_labels(ip);
+    let units = zone_units(IpFamily::of(ip), zone_prefix);
+    labels[..labels.len() - units].join(".")
+}
+
+fn zone_units(family: IpFamily, prefix: u8) -> usize {
+    match family {
+        IpFamily::V4 => prefix as usize / 8,
+        IpFamily::V6 => prefix as usize / 4,
+    }
+}
+
+/// 가장 구체적인 단위가 먼저 오는 역방향 레이블 (IPv4 옥텟, IPv6 니블)
+fn reverse_labels(ip: &IpAddr) -> Vec<String> {
+    match ip {
+        IpAddr::V4(v4) => v4.octets().iter().rev().map(|o| o.to_string()).collect(),
+        IpAddr::V6(v6) => v6
+            .octets()
+            .iter()
+            .rev()
+            .flat_map(|byte| [byte & 0x0f, byte >> 4])
+            .map(|nibble| format!("{:x}", nibble))
+            .collect(),
+    }
+}
+
+/// zone 기준 상대 이름. zone 밖의 이름이면 None
+fn relative_name(fqdn: &str, zone_name: &str) -> Option<String> {
+    if fqdn == zone_name {
+        return Some("@".to_string());
+    }
+    fqdn.strip_suffix(zone_name)
+        .and_then(|prefix| prefix.strip_suffix('.'))
+        .filter(|prefix| !prefix.is_empty())
+        .map(str::to_string)
+}
+
+/// 도메인 이름 정규화: 소문자, 끝의 점 제거, 레이블 규칙(1~63자, 영숫자/하이픈/밑줄) 검사
+pub fn normalize_domain(name: &str) -> Option<String> {
+    let name = name.trim().trim_end_matches('.').to_lowercase();
+    if name.is_empty() || name.len() > 253 {
+        return None;
+    }
+    let valid = name.split('.').all(|label| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    });
+    valid.then_some(name)
+}
+
+/// 호스트 이름(또는 장비 이름)을 FQDN으로 만든다. 점이 없는 이름은 zone 도메인을 붙인다.
+pub fn host_fqdn(name: &str, domain: &str) -> Option<String> {
+    let trimmed = name.trim();
+    if trimmed.contains('.') {
+        normalize_domain(trimmed)
+    } else {
+        normalize_domain(&format!("{}.{}", trimmed, domain))
+    }
+}
+
+/// 장비 이름을 DNS 레이블로 변환 ("Core Switch #1" -> "core-switch-1")
+pub fn device_label(name: &str) -> Option<String> {
+    let mut label = String::new();
+    for c in name.trim().to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            label.push(c);
+        } else if !label.ends_with('-') {
+            label.push('-');
+        }
+    }
+    let label = label.trim_matches('-');
+    let label = &label[..label.len().min(63)];
+    let label = label.trim_end_matches('-');
+    (!label.is_empty()).then(|| label.to_string())
+}
+
+/// 레코드 줄 단위 비교. 이전 파일에만 있는 줄은 `-`, 새 파일에만 있는 줄은 `+`로 표시한다.
+pub fn diff_lines(previous: &str, next: &str) -> Vec<String> {
+    let mut remaining: HashMap<&str, usize> = HashMap::new();
+    for line in next.lines() {
+        *remaining.entry(line).or_default() += 1;
+    }
+
+    let mut removed = Vec::new();
+    for line in previous.lines() {
+        match remaining.get_mut(line) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => removed.push(format!("- {}", line)),
+        }
+    }
+
+    let mut unmatched: HashMap<&str, usize> = HashMap::new();
+    for line in previous.lines() {
+        *unmatched.entry(line).or_default() += 1;
+    }
+    let mut added = Vec::new();
+    for line in next.lines() {
+        match unmatched.get_mut(line) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => added.push(format!("+ {}", line)),
+        }
+    }
+
+    removed.extend(added);
+    removed
+}
@@ -1,10 +1,16 @@
 use crate::config::db_config::DbConfig;
+use crate::service::error::protocol::api_token::API_TOKEN_SCOPE_DENIED;
+use crate::service::error::protocol::custodian::CUSTODIAN_EXECUTION_IN_PROGRESS;
 use crate::service::error::protocol::email::EMAIL_ALREADY_VERIFIED;
+use crate::service::error::protocol::external_api::{
+    EXTERNAL_API_FIELD_LOCKED, EXTERNAL_API_NAME_EXISTS, EXTERNAL_API_SYNC_IN_PROGRESS,
+};
 use crate::service::error::protocol::file::{FILE_NOT_FOUND, FILE_READ_ERROR, FILE_UPLOAD_ERROR};
 use crate::service::error::protocol::follow::{
     FOLLOW_ALREADY_FOLLOWING, FOLLOW_CANNOT_FOLLOW_SELF, FOLLOW_NOT_EXIST,
 };
 use crate::service::error::protocol::general::{BAD_REQUEST, VALIDATION_ERROR};
+use crate::service::error::protocol::ip_range::{IP_RANGE_EXHAUSTED, IP_RANGE_OVERLAP};
 use crate::service::error::protocol::like::{LIKE_ALREADY_EXISTS, LIKE_NOT_FOUND};
 use crate::service::error::protocol::markdown::MARKDOWN_RENDER_FAILED;
 use crate::service::error::protocol::oauth::{
@@ -17,23 +23,32 @@ use crate::service::error::protocol::password::{
     PASSWORD_ALREADY_SET, PASSWORD_CANNOT_UPDATE_OAUTH_ONLY, PASSWORD_INCORRECT,
     PASSWORD_NEW_PASSWORD_MISSING, PASSWORD_REQUIRED_FOR_UPDATE,
 };
+use crate::service::error::protocol::permission::PERMISSION_DENIED;
 use crate::service::error::protocol::post::POST_NOT_FOUND;
+use crate::service::error::protocol::rack::{RACK_POWER_BUDGET_EXCEEDED, RACK_SLOT_CONFLICT};
+use crate::service::error::protocol::rate_limit::{RATE_LIMIT_EXCEEDED, RATE_LIMIT_LOCKED_OUT};
 use crate::service::error::protocol::report::REPORT_NOT_FOUND;
 use crate::service::error::protocol::system::{
     SYS_DATABASE_ERROR, SYS_HASHING_ERROR, SYS_INTERNAL_ERROR, SYS_NOT_FOUND,
     SYS_TOKEN_CREATION_ERROR, SYS_TRANSACTION_ERROR,
 };
+use crate::service::error::protocol::tenant::{TENANT_ACCESS_DENIED, TENANT_REQUIRED};
 use crate::service::error::protocol::token::{
     TOKEN_EMAIL_MISMATCH, TOKEN_EXPIRED_RESET, TOKEN_EXPIRED_VERIFICATION, TOKEN_INVALID_RESET,
     TOKEN_INVALID_VERIFICATION,
 };
+use crate::service::error::protocol::two_factor::{
+    TWO_FACTOR_ALREADY_ENABLED, TWO_FACTOR_INVALID_CODE, TWO_FACTOR_NOT_ENABLED,
+    TWO_FACTOR_REQUIRED,
+};
 use crate::service::error::protocol::user::{
     USER_HANDLE_ALREADY_EXISTS, USER_INVALID_PASSWORD, USER_INVALID_TOKEN, USER_NO_REFRESH_TOKEN,
     USER_NOT_FOUND, USER_NOT_VERIFIED, USER_TOKEN_EXPIRED, USER_UNAUTHORIZED,
 };
 use axum::Json;
 use axum::extract::Request;
-use axum::http::StatusCode;
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderValue, StatusCode};
 use axum::response::{IntoResponse, Response};
 use sea_orm::{DbErr, TransactionError};
 use serde::Serialize;
@@ -99,7 +114,25 @@ pub enum Errors {
     UserInvalidToken, // 유효하지 않은 토큰
 
     // 권한 관련 오류
-    ForbiddenError(String), // 403 Forbidden - 접근 권한 없음
+    ForbiddenError(String),   // 403 Forbidden - 접근 권한 없음
+    PermissionDenied(String), // 역할/사무실 범위로 허용되지 않은 작업
+
+    // 테넌트
+    TenantRequired,             // 소속 테넌트가 여럿인데 요청에 테넌트를 지정하지 않음
+    TenantAccessDenied(String), // 소속되지 않았거나 비활성화된 테넌트
+
+    // API 토큰
+    ApiTokenScopeDenied(String), // 토큰 범위로 부를 수 없는 경로
+
+    // 2단계 인증
+    TwoFactorInvalidCode,      // 틀렸거나 이미 쓴 인증 코드·복구 코드
+    TwoFactorNotEnabled,       // 2단계 인증을 등록하지 않음
+    TwoFactorAlreadyEnabled,   // 이미 2단계 인증을 쓰는 중
+    TwoFactorRequired(String), // 역할 정책상 끌 수 없음
+
+    // 요청 제한 (값은 다시 시도할 수 있을 때까지 남은 초, Retry-After 헤더로 보낸다)
+    TooManyRequests(u64), // 경로별 요청 횟수 초과
+    LoginLockedOut(u64),  // 비밀번호를 반복해서 틀려 잠긴 계정
 
     // Post
     PostNotFound,
@@ -110,6 +143,22 @@ pub enum Errors {
     // Server Room
     ServerRoomNotFound,
 
+    // IP Range
+    IpRangeOverlap(String),
+    IpRangeExhausted(String),
+
+    // 랙 관련 오류
+    RackSlotConflict(String),        // 다른 장비와 U 위치가 겹침
+    RackPowerBudgetExceeded(String), // 랙 전력 용량 초과 (RACK_POWER_BUDGET_MODE=reject)
+
+    // 외부 API 연결
+    ExternalApiNameExists(String),  // 같은 이름의 연결이 이미 있음
+    ExternalApiSyncInProgress(i32), // 다른 작업(또는 다른 인스턴스)이 같은 연결을 동기화 중
+    ExternalApiFieldLocked(String), // 외부 API가 관리하는(locked) 필드를 직접 수정하려 함
+
+    // Custodian
+    CustodianExecutionInProgress(uuid::Uuid), // 같은 정책의 이전 실행이 아직 끝나지 않음
+
     // follow 관련 오류
     FollowCannotFollowSelf,
     FollowAlreadyFollowing,
@@ -216,6 +265,16 @@ impl IntoResponse for Errors {
             | Errors::UserNoRefreshToken
             | Errors::UserInvalidToken
             | Errors::ForbiddenError(_)
+            | Errors::PermissionDenied(_)
+            | Errors::TenantRequired
+            | Errors::TenantAccessDenied(_)
+            | Errors::ApiTokenScopeDenied(_)
+            | Errors::TwoFactorInvalidCode
+            | Errors::TwoFactorNotEnabled
+            | Errors::TwoFactorAlreadyEnabled
+            | Errors::TwoFactorRequired(_)
+            | Errors::TooManyRequests(_)
+            | Errors::LoginLockedOut(_)
             | Errors::FollowCannotFollowSelf
             | Errors::FollowAlreadyFollowing
             | Errors::PasswordRequiredForUpdate
@@ -237,6 +296,14 @@ impl IntoResponse for Errors {
             | Errors::OauthInvalidImageUrl
             | Errors::DraftLimitExceeded
             | Errors::DraftSlugAlreadyExists
+            | Errors::IpRangeOverlap(_)
+            | Errors::IpRangeExhausted(_)
+            | Errors::RackSlotConflict(_)
+            | Errors::RackPowerBudgetExceeded(_)
+            | Errors::ExternalApiNameExists(_)
+            | Errors::ExternalApiSyncInProgress(_)
+            | Errors::ExternalApiFieldLocked(_)
+            | Errors::CustodianExecutionInProgress(_)
             | Errors::BadRequestError(_)
             | Errors::ValidationError(_)
             | Errors::FileTooLargeError(_) => {
@@ -273,6 +340,11 @@ impl IntoResponse for Errors {
             }
         }
 
+        let retry_after = match &self {
+            Errors::TooManyRequests(seconds) | Errors::LoginLockedOut(seconds) => Some(*seconds),
+            _ => None,
+        };
+
         // 오류 유형에 따라 상태 코드, 오류 코드, 상세 정보를 결정
         let (status, code, details) = match self {
             // 사용자 관련 오류 - 주로 401 Unauthorized 또는 404 Not Found
@@ -288,6 +360,49 @@ impl IntoResponse for Errors {
             Errors::UserInvalidToken => (StatusCode::UNAUTHORIZED, USER_INVALID_TOKEN, None),
 
             Errors::ForbiddenError(msg) => (StatusCode::FORBIDDEN, "FORBIDDEN", Some(msg.clone())),
+            Errors::PermissionDenied(msg) => {
+                (StatusCode::FORBIDDEN, PERMISSION_DENIED, Some(msg.clone()))
+            }
+            Errors::TenantRequired => (
+                StatusCode::BAD_REQUEST,
+                TENANT_REQUIRED,
+                Some("Select a tenant with the X-Tenant-Id header".to_string()),
+            ),
+            Errors::TenantAccessDenied(msg) => (
+                StatusCode::FORBIDDEN,
+                TENANT_ACCESS_DENIED,
+                Some(msg.clone()),
+            ),
+            Errors::ApiTokenScopeDenied(msg) => (
+                StatusCode::FORBIDDEN,
+                API_TOKEN_SCOPE_DENIED,
+                Some(msg.clone()),
+            ),
+            Errors::TwoFactorInvalidCode => {
+                (StatusCode::UNAUTHORIZED, TWO_FACTOR_INVALID_CODE, None)
+            }
+            Errors::TwoFactorNotEnabled => (StatusCode::BAD_REQUEST, TWO_FACTOR_NOT_ENABLED, None),
+            Errors::TwoFactorAlreadyEnabled => {
+                (StatusCode::CONFLICT, TWO_FACTOR_ALREADY_ENABLED, None)
+            }
+            Errors::TwoFactorRequired(msg) => (
+                StatusCode::FORBIDDEN,
+                TWO_FACTOR_REQUIRED,
+                Some(msg.clone()),
+            ),
+            Errors::TooManyRequests(seconds) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                RATE_LIMIT_EXCEEDED,
+                Some(format!("Retry after {} seconds", seconds)),
+            ),
+            Errors::LoginLockedOut(seconds) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                RATE_LIMIT_LOCKED_OUT,
+                Some(format!(
+                    "Too many failed sign-in attempts. Retry after {} seconds",
+                    seconds
+                )),
+            ),
 
             Errors::PostNotFound => (StatusCode::NOT_FOUND, POST_NOT_FOUND, None),
 
@@ -297,6 +412,39 @@ impl IntoResponse for Errors {
             // Server Room
             Errors::ServerRoomNotFound => (StatusCode::NOT_FOUND, "SERVER_ROOM_NOT_FOUND", None),
 
+            // IP Range
+            Errors::IpRangeOverlap(msg) => (StatusCode::CONFLICT, IP_RANGE_OVERLAP, Some(msg)),
+            Errors::IpRangeExhausted(msg) => (StatusCode::CONFLICT, IP_RANGE_EXHAUSTED, Some(msg)),
+
+            // 랙 관련 오류
+            Errors::RackSlotConflict(msg) => (StatusCode::CONFLICT, RACK_SLOT_CONFLICT, Some(msg)),
+            Errors::RackPowerBudgetExceeded(msg) => {
+                (StatusCode::CONFLICT, RACK_POWER_BUDGET_EXCEEDED, Some(msg))
+            }
+
+            // 외부 API 연결
+            Errors::ExternalApiNameExists(msg) => {
+                (StatusCode::CONFLICT, EXTERNAL_API_NAME_EXISTS, Some(msg))
+            }
+            Errors::ExternalApiSyncInProgress(id) => (
+                StatusCode::CONFLICT,
+                EXTERNAL_API_SYNC_IN_PROGRESS,
+                Some(format!("External API connection {} is already syncing", id)),
+            ),
+            Errors::ExternalApiFieldLocked(msg) => {
+                (StatusCode::CONFLICT, EXTERNAL_API_FIELD_LOCKED, Some(msg))
+            }
+
+            // Custodian
+            Errors::CustodianExecutionInProgress(id) => (
+                StatusCode::CONFLICT,
+                CUSTODIAN_EXECUTION_IN_PROGRESS,
+                Some(format!(
+                    "Custodian policy {} already has a running execution",
+                    id
+                )),
+            ),
+
             // Follow
             Errors::FollowCannotFollowSelf => {
                 (StatusCode::BAD_REQUEST, FOLLOW_CANNOT_FOLLOW_SELF, None)
@@ -445,7 +593,13 @@ impl IntoResponse for Errors {
         };
 
         // HTTP 응답으로 변환하여 반환
-        (status, Json(body)).into_response()
+        let mut response = (status, Json(body)).into_response();
+        if let Some(seconds) = retry_after {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(seconds));
+        }
+        response
     }
 }
 
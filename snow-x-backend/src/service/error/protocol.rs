@@ -10,6 +10,26 @@ pub mod user {
     pub const USER_NO_REFRESH_TOKEN: &str = "user:no_refresh_token";
     pub const USER_INVALID_TOKEN: &str = "user:invalid_token";
 }
+pub mod permission {
+    pub const PERMISSION_DENIED: &str = "permission:denied";
+}
+pub mod tenant {
+    pub const TENANT_REQUIRED: &str = "tenant:required";
+    pub const TENANT_ACCESS_DENIED: &str = "tenant:access_denied";
+}
+pub mod api_token {
+    pub const API_TOKEN_SCOPE_DENIED: &str = "api_token:scope_denied";
+}
+pub mod two_factor {
+    pub const TWO_FACTOR_INVALID_CODE: &str = "two_factor:invalid_code";
+    pub const TWO_FACTOR_NOT_ENABLED: &str = "two_factor:not_enabled";
+    pub const TWO_FACTOR_ALREADY_ENABLED: &str = "two_factor:already_enabled";
+    pub const TWO_FACTOR_REQUIRED: &str = "two_factor:required";
+}
+pub mod rate_limit {
+    pub const RATE_LIMIT_EXCEEDED: &str = "rate_limit:exceeded";
+    pub const RATE_LIMIT_LOCKED_OUT: &str = "rate_limit:locked_out";
+}
 pub mod post {
     pub const POST_NOT_FOUND: &str = "post:not_found";
 }
@@ -37,6 +57,26 @@ pub mod general {
     pub const VALIDATION_ERROR: &str = "general:validation_error";
 }
 
+pub mod ip_range {
+    pub const IP_RANGE_OVERLAP: &str = "ip_range:overlap";
+    pub const IP_RANGE_EXHAUSTED: &str = "ip_range:exhausted";
+}
+
+pub mod rack {
+    pub const RACK_SLOT_CONFLICT: &str = "rack:slot_conflict";
+    pub const RACK_POWER_BUDGET_EXCEEDED: &str = "rack:power_budget_exceeded";
+}
+
+pub mod external_api {
+    pub const EXTERNAL_API_NAME_EXISTS: &str = "external_api:name_exists";
+    pub const EXTERNAL_API_SYNC_IN_PROGRESS: &str = "external_api:sync_in_progress";
+    pub const EXTERNAL_API_FIELD_LOCKED: &str = "external_api:field_locked";
+}
+
+pub mod custodian {
+    pub const CUSTODIAN_EXECUTION_IN_PROGRESS: &str = "custodian:execution_in_progress";
+}
+
 pub mod file {
     pub const FILE_UPLOAD_ERROR: &str = "file:upload_error";
     pub const FILE_NOT_FOUND: &str = "file:not_found";
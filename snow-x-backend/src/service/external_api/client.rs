@@ -0,0 +1,305 @@
+use crate::connection::outbound::OutboundHttpClient;
+use crate::dto::external_api::response::TestExternalApiConnectionResponse;
+use crate::service::error::errors::{Errors, ServiceResult};
+use reqwest::Request;
+use reqwest::header::{CONTENT_TYPE, HeaderMap};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// 외부 API 요청 제한 시간
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// 동기화 로그에 남기는 응답 본문 최대 길이
+const MAX_LOGGED_BODY: usize = 4096;
+/// 응답 본문이 목록을 감싸고 있을 때 쓰는 흔한 키
+const WRAPPER_KEYS: [&str; 4] = ["data", "results", "items", "records"];
+/// 응답과 로그에서 가리는 값
+pub const MASKED_SECRET: &str = "********";
+/// auth_config에서 비밀 값으로 취급하는 키
+pub const SECRET_AUTH_KEYS: [&str; 3] = ["token", "key", "password"];
+
+/// 한 번의 요청 결과. 실패해도 알 수 있는 만큼 채워 동기화 로그에 남긴다.
+pub struct FetchOutcome {
+    pub request_headers: Value,
+    pub response_status: Option<i32>,
+    pub response_headers: Option<Value>,
+    pub response_body: Option<String>,
+    pub duration_ms: i32,
+    pub result: Result<Vec<Value>, String>,
+}
+
+/// auth_config를 검사한다. `type`이 없거나 `none`이면 인증 없음.
+pub fn validate_auth_config(auth_config: &Value) -> ServiceResult<()> {
+    let Some(config) = auth_config.as_object() else {
+        return Err(Errors::ValidationError(
+            "auth_config must be an object".to_string(),
+        ));
+    };
+
+    let required: &[&str] = match auth_type(config).as_str() {
+        "" | "none" => &[],
+        "bearer" => &["token"],
+        "api_key" => &["key"],
+        "basic" => &["username", "password"],
+        other => {
+            return Err(Errors::ValidationError(format!(
+                "Unknown auth type '{}' (expected: bearer, api_key, basic)",
+                other
+            )));
+        }
+    };
+    for key in required {
+        if config
+            .get(*key)
+            .and_then(Value::as_str)
+            .is_none_or(str::is_empty)
+        {
+            return Err(Errors::ValidationError(format!(
+                "auth_config.{} is required for {} auth",
+                key,
+                auth_type(config)
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn auth_type(config: &Map<String, Value>) -> String {
+    config
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// 연결 헤더와 인증 설정을 붙인 `GET base_url` 요청을 만든다.
+/// 보내기 전에 주소가 내부망을 가리키지 않는지 확인한다 (`OutboundHttpClient::check_url`).
+async fn build_request(
+    client: &OutboundHttpClient,
+    base_url: &str,
+    headers: &HashMap<String, String>,
+    auth_config: Option<&Value>,
+) -> Result<Request, String> {
+    let url = client.check_url(base_url).await?;
+    let mut builder = client.client().get(url).timeout(REQUEST_TIMEOUT);
+    for (name, value) in headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+
+    if let Some(config) = auth_config.and_then(Value::as_object) {
+        let text = |key: &str| config.get(key).and_then(Value::as_str).unwrap_or_default();
+        match auth_type(config).as_str() {
+            "bearer" if !text("token").is_empty() => {
+                builder = builder.bearer_auth(text("token"));
+            }
+            "api_key" if !text("key").is_empty() => {
+                let header_name = config
+                    .get("header_name")
+                    .and_then(Value::as_str)
+                    .unwrap_or("X-API-Key");
+                builder = builder.header(header_name, text("key"));
+            }
+            "basic" if !text("username").is_empty() => {
+                builder = builder.basic_auth(text("username"), Some(text("password")));
+            }
+            _ => {}
+        }
+    }
+
+    builder
+        .build()
+        .map_err(|e| format!("Invalid request for {}: {}", base_url, e))
+}
+
+/// 로그에 남길 헤더. 인증 헤더 값은 가린다.
+fn loggable_headers(headers: &HeaderMap) -> Value {
+    Value::Object(
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let sensitive = value.is_sensitive()
+                    || name == reqwest::header::AUTHORIZATION
+                    || name.as_str().contains("key")
+                    || name.as_str().contains("token");
+                let value = if sensitive {
+                    MASKED_SECRET.to_string()
+                } else {
+                    value.to_str().unwrap_or_default().to_string()
+                };
+                (name.to_string(), Value::String(value))
+            })
+            .collect(),
+    )
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((index, _)) => format!("{}…", &text[..index]),
+        None => text.to_string(),
+    }
+}
+
+/// 응답 JSON을 항목 목록으로 맞춘다.
+/// 배열은 그대로, `data`/`results`/`items`/`records` 배열로 감싼 객체는 그 배열, 그 밖의 객체는 한 항목.
+/// 객체가 아닌 항목은 `{"content": 값}`으로 감싼다.
+pub fn normalize_items(body: Value) -> Vec<Value> {
+    let items = match body {
+        Value::Array(items) => items,
+        Value::Object(mut map) => {
+            let wrapped = WRAPPER_KEYS
+                .iter()
+                .find(|key| map.get(**key).is_some_and(Value::is_array))
+                .and_then(|key| map.remove(*key));
+            match wrapped {
+                Some(Value::Array(items)) => items,
+                _ => vec![Value::Object(map)],
+            }
+        }
+        other => vec![other],
+    };
+
+    items
+        .into_iter()
+        .map(|item| match item {
+            Value::Object(_) => item,
+            other => serde_json::json!({ "content": other }),
+        })
+        .collect()
+}
+
+/// `GET base_url`로 데이터를 받아 항목 목록으로 돌려준다.
+/// 4xx/5xx, 네트워크 오류, JSON이 아닌 응답은 `result`의 오류로 돌려준다.
+pub async fn fetch_items(
+    client: &OutboundHttpClient,
+    base_url: &str,
+    headers: &HashMap<String, String>,
+    auth_config: Option<&Value>,
+) -> FetchOutcome {
+    let mut outcome = FetchOutcome {
+        request_headers: Value::Object(Map::new()),
+        response_status: None,
+        response_headers: None,
+        response_body: None,
+        duration_ms: 0,
+        result: Err(String::new()),
+    };
+
+    let request = match build_request(client, base_url, headers, auth_config).await {
+        Ok(request) => request,
+        Err(message) => {
+            outcome.result = Err(message);
+            return outcome;
+        }
+    };
+    outcome.request_headers = loggable_headers(request.headers());
+
+    let started = Instant::now();
+    let response = client.client().execute(request).await;
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            outcome.duration_ms = started.elapsed().as_millis() as i32;
+            outcome.result = Err(if e.is_timeout() {
+                format!("Request timeout for {}", base_url)
+            } else {
+                format!("Request to {} failed: {}", base_url, e)
+            });
+            return outcome;
+        }
+    };
+
+    let status = response.status();
+    outcome.response_status = Some(status.as_u16() as i32);
+    outcome.response_headers = Some(loggable_headers(response.headers()));
+    let body = response.text().await;
+    outcome.duration_ms = started.elapsed().as_millis() as i32;
+
+    let body = match body {
+        Ok(body) => body,
+        Err(e) => {
+            outcome.result = Err(format!("Failed to read response body: {}", e));
+            return outcome;
+        }
+    };
+    outcome.response_body = Some(truncate(&body, MAX_LOGGED_BODY));
+
+    if !status.is_success() {
+        outcome.result = Err(format!(
+            "API request failed: HTTP {} - {}",
+            status.as_u16(),
+            truncate(&body, 500)
+        ));
+        return outcome;
+    }
+
+    outcome.result = serde_json::from_str::<Value>(&body)
+        .map(normalize_items)
+        .map_err(|e| format!("Invalid JSON response from {}: {}", base_url, e));
+    outcome
+}
+
+/// 저장하지 않고 연결을 확인한다. 실패도 오류가 아닌 `status: error` 응답으로 돌려준다.
+pub async fn test_connection(
+    client: &OutboundHttpClient,
+    base_url: &str,
+    headers: &HashMap<String, String>,
+    auth_config: Option<&Value>,
+) -> TestExternalApiConnectionResponse {
+    let failed = |message: String| TestExternalApiConnectionResponse {
+        status: "error".to_string(),
+        message,
+        status_code: None,
+        response_time_ms: None,
+        content_type: None,
+        sample_data: None,
+    };
+
+    let request = match build_request(client, base_url, headers, auth_config).await {
+        Ok(request) => request,
+        Err(message) => return failed(message),
+    };
+
+    let started = Instant::now();
+    let response = match client.client().execute(request).await {
+        Ok(response) => response,
+        Err(e) if e.is_timeout() => return failed("Connection timeout".to_string()),
+        Err(e) => return failed(format!("Connection error: {}", e)),
+    };
+
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let body = response.text().await.unwrap_or_default();
+    let elapsed = started.elapsed().as_millis() as i64;
+
+    let sample_data = status
+        .is_success()
+        .then(|| match serde_json::from_str::<Value>(&body) {
+            Ok(json) => normalize_items(json).into_iter().next(),
+            Err(_) => Some(serde_json::json!({
+                "response_text": truncate(&body, 500),
+                "content_type": content_type.clone().unwrap_or_default(),
+            })),
+        });
+
+    TestExternalApiConnectionResponse {
+        status: if status.is_success() {
+            "success"
+        } else {
+            "error"
+        }
+        .to_string(),
+        message: if status.is_success() {
+            format!("Connected successfully (HTTP {})", status.as_u16())
+        } else {
+            format!("Connection failed (HTTP {})", status.as_u16())
+        },
+        status_code: Some(status.as_u16()),
+        response_time_ms: Some(elapsed),
+        content_type,
+        sample_data: sample_data.flatten(),
+    }
+}
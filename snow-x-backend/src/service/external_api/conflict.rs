@@ -0,0 +1,898 @@
+//! 동기화된 레코드의 필드 소유 규칙과 충돌 검토.
+//!
+//! 연결의 `field_policies`가 필드마다 소유자를 정한다.
+//! - `locked`: 원본이 소유한다. 사람이 수정하면 거부하고, 동기화는 항상 덮어쓴다.
+//! - `manual_override` (기본값): 사람이 수정하면 그 필드를 수동 수정으로 표시(`sync_field_overrides`)하고
+//!   이후 동기화는 덮어쓰지 않는다. 원본 값이 마지막으로 확인한 값에서 바뀌면 `sync_conflicts`에
+//!   충돌을 남겨 검토(원본 값 적용 / 현재 값 유지)를 기다린다.
+//!
+//! 대상 테이블을 찾는 매칭 키(담당자 email, 장비 serial_number, 라이브러리 model)는 항상 `locked`다.
+
+use super::SOURCE_TYPE_API_SYNC;
+use crate::dto::external_api::response::{
+    SyncConflictListResponse, SyncConflictResponse, SyncFieldOverrideListResponse,
+    SyncFieldOverrideResponse,
+};
+use crate::entity::{
+    contacts, device_library, devices, external_api_connections, sync_conflicts,
+    sync_field_overrides,
+};
+use crate::service::audit::{
+    ACTION_UPDATE, AuditEntry, RESOURCE_CONTACT, RESOURCE_DEVICE, RESOURCE_DEVICE_LIBRARY,
+    diff_snapshots, record_audit,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::rack::capacity::{
+    PowerBudgetOverage, check_rack_power_budget, notify_power_budget_overage,
+};
+use crate::service::rack::elevation::{ensure_rack_slots_available, lock_rack};
+use crate::service::tenant::external_api_connections_in_tenant;
+use chrono::Utc;
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::{
+    ActiveModelBehavior, ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait,
+    DatabaseConnection, EntityTrait, IntoActiveModel, ModelTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, TransactionTrait, TryIntoModel,
+};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub const POLICY_LOCKED: &str = "locked";
+pub const POLICY_MANUAL_OVERRIDE: &str = "manual_override";
+pub const FIELD_POLICIES: [&str; 2] = [POLICY_LOCKED, POLICY_MANUAL_OVERRIDE];
+
+pub const CONFLICT_PENDING: &str = "pending";
+pub const CONFLICT_ACCEPTED_SOURCE: &str = "accepted_source";
+pub const CONFLICT_KEPT_LOCAL: &str = "kept_local";
+pub const CONFLICT_STATUSES: [&str; 3] = [
+    CONFLICT_PENDING,
+    CONFLICT_ACCEPTED_SOURCE,
+    CONFLICT_KEPT_LOCAL,
+];
+
+/// 필드 이름 → 소유 규칙
+pub type FieldPolicies = HashMap<String, String>;
+
+/// 동기화가 값을 쓰는 필드 (`targets`의 apply 함수와 같은 목록)
+const CONTACT_FIELDS: [&str; 8] = [
+    "name",
+    "title",
+    "department",
+    "phone",
+    "mobile",
+    "email",
+    "office_location",
+    "responsibilities",
+];
+const DEVICE_FIELDS: [&str; 11] = [
+    "name",
+    "description",
+    "device_type",
+    "manufacturer",
+    "model",
+    "serial_number",
+    "rack_size",
+    "power_consumption",
+    "status",
+    "purchase_date",
+    "warranty_end",
+];
+const DEVICE_LIBRARY_FIELDS: [&str; 8] = [
+    "name",
+    "description",
+    "device_type",
+    "manufacturer",
+    "model",
+    "default_rack_size",
+    "default_power_consumption",
+    "default_config",
+];
+
+/// 대상 종류(= 감사 로그 리소스 종류)별로 동기화가 관리하는 필드
+pub fn synced_fields(resource_type: &str) -> &'static [&'static str] {
+    match resource_type {
+        RESOURCE_CONTACT => &CONTACT_FIELDS,
+        RESOURCE_DEVICE => &DEVICE_FIELDS,
+        RESOURCE_DEVICE_LIBRARY => &DEVICE_LIBRARY_FIELDS,
+        _ => &[],
+    }
+}
+
+/// 동기화가 기존 레코드를 찾는 매칭 키. 사람이 바꾸면 다음 동기화에서 중복이 생기므로 항상 locked.
+pub fn key_field(resource_type: &str) -> Option<&'static str> {
+    match resource_type {
+        RESOURCE_CONTACT => Some("email"),
+        RESOURCE_DEVICE => Some("serial_number"),
+        RESOURCE_DEVICE_LIBRARY => Some("model"),
+        _ => None,
+    }
+}
+
+pub fn field_policy<'a>(policies: &'a FieldPolicies, resource_type: &str, field: &str) -> &'a str {
+    if key_field(resource_type) == Some(field) {
+        return POLICY_LOCKED;
+    }
+    policies
+        .get(field)
+        .map(String::as_str)
+        .unwrap_or(POLICY_MANUAL_OVERRIDE)
+}
+
+/// 저장된 소유 규칙. 형식이 맞지 않으면 규칙 없음(모두 manual_override)으로 본다.
+pub fn stored_field_policies(model: &external_api_connections::Model) -> FieldPolicies {
+    model
+        .field_policies
+        .clone()
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+pub fn validate_field_policies(target_type: &str, policies: &FieldPolicies) -> ServiceResult<()> {
+    let fields = synced_fields(target_type);
+    for (field, policy) in policies {
+        if !fields.contains(&field.as_str()) {
+            return Err(Errors::ValidationError(format!(
+                "'{}' is not a synced {} field (expected one of: {})",
+                field,
+                target_type,
+                fields.join(", ")
+            )));
+        }
+        if !FIELD_POLICIES.contains(&policy.as_str()) {
+            return Err(Errors::ValidationError(format!(
+                "Invalid policy '{}' for {} (expected one of: {})",
+                policy,
+                field,
+                FIELD_POLICIES.join(", ")
+            )));
+        }
+        if key_field(target_type) == Some(field.as_str()) && policy != POLICY_LOCKED {
+            return Err(Errors::ValidationError(format!(
+                "{} is the {} match key and is always locked",
+                field, target_type
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn to_json<T: Serialize>(value: &T) -> ServiceResult<Value> {
+    serde_json::to_value(value).map_err(|e| Errors::SysInternalError(e.to_string()))
+}
+
+fn column_of<E: EntityTrait>(field: &str) -> ServiceResult<E::Column> {
+    E::Column::from_str(field)
+        .map_err(|_| Errors::SysInternalError(format!("Unknown column '{}'", field)))
+}
+
+/// 사람이 동기화된 레코드를 수정한 내용 (수정 전후 스냅샷)
+pub struct ManualEdit<'a, M: Serialize> {
+    pub resource_type: &'static str,
+    pub resource_id: Uuid,
+    /// 수정 전 레코드의 source_type / external_api_connection_id
+    pub source_type: &'a str,
+    pub connection_id: Option<i32>,
+    pub actor_id: Uuid,
+    pub before: &'a M,
+    pub after: &'a M,
+}
+
+/// 사람이 레코드를 수정한 뒤 같은 트랜잭션 안에서 호출한다.
+///
+/// 외부 API로 동기화된 레코드가 아니면 아무것도 하지 않는다. `locked` 필드가 바뀌었으면
+/// `ExternalApiFieldLocked`로 거부하고, 나머지 동기화 필드는 수동 수정으로 표시한다.
+pub async fn guard_manual_edit<C, M>(conn: &C, edit: ManualEdit<'_, M>) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+    M: Serialize,
+{
+    let Some(connection_id) = edit
+        .connection_id
+        .filter(|_| edit.source_type == SOURCE_TYPE_API_SYNC)
+    else {
+        return Ok(());
+    };
+    let Some(connection) = external_api_connections::Entity::find_by_id(connection_id)
+        .one(conn)
+        .await?
+    else {
+        return Ok(());
+    };
+
+    let fields = synced_fields(edit.resource_type);
+    let before = to_json(edit.before)?;
+    let after = to_json(edit.after)?;
+    let changes: Vec<_> = diff_snapshots(Some(&before), Some(&after))
+        .into_iter()
+        .filter(|change| fields.contains(&change.field.as_str()))
+        .collect();
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let policies = stored_field_policies(&connection);
+    let locked: Vec<&str> = changes
+        .iter()
+        .map(|change| change.field.as_str())
+        .filter(|field| field_policy(&policies, edit.resource_type, field) == POLICY_LOCKED)
+        .collect();
+    if !locked.is_empty() {
+        return Err(Errors::ExternalApiFieldLocked(format!(
+            "Managed by external API connection '{}': {}",
+            connection.name,
+            locked.join(", ")
+        )));
+    }
+
+    let now: DateTimeWithTimeZone = Utc::now().into();
+    for change in changes {
+        let existing = sync_field_overrides::Entity::find()
+            .filter(sync_field_overrides::Column::ResourceType.eq(edit.resource_type))
+            .filter(sync_field_overrides::Column::ResourceId.eq(edit.resource_id))
+            .filter(sync_field_overrides::Column::Field.eq(&change.field))
+            .one(conn)
+            .await?;
+
+        match existing {
+            // 처음 수정할 때의 원본 값을 기준값으로 유지한다
+            Some(existing) => {
+                let mut model: sync_field_overrides::ActiveModel = existing.into();
+                model.overridden_by = ActiveValue::Set(Some(edit.actor_id));
+                model.updated_at = ActiveValue::Set(now);
+                model.update(conn).await?;
+            }
+            None => {
+                sync_field_overrides::ActiveModel {
+                    id: ActiveValue::Set(Uuid::new_v4()),
+                    resource_type: ActiveValue::Set(edit.resource_type.to_string()),
+                    resource_id: ActiveValue::Set(edit.resource_id),
+                    field: ActiveValue::Set(change.field.clone()),
+                    external_api_connection_id: ActiveValue::Set(connection_id),
+                    source_value: ActiveValue::Set(Some(change.before.clone())),
+                    overridden_by: ActiveValue::Set(Some(edit.actor_id)),
+                    created_at: ActiveValue::Set(now),
+                    updated_at: ActiveValue::Set(now),
+                }
+                .insert(conn)
+                .await?;
+            }
+        }
+
+        // 대기 중인 충돌이 있으면 현재 값을 새 값으로 맞춘다
+        sync_conflicts::Entity::update_many()
+            .col_expr(
+                sync_conflicts::Column::LocalValue,
+                sea_orm::sea_query::Expr::value(change.after.clone()),
+            )
+            .col_expr(
+                sync_conflicts::Column::UpdatedAt,
+                sea_orm::sea_query::Expr::value(now),
+            )
+            .filter(sync_conflicts::Column::ResourceType.eq(edit.resource_type))
+            .filter(sync_conflicts::Column::ResourceId.eq(edit.resource_id))
+            .filter(sync_conflicts::Column::Field.eq(&change.field))
+            .filter(sync_conflicts::Column::Status.eq(CONFLICT_PENDING))
+            .exec(conn)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// 동기화가 수정하려는 기존 레코드
+pub struct SyncedRecord<'a, M> {
+    pub resource_type: &'static str,
+    pub resource_id: Uuid,
+    pub resource_name: &'a str,
+    pub connection_id: i32,
+    pub before: &'a M,
+}
+
+/// 같은 필드의 대기 중인 충돌을 새로 만들거나 원본 값을 갱신한다.
+/// 이미 같은 원본 값으로 대기 중이면 `false`.
+async fn raise_conflict<C, M>(
+    conn: &C,
+    record: &SyncedRecord<'_, M>,
+    field: &str,
+    local_value: Value,
+    source_value: Value,
+) -> ServiceResult<bool>
+where
+    C: ConnectionTrait,
+{
+    let now: DateTimeWithTimeZone = Utc::now().into();
+    let pending = sync_conflicts::Entity::find()
+        .filter(sync_conflicts::Column::ResourceType.eq(record.resource_type))
+        .filter(sync_conflicts::Column::ResourceId.eq(record.resource_id))
+        .filter(sync_conflicts::Column::Field.eq(field))
+        .filter(sync_conflicts::Column::Status.eq(CONFLICT_PENDING))
+        .one(conn)
+        .await?;
+
+    match pending {
+        Some(pending) if pending.source_value.as_ref() == Some(&source_value) => Ok(false),
+        Some(pending) => {
+            let mut model: sync_conflicts::ActiveModel = pending.into();
+            model.resource_name = ActiveValue::Set(Some(record.resource_name.to_string()));
+            model.local_value = ActiveValue::Set(Some(local_value));
+            model.source_value = ActiveValue::Set(Some(source_value));
+            model.updated_at = ActiveValue::Set(now);
+            model.update(conn).await?;
+            Ok(true)
+        }
+        None => {
+            sync_conflicts::ActiveModel {
+                id: ActiveValue::Set(Uuid::new_v4()),
+                resource_type: ActiveValue::Set(record.resource_type.to_string()),
+                resource_id: ActiveValue::Set(record.resource_id),
+                resource_name: ActiveValue::Set(Some(record.resource_name.to_string())),
+                field: ActiveValue::Set(field.to_string()),
+                external_api_connection_id: ActiveValue::Set(record.connection_id),
+                local_value: ActiveValue::Set(Some(local_value)),
+                source_value: ActiveValue::Set(Some(source_value)),
+                status: ActiveValue::Set(CONFLICT_PENDING.to_string()),
+                detected_at: ActiveValue::Set(now),
+                updated_at: ActiveValue::Set(now),
+                resolved_by: ActiveValue::Set(None),
+                resolved_at: ActiveValue::Set(None),
+            }
+            .insert(conn)
+            .await?;
+            Ok(true)
+        }
+    }
+}
+
+/// 동기화로 기존 레코드를 저장하기 전에 호출한다.
+///
+/// 수동 수정된 필드는 `model`에서 기존 값으로 되돌리고, 원본 값이 마지막으로 확인한 값에서
+/// 바뀌었으면 충돌을 남긴다. 규칙이 `locked`로 바뀐 필드는 표시를 지우고 원본 값을 따른다.
+/// 새로 생기거나 갱신된 충돌 수를 돌려준다.
+pub async fn protect_overridden_fields<C, A, M>(
+    conn: &C,
+    record: SyncedRecord<'_, M>,
+    policies: &FieldPolicies,
+    model: &mut A,
+) -> ServiceResult<usize>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait + TryIntoModel<M>,
+    M: ModelTrait<Entity = A::Entity> + Serialize,
+{
+    let overrides = sync_field_overrides::Entity::find()
+        .filter(sync_field_overrides::Column::ResourceType.eq(record.resource_type))
+        .filter(sync_field_overrides::Column::ResourceId.eq(record.resource_id))
+        .all(conn)
+        .await?;
+    if overrides.is_empty() {
+        return Ok(0);
+    }
+
+    let current = to_json(record.before)?;
+    let proposed = to_json(&model.clone().try_into_model()?)?;
+    let mut conflicts = 0;
+
+    for field_override in overrides {
+        if field_policy(policies, record.resource_type, &field_override.field) == POLICY_LOCKED {
+            field_override.delete(conn).await?;
+            continue;
+        }
+        let Ok(column) = column_of::<A::Entity>(&field_override.field) else {
+            continue;
+        };
+
+        let local_value = current
+            .get(&field_override.field)
+            .cloned()
+            .unwrap_or(Value::Null);
+        let source_value = proposed
+            .get(&field_override.field)
+            .cloned()
+            .unwrap_or(Value::Null);
+        let baseline = field_override.source_value.clone().unwrap_or(Value::Null);
+
+        if source_value == local_value {
+            // 원본이 사람이 입력한 값으로 따라왔으면 기준값만 옮긴다
+            if source_value != baseline {
+                let mut active: sync_field_overrides::ActiveModel = field_override.into();
+                active.source_value = ActiveValue::Set(Some(source_value));
+                active.updated_at = ActiveValue::Set(Utc::now().into());
+                active.update(conn).await?;
+            }
+            continue;
+        }
+
+        model.set(column, record.before.get(column));
+        // 마지막으로 확인한 원본 값 그대로면 사람이 바꾼 값을 조용히 지킨다
+        if source_value != baseline
+            && raise_conflict(
+                conn,
+                &record,
+                &field_override.field,
+                local_value,
+                source_value,
+            )
+            .await?
+        {
+            conflicts += 1;
+        }
+    }
+
+    Ok(conflicts)
+}
+
+fn to_conflict_response(model: sync_conflicts::Model) -> SyncConflictResponse {
+    SyncConflictResponse {
+        id: model.id,
+        resource_type: model.resource_type,
+        resource_id: model.resource_id,
+        resource_name: model.resource_name,
+        field: model.field,
+        connection_id: model.external_api_connection_id,
+        local_value: model.local_value,
+        source_value: model.source_value,
+        status: model.status,
+        detected_at: model.detected_at.into(),
+        updated_at: model.updated_at.into(),
+        resolved_by: model.resolved_by,
+        resolved_at: model.resolved_at.map(Into::into),
+    }
+}
+
+fn to_override_response(model: sync_field_overrides::Model) -> SyncFieldOverrideResponse {
+    SyncFieldOverrideResponse {
+        id: model.id,
+        resource_type: model.resource_type,
+        resource_id: model.resource_id,
+        field: model.field,
+        connection_id: model.external_api_connection_id,
+        source_value: model.source_value,
+        overridden_by: model.overridden_by,
+        created_at: model.created_at.into(),
+        updated_at: model.updated_at.into(),
+    }
+}
+
+/// 충돌 목록 필터
+#[derive(Default)]
+pub struct SyncConflictFilter {
+    /// 기본값 pending
+    pub status: Option<String>,
+    pub resource_type: Option<String>,
+    pub resource_id: Option<Uuid>,
+    pub connection_id: Option<i32>,
+}
+
+/// 충돌 검토 대기열 (기본: 대기 중인 충돌, 오래된 순)
+pub async fn service_get_sync_conflicts(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    filter: SyncConflictFilter,
+    page: u64,
+    limit: u64,
+) -> ServiceResult<SyncConflictListResponse> {
+    let page = page.max(1);
+    let limit = limit.clamp(1, 200);
+
+    let status = filter
+        .status
+        .unwrap_or_else(|| CONFLICT_PENDING.to_string());
+    if !CONFLICT_STATUSES.contains(&status.as_str()) {
+        return Err(Errors::BadRequestError(format!(
+            "Unknown status '{}' (expected one of: {})",
+            status,
+            CONFLICT_STATUSES.join(", ")
+        )));
+    }
+
+    let mut query = sync_conflicts::Entity::find()
+        .filter(
+            sync_conflicts::Column::ExternalApiConnectionId
+                .in_subquery(external_api_connections_in_tenant(tenant_id)),
+        )
+        .filter(sync_conflicts::Column::Status.eq(&status));
+    if let Some(resource_type) = filter.resource_type {
+        query = query.filter(sync_conflicts::Column::ResourceType.eq(resource_type));
+    }
+    if let Some(resource_id) = filter.resource_id {
+        query = query.filter(sync_conflicts::Column::ResourceId.eq(resource_id));
+    }
+    if let Some(connection_id) = filter.connection_id {
+        query = query.filter(sync_conflicts::Column::ExternalApiConnectionId.eq(connection_id));
+    }
+
+    // 대기 중인 충돌은 오래된 것부터, 처리된 충돌은 최근 처리한 것부터
+    let query = if status == CONFLICT_PENDING {
+        query.order_by_asc(sync_conflicts::Column::DetectedAt)
+    } else {
+        query.order_by_desc(sync_conflicts::Column::ResolvedAt)
+    };
+    let paginator = query.paginate(conn, limit);
+    let total = paginator.num_items().await?;
+    let rows = paginator.fetch_page(page - 1).await?;
+
+    Ok(SyncConflictListResponse {
+        conflicts: rows.into_iter().map(to_conflict_response).collect(),
+        total,
+        page,
+        limit,
+    })
+}
+
+async fn find_pending_conflict<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    id: Uuid,
+) -> ServiceResult<sync_conflicts::Model>
+where
+    C: ConnectionTrait,
+{
+    let conflict = sync_conflicts::Entity::find_by_id(id)
+        .filter(
+            sync_conflicts::Column::ExternalApiConnectionId
+                .in_subquery(external_api_connections_in_tenant(tenant_id)),
+        )
+        .one(conn)
+        .await?
+        .ok_or_else(|| Errors::NotFound(format!("Sync conflict {} not found", id)))?;
+    if conflict.status != CONFLICT_PENDING {
+        return Err(Errors::BadRequestError(format!(
+            "Sync conflict {} is already resolved ({})",
+            id, conflict.status
+        )));
+    }
+    Ok(conflict)
+}
+
+async fn find_override<C>(
+    conn: &C,
+    conflict: &sync_conflicts::Model,
+) -> ServiceResult<Option<sync_field_overrides::Model>>
+where
+    C: ConnectionTrait,
+{
+    Ok(sync_field_overrides::Entity::find()
+        .filter(sync_field_overrides::Column::ResourceType.eq(&conflict.resource_type))
+        .filter(sync_field_overrides::Column::ResourceId.eq(conflict.resource_id))
+        .filter(sync_field_overrides::Column::Field.eq(&conflict.field))
+        .one(conn)
+        .await?)
+}
+
+async fn mark_resolved<C>(
+    conn: &C,
+    conflict: sync_conflicts::Model,
+    status: &str,
+    resolved_by: Uuid,
+) -> ServiceResult<sync_conflicts::Model>
+where
+    C: ConnectionTrait,
+{
+    let now: DateTimeWithTimeZone = Utc::now().into();
+    let mut model: sync_conflicts::ActiveModel = conflict.into();
+    model.status = ActiveValue::Set(status.to_string());
+    model.resolved_by = ActiveValue::Set(Some(resolved_by));
+    model.resolved_at = ActiveValue::Set(Some(now));
+    model.updated_at = ActiveValue::Set(now);
+    Ok(model.update(conn).await?)
+}
+
+/// 필드 하나만 바꾼 모델 (JSON을 거쳐 필드 타입에 맞게 변환)
+fn with_field_value<M>(before: &M, field: &str, value: Value) -> ServiceResult<M>
+where
+    M: Serialize + DeserializeOwned,
+{
+    let mut json = to_json(before)?;
+    if let Some(map) = json.as_object_mut() {
+        map.insert(field.to_string(), value);
+    }
+    serde_json::from_value(json).map_err(|e| {
+        Errors::BadRequestError(format!(
+            "Source value for {} cannot be applied: {}",
+            field, e
+        ))
+    })
+}
+
+type ModelOf<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::Model;
+
+/// `updated`의 `field` 값만 기존 레코드에 저장한다
+async fn save_field<C, A>(
+    conn: &C,
+    before: &ModelOf<A>,
+    updated: &ModelOf<A>,
+    field: &str,
+) -> ServiceResult<ModelOf<A>>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait + ActiveModelBehavior + Send,
+    ModelOf<A>: IntoActiveModel<A>,
+{
+    let column = column_of::<A::Entity>(field)?;
+    let updated_at = column_of::<A::Entity>("updated_at")?;
+    let now: DateTimeWithTimeZone = Utc::now().into();
+
+    let mut model = before.clone().into_active_model();
+    model.set(column, updated.get(column));
+    model.set(updated_at, now.into());
+    Ok(model.update(conn).await?)
+}
+
+/// 원본 값을 레코드에 적용하고 감사 로그를 남긴다. 랙에 있는 장비의 크기/전력이 바뀌면
+/// 단건 수정과 같은 검증을 거친다.
+async fn apply_conflict_source<C>(
+    conn: &C,
+    conflict: &sync_conflicts::Model,
+    actor_id: Uuid,
+) -> ServiceResult<Option<PowerBudgetOverage>>
+where
+    C: ConnectionTrait,
+{
+    let not_found = || {
+        Errors::NotFound(format!(
+            "{} {} not found",
+            conflict.resource_type, conflict.resource_id
+        ))
+    };
+    let value = conflict.source_value.clone().unwrap_or(Value::Null);
+    let field = conflict.field.as_str();
+
+    match conflict.resource_type.as_str() {
+        RESOURCE_DEVICE => {
+            let before = devices::Entity::find_by_id(conflict.resource_id)
+                .filter(devices::Column::IsActive.eq(true))
+                .one(conn)
+                .await?
+                .ok_or_else(not_found)?;
+            let updated = with_field_value(&before, field, value)?;
+
+            let mut power_overage = None;
+            if let Some(rack_id) = before.rack_id
+                && (updated.rack_size != before.rack_size
+                    || updated.power_consumption != before.power_consumption)
+            {
+                let rack = lock_rack(conn, &rack_id).await?;
+                if updated.rack_size != before.rack_size
+                    && let Some(position) = before.rack_position
+                {
+                    ensure_rack_slots_available(
+                        conn,
+                        &rack,
+                        position,
+                        updated.rack_size,
+                        Some(&before.id),
+                    )
+                    .await?;
+                }
+                if updated.power_consumption != before.power_consumption {
+                    power_overage = check_rack_power_budget(
+                        conn,
+                        &rack,
+                        &before.name,
+                        updated.power_consumption,
+                        Some(&before.id),
+                    )
+                    .await?;
+                }
+            }
+
+            let after =
+                save_field::<_, devices::ActiveModel>(conn, &before, &updated, field).await?;
+            record_audit(
+                conn,
+                AuditEntry {
+                    resource_type: RESOURCE_DEVICE,
+                    resource_id: after.id,
+                    resource_name: Some(&after.name),
+                    action: ACTION_UPDATE,
+                    actor_id: Some(actor_id),
+                    before: Some(&before),
+                    after: Some(&after),
+                },
+            )
+            .await?;
+            Ok(power_overage)
+        }
+        RESOURCE_CONTACT => {
+            let before = contacts::Entity::find_by_id(conflict.resource_id)
+                .filter(contacts::Column::IsActive.eq(true))
+                .one(conn)
+                .await?
+                .ok_or_else(not_found)?;
+            let updated = with_field_value(&before, field, value)?;
+            let after =
+                save_field::<_, contacts::ActiveModel>(conn, &before, &updated, field).await?;
+            record_audit(
+                conn,
+                AuditEntry {
+                    resource_type: RESOURCE_CONTACT,
+                    resource_id: after.id,
+                    resource_name: Some(&after.name),
+                    action: ACTION_UPDATE,
+                    actor_id: Some(actor_id),
+                    before: Some(&before),
+                    after: Some(&after),
+                },
+            )
+            .await?;
+            Ok(None)
+        }
+        RESOURCE_DEVICE_LIBRARY => {
+            let before = device_library::Entity::find_by_id(conflict.resource_id)
+                .filter(device_library::Column::IsActive.eq(true))
+                .one(conn)
+                .await?
+                .ok_or_else(not_found)?;
+            let updated = with_field_value(&before, field, value)?;
+            let after =
+                save_field::<_, device_library::ActiveModel>(conn, &before, &updated, field)
+                    .await?;
+            record_audit(
+                conn,
+                AuditEntry {
+                    resource_type: RESOURCE_DEVICE_LIBRARY,
+                    resource_id: after.id,
+                    resource_name: Some(&after.name),
+                    action: ACTION_UPDATE,
+                    actor_id: Some(actor_id),
+                    before: Some(&before),
+                    after: Some(&after),
+                },
+            )
+            .await?;
+            Ok(None)
+        }
+        other => Err(Errors::SysInternalError(format!(
+            "Unsupported conflict resource_type '{}'",
+            other
+        ))),
+    }
+}
+
+/// 원본 값을 적용한다. 필드의 수동 수정 표시를 지워 이후 동기화가 다시 값을 관리한다.
+pub async fn service_accept_source(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    conflict_id: Uuid,
+    resolved_by: Uuid,
+) -> ServiceResult<SyncConflictResponse> {
+    let txn = conn.begin().await?;
+
+    let conflict = find_pending_conflict(&txn, tenant_id, conflict_id).await?;
+    let power_overage = apply_conflict_source(&txn, &conflict, resolved_by).await?;
+    if let Some(field_override) = find_override(&txn, &conflict).await? {
+        field_override.delete(&txn).await?;
+    }
+    let conflict = mark_resolved(&txn, conflict, CONFLICT_ACCEPTED_SOURCE, resolved_by).await?;
+
+    txn.commit().await?;
+
+    if let Some(overage) = power_overage {
+        notify_power_budget_overage(conn, overage, Some(resolved_by)).await;
+    }
+
+    Ok(to_conflict_response(conflict))
+}
+
+/// 현재 값을 유지한다. 이번 원본 값을 확인한 것으로 기록해 같은 값으로는 다시 충돌하지 않는다.
+pub async fn service_keep_local(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    conflict_id: Uuid,
+    resolved_by: Uuid,
+) -> ServiceResult<SyncConflictResponse> {
+    let txn = conn.begin().await?;
+
+    let conflict = find_pending_conflict(&txn, tenant_id, conflict_id).await?;
+    let now: DateTimeWithTimeZone = Utc::now().into();
+    match find_override(&txn, &conflict).await? {
+        Some(field_override) => {
+            let mut model: sync_field_overrides::ActiveModel = field_override.into();
+            model.source_value = ActiveValue::Set(conflict.source_value.clone());
+            model.updated_at = ActiveValue::Set(now);
+            model.update(&txn).await?;
+        }
+        // 표시가 해제된 뒤라면 다시 표시해 다음 동기화가 덮어쓰지 않게 한다
+        None => {
+            sync_field_overrides::ActiveModel {
+                id: ActiveValue::Set(Uuid::new_v4()),
+                resource_type: ActiveValue::Set(conflict.resource_type.clone()),
+                resource_id: ActiveValue::Set(conflict.resource_id),
+                field: ActiveValue::Set(conflict.field.clone()),
+                external_api_connection_id: ActiveValue::Set(conflict.external_api_connection_id),
+                source_value: ActiveValue::Set(conflict.source_value.clone()),
+                overridden_by: ActiveValue::Set(Some(resolved_by)),
+                created_at: ActiveValue::Set(now),
+                updated_at: ActiveValue::Set(now),
+            }
+            .insert(&txn)
+            .await?;
+        }
+    }
+    let conflict = mark_resolved(&txn, conflict, CONFLICT_KEPT_LOCAL, resolved_by).await?;
+
+    txn.commit().await?;
+    Ok(to_conflict_response(conflict))
+}
+
+/// 수동 수정 표시 목록 필터
+#[derive(Default)]
+pub struct SyncFieldOverrideFilter {
+    pub resource_type: Option<String>,
+    pub resource_id: Option<Uuid>,
+    pub connection_id: Option<i32>,
+}
+
+pub async fn service_get_field_overrides(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    filter: SyncFieldOverrideFilter,
+    page: u64,
+    limit: u64,
+) -> ServiceResult<SyncFieldOverrideListResponse> {
+    let page = page.max(1);
+    let limit = limit.clamp(1, 200);
+
+    let mut query = sync_field_overrides::Entity::find().filter(
+        sync_field_overrides::Column::ExternalApiConnectionId
+            .in_subquery(external_api_connections_in_tenant(tenant_id)),
+    );
+    if let Some(resource_type) = filter.resource_type {
+        query = query.filter(sync_field_overrides::Column::ResourceType.eq(resource_type));
+    }
+    if let Some(resource_id) = filter.resource_id {
+        query = query.filter(sync_field_overrides::Column::ResourceId.eq(resource_id));
+    }
+    if let Some(connection_id) = filter.connection_id {
+        query =
+            query.filter(sync_field_overrides::Column::ExternalApiConnectionId.eq(connection_id));
+    }
+
+    let paginator = query
+        .order_by_desc(sync_field_overrides::Column::UpdatedAt)
+        .paginate(conn, limit);
+    let total = paginator.num_items().await?;
+    let rows = paginator.fetch_page(page - 1).await?;
+
+    Ok(SyncFieldOverrideListResponse {
+        overrides: rows.into_iter().map(to_override_response).collect(),
+        total,
+        page,
+        limit,
+    })
+}
+
+/// 수동 수정 표시를 지워 필드를 원본에 돌려준다. 다음 동기화가 원본 값으로 덮어쓰며,
+/// 그 필드의 대기 중인 충돌은 의미가 없어지므로 함께 지운다.
+pub async fn service_delete_field_override(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    override_id: Uuid,
+) -> ServiceResult<()> {
+    let txn = conn.begin().await?;
+
+    let field_override = sync_field_overrides::Entity::find_by_id(override_id)
+        .filter(
+            sync_field_overrides::Column::ExternalApiConnectionId
+                .in_subquery(external_api_connections_in_tenant(tenant_id)),
+        )
+        .one(&txn)
+        .await?
+        .ok_or_else(|| Errors::NotFound(format!("Field override {} not found", override_id)))?;
+
+    sync_conflicts::Entity::delete_many()
+        .filter(sync_conflicts::Column::ResourceType.eq(&field_override.resource_type))
+        .filter(sync_conflicts::Column::ResourceId.eq(field_override.resource_id))
+        .filter(sync_conflicts::Column::Field.eq(&field_override.field))
+        .filter(sync_conflicts::Column::Status.eq(CONFLICT_PENDING))
+        .exec(&txn)
+        .await?;
+    field_override.delete(&txn).await?;
+
+    txn.commit().await?;
+    Ok(())
+}
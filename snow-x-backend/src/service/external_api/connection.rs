@@ -0,0 +1,491 @@
+use super::client::{MASKED_SECRET, SECRET_AUTH_KEYS, test_connection, validate_auth_config};
+use super::conflict::{FieldPolicies, stored_field_policies, validate_field_policies};
+use super::{DEFAULT_SYNC_INTERVAL, MIN_SYNC_INTERVAL, TARGET_TYPES};
+use crate::config::db_config::DbConfig;
+use crate::connection::outbound::{OutboundHttpClient, is_private_host};
+use crate::dto::external_api::request::{
+    CreateExternalApiConnectionRequest, FieldMapping, TestExternalApiConnectionRequest,
+    UpdateExternalApiConnectionRequest,
+};
+use crate::dto::external_api::response::{
+    ExternalApiConnectionListResponse, ExternalApiConnectionResponse,
+    TestExternalApiConnectionResponse,
+};
+use crate::entity::{contacts, device_library, devices, external_api_connections};
+use crate::service::error::errors::{Errors, ServiceResult};
+use chrono::Utc;
+use reqwest::Url;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const FIELD_DATA_TYPES: [&str; 4] = ["string", "number", "boolean", "date"];
+const FIELD_TRANSFORMATIONS: [&str; 4] = ["uppercase", "lowercase", "trim", "title"];
+const FILTER_OPERATORS: [&str; 3] = ["equals", "not_equals", "contains"];
+
+/// 연결 조회 (없으면 404)
+pub async fn find_connection<C>(conn: &C, id: i32) -> ServiceResult<external_api_connections::Model>
+where
+    C: ConnectionTrait,
+{
+    external_api_connections::Entity::find_by_id(id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| Errors::NotFound(format!("External API connection {} not found", id)))
+}
+
+/// 저장된 JSON 헤더를 문자열 맵으로 읽는다 (문자열이 아닌 값은 JSON 표기로)
+pub fn stored_headers(model: &external_api_connections::Model) -> HashMap<String, String> {
+    model
+        .headers
+        .as_ref()
+        .and_then(Value::as_object)
+        .map(|map| {
+            map.iter()
+                .map(|(name, value)| {
+                    let value = match value {
+                        Value::String(text) => text.clone(),
+                        other => other.to_string(),
+                    };
+                    (name.clone(), value)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 저장된 필드 매핑. 형식이 맞지 않으면 매핑 없음(원본 그대로)으로 본다.
+pub fn stored_field_mapping(model: &external_api_connections::Model) -> Option<FieldMapping> {
+    model
+        .field_mapping
+        .clone()
+        .and_then(|value| serde_json::from_value(value).ok())
+}
+
+/// 응답용으로 auth_config의 비밀 값을 가린다
+fn mask_auth_config(auth_config: &Value) -> Value {
+    let mut masked = auth_config.clone();
+    if let Some(map) = masked.as_object_mut() {
+        for key in SECRET_AUTH_KEYS {
+            if let Some(value) = map.get_mut(key)
+                && value.as_str().is_some_and(|text| !text.is_empty())
+            {
+                *value = Value::String(MASKED_SECRET.to_string());
+            }
+        }
+    }
+    masked
+}
+
+/// 수정 요청에 가려진 값(`********`)이 그대로 오면 기존 비밀 값을 유지한다
+fn restore_masked_secrets(mut auth_config: Value, existing: Option<&Value>) -> Value {
+    if let Some(map) = auth_config.as_object_mut() {
+        for key in SECRET_AUTH_KEYS {
+            if map.get(key).and_then(Value::as_str) == Some(MASKED_SECRET)
+                && let Some(previous) = existing.and_then(|config| config.get(key))
+            {
+                map.insert(key.to_string(), previous.clone());
+            }
+        }
+    }
+    auth_config
+}
+
+pub fn to_connection_response(
+    model: &external_api_connections::Model,
+) -> ExternalApiConnectionResponse {
+    ExternalApiConnectionResponse {
+        id: model.id,
+        name: model.name.clone(),
+        base_url: model.base_url.clone(),
+        description: model.description.clone(),
+        target_type: model.target_type.clone(),
+        headers: model.headers.as_ref().map(|_| stored_headers(model)),
+        auth_config: model.auth_config.as_ref().map(mask_auth_config),
+        field_mapping: stored_field_mapping(model),
+        field_policies: stored_field_policies(model),
+        sync_interval: model.sync_interval.unwrap_or(DEFAULT_SYNC_INTERVAL),
+        is_active: model.is_active.unwrap_or(true),
+        auto_sync: model.auto_sync.unwrap_or(true),
+        last_sync_at: model.last_sync_at.map(Into::into),
+        next_sync_at: model.next_sync_at,
+        sync_count: model.sync_count.unwrap_or(0),
+        last_error_message: model.last_error_message.clone(),
+        created_at: model.created_at.into(),
+        updated_at: model.updated_at.into(),
+    }
+}
+
+fn validate_name(name: &str) -> ServiceResult<String> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > 255 {
+        return Err(Errors::ValidationError(
+            "name must be 1-255 characters".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+fn validate_base_url(base_url: &str) -> ServiceResult<String> {
+    let base_url = base_url.trim();
+    if base_url.len() > 500 {
+        return Err(Errors::ValidationError(
+            "base_url must be at most 500 characters".to_string(),
+        ));
+    }
+    let url = Url::parse(base_url)
+        .map_err(|_| Errors::ValidationError(format!("'{}' is not a valid URL", base_url)))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(Errors::ValidationError(
+            "base_url must use http or https".to_string(),
+        ));
+    }
+    // 이름이 가리키는 주소는 요청할 때마다 다시 확인한다 (`OutboundHttpClient`)
+    if !DbConfig::get().external_api_allow_private_urls && is_private_host(&url) {
+        return Err(Errors::ValidationError(
+            "base_url must not point to a local or private address".to_string(),
+        ));
+    }
+    Ok(base_url.to_string())
+}
+
+fn validate_target_type(target_type: &str) -> ServiceResult<()> {
+    if !TARGET_TYPES.contains(&target_type) {
+        return Err(Errors::ValidationError(format!(
+            "Invalid target_type '{}' (expected one of: {})",
+            target_type,
+            TARGET_TYPES.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+fn validate_sync_interval(sync_interval: i32) -> ServiceResult<()> {
+    if sync_interval < MIN_SYNC_INTERVAL {
+        return Err(Errors::ValidationError(format!(
+            "sync_interval must be at least {} seconds",
+            MIN_SYNC_INTERVAL
+        )));
+    }
+    Ok(())
+}
+
+fn validate_field_mapping(mapping: &FieldMapping) -> ServiceResult<()> {
+    for rule in &mapping.mappings {
+        if rule.source_field.trim().is_empty() || rule.target_field.trim().is_empty() {
+            return Err(Errors::ValidationError(
+                "Field mappings need both source_field and target_field".to_string(),
+            ));
+        }
+        if let Some(data_type) = rule.data_type.as_deref().filter(|v| !v.is_empty())
+            && !FIELD_DATA_TYPES.contains(&data_type)
+        {
+            return Err(Errors::ValidationError(format!(
+                "Invalid data_type '{}' for {} (expected one of: {})",
+                data_type,
+                rule.source_field,
+                FIELD_DATA_TYPES.join(", ")
+            )));
+        }
+        if let Some(transformation) = rule.transformation.as_deref().filter(|v| !v.is_empty())
+            && !FIELD_TRANSFORMATIONS.contains(&transformation)
+        {
+            return Err(Errors::ValidationError(format!(
+                "Invalid transformation '{}' for {} (expected one of: {})",
+                transformation,
+                rule.source_field,
+                FIELD_TRANSFORMATIONS.join(", ")
+            )));
+        }
+    }
+    for condition in &mapping.filter_conditions {
+        if !FILTER_OPERATORS.contains(&condition.operator.as_str()) {
+            return Err(Errors::ValidationError(format!(
+                "Invalid filter operator '{}' (expected one of: {})",
+                condition.operator,
+                FILTER_OPERATORS.join(", ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+async fn ensure_name_available<C>(conn: &C, name: &str, exclude: Option<i32>) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    let mut query = external_api_connections::Entity::find()
+        .filter(external_api_connections::Column::Name.eq(name));
+    if let Some(id) = exclude {
+        query = query.filter(external_api_connections::Column::Id.ne(id));
+    }
+    if query.count(conn).await? > 0 {
+        return Err(Errors::ExternalApiNameExists(format!(
+            "External API connection '{}' already exists",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// 자동 동기화가 켜진 활성 연결이면 지금부터 주기 뒤, 아니면 예약 없음
+pub(crate) fn schedule_next_sync(
+    is_active: bool,
+    auto_sync: bool,
+    sync_interval: i32,
+) -> Option<i32> {
+    (is_active && auto_sync).then(|| Utc::now().timestamp() as i32 + sync_interval)
+}
+
+/// 동기화가 연속으로 `failures`번 실패했을 때의 다음 재시도 시각.
+/// `EXTERNAL_API_SYNC_RETRY_BASE`초부터 두 배씩 늘려 `EXTERNAL_API_SYNC_MAX_BACKOFF`초를 넘지 않는다.
+pub(crate) fn schedule_retry(is_active: bool, auto_sync: bool, failures: u32) -> Option<i32> {
+    (is_active && auto_sync).then(|| {
+        let config = DbConfig::get();
+        let base = config.external_api_sync_retry_base.max(1);
+        let max = config.external_api_sync_max_backoff.max(base);
+        let exponent = failures.saturating_sub(1).min(20);
+        let delay = base.saturating_mul(1 << exponent).min(max);
+        let delay = i32::try_from(delay).unwrap_or(i32::MAX);
+        (Utc::now().timestamp() as i32).saturating_add(delay)
+    })
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> ServiceResult<Value> {
+    serde_json::to_value(value).map_err(|e| Errors::SysInternalError(e.to_string()))
+}
+
+pub async fn service_get_connections(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    page: u64,
+    limit: u64,
+    target_type: Option<String>,
+    is_active: Option<bool>,
+) -> ServiceResult<ExternalApiConnectionListResponse> {
+    let page = page.max(1);
+    let limit = limit.clamp(1, 200);
+
+    let mut query = external_api_connections::Entity::find()
+        .filter(external_api_connections::Column::TenantId.eq(tenant_id));
+    if let Some(target_type) = target_type {
+        query = query.filter(external_api_connections::Column::TargetType.eq(target_type));
+    }
+    if let Some(is_active) = is_active {
+        query = query.filter(external_api_connections::Column::IsActive.eq(is_active));
+    }
+
+    let paginator = query
+        .order_by_asc(external_api_connections::Column::Name)
+        .paginate(conn, limit);
+    let total = paginator.num_items().await?;
+    let connections = paginator.fetch_page(page - 1).await?;
+
+    Ok(ExternalApiConnectionListResponse {
+        connections: connections.iter().map(to_connection_response).collect(),
+        total,
+        page,
+        limit,
+    })
+}
+
+pub async fn service_get_connection(
+    conn: &DatabaseConnection,
+    id: i32,
+) -> ServiceResult<ExternalApiConnectionResponse> {
+    let connection = find_connection(conn, id).await?;
+    Ok(to_connection_response(&connection))
+}
+
+pub async fn service_create_connection(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    request: CreateExternalApiConnectionRequest,
+) -> ServiceResult<ExternalApiConnectionResponse> {
+    let name = validate_name(&request.name)?;
+    let base_url = validate_base_url(&request.base_url)?;
+    validate_target_type(&request.target_type)?;
+    let sync_interval = request.sync_interval.unwrap_or(DEFAULT_SYNC_INTERVAL);
+    validate_sync_interval(sync_interval)?;
+    if let Some(auth_config) = request.auth_config.as_ref() {
+        validate_auth_config(auth_config)?;
+    }
+    if let Some(mapping) = request.field_mapping.as_ref() {
+        validate_field_mapping(mapping)?;
+    }
+    if let Some(policies) = request.field_policies.as_ref() {
+        validate_field_policies(&request.target_type, policies)?;
+    }
+    ensure_name_available(conn, &name, None).await?;
+
+    let is_active = request.is_active.unwrap_or(true);
+    let auto_sync = request.auto_sync.unwrap_or(true);
+    let now = Utc::now();
+
+    let connection = external_api_connections::ActiveModel {
+        id: ActiveValue::NotSet,
+        tenant_id: ActiveValue::Set(Some(tenant_id)),
+        name: ActiveValue::Set(name),
+        base_url: ActiveValue::Set(base_url),
+        description: ActiveValue::Set(request.description),
+        headers: ActiveValue::Set(request.headers.as_ref().map(to_json).transpose()?),
+        auth_config: ActiveValue::Set(request.auth_config),
+        sync_interval: ActiveValue::Set(Some(sync_interval)),
+        is_active: ActiveValue::Set(Some(is_active)),
+        auto_sync: ActiveValue::Set(Some(auto_sync)),
+        last_sync_at: ActiveValue::Set(None),
+        next_sync_at: ActiveValue::Set(schedule_next_sync(is_active, auto_sync, sync_interval)),
+        sync_count: ActiveValue::Set(Some(0)),
+        last_error_message: ActiveValue::Set(None),
+        created_at: ActiveValue::Set(now.into()),
+        updated_at: ActiveValue::Set(now.into()),
+        field_mapping: ActiveValue::Set(request.field_mapping.as_ref().map(to_json).transpose()?),
+        field_policies: ActiveValue::Set(request.field_policies.as_ref().map(to_json).transpose()?),
+        target_type: ActiveValue::Set(request.target_type),
+    }
+    .insert(conn)
+    .await?;
+
+    Ok(to_connection_response(&connection))
+}
+
+pub async fn service_update_connection(
+    conn: &DatabaseConnection,
+    id: i32,
+    request: UpdateExternalApiConnectionRequest,
+) -> ServiceResult<ExternalApiConnectionResponse> {
+    let existing = find_connection(conn, id).await?;
+    let mut model: external_api_connections::ActiveModel = existing.clone().into();
+
+    if let Some(name) = request.name.as_deref() {
+        let name = validate_name(name)?;
+        ensure_name_available(conn, &name, Some(id)).await?;
+        model.name = ActiveValue::Set(name);
+    }
+    if let Some(base_url) = request.base_url.as_deref() {
+        model.base_url = ActiveValue::Set(validate_base_url(base_url)?);
+    }
+    if let Some(description) = request.description {
+        model.description = ActiveValue::Set(Some(description));
+    }
+    if let Some(target_type) = request.target_type.as_ref() {
+        validate_target_type(target_type)?;
+        model.target_type = ActiveValue::Set(target_type.clone());
+    }
+    // 대상 종류가 바뀌면 기존 규칙도 새 대상의 필드 기준으로 다시 검사한다
+    if request.field_policies.is_some() || request.target_type.is_some() {
+        let policies: FieldPolicies = request
+            .field_policies
+            .clone()
+            .unwrap_or_else(|| stored_field_policies(&existing));
+        let target_type = request
+            .target_type
+            .as_deref()
+            .unwrap_or(&existing.target_type);
+        validate_field_policies(target_type, &policies)?;
+        if request.field_policies.is_some() {
+            model.field_policies = ActiveValue::Set(Some(to_json(&policies)?));
+        }
+    }
+    if let Some(headers) = request.headers.as_ref() {
+        model.headers = ActiveValue::Set(Some(to_json(headers)?));
+    }
+    if let Some(auth_config) = request.auth_config {
+        let auth_config = restore_masked_secrets(auth_config, existing.auth_config.as_ref());
+        validate_auth_config(&auth_config)?;
+        model.auth_config = ActiveValue::Set(Some(auth_config));
+    }
+    if let Some(mapping) = request.field_mapping.as_ref() {
+        validate_field_mapping(mapping)?;
+        model.field_mapping = ActiveValue::Set(Some(to_json(mapping)?));
+    }
+    if let Some(sync_interval) = request.sync_interval {
+        validate_sync_interval(sync_interval)?;
+        model.sync_interval = ActiveValue::Set(Some(sync_interval));
+    }
+    if let Some(is_active) = request.is_active {
+        model.is_active = ActiveValue::Set(Some(is_active));
+    }
+    if let Some(auto_sync) = request.auto_sync {
+        model.auto_sync = ActiveValue::Set(Some(auto_sync));
+    }
+
+    // 주기나 자동 동기화 여부가 바뀌면 다음 예약을 다시 잡는다
+    if request.sync_interval.is_some() || request.auto_sync.is_some() || request.is_active.is_some()
+    {
+        model.next_sync_at = ActiveValue::Set(schedule_next_sync(
+            request
+                .is_active
+                .unwrap_or(existing.is_active.unwrap_or(true)),
+            request
+                .auto_sync
+                .unwrap_or(existing.auto_sync.unwrap_or(true)),
+            request
+                .sync_interval
+                .unwrap_or(existing.sync_interval.unwrap_or(DEFAULT_SYNC_INTERVAL)),
+        ));
+    }
+    model.updated_at = ActiveValue::Set(Utc::now().into());
+
+    let connection = model.update(conn).await?;
+    Ok(to_connection_response(&connection))
+}
+
+/// 연결을 삭제한다. 동기화 로그와 원본 데이터는 함께 삭제되고(cascade),
+/// 동기화로 만든 장비/담당자/라이브러리는 남기되 연결 참조만 지운다.
+pub async fn service_delete_connection(conn: &DatabaseConnection, id: i32) -> ServiceResult<()> {
+    let txn = conn.begin().await?;
+    let connection = find_connection(&txn, id).await?;
+
+    devices::Entity::update_many()
+        .col_expr(
+            devices::Column::ExternalApiConnectionId,
+            Expr::value(Option::<i32>::None),
+        )
+        .filter(devices::Column::ExternalApiConnectionId.eq(id))
+        .exec(&txn)
+        .await?;
+    contacts::Entity::update_many()
+        .col_expr(
+            contacts::Column::ExternalApiConnectionId,
+            Expr::value(Option::<i32>::None),
+        )
+        .filter(contacts::Column::ExternalApiConnectionId.eq(id))
+        .exec(&txn)
+        .await?;
+    device_library::Entity::update_many()
+        .col_expr(
+            device_library::Column::ExternalApiConnectionId,
+            Expr::value(Option::<i32>::None),
+        )
+        .filter(device_library::Column::ExternalApiConnectionId.eq(id))
+        .exec(&txn)
+        .await?;
+
+    connection.delete(&txn).await?;
+    txn.commit().await?;
+    Ok(())
+}
+
+/// 저장하지 않고 연결을 확인한다
+pub async fn service_test_connection(
+    client: &OutboundHttpClient,
+    request: TestExternalApiConnectionRequest,
+) -> ServiceResult<TestExternalApiConnectionResponse> {
+    let base_url = validate_base_url(&request.base_url)?;
+    if let Some(auth_config) = request.auth_config.as_ref() {
+        validate_auth_config(auth_config)?;
+    }
+    Ok(test_connection(
+        client,
+        &base_url,
+        &request.headers.unwrap_or_default(),
+        request.auth_config.as_ref(),
+    )
+    .await)
+}
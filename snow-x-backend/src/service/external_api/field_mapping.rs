@@ -0,0 +1,273 @@
+use crate::dto::external_api::request::{FieldMapping, FilterCondition};
+use serde_json::{Map, Number, Value};
+use sha2::{Digest, Sha256};
+
+/// 필드 매핑을 적용한다. 필터 조건에 맞지 않으면 `None` (동기화 대상에서 제외).
+/// 매핑 규칙이 없으면 원본 항목을 그대로 쓴다.
+pub fn apply_field_mapping(item: &Value, mapping: &FieldMapping) -> Option<Value> {
+    if !mapping
+        .filter_conditions
+        .iter()
+        .all(|condition| matches_condition(item, condition))
+    {
+        return None;
+    }
+
+    if mapping.mappings.is_empty() {
+        return Some(item.clone());
+    }
+
+    let mut result = Value::Object(Map::new());
+    for rule in &mapping.mappings {
+        if rule.source_field.is_empty() || rule.target_field.is_empty() {
+            continue;
+        }
+
+        let mut value = nested_value(item, &rule.source_field)
+            .cloned()
+            .unwrap_or(Value::Null);
+        if value.is_null()
+            && let Some(default) = rule.default_value.as_ref()
+        {
+            value = default.clone();
+        }
+
+        let value = convert_data_type(
+            value,
+            rule.data_type
+                .as_deref()
+                .filter(|v| !v.is_empty())
+                .unwrap_or("string"),
+        );
+        let value = apply_transformation(value, rule.transformation.as_deref());
+        set_nested_value(&mut result, &rule.target_field, value);
+    }
+
+    Some(result)
+}
+
+/// `a.b.c` 경로의 값. 중간에 객체가 아니면 `None`
+fn nested_value<'a>(item: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(item, |value, key| value.as_object()?.get(key))
+}
+
+fn set_nested_value(target: &mut Value, path: &str, value: Value) {
+    let mut keys: Vec<&str> = path.split('.').collect();
+    let last = keys.pop().unwrap_or(path);
+
+    let mut current = target;
+    for key in keys {
+        let map = as_object_mut(current);
+        current = map
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+    as_object_mut(current).insert(last.to_string(), value);
+}
+
+/// 객체가 아니면 빈 객체로 바꾼 뒤 돌려준다
+fn as_object_mut(value: &mut Value) -> &mut Map<String, Value> {
+    if !value.is_object() {
+        *value = Value::Object(Map::new());
+    }
+    match value {
+        Value::Object(map) => map,
+        _ => unreachable!(),
+    }
+}
+
+/// 비교/포함 검사용 문자열 표현 (문자열은 따옴표 없이, null은 빈 문자열)
+fn scalar_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn matches_condition(item: &Value, condition: &FilterCondition) -> bool {
+    if condition.field.is_empty() {
+        return true;
+    }
+
+    let actual = nested_value(item, &condition.field).unwrap_or(&Value::Null);
+    // UI는 조건 값을 문자열로 보내므로 "1"과 1, "true"와 true는 같은 값으로 본다
+    let equals = actual == &condition.value
+        || (!actual.is_null()
+            && !actual.is_object()
+            && !actual.is_array()
+            && scalar_text(actual) == scalar_text(&condition.value));
+
+    match condition.operator.as_str() {
+        "equals" => equals,
+        "not_equals" => !equals,
+        "contains" => scalar_text(actual).contains(&scalar_text(&condition.value)),
+        _ => true,
+    }
+}
+
+/// 타입 변환. 변환할 수 없으면 원래 값을 유지한다.
+fn convert_data_type(value: Value, data_type: &str) -> Value {
+    match (data_type, value) {
+        (_, Value::Null) => Value::Null,
+        ("string", value) => Value::String(scalar_text(&value)),
+        ("number", Value::String(text)) => {
+            let trimmed = text.trim();
+            let parsed = if trimmed.contains('.') {
+                trimmed.parse::<f64>().ok().and_then(Number::from_f64)
+            } else {
+                trimmed.parse::<i64>().ok().map(Number::from)
+            };
+            parsed.map(Value::Number).unwrap_or(Value::String(text))
+        }
+        ("number", Value::Bool(flag)) => Value::Number(Number::from(flag as i64)),
+        ("boolean", Value::Bool(flag)) => Value::Bool(flag),
+        ("boolean", value) => Value::Bool(matches!(
+            scalar_text(&value).to_lowercase().as_str(),
+            "true" | "1" | "yes" | "on"
+        )),
+        ("date", Value::String(text)) => parse_date(&text)
+            .map(Value::String)
+            .unwrap_or(Value::String(text)),
+        (_, value) => value,
+    }
+}
+
+/// 흔한 날짜 형식을 ISO 8601로 맞춘다
+fn parse_date(text: &str) -> Option<String> {
+    let text = text.trim();
+    if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(text) {
+        return Some(datetime.to_rfc3339());
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"] {
+        if let Ok(datetime) = chrono::NaiveDateTime::parse_from_str(text, format) {
+            return Some(datetime.format("%Y-%m-%dT%H:%M:%S").to_string());
+        }
+    }
+    for format in ["%Y-%m-%d", "%Y/%m/%d"] {
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(text, format) {
+            return Some(date.format("%Y-%m-%d").to_string());
+        }
+    }
+    None
+}
+
+fn apply_transformation(value: Value, transformation: Option<&str>) -> Value {
+    let Value::String(text) = value else {
+        return value;
+    };
+    Value::String(match transformation {
+        Some("uppercase") => text.to_uppercase(),
+        Some("lowercase") => text.to_lowercase(),
+        Some("trim") => text.trim().to_string(),
+        Some("title") => title_case(&text),
+        _ => text,
+    })
+}
+
+/// 글자가 아닌 문자 뒤의 첫 글자는 대문자, 나머지는 소문자
+fn title_case(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut word_start = true;
+    for c in text.chars() {
+        if word_start {
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+        word_start = !c.is_alphabetic();
+    }
+    result
+}
+
+/// 항목의 내용 해시 (sha256 hex).
+/// 기존 Python 동기화 작업이 저장한 해시와 같도록 `json.dumps(item, sort_keys=True)`와
+/// 같은 형태(키 정렬, `", "`/`": "` 구분자, 비 ASCII는 `\uXXXX`)로 직렬화한다.
+pub fn content_hash(item: &Value) -> String {
+    let mut canonical = String::new();
+    write_canonical(&mut canonical, item);
+    format!("{:x}", Sha256::digest(canonical.as_bytes()))
+}
+
+fn write_canonical(out: &mut String, value: &Value) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(flag) => out.push_str(if *flag { "true" } else { "false" }),
+        Value::Number(number) => out.push_str(&number.to_string()),
+        Value::String(text) => write_canonical_string(out, text),
+        Value::Array(items) => {
+            out.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push_str(", ");
+                }
+                write_canonical(out, item);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort_unstable();
+            out.push('{');
+            for (index, key) in keys.into_iter().enumerate() {
+                if index > 0 {
+                    out.push_str(", ");
+                }
+                write_canonical_string(out, key);
+                out.push_str(": ");
+                write_canonical(out, &map[key]);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_canonical_string(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            c if c.is_ascii() && !c.is_ascii_control() => out.push(c),
+            c => {
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    out.push_str(&format!("\\u{:04x}", unit));
+                }
+            }
+        }
+    }
+    out.push('"');
+}
+
+/// 외부 시스템의 ID (`id`, `_id`, `uuid` 순). 비어 있거나 false인 값은 없는 것으로 본다.
+pub fn external_id(item: &Value) -> Option<String> {
+    ["id", "_id", "uuid"]
+        .iter()
+        .find_map(|key| match item.get(key)? {
+            Value::String(text) if !text.is_empty() => Some(text.clone()),
+            Value::Number(number) if number.as_f64() != Some(0.0) => Some(number.to_string()),
+            _ => None,
+        })
+}
+
+/// 원본 데이터 분류 (Python 동기화 작업과 같은 규칙)
+pub fn infer_data_type(item: &Value) -> &'static str {
+    let text = item.to_string().to_lowercase();
+    let has_key = |key: &str| item.get(key).is_some();
+    if text.contains("device") || has_key("serial_number") {
+        "device"
+    } else if text.contains("user") || has_key("email") {
+        "user"
+    } else if text.contains("log") || has_key("timestamp") {
+        "log"
+    } else {
+        "general"
+    }
+}
@@ -0,0 +1,107 @@
+use super::DATA_STATUS_ACTIVE;
+use super::connection::find_connection;
+use crate::dto::external_api::response::{
+    ExternalApiDataListResponse, ExternalApiDataResponse, ExternalApiSyncLogListResponse,
+    ExternalApiSyncLogResponse,
+};
+use crate::entity::{external_api_data, external_api_sync_logs};
+use crate::service::error::errors::ServiceResult;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+};
+
+pub fn to_sync_log_response(log: &external_api_sync_logs::Model) -> ExternalApiSyncLogResponse {
+    ExternalApiSyncLogResponse {
+        id: log.id,
+        connection_id: log.connection_id,
+        status: log.status.clone(),
+        request_url: log.request_url.clone(),
+        request_method: log.request_method.clone(),
+        response_status: log.response_status,
+        records_processed: log.records_processed.unwrap_or(0),
+        error_message: log.error_message.clone(),
+        duration: log.duration,
+        started_at: log.started_at.into(),
+        completed_at: log.completed_at.map(Into::into),
+    }
+}
+
+/// 연결의 동기화 실행 기록 (최근 순)
+pub async fn service_get_sync_logs(
+    conn: &DatabaseConnection,
+    connection_id: i32,
+    page: u64,
+    limit: u64,
+) -> ServiceResult<ExternalApiSyncLogListResponse> {
+    find_connection(conn, connection_id).await?;
+    let page = page.max(1);
+    let limit = limit.clamp(1, 200);
+
+    let paginator = external_api_sync_logs::Entity::find()
+        .filter(external_api_sync_logs::Column::ConnectionId.eq(connection_id))
+        .order_by_desc(external_api_sync_logs::Column::StartedAt)
+        .order_by_desc(external_api_sync_logs::Column::Id)
+        .paginate(conn, limit);
+    let total = paginator.num_items().await?;
+    let logs = paginator.fetch_page(page - 1).await?;
+
+    Ok(ExternalApiSyncLogListResponse {
+        logs: logs.iter().map(to_sync_log_response).collect(),
+        total,
+        page,
+        limit,
+    })
+}
+
+/// 연결에서 받아 둔 원본 데이터 (기본: active만, 최근 동기화 순)
+pub async fn service_get_synced_data(
+    conn: &DatabaseConnection,
+    connection_id: i32,
+    page: u64,
+    limit: u64,
+    data_type: Option<String>,
+    status: Option<String>,
+) -> ServiceResult<ExternalApiDataListResponse> {
+    find_connection(conn, connection_id).await?;
+    let page = page.max(1);
+    let limit = limit.clamp(1, 200);
+
+    let mut query = external_api_data::Entity::find()
+        .filter(external_api_data::Column::ConnectionId.eq(connection_id))
+        .filter(
+            external_api_data::Column::Status
+                .eq(status.unwrap_or_else(|| DATA_STATUS_ACTIVE.to_string())),
+        );
+    if let Some(data_type) = data_type {
+        query = query.filter(external_api_data::Column::DataType.eq(data_type));
+    }
+
+    let paginator = query
+        .order_by_desc(external_api_data::Column::LastSyncAt)
+        .order_by_desc(external_api_data::Column::Id)
+        .paginate(conn, limit);
+    let total = paginator.num_items().await?;
+    let rows = paginator.fetch_page(page - 1).await?;
+
+    Ok(ExternalApiDataListResponse {
+        data: rows
+            .into_iter()
+            .map(|row| ExternalApiDataResponse {
+                id: row.id,
+                connection_id: row.connection_id,
+                external_id: row.external_id,
+                data_type: row.data_type,
+                raw_data: row.raw_data,
+                processed_data: row.processed_data,
+                hash: row.hash,
+                status: row.status.unwrap_or_else(|| DATA_STATUS_ACTIVE.to_string()),
+                last_sync_at: row.last_sync_at.into(),
+                created_at: row.created_at.into(),
+                updated_at: row.updated_at.into(),
+            })
+            .collect(),
+        total,
+        page,
+        limit,
+    })
+}
@@ -0,0 +1,50 @@
+//! 외부 API 연결과 동기화.
+//!
+//! 연결마다 `base_url`을 GET으로 불러 JSON 항목 목록을 받고, `field_mapping`을 적용해
+//! `target_type`(장비/장비 라이브러리/담당자) 테이블에 upsert한다. 받은 원본 항목은
+//! `external_api_data`에 내용 해시와 함께 보관해 바뀐 항목만 다시 쓰고, 응답에서 사라진
+//! 항목은 보관(archived) 처리한다. 실행마다 `external_api_sync_logs`에 한 행을 남긴다.
+//!
+//! 사람이 수정한 필드는 연결의 `field_policies`에 따라 보호하거나 거부한다 (`conflict`).
+//!
+//! 자동 동기화는 `scheduler`가 `next_sync_at`이 지난 연결을 골라 실행한다.
+
+pub mod client;
+pub mod conflict;
+pub mod connection;
+pub mod field_mapping;
+pub mod history;
+pub mod scheduler;
+pub mod sync;
+pub mod targets;
+
+pub use conflict::{
+    service_accept_source, service_delete_field_override, service_get_field_overrides,
+    service_get_sync_conflicts, service_keep_local,
+};
+pub use connection::{
+    service_create_connection, service_delete_connection, service_get_connection,
+    service_get_connections, service_test_connection, service_update_connection,
+};
+pub use history::{service_get_sync_logs, service_get_synced_data};
+pub use sync::service_sync_connection;
+
+pub const TARGET_DEVICE: &str = "device";
+pub const TARGET_DEVICE_LIBRARY: &str = "device_library";
+pub const TARGET_CONTACT: &str = "contact";
+pub const TARGET_TYPES: [&str; 3] = [TARGET_DEVICE, TARGET_DEVICE_LIBRARY, TARGET_CONTACT];
+
+/// 동기화로 만들어진 행의 `source_type`
+pub const SOURCE_TYPE_API_SYNC: &str = "api_sync";
+
+pub const SYNC_STATUS_IN_PROGRESS: &str = "in_progress";
+pub const SYNC_STATUS_SUCCESS: &str = "success";
+pub const SYNC_STATUS_ERROR: &str = "error";
+
+pub const DATA_STATUS_ACTIVE: &str = "active";
+pub const DATA_STATUS_ARCHIVED: &str = "archived";
+
+/// 기본 동기화 주기 (초)
+pub const DEFAULT_SYNC_INTERVAL: i32 = 3600;
+/// 외부 API를 너무 자주 부르지 않도록 하는 최소 주기 (초)
+pub const MIN_SYNC_INTERVAL: i32 = 60;
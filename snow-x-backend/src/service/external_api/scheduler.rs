@@ -0,0 +1,183 @@
+use super::SYNC_STATUS_ERROR;
+use super::connection::find_connection;
+use super::sync::{run_sync, try_lock_connection};
+use crate::config::db_config::DbConfig;
+use crate::connection::outbound::OutboundHttpClient;
+use crate::entity::external_api_connections;
+use crate::service::error::errors::ServiceResult;
+use chrono::Utc;
+use sea_orm::sea_query::{Condition, NullOrdering, Order};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, error, info, warn};
+
+/// 한 번에 가져오는 실행 대상 연결 수
+const DUE_BATCH: u64 = 100;
+
+/// 자동 동기화가 켜진 활성 연결이고 예약 시각이 지났는지.
+/// 예약 시각이 없는 연결(이전 버전에서 만든 연결)은 바로 실행한다.
+fn is_due(connection: &external_api_connections::Model, now: i32) -> bool {
+    connection.is_active != Some(false)
+        && connection.auto_sync != Some(false)
+        && connection.next_sync_at.is_none_or(|at| at <= now)
+}
+
+async fn find_due_connections(conn: &DatabaseConnection) -> ServiceResult<Vec<i32>> {
+    use external_api_connections::Column;
+
+    let now = Utc::now().timestamp() as i32;
+    Ok(external_api_connections::Entity::find()
+        .select_only()
+        .column(Column::Id)
+        .filter(
+            Condition::any()
+                .add(Column::IsActive.eq(true))
+                .add(Column::IsActive.is_null()),
+        )
+        .filter(
+            Condition::any()
+                .add(Column::AutoSync.eq(true))
+                .add(Column::AutoSync.is_null()),
+        )
+        .filter(
+            Condition::any()
+                .add(Column::NextSyncAt.lte(now))
+                .add(Column::NextSyncAt.is_null()),
+        )
+        .order_by_with_nulls(Column::NextSyncAt, Order::Asc, NullOrdering::First)
+        .limit(DUE_BATCH)
+        .into_tuple()
+        .all(conn)
+        .await?)
+}
+
+/// 예약된 연결 하나를 실행한다. 다른 인스턴스가 실행 중이거나 방금 끝내 더 이상
+/// 예약 시각이 아니면 건너뛴다.
+async fn run_due_sync(
+    conn: &DatabaseConnection,
+    http_client: &OutboundHttpClient,
+    connection_id: i32,
+) -> ServiceResult<()> {
+    let Some(lock) = try_lock_connection(conn, connection_id).await? else {
+        debug!(
+            "External API connection {} is syncing elsewhere; skipping",
+            connection_id
+        );
+        return Ok(());
+    };
+
+    let result = async {
+        // 잠금을 잡은 뒤 다시 읽어 다른 인스턴스가 갱신한 예약 시각을 반영한다
+        let connection = find_connection(&lock, connection_id).await?;
+        if !is_due(&connection, Utc::now().timestamp() as i32) {
+            return Ok(None);
+        }
+        run_sync(conn, http_client, connection, None)
+            .await
+            .map(Some)
+    }
+    .await;
+
+    lock.commit().await?;
+
+    let Some(response) = result? else {
+        return Ok(());
+    };
+    if response.log.status == SYNC_STATUS_ERROR {
+        warn!(
+            "Scheduled sync of external API connection {} failed: {}",
+            connection_id,
+            response
+                .log
+                .error_message
+                .as_deref()
+                .unwrap_or("unknown error")
+        );
+    } else {
+        info!(
+            "Scheduled sync of external API connection {}: fetched={}, changed={}, created={}, updated={}, archived={}, errors={}",
+            connection_id,
+            response.fetched,
+            response.changed,
+            response.created,
+            response.updated,
+            response.archived,
+            response.errors.len()
+        );
+    }
+    Ok(())
+}
+
+/// 예약 시각이 된 연결을 주기적으로 찾아 동기화하는 백그라운드 태스크를 시작한다.
+///
+/// 실행 수는 `EXTERNAL_API_SYNC_CONCURRENCY`로 제한하고, 같은 연결은 Postgres advisory lock으로
+/// 모든 인스턴스를 통틀어 하나만 실행한다. `EXTERNAL_API_SYNC_POLL_INTERVAL=0`이면 시작하지 않는다.
+pub fn spawn_external_api_scheduler(conn: DatabaseConnection, http_client: OutboundHttpClient) {
+    let config = DbConfig::get();
+    if config.external_api_sync_poll_interval == 0 {
+        info!("External API auto-sync scheduler is disabled");
+        return;
+    }
+
+    let interval = Duration::from_secs(config.external_api_sync_poll_interval);
+    let concurrency = config.external_api_sync_concurrency.max(1);
+    tokio::spawn(run_scheduler(conn, http_client, interval, concurrency));
+}
+
+async fn run_scheduler(
+    conn: DatabaseConnection,
+    http_client: OutboundHttpClient,
+    interval: Duration,
+    concurrency: usize,
+) {
+    let permits = Arc::new(Semaphore::new(concurrency));
+    // 이 인스턴스에서 대기 중이거나 실행 중인 연결 (다음 주기에 다시 넣지 않도록)
+    let scheduled: Arc<Mutex<HashSet<i32>>> = Arc::default();
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        let due = match find_due_connections(&conn).await {
+            Ok(due) => due,
+            Err(e) => {
+                error!("Failed to load due external API connections: {:?}", e);
+                continue;
+            }
+        };
+
+        for connection_id in due {
+            if !scheduled
+                .lock()
+                .expect("scheduler state poisoned")
+                .insert(connection_id)
+            {
+                continue;
+            }
+
+            let conn = conn.clone();
+            let http_client = http_client.clone();
+            let permits = permits.clone();
+            let scheduled = scheduled.clone();
+            tokio::spawn(async move {
+                if let Ok(_permit) = permits.acquire_owned().await
+                    && let Err(e) = run_due_sync(&conn, &http_client, connection_id).await
+                {
+                    error!(
+                        "Scheduled sync of external API connection {} failed: {:?}",
+                        connection_id, e
+                    );
+                }
+                scheduled
+                    .lock()
+                    .expect("scheduler state poisoned")
+                    .remove(&connection_id);
+            });
+        }
+    }
+}
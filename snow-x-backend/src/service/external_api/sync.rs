@@ -0,0 +1,814 @@
+use super::client::{FetchOutcome, fetch_items};
+use super::conflict::{FieldPolicies, stored_field_policies};
+use super::connection::{
+    find_connection, schedule_next_sync, schedule_retry, stored_field_mapping, stored_headers,
+};
+use super::field_mapping::{apply_field_mapping, content_hash, external_id, infer_data_type};
+use super::history::to_sync_log_response;
+use super::targets::{SyncActor, TargetOutcome, apply_contact, apply_device, apply_device_library};
+use super::{
+    DATA_STATUS_ACTIVE, DATA_STATUS_ARCHIVED, DEFAULT_SYNC_INTERVAL, SYNC_STATUS_ERROR,
+    SYNC_STATUS_IN_PROGRESS, SYNC_STATUS_SUCCESS, TARGET_CONTACT, TARGET_DEVICE,
+    TARGET_DEVICE_LIBRARY,
+};
+use crate::connection::outbound::OutboundHttpClient;
+use crate::dto::external_api::request::FieldMapping;
+use crate::dto::external_api::response::{ExternalApiSyncItemError, ExternalApiSyncResponse};
+use crate::entity::{external_api_connections, external_api_data, external_api_sync_logs};
+use crate::service::audit::ACTION_CREATE;
+use crate::service::bulk_io::describe_error;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::rack::capacity::{PowerBudgetOverage, notify_power_budget_overage};
+use crate::service::user::system_user::service_resolve_system_user_id;
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DatabaseBackend,
+    DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    Statement, TransactionTrait,
+};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// 한 번에 갱신할 원본 데이터 ID 수 (바인드 파라미터 한도 이내)
+const UPDATE_CHUNK: usize = 1000;
+/// 동기화 로그 error_message에 남기는 항목 오류 수
+const MAX_LOGGED_ITEM_ERRORS: usize = 5;
+/// 연결별 advisory lock의 첫 번째 키 (`pg_try_advisory_xact_lock(int4, int4)`), "EAPI"
+const SYNC_LOCK_NAMESPACE: i32 = 0x4541_5049;
+/// 재시도 간격 계산에 보는 최근 실패 로그 수
+const MAX_COUNTED_FAILURES: u64 = 20;
+
+/// 응답 항목을 반영한 결과
+#[derive(Default)]
+struct SyncSummary {
+    fetched: usize,
+    filtered: usize,
+    processed: usize,
+    changed: usize,
+    archived: usize,
+    created: usize,
+    updated: usize,
+    skipped: usize,
+    conflicts: usize,
+    errors: Vec<ExternalApiSyncItemError>,
+    power_overages: Vec<PowerBudgetOverage>,
+}
+
+/// 원본 데이터 행을 찾는 키. 외부 ID가 없으면 내용 해시로 식별한다.
+fn data_identifier(external_id: Option<&str>, hash: &str) -> String {
+    match external_id {
+        Some(id) => format!("id:{}", id),
+        None => format!("hash:{}", hash),
+    }
+}
+
+async fn apply_target<C>(
+    conn: &C,
+    target_type: &str,
+    item: &Value,
+    connection_id: i32,
+    tenant_id: Option<Uuid>,
+    policies: &FieldPolicies,
+    actor: SyncActor,
+) -> ServiceResult<TargetOutcome>
+where
+    C: ConnectionTrait,
+{
+    match target_type {
+        TARGET_CONTACT => {
+            apply_contact(conn, item, connection_id, tenant_id, policies, actor).await
+        }
+        TARGET_DEVICE => apply_device(conn, item, connection_id, tenant_id, policies, actor).await,
+        TARGET_DEVICE_LIBRARY => {
+            apply_device_library(conn, item, connection_id, tenant_id, policies, actor).await
+        }
+        other => Err(Errors::BadRequestError(format!(
+            "Unsupported target_type '{}'",
+            other
+        ))),
+    }
+}
+
+/// 바뀐 항목 하나를 원본 데이터와 대상 테이블에 반영한다.
+/// 대상 반영이 실패하면 원본 데이터도 기록하지 않아 다음 동기화에서 다시 시도한다.
+#[allow(clippy::too_many_arguments)]
+async fn apply_changed_item<C>(
+    conn: &C,
+    connection: &external_api_connections::Model,
+    existing: Option<&external_api_data::Model>,
+    item: &Value,
+    processed: &Value,
+    external_id: Option<String>,
+    hash: String,
+    policies: &FieldPolicies,
+    actor: SyncActor,
+) -> ServiceResult<TargetOutcome>
+where
+    C: ConnectionTrait,
+{
+    let outcome = apply_target(
+        conn,
+        &connection.target_type,
+        processed,
+        connection.id,
+        connection.tenant_id,
+        policies,
+        actor,
+    )
+    .await?;
+
+    let now = Utc::now();
+    match existing {
+        Some(row) => {
+            let mut model: external_api_data::ActiveModel = row.clone().into();
+            model.raw_data = ActiveValue::Set(item.clone());
+            model.processed_data = ActiveValue::Set(Some(processed.clone()));
+            model.hash = ActiveValue::Set(hash);
+            model.status = ActiveValue::Set(Some(DATA_STATUS_ACTIVE.to_string()));
+            model.last_sync_at = ActiveValue::Set(now.into());
+            model.updated_at = ActiveValue::Set(now.into());
+            model.update(conn).await?;
+        }
+        None => {
+            external_api_data::ActiveModel {
+                id: ActiveValue::NotSet,
+                connection_id: ActiveValue::Set(connection.id),
+                external_id: ActiveValue::Set(external_id),
+                data_type: ActiveValue::Set(infer_data_type(item).to_string()),
+                raw_data: ActiveValue::Set(item.clone()),
+                processed_data: ActiveValue::Set(Some(processed.clone())),
+                hash: ActiveValue::Set(hash),
+                status: ActiveValue::Set(Some(DATA_STATUS_ACTIVE.to_string())),
+                last_sync_at: ActiveValue::Set(now.into()),
+                created_at: ActiveValue::Set(now.into()),
+                updated_at: ActiveValue::Set(now.into()),
+            }
+            .insert(conn)
+            .await?;
+        }
+    }
+
+    Ok(outcome)
+}
+
+async fn update_data_rows(
+    txn: &DatabaseTransaction,
+    ids: &[i32],
+    status: Option<&str>,
+) -> ServiceResult<()> {
+    let now = Utc::now();
+    for chunk in ids.chunks(UPDATE_CHUNK) {
+        let mut update = external_api_data::Entity::update_many()
+            .col_expr(external_api_data::Column::LastSyncAt, Expr::value(now))
+            .filter(external_api_data::Column::Id.is_in(chunk.iter().copied()));
+        if let Some(status) = status {
+            update = update
+                .col_expr(external_api_data::Column::Status, Expr::value(status))
+                .col_expr(external_api_data::Column::UpdatedAt, Expr::value(now));
+        }
+        update.exec(txn).await?;
+    }
+    Ok(())
+}
+
+/// 바뀌어서 다시 반영할 항목
+struct ChangedItem<'a> {
+    index: usize,
+    item: &'a Value,
+    processed: Value,
+    external_id: Option<String>,
+    hash: String,
+    existing: Option<&'a external_api_data::Model>,
+}
+
+/// 받은 항목을 기존 원본 데이터와 맞춰 본 결과 (DB에 쓰기 전)
+#[derive(Default)]
+struct SyncPlan<'a> {
+    filtered: usize,
+    processed: usize,
+    changed: Vec<ChangedItem<'a>>,
+    /// 내용이 그대로라 `last_sync_at`만 갱신할 원본 데이터
+    unchanged_ids: Vec<i32>,
+    /// 이번 응답에 없어 보관 처리할 원본 데이터
+    archived_ids: Vec<i32>,
+    errors: Vec<ExternalApiSyncItemError>,
+}
+
+/// 필드 매핑과 필터를 적용하고, 외부 ID(없으면 내용 해시)로 기존 원본 데이터와 맞춘다.
+/// 같은 응답 안에서 식별자가 겹치는 항목은 처음 것만 쓰고 나머지는 항목 오류로 남긴다.
+fn plan_items<'a>(
+    existing_rows: &'a [external_api_data::Model],
+    items: &'a [Value],
+    mapping: &FieldMapping,
+) -> SyncPlan<'a> {
+    let rows_by_identifier: HashMap<String, &external_api_data::Model> = existing_rows
+        .iter()
+        .map(|row| (data_identifier(row.external_id.as_deref(), &row.hash), row))
+        .collect();
+
+    let mut plan = SyncPlan::default();
+    let mut seen: HashSet<String> = HashSet::new();
+
+    for (index, item) in items.iter().enumerate() {
+        let Some(processed) = apply_field_mapping(item, mapping) else {
+            plan.filtered += 1;
+            continue;
+        };
+
+        let hash = content_hash(item);
+        let external_id = external_id(item);
+        let identifier = data_identifier(external_id.as_deref(), &hash);
+        if !seen.insert(identifier.clone()) {
+            plan.errors.push(ExternalApiSyncItemError {
+                index,
+                external_id,
+                message: "Duplicate item in response; only the first one was used".to_string(),
+            });
+            continue;
+        }
+        plan.processed += 1;
+
+        let existing = rows_by_identifier.get(&identifier).copied();
+        let changed = existing.is_none_or(|row| {
+            row.hash != hash
+                || row.status.as_deref() == Some(DATA_STATUS_ARCHIVED)
+                || row.processed_data.as_ref() != Some(&processed)
+        });
+        if !changed {
+            plan.unchanged_ids.extend(existing.map(|row| row.id));
+            continue;
+        }
+
+        plan.changed.push(ChangedItem {
+            index,
+            item,
+            processed,
+            external_id,
+            hash,
+            existing,
+        });
+    }
+
+    // 이번 응답에 없는 active 데이터는 보관 처리
+    plan.archived_ids = existing_rows
+        .iter()
+        .filter(|row| row.status.as_deref() != Some(DATA_STATUS_ARCHIVED))
+        .filter(|row| !seen.contains(&data_identifier(row.external_id.as_deref(), &row.hash)))
+        .map(|row| row.id)
+        .collect();
+
+    plan
+}
+
+/// 받은 항목을 하나의 트랜잭션에서 반영한다. 항목마다 savepoint를 두어
+/// 한 항목의 실패가 나머지 항목의 반영을 막지 않게 한다.
+async fn apply_items(
+    txn: &DatabaseTransaction,
+    connection: &external_api_connections::Model,
+    items: Vec<Value>,
+    actor: SyncActor,
+) -> ServiceResult<SyncSummary> {
+    let mapping = stored_field_mapping(connection).unwrap_or_default();
+    let policies = stored_field_policies(connection);
+    let existing_rows = external_api_data::Entity::find()
+        .filter(external_api_data::Column::ConnectionId.eq(connection.id))
+        .all(txn)
+        .await?;
+    let plan = plan_items(&existing_rows, &items, &mapping);
+
+    let mut summary = SyncSummary {
+        fetched: items.len(),
+        filtered: plan.filtered,
+        processed: plan.processed,
+        changed: plan.changed.len(),
+        archived: plan.archived_ids.len(),
+        errors: plan.errors,
+        ..Default::default()
+    };
+
+    for changed in plan.changed {
+        let savepoint = txn.begin().await?;
+        let result = apply_changed_item(
+            &savepoint,
+            connection,
+            changed.existing,
+            changed.item,
+            &changed.processed,
+            changed.external_id.clone(),
+            changed.hash,
+            &policies,
+            actor,
+        )
+        .await;
+        match result {
+            Ok(outcome) => {
+                savepoint.commit().await?;
+                match outcome {
+                    TargetOutcome::Applied {
+                        action,
+                        power_overage,
+                        conflicts,
+                    } => {
+                        if action == ACTION_CREATE {
+                            summary.created += 1;
+                        } else {
+                            summary.updated += 1;
+                        }
+                        summary.conflicts += conflicts;
+                        summary.power_overages.extend(power_overage);
+                    }
+                    TargetOutcome::Skipped => summary.skipped += 1,
+                }
+            }
+            Err(err) => {
+                savepoint.rollback().await?;
+                summary.errors.push(ExternalApiSyncItemError {
+                    index: changed.index,
+                    external_id: changed.external_id,
+                    message: describe_error(err),
+                });
+            }
+        }
+    }
+    summary.errors.sort_by_key(|error| error.index);
+
+    update_data_rows(txn, &plan.unchanged_ids, None).await?;
+    update_data_rows(txn, &plan.archived_ids, Some(DATA_STATUS_ARCHIVED)).await?;
+
+    Ok(summary)
+}
+
+/// 요청 정보를 채운 완료 상태의 로그
+fn finished_log(
+    log: external_api_sync_logs::Model,
+    fetch: &FetchOutcome,
+    status: &str,
+    records_processed: i32,
+    error_message: Option<String>,
+) -> external_api_sync_logs::ActiveModel {
+    let now = Utc::now();
+    let mut model: external_api_sync_logs::ActiveModel = log.into();
+    model.status = ActiveValue::Set(status.to_string());
+    model.request_headers = ActiveValue::Set(Some(fetch.request_headers.clone()));
+    model.response_status = ActiveValue::Set(fetch.response_status);
+    model.response_headers = ActiveValue::Set(fetch.response_headers.clone());
+    model.response_body = ActiveValue::Set(fetch.response_body.clone());
+    model.records_processed = ActiveValue::Set(Some(records_processed));
+    model.error_message = ActiveValue::Set(error_message);
+    model.duration = ActiveValue::Set(Some(fetch.duration_ms));
+    model.completed_at = ActiveValue::Set(Some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+    model
+}
+
+/// 요청 정보를 채운 로그를 완료 상태로 바꾼다
+async fn finish_log(
+    conn: &DatabaseConnection,
+    log: external_api_sync_logs::Model,
+    fetch: &FetchOutcome,
+    status: &str,
+    records_processed: i32,
+    error_message: Option<String>,
+) -> ServiceResult<external_api_sync_logs::Model> {
+    Ok(
+        finished_log(log, fetch, status, records_processed, error_message)
+            .update(conn)
+            .await?,
+    )
+}
+
+fn summarize_item_errors(errors: &[ExternalApiSyncItemError]) -> Option<String> {
+    if errors.is_empty() {
+        return None;
+    }
+    let mut message = format!("{} item(s) could not be synced: ", errors.len());
+    let details: Vec<String> = errors
+        .iter()
+        .take(MAX_LOGGED_ITEM_ERRORS)
+        .map(|error| match error.external_id.as_deref() {
+            Some(id) => format!("#{} ({}): {}", error.index, id, error.message),
+            None => format!("#{}: {}", error.index, error.message),
+        })
+        .collect();
+    message.push_str(&details.join("; "));
+    if errors.len() > MAX_LOGGED_ITEM_ERRORS {
+        message.push_str("; …");
+    }
+    Some(message)
+}
+
+/// 연결 동기화 잠금을 잡는다. 다른 작업이나 다른 인스턴스가 잡고 있으면 `None`.
+///
+/// 트랜잭션 범위의 advisory lock이므로 돌려받은 트랜잭션을 끝내면(또는 연결이 끊기면) 풀린다.
+pub(super) async fn try_lock_connection(
+    conn: &DatabaseConnection,
+    connection_id: i32,
+) -> ServiceResult<Option<DatabaseTransaction>> {
+    let txn = conn.begin().await?;
+    let locked = txn
+        .query_one(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "SELECT pg_try_advisory_xact_lock($1, $2) AS locked",
+            vec![SYNC_LOCK_NAMESPACE.into(), connection_id.into()],
+        ))
+        .await?
+        .map(|row| row.try_get::<bool>("", "locked"))
+        .transpose()?
+        .unwrap_or(false);
+
+    if locked {
+        Ok(Some(txn))
+    } else {
+        txn.rollback().await?;
+        Ok(None)
+    }
+}
+
+/// 이번 실행 전까지 연속으로 실패한 횟수 (진행 중으로 남은 로그는 건너뛴다)
+async fn count_recent_failures(
+    conn: &DatabaseConnection,
+    connection_id: i32,
+    current_log_id: i32,
+) -> ServiceResult<u32> {
+    let statuses: Vec<String> = external_api_sync_logs::Entity::find()
+        .select_only()
+        .column(external_api_sync_logs::Column::Status)
+        .filter(external_api_sync_logs::Column::ConnectionId.eq(connection_id))
+        .filter(external_api_sync_logs::Column::Id.ne(current_log_id))
+        .filter(external_api_sync_logs::Column::Status.ne(SYNC_STATUS_IN_PROGRESS))
+        .order_by_desc(external_api_sync_logs::Column::Id)
+        .limit(MAX_COUNTED_FAILURES)
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    Ok(statuses
+        .iter()
+        .take_while(|status| status.as_str() == SYNC_STATUS_ERROR)
+        .count() as u32)
+}
+
+/// 연결을 지금 동기화한다. `actor`는 수동 실행한 사용자이며, 예약 실행이면 `None`.
+///
+/// 같은 연결은 한 번에 하나만 실행되며(모든 인스턴스 공통), 이미 실행 중이면
+/// `ExternalApiSyncInProgress`를 돌려준다.
+pub async fn service_sync_connection(
+    conn: &DatabaseConnection,
+    http_client: &OutboundHttpClient,
+    connection_id: i32,
+    actor: Option<Uuid>,
+) -> ServiceResult<ExternalApiSyncResponse> {
+    let Some(lock) = try_lock_connection(conn, connection_id).await? else {
+        return Err(Errors::ExternalApiSyncInProgress(connection_id));
+    };
+
+    let result = async {
+        let connection = find_connection(conn, connection_id).await?;
+        if connection.is_active == Some(false) {
+            return Err(Errors::BadRequestError(format!(
+                "External API connection '{}' is inactive",
+                connection.name
+            )));
+        }
+        run_sync(conn, http_client, connection, actor).await
+    }
+    .await;
+
+    lock.commit().await?;
+    result
+}
+
+/// 잠금을 잡은 상태에서 동기화를 한 번 실행한다.
+///
+/// 실행 기록은 먼저 `in_progress`로 남기고, 외부 API 호출이나 반영이 실패하면 `error`로,
+/// 끝나면 `success`로 바꾼다. 일부 항목만 실패한 경우는 `success`이며 실패 항목은
+/// error_message와 응답의 `errors`에 남는다. 실패하면 다음 자동 동기화를 지수적으로 늦춘다.
+/// 새로 만드는 행의 작성자는 `actor`, 없으면 시스템 사용자.
+pub(super) async fn run_sync(
+    conn: &DatabaseConnection,
+    http_client: &OutboundHttpClient,
+    connection: external_api_connections::Model,
+    actor: Option<Uuid>,
+) -> ServiceResult<ExternalApiSyncResponse> {
+    let sync_actor = SyncActor {
+        created_by: match actor {
+            Some(actor) => actor,
+            None => service_resolve_system_user_id(conn).await?,
+        },
+        actor_id: actor,
+    };
+
+    let started_at = Utc::now();
+    let log = external_api_sync_logs::ActiveModel {
+        id: ActiveValue::NotSet,
+        connection_id: ActiveValue::Set(connection.id),
+        status: ActiveValue::Set(SYNC_STATUS_IN_PROGRESS.to_string()),
+        request_url: ActiveValue::Set(connection.base_url.clone()),
+        request_method: ActiveValue::Set("GET".to_string()),
+        request_headers: ActiveValue::Set(None),
+        request_body: ActiveValue::Set(None),
+        response_status: ActiveValue::Set(None),
+        response_headers: ActiveValue::Set(None),
+        response_body: ActiveValue::Set(None),
+        records_processed: ActiveValue::Set(Some(0)),
+        error_message: ActiveValue::Set(None),
+        duration: ActiveValue::Set(None),
+        started_at: ActiveValue::Set(started_at.into()),
+        completed_at: ActiveValue::Set(None),
+        created_at: ActiveValue::Set(started_at.into()),
+        updated_at: ActiveValue::Set(started_at.into()),
+    }
+    .insert(conn)
+    .await?;
+
+    let mut fetch = fetch_items(
+        http_client,
+        &connection.base_url,
+        &stored_headers(&connection),
+        connection.auth_config.as_ref(),
+    )
+    .await;
+
+    let result = match std::mem::replace(&mut fetch.result, Ok(Vec::new())) {
+        Ok(items) => {
+            let txn = conn.begin().await?;
+            match apply_items(&txn, &connection, items, sync_actor).await {
+                Ok(summary) => {
+                    txn.commit().await?;
+                    Ok(summary)
+                }
+                Err(err) => {
+                    txn.rollback().await?;
+                    Err(describe_error(err))
+                }
+            }
+        }
+        Err(message) => Err(message),
+    };
+
+    let now = Utc::now();
+    let mut model: external_api_connections::ActiveModel = connection.clone().into();
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let (log, summary) = match result {
+        Ok(summary) => {
+            model.last_sync_at = ActiveValue::Set(Some(now.into()));
+            model.sync_count = ActiveValue::Set(Some(connection.sync_count.unwrap_or(0) + 1));
+            model.last_error_message = ActiveValue::Set(None);
+            model.next_sync_at = ActiveValue::Set(schedule_next_sync(
+                connection.is_active.unwrap_or(true),
+                connection.auto_sync.unwrap_or(true),
+                connection.sync_interval.unwrap_or(DEFAULT_SYNC_INTERVAL),
+            ));
+            model.update(conn).await?;
+
+            let log = finish_log(
+                conn,
+                log,
+                &fetch,
+                SYNC_STATUS_SUCCESS,
+                summary.processed as i32,
+                summarize_item_errors(&summary.errors),
+            )
+            .await?;
+            (log, summary)
+        }
+        Err(message) => {
+            let failures = count_recent_failures(conn, connection.id, log.id).await? + 1;
+            model.last_error_message = ActiveValue::Set(Some(message.clone()));
+            model.next_sync_at = ActiveValue::Set(schedule_retry(
+                connection.is_active.unwrap_or(true),
+                connection.auto_sync.unwrap_or(true),
+                failures,
+            ));
+            model.update(conn).await?;
+
+            let log = finish_log(conn, log, &fetch, SYNC_STATUS_ERROR, 0, Some(message)).await?;
+            (log, SyncSummary::default())
+        }
+    };
+
+    for overage in summary.power_overages {
+        notify_power_budget_overage(conn, overage, actor).await;
+    }
+
+    Ok(ExternalApiSyncResponse {
+        connection_id: connection.id,
+        log: to_sync_log_response(&log),
+        fetched: summary.fetched,
+        filtered: summary.filtered,
+        changed: summary.changed,
+        archived: summary.archived,
+        created: summary.created,
+        updated: summary.updated,
+        skipped: summary.skipped,
+        conflicts: summary.conflicts,
+        errors: summary.errors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::outbound::OutboundHttpClient;
+    use crate::dto::external_api::request::{FieldMappingRule, FilterCondition};
+    use crate::service::external_api::client::MASKED_SECRET;
+    use axum::extract::Query;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::{Json, Router};
+    use serde_json::json;
+    use std::net::SocketAddr;
+
+    /// 로컬 목 서버. `/devices?page=N`은 두 쪽으로 나뉜 `data` 목록, `/broken`은 500
+    async fn spawn_mock_api() -> SocketAddr {
+        async fn devices(Query(query): Query<HashMap<String, u32>>) -> Json<Value> {
+            let page = query.get("page").copied().unwrap_or(1);
+            let data = match page {
+                1 => json!([
+                    {"attributes": {"serial": "sn-001", "name": " core-sw "}, "kind": "switch"},
+                    {"attributes": {"serial": "sn-002", "name": "edge-fw"}, "kind": "firewall"},
+                    {"kind": "switch", "attributes": {"name": " core-sw ", "serial": "sn-001"}},
+                ]),
+                _ => json!([
+                    {"attributes": {"serial": "sn-003", "name": "pdu"}, "kind": "power"},
+                ]),
+            };
+            Json(json!({"data": data, "page": page, "total_pages": 2}))
+        }
+
+        let app = Router::new().route("/devices", get(devices)).route(
+            "/broken",
+            get(|| async { (StatusCode::INTERNAL_SERVER_ERROR, "upstream exploded") }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        addr
+    }
+
+    fn mock_client() -> OutboundHttpClient {
+        OutboundHttpClient::new(true).unwrap()
+    }
+
+    /// 모든 쪽을 받아 하나의 항목 목록으로
+    async fn fetch_all_pages(addr: SocketAddr) -> Vec<Value> {
+        let mut items = Vec::new();
+        for page in 1..=2 {
+            let url = format!("http://{}/devices?page={}", addr, page);
+            let outcome = fetch_items(&mock_client(), &url, &HashMap::new(), None).await;
+            assert_eq!(outcome.response_status, Some(200));
+            items.extend(outcome.result.unwrap());
+        }
+        items
+    }
+
+    fn rule(source: &str, target: &str, transformation: &str) -> FieldMappingRule {
+        FieldMappingRule {
+            source_field: source.to_string(),
+            target_field: target.to_string(),
+            data_type: None,
+            default_value: None,
+            transformation: Some(transformation.to_string()),
+        }
+    }
+
+    fn device_mapping() -> FieldMapping {
+        FieldMapping {
+            mappings: vec![
+                rule("attributes.serial", "serial_number", "uppercase"),
+                rule("attributes.name", "name", "trim"),
+            ],
+            filter_conditions: vec![FilterCondition {
+                field: "kind".to_string(),
+                operator: "not_equals".to_string(),
+                value: json!("power"),
+            }],
+        }
+    }
+
+    fn data_row(id: i32, item: &Value, status: &str) -> external_api_data::Model {
+        let now = Utc::now().into();
+        external_api_data::Model {
+            id,
+            connection_id: 7,
+            external_id: None,
+            data_type: infer_data_type(item).to_string(),
+            raw_data: item.clone(),
+            processed_data: apply_field_mapping(item, &device_mapping()),
+            hash: content_hash(item),
+            status: Some(status.to_string()),
+            last_sync_at: now,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn maps_paged_items_and_dedupes_by_hash() {
+        let addr = spawn_mock_api().await;
+        let items = fetch_all_pages(addr).await;
+        assert_eq!(items.len(), 4);
+
+        let plan = plan_items(&[], &items, &device_mapping());
+
+        // 세 번째 항목은 키 순서만 다른 첫 항목이라 같은 해시로 걸러지고, power 항목은 필터에 걸린다
+        assert_eq!(plan.filtered, 1);
+        assert_eq!(plan.processed, 2);
+        assert_eq!(plan.errors.len(), 1);
+        assert_eq!(plan.errors[0].index, 2);
+        let mapped: Vec<&Value> = plan.changed.iter().map(|item| &item.processed).collect();
+        assert_eq!(
+            mapped,
+            vec![
+                &json!({"serial_number": "SN-001", "name": "core-sw"}),
+                &json!({"serial_number": "SN-002", "name": "edge-fw"}),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn unchanged_hash_is_skipped_and_missing_rows_are_archived() {
+        let addr = spawn_mock_api().await;
+        let items = fetch_all_pages(addr).await;
+        let gone = json!({"attributes": {"serial": "sn-999", "name": "retired"}, "kind": "switch"});
+        let existing = vec![
+            data_row(1, &items[0], DATA_STATUS_ACTIVE),
+            data_row(2, &gone, DATA_STATUS_ACTIVE),
+            data_row(3, &items[1], DATA_STATUS_ARCHIVED),
+        ];
+
+        let plan = plan_items(&existing, &items, &device_mapping());
+
+        assert_eq!(plan.unchanged_ids, vec![1]);
+        assert_eq!(plan.archived_ids, vec![2]);
+        // 보관된 항목이 다시 나타나면 되살린다
+        assert_eq!(plan.changed.len(), 1);
+        assert_eq!(plan.changed[0].existing.map(|row| row.id), Some(3));
+    }
+
+    #[tokio::test]
+    async fn failed_fetch_is_logged_with_response() {
+        let addr = spawn_mock_api().await;
+        let url = format!("http://{}/broken", addr);
+        let fetch = fetch_items(
+            &mock_client(),
+            &url,
+            &HashMap::from([("Authorization".to_string(), "Bearer secret".to_string())]),
+            None,
+        )
+        .await;
+        let message = fetch.result.clone().unwrap_err();
+        assert!(message.contains("HTTP 500"), "{}", message);
+
+        let now = Utc::now().into();
+        let log = external_api_sync_logs::Model {
+            id: 11,
+            connection_id: 7,
+            status: SYNC_STATUS_IN_PROGRESS.to_string(),
+            request_url: url,
+            request_method: "GET".to_string(),
+            request_headers: None,
+            request_body: None,
+            response_status: None,
+            response_headers: None,
+            response_body: None,
+            records_processed: Some(0),
+            error_message: None,
+            duration: None,
+            started_at: now,
+            completed_at: None,
+            created_at: now,
+            updated_at: now,
+        };
+        let finished = finished_log(log, &fetch, SYNC_STATUS_ERROR, 0, Some(message.clone()));
+
+        assert_eq!(finished.status.as_ref(), SYNC_STATUS_ERROR);
+        assert_eq!(finished.response_status.as_ref(), &Some(500));
+        assert_eq!(
+            finished.response_body.as_ref().as_deref(),
+            Some("upstream exploded")
+        );
+        assert_eq!(finished.error_message.as_ref(), &Some(message));
+        assert!(finished.completed_at.as_ref().is_some());
+        // 인증 헤더 값은 로그에 남기지 않는다
+        let headers = finished.request_headers.as_ref().clone().unwrap();
+        assert_eq!(headers["authorization"], json!(MASKED_SECRET));
+    }
+
+    #[tokio::test]
+    async fn refuses_private_targets_by_default() {
+        let addr = spawn_mock_api().await;
+        let outcome = fetch_items(
+            &OutboundHttpClient::new(false).unwrap(),
+            &format!("http://{}/devices", addr),
+            &HashMap::new(),
+            None,
+        )
+        .await;
+        assert_eq!(outcome.response_status, None);
+        assert!(outcome.result.unwrap_err().contains("private address"));
+    }
+}
@@ -0,0 +1,533 @@
+use super::SOURCE_TYPE_API_SYNC;
+use super::conflict::{FieldPolicies, SyncedRecord, protect_overridden_fields};
+use crate::entity::{contacts, device_library, devices};
+use crate::service::audit::{
+    ACTION_CREATE, ACTION_UPDATE, AuditEntry, RESOURCE_CONTACT, RESOURCE_DEVICE,
+    RESOURCE_DEVICE_LIBRARY, record_audit,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::rack::capacity::{PowerBudgetOverage, check_rack_power_budget};
+use crate::service::rack::elevation::{ensure_rack_slots_available, lock_rack};
+use chrono::{NaiveDate, Utc};
+use sea_orm::sea_query::{Expr, Func};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+/// 매핑된 항목 하나를 대상 테이블에 반영한 결과
+pub enum TargetOutcome {
+    /// `create`/`update` (감사 로그와 같은 값)
+    Applied {
+        action: &'static str,
+        power_overage: Option<PowerBudgetOverage>,
+        /// 수동 수정된 필드에 대해 새로 생긴 충돌 수
+        conflicts: usize,
+    },
+    /// 식별 필드가 없어 반영하지 않음
+    Skipped,
+}
+
+/// 동기화로 만든 행의 작성자와 감사 로그에 남길 실행자.
+/// 예약 동기화는 시스템 사용자가 작성자이고 실행자는 없다.
+#[derive(Clone, Copy)]
+pub struct SyncActor {
+    pub created_by: Uuid,
+    pub actor_id: Option<Uuid>,
+}
+
+/// 항목에 키가 없으면 `None`, 값이 null이거나 빈 문자열이면 `Some(None)`.
+/// 수정 시 항목에 없는 필드는 기존 값을 유지하기 위해 두 경우를 구분한다.
+type ItemField<T> = Option<Option<T>>;
+
+/// 매핑된 항목 값을 필드 이름으로 읽는 헬퍼
+struct ItemReader<'a> {
+    item: &'a Map<String, Value>,
+}
+
+impl ItemReader<'_> {
+    fn text(&self, key: &str) -> ItemField<String> {
+        let value = match self.item.get(key)? {
+            Value::Null => return Some(None),
+            Value::String(text) => text.trim().to_string(),
+            other => other.to_string(),
+        };
+        Some((!value.is_empty()).then_some(value))
+    }
+
+    fn int(&self, key: &str) -> ServiceResult<ItemField<i32>> {
+        let invalid = || Errors::BadRequestError(format!("{} must be an integer", key));
+        match self.item.get(key) {
+            None => Ok(None),
+            Some(Value::Null) => Ok(Some(None)),
+            Some(Value::Number(number)) => {
+                let value = number
+                    .as_i64()
+                    .or_else(|| {
+                        number
+                            .as_f64()
+                            .filter(|f| f.fract() == 0.0)
+                            .map(|f| f as i64)
+                    })
+                    .ok_or_else(invalid)?;
+                i32::try_from(value)
+                    .map(|v| Some(Some(v)))
+                    .map_err(|_| invalid())
+            }
+            Some(Value::String(text)) if text.trim().is_empty() => Ok(Some(None)),
+            Some(Value::String(text)) => text
+                .trim()
+                .parse::<i32>()
+                .map(|v| Some(Some(v)))
+                .map_err(|_| invalid()),
+            Some(_) => Err(invalid()),
+        }
+    }
+
+    /// `YYYY-MM-DD`로 시작하는 문자열 (ISO 날짜/시각 모두 허용)
+    fn date(&self, key: &str) -> ServiceResult<ItemField<NaiveDate>> {
+        match self.text(key) {
+            None => Ok(None),
+            Some(None) => Ok(Some(None)),
+            Some(Some(text)) => text
+                .get(..10)
+                .and_then(|prefix| NaiveDate::parse_from_str(prefix, "%Y-%m-%d").ok())
+                .map(|date| Some(Some(date)))
+                .ok_or_else(|| {
+                    Errors::BadRequestError(format!("{} '{}' is not a date", key, text))
+                }),
+        }
+    }
+
+    fn json(&self, key: &str) -> ItemField<Value> {
+        Some(match self.item.get(key)? {
+            Value::Null => None,
+            other => Some(other.clone()),
+        })
+    }
+}
+
+fn set_if_present<T>(target: &mut ActiveValue<Option<T>>, value: ItemField<T>)
+where
+    T: Into<sea_orm::Value> + sea_orm::sea_query::Nullable,
+{
+    if let Some(value) = value {
+        *target = ActiveValue::Set(value);
+    }
+}
+
+/// null이 될 수 없는 열은 값이 있을 때만 바꾼다
+fn set_if_some<T>(target: &mut ActiveValue<T>, value: ItemField<T>)
+where
+    T: Into<sea_orm::Value>,
+{
+    if let Some(Some(value)) = value {
+        *target = ActiveValue::Set(value);
+    }
+}
+
+fn item_object(item: &Value) -> ServiceResult<&Map<String, Value>> {
+    item.as_object()
+        .ok_or_else(|| Errors::BadRequestError("Mapped item is not a JSON object".to_string()))
+}
+
+/// 담당자: `email`(대소문자 무시)로 기존 담당자를 찾는다
+pub async fn apply_contact<C>(
+    conn: &C,
+    item: &Value,
+    connection_id: i32,
+    tenant_id: Option<Uuid>,
+    policies: &FieldPolicies,
+    actor: SyncActor,
+) -> ServiceResult<TargetOutcome>
+where
+    C: ConnectionTrait,
+{
+    let reader = ItemReader {
+        item: item_object(item)?,
+    };
+    let Some(Some(email)) = reader.text("email") else {
+        return Ok(TargetOutcome::Skipped);
+    };
+
+    let existing = contacts::Entity::find()
+        .filter(contacts::Column::TenantId.eq(tenant_id))
+        .filter(
+            Expr::expr(Func::lower(Expr::col(contacts::Column::Email))).eq(email.to_lowercase()),
+        )
+        .order_by_desc(contacts::Column::IsActive)
+        .one(conn)
+        .await?;
+
+    let mut conflicts = 0;
+    let (action, contact) = match existing.as_ref() {
+        Some(before) => {
+            let mut model: contacts::ActiveModel = before.clone().into();
+            set_if_some(&mut model.name, reader.text("name"));
+            set_if_present(&mut model.title, reader.text("title"));
+            set_if_present(&mut model.department, reader.text("department"));
+            set_if_present(&mut model.phone, reader.text("phone"));
+            set_if_present(&mut model.mobile, reader.text("mobile"));
+            set_if_present(&mut model.office_location, reader.text("office_location"));
+            set_if_present(&mut model.responsibilities, reader.text("responsibilities"));
+            conflicts = protect_overridden_fields(
+                conn,
+                SyncedRecord {
+                    resource_type: RESOURCE_CONTACT,
+                    resource_id: before.id,
+                    resource_name: &before.name,
+                    connection_id,
+                    before,
+                },
+                policies,
+                &mut model,
+            )
+            .await?;
+            model.source_type = ActiveValue::Set(SOURCE_TYPE_API_SYNC.to_string());
+            model.external_api_connection_id = ActiveValue::Set(Some(connection_id));
+            model.updated_at = ActiveValue::Set(Utc::now().into());
+            (ACTION_UPDATE, model.update(conn).await?)
+        }
+        None => {
+            let name = reader
+                .text("name")
+                .flatten()
+                .unwrap_or_else(|| email.clone());
+            let contact = contacts::ActiveModel {
+                id: ActiveValue::Set(Uuid::new_v4()),
+                tenant_id: ActiveValue::Set(tenant_id),
+                name: ActiveValue::Set(name),
+                title: ActiveValue::Set(reader.text("title").flatten()),
+                department: ActiveValue::Set(reader.text("department").flatten()),
+                phone: ActiveValue::Set(reader.text("phone").flatten()),
+                mobile: ActiveValue::Set(reader.text("mobile").flatten()),
+                email: ActiveValue::Set(Some(email)),
+                office_location: ActiveValue::Set(reader.text("office_location").flatten()),
+                responsibilities: ActiveValue::Set(reader.text("responsibilities").flatten()),
+                created_by: ActiveValue::Set(actor.created_by),
+                created_at: ActiveValue::NotSet,
+                updated_at: ActiveValue::NotSet,
+                is_active: ActiveValue::Set(true),
+                source_type: ActiveValue::Set(SOURCE_TYPE_API_SYNC.to_string()),
+                external_api_connection_id: ActiveValue::Set(Some(connection_id)),
+            }
+            .insert(conn)
+            .await?;
+            (ACTION_CREATE, contact)
+        }
+    };
+
+    record_audit(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_CONTACT,
+            resource_id: contact.id,
+            resource_name: Some(&contact.name),
+            action,
+            actor_id: actor.actor_id,
+            before: existing.as_ref(),
+            after: Some(&contact),
+        },
+    )
+    .await?;
+
+    Ok(TargetOutcome::Applied {
+        action,
+        power_overage: None,
+        conflicts,
+    })
+}
+
+/// 장비: `serial_number`, 없으면 `name`으로 기존 장비를 찾는다.
+/// 랙 배치(rack_id, rack_position)는 IPAM에서 관리하므로 동기화로 바꾸지 않는다.
+pub async fn apply_device<C>(
+    conn: &C,
+    item: &Value,
+    connection_id: i32,
+    tenant_id: Option<Uuid>,
+    policies: &FieldPolicies,
+    actor: SyncActor,
+) -> ServiceResult<TargetOutcome>
+where
+    C: ConnectionTrait,
+{
+    let reader = ItemReader {
+        item: item_object(item)?,
+    };
+    let serial_number = reader.text("serial_number").flatten();
+    let name = reader.text("name").flatten();
+
+    let lookup = match (serial_number.as_ref(), name.as_ref()) {
+        (Some(serial_number), _) => devices::Column::SerialNumber.eq(serial_number.clone()),
+        (None, Some(name)) => devices::Column::Name.eq(name.clone()),
+        (None, None) => return Ok(TargetOutcome::Skipped),
+    };
+    let existing = devices::Entity::find()
+        .filter(devices::Column::TenantId.eq(tenant_id))
+        .filter(lookup)
+        .filter(devices::Column::IsActive.eq(true))
+        .one(conn)
+        .await?;
+
+    let rack_size = reader.int("rack_size")?;
+    let power_consumption = reader.int("power_consumption")?;
+    let purchase_date = reader.date("purchase_date")?;
+    let warranty_end = reader.date("warranty_end")?;
+    if let Some(Some(size)) = rack_size
+        && size < 1
+    {
+        return Err(Errors::BadRequestError(
+            "rack_size must be at least 1".to_string(),
+        ));
+    }
+
+    let mut power_overage = None;
+    let mut conflicts = 0;
+    let (action, device) = match existing.as_ref() {
+        Some(before) => {
+            let mut model: devices::ActiveModel = before.clone().into();
+            set_if_some(&mut model.name, reader.text("name"));
+            set_if_present(&mut model.description, reader.text("description"));
+            set_if_some(&mut model.device_type, reader.text("device_type"));
+            set_if_present(&mut model.manufacturer, reader.text("manufacturer"));
+            set_if_present(&mut model.model, reader.text("model"));
+            set_if_present(&mut model.serial_number, reader.text("serial_number"));
+            set_if_some(&mut model.rack_size, rack_size);
+            set_if_present(&mut model.power_consumption, power_consumption);
+            set_if_some(&mut model.status, reader.text("status"));
+            set_if_present(&mut model.purchase_date, purchase_date);
+            set_if_present(&mut model.warranty_end, warranty_end);
+            conflicts = protect_overridden_fields(
+                conn,
+                SyncedRecord {
+                    resource_type: RESOURCE_DEVICE,
+                    resource_id: before.id,
+                    resource_name: &before.name,
+                    connection_id,
+                    before,
+                },
+                policies,
+                &mut model,
+            )
+            .await?;
+
+            // 수동 수정 보호를 거친 뒤의 값으로 검증한다
+            let new_rack_size = model.rack_size.clone().unwrap();
+            let new_power = model.power_consumption.clone().unwrap();
+            let device_name = model.name.clone().unwrap();
+
+            // 랙에 있는 장비의 크기/전력이 바뀌면 단건 수정과 같은 검증을 거친다
+            if let Some(rack_id) = before.rack_id
+                && (new_rack_size != before.rack_size || new_power != before.power_consumption)
+            {
+                let rack = lock_rack(conn, &rack_id).await?;
+                if new_rack_size != before.rack_size
+                    && let Some(position) = before.rack_position
+                {
+                    ensure_rack_slots_available(
+                        conn,
+                        &rack,
+                        position,
+                        new_rack_size,
+                        Some(&before.id),
+                    )
+                    .await?;
+                }
+                if new_power != before.power_consumption {
+                    power_overage = check_rack_power_budget(
+                        conn,
+                        &rack,
+                        &device_name,
+                        new_power,
+                        Some(&before.id),
+                    )
+                    .await?;
+                }
+            }
+
+            model.source_type = ActiveValue::Set(SOURCE_TYPE_API_SYNC.to_string());
+            model.external_api_connection_id = ActiveValue::Set(Some(connection_id));
+            model.updated_at = ActiveValue::Set(Utc::now().into());
+            (ACTION_UPDATE, model.update(conn).await?)
+        }
+        None => {
+            let name = name.or_else(|| serial_number.clone()).unwrap_or_default();
+            let device_type = reader.text("device_type").flatten().ok_or_else(|| {
+                Errors::BadRequestError("device_type is required for new devices".to_string())
+            })?;
+            let now = Utc::now();
+            let device = devices::ActiveModel {
+                id: ActiveValue::Set(Uuid::new_v4()),
+                tenant_id: ActiveValue::Set(tenant_id),
+                rack_id: ActiveValue::Set(None),
+                name: ActiveValue::Set(name),
+                description: ActiveValue::Set(reader.text("description").flatten()),
+                device_type: ActiveValue::Set(device_type),
+                manufacturer: ActiveValue::Set(reader.text("manufacturer").flatten()),
+                model: ActiveValue::Set(reader.text("model").flatten()),
+                serial_number: ActiveValue::Set(serial_number),
+                rack_position: ActiveValue::Set(None),
+                rack_size: ActiveValue::Set(rack_size.flatten().unwrap_or(1)),
+                power_consumption: ActiveValue::Set(power_consumption.flatten()),
+                status: ActiveValue::Set(
+                    reader
+                        .text("status")
+                        .flatten()
+                        .unwrap_or_else(|| "active".to_string()),
+                ),
+                purchase_date: ActiveValue::Set(purchase_date.flatten()),
+                warranty_end: ActiveValue::Set(warranty_end.flatten()),
+                created_by: ActiveValue::Set(actor.created_by),
+                created_at: ActiveValue::Set(now.into()),
+                updated_at: ActiveValue::Set(now.into()),
+                is_active: ActiveValue::Set(true),
+                source_type: ActiveValue::Set(SOURCE_TYPE_API_SYNC.to_string()),
+                external_api_connection_id: ActiveValue::Set(Some(connection_id)),
+            }
+            .insert(conn)
+            .await?;
+            (ACTION_CREATE, device)
+        }
+    };
+
+    record_audit(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_DEVICE,
+            resource_id: device.id,
+            resource_name: Some(&device.name),
+            action,
+            actor_id: actor.actor_id,
+            before: existing.as_ref(),
+            after: Some(&device),
+        },
+    )
+    .await?;
+
+    Ok(TargetOutcome::Applied {
+        action,
+        power_overage,
+        conflicts,
+    })
+}
+
+/// 장비 라이브러리: `model`, 없으면 `name`으로 기존 항목을 찾는다
+pub async fn apply_device_library<C>(
+    conn: &C,
+    item: &Value,
+    connection_id: i32,
+    tenant_id: Option<Uuid>,
+    policies: &FieldPolicies,
+    actor: SyncActor,
+) -> ServiceResult<TargetOutcome>
+where
+    C: ConnectionTrait,
+{
+    let reader = ItemReader {
+        item: item_object(item)?,
+    };
+    let model_name = reader.text("model").flatten();
+    let name = reader.text("name").flatten();
+
+    let lookup = match (model_name.as_ref(), name.as_ref()) {
+        (Some(model_name), _) => device_library::Column::Model.eq(model_name.clone()),
+        (None, Some(name)) => device_library::Column::Name.eq(name.clone()),
+        (None, None) => return Ok(TargetOutcome::Skipped),
+    };
+    let existing = device_library::Entity::find()
+        .filter(device_library::Column::TenantId.eq(tenant_id))
+        .filter(lookup)
+        .filter(device_library::Column::IsActive.eq(true))
+        .one(conn)
+        .await?;
+
+    let default_rack_size = reader.int("default_rack_size")?;
+    let default_power_consumption = reader.int("default_power_consumption")?;
+
+    let mut conflicts = 0;
+    let (action, library) = match existing.as_ref() {
+        Some(before) => {
+            let mut model: device_library::ActiveModel = before.clone().into();
+            set_if_some(&mut model.name, reader.text("name"));
+            set_if_present(&mut model.description, reader.text("description"));
+            set_if_some(&mut model.device_type, reader.text("device_type"));
+            set_if_present(&mut model.manufacturer, reader.text("manufacturer"));
+            set_if_present(&mut model.model, reader.text("model"));
+            set_if_present(&mut model.default_rack_size, default_rack_size);
+            set_if_present(
+                &mut model.default_power_consumption,
+                default_power_consumption,
+            );
+            set_if_present(&mut model.default_config, reader.json("default_config"));
+            conflicts = protect_overridden_fields(
+                conn,
+                SyncedRecord {
+                    resource_type: RESOURCE_DEVICE_LIBRARY,
+                    resource_id: before.id,
+                    resource_name: &before.name,
+                    connection_id,
+                    before,
+                },
+                policies,
+                &mut model,
+            )
+            .await?;
+            model.source_type = ActiveValue::Set(SOURCE_TYPE_API_SYNC.to_string());
+            model.external_api_connection_id = ActiveValue::Set(Some(connection_id));
+            model.updated_at = ActiveValue::Set(Utc::now().into());
+            (ACTION_UPDATE, model.update(conn).await?)
+        }
+        None => {
+            let library = device_library::ActiveModel {
+                id: ActiveValue::Set(Uuid::new_v4()),
+                tenant_id: ActiveValue::Set(tenant_id),
+                name: ActiveValue::Set(name.or_else(|| model_name.clone()).unwrap_or_default()),
+                description: ActiveValue::Set(reader.text("description").flatten()),
+                device_type: ActiveValue::Set(
+                    reader
+                        .text("device_type")
+                        .flatten()
+                        .unwrap_or_else(|| "general".to_string()),
+                ),
+                manufacturer: ActiveValue::Set(reader.text("manufacturer").flatten()),
+                model: ActiveValue::Set(model_name),
+                default_rack_size: ActiveValue::Set(default_rack_size.flatten()),
+                default_power_consumption: ActiveValue::Set(default_power_consumption.flatten()),
+                default_config: ActiveValue::Set(reader.json("default_config").flatten()),
+                device_id: ActiveValue::Set(None),
+                device_name: ActiveValue::Set(None),
+                created_by: ActiveValue::Set(actor.created_by),
+                created_at: ActiveValue::NotSet,
+                updated_at: ActiveValue::NotSet,
+                is_active: ActiveValue::Set(true),
+                source_type: ActiveValue::Set(SOURCE_TYPE_API_SYNC.to_string()),
+                external_api_connection_id: ActiveValue::Set(Some(connection_id)),
+            }
+            .insert(conn)
+            .await?;
+            (ACTION_CREATE, library)
+        }
+    };
+
+    record_audit(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_DEVICE_LIBRARY,
+            resource_id: library.id,
+            resource_name: Some(&library.name),
+            action,
+            actor_id: actor.actor_id,
+            before: existing.as_ref(),
+            after: Some(&library),
+        },
+    )
+    .await?;
+
+    Ok(TargetOutcome::Applied {
+        action,
+        power_overage: None,
+        conflicts,
+    })
+}
@@ -9,8 +9,11 @@ use crate::repository::user::update_follow_count::{
     repository_increment_user_follower_count, repository_increment_user_following_count,
 };
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::notification::CATEGORY_NEW_FOLLOWER;
+use crate::service::notification::inbox::{UserNotificationParams, enqueue_user_notification};
 use sea_orm::ConnectionTrait;
 use sea_orm::TransactionTrait;
+use serde_json::json;
 
 pub async fn service_create_follow_by_handle<C>(
     conn: &C,
@@ -46,6 +49,21 @@ where
     repository_increment_user_following_count(&txn, follower.id).await?;
     repository_increment_user_follower_count(&txn, followee.id).await?;
 
+    // 팔로우 당한 사용자에게 알림
+    enqueue_user_notification(
+        &txn,
+        UserNotificationParams {
+            category: CATEGORY_NEW_FOLLOWER,
+            title: format!("{}님이 회원님을 팔로우합니다", follower.name),
+            message: format!("@{}", follower.handle),
+            recipient_ids: vec![followee.id],
+            actor_id: Some(follower.id),
+            link: Some(format!("/@{}/profile", follower.handle)),
+            data: json!({ "follower_id": follower.id }),
+        },
+    )
+    .await?;
+
     // Commit the transaction
     txn.commit().await?;
 
@@ -0,0 +1,82 @@
+use crate::entity::ip_addresses;
+use crate::service::audit::{
+    ACTION_CREATE, ACTION_UPDATE, AuditEntry, RESOURCE_IP_ADDRESS, record_audit,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{ConnectionTrait, FromQueryResult, QueryResult, Statement};
+use uuid::Uuid;
+
+/// `ip_addresses::Model`로 읽을 수 있는 RETURNING/SELECT 열 (INET, MACADDR은 문자열로)
+pub(crate) const IP_ADDRESS_COLUMNS: &str = r#"
+    id,
+    ip_range_id,
+    HOST(ip_address) as ip_address,
+    mac_address::text as mac_address,
+    hostname,
+    status,
+    description,
+    lease_start,
+    lease_end,
+    created_by,
+    created_at,
+    updated_at,
+    is_active
+"#;
+
+pub(crate) fn ip_address_from_row(row: &QueryResult) -> ServiceResult<ip_addresses::Model> {
+    ip_addresses::Model::from_query_result(row, "")
+        .map_err(|e| Errors::DatabaseError(e.to_string()))
+}
+
+/// 감사 로그의 변경 전 스냅샷. 같은 트랜잭션 안에서 행을 잠그고 읽는다
+pub(crate) async fn lock_ip_address<C>(
+    conn: &C,
+    ip_range_id: &Uuid,
+    ip_address: &str,
+) -> ServiceResult<Option<ip_addresses::Model>>
+where
+    C: ConnectionTrait,
+{
+    let sql = format!(
+        "SELECT {} FROM ip_addresses WHERE ip_range_id = $1 AND ip_address = $2::inet FOR UPDATE",
+        IP_ADDRESS_COLUMNS
+    );
+
+    conn.query_one(Statement::from_sql_and_values(
+        sea_orm::DatabaseBackend::Postgres,
+        sql,
+        vec![(*ip_range_id).into(), ip_address.into()],
+    ))
+    .await?
+    .map(|row| ip_address_from_row(&row))
+    .transpose()
+}
+
+/// IP 주소 변경을 감사 로그에 남긴다. 변경 전 행이 없으면 생성으로 기록한다
+pub(crate) async fn record_ip_address_audit<C>(
+    conn: &C,
+    before: Option<&ip_addresses::Model>,
+    after: &ip_addresses::Model,
+    actor_id: Option<Uuid>,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    record_audit(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_IP_ADDRESS,
+            resource_id: after.id,
+            resource_name: Some(&after.ip_address),
+            action: if before.is_some() {
+                ACTION_UPDATE
+            } else {
+                ACTION_CREATE
+            },
+            actor_id,
+            before,
+            after: Some(after),
+        },
+    )
+    .await
+}
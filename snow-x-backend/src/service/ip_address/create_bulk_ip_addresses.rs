@@ -1,8 +1,13 @@
 use crate::entity::ip_addresses;
 use crate::service::error::errors::{Errors, ServiceResult};
-use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
+use crate::service::ip_address::audit::{ip_address_from_row, record_ip_address_audit};
+use crate::service::ip_range::get_ip_range_by_id::service_get_ip_range_by_id;
+use crate::utils::ip_math::{IpNetwork, ip_to_number, number_to_ip, parse_ip};
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement, TransactionTrait};
 use uuid::Uuid;
 
+const MAX_BULK_IP_ADDRESSES: u128 = 1000;
+
 pub async fn service_create_bulk_ip_addresses(
     conn: &DatabaseConnection,
     ip_range_id: &Uuid,
@@ -25,9 +30,28 @@ pub async fn service_create_bulk_ip_addresses(
         return Err(Errors::BadRequestError("Invalid status value".to_string()));
     }
 
+    let ip_range = service_get_ip_range_by_id(conn, ip_range_id).await?;
+    let network = IpNetwork::parse(
+        &ip_range.network_address,
+        ip_range.subnet_mask,
+        ip_range.ip_version,
+    )?;
+
+    let start_addr = parse_ip(start_ip)?;
+    let end_addr = parse_ip(end_ip)?;
+
+    for addr in [&start_addr, &end_addr] {
+        if !network.contains_ip(addr) {
+            return Err(Errors::BadRequestError(format!(
+                "IP address {} is outside of the IP range {}/{}",
+                addr, ip_range.network_address, ip_range.subnet_mask
+            )));
+        }
+    }
+
     // Convert IP addresses to numbers for range calculation
-    let start_num = ip_to_number(start_ip)?;
-    let end_num = ip_to_number(end_ip)?;
+    let start_num = ip_to_number(&start_addr);
+    let end_num = ip_to_number(&end_addr);
 
     if start_num > end_num {
         return Err(Errors::BadRequestError(
@@ -35,18 +59,20 @@ pub async fn service_create_bulk_ip_addresses(
         ));
     }
 
+    // IPv6 대역은 수십억 개 이상의 주소를 가질 수 있으므로 한 번에 생성할 수 있는 개수를 제한한다
     let ip_count = end_num - start_num + 1;
-    if ip_count > 1000 {
-        return Err(Errors::BadRequestError(
-            "Cannot create more than 1000 IP addresses at once".to_string(),
-        ));
+    if ip_count > MAX_BULK_IP_ADDRESSES {
+        return Err(Errors::BadRequestError(format!(
+            "Cannot create more than {} IP addresses at once",
+            MAX_BULK_IP_ADDRESSES
+        )));
     }
 
     // Generate all IP addresses in the range
     let mut ip_addresses_list = Vec::new();
     for i in 0..ip_count {
         let ip_num = start_num + i;
-        let ip_str = number_to_ip(ip_num);
+        let ip_str = number_to_ip(ip_num, network.family()).to_string();
         ip_addresses_list.push(ip_str);
     }
 
@@ -83,10 +109,12 @@ pub async fn service_create_bulk_ip_addresses(
             id,
             ip_range_id,
             HOST(ip_address) as ip_address,
-            mac_address,
+            mac_address::text as mac_address,
             hostname,
             status,
             description,
+            lease_start,
+            lease_end,
             created_by,
             created_at,
             updated_at,
@@ -95,7 +123,9 @@ pub async fn service_create_bulk_ip_addresses(
         values.join(", ")
     );
 
-    let rows = conn
+    let txn = conn.begin().await?;
+
+    let rows = txn
         .query_all(Statement::from_string(
             sea_orm::DatabaseBackend::Postgres,
             sql,
@@ -103,38 +133,15 @@ pub async fn service_create_bulk_ip_addresses(
         .await
         .map_err(|e| Errors::DatabaseError(e.to_string()))?;
 
+    // 이미 있던 주소는 ON CONFLICT로 건너뛰므로 새로 만든 행만 기록된다
     let mut result = Vec::new();
     for row in rows {
-        let model = ip_addresses::Model::from_query_result(&row, "")
-            .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+        let model = ip_address_from_row(&row)?;
+        record_ip_address_audit(&txn, None, &model, Some(*created_by)).await?;
         result.push(model);
     }
 
-    Ok(result)
-}
-
-fn ip_to_number(ip: &str) -> ServiceResult<u32> {
-    let parts: Result<Vec<u32>, _> = ip.split('.').map(|s| s.parse::<u32>()).collect();
+    txn.commit().await?;
 
-    match parts {
-        Ok(p) if p.len() == 4 && p.iter().all(|&x| x <= 255) => {
-            Ok((p[0] << 24) + (p[1] << 16) + (p[2] << 8) + p[3])
-        }
-        _ => Err(Errors::BadRequestError(format!(
-            "Invalid IP address: {}",
-            ip
-        ))),
-    }
-}
-
-fn number_to_ip(num: u32) -> String {
-    format!(
-        "{}.{}.{}.{}",
-        (num >> 24) & 255,
-        (num >> 16) & 255,
-        (num >> 8) & 255,
-        num & 255
-    )
+    Ok(result)
 }
-
-use sea_orm::FromQueryResult;
@@ -1,4 +1,5 @@
 use crate::entity::ip_addresses;
+use crate::service::auth::permission::AccessScope;
 use crate::service::error::errors::{Errors, ServiceResult};
 use sea_orm::{ConnectionTrait, DatabaseConnection, FromQueryResult, Statement};
 use uuid::Uuid;
@@ -8,8 +9,10 @@ pub struct IpAddressListResult {
     pub total: u64,
 }
 
+/// 테넌트의 IP 주소 목록. 사무실 범위가 있는 사용자에게는 그 사무실 대역의 주소만 보인다
 pub async fn service_get_ip_addresses(
     conn: &DatabaseConnection,
+    scope: &AccessScope,
     ip_range_id: Option<&Uuid>,
     status: Option<&str>,
     search: Option<&str>,
@@ -17,9 +20,22 @@ pub async fn service_get_ip_addresses(
     limit: u64,
 ) -> ServiceResult<IpAddressListResult> {
     // Build WHERE clause
-    let mut where_clauses = vec!["is_active = true".to_string()];
-    let mut param_idx = 1;
-    let mut params: Vec<sea_orm::Value> = vec![];
+    // IP 대역의 tenant_id는 사무실을 가리키므로 사무실을 거쳐 테넌트로 거른다
+    let mut where_clauses = vec![
+        "is_active = true".to_string(),
+        "ip_range_id IN (SELECT r.id FROM ip_ranges r JOIN offices o ON o.id = r.tenant_id WHERE o.tenant_id = $1)".to_string(),
+    ];
+    let mut param_idx = 2;
+    let mut params: Vec<sea_orm::Value> = vec![scope.tenant_id.into()];
+
+    if let Some(office_ids) = &scope.office_ids {
+        where_clauses.push(format!(
+            "ip_range_id IN (SELECT id FROM ip_ranges WHERE tenant_id = ANY(${}))",
+            param_idx
+        ));
+        params.push(office_ids.clone().into());
+        param_idx += 1;
+    }
 
     if let Some(range_id) = ip_range_id {
         where_clauses.push(format!("ip_range_id = ${}", param_idx));
@@ -35,7 +51,7 @@ pub async fn service_get_ip_addresses(
 
     if let Some(search_term) = search {
         where_clauses.push(format!(
-            "(HOST(ip_address) ILIKE ${} OR mac_address ILIKE ${} OR hostname ILIKE ${} OR description ILIKE ${})",
+            "(HOST(ip_address) ILIKE ${} OR mac_address::text ILIKE ${} OR hostname ILIKE ${} OR description ILIKE ${})",
             param_idx, param_idx, param_idx, param_idx
         ));
         let search_pattern = format!("%{}%", search_term);
@@ -79,10 +95,12 @@ pub async fn service_get_ip_addresses(
             id,
             ip_range_id,
             HOST(ip_address) as ip_address,
-            mac_address,
+            mac_address::text as mac_address,
             hostname,
             status,
             description,
+            lease_start,
+            lease_end,
             created_by,
             created_at,
             updated_at,
@@ -1,3 +1,4 @@
+pub(crate) mod audit;
 mod create_bulk_ip_addresses;
 mod get_ip_addresses;
 
@@ -0,0 +1,277 @@
+use crate::entity::{device_ip_mappings, devices, ip_addresses, ip_ranges};
+use crate::service::audit::RESOURCE_IP_RANGE;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::ip_address::audit::{
+    ip_address_from_row, lock_ip_address, record_ip_address_audit,
+};
+use crate::service::ip_range::hierarchy::{fetch_tenant_ranges, infer_parents};
+use crate::service::realtime::{self, ipam_topic, publish_realtime_event};
+use crate::service::tenant::tenant_of_ip_range;
+use crate::service::webhook::{EVENT_IP_ALLOCATED, WebhookEvent, publish_webhook_event};
+use crate::utils::ip_math::{IpNetwork, find_free_run, ip_to_number, number_to_ip, parse_ip};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    FromQueryResult, QueryFilter, Set, Statement, TransactionTrait,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+pub const MAX_ALLOCATION_COUNT: u32 = 256;
+
+pub struct AllocateIpParams {
+    pub count: u32,
+    pub hostname: Option<String>,
+    pub description: Option<String>,
+    pub device_id: Option<Uuid>,
+    pub interface_name: Option<String>,
+    pub is_primary: bool,
+}
+
+/// 대역에서 다음 빈 주소(count > 1이면 연속된 주소 블록)를 찾아 `allocated`로 예약한다.
+///
+/// 대역 행을 `FOR UPDATE`로 잠가 같은 대역에 대한 동시 할당을 직렬화하며,
+/// 네트워크/브로드캐스트/게이트웨이 주소와 하위 대역이 차지한 구간은 건너뛴다.
+/// device_id가 주어지면 같은 트랜잭션에서 device_ip_mappings에 연결한다.
+pub async fn service_allocate_ip_addresses(
+    conn: &DatabaseConnection,
+    ip_range_id: &Uuid,
+    params: AllocateIpParams,
+    allocated_by: &Uuid,
+) -> ServiceResult<Vec<ip_addresses::Model>> {
+    if params.count == 0 || params.count > MAX_ALLOCATION_COUNT {
+        return Err(Errors::BadRequestError(format!(
+            "Count must be between 1 and {}",
+            MAX_ALLOCATION_COUNT
+        )));
+    }
+
+    let txn = conn.begin().await?;
+
+    let range = lock_ip_range(&txn, ip_range_id).await?;
+    let network = IpNetwork::parse(&range.network_address, range.subnet_mask, range.ip_version)?;
+
+    if let Some(device_id) = params.device_id {
+        devices::Entity::find_by_id(device_id)
+            .filter(devices::Column::IsActive.eq(true))
+            .one(&txn)
+            .await?
+            .ok_or_else(|| Errors::NotFound("Device not found".to_string()))?;
+    }
+
+    let occupied = occupied_intervals(&txn, &range).await?;
+
+    let (usable_start, usable_end) = network.usable_bounds();
+    let start = find_free_run(usable_start, usable_end, &occupied, params.count as u128)
+        .ok_or_else(|| {
+            Errors::IpRangeExhausted(format!(
+                "No {} contiguous free address(es) left in {}/{}",
+                params.count, range.network_address, range.subnet_mask
+            ))
+        })?;
+
+    let now = chrono::Utc::now();
+    let mut allocated = Vec::with_capacity(params.count as usize);
+
+    for offset in 0..params.count as u128 {
+        let address = number_to_ip(start + offset, network.family()).to_string();
+        let before = lock_ip_address(&txn, &range.id, &address).await?;
+
+        let sql = r#"
+            INSERT INTO ip_addresses (
+                id, ip_range_id, ip_address, status, hostname, description,
+                created_by, created_at, updated_at, is_active
+            ) VALUES (
+                $1, $2, $3::inet, 'allocated', $4, $5, $6, $7, $7, true
+            )
+            ON CONFLICT (ip_address, ip_range_id) DO UPDATE
+            SET status = 'allocated',
+                hostname = COALESCE(EXCLUDED.hostname, ip_addresses.hostname),
+                description = COALESCE(EXCLUDED.description, ip_addresses.description),
+                updated_at = EXCLUDED.updated_at,
+                is_active = true
+            WHERE ip_addresses.status = 'available' OR ip_addresses.is_active = false
+            RETURNING
+                id,
+                ip_range_id,
+                HOST(ip_address) as ip_address,
+                mac_address::text as mac_address,
+                hostname,
+                status,
+                description,
+                lease_start,
+                lease_end,
+                created_by,
+                created_at,
+                updated_at,
+                is_active
+        "#;
+
+        let row = txn
+            .query_one(Statement::from_sql_and_values(
+                sea_orm::DatabaseBackend::Postgres,
+                sql,
+                vec![
+                    Uuid::new_v4().into(),
+                    range.id.into(),
+                    address.clone().into(),
+                    params.hostname.clone().into(),
+                    params.description.clone().into(),
+                    (*allocated_by).into(),
+                    now.into(),
+                ],
+            ))
+            .await?
+            .ok_or_else(|| {
+                Errors::IpRangeExhausted(format!("IP address {} is no longer available", address))
+            })?;
+
+        let model = ip_address_from_row(&row)?;
+        record_ip_address_audit(&txn, before.as_ref(), &model, Some(*allocated_by)).await?;
+        allocated.push(model);
+    }
+
+    if let Some(device_id) = params.device_id {
+        for (index, address) in allocated.iter().enumerate() {
+            device_ip_mappings::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                device_id: Set(device_id),
+                ip_address_id: Set(address.id),
+                interface_name: Set(params.interface_name.clone()),
+                is_primary: Set(params.is_primary && index == 0),
+                created_at: Set(now.into()),
+            }
+            .insert(&txn)
+            .await?;
+        }
+    }
+
+    let tenant = tenant_of_ip_range(&txn, range.id).await?;
+
+    publish_webhook_event(
+        &txn,
+        WebhookEvent {
+            event_type: EVENT_IP_ALLOCATED,
+            resource_type: RESOURCE_IP_RANGE,
+            resource_id: range.id,
+            resource_name: Some(&range.name),
+            actor_id: Some(*allocated_by),
+            tenant_id: tenant.tenant_id(),
+            data: json!({
+                "addresses": allocated,
+                "device_id": params.device_id,
+                "interface_name": params.interface_name,
+            }),
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    if let Some(tenant_id) = tenant.tenant_id() {
+        publish_realtime_event(
+            &ipam_topic(tenant_id),
+            realtime::EVENT_IP_ALLOCATED,
+            json!({
+                "ip_range_id": range.id,
+                "device_id": params.device_id,
+                "ip_addresses": allocated.iter().map(|a| &a.ip_address).collect::<Vec<_>>(),
+            }),
+        )
+        .await;
+    }
+
+    Ok(allocated)
+}
+
+pub(crate) async fn lock_ip_range<C>(conn: &C, id: &Uuid) -> ServiceResult<ip_ranges::Model>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        SELECT
+            id,
+            tenant_id,
+            name,
+            description,
+            HOST(network_address) as network_address,
+            subnet_mask,
+            CASE WHEN gateway IS NOT NULL THEN HOST(gateway) ELSE NULL END as gateway,
+            dns_servers,
+            vlan_id,
+            ip_version,
+            created_by,
+            created_at,
+            updated_at,
+            is_active
+        FROM ip_ranges
+        WHERE id = $1 AND is_active = true
+        FOR UPDATE
+    "#;
+
+    let row = conn
+        .query_one(Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            sql,
+            vec![(*id).into()],
+        ))
+        .await?
+        .ok_or_else(|| Errors::NotFound("IP range not found".to_string()))?;
+
+    ip_ranges::Model::from_query_result(&row, "").map_err(|e| Errors::DatabaseError(e.to_string()))
+}
+
+/// 대역 안에서 사용 중인 주소, 게이트웨이, 하위 대역이 차지한 구간
+pub(crate) async fn occupied_intervals<C>(
+    conn: &C,
+    range: &ip_ranges::Model,
+) -> ServiceResult<Vec<(u128, u128)>>
+where
+    C: ConnectionTrait,
+{
+    #[derive(FromQueryResult)]
+    struct UsedAddress {
+        ip_address: String,
+    }
+
+    let sql = r#"
+        SELECT HOST(ip_address) as ip_address
+        FROM ip_addresses
+        WHERE ip_range_id = $1
+          AND is_active = true
+          AND status <> 'available'
+    "#;
+
+    let rows = conn
+        .query_all(Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            sql,
+            vec![range.id.into()],
+        ))
+        .await?;
+
+    let mut occupied = Vec::with_capacity(rows.len());
+    for row in rows {
+        let used = UsedAddress::from_query_result(&row, "")
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+        if let Ok(addr) = parse_ip(&used.ip_address) {
+            let value = ip_to_number(&addr);
+            occupied.push((value, value));
+        }
+    }
+
+    if let Some(gateway) = range.gateway.as_deref() {
+        let gateway = ip_to_number(&parse_ip(gateway)?);
+        occupied.push((gateway, gateway));
+    }
+
+    let tenant_ranges = fetch_tenant_ranges(conn, &range.tenant_id).await?;
+    let parents = infer_parents(&tenant_ranges);
+    occupied.extend(
+        tenant_ranges
+            .iter()
+            .filter(|child| parents.get(&child.model.id) == Some(&range.id))
+            .map(|child| (child.network.first(), child.network.last())),
+    );
+
+    Ok(occupied)
+}
@@ -1,39 +1,72 @@
 use crate::entity::ip_ranges;
+use crate::service::audit::{ACTION_CREATE, AuditEntry, RESOURCE_IP_RANGE, record_audit_or_warn};
 use crate::service::error::errors::{Errors, ServiceResult};
-use sea_orm::{DatabaseConnection, FromQueryResult};
+use crate::service::ip_range::hierarchy::ensure_no_sibling_overlap;
+use crate::service::tenant::tenant_of_office;
+use crate::service::webhook::{
+    EVENT_IP_RANGE_CREATED, WebhookEvent, publish_webhook_event_or_warn,
+};
+use crate::utils::ip_math::{IpNetwork, parse_ip};
+use sea_orm::{ConnectionTrait, FromQueryResult, TransactionTrait};
+use serde_json::json;
 use uuid::Uuid;
 
-pub async fn service_create_ip_range(
-    conn: &DatabaseConnection,
-    tenant_id: &Uuid,
-    name: &str,
-    description: Option<&str>,
-    network_address: &str,
-    subnet_mask: i32,
-    gateway: Option<&str>,
-    dns_servers: Option<Vec<String>>,
-    vlan_id: Option<i32>,
-    ip_version: i32,
+/// IP 대역 생성 요청. `tenant_id`는 대역을 둘 사무실이다
+pub struct CreateIpRangeParams {
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub network_address: String,
+    pub subnet_mask: i32,
+    pub gateway: Option<String>,
+    pub dns_servers: Option<Vec<String>>,
+    pub vlan_id: Option<i32>,
+    pub ip_version: i32,
+}
+
+pub async fn service_create_ip_range<C>(
+    conn: &C,
+    params: CreateIpRangeParams,
     created_by: &Uuid,
-) -> ServiceResult<ip_ranges::Model> {
-    // Validate subnet mask
-    if subnet_mask < 8 || subnet_mask > 32 {
-        return Err(Errors::BadRequestError(
-            "Subnet mask must be between 8 and 32".to_string(),
-        ));
-    }
+) -> ServiceResult<ip_ranges::Model>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let CreateIpRangeParams {
+        tenant_id,
+        name,
+        description,
+        network_address,
+        subnet_mask,
+        gateway,
+        dns_servers,
+        vlan_id,
+        ip_version,
+    } = params;
 
-    // Validate IP version
-    if ip_version != 4 && ip_version != 6 {
+    // Validate IP version, subnet mask and address family together
+    let network = IpNetwork::parse(&network_address, subnet_mask, ip_version)?;
+
+    if let Some(gw) = gateway.as_deref()
+        && !network.contains_ip(&parse_ip(gw)?)
+    {
         return Err(Errors::BadRequestError(
-            "IP version must be 4 or 6".to_string(),
+            "Gateway must be inside the IP range".to_string(),
         ));
     }
 
+    // 겹침 확인과 저장을 같은 트랜잭션(사무실 잠금) 안에서 한다
+    let txn = conn.begin().await?;
+
+    // Reject duplicate/overlapping ranges in the same office; nested ranges become children
+    ensure_no_sibling_overlap(&txn, &tenant_id, &network, None).await?;
+
+    // Store the aligned network address (e.g. 10.0.1.5/24 -> 10.0.1.0)
+    let network_address = network.network_ip().to_string();
+
     let id = Uuid::new_v4();
     let now = chrono::Utc::now();
 
-    use sea_orm::ConnectionTrait;
     use sea_orm::Statement;
 
     // Convert DNS servers to JSON for storage in JSONB column
@@ -66,20 +99,18 @@ pub async fn service_create_ip_range(
             is_active
     "#;
 
-    let gateway_str = gateway.map(|s| s.to_string());
-
-    let result = conn
+    let result = txn
         .query_one(Statement::from_sql_and_values(
             sea_orm::DatabaseBackend::Postgres,
             sql,
             vec![
                 id.into(),
-                (*tenant_id).into(),
+                tenant_id.into(),
                 name.into(),
-                description.map(|s| s.to_string()).into(),
+                description.into(),
                 network_address.into(),
                 subnet_mask.into(),
-                gateway_str.into(),
+                gateway.into(),
                 dns_json.into(),
                 vlan_id.into(),
                 ip_version.into(),
@@ -95,6 +126,40 @@ pub async fn service_create_ip_range(
     if let Some(row) = result {
         let model = ip_ranges::Model::from_query_result(&row, "")
             .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+        record_audit_or_warn(
+            &txn,
+            AuditEntry {
+                resource_type: RESOURCE_IP_RANGE,
+                resource_id: model.id,
+                resource_name: Some(&model.name),
+                action: ACTION_CREATE,
+                actor_id: Some(*created_by),
+                before: None,
+                after: Some(&model),
+            },
+        )
+        .await;
+
+        // ip_ranges.tenant_id는 사무실이므로 웹훅에는 사무실의 테넌트를 쓴다
+        let owner = tenant_of_office(&txn, model.tenant_id).await?;
+
+        publish_webhook_event_or_warn(
+            &txn,
+            WebhookEvent {
+                event_type: EVENT_IP_RANGE_CREATED,
+                resource_type: RESOURCE_IP_RANGE,
+                resource_id: model.id,
+                resource_name: Some(&model.name),
+                actor_id: Some(*created_by),
+                tenant_id: owner.tenant_id(),
+                data: json!({ "ip_range": model }),
+            },
+        )
+        .await;
+
+        txn.commit().await?;
+
         Ok(model)
     } else {
         Err(Errors::DatabaseError(
@@ -1,8 +1,15 @@
+use crate::service::audit::{ACTION_DELETE, AuditEntry, RESOURCE_IP_RANGE, record_audit_or_warn};
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::ip_range::get_ip_range_by_id::service_get_ip_range_by_id;
 use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
 use uuid::Uuid;
 
-pub async fn service_delete_ip_range(conn: &DatabaseConnection, id: &Uuid) -> ServiceResult<()> {
+pub async fn service_delete_ip_range(
+    conn: &DatabaseConnection,
+    id: &Uuid,
+    deleted_by: &Uuid,
+) -> ServiceResult<()> {
+    let before = service_get_ip_range_by_id(conn, id).await?;
     let now = chrono::Utc::now();
 
     let sql = r#"
@@ -24,5 +31,19 @@ pub async fn service_delete_ip_range(conn: &DatabaseConnection, id: &Uuid) -> Se
         return Err(Errors::NotFound("IP range not found".to_string()));
     }
 
+    record_audit_or_warn(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_IP_RANGE,
+            resource_id: before.id,
+            resource_name: Some(&before.name),
+            action: ACTION_DELETE,
+            actor_id: Some(*deleted_by),
+            before: Some(&before),
+            after: None,
+        },
+    )
+    .await;
+
     Ok(())
 }
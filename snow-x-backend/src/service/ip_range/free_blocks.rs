@@ -0,0 +1,178 @@
+use crate::entity::ip_ranges;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::ip_range::allocate_ip_addresses::{lock_ip_range, occupied_intervals};
+use crate::service::ip_range::create_ip_range::{CreateIpRangeParams, service_create_ip_range};
+use crate::service::ip_range::get_ip_range_by_id::service_get_ip_range_by_id;
+use crate::utils::ip_math::{IpNetwork, cidr_blocks, free_intervals, parse_ip};
+use sea_orm::{ConnectionTrait, DatabaseConnection, TransactionTrait};
+use uuid::Uuid;
+
+pub const MAX_FREE_BLOCK_CANDIDATES: u32 = 256;
+
+pub struct FreeBlocksResult {
+    pub ip_range: ip_ranges::Model,
+    /// 빈 공간을 정렬된 최대 CIDR 블록으로 분해한 목록 (큰 블록 우선)
+    pub blocks: Vec<IpNetwork>,
+    /// 요청한 prefix로 잘라낼 수 있는 후보 블록 (best-fit 순)
+    pub candidates: Vec<IpNetwork>,
+}
+
+pub struct CreateChildSubnetParams {
+    pub name: String,
+    pub description: Option<String>,
+    /// 생략하면 best-fit 위치의 첫 번째 빈 블록을 사용
+    pub network_address: Option<String>,
+    pub subnet_mask: i32,
+    pub gateway: Option<String>,
+    pub dns_servers: Option<Vec<String>>,
+    pub vlan_id: Option<i32>,
+}
+
+/// 대역 안에서 사용 중인 주소(available 제외), 게이트웨이, 하위 대역을 뺀 빈 CIDR 블록을 계산한다.
+/// prefix를 지정하면 해당 크기로 잘라낼 수 있는 블록을 최대 limit개 함께 반환한다.
+pub async fn service_get_free_blocks(
+    conn: &DatabaseConnection,
+    ip_range_id: &Uuid,
+    prefix: Option<i32>,
+    limit: u32,
+) -> ServiceResult<FreeBlocksResult> {
+    let ip_range = service_get_ip_range_by_id(conn, ip_range_id).await?;
+    let network = range_network(&ip_range)?;
+    let blocks = compute_free_blocks(conn, &ip_range, &network).await?;
+
+    let candidates = match prefix {
+        Some(prefix) => {
+            let prefix = validate_child_prefix(&network, prefix)?;
+            carve_candidates(&blocks, prefix, limit.min(MAX_FREE_BLOCK_CANDIDATES))
+        }
+        None => Vec::new(),
+    };
+
+    Ok(FreeBlocksResult {
+        ip_range,
+        blocks,
+        candidates,
+    })
+}
+
+/// 상위 대역의 빈 공간에서 하위 대역을 잘라 새 IP 대역으로 생성한다.
+/// 상위 대역 행을 잠근 트랜잭션 안에서 정렬과 빈 공간 여부를 검증한다.
+pub async fn service_create_child_subnet(
+    conn: &DatabaseConnection,
+    parent_id: &Uuid,
+    params: CreateChildSubnetParams,
+    created_by: &Uuid,
+) -> ServiceResult<ip_ranges::Model> {
+    let txn = conn.begin().await?;
+
+    let parent = lock_ip_range(&txn, parent_id).await?;
+    let network = range_network(&parent)?;
+    let prefix = validate_child_prefix(&network, params.subnet_mask)?;
+    let blocks = compute_free_blocks(&txn, &parent, &network).await?;
+
+    let child = match params.network_address.as_deref() {
+        Some(address) => {
+            let addr = parse_ip(address)?;
+            if !network.contains_ip(&addr) {
+                return Err(Errors::BadRequestError(format!(
+                    "Subnet {}/{} is outside of the parent range {}/{}",
+                    address, prefix, parent.network_address, parent.subnet_mask
+                )));
+            }
+            if !IpNetwork::is_aligned(&addr, prefix) {
+                return Err(Errors::BadRequestError(format!(
+                    "Network address {} is not aligned to /{}",
+                    address, prefix
+                )));
+            }
+
+            let child = IpNetwork::new(addr, prefix)?;
+            let is_free = blocks.iter().any(|block| block.contains_network(&child));
+            if !is_free {
+                return Err(Errors::IpRangeOverlap(format!(
+                    "Subnet {}/{} overlaps addresses or child ranges already in use",
+                    address, prefix
+                )));
+            }
+            child
+        }
+        None => carve_candidates(&blocks, prefix, 1)
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                Errors::IpRangeExhausted(format!(
+                    "No free /{} left in {}/{}",
+                    prefix, parent.network_address, parent.subnet_mask
+                ))
+            })?,
+    };
+
+    let created = service_create_ip_range(
+        &txn,
+        CreateIpRangeParams {
+            tenant_id: parent.tenant_id,
+            name: params.name,
+            description: params.description,
+            network_address: child.network_ip().to_string(),
+            subnet_mask: child.prefix() as i32,
+            gateway: params.gateway,
+            dns_servers: params.dns_servers,
+            vlan_id: params.vlan_id,
+            ip_version: parent.ip_version,
+        },
+        created_by,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(created)
+}
+
+fn range_network(range: &ip_ranges::Model) -> ServiceResult<IpNetwork> {
+    IpNetwork::parse(&range.network_address, range.subnet_mask, range.ip_version)
+}
+
+fn validate_child_prefix(parent: &IpNetwork, prefix: i32) -> ServiceResult<u8> {
+    let prefix = parent.family().validate_prefix(prefix)?;
+    if prefix <= parent.prefix() {
+        return Err(Errors::BadRequestError(format!(
+            "Child prefix /{} must be longer than the parent prefix /{}",
+            prefix,
+            parent.prefix()
+        )));
+    }
+    Ok(prefix)
+}
+
+async fn compute_free_blocks<C>(
+    conn: &C,
+    range: &ip_ranges::Model,
+    network: &IpNetwork,
+) -> ServiceResult<Vec<IpNetwork>>
+where
+    C: ConnectionTrait,
+{
+    let occupied = occupied_intervals(conn, range).await?;
+
+    let mut blocks: Vec<IpNetwork> = free_intervals(network.first(), network.last(), &occupied)
+        .into_iter()
+        .flat_map(|(start, end)| cidr_blocks(start, end, network.family()))
+        .collect();
+    blocks.sort_by_key(|block| (block.prefix(), block.first()));
+
+    Ok(blocks)
+}
+
+/// 요청한 prefix 크기의 블록 후보를 best-fit 순서로 고른다.
+/// 가장 작은(prefix가 긴) 빈 블록부터 잘라 큰 빈 공간이 쪼개지는 것을 줄인다.
+fn carve_candidates(blocks: &[IpNetwork], prefix: u8, limit: u32) -> Vec<IpNetwork> {
+    let mut fitting: Vec<&IpNetwork> = blocks.iter().filter(|b| b.prefix() <= prefix).collect();
+    fitting.sort_by_key(|block| (std::cmp::Reverse(block.prefix()), block.first()));
+
+    fitting
+        .into_iter()
+        .flat_map(|block| block.subnets(prefix))
+        .take(limit as usize)
+        .collect()
+}
@@ -1,4 +1,5 @@
 use crate::entity::ip_ranges;
+use crate::service::auth::permission::AccessScope;
 use crate::service::error::errors::{Errors, ServiceResult};
 use crate::service::ip_range::{RangeUsageStats, fetch_ip_range_usage};
 use sea_orm::{ConnectionTrait, DatabaseConnection, FromQueryResult, Statement};
@@ -11,8 +12,10 @@ pub struct IpRangeListResult {
     pub usage: HashMap<Uuid, RangeUsageStats>,
 }
 
+/// 테넌트의 활성 대역 목록. 사무실 범위가 있는 사용자에게는 그 사무실의 대역만 보인다
 pub async fn service_get_ip_ranges(
     conn: &DatabaseConnection,
+    scope: &AccessScope,
     page: u64,
     limit: u64,
 ) -> ServiceResult<IpRangeListResult> {
@@ -21,6 +24,8 @@ pub async fn service_get_ip_ranges(
         SELECT COUNT(*) as count
         FROM ip_ranges
         WHERE is_active = true
+          AND tenant_id IN (SELECT id FROM offices WHERE tenant_id = $1)
+          AND ($2::uuid[] IS NULL OR tenant_id = ANY($2))
     "#;
 
     #[derive(FromQueryResult)]
@@ -32,7 +37,7 @@ pub async fn service_get_ip_ranges(
         .query_one(Statement::from_sql_and_values(
             sea_orm::DatabaseBackend::Postgres,
             count_sql,
-            vec![],
+            vec![scope.tenant_id.into(), scope.office_ids.clone().into()],
         ))
         .await
         .map_err(|e| Errors::DatabaseError(e.to_string()))?
@@ -63,6 +68,8 @@ pub async fn service_get_ip_ranges(
             is_active
         FROM ip_ranges
         WHERE is_active = true
+          AND tenant_id IN (SELECT id FROM offices WHERE tenant_id = $3)
+          AND ($4::uuid[] IS NULL OR tenant_id = ANY($4))
         ORDER BY created_at DESC
         LIMIT $1 OFFSET $2
     "#;
@@ -71,7 +78,12 @@ pub async fn service_get_ip_ranges(
         .query_all(Statement::from_sql_and_values(
             sea_orm::DatabaseBackend::Postgres,
             sql,
-            vec![(limit as i64).into(), (offset as i64).into()],
+            vec![
+                (limit as i64).into(),
+                (offset as i64).into(),
+                scope.tenant_id.into(),
+                scope.office_ids.clone().into(),
+            ],
         ))
         .await
         .map_err(|e| Errors::DatabaseError(e.to_string()))?;
@@ -0,0 +1,318 @@
+use crate::entity::ip_ranges;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::ip_range::get_ip_range_by_id::service_get_ip_range_by_id;
+use crate::service::ip_range::{RangeUsageStats, fetch_ip_range_usage};
+use crate::utils::ip_math::IpNetwork;
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, FromQueryResult, Statement};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+// IP 대역 계층 구조
+// 부모/자식 관계는 별도 컬럼 없이 같은 테넌트(office) 안에서 CIDR 포함 관계로 추론한다.
+// 자식의 부모는 자신을 포함하는 대역 중 prefix가 가장 긴(가장 작은) 대역이다.
+
+/// 사무실별 대역 쓰기 advisory lock의 첫 번째 키 (`pg_advisory_xact_lock(int4, int4)`), "IPRG"
+const RANGE_LOCK_NAMESPACE: i32 = 0x4950_5247;
+
+pub struct IpRangeTree {
+    pub range: ip_ranges::Model,
+    pub parent_id: Option<Uuid>,
+    pub usage: RangeUsageStats,
+    pub children: Vec<IpRangeTree>,
+}
+
+pub(crate) struct TenantRange {
+    pub model: ip_ranges::Model,
+    pub network: IpNetwork,
+}
+
+pub(crate) async fn fetch_tenant_ranges<C>(
+    conn: &C,
+    tenant_id: &Uuid,
+) -> ServiceResult<Vec<TenantRange>>
+where
+    C: ConnectionTrait,
+{
+    fetch_active_ranges(conn, Some(tenant_id), None).await
+}
+
+/// 활성 대역 목록. `office_id`(`ip_ranges.tenant_id`)와 `owner_tenant_id`(사무실이 속한 테넌트)로
+/// 좁히며, 둘 다 없으면 모든 대역을 조회한다.
+pub(crate) async fn fetch_active_ranges<C>(
+    conn: &C,
+    office_id: Option<&Uuid>,
+    owner_tenant_id: Option<&Uuid>,
+) -> ServiceResult<Vec<TenantRange>>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        SELECT
+            id,
+            tenant_id,
+            name,
+            description,
+            HOST(network_address) as network_address,
+            subnet_mask,
+            CASE WHEN gateway IS NOT NULL THEN HOST(gateway) ELSE NULL END as gateway,
+            dns_servers,
+            vlan_id,
+            ip_version,
+            created_by,
+            created_at,
+            updated_at,
+            is_active
+        FROM ip_ranges
+        WHERE ($1::uuid IS NULL OR tenant_id = $1)
+          AND ($2::uuid IS NULL OR tenant_id IN (SELECT id FROM offices WHERE tenant_id = $2))
+          AND is_active = true
+        ORDER BY created_at
+    "#;
+
+    let rows = conn
+        .query_all(Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            sql,
+            vec![office_id.copied().into(), owner_tenant_id.copied().into()],
+        ))
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    let mut ranges = Vec::with_capacity(rows.len());
+    for row in rows {
+        let model = ip_ranges::Model::from_query_result(&row, "")
+            .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+        // 검증 이전에 저장된 잘못된 대역은 계층 계산에서 제외한다
+        if let Ok(network) =
+            IpNetwork::parse(&model.network_address, model.subnet_mask, model.ip_version)
+        {
+            ranges.push(TenantRange { model, network });
+        }
+    }
+
+    Ok(ranges)
+}
+
+/// 같은 테넌트 안에서 포함 관계가 아닌 겹침(동일 CIDR 중복 포함)이 있으면 거부한다.
+///
+/// 사무실의 대역 쓰기 잠금을 먼저 잡으므로, 대역을 저장하는 트랜잭션 안에서 호출해야
+/// 동시에 들어온 같은 CIDR이 모두 통과하지 않는다.
+pub async fn ensure_no_sibling_overlap<C>(
+    conn: &C,
+    tenant_id: &Uuid,
+    network: &IpNetwork,
+    exclude_id: Option<&Uuid>,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    lock_office_ranges(conn, tenant_id).await?;
+    let ranges = fetch_tenant_ranges(conn, tenant_id).await?;
+
+    if let Some(other) = find_sibling_overlap(&ranges, network, exclude_id) {
+        return Err(Errors::IpRangeOverlap(format!(
+            "IP range {}/{} overlaps with existing range '{}' ({}/{})",
+            network.network_ip(),
+            network.prefix(),
+            other.model.name,
+            other.model.network_address,
+            other.model.subnet_mask
+        )));
+    }
+
+    Ok(())
+}
+
+/// 사무실의 대역 쓰기 잠금. 트랜잭션 범위의 advisory lock이라 트랜잭션이 끝나면 풀린다
+async fn lock_office_ranges<C>(conn: &C, office_id: &Uuid) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        "SELECT pg_advisory_xact_lock($1, hashtext($2::text))",
+        vec![RANGE_LOCK_NAMESPACE.into(), (*office_id).into()],
+    ))
+    .await?;
+    Ok(())
+}
+
+/// 포함 관계가 아니면서 겹치는 첫 번째 대역
+fn find_sibling_overlap<'a>(
+    ranges: &'a [TenantRange],
+    network: &IpNetwork,
+    exclude_id: Option<&Uuid>,
+) -> Option<&'a TenantRange> {
+    ranges.iter().find(|other| {
+        Some(&other.model.id) != exclude_id
+            && other.network.overlaps(network)
+            && !other.network.is_supernet_of(network)
+            && !network.is_supernet_of(&other.network)
+    })
+}
+
+/// 각 대역의 직접 부모 ID를 계산한다.
+pub(crate) fn infer_parents(ranges: &[TenantRange]) -> HashMap<Uuid, Uuid> {
+    let mut parents = HashMap::new();
+
+    for child in ranges {
+        let parent = ranges
+            .iter()
+            .filter(|candidate| candidate.network.is_supernet_of(&child.network))
+            .max_by_key(|candidate| candidate.network.prefix());
+
+        if let Some(parent) = parent {
+            parents.insert(child.model.id, parent.model.id);
+        }
+    }
+
+    parents
+}
+
+pub async fn service_get_ip_range_children(
+    conn: &DatabaseConnection,
+    id: &Uuid,
+) -> ServiceResult<IpRangeTree> {
+    let root = service_get_ip_range_by_id(conn, id).await?;
+    let ranges = fetch_tenant_ranges(conn, &root.tenant_id).await?;
+    let parents = infer_parents(&ranges);
+
+    let mut children_of: HashMap<Uuid, Vec<&TenantRange>> = HashMap::new();
+    for range in &ranges {
+        if let Some(parent_id) = parents.get(&range.model.id) {
+            children_of.entry(*parent_id).or_default().push(range);
+        }
+    }
+    for children in children_of.values_mut() {
+        children.sort_by_key(|child| child.network.first());
+    }
+
+    // 루트 아래 모든 하위 대역의 사용량을 한 번에 조회
+    let mut subtree_ids = vec![root.id];
+    let mut cursor = 0;
+    while cursor < subtree_ids.len() {
+        if let Some(children) = children_of.get(&subtree_ids[cursor]) {
+            subtree_ids.extend(children.iter().map(|child| child.model.id));
+        }
+        cursor += 1;
+    }
+    let usage = fetch_ip_range_usage(conn, &subtree_ids).await?;
+
+    Ok(build_tree(
+        root,
+        parents.get(id).copied(),
+        &children_of,
+        &usage,
+    ))
+}
+
+fn build_tree(
+    range: ip_ranges::Model,
+    parent_id: Option<Uuid>,
+    children_of: &HashMap<Uuid, Vec<&TenantRange>>,
+    usage: &HashMap<Uuid, RangeUsageStats>,
+) -> IpRangeTree {
+    let children = children_of
+        .get(&range.id)
+        .map(|children| {
+            children
+                .iter()
+                .map(|child| build_tree(child.model.clone(), Some(range.id), children_of, usage))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    IpRangeTree {
+        usage: usage.get(&range.id).cloned().unwrap_or_default(),
+        range,
+        parent_id,
+        children,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(cidr: &str) -> TenantRange {
+        let (addr, prefix) = cidr.split_once('/').unwrap();
+        let network = IpNetwork::new(addr.parse().unwrap(), prefix.parse().unwrap()).unwrap();
+        let now = chrono::Utc::now().into();
+        TenantRange {
+            model: ip_ranges::Model {
+                id: Uuid::new_v4(),
+                tenant_id: Uuid::nil(),
+                name: cidr.to_string(),
+                description: None,
+                network_address: network.network_ip().to_string(),
+                subnet_mask: network.prefix() as i32,
+                gateway: None,
+                dns_servers: None,
+                vlan_id: None,
+                ip_version: 4,
+                created_by: Uuid::nil(),
+                created_at: now,
+                updated_at: now,
+                is_active: true,
+            },
+            network,
+        }
+    }
+
+    #[test]
+    fn rejects_duplicate_cidr() {
+        let ranges = vec![range("10.0.0.0/24")];
+
+        let duplicate = range("10.0.0.0/24").network;
+        assert_eq!(
+            find_sibling_overlap(&ranges, &duplicate, None).map(|r| r.model.id),
+            Some(ranges[0].model.id)
+        );
+
+        // 자기 자신은 수정할 때 제외한다
+        assert!(find_sibling_overlap(&ranges, &duplicate, Some(&ranges[0].model.id)).is_none());
+    }
+
+    #[test]
+    fn allows_nested_and_disjoint_ranges() {
+        let ranges = vec![range("10.0.0.0/16"), range("10.1.0.0/24")];
+
+        assert!(find_sibling_overlap(&ranges, &range("10.0.4.0/24").network, None).is_none());
+        assert!(find_sibling_overlap(&ranges, &range("10.1.1.0/24").network, None).is_none());
+        assert!(find_sibling_overlap(&ranges, &range("10.0.0.0/8").network, None).is_none());
+    }
+
+    #[test]
+    fn infers_the_longest_prefix_parent() {
+        let ranges = vec![
+            range("10.0.0.0/8"),
+            range("10.0.0.0/16"),
+            range("10.0.1.0/24"),
+            range("10.1.0.0/24"),
+            range("192.168.0.0/24"),
+        ];
+        let parents = infer_parents(&ranges);
+        let id = |i: usize| ranges[i].model.id;
+
+        assert_eq!(parents.get(&id(0)), None);
+        assert_eq!(parents.get(&id(1)), Some(&id(0)));
+        assert_eq!(parents.get(&id(2)), Some(&id(1)));
+        assert_eq!(parents.get(&id(3)), Some(&id(0)));
+        assert_eq!(parents.get(&id(4)), None);
+    }
+
+    #[test]
+    fn disjoint_siblings_share_a_parent() {
+        let ranges = vec![
+            range("172.16.0.0/16"),
+            range("172.16.0.0/24"),
+            range("172.16.1.0/24"),
+        ];
+        let parents = infer_parents(&ranges);
+
+        assert_eq!(parents.get(&ranges[1].model.id), Some(&ranges[0].model.id));
+        assert_eq!(parents.get(&ranges[2].model.id), Some(&ranges[0].model.id));
+        assert!(!parents.contains_key(&ranges[0].model.id));
+    }
+}
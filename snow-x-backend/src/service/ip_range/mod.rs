@@ -1,7 +1,10 @@
+pub mod allocate_ip_addresses;
 pub mod create_ip_range;
 pub mod delete_ip_range;
+pub mod free_blocks;
 pub mod get_ip_range_by_id;
 pub mod get_ip_ranges;
+pub mod hierarchy;
 pub mod update_ip_range;
 pub mod usage;
 
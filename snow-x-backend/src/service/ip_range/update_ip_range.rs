@@ -1,8 +1,19 @@
 use crate::entity::{ip_ranges, users};
+use crate::service::audit::{ACTION_UPDATE, AuditEntry, RESOURCE_IP_RANGE, record_audit_or_warn};
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::ip_range::hierarchy::ensure_no_sibling_overlap;
+use crate::service::notification::channel::CHANNEL_WEB;
 use crate::service::notification::{self, CreateNotificationParams};
+use crate::service::tenant::tenant_of_office;
+use crate::service::webhook::{
+    EVENT_IP_RANGE_UPDATED, WebhookEvent, publish_webhook_event_or_warn,
+};
+use crate::utils::ip_math::{IpNetwork, parse_ip};
 use chrono::Utc;
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set,
+    TransactionTrait,
+};
 use serde_json::json;
 use tracing::warn;
 use uuid::Uuid;
@@ -20,31 +31,35 @@ pub async fn service_update_ip_range(
     vlan_id: Option<i32>,
     ip_version: Option<i32>,
 ) -> ServiceResult<ip_ranges::Model> {
-    // Validate subnet mask if provided
-    if let Some(mask) = subnet_mask {
-        if mask < 8 || mask > 32 {
-            return Err(Errors::BadRequestError(
-                "Subnet mask must be between 8 and 32".to_string(),
-            ));
-        }
-    }
-
-    // Validate IP version if provided
-    if let Some(version) = ip_version {
-        if version != 4 && version != 6 {
-            return Err(Errors::BadRequestError(
-                "IP version must be 4 or 6".to_string(),
-            ));
-        }
-    }
+    // 겹침 확인과 저장을 같은 트랜잭션(사무실 잠금) 안에서 한다
+    let txn = conn.begin().await?;
 
     let existing = ip_ranges::Entity::find_by_id(*id)
         .filter(ip_ranges::Column::IsActive.eq(true))
-        .one(conn)
+        .one(&txn)
         .await
         .map_err(|e| Errors::DatabaseError(e.to_string()))?
         .ok_or_else(|| Errors::NotFound("IP range not found".to_string()))?;
 
+    // Validate the resulting network (IP version, subnet mask and address family together)
+    let network = IpNetwork::parse(
+        network_address
+            .as_deref()
+            .unwrap_or(&existing.network_address),
+        subnet_mask.unwrap_or(existing.subnet_mask),
+        ip_version.unwrap_or(existing.ip_version),
+    )?;
+
+    if let Some(gw) = gateway.as_deref().or(existing.gateway.as_deref())
+        && !network.contains_ip(&parse_ip(gw)?)
+    {
+        return Err(Errors::BadRequestError(
+            "Gateway must be inside the IP range".to_string(),
+        ));
+    }
+
+    ensure_no_sibling_overlap(&txn, &existing.tenant_id, &network, Some(id)).await?;
+
     let mut model: ip_ranges::ActiveModel = existing.clone().into();
     let mut has_change = false;
 
@@ -56,12 +71,10 @@ pub async fn service_update_ip_range(
         model.description = Set(Some(d));
         has_change = true;
     }
-    if let Some(na) = network_address {
-        model.network_address = Set(na);
-        has_change = true;
-    }
-    if let Some(sm) = subnet_mask {
-        model.subnet_mask = Set(sm);
+    if network_address.is_some() || subnet_mask.is_some() {
+        // Store the aligned network address (e.g. 10.0.1.5/24 -> 10.0.1.0)
+        model.network_address = Set(network.network_ip().to_string());
+        model.subnet_mask = Set(network.prefix() as i32);
         has_change = true;
     }
     if let Some(g) = gateway {
@@ -88,10 +101,26 @@ pub async fn service_update_ip_range(
     model.updated_at = Set(Utc::now().into());
 
     let updated = model
-        .update(conn)
+        .update(&txn)
         .await
         .map_err(|e| Errors::DatabaseError(e.to_string()))?;
 
+    record_audit_or_warn(
+        &txn,
+        AuditEntry {
+            resource_type: RESOURCE_IP_RANGE,
+            resource_id: updated.id,
+            resource_name: Some(&updated.name),
+            action: ACTION_UPDATE,
+            actor_id: Some(updated_by),
+            before: Some(&existing),
+            after: Some(&updated),
+        },
+    )
+    .await;
+
+    txn.commit().await?;
+
     enqueue_ip_range_diff_notification(conn, &existing, &updated, updated_by)
         .await
         .unwrap_or_else(|err| {
@@ -179,6 +208,8 @@ async fn enqueue_ip_range_diff_notification(
 
     let payload = json!({
         "ip_range_id": after.id,
+        "resource_type": RESOURCE_IP_RANGE,
+        "resource_id": after.id,
         "diff": diff,
         "actor_id": updated_by,
         "actor_name": actor_name,
@@ -188,17 +219,34 @@ async fn enqueue_ip_range_diff_notification(
         "resource_name": after.name,
     });
 
+    // ip_ranges.tenant_id는 사무실이므로 웹훅과 알림에는 사무실의 테넌트를 쓴다
+    let tenant_id = tenant_of_office(conn, after.tenant_id).await?.tenant_id();
+
+    publish_webhook_event_or_warn(
+        conn,
+        WebhookEvent {
+            event_type: EVENT_IP_RANGE_UPDATED,
+            resource_type: RESOURCE_IP_RANGE,
+            resource_id: after.id,
+            resource_name: Some(&after.name),
+            actor_id: Some(updated_by),
+            tenant_id,
+            data: payload.clone(),
+        },
+    )
+    .await;
+
     notification::service_create_notification(
         conn,
         CreateNotificationParams {
-            tenant_id: Some(after.tenant_id),
-            channel: "web".to_string(),
-            category: Some("ip_range_updated".to_string()),
+            tenant_id,
+            channel: CHANNEL_WEB.to_string(),
+            category: Some(notification::CATEGORY_IP_RANGE_UPDATED.to_string()),
             title: Some(format!("IP 대역 수정: {}", after.name)),
             message: Some("IP 대역 정보가 수정되었습니다.".to_string()),
             payload: Some(payload),
             scheduled_at: None,
-            max_retries: Some(0),
+            max_retries: None,
         },
     )
     .await?;
@@ -39,6 +39,7 @@ struct UsageRow {
     count: i64,
 }
 
+/// 대역별 상태 집계. IPv4/IPv6 모두 대역의 네트워크 안에 속한 주소만 집계한다.
 pub async fn fetch_ip_range_usage(
     conn: &DatabaseConnection,
     range_ids: &[Uuid],
@@ -54,11 +55,14 @@ pub async fn fetch_ip_range_usage(
         .collect();
     let sql = format!(
         r#"
-        SELECT ip_range_id, status, COUNT(*) as count
-        FROM ip_addresses
-        WHERE is_active = true
-          AND ip_range_id IN ({})
-        GROUP BY ip_range_id, status
+        SELECT a.ip_range_id, a.status, COUNT(*) as count
+        FROM ip_addresses a
+        JOIN ip_ranges r ON r.id = a.ip_range_id
+        WHERE a.is_active = true
+          AND a.ip_range_id IN ({})
+          AND family(a.ip_address) = r.ip_version
+          AND a.ip_address <<= set_masklen(r.network_address, r.subnet_mask)
+        GROUP BY a.ip_range_id, a.status
         "#,
         placeholders.join(", ")
     );
@@ -4,8 +4,12 @@ use crate::repository::like::create_like::repository_create_like_by_post_id;
 use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
 use crate::repository::post::update_like_count::repository_increment_post_like_count;
 use crate::repository::system_events::log_event::repository_log_event;
+use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::notification::CATEGORY_POST_LIKE;
+use crate::service::notification::inbox::{UserNotificationParams, enqueue_user_notification};
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use serde_json::json;
 use uuid::Uuid;
 
 pub async fn service_create_post_like<C>(
@@ -19,7 +23,7 @@ where
     let txn = conn.begin().await?;
 
     // 포스트 존재 확인
-    let _post = repository_get_post_by_uuid(&txn, post_id).await?;
+    let post = repository_get_post_by_uuid(&txn, post_id).await?;
 
     // 자신의 포스트에도 좋아요를 누를 수 있음 (제거된 제약)
 
@@ -35,6 +39,27 @@ where
     // 포스트 좋아요 개수 증가
     repository_increment_post_like_count(&txn, *post_id).await?;
 
+    // 포스트 작성자에게 알림 (자기 포스트면 보내지 않음)
+    let liker = repository_find_user_by_uuid(&txn, user_id)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+    let author = repository_find_user_by_uuid(&txn, &post.user_id)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+    enqueue_user_notification(
+        &txn,
+        UserNotificationParams {
+            category: CATEGORY_POST_LIKE,
+            title: format!("{}님이 포스트를 좋아합니다", liker.name),
+            message: post.title.clone(),
+            recipient_ids: vec![post.user_id],
+            actor_id: Some(*user_id),
+            link: Some(format!("/@{}/post/{}", author.handle, post.slug)),
+            data: json!({ "post_id": post.id }),
+        },
+    )
+    .await?;
+
     txn.commit().await?;
 
     // 좋아요 생성 이벤트 로깅
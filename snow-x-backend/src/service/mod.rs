@@ -1,12 +1,21 @@
 pub mod admin;
+pub mod api_token;
+pub mod audit;
 pub mod auth;
+pub mod bulk_io;
 pub mod comment;
 pub mod contact;
+pub mod custodian_reconciler;
+pub mod custodian_scheduler;
 pub mod custodian_service;
+pub mod custodian_validator;
 pub mod device;
 pub mod device_library;
+pub mod dhcp_lease;
+pub mod dns_zone;
 pub mod draft;
 pub mod error;
+pub mod external_api;
 pub mod follow;
 pub mod hashtag;
 pub mod ip_address;
@@ -15,9 +24,15 @@ pub mod like;
 pub mod meilisearch;
 pub mod notification;
 pub mod oauth;
+pub mod office;
 pub mod post;
 pub mod rack;
+pub mod rate_limit;
+pub mod realtime;
 pub mod report;
 pub mod server_room;
+pub mod tenant;
+pub mod two_factor;
 pub mod user;
 pub mod validator;
+pub mod webhook;
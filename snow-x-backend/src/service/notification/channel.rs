@@ -0,0 +1,243 @@
+//! 알림 발송 채널.
+//!
+//! `notifications_outbox.channel` 값으로 채널을 고른다. 새 채널은 `NotificationChannel`을
+//! 구현해 `ChannelRegistry`에 등록하면 된다.
+
+use crate::config::db_config::DbConfig;
+use crate::entity::notifications_outbox;
+use crate::microservices::email_client::queue_send_email;
+use reqwest::{Client, StatusCode, Url};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const CHANNEL_WEB: &str = "web";
+pub const CHANNEL_EMAIL: &str = "email";
+pub const CHANNEL_WEBHOOK: &str = "webhook";
+
+/// 웹훅 요청 하나의 최대 대기 시간
+pub const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 발송 실패. 다시 보내도 같은 결과일 실패(설정 누락 등)는 재시도하지 않는다.
+#[derive(Debug)]
+pub enum DeliveryError {
+    Retryable(String),
+    Permanent(String),
+}
+
+impl DeliveryError {
+    pub fn message(&self) -> &str {
+        match self {
+            DeliveryError::Retryable(message) | DeliveryError::Permanent(message) => message,
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, DeliveryError::Retryable(_))
+    }
+}
+
+pub type DeliveryFuture<'a> = Pin<Box<dyn Future<Output = Result<(), DeliveryError>> + Send + 'a>>;
+
+pub trait NotificationChannel: Send + Sync {
+    /// `notifications_outbox.channel` 값
+    fn name(&self) -> &'static str;
+
+    fn deliver<'a>(&'a self, notification: &'a notifications_outbox::Model) -> DeliveryFuture<'a>;
+}
+
+/// 채널 이름 → 구현
+#[derive(Clone, Default)]
+pub struct ChannelRegistry {
+    channels: HashMap<&'static str, Arc<dyn NotificationChannel>>,
+}
+
+impl ChannelRegistry {
+    /// email, webhook 채널을 등록한 기본 구성.
+    /// web 채널(`inbox::InboxChannel`)은 DB 연결이 필요해 발송 태스크를 시작할 때 등록한다.
+    pub fn with_default_channels(http_client: Client) -> Self {
+        let mut registry = Self::default();
+        registry.register(EmailChannel {
+            http_client: http_client.clone(),
+        });
+        registry.register(WebhookChannel {
+            http_client,
+            default_url: DbConfig::get().notification_webhook_url.clone(),
+        });
+        registry
+    }
+
+    pub fn register<T: NotificationChannel + 'static>(&mut self, channel: T) {
+        self.channels.insert(channel.name(), Arc::new(channel));
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<dyn NotificationChannel>> {
+        self.channels.get(name).cloned()
+    }
+}
+
+fn payload_str<'a>(notification: &'a notifications_outbox::Model, key: &str) -> Option<&'a str> {
+    notification
+        .payload
+        .as_ref()?
+        .get(key)?
+        .as_str()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+/// 태스크 서버를 통해 메일을 보낸다.
+///
+/// 받는 사람은 `payload.email_to`(문자열 또는 문자열 배열), 본문은 `payload.html_content`가
+/// 있으면 그대로, 없으면 `message`를 HTML로 감싸 쓴다.
+pub struct EmailChannel {
+    http_client: Client,
+}
+
+fn email_recipients(notification: &notifications_outbox::Model) -> Vec<String> {
+    let Some(value) = notification
+        .payload
+        .as_ref()
+        .and_then(|payload| payload.get("email_to"))
+    else {
+        return Vec::new();
+    };
+    let values: Vec<&Value> = match value {
+        Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    };
+    values
+        .into_iter()
+        .filter_map(Value::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '\n' => escaped.push_str("<br>"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+impl NotificationChannel for EmailChannel {
+    fn name(&self) -> &'static str {
+        CHANNEL_EMAIL
+    }
+
+    fn deliver<'a>(&'a self, notification: &'a notifications_outbox::Model) -> DeliveryFuture<'a> {
+        Box::pin(async move {
+            let recipients = email_recipients(notification);
+            if recipients.is_empty() {
+                return Err(DeliveryError::Permanent(
+                    "payload.email_to is required for email notifications".to_string(),
+                ));
+            }
+
+            let subject = notification
+                .title
+                .as_deref()
+                .or(notification.category.as_deref())
+                .unwrap_or("SnowX notification");
+            let html_content = match payload_str(notification, "html_content") {
+                Some(html) => html.to_string(),
+                None => format!(
+                    "<p>{}</p>",
+                    escape_html(notification.message.as_deref().unwrap_or(subject))
+                ),
+            };
+
+            for email_to in &recipients {
+                queue_send_email(&self.http_client, email_to, subject, &html_content)
+                    .await
+                    .map_err(|e| {
+                        DeliveryError::Retryable(format!("Email to {} failed: {}", email_to, e))
+                    })?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// 알림 내용을 JSON으로 POST한다. 주소는 `payload.webhook_url`, 없으면 `NOTIFICATION_WEBHOOK_URL`.
+pub struct WebhookChannel {
+    http_client: Client,
+    default_url: Option<String>,
+}
+
+impl NotificationChannel for WebhookChannel {
+    fn name(&self) -> &'static str {
+        CHANNEL_WEBHOOK
+    }
+
+    fn deliver<'a>(&'a self, notification: &'a notifications_outbox::Model) -> DeliveryFuture<'a> {
+        Box::pin(async move {
+            let url = payload_str(notification, "webhook_url")
+                .or(self.default_url.as_deref())
+                .ok_or_else(|| {
+                    DeliveryError::Permanent(
+                        "No webhook_url in payload and NOTIFICATION_WEBHOOK_URL is not set"
+                            .to_string(),
+                    )
+                })?;
+            let url = Url::parse(url)
+                .ok()
+                .filter(|url| matches!(url.scheme(), "http" | "https"))
+                .ok_or_else(|| {
+                    DeliveryError::Permanent(format!("Invalid webhook URL '{}'", url))
+                })?;
+
+            let body = serde_json::json!({
+                "id": notification.id,
+                "tenant_id": notification.tenant_id,
+                "category": notification.category,
+                "title": notification.title,
+                "message": notification.message,
+                "payload": notification.payload,
+                "created_at": notification.created_at,
+            });
+
+            let response = self
+                .http_client
+                .post(url)
+                .timeout(WEBHOOK_TIMEOUT)
+                .json(&body)
+                .send()
+                .await
+                .map_err(|e| DeliveryError::Retryable(format!("Webhook request failed: {}", e)))?;
+
+            check_webhook_status(response.status())
+        })
+    }
+}
+
+/// 웹훅 응답 상태를 발송 결과로 바꾼다.
+/// 요청 자체가 거부된 경우(4xx)는 다시 보내도 같으므로 재시도하지 않는다.
+pub fn check_webhook_status(status: StatusCode) -> Result<(), DeliveryError> {
+    if status.is_success() {
+        return Ok(());
+    }
+    let message = format!("Webhook responded with {}", status);
+    if status.is_client_error()
+        && status != StatusCode::REQUEST_TIMEOUT
+        && status != StatusCode::TOO_MANY_REQUESTS
+    {
+        Err(DeliveryError::Permanent(message))
+    } else {
+        Err(DeliveryError::Retryable(message))
+    }
+}
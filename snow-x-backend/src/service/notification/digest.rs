@@ -0,0 +1,136 @@
+//! 메일 요약(digest) 발송 태스크.
+//!
+//! 요약으로 받도록 설정한 알림은 `notification_digest_items`에 쌓인다. 가장 오래된 항목이
+//! `NOTIFICATION_DIGEST_INTERVAL`초보다 오래된 사용자마다 쌓인 항목을 메일 한 통으로 묶어
+//! email outbox 행을 만들고 항목을 지운다. 항목은 `FOR UPDATE SKIP LOCKED`로 가져오므로
+//! 여러 인스턴스가 같은 항목을 두 번 보내지 않는다.
+
+use super::channel::{CHANNEL_EMAIL, escape_html};
+use super::inbox::email_html;
+use super::{CreateNotificationParams, service_create_notification};
+use crate::config::db_config::DbConfig;
+use crate::entity::{notification_digest_items, users};
+use crate::service::error::errors::ServiceResult;
+use chrono::{Duration as ChronoDuration, Utc};
+use sea_orm::sea_query::{LockBehavior, LockType};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait,
+};
+use serde_json::json;
+use std::time::Duration;
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// 요약 메일의 outbox 분류
+const DIGEST_CATEGORY: &str = "digest";
+/// 보낼 요약이 있는지 확인하는 간격
+const DIGEST_POLL_INTERVAL: Duration = Duration::from_secs(300);
+/// 한 번에 처리하는 사용자 수
+const DIGEST_USER_BATCH: u64 = 100;
+/// 메일 한 통에 담는 최대 항목 수. 나머지는 다음 요약으로 넘어간다.
+const DIGEST_MAX_ITEMS: u64 = 200;
+
+/// 한 사용자의 쌓인 항목을 메일 한 통으로 보낸다. 보낸 항목 수를 돌려준다.
+async fn send_digest(conn: &DatabaseConnection, user_id: Uuid) -> ServiceResult<usize> {
+    let Some(user) = users::Entity::find_by_id(user_id).one(conn).await? else {
+        return Ok(0);
+    };
+
+    let txn = conn.begin().await?;
+    let items = notification_digest_items::Entity::find()
+        .filter(notification_digest_items::Column::UserId.eq(user_id))
+        .order_by_asc(notification_digest_items::Column::CreatedAt)
+        .limit(DIGEST_MAX_ITEMS)
+        .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+        .all(&txn)
+        .await?;
+    if items.is_empty() {
+        txn.commit().await?;
+        return Ok(0);
+    }
+
+    let title = format!("SnowX 알림 요약 ({}건)", items.len());
+    let mut html_content = format!("<h2>{}</h2>", escape_html(&title));
+    for item in &items {
+        html_content.push_str(&email_html(
+            item.title.as_deref(),
+            item.message.as_deref(),
+            item.link.as_deref(),
+        ));
+        html_content.push_str("<hr>");
+    }
+
+    service_create_notification(
+        &txn,
+        CreateNotificationParams {
+            tenant_id: None,
+            channel: CHANNEL_EMAIL.to_string(),
+            category: Some(DIGEST_CATEGORY.to_string()),
+            title: Some(title),
+            message: None,
+            payload: Some(json!({
+                "email_to": user.email,
+                "html_content": html_content,
+            })),
+            scheduled_at: None,
+            max_retries: None,
+        },
+    )
+    .await?;
+
+    notification_digest_items::Entity::delete_many()
+        .filter(notification_digest_items::Column::Id.is_in(items.iter().map(|item| item.id)))
+        .exec(&txn)
+        .await?;
+    txn.commit().await?;
+
+    Ok(items.len())
+}
+
+/// 요약을 보낼 때가 된 사용자마다 요약 메일을 예약한다. 보낸 사용자 수를 돌려준다.
+async fn send_due_digests(conn: &DatabaseConnection, interval: u64) -> ServiceResult<usize> {
+    let cutoff = Utc::now() - ChronoDuration::seconds(interval as i64);
+    let user_ids: Vec<Uuid> = notification_digest_items::Entity::find()
+        .select_only()
+        .column(notification_digest_items::Column::UserId)
+        .filter(notification_digest_items::Column::CreatedAt.lte(cutoff))
+        .distinct()
+        .limit(DIGEST_USER_BATCH)
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    let mut sent = 0;
+    for user_id in user_ids {
+        match send_digest(conn, user_id).await {
+            Ok(0) => {}
+            Ok(_) => sent += 1,
+            Err(e) => error!("Failed to send notification digest to {}: {:?}", user_id, e),
+        }
+    }
+    Ok(sent)
+}
+
+/// 요약 발송 태스크를 시작한다. `NOTIFICATION_DIGEST_INTERVAL=0`이면 시작하지 않는다.
+pub fn spawn_notification_digest(conn: DatabaseConnection) {
+    let interval = DbConfig::get().notification_digest_interval;
+    if interval == 0 {
+        info!("Notification digest is disabled");
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(DIGEST_POLL_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match send_due_digests(&conn, interval).await {
+                Ok(0) => {}
+                Ok(count) => info!("Queued notification digest for {} user(s)", count),
+                Err(e) => error!("Failed to send notification digests: {:?}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,278 @@
+//! `notifications_outbox`의 대기 중인 알림을 채널별로 발송하는 백그라운드 태스크.
+//!
+//! 예약 시각이 지난 pending 행을 `FOR UPDATE SKIP LOCKED`로 가져와 processing으로 바꾼 뒤
+//! 발송하므로 여러 인스턴스가 같은 행을 동시에 보내지 않는다. 실패하면 `max_retries`까지
+//! 지수 백오프로 다시 예약하고, processing 상태로 멈춘 행(발송 중 프로세스 종료 등)은
+//! 시간이 지나면 실패 한 번으로 보고 다시 예약한다.
+
+use super::channel::{ChannelRegistry, DeliveryError};
+use super::inbox::InboxChannel;
+use super::{STATUS_DONE, STATUS_FAILED, STATUS_PENDING, STATUS_PROCESSING};
+use crate::config::db_config::DbConfig;
+use crate::connection::outbound::OutboundHttpClient;
+use crate::entity::notifications_outbox;
+use crate::service::error::errors::ServiceResult;
+use crate::service::webhook::delivery::SubscriptionWebhookChannel;
+use chrono::{DateTime, Duration as ChronoDuration, SubsecRound, Utc};
+use reqwest::Client;
+use sea_orm::sea_query::{Expr, LockBehavior, LockType};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, TransactionTrait,
+};
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tracing::{debug, error, info, warn};
+
+/// 한 번에 되살리는 멈춘 행 수
+const RECLAIM_BATCH: u64 = 100;
+
+/// `retries`번째 재시도까지 기다릴 시간 (초): base·2^(retries-1), 최대 max_backoff
+fn retry_delay(retries: i32) -> i64 {
+    let config = DbConfig::get();
+    let exponent = retries.saturating_sub(1).clamp(0, 20) as u32;
+    config
+        .notification_retry_base
+        .max(1)
+        .saturating_mul(1u64 << exponent)
+        .min(config.notification_max_backoff.max(1)) as i64
+}
+
+/// 실패한 알림을 다시 예약하거나 실패로 끝낸다.
+/// `claimed_at`이 지금 값과 다르면 다른 작업자가 이미 가져간 행이므로 건드리지 않는다.
+async fn record_failure<C>(
+    conn: &C,
+    notification: &notifications_outbox::Model,
+    claimed_at: Option<DateTime<Utc>>,
+    error: &DeliveryError,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    use notifications_outbox::Column;
+
+    let now = Utc::now();
+    let mut update = notifications_outbox::Entity::update_many()
+        .col_expr(Column::LastError, Expr::value(error.message()))
+        .col_expr(Column::UpdatedAt, Expr::value(now))
+        .filter(Column::Id.eq(notification.id))
+        .filter(Column::Status.eq(STATUS_PROCESSING));
+    if let Some(claimed_at) = claimed_at {
+        update = update.filter(Column::ProcessingStartedAt.eq(claimed_at));
+    }
+
+    if error.is_retryable() && notification.retry_count < notification.max_retries {
+        let retries = notification.retry_count + 1;
+        let scheduled_at = now + ChronoDuration::seconds(retry_delay(retries));
+        update = update
+            .col_expr(Column::Status, Expr::value(STATUS_PENDING))
+            .col_expr(Column::RetryCount, Expr::value(retries))
+            .col_expr(Column::ScheduledAt, Expr::value(scheduled_at))
+            .col_expr(
+                Column::ProcessingStartedAt,
+                Expr::value(Option::<DateTime<Utc>>::None),
+            );
+        debug!(
+            "Notification {} failed (retry {}/{} at {}): {}",
+            notification.id,
+            retries,
+            notification.max_retries,
+            scheduled_at,
+            error.message()
+        );
+    } else {
+        update = update
+            .col_expr(Column::Status, Expr::value(STATUS_FAILED))
+            .col_expr(Column::ProcessedAt, Expr::value(now));
+        warn!(
+            "Notification {} ({}) failed permanently after {} retries: {}",
+            notification.id,
+            notification.channel,
+            notification.retry_count,
+            error.message()
+        );
+    }
+
+    update.exec(conn).await?;
+    Ok(())
+}
+
+async fn record_success(
+    conn: &DatabaseConnection,
+    notification: &notifications_outbox::Model,
+    claimed_at: DateTime<Utc>,
+) -> ServiceResult<()> {
+    use notifications_outbox::Column;
+
+    let now = Utc::now();
+    notifications_outbox::Entity::update_many()
+        .col_expr(Column::Status, Expr::value(STATUS_DONE))
+        .col_expr(Column::ProcessedAt, Expr::value(now))
+        .col_expr(Column::UpdatedAt, Expr::value(now))
+        .filter(Column::Id.eq(notification.id))
+        .filter(Column::Status.eq(STATUS_PROCESSING))
+        .filter(Column::ProcessingStartedAt.eq(claimed_at))
+        .exec(conn)
+        .await?;
+    Ok(())
+}
+
+/// 예약 시각이 지난 pending 알림을 가져와 processing으로 바꾼다
+async fn claim_due(
+    conn: &DatabaseConnection,
+    batch: u64,
+) -> ServiceResult<(Vec<notifications_outbox::Model>, DateTime<Utc>)> {
+    use notifications_outbox::Column;
+
+    // DB에 저장되는 정밀도(마이크로초)로 맞춰야 완료 처리 시 같은 값으로 비교된다
+    let now = Utc::now().trunc_subsecs(6);
+    let txn = conn.begin().await?;
+    let rows = notifications_outbox::Entity::find()
+        .filter(Column::Status.eq(STATUS_PENDING))
+        .filter(Column::ScheduledAt.lte(now))
+        .order_by_asc(Column::ScheduledAt)
+        .limit(batch)
+        .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+        .all(&txn)
+        .await?;
+
+    if !rows.is_empty() {
+        notifications_outbox::Entity::update_many()
+            .col_expr(Column::Status, Expr::value(STATUS_PROCESSING))
+            .col_expr(Column::ProcessingStartedAt, Expr::value(now))
+            .col_expr(Column::UpdatedAt, Expr::value(now))
+            .filter(Column::Id.is_in(rows.iter().map(|row| row.id)))
+            .exec(&txn)
+            .await?;
+    }
+    txn.commit().await?;
+
+    Ok((rows, now))
+}
+
+/// processing 상태로 `timeout`보다 오래 머문 알림을 실패 한 번으로 보고 다시 예약한다
+async fn reclaim_stuck(conn: &DatabaseConnection, timeout: Duration) -> ServiceResult<usize> {
+    use notifications_outbox::Column;
+
+    let cutoff = Utc::now() - ChronoDuration::seconds(timeout.as_secs() as i64);
+    let txn = conn.begin().await?;
+    let rows = notifications_outbox::Entity::find()
+        .filter(Column::Status.eq(STATUS_PROCESSING))
+        .filter(Column::ProcessingStartedAt.lt(cutoff))
+        .limit(RECLAIM_BATCH)
+        .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+        .all(&txn)
+        .await?;
+
+    let error = DeliveryError::Retryable(format!(
+        "Delivery did not finish within {}s",
+        timeout.as_secs()
+    ));
+    for row in &rows {
+        record_failure(&txn, row, None, &error).await?;
+    }
+    txn.commit().await?;
+
+    Ok(rows.len())
+}
+
+async fn deliver(
+    conn: &DatabaseConnection,
+    channels: &ChannelRegistry,
+    notification: notifications_outbox::Model,
+    claimed_at: DateTime<Utc>,
+) -> ServiceResult<()> {
+    let result = match channels.get(&notification.channel) {
+        Some(channel) => channel.deliver(&notification).await,
+        None => Err(DeliveryError::Permanent(format!(
+            "No notification channel '{}'",
+            notification.channel
+        ))),
+    };
+
+    match result {
+        Ok(()) => record_success(conn, &notification, claimed_at).await,
+        Err(e) => record_failure(conn, &notification, Some(claimed_at), &e).await,
+    }
+}
+
+/// 알림 발송 백그라운드 태스크를 시작한다. `NOTIFICATION_DISPATCH_POLL_INTERVAL=0`이면 시작하지 않는다.
+/// 기본 채널에 더해 알림함(web)과 웹훅 구독 배달 채널을 등록한다.
+/// 웹훅 구독은 사용자가 정한 주소로 보내므로 `webhook_client`(내부망 차단)를 쓴다.
+pub fn spawn_notification_dispatcher(
+    conn: DatabaseConnection,
+    http_client: Client,
+    webhook_client: OutboundHttpClient,
+) {
+    let mut channels = ChannelRegistry::with_default_channels(http_client);
+    channels.register(InboxChannel::new(conn.clone()));
+    channels.register(SubscriptionWebhookChannel::new(
+        conn.clone(),
+        webhook_client,
+    ));
+    spawn_notification_dispatcher_with(conn, channels);
+}
+
+/// 채널 구성을 직접 지정해 발송 태스크를 시작한다.
+pub fn spawn_notification_dispatcher_with(conn: DatabaseConnection, channels: ChannelRegistry) {
+    let config = DbConfig::get();
+    if config.notification_dispatch_poll_interval == 0 {
+        info!("Notification dispatcher is disabled");
+        return;
+    }
+
+    let interval = Duration::from_secs(config.notification_dispatch_poll_interval);
+    let timeout = Duration::from_secs(config.notification_processing_timeout.max(1));
+    let batch = config.notification_dispatch_batch.max(1);
+    tokio::spawn(run_dispatcher(conn, channels, interval, timeout, batch));
+}
+
+async fn run_dispatcher(
+    conn: DatabaseConnection,
+    channels: ChannelRegistry,
+    interval: Duration,
+    timeout: Duration,
+    batch: u64,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        match reclaim_stuck(&conn, timeout).await {
+            Ok(0) => {}
+            Ok(count) => warn!("Rescheduled {} notification(s) stuck in processing", count),
+            Err(e) => error!("Failed to reclaim stuck notifications: {:?}", e),
+        }
+
+        // 쌓인 알림은 다음 주기를 기다리지 않고 이어서 보낸다
+        loop {
+            let (claimed, claimed_at) = match claim_due(&conn, batch).await {
+                Ok(claimed) => claimed,
+                Err(e) => {
+                    error!("Failed to claim due notifications: {:?}", e);
+                    break;
+                }
+            };
+            let full_batch = claimed.len() as u64 == batch;
+
+            let mut deliveries = JoinSet::new();
+            for notification in claimed {
+                let conn = conn.clone();
+                let channels = channels.clone();
+                deliveries.spawn(async move {
+                    let id = notification.id;
+                    if let Err(e) = deliver(&conn, &channels, notification, claimed_at).await {
+                        error!("Failed to record delivery of notification {}: {:?}", id, e);
+                    }
+                });
+            }
+            while deliveries.join_next().await.is_some() {}
+
+            if !full_batch {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! 사용자별 알림함.
+//!
+//! web 채널 outbox 행을 `InboxChannel`이 받는 사람마다 `user_notifications` 행으로 나눈다.
+//! 같은 트랜잭션에서 메일을 켠 사용자에게는 email outbox 행(즉시)이나
+//! `notification_digest_items` 행(요약)을 만든다.
+//! 새로 들어간 알림은 커밋한 뒤 `user:<user_id>` 실시간 토픽으로도 보낸다.
+
+use super::channel::{
+    CHANNEL_EMAIL, CHANNEL_WEB, DeliveryError, DeliveryFuture, NotificationChannel, escape_html,
+};
+use super::preference::{load_preferences, resolve};
+use super::subscription::{resource_scope, scope_subscribers, tenant_of_resource};
+use super::{CATEGORY_GENERAL, CreateNotificationParams, service_create_notification};
+use crate::config::db_config::DbConfig;
+use crate::dto::notification::response::{
+    InboxNotificationListResponse, InboxNotificationResponse, MarkAllReadResponse,
+    UnreadCountResponse,
+};
+use crate::entity::{notification_digest_items, notifications_outbox, user_notifications, users};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::realtime::{EVENT_NOTIFICATION_CREATED, publish_realtime_event, user_topic};
+use chrono::Utc;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    EntityTrait, IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait,
+};
+use serde_json::{Value, json};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+/// `user_notifications.resource_type` 최대 길이
+const RESOURCE_TYPE_MAX_LEN: usize = 30;
+
+/// 사용자에게 직접 보내는 알림 (팔로우, 댓글 답글, 좋아요 등)
+pub struct UserNotificationParams {
+    pub category: &'static str,
+    pub title: String,
+    pub message: String,
+    pub recipient_ids: Vec<Uuid>,
+    pub actor_id: Option<Uuid>,
+    pub link: Option<String>,
+    pub data: Value,
+}
+
+/// 받는 사람에게 보낼 web outbox 행을 만든다. 받는 사람이 행위자 본인뿐이면 만들지 않는다.
+///
+/// 호출한 트랜잭션 안에서 실행하면 본 작업이 롤백될 때 알림도 함께 사라진다.
+pub async fn enqueue_user_notification<C>(
+    conn: &C,
+    params: UserNotificationParams,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    let recipient_ids: Vec<Uuid> = params
+        .recipient_ids
+        .into_iter()
+        .filter(|id| Some(*id) != params.actor_id)
+        .collect();
+    if recipient_ids.is_empty() {
+        return Ok(());
+    }
+
+    service_create_notification(
+        conn,
+        CreateNotificationParams {
+            tenant_id: None,
+            channel: CHANNEL_WEB.to_string(),
+            category: Some(params.category.to_string()),
+            title: Some(params.title),
+            message: Some(params.message),
+            payload: Some(json!({
+                "recipient_ids": recipient_ids,
+                "actor_id": params.actor_id,
+                "link": params.link,
+                "data": params.data,
+            })),
+            scheduled_at: None,
+            max_retries: None,
+        },
+    )
+    .await
+    .map(|_| ())
+}
+
+fn payload_value<'a>(
+    notification: &'a notifications_outbox::Model,
+    key: &str,
+) -> Option<&'a Value> {
+    notification.payload.as_ref()?.get(key)
+}
+
+fn payload_str<'a>(notification: &'a notifications_outbox::Model, key: &str) -> Option<&'a str> {
+    payload_value(notification, key)?
+        .as_str()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+fn payload_uuid(notification: &notifications_outbox::Model, key: &str) -> Option<Uuid> {
+    payload_str(notification, key).and_then(|v| Uuid::parse_str(v).ok())
+}
+
+/// 메일에 넣을 링크. `NOTIFICATION_LINK_BASE_URL`이 없으면 상대 경로는 넣지 않는다.
+pub(super) fn absolute_link(link: &str) -> Option<String> {
+    if link.starts_with("http://") || link.starts_with("https://") {
+        return Some(link.to_string());
+    }
+    let base_url = DbConfig::get().notification_link_base_url.as_deref()?;
+    link.starts_with('/')
+        .then(|| format!("{}{}", base_url, link))
+}
+
+/// 알림 한 건을 메일 본문 조각으로 만든다
+pub(super) fn email_html(title: Option<&str>, message: Option<&str>, link: Option<&str>) -> String {
+    let mut html = String::new();
+    if let Some(title) = title {
+        html.push_str(&format!("<p><strong>{}</strong></p>", escape_html(title)));
+    }
+    if let Some(message) = message {
+        html.push_str(&format!("<p>{}</p>", escape_html(message)));
+    }
+    if let Some(link) = link.and_then(absolute_link) {
+        html.push_str(&format!(
+            "<p><a href=\"{}\">바로 가기</a></p>",
+            escape_html(&link)
+        ));
+    }
+    html
+}
+
+/// outbox 행의 받는 사람: 직접 지정한 사용자 + 리소스 테넌트의 구독자·담당자 - 행위자
+async fn recipients<C>(
+    conn: &C,
+    notification: &notifications_outbox::Model,
+) -> ServiceResult<BTreeSet<Uuid>>
+where
+    C: ConnectionTrait,
+{
+    let mut recipients: BTreeSet<Uuid> = payload_value(notification, "recipient_ids")
+        .and_then(Value::as_array)
+        .map(|ids| {
+            ids.iter()
+                .filter_map(Value::as_str)
+                .filter_map(|id| Uuid::parse_str(id).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let (Some(resource_type), Some(resource_id)) = (
+        payload_str(notification, "resource_type"),
+        payload_uuid(notification, "resource_id"),
+    ) && let Some(tenant_id) = tenant_of_resource(conn, resource_type, resource_id)
+        .await?
+        .tenant_id()
+    {
+        let scope = resource_scope(conn, resource_type, resource_id).await?;
+        recipients.extend(scope_subscribers(conn, tenant_id, &scope).await?);
+    }
+
+    if let Some(actor_id) = payload_uuid(notification, "actor_id") {
+        recipients.remove(&actor_id);
+    }
+    Ok(recipients)
+}
+
+/// outbox 행을 받는 사람별 알림함·메일·요약 항목으로 나눈다. 만든 알림함 행 수를 돌려준다.
+/// 다시 실행해도 알림함 행은 (outbox_id, user_id)로 한 번만 만들어진다.
+async fn fan_out(
+    conn: &DatabaseConnection,
+    notification: &notifications_outbox::Model,
+) -> ServiceResult<u64> {
+    let recipient_ids = recipients(conn, notification).await?;
+    if recipient_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let recipients: Vec<(Uuid, String)> = users::Entity::find()
+        .select_only()
+        .columns([users::Column::Id, users::Column::Email])
+        .filter(users::Column::Id.is_in(recipient_ids))
+        .into_tuple()
+        .all(conn)
+        .await?;
+    let user_ids: Vec<Uuid> = recipients.iter().map(|(id, _)| *id).collect();
+
+    let category = notification
+        .category
+        .as_deref()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .unwrap_or(CATEGORY_GENERAL)
+        .to_string();
+    let preferences = load_preferences(conn, &user_ids, &category).await?;
+
+    let link = payload_str(notification, "link").map(str::to_string);
+    let resource_type = payload_str(notification, "resource_type")
+        .filter(|t| t.len() <= RESOURCE_TYPE_MAX_LEN)
+        .map(str::to_string);
+    let resource_id = payload_uuid(notification, "resource_id");
+    let actor_id = payload_uuid(notification, "actor_id");
+    let now = Utc::now();
+
+    let txn = conn.begin().await?;
+    let mut inbox_rows = Vec::new();
+    for (user_id, email) in &recipients {
+        if resolve(&preferences, *user_id, CHANNEL_WEB).enabled {
+            inbox_rows.push(user_notifications::Model {
+                id: Uuid::new_v4(),
+                user_id: *user_id,
+                outbox_id: Some(notification.id),
+                category: category.clone(),
+                title: notification.title.clone(),
+                message: notification.message.clone(),
+                payload: notification.payload.clone(),
+                link: link.clone(),
+                resource_type: resource_type.clone(),
+                resource_id,
+                actor_id,
+                read_at: None,
+                created_at: now.into(),
+            });
+        }
+
+        let email_preference = resolve(&preferences, *user_id, CHANNEL_EMAIL);
+        if !email_preference.enabled {
+            continue;
+        }
+        if email_preference.is_digest() {
+            notification_digest_items::Entity::insert(notification_digest_items::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                user_id: Set(*user_id),
+                category: Set(category.clone()),
+                title: Set(notification.title.clone()),
+                message: Set(notification.message.clone()),
+                link: Set(link.clone()),
+                created_at: Set(now.into()),
+            })
+            .exec_without_returning(&txn)
+            .await?;
+        } else {
+            service_create_notification(
+                &txn,
+                CreateNotificationParams {
+                    tenant_id: notification.tenant_id,
+                    channel: CHANNEL_EMAIL.to_string(),
+                    category: Some(category.clone()),
+                    title: notification.title.clone(),
+                    message: notification.message.clone(),
+                    payload: Some(json!({
+                        "email_to": email,
+                        "html_content": email_html(
+                            notification.title.as_deref(),
+                            notification.message.as_deref(),
+                            link.as_deref(),
+                        ),
+                    })),
+                    scheduled_at: None,
+                    max_retries: None,
+                },
+            )
+            .await?;
+        }
+    }
+
+    let inserted = if inbox_rows.is_empty() {
+        0
+    } else {
+        user_notifications::Entity::insert_many(
+            inbox_rows
+                .iter()
+                .map(|row| row.clone().into_active_model().reset_all()),
+        )
+        .on_conflict(
+            OnConflict::columns([
+                user_notifications::Column::OutboxId,
+                user_notifications::Column::UserId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(&txn)
+        .await?
+    };
+    txn.commit().await?;
+
+    // 이미 나눠 둔 outbox 행을 다시 전달한 경우에는 새 알림이 아니다
+    if inserted > 0 {
+        for row in inbox_rows {
+            let topic = user_topic(row.user_id);
+            let data = serde_json::to_value(to_response(row)).unwrap_or_default();
+            publish_realtime_event(&topic, EVENT_NOTIFICATION_CREATED, data).await;
+        }
+    }
+
+    Ok(inserted)
+}
+
+/// web 채널. outbox 행을 받는 사람별 알림함으로 나눈다.
+pub struct InboxChannel {
+    conn: DatabaseConnection,
+}
+
+impl InboxChannel {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+}
+
+impl NotificationChannel for InboxChannel {
+    fn name(&self) -> &'static str {
+        CHANNEL_WEB
+    }
+
+    fn deliver<'a>(&'a self, notification: &'a notifications_outbox::Model) -> DeliveryFuture<'a> {
+        Box::pin(async move {
+            fan_out(&self.conn, notification)
+                .await
+                .map(|_| ())
+                .map_err(|e| DeliveryError::Retryable(format!("Inbox fan-out failed: {:?}", e)))
+        })
+    }
+}
+
+fn to_response(model: user_notifications::Model) -> InboxNotificationResponse {
+    InboxNotificationResponse {
+        id: model.id,
+        category: model.category,
+        title: model.title,
+        message: model.message,
+        payload: model.payload,
+        link: model.link,
+        resource_type: model.resource_type,
+        resource_id: model.resource_id,
+        actor_id: model.actor_id,
+        is_read: model.read_at.is_some(),
+        read_at: model.read_at.map(Into::into),
+        created_at: model.created_at.into(),
+    }
+}
+
+async fn count_unread(conn: &DatabaseConnection, user_id: Uuid) -> ServiceResult<u64> {
+    Ok(user_notifications::Entity::find()
+        .filter(user_notifications::Column::UserId.eq(user_id))
+        .filter(user_notifications::Column::ReadAt.is_null())
+        .count(conn)
+        .await?)
+}
+
+/// 내 알림함을 최신순으로 조회한다
+pub async fn service_get_inbox(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    page: u64,
+    limit: u64,
+    unread_only: bool,
+    category: Option<String>,
+) -> ServiceResult<InboxNotificationListResponse> {
+    let page = page.max(1);
+    let limit = limit.clamp(1, 100);
+
+    let mut query =
+        user_notifications::Entity::find().filter(user_notifications::Column::UserId.eq(user_id));
+    if unread_only {
+        query = query.filter(user_notifications::Column::ReadAt.is_null());
+    }
+    if let Some(category) = category.filter(|c| !c.trim().is_empty()) {
+        query = query.filter(user_notifications::Column::Category.eq(category.trim()));
+    }
+
+    let paginator = query
+        .order_by_desc(user_notifications::Column::CreatedAt)
+        .paginate(conn, limit);
+    let total = paginator.num_items().await?;
+    let notifications = paginator.fetch_page(page - 1).await?;
+
+    Ok(InboxNotificationListResponse {
+        notifications: notifications.into_iter().map(to_response).collect(),
+        total,
+        unread_count: count_unread(conn, user_id).await?,
+        page,
+        limit,
+    })
+}
+
+pub async fn service_get_unread_count(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+) -> ServiceResult<UnreadCountResponse> {
+    Ok(UnreadCountResponse {
+        unread_count: count_unread(conn, user_id).await?,
+    })
+}
+
+/// 알림 하나를 읽음으로 표시한다. 이미 읽은 알림은 처음 읽은 시각을 유지한다.
+pub async fn service_mark_read(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    id: Uuid,
+) -> ServiceResult<InboxNotificationResponse> {
+    user_notifications::Entity::update_many()
+        .col_expr(user_notifications::Column::ReadAt, Expr::value(Utc::now()))
+        .filter(user_notifications::Column::Id.eq(id))
+        .filter(user_notifications::Column::UserId.eq(user_id))
+        .filter(user_notifications::Column::ReadAt.is_null())
+        .exec(conn)
+        .await?;
+
+    user_notifications::Entity::find_by_id(id)
+        .filter(user_notifications::Column::UserId.eq(user_id))
+        .one(conn)
+        .await?
+        .map(to_response)
+        .ok_or_else(|| Errors::NotFound("Notification not found".to_string()))
+}
+
+/// 안 읽은 알림을 모두 읽음으로 표시한다. 분류를 주면 그 분류만 처리한다.
+pub async fn service_mark_all_read(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    category: Option<String>,
+) -> ServiceResult<MarkAllReadResponse> {
+    let mut update = user_notifications::Entity::update_many()
+        .col_expr(user_notifications::Column::ReadAt, Expr::value(Utc::now()))
+        .filter(user_notifications::Column::UserId.eq(user_id))
+        .filter(user_notifications::Column::ReadAt.is_null());
+    if let Some(category) = category.filter(|c| !c.trim().is_empty()) {
+        update = update.filter(user_notifications::Column::Category.eq(category.trim()));
+    }
+
+    let result = update.exec(conn).await?;
+    Ok(MarkAllReadResponse {
+        updated: result.rows_affected,
+    })
+}
@@ -1,3 +1,20 @@
+//! 알림 outbox. 알림을 `notifications_outbox`에 쌓고 `dispatcher`가 채널별로 발송한다.
+//!
+//! web 채널 알림은 `inbox`가 받는 사람마다 `user_notifications` 행으로 나눠 넣는다.
+//! 받는 사람은 payload의 다음 키로 정한다.
+//!
+//! - `recipient_ids`: 직접 받을 사용자 ID 배열
+//! - `resource_type`, `resource_id`: 이 리소스(와 상위 리소스)를 구독했거나 담당자로 매핑된 사용자
+//! - `actor_id`: 알림을 일으킨 사용자. 본인에게는 보내지 않는다.
+//! - `link`: 알림을 눌렀을 때 이동할 경로
+
+pub mod channel;
+pub mod digest;
+pub mod dispatcher;
+pub mod inbox;
+pub mod preference;
+pub mod subscription;
+
 use crate::{
     entity::notifications_outbox,
     service::error::errors::{Errors, ServiceResult},
@@ -5,8 +22,8 @@ use crate::{
 use chrono::{DateTime, Utc};
 use sea_orm::PaginatorTrait;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
-    QueryOrder,
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    EntityTrait, QueryFilter, QueryOrder,
 };
 use uuid::Uuid;
 
@@ -16,6 +33,29 @@ pub const STATUS_PROCESSING: &str = "processing";
 pub const STATUS_DONE: &str = "done";
 pub const STATUS_FAILED: &str = "failed";
 
+/// 알림 분류
+pub const CATEGORY_GENERAL: &str = "general";
+pub const CATEGORY_RACK_CREATED: &str = "rack_created";
+pub const CATEGORY_RACK_UPDATED: &str = "rack_updated";
+pub const CATEGORY_RACK_POWER_BUDGET_EXCEEDED: &str = "rack_power_budget_exceeded";
+pub const CATEGORY_IP_RANGE_UPDATED: &str = "ip_range_updated";
+pub const CATEGORY_DHCP_LEASE_EXPIRED: &str = "dhcp_lease_expired";
+pub const CATEGORY_NEW_FOLLOWER: &str = "new_follower";
+pub const CATEGORY_COMMENT_REPLY: &str = "comment_reply";
+pub const CATEGORY_POST_LIKE: &str = "post_like";
+
+pub const CATEGORIES: [&str; 9] = [
+    CATEGORY_GENERAL,
+    CATEGORY_RACK_CREATED,
+    CATEGORY_RACK_UPDATED,
+    CATEGORY_RACK_POWER_BUDGET_EXCEEDED,
+    CATEGORY_IP_RANGE_UPDATED,
+    CATEGORY_DHCP_LEASE_EXPIRED,
+    CATEGORY_NEW_FOLLOWER,
+    CATEGORY_COMMENT_REPLY,
+    CATEGORY_POST_LIKE,
+];
+
 /// 알림 생성시 필요한 데이터
 pub struct CreateNotificationParams {
     pub tenant_id: Option<Uuid>,
@@ -37,10 +77,13 @@ pub struct NotificationListResult {
 }
 
 /// 알림을 생성하고 큐에 적재
-pub async fn service_create_notification(
-    conn: &DatabaseConnection,
+pub async fn service_create_notification<C>(
+    conn: &C,
     params: CreateNotificationParams,
-) -> ServiceResult<notifications_outbox::Model> {
+) -> ServiceResult<notifications_outbox::Model>
+where
+    C: ConnectionTrait,
+{
     if params.channel.trim().is_empty() {
         return Err(Errors::BadRequestError(
             "channel must not be empty".to_string(),
@@ -0,0 +1,222 @@
+//! 사용자별 알림 설정. 분류 × 채널(web, email)마다 받을지 정하고, 메일은 바로 보낼지
+//! 모아서(digest) 보낼지 고른다. 저장한 값이 없으면 인앱 알림만 받는다.
+
+use super::CATEGORIES;
+use super::channel::{CHANNEL_EMAIL, CHANNEL_WEB};
+use crate::dto::notification::request::UpdateNotificationPreferencesRequest;
+use crate::dto::notification::response::{
+    NotificationPreferenceListResponse, NotificationPreferenceResponse,
+};
+use crate::entity::notification_preferences;
+use crate::service::error::errors::{Errors, ServiceResult};
+use chrono::Utc;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    TransactionTrait,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 전달 방식
+pub const MODE_IMMEDIATE: &str = "immediate";
+pub const MODE_DIGEST: &str = "digest";
+
+/// 설정할 수 있는 채널
+pub const PREFERENCE_CHANNELS: [&str; 2] = [CHANNEL_WEB, CHANNEL_EMAIL];
+
+/// 한 사용자·분류·채널에 적용되는 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectivePreference {
+    pub enabled: bool,
+    pub delivery_mode: String,
+}
+
+impl EffectivePreference {
+    /// 저장한 설정이 없을 때: 인앱 알림은 받고 메일은 받지 않는다
+    fn default_for(channel: &str) -> Self {
+        Self {
+            enabled: channel == CHANNEL_WEB,
+            delivery_mode: MODE_IMMEDIATE.to_string(),
+        }
+    }
+
+    pub fn is_digest(&self) -> bool {
+        self.delivery_mode == MODE_DIGEST
+    }
+}
+
+/// 여러 사용자의 한 분류 설정을 한 번에 읽는다. 키는 (사용자, 채널)
+pub(super) async fn load_preferences<C>(
+    conn: &C,
+    user_ids: &[Uuid],
+    category: &str,
+) -> ServiceResult<HashMap<(Uuid, String), EffectivePreference>>
+where
+    C: ConnectionTrait,
+{
+    if user_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let rows = notification_preferences::Entity::find()
+        .filter(notification_preferences::Column::UserId.is_in(user_ids.iter().copied()))
+        .filter(notification_preferences::Column::Category.eq(category))
+        .all(conn)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            (
+                (row.user_id, row.channel),
+                EffectivePreference {
+                    enabled: row.enabled,
+                    delivery_mode: row.delivery_mode,
+                },
+            )
+        })
+        .collect())
+}
+
+/// 불러온 설정에서 사용자·채널 값을 찾고, 없으면 기본값을 쓴다
+pub(super) fn resolve(
+    preferences: &HashMap<(Uuid, String), EffectivePreference>,
+    user_id: Uuid,
+    channel: &str,
+) -> EffectivePreference {
+    preferences
+        .get(&(user_id, channel.to_string()))
+        .cloned()
+        .unwrap_or_else(|| EffectivePreference::default_for(channel))
+}
+
+fn validate_preference(category: &str, channel: &str, delivery_mode: &str) -> ServiceResult<()> {
+    if !CATEGORIES.contains(&category) {
+        return Err(Errors::ValidationError(format!(
+            "Unknown notification category '{}'. Expected one of: {}",
+            category,
+            CATEGORIES.join(", ")
+        )));
+    }
+    if !PREFERENCE_CHANNELS.contains(&channel) {
+        return Err(Errors::ValidationError(format!(
+            "Unknown notification channel '{}'. Expected one of: {}",
+            channel,
+            PREFERENCE_CHANNELS.join(", ")
+        )));
+    }
+    match delivery_mode {
+        MODE_IMMEDIATE => Ok(()),
+        MODE_DIGEST if channel == CHANNEL_EMAIL => Ok(()),
+        MODE_DIGEST => Err(Errors::ValidationError(
+            "digest delivery is only available for the email channel".to_string(),
+        )),
+        other => Err(Errors::ValidationError(format!(
+            "Unknown delivery mode '{}'. Expected one of: {}, {}",
+            other, MODE_IMMEDIATE, MODE_DIGEST
+        ))),
+    }
+}
+
+/// 모든 분류 × 채널의 설정을 돌려준다. 저장하지 않은 조합은 기본값으로 채운다.
+pub async fn service_get_preferences(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+) -> ServiceResult<NotificationPreferenceListResponse> {
+    let stored: HashMap<(String, String), notification_preferences::Model> =
+        notification_preferences::Entity::find()
+            .filter(notification_preferences::Column::UserId.eq(user_id))
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|row| ((row.category.clone(), row.channel.clone()), row))
+            .collect();
+
+    let mut preferences = Vec::with_capacity(CATEGORIES.len() * PREFERENCE_CHANNELS.len());
+    for category in CATEGORIES {
+        for channel in PREFERENCE_CHANNELS {
+            let response = match stored.get(&(category.to_string(), channel.to_string())) {
+                Some(row) => NotificationPreferenceResponse {
+                    category: category.to_string(),
+                    channel: channel.to_string(),
+                    enabled: row.enabled,
+                    delivery_mode: row.delivery_mode.clone(),
+                    is_default: false,
+                },
+                None => {
+                    let default = EffectivePreference::default_for(channel);
+                    NotificationPreferenceResponse {
+                        category: category.to_string(),
+                        channel: channel.to_string(),
+                        enabled: default.enabled,
+                        delivery_mode: default.delivery_mode,
+                        is_default: true,
+                    }
+                }
+            };
+            preferences.push(response);
+        }
+    }
+
+    Ok(NotificationPreferenceListResponse { preferences })
+}
+
+/// 보낸 항목만 저장한다. 하나라도 잘못되면 아무것도 바꾸지 않는다.
+pub async fn service_update_preferences(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    request: UpdateNotificationPreferencesRequest,
+) -> ServiceResult<NotificationPreferenceListResponse> {
+    if request.preferences.is_empty() {
+        return Err(Errors::ValidationError(
+            "preferences must contain at least one item".to_string(),
+        ));
+    }
+
+    let now = Utc::now();
+    let mut rows = Vec::with_capacity(request.preferences.len());
+    for item in request.preferences {
+        let category = item.category.trim().to_string();
+        let channel = item.channel.trim().to_string();
+        let delivery_mode = item
+            .delivery_mode
+            .map(|mode| mode.trim().to_string())
+            .unwrap_or_else(|| MODE_IMMEDIATE.to_string());
+        validate_preference(&category, &channel, &delivery_mode)?;
+
+        rows.push(notification_preferences::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set(user_id),
+            category: Set(category),
+            channel: Set(channel),
+            enabled: Set(item.enabled),
+            delivery_mode: Set(delivery_mode),
+            created_at: Set(now.into()),
+            updated_at: Set(now.into()),
+        });
+    }
+
+    let txn = conn.begin().await?;
+    for row in rows {
+        notification_preferences::Entity::insert(row)
+            .on_conflict(
+                OnConflict::columns([
+                    notification_preferences::Column::UserId,
+                    notification_preferences::Column::Category,
+                    notification_preferences::Column::Channel,
+                ])
+                .update_columns([
+                    notification_preferences::Column::Enabled,
+                    notification_preferences::Column::DeliveryMode,
+                    notification_preferences::Column::UpdatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec_without_returning(&txn)
+            .await?;
+    }
+    txn.commit().await?;
+
+    service_get_preferences(conn, user_id).await
+}
@@ -0,0 +1,353 @@
+//! 리소스 알림 구독. 사무실·서버실·랙·장비·IP 대역을 구독하면 그 리소스와 하위 리소스의
+//! 알림을 받는다. `contact_resource_mappings`로 담당자에 매핑된 사용자(연락처 메일이 같은
+//! 계정)도 구독한 것으로 본다.
+//!
+//! 구독은 요청한 테넌트와 사무실 범위 안의 리소스만 만들 수 있고, 알림은 리소스가 속한 테넌트의
+//! 구성원에게만 간다.
+
+use crate::dto::notification::request::CreateResourceSubscriptionRequest;
+use crate::dto::notification::response::{
+    ResourceSubscriptionListResponse, ResourceSubscriptionResponse,
+};
+use crate::entity::contact_resource_mappings::{self, ResourceType};
+use crate::entity::{
+    contacts, devices, ip_ranges, notification_resource_subscriptions, office, racks, server_rooms,
+    tenant_memberships, users,
+};
+use crate::service::audit::{
+    RESOURCE_DEVICE, RESOURCE_IP_RANGE, RESOURCE_OFFICE, RESOURCE_RACK, RESOURCE_SERVER_ROOM,
+};
+use crate::service::auth::permission::{
+    AccessScope, OfficeBinding, PermissionAction, PermissionResource, office_of_device,
+    office_of_ip_range, office_of_rack, office_of_server_room,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::tenant::{
+    TenantOwner, tenant_of_device, tenant_of_ip_range, tenant_of_office, tenant_of_rack,
+    tenant_of_server_room,
+};
+use chrono::Utc;
+use sea_orm::sea_query::{Expr, Func};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait,
+    DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+/// 구독할 수 있는 리소스 종류
+pub const SUBSCRIBABLE_RESOURCES: [&str; 5] = [
+    RESOURCE_OFFICE,
+    RESOURCE_SERVER_ROOM,
+    RESOURCE_RACK,
+    RESOURCE_DEVICE,
+    RESOURCE_IP_RANGE,
+];
+
+fn mapping_type(resource_type: &str) -> Option<ResourceType> {
+    match resource_type {
+        RESOURCE_OFFICE => Some(ResourceType::Office),
+        RESOURCE_SERVER_ROOM => Some(ResourceType::ServerRoom),
+        RESOURCE_RACK => Some(ResourceType::Rack),
+        RESOURCE_DEVICE => Some(ResourceType::Device),
+        RESOURCE_IP_RANGE => Some(ResourceType::IpRange),
+        _ => None,
+    }
+}
+
+/// 리소스와 그 상위 리소스 목록 (장비 → 랙 → 서버실 → 사무실).
+/// 없는 리소스나 구독할 수 없는 종류면 빈 목록이다.
+pub(super) async fn resource_scope<C>(
+    conn: &C,
+    resource_type: &str,
+    resource_id: Uuid,
+) -> ServiceResult<Vec<(&'static str, Uuid)>>
+where
+    C: ConnectionTrait,
+{
+    let mut scope = Vec::new();
+    let mut rack_id = None;
+    let mut server_room_id = None;
+    let mut office_id = None;
+
+    match resource_type {
+        RESOURCE_DEVICE => {
+            if let Some(device) = devices::Entity::find_by_id(resource_id).one(conn).await? {
+                scope.push((RESOURCE_DEVICE, device.id));
+                rack_id = device.rack_id;
+            }
+        }
+        RESOURCE_RACK => rack_id = Some(resource_id),
+        RESOURCE_SERVER_ROOM => server_room_id = Some(resource_id),
+        RESOURCE_OFFICE => office_id = Some(resource_id),
+        RESOURCE_IP_RANGE => {
+            let ip_range = ip_ranges::Entity::find_by_id(resource_id).one(conn).await?;
+            scope.extend(ip_range.map(|ip_range| (RESOURCE_IP_RANGE, ip_range.id)));
+        }
+        _ => {}
+    }
+
+    if let Some(id) = rack_id
+        && let Some(rack) = racks::Entity::find_by_id(id).one(conn).await?
+    {
+        scope.push((RESOURCE_RACK, rack.id));
+        server_room_id = Some(rack.server_room_id);
+    }
+    if let Some(id) = server_room_id
+        && let Some(room) = server_rooms::Entity::find_by_id(id).one(conn).await?
+    {
+        scope.push((RESOURCE_SERVER_ROOM, room.id));
+        office_id = Some(room.office_id);
+    }
+    if let Some(id) = office_id
+        && office::Entity::find_by_id(id).one(conn).await?.is_some()
+    {
+        scope.push((RESOURCE_OFFICE, id));
+    }
+
+    Ok(scope)
+}
+
+/// 구독할 수 있는 리소스가 속한 테넌트
+pub(super) async fn tenant_of_resource<C>(
+    conn: &C,
+    resource_type: &str,
+    resource_id: Uuid,
+) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    match resource_type {
+        RESOURCE_OFFICE => tenant_of_office(conn, resource_id).await,
+        RESOURCE_SERVER_ROOM => tenant_of_server_room(conn, resource_id).await,
+        RESOURCE_RACK => tenant_of_rack(conn, resource_id).await,
+        RESOURCE_DEVICE => tenant_of_device(conn, resource_id).await,
+        RESOURCE_IP_RANGE => tenant_of_ip_range(conn, resource_id).await,
+        _ => Ok(TenantOwner::Missing),
+    }
+}
+
+/// 구독하려는 리소스가 테넌트와 사무실 범위 안인지 확인한다.
+/// 다른 테넌트의 리소스는 없는 것으로 본다.
+async fn require_resource_access<C>(
+    conn: &C,
+    scope: &AccessScope,
+    resource_type: &str,
+    resource_id: Uuid,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    let (resource, binding) = match resource_type {
+        RESOURCE_OFFICE => (
+            PermissionResource::Office,
+            Some(OfficeBinding::Office(resource_id)),
+        ),
+        RESOURCE_SERVER_ROOM => (
+            PermissionResource::ServerRoom,
+            office_of_server_room(conn, resource_id).await?,
+        ),
+        RESOURCE_RACK => (
+            PermissionResource::Rack,
+            office_of_rack(conn, resource_id).await?,
+        ),
+        RESOURCE_DEVICE => (
+            PermissionResource::Device,
+            office_of_device(conn, resource_id).await?,
+        ),
+        RESOURCE_IP_RANGE => (
+            PermissionResource::IpRange,
+            office_of_ip_range(conn, resource_id).await?,
+        ),
+        _ => {
+            return Err(Errors::ValidationError(format!(
+                "Unknown resource type '{}'",
+                resource_type
+            )));
+        }
+    };
+
+    scope.require_owner(
+        resource,
+        tenant_of_resource(conn, resource_type, resource_id).await?,
+    )?;
+    match binding {
+        Some(binding) => scope.require_binding(resource, PermissionAction::Read, binding),
+        None => Err(Errors::NotFound(format!("{} not found", resource))),
+    }
+}
+
+/// 범위 안의 리소스를 구독했거나 담당자로 매핑된 사용자 중 테넌트 구성원
+pub(super) async fn scope_subscribers<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    scope: &[(&'static str, Uuid)],
+) -> ServiceResult<BTreeSet<Uuid>>
+where
+    C: ConnectionTrait,
+{
+    let mut user_ids = BTreeSet::new();
+    if scope.is_empty() {
+        return Ok(user_ids);
+    }
+
+    let mut subscribed = Condition::any();
+    let mut mapped = Condition::any();
+    for (resource_type, resource_id) in scope {
+        subscribed = subscribed.add(
+            Condition::all()
+                .add(notification_resource_subscriptions::Column::ResourceType.eq(*resource_type))
+                .add(notification_resource_subscriptions::Column::ResourceId.eq(*resource_id)),
+        );
+        if let Some(mapping_type) = mapping_type(resource_type) {
+            mapped = mapped.add(
+                Condition::all()
+                    .add(contact_resource_mappings::Column::ResourceType.eq(mapping_type))
+                    .add(contact_resource_mappings::Column::ResourceId.eq(*resource_id)),
+            );
+        }
+    }
+
+    user_ids.extend(
+        notification_resource_subscriptions::Entity::find()
+            .filter(subscribed)
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|subscription| subscription.user_id),
+    );
+
+    // 같은 테넌트의 담당자 연락처와 메일이 같은 계정
+    let contact_ids: Vec<Uuid> = contact_resource_mappings::Entity::find()
+        .filter(mapped)
+        .all(conn)
+        .await?
+        .into_iter()
+        .map(|mapping| mapping.contact_id)
+        .collect();
+    let emails: Vec<String> = if contact_ids.is_empty() {
+        Vec::new()
+    } else {
+        contacts::Entity::find()
+            .filter(contacts::Column::Id.is_in(contact_ids))
+            .filter(contacts::Column::TenantId.eq(tenant_id))
+            .filter(contacts::Column::IsActive.eq(true))
+            .all(conn)
+            .await?
+            .into_iter()
+            .filter_map(|contact| contact.email)
+            .map(|email| email.trim().to_lowercase())
+            .filter(|email| !email.is_empty())
+            .collect()
+    };
+    if !emails.is_empty() {
+        let mapped_users: Vec<Uuid> = users::Entity::find()
+            .select_only()
+            .column(users::Column::Id)
+            .filter(Expr::expr(Func::lower(Expr::col(users::Column::Email))).is_in(emails))
+            .into_tuple()
+            .all(conn)
+            .await?;
+        user_ids.extend(mapped_users);
+    }
+    if user_ids.is_empty() {
+        return Ok(user_ids);
+    }
+
+    // 테넌트를 떠난 사용자의 구독은 남아 있어도 알림을 보내지 않는다
+    let members: Vec<Uuid> = tenant_memberships::Entity::find()
+        .select_only()
+        .column(tenant_memberships::Column::UserId)
+        .filter(tenant_memberships::Column::TenantId.eq(tenant_id))
+        .filter(tenant_memberships::Column::UserId.is_in(user_ids))
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    Ok(members.into_iter().collect())
+}
+
+fn to_response(model: notification_resource_subscriptions::Model) -> ResourceSubscriptionResponse {
+    ResourceSubscriptionResponse {
+        id: model.id,
+        resource_type: model.resource_type,
+        resource_id: model.resource_id,
+        created_at: model.created_at.into(),
+    }
+}
+
+pub async fn service_get_resource_subscriptions(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+) -> ServiceResult<ResourceSubscriptionListResponse> {
+    let subscriptions = notification_resource_subscriptions::Entity::find()
+        .filter(notification_resource_subscriptions::Column::UserId.eq(user_id))
+        .order_by_desc(notification_resource_subscriptions::Column::CreatedAt)
+        .all(conn)
+        .await?;
+
+    Ok(ResourceSubscriptionListResponse {
+        subscriptions: subscriptions.into_iter().map(to_response).collect(),
+    })
+}
+
+/// 리소스를 구독한다. 이미 구독 중이면 기존 구독을 돌려준다.
+pub async fn service_subscribe_resource(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    scope: &AccessScope,
+    request: CreateResourceSubscriptionRequest,
+) -> ServiceResult<ResourceSubscriptionResponse> {
+    let resource_type = SUBSCRIBABLE_RESOURCES
+        .into_iter()
+        .find(|t| *t == request.resource_type.trim())
+        .ok_or_else(|| {
+            Errors::ValidationError(format!(
+                "Unknown resource type '{}'. Expected one of: {}",
+                request.resource_type,
+                SUBSCRIBABLE_RESOURCES.join(", ")
+            ))
+        })?;
+
+    require_resource_access(conn, scope, resource_type, request.resource_id).await?;
+
+    if let Some(existing) = notification_resource_subscriptions::Entity::find()
+        .filter(notification_resource_subscriptions::Column::UserId.eq(user_id))
+        .filter(notification_resource_subscriptions::Column::ResourceType.eq(resource_type))
+        .filter(notification_resource_subscriptions::Column::ResourceId.eq(request.resource_id))
+        .one(conn)
+        .await?
+    {
+        return Ok(to_response(existing));
+    }
+
+    let created = notification_resource_subscriptions::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(user_id),
+        resource_type: Set(resource_type.to_string()),
+        resource_id: Set(request.resource_id),
+        created_at: Set(Utc::now().into()),
+    }
+    .insert(conn)
+    .await?;
+
+    Ok(to_response(created))
+}
+
+pub async fn service_unsubscribe_resource(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    id: Uuid,
+) -> ServiceResult<()> {
+    let result = notification_resource_subscriptions::Entity::delete_many()
+        .filter(notification_resource_subscriptions::Column::Id.eq(id))
+        .filter(notification_resource_subscriptions::Column::UserId.eq(user_id))
+        .exec(conn)
+        .await?;
+    if result.rows_affected == 0 {
+        return Err(Errors::NotFound(
+            "Notification subscription not found".to_string(),
+        ));
+    }
+    Ok(())
+}
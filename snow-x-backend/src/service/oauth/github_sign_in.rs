@@ -1,5 +1,6 @@
 use crate::connection::cloudflare_r2::R2Client;
 use crate::dto::auth::response::jwt::AuthJWTResponse;
+use crate::dto::auth::response::sign_in::SignInResponse;
 use crate::entity::common::{ActionType, OAuthProvider, TargetType};
 use crate::entity::user_refresh_tokens::ActiveModel as RefreshTokenActiveModel;
 use crate::repository::system_events::log_event::repository_log_event;
@@ -8,6 +9,7 @@ use crate::service::error::errors::{Errors, ServiceResult};
 use crate::service::oauth::find_or_create_oauth_user::service_find_or_create_oauth_user;
 use crate::service::oauth::oauth_avatar_upload::upload_oauth_avatar;
 use crate::service::oauth::provider::github::client::{exchange_github_code, get_github_user_info};
+use crate::service::two_factor::begin_two_factor_sign_in;
 use reqwest::Client as ReqwestClient;
 use sea_orm::{ActiveModelTrait, ConnectionTrait, Set, TransactionTrait};
 use tracing::{info, warn};
@@ -20,7 +22,7 @@ pub async fn service_github_sign_in<C>(
     ip_address: Option<String>,
     auth_code: &str,
     handle: Option<&str>,
-) -> ServiceResult<AuthJWTResponse>
+) -> ServiceResult<SignInResponse>
 where
     C: ConnectionTrait + TransactionTrait,
 {
@@ -76,6 +78,11 @@ where
         );
     }
 
+    // 제공자 인증과 별개로 2단계 인증 사용자는 코드를 확인한 뒤에 토큰을 받는다
+    if let Some(challenge) = begin_two_factor_sign_in(txn, &oauth_result.user).await? {
+        return Ok(SignInResponse::TwoFactorRequired(challenge));
+    }
+
     // 5. JWT 토큰 생성 (Google과 동일한 로직)
     let access_token = create_jwt_access_token(&oauth_result.user.id)
         .map_err(|e| Errors::TokenCreationError(e.to_string()))?;
@@ -116,8 +123,8 @@ where
     )
     .await;
 
-    Ok(AuthJWTResponse {
+    Ok(SignInResponse::Authenticated(AuthJWTResponse {
         access_token,
         cookie_refresh_token: refresh_token.token,
-    })
+    }))
 }
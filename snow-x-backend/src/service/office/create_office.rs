@@ -0,0 +1,50 @@
+use crate::dto::office::request::CreateOfficeRequest;
+use crate::entity::office;
+use crate::service::audit::{ACTION_CREATE, AuditEntry, RESOURCE_OFFICE, record_audit};
+use crate::service::error::errors::ServiceResult;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, Set, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_create_office(
+    conn: &DatabaseConnection,
+    tenant_id: Uuid,
+    request: CreateOfficeRequest,
+    created_by: Uuid,
+) -> ServiceResult<office::Model> {
+    let txn = conn.begin().await?;
+    let now = chrono::Utc::now();
+
+    let office = office::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        tenant_id: Set(Some(tenant_id)),
+        name: Set(request.name),
+        description: Set(request.description),
+        address: Set(request.address),
+        contact_person: Set(request.contact_person),
+        phone: Set(request.phone),
+        email: Set(request.email),
+        created_by: Set(created_by),
+        created_at: Set(now.into()),
+        updated_at: Set(now.into()),
+        is_active: Set(true),
+    }
+    .insert(&txn)
+    .await?;
+
+    record_audit(
+        &txn,
+        AuditEntry {
+            resource_type: RESOURCE_OFFICE,
+            resource_id: office.id,
+            resource_name: Some(&office.name),
+            action: ACTION_CREATE,
+            actor_id: Some(created_by),
+            before: None,
+            after: Some(&office),
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+    Ok(office)
+}
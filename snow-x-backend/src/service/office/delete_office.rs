@@ -0,0 +1,46 @@
+use crate::entity::office::{self, Entity as Office};
+use crate::service::audit::{ACTION_DELETE, AuditEntry, RESOURCE_OFFICE, record_audit};
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect, Set,
+    TransactionTrait,
+};
+use uuid::Uuid;
+
+/// 사무실을 비활성화(soft delete)한다
+pub async fn service_delete_office(
+    conn: &DatabaseConnection,
+    office_id: Uuid,
+    deleted_by: Uuid,
+) -> ServiceResult<()> {
+    let txn = conn.begin().await?;
+
+    let before = Office::find_by_id(office_id)
+        .filter(office::Column::IsActive.eq(true))
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| Errors::NotFound("Office not found".to_string()))?;
+
+    let mut active: office::ActiveModel = before.clone().into();
+    active.is_active = Set(false);
+    active.updated_at = Set(chrono::Utc::now().into());
+    active.update(&txn).await?;
+
+    record_audit(
+        &txn,
+        AuditEntry {
+            resource_type: RESOURCE_OFFICE,
+            resource_id: before.id,
+            resource_name: Some(&before.name),
+            action: ACTION_DELETE,
+            actor_id: Some(deleted_by),
+            before: Some(&before),
+            after: None,
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod create_office;
+pub mod delete_office;
+pub mod update_office;
@@ -0,0 +1,65 @@
+use crate::dto::office::request::UpdateOfficeRequest;
+use crate::entity::office::{self, Entity as Office};
+use crate::service::audit::{ACTION_UPDATE, AuditEntry, RESOURCE_OFFICE, record_audit};
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect, Set,
+    TransactionTrait,
+};
+use uuid::Uuid;
+
+pub async fn service_update_office(
+    conn: &DatabaseConnection,
+    office_id: Uuid,
+    request: UpdateOfficeRequest,
+    updated_by: Uuid,
+) -> ServiceResult<office::Model> {
+    let txn = conn.begin().await?;
+
+    let before = Office::find_by_id(office_id)
+        .filter(office::Column::IsActive.eq(true))
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| Errors::NotFound("Office not found".to_string()))?;
+
+    let mut active: office::ActiveModel = before.clone().into();
+    if let Some(name) = request.name {
+        active.name = Set(name);
+    }
+    if let Some(description) = request.description {
+        active.description = Set(Some(description));
+    }
+    if let Some(address) = request.address {
+        active.address = Set(address);
+    }
+    if let Some(contact_person) = request.contact_person {
+        active.contact_person = Set(Some(contact_person));
+    }
+    if let Some(phone) = request.phone {
+        active.phone = Set(Some(phone));
+    }
+    if let Some(email) = request.email {
+        active.email = Set(Some(email));
+    }
+    active.updated_at = Set(chrono::Utc::now().into());
+
+    let office = active.update(&txn).await?;
+
+    record_audit(
+        &txn,
+        AuditEntry {
+            resource_type: RESOURCE_OFFICE,
+            resource_id: office.id,
+            resource_name: Some(&office.name),
+            action: ACTION_UPDATE,
+            actor_id: Some(updated_by),
+            before: Some(&before),
+            after: Some(&office),
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+    Ok(office)
+}
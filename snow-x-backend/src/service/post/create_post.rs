@@ -7,6 +7,9 @@ use crate::repository::hashtag::associate_post_hashtags::repository_associate_po
 use crate::repository::post::create_post::repository_create_post;
 use crate::repository::system_events::log_event::repository_log_event;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::webhook::{
+    EVENT_POST_CREATED, RESOURCE_POST, WebhookEvent, publish_webhook_event,
+};
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use serde_json::json;
 use tracing::{info, warn};
@@ -72,6 +75,29 @@ where
         Vec::new()
     };
 
+    publish_webhook_event(
+        &txn,
+        WebhookEvent {
+            event_type: EVENT_POST_CREATED,
+            resource_type: RESOURCE_POST,
+            resource_id: created_post.id,
+            resource_name: Some(&created_post.title),
+            actor_id: Some(*user_uuid),
+            // 블로그 글은 테넌트에 속하지 않아 모든 테넌트의 구독에 간다
+            tenant_id: None,
+            data: json!({
+                "post_id": created_post.id,
+                "user_id": created_post.user_id,
+                "title": created_post.title,
+                "slug": created_post.slug,
+                "summary": created_post.summary,
+                "hashtags": hashtags,
+                "created_at": created_post.created_at,
+            }),
+        },
+    )
+    .await?;
+
     // Commit the transaction
     txn.commit().await?;
 
@@ -0,0 +1,344 @@
+use crate::config::db_config::DbConfig;
+use crate::dto::rack::response::rack_capacity::CapacityRollupResponse;
+use crate::entity::{devices, office, racks, server_rooms};
+use crate::service::audit::RESOURCE_RACK;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::notification::channel::CHANNEL_WEB;
+use crate::service::notification::{self, CreateNotificationParams};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+};
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use tracing::warn;
+use uuid::Uuid;
+
+pub const SCOPE_RACK: &str = "rack";
+pub const SCOPE_SERVER_ROOM: &str = "server_room";
+pub const SCOPE_OFFICE: &str = "office";
+
+/// 장비 배치 후 랙 전력 사용량이 용량을 넘는 경우의 정보 (warn 모드에서 알림으로 남긴다)
+#[derive(Debug, Clone)]
+pub struct PowerBudgetOverage {
+    pub rack_id: Uuid,
+    pub rack_name: String,
+    pub device_name: String,
+    pub power_capacity: i64,
+    pub power_used: i64,
+}
+
+/// 랙 하나의 집계. 장비 목록은 해당 랙의 활성 장비여야 한다.
+pub(crate) fn rack_capacity(
+    rack: &racks::Model,
+    devices: &[devices::Model],
+) -> CapacityRollupResponse {
+    let used_units: i32 = devices.iter().map(|d| d.rack_size.max(1)).sum();
+    let power_used: i64 = devices
+        .iter()
+        .filter_map(|d| d.power_consumption)
+        .map(|w| w.max(0) as i64)
+        .sum();
+
+    let mut device_counts_by_type = BTreeMap::new();
+    for device in devices {
+        *device_counts_by_type
+            .entry(device.device_type.clone())
+            .or_insert(0) += 1;
+    }
+
+    let mut rollup = CapacityRollupResponse {
+        scope: SCOPE_RACK.to_string(),
+        id: rack.id,
+        name: rack.name.clone(),
+        rack_count: 1,
+        total_units: rack.rack_height.max(0),
+        used_units,
+        free_units: 0,
+        power_capacity: rack.power_capacity.map(|w| w.max(0) as i64).unwrap_or(0),
+        power_used,
+        power_available: 0,
+        power_usage_percentage: None,
+        unbudgeted_racks: if rack.power_capacity.is_some() { 0 } else { 1 },
+        device_count: devices.len() as i32,
+        device_counts_by_type,
+        children: Vec::new(),
+    };
+    finalize(&mut rollup);
+    rollup
+}
+
+/// 하위 집계를 합산해 상위 단위 집계를 만든다
+fn combine(
+    scope: &str,
+    id: Uuid,
+    name: String,
+    children: Vec<CapacityRollupResponse>,
+) -> CapacityRollupResponse {
+    let mut rollup = CapacityRollupResponse {
+        scope: scope.to_string(),
+        id,
+        name,
+        rack_count: 0,
+        total_units: 0,
+        used_units: 0,
+        free_units: 0,
+        power_capacity: 0,
+        power_used: 0,
+        power_available: 0,
+        power_usage_percentage: None,
+        unbudgeted_racks: 0,
+        device_count: 0,
+        device_counts_by_type: BTreeMap::new(),
+        children: Vec::new(),
+    };
+
+    for child in &children {
+        rollup.rack_count += child.rack_count;
+        rollup.total_units += child.total_units;
+        rollup.used_units += child.used_units;
+        rollup.power_capacity += child.power_capacity;
+        rollup.power_used += child.power_used;
+        rollup.unbudgeted_racks += child.unbudgeted_racks;
+        rollup.device_count += child.device_count;
+        for (device_type, count) in &child.device_counts_by_type {
+            *rollup
+                .device_counts_by_type
+                .entry(device_type.clone())
+                .or_insert(0) += count;
+        }
+    }
+    rollup.children = children;
+    finalize(&mut rollup);
+    rollup
+}
+
+fn finalize(rollup: &mut CapacityRollupResponse) {
+    rollup.free_units = (rollup.total_units - rollup.used_units).max(0);
+    rollup.power_available = rollup.power_capacity - rollup.power_used;
+    rollup.power_usage_percentage = (rollup.power_capacity > 0)
+        .then(|| rollup.power_used as f64 / rollup.power_capacity as f64 * 100.0);
+}
+
+/// 여러 랙의 집계를 장비 한 번의 조회로 계산한다
+async fn rack_capacities<C>(
+    conn: &C,
+    racks: &[racks::Model],
+) -> ServiceResult<Vec<CapacityRollupResponse>>
+where
+    C: ConnectionTrait,
+{
+    if racks.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rack_ids: Vec<Uuid> = racks.iter().map(|rack| rack.id).collect();
+    let all_devices = devices::Entity::find()
+        .filter(devices::Column::RackId.is_in(rack_ids))
+        .filter(devices::Column::IsActive.eq(true))
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    let mut by_rack: HashMap<Uuid, Vec<devices::Model>> = HashMap::new();
+    for device in all_devices {
+        if let Some(rack_id) = device.rack_id {
+            by_rack.entry(rack_id).or_default().push(device);
+        }
+    }
+
+    Ok(racks
+        .iter()
+        .map(|rack| {
+            rack_capacity(
+                rack,
+                by_rack.get(&rack.id).map(Vec::as_slice).unwrap_or(&[]),
+            )
+        })
+        .collect())
+}
+
+async fn server_room_capacity<C>(
+    conn: &C,
+    room: &server_rooms::Model,
+) -> ServiceResult<CapacityRollupResponse>
+where
+    C: ConnectionTrait,
+{
+    let room_racks = racks::Entity::find()
+        .filter(racks::Column::ServerRoomId.eq(room.id))
+        .filter(racks::Column::IsActive.eq(true))
+        .order_by_asc(racks::Column::Name)
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    let children = rack_capacities(conn, &room_racks).await?;
+    Ok(combine(
+        SCOPE_SERVER_ROOM,
+        room.id,
+        room.name.clone(),
+        children,
+    ))
+}
+
+pub async fn service_get_rack_capacity(
+    conn: &DatabaseConnection,
+    rack_id: Uuid,
+) -> ServiceResult<CapacityRollupResponse> {
+    let rack = racks::Entity::find_by_id(rack_id)
+        .filter(racks::Column::IsActive.eq(true))
+        .one(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?
+        .ok_or_else(|| Errors::NotFound("Rack not found".to_string()))?;
+
+    let mut rollups = rack_capacities(conn, std::slice::from_ref(&rack)).await?;
+    Ok(rollups.remove(0))
+}
+
+pub async fn service_get_server_room_capacity(
+    conn: &DatabaseConnection,
+    server_room_id: Uuid,
+) -> ServiceResult<CapacityRollupResponse> {
+    let room = server_rooms::Entity::find_by_id(server_room_id)
+        .filter(server_rooms::Column::IsActive.eq(true))
+        .one(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?
+        .ok_or(Errors::ServerRoomNotFound)?;
+
+    server_room_capacity(conn, &room).await
+}
+
+pub async fn service_get_office_capacity(
+    conn: &DatabaseConnection,
+    office_id: Uuid,
+) -> ServiceResult<CapacityRollupResponse> {
+    let office = office::Entity::find_by_id(office_id)
+        .filter(office::Column::IsActive.eq(true))
+        .one(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?
+        .ok_or_else(|| Errors::NotFound("Office not found".to_string()))?;
+
+    let rooms = server_rooms::Entity::find()
+        .filter(server_rooms::Column::OfficeId.eq(office.id))
+        .filter(server_rooms::Column::IsActive.eq(true))
+        .order_by_asc(server_rooms::Column::Name)
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    let mut children = Vec::with_capacity(rooms.len());
+    for room in &rooms {
+        children.push(server_room_capacity(conn, room).await?);
+    }
+
+    Ok(combine(SCOPE_OFFICE, office.id, office.name, children))
+}
+
+/// 장비를 랙에 넣었을 때 전력 예산을 넘는지 확인한다.
+/// RACK_POWER_BUDGET_MODE=reject면 오류를, 아니면 초과 정보를 돌려준다 (호출자가 커밋 후 알림).
+/// power_capacity가 없는 랙은 검사하지 않는다.
+pub(crate) async fn check_rack_power_budget<C>(
+    conn: &C,
+    rack: &racks::Model,
+    device_name: &str,
+    power_consumption: Option<i32>,
+    exclude_device: Option<&Uuid>,
+) -> ServiceResult<Option<PowerBudgetOverage>>
+where
+    C: ConnectionTrait,
+{
+    let (Some(capacity), Some(consumption)) = (rack.power_capacity, power_consumption) else {
+        return Ok(None);
+    };
+
+    let mut query = devices::Entity::find()
+        .filter(devices::Column::RackId.eq(rack.id))
+        .filter(devices::Column::IsActive.eq(true));
+    if let Some(device_id) = exclude_device {
+        query = query.filter(devices::Column::Id.ne(*device_id));
+    }
+    let others = query
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    let power_used: i64 = others
+        .iter()
+        .filter_map(|d| d.power_consumption)
+        .map(|w| w.max(0) as i64)
+        .sum::<i64>()
+        + consumption.max(0) as i64;
+    let power_capacity = capacity.max(0) as i64;
+
+    if power_used <= power_capacity {
+        return Ok(None);
+    }
+
+    if DbConfig::get().rack_power_budget_reject {
+        return Err(Errors::RackPowerBudgetExceeded(format!(
+            "Placing '{}' would draw {}W in rack '{}' (capacity {}W)",
+            device_name, power_used, rack.name, power_capacity
+        )));
+    }
+
+    Ok(Some(PowerBudgetOverage {
+        rack_id: rack.id,
+        rack_name: rack.name.clone(),
+        device_name: device_name.to_string(),
+        power_capacity,
+        power_used,
+    }))
+}
+
+/// warn 모드에서 전력 예산 초과를 알림으로 남긴다. 실패해도 장비 저장은 유지한다.
+pub(crate) async fn notify_power_budget_overage(
+    conn: &DatabaseConnection,
+    overage: PowerBudgetOverage,
+    actor_id: Option<Uuid>,
+) {
+    warn!(
+        rack_id = %overage.rack_id,
+        power_used = overage.power_used,
+        power_capacity = overage.power_capacity,
+        "rack power budget exceeded"
+    );
+
+    let payload = json!({
+        "rack_id": overage.rack_id,
+        "resource_type": RESOURCE_RACK,
+        "resource_id": overage.rack_id,
+        "device_name": overage.device_name,
+        "power_capacity": overage.power_capacity,
+        "power_used": overage.power_used,
+        "actor_id": actor_id,
+        "link": format!("/ipam/racks/{}", overage.rack_id),
+        "resource_name": overage.rack_name
+    });
+
+    if let Err(err) = notification::service_create_notification(
+        conn,
+        CreateNotificationParams {
+            tenant_id: None,
+            channel: CHANNEL_WEB.to_string(),
+            category: Some(notification::CATEGORY_RACK_POWER_BUDGET_EXCEEDED.to_string()),
+            title: Some(format!("랙 전력 용량 초과: {}", overage.rack_name)),
+            message: Some(format!(
+                "'{}' 배치 후 전력 사용량 {}W가 용량 {}W를 초과합니다.",
+                overage.device_name, overage.power_used, overage.power_capacity
+            )),
+            payload: Some(payload),
+            scheduled_at: None,
+            max_retries: None,
+        },
+    )
+    .await
+    {
+        warn!(
+            rack_id = %overage.rack_id,
+            "failed to enqueue power-budget notification: {err:?}"
+        );
+    }
+}
@@ -4,8 +4,12 @@ use crate::dto::rack::response::rack_info::RackInfoResponse;
 use crate::entity::server_rooms::{self, Entity as ServerRoom};
 use crate::entity::users;
 use crate::repository::rack::repository_create_rack;
+use crate::service::audit::{ACTION_CREATE, AuditEntry, RESOURCE_RACK, record_audit_or_warn};
 use crate::service::error::errors::Errors;
+use crate::service::notification::channel::CHANNEL_WEB;
 use crate::service::notification::{self, CreateNotificationParams};
+use crate::service::tenant::tenant_of_rack;
+use crate::service::webhook::{EVENT_RACK_CREATED, WebhookEvent, publish_webhook_event_or_warn};
 use chrono::Utc;
 use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, Set};
 use serde_json::json;
@@ -67,6 +71,20 @@ pub async fn service_create_rack(
     )
     .await?;
 
+    record_audit_or_warn(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_RACK,
+            resource_id: rack.id,
+            resource_name: Some(&rack.name),
+            action: ACTION_CREATE,
+            actor_id: Some(created_by),
+            before: None,
+            after: Some(&rack),
+        },
+    )
+    .await;
+
     let rack_info = build_rack_response(conn, rack).await?;
 
     if let Err(err) = enqueue_rack_created_notification(conn, &rack_info, created_by).await {
@@ -109,6 +127,8 @@ async fn enqueue_rack_created_notification(
 
     let payload = json!({
         "rack_id": rack.id,
+        "resource_type": RESOURCE_RACK,
+        "resource_id": rack.id,
         "server_room_id": rack.server_room_id,
         "server_room_name": server_room_label,
         "rack_height": rack.rack_height,
@@ -120,17 +140,33 @@ async fn enqueue_rack_created_notification(
         "link": format!("/ipam/racks/{}", rack.id)
     });
 
+    let owner = tenant_of_rack(conn, rack.id).await?;
+
+    publish_webhook_event_or_warn(
+        conn,
+        WebhookEvent {
+            event_type: EVENT_RACK_CREATED,
+            resource_type: RESOURCE_RACK,
+            resource_id: rack.id,
+            resource_name: Some(&rack.name),
+            actor_id: Some(created_by),
+            tenant_id: owner.tenant_id(),
+            data: payload.clone(),
+        },
+    )
+    .await;
+
     notification::service_create_notification(
         conn,
         CreateNotificationParams {
             tenant_id: None,
-            channel: "web".to_string(),
-            category: Some("rack_created".to_string()),
+            channel: CHANNEL_WEB.to_string(),
+            category: Some(notification::CATEGORY_RACK_CREATED.to_string()),
             title: Some(title),
             message: Some(message),
             payload: Some(payload),
             scheduled_at: None,
-            max_retries: Some(0),
+            max_retries: None,
         },
     )
     .await
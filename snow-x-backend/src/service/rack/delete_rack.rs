@@ -1,11 +1,32 @@
-use crate::repository::rack::repository_delete_rack;
+use crate::repository::rack::{repository_delete_rack, repository_get_rack_by_id};
+use crate::service::audit::{ACTION_DELETE, AuditEntry, RESOURCE_RACK, record_audit_or_warn};
 use crate::service::error::errors::Errors;
 use sea_orm::ConnectionTrait;
 use uuid::Uuid;
 
-pub async fn service_delete_rack<C>(conn: &C, rack_id: Uuid) -> Result<(), Errors>
+pub async fn service_delete_rack<C>(conn: &C, rack_id: Uuid, deleted_by: Uuid) -> Result<(), Errors>
 where
     C: ConnectionTrait,
 {
-    repository_delete_rack(conn, &rack_id).await
+    let before = repository_get_rack_by_id(conn, &rack_id).await?;
+
+    repository_delete_rack(conn, &rack_id).await?;
+
+    if let Some(before) = before {
+        record_audit_or_warn(
+            conn,
+            AuditEntry {
+                resource_type: RESOURCE_RACK,
+                resource_id: before.id,
+                resource_name: Some(&before.name),
+                action: ACTION_DELETE,
+                actor_id: Some(deleted_by),
+                before: Some(&before),
+                after: None,
+            },
+        )
+        .await;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,210 @@
+use crate::dto::rack::response::rack_elevation::{
+    RackElevationResponse, RackFreeGap, RackUnitSlot, RackUnplacedDevice,
+};
+use crate::entity::{devices, racks};
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use uuid::Uuid;
+
+/// 장비 배치 검증 동안 같은 랙에 대한 동시 배치를 막기 위해 랙 행을 잠근다.
+pub(crate) async fn lock_rack<C>(conn: &C, rack_id: &Uuid) -> ServiceResult<racks::Model>
+where
+    C: ConnectionTrait,
+{
+    racks::Entity::find_by_id(*rack_id)
+        .filter(racks::Column::IsActive.eq(true))
+        .lock_exclusive()
+        .one(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?
+        .ok_or_else(|| Errors::NotFound("Rack not found".to_string()))
+}
+
+/// 장비가 차지하는 U 범위 (rack_position이 가장 아래 U, 1부터 시작).
+/// 저장된 값이 비정상적으로 커도 넘치지 않도록 i32 끝에서 멈춘다.
+fn occupied_units(position: i32, size: i32) -> (i32, i32) {
+    (position, position.saturating_add(size.max(1) - 1))
+}
+
+/// `position`부터 `size`U가 랙 높이 안에 있고 다른 장비와 겹치지 않는지 확인한다.
+/// `exclude_device`는 자기 자신을 옮기는 경우 비교 대상에서 제외할 장비.
+pub(crate) async fn ensure_rack_slots_available<C>(
+    conn: &C,
+    rack: &racks::Model,
+    position: i32,
+    size: i32,
+    exclude_device: Option<&Uuid>,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    if size < 1 {
+        return Err(Errors::BadRequestError(
+            "rack_size must be at least 1U".to_string(),
+        ));
+    }
+    if position < 1 || position > rack.rack_height {
+        return Err(Errors::BadRequestError(format!(
+            "rack_position must be between 1 and {}",
+            rack.rack_height
+        )));
+    }
+
+    let start = position;
+    let end = position.checked_add(size - 1).ok_or_else(|| {
+        Errors::ValidationError(format!(
+            "rack_size {}U at U{} is out of range",
+            size, position
+        ))
+    })?;
+    if end > rack.rack_height {
+        return Err(Errors::BadRequestError(format!(
+            "U{}-U{} exceeds rack '{}' height of {}U",
+            start, end, rack.name, rack.rack_height
+        )));
+    }
+
+    let mut query = devices::Entity::find()
+        .filter(devices::Column::RackId.eq(rack.id))
+        .filter(devices::Column::IsActive.eq(true))
+        .filter(devices::Column::RackPosition.is_not_null());
+    if let Some(device_id) = exclude_device {
+        query = query.filter(devices::Column::Id.ne(*device_id));
+    }
+
+    let placed = query
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    for other in placed {
+        let Some(other_position) = other.rack_position else {
+            continue;
+        };
+        let (other_start, other_end) = occupied_units(other_position, other.rack_size);
+        if start <= other_end && other_start <= end {
+            return Err(Errors::RackSlotConflict(format!(
+                "U{}-U{} overlaps device '{}' (U{}-U{})",
+                start, end, other.name, other_start, other_end
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// 랙 높이를 줄일 때 가장 높은 곳에 배치된 장비의 윗단 U
+pub(crate) async fn highest_occupied_unit<C>(
+    conn: &C,
+    rack_id: &Uuid,
+) -> ServiceResult<Option<(i32, String)>>
+where
+    C: ConnectionTrait,
+{
+    let placed = devices::Entity::find()
+        .filter(devices::Column::RackId.eq(*rack_id))
+        .filter(devices::Column::IsActive.eq(true))
+        .filter(devices::Column::RackPosition.is_not_null())
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    Ok(placed
+        .into_iter()
+        .filter_map(|device| {
+            let position = device.rack_position?;
+            Some((occupied_units(position, device.rack_size).1, device.name))
+        })
+        .max_by_key(|(top, _)| *top))
+}
+
+pub async fn service_get_rack_elevation(
+    conn: &DatabaseConnection,
+    rack_id: Uuid,
+) -> ServiceResult<RackElevationResponse> {
+    let rack = racks::Entity::find_by_id(rack_id)
+        .filter(racks::Column::IsActive.eq(true))
+        .one(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?
+        .ok_or_else(|| Errors::NotFound("Rack not found".to_string()))?;
+
+    let devices = devices::Entity::find()
+        .filter(devices::Column::RackId.eq(rack.id))
+        .filter(devices::Column::IsActive.eq(true))
+        .order_by_asc(devices::Column::RackPosition)
+        .order_by_asc(devices::Column::Name)
+        .all(conn)
+        .await
+        .map_err(|e| Errors::DatabaseError(e.to_string()))?;
+
+    let height = rack.rack_height.max(0);
+    let mut slots: Vec<RackUnitSlot> = (1..=height)
+        .map(|unit| RackUnitSlot {
+            unit,
+            device_id: None,
+            device_name: None,
+            device_type: None,
+            conflicting_device_ids: Vec::new(),
+        })
+        .collect();
+    let mut unplaced = Vec::new();
+
+    for device in devices {
+        let Some(position) = device.rack_position else {
+            unplaced.push(RackUnplacedDevice {
+                id: device.id,
+                name: device.name,
+                device_type: device.device_type,
+                rack_size: device.rack_size,
+            });
+            continue;
+        };
+
+        // 검증 도입 이전에 저장된 데이터는 범위를 벗어나거나 겹칠 수 있으므로 잘라서 표시
+        let (start, end) = occupied_units(position, device.rack_size);
+        for unit in start.max(1)..=end.min(height) {
+            let slot = &mut slots[(unit - 1) as usize];
+            if slot.device_id.is_some() {
+                slot.conflicting_device_ids.push(device.id);
+            } else {
+                slot.device_id = Some(device.id);
+                slot.device_name = Some(device.name.clone());
+                slot.device_type = Some(device.device_type.clone());
+            }
+        }
+    }
+
+    let mut free_gaps: Vec<RackFreeGap> = Vec::new();
+    for slot in slots.iter().filter(|slot| slot.device_id.is_none()) {
+        match free_gaps.last_mut() {
+            Some(gap) if gap.end_unit + 1 == slot.unit => {
+                gap.end_unit = slot.unit;
+                gap.size += 1;
+            }
+            _ => free_gaps.push(RackFreeGap {
+                start_unit: slot.unit,
+                end_unit: slot.unit,
+                size: 1,
+            }),
+        }
+    }
+
+    let free_units: i32 = free_gaps.iter().map(|gap| gap.size).sum();
+    let largest_free_gap = free_gaps.iter().map(|gap| gap.size).max().unwrap_or(0);
+
+    Ok(RackElevationResponse {
+        rack_id: rack.id,
+        rack_name: rack.name,
+        rack_height: rack.rack_height,
+        used_units: height - free_units,
+        free_units,
+        largest_free_gap,
+        slots,
+        free_gaps,
+        unplaced_devices: unplaced,
+    })
+}
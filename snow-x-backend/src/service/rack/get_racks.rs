@@ -2,6 +2,7 @@ use super::mapper::build_rack_response;
 use crate::dto::rack::response::rack_info::RackInfoResponse;
 use crate::dto::rack::response::rack_list::RackListResponse;
 use crate::repository::rack::repository_get_racks;
+use crate::service::auth::permission::AccessScope;
 use crate::service::error::errors::Errors;
 use sea_orm::ConnectionTrait;
 
@@ -9,11 +10,12 @@ pub async fn service_get_racks<C>(
     conn: &C,
     page: u64,
     limit: u64,
+    scope: &AccessScope,
 ) -> Result<RackListResponse, Errors>
 where
     C: ConnectionTrait,
 {
-    let (racks, total) = repository_get_racks(conn, page, limit).await?;
+    let (racks, total) = repository_get_racks(conn, page, limit, scope).await?;
 
     let mut rack_responses: Vec<RackInfoResponse> = Vec::with_capacity(racks.len());
     for rack in racks {
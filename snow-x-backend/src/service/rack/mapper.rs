@@ -1,3 +1,4 @@
+use super::capacity::rack_capacity;
 use crate::dto::rack::response::rack_info::{RackDeviceSummary, RackInfoResponse};
 use crate::entity::{devices, office, racks::Model as RackModel, server_rooms};
 use crate::service::error::errors::Errors;
@@ -47,6 +48,8 @@ where
         "rack related entities loaded"
     );
 
+    let capacity = rack_capacity(&rack, &devices);
+
     let mut device_summaries = Vec::with_capacity(devices.len());
     let mut used_units = 0_i32;
 
@@ -68,6 +71,7 @@ where
             serial_number: device.serial_number,
             rack_position: device.rack_position,
             rack_size: sanitized_size,
+            power_consumption: device.power_consumption,
         });
     }
 
@@ -100,6 +104,11 @@ where
         device_count,
         usage_percentage,
         used_units,
+        free_units: capacity.free_units,
+        power_used: capacity.power_used,
+        power_available: rack.power_capacity.map(|_| capacity.power_available),
+        power_usage_percentage: capacity.power_usage_percentage,
+        device_counts_by_type: capacity.device_counts_by_type,
         devices: device_summaries,
     })
 }
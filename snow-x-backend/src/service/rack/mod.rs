@@ -1,12 +1,18 @@
+pub mod capacity;
 pub mod create_rack;
 pub mod delete_rack;
+pub mod elevation;
 pub mod get_rack_by_id;
 pub mod get_racks;
 mod mapper;
 pub mod update_rack;
 
+pub use capacity::{
+    service_get_office_capacity, service_get_rack_capacity, service_get_server_room_capacity,
+};
 pub use create_rack::service_create_rack;
 pub use delete_rack::service_delete_rack;
+pub use elevation::service_get_rack_elevation;
 pub use get_rack_by_id::service_get_rack_by_id;
 pub use get_racks::service_get_racks;
 pub use update_rack::service_update_rack;
@@ -1,9 +1,14 @@
+use super::elevation::highest_occupied_unit;
 use super::mapper::build_rack_response;
 use crate::dto::rack::request::update_rack::UpdateRackRequest;
 use crate::dto::rack::response::rack_info::RackInfoResponse;
 use crate::entity::{racks, users};
+use crate::service::audit::{ACTION_UPDATE, AuditEntry, RESOURCE_RACK, record_audit_or_warn};
 use crate::service::error::errors::Errors;
+use crate::service::notification::channel::CHANNEL_WEB;
 use crate::service::notification::{self, CreateNotificationParams};
+use crate::service::tenant::tenant_of_rack;
+use crate::service::webhook::{EVENT_RACK_UPDATED, WebhookEvent, publish_webhook_event_or_warn};
 use chrono::Utc;
 use sea_orm::prelude::Decimal;
 use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
@@ -36,6 +41,21 @@ pub async fn service_update_rack(
         has_change = true;
     }
     if let Some(height) = request.rack_height {
+        if height < 1 {
+            return Err(Errors::BadRequestError(
+                "rack_height must be at least 1U".to_string(),
+            ));
+        }
+        // 이미 배치된 장비가 잘려 나가는 높이로는 줄일 수 없음
+        if height < existing.rack_height
+            && let Some((top, device_name)) = highest_occupied_unit(conn, &rack_id).await?
+            && top > height
+        {
+            return Err(Errors::RackSlotConflict(format!(
+                "Cannot shrink rack to {}U: device '{}' occupies up to U{}",
+                height, device_name, top
+            )));
+        }
         model.rack_height = Set(height);
         has_change = true;
     }
@@ -75,6 +95,20 @@ pub async fn service_update_rack(
         .await
         .map_err(|e| Errors::DatabaseError(e.to_string()))?;
 
+    record_audit_or_warn(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_RACK,
+            resource_id: updated.id,
+            resource_name: Some(&updated.name),
+            action: ACTION_UPDATE,
+            actor_id: Some(updated_by),
+            before: Some(&existing),
+            after: Some(&updated),
+        },
+    )
+    .await;
+
     if let Err(err) = enqueue_rack_update_notification(conn, &existing, &updated, updated_by).await
     {
         warn!(
@@ -155,6 +189,8 @@ async fn enqueue_rack_update_notification(
 
     let payload = json!({
         "rack_id": after.id,
+        "resource_type": RESOURCE_RACK,
+        "resource_id": after.id,
         "server_room_id": after.server_room_id,
         "diff": diff,
         "actor_id": updated_by,
@@ -165,17 +201,33 @@ async fn enqueue_rack_update_notification(
         "resource_name": after.name
     });
 
+    let owner = tenant_of_rack(conn, after.id).await?;
+
+    publish_webhook_event_or_warn(
+        conn,
+        WebhookEvent {
+            event_type: EVENT_RACK_UPDATED,
+            resource_type: RESOURCE_RACK,
+            resource_id: after.id,
+            resource_name: Some(&after.name),
+            actor_id: Some(updated_by),
+            tenant_id: owner.tenant_id(),
+            data: payload.clone(),
+        },
+    )
+    .await;
+
     notification::service_create_notification(
         conn,
         CreateNotificationParams {
             tenant_id: None,
-            channel: "web".to_string(),
-            category: Some("rack_updated".to_string()),
+            channel: CHANNEL_WEB.to_string(),
+            category: Some(notification::CATEGORY_RACK_UPDATED.to_string()),
             title: Some(format!("랙 수정: {}", after.name)),
             message: Some("랙 정보가 업데이트되었습니다.".to_string()),
             payload: Some(payload),
             scheduled_at: None,
-            max_retries: Some(0),
+            max_retries: None,
         },
     )
     .await
@@ -0,0 +1,155 @@
+use crate::config::db_config::{DbConfig, RateLimitRule};
+use crate::service::error::errors::{Errors, ServiceResult};
+use redis::Script;
+use redis::aio::ConnectionManager;
+use std::sync::{LazyLock, OnceLock};
+use tracing::warn;
+
+/// 요청 제한에 쓰는 연결. 서버 시작 시 `init_rate_limiter`로 정한다 (미들웨어는 AppState를 받지 않는다).
+static LIMITER: OnceLock<ConnectionManager> = OnceLock::new();
+
+/// 고정 구간 카운터. 구간의 첫 요청에서 만료 시간을 정하고, 현재 횟수와 남은 초를 돌려준다
+static HIT_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+local count = redis.call('INCR', KEYS[1])
+if count == 1 then
+    redis.call('EXPIRE', KEYS[1], ARGV[1])
+end
+return {count, redis.call('TTL', KEYS[1])}
+",
+    )
+});
+
+pub fn init_rate_limiter(redis: ConnectionManager) {
+    if LIMITER.set(redis).is_err() {
+        warn!("Rate limiter is already initialized");
+    }
+}
+
+/// 요청 제한을 켰을 때만 연결을 돌려준다
+pub(super) fn limiter() -> Option<ConnectionManager> {
+    if !DbConfig::get().rate_limit_enabled {
+        return None;
+    }
+    LIMITER.get().cloned()
+}
+
+/// 요청 횟수를 제한하는 경로
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitRoute {
+    SignIn,
+    TwoFactor,
+    ForgotPassword,
+    ResendVerification,
+    CheckHandle,
+}
+
+impl RateLimitRoute {
+    pub fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "/v0/auth/sign_in" => Some(Self::SignIn),
+            "/v0/auth/sign_in/two_factor" | "/v0/auth/sign_in/two_factor/enroll" => {
+                Some(Self::TwoFactor)
+            }
+            "/v0/auth/forgot_password" => Some(Self::ForgotPassword),
+            "/v0/auth/resend_verification" => Some(Self::ResendVerification),
+            "/v0/user/check-handle" => Some(Self::CheckHandle),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SignIn => "sign_in",
+            Self::TwoFactor => "two_factor",
+            Self::ForgotPassword => "forgot_password",
+            Self::ResendVerification => "resend_verification",
+            Self::CheckHandle => "check_handle",
+        }
+    }
+
+    fn rule(&self) -> RateLimitRule {
+        let config = DbConfig::get();
+        match self {
+            Self::SignIn => config.rate_limit_sign_in,
+            Self::TwoFactor => config.rate_limit_two_factor,
+            Self::ForgotPassword => config.rate_limit_forgot_password,
+            Self::ResendVerification => config.rate_limit_resend_verification,
+            Self::CheckHandle => config.rate_limit_check_handle,
+        }
+    }
+
+    /// 계정별로도 세는지. 2단계 인증은 대기 토큰의 사용자(`sub`)로, 나머지는 요청 본문의
+    /// handle(email)로 센다. 핸들 중복 확인은 매번 다른 핸들을 보내므로 IP로만 센다.
+    pub fn keyed_by_account(&self) -> bool {
+        matches!(
+            self,
+            Self::SignIn | Self::TwoFactor | Self::ForgotPassword | Self::ResendVerification
+        )
+    }
+}
+
+/// 계정 식별자는 대소문자와 앞뒤 공백을 구분하지 않는다
+pub(super) fn normalize_account(account: &str) -> String {
+    account.trim().to_lowercase()
+}
+
+/// 클라이언트 IP와 계정별 요청 횟수를 세고, 한도를 넘었으면 `TooManyRequests`를 돌려준다.
+/// Redis 오류로 로그인을 막지 않도록 실패하면 경고만 남기고 통과시킨다.
+pub async fn check_rate_limit(
+    route: RateLimitRoute,
+    client_ip: &str,
+    account: Option<&str>,
+) -> ServiceResult<()> {
+    let rule = route.rule();
+    if rule.limit == 0 {
+        return Ok(());
+    }
+    let Some(mut redis) = limiter() else {
+        return Ok(());
+    };
+
+    let mut keys = vec![format!("rate_limit:{}:ip:{}", route.as_str(), client_ip)];
+    if let Some(account) = account.filter(|_| route.keyed_by_account()) {
+        keys.push(format!(
+            "rate_limit:{}:account:{}",
+            route.as_str(),
+            normalize_account(account)
+        ));
+    }
+
+    let mut retry_after = None;
+    for key in keys {
+        let (count, ttl): (u64, i64) = match HIT_SCRIPT
+            .key(&key)
+            .arg(rule.window)
+            .invoke_async(&mut redis)
+            .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                warn!("Rate limit check failed for {}: {}", key, e);
+                return Ok(());
+            }
+        };
+
+        if count > rule.limit {
+            let seconds = u64::try_from(ttl).unwrap_or(rule.window).max(1);
+            retry_after = retry_after.max(Some(seconds));
+        }
+    }
+
+    match retry_after {
+        Some(seconds) => {
+            warn!(
+                "Rate limit exceeded on {} from {} (retry after {}s)",
+                route.as_str(),
+                client_ip,
+                seconds
+            );
+            Err(Errors::TooManyRequests(seconds))
+        }
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,175 @@
+use super::limiter::{limiter, normalize_account};
+use crate::config::db_config::DbConfig;
+use crate::entity::common::{ActionType, TargetType};
+use crate::repository::system_events::log_event::repository_log_event;
+use crate::service::error::errors::{Errors, ServiceResult};
+use redis::{AsyncCommands, Script};
+use sea_orm::ConnectionTrait;
+use serde_json::json;
+use std::sync::LazyLock;
+use tracing::warn;
+use uuid::Uuid;
+
+/// 잠금 단계를 기억하는 시간. 이 시간 동안 다시 잠기지 않으면 첫 단계부터 시작한다
+const LOCKOUT_LEVEL_TTL: u64 = 86400;
+
+/// 실패 횟수를 세고, 한도에 닿으면 단계마다 두 배로 늘어나는 시간만큼 잠근다.
+/// 반환값: {잠금 단계, 잠금 초} (잠그지 않았으면 0)
+static FAILURE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+local failures = redis.call('INCR', KEYS[1])
+if failures == 1 then
+    redis.call('EXPIRE', KEYS[1], ARGV[1])
+end
+if failures < tonumber(ARGV[2]) then
+    return {0, 0}
+end
+redis.call('DEL', KEYS[1])
+local level = redis.call('INCR', KEYS[3])
+redis.call('EXPIRE', KEYS[3], ARGV[5])
+local duration = math.floor(math.min(tonumber(ARGV[3]) * 2 ^ (level - 1), tonumber(ARGV[4])))
+redis.call('SET', KEYS[2], level, 'EX', duration)
+return {level, duration}
+",
+    )
+});
+
+struct LockoutKeys {
+    failures: String,
+    lockout: String,
+    level: String,
+}
+
+/// 잠금은 핸들과 클라이언트 IP 쌍마다 따로 둔다.
+/// 핸들만으로 잠그면 누구나 남의 핸들로 비밀번호를 틀려 그 사용자의 로그인을 막을 수 있다.
+fn lockout_keys(handle: &str, client_ip: Option<&str>) -> LockoutKeys {
+    let subject = format!(
+        "{}:{}",
+        normalize_account(handle),
+        client_ip.unwrap_or("unknown")
+    );
+    LockoutKeys {
+        failures: format!("login_failures:{}", subject),
+        lockout: format!("login_lockout:{}", subject),
+        level: format!("login_lockout_level:{}", subject),
+    }
+}
+
+/// 이 IP에서 잠긴 계정이면 남은 시간과 함께 `LoginLockedOut`을 돌려준다
+pub async fn check_login_lockout(handle: &str, client_ip: Option<&str>) -> ServiceResult<()> {
+    let Some(mut redis) = limiter() else {
+        return Ok(());
+    };
+
+    let ttl: i64 = match redis.ttl(lockout_keys(handle, client_ip).lockout).await {
+        Ok(ttl) => ttl,
+        Err(e) => {
+            warn!("Login lockout check failed for {}: {}", handle, e);
+            return Ok(());
+        }
+    };
+
+    if ttl > 0 {
+        Err(Errors::LoginLockedOut(ttl as u64))
+    } else {
+        Ok(())
+    }
+}
+
+/// 비밀번호 실패를 기록한다. 이번 실패로 이 IP에서 계정이 잠기면 `system_events`에 남기고 `LoginLockedOut`을 돌려준다.
+/// 없는 핸들도 같은 방식으로 세어, 응답만으로 계정이 있는지 알 수 없게 한다.
+pub async fn record_login_failure<C>(
+    conn: &C,
+    handle: &str,
+    user_id: Option<Uuid>,
+    client_ip: Option<&str>,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    let config = DbConfig::get();
+    if config.login_lockout_threshold == 0 {
+        return Ok(());
+    }
+    let Some(mut redis) = limiter() else {
+        return Ok(());
+    };
+
+    let keys = lockout_keys(handle, client_ip);
+    let base = config.login_lockout_base.max(1);
+    let (level, duration): (u64, u64) = match FAILURE_SCRIPT
+        .key(&keys.failures)
+        .key(&keys.lockout)
+        .key(&keys.level)
+        .arg(config.login_failure_window.max(1))
+        .arg(config.login_lockout_threshold)
+        .arg(base)
+        .arg(config.login_lockout_max.max(base))
+        .arg(LOCKOUT_LEVEL_TTL)
+        .invoke_async(&mut redis)
+        .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            warn!("Failed to record login failure for {}: {}", handle, e);
+            return Ok(());
+        }
+    };
+
+    if duration == 0 {
+        return Ok(());
+    }
+
+    warn!(
+        "Sign-in for {} locked for {}s (level {}) after repeated failures from {:?}",
+        handle, duration, level, client_ip
+    );
+    repository_log_event(
+        conn,
+        user_id,
+        ActionType::UserLockedOut,
+        user_id,
+        user_id.map(|_| TargetType::User),
+        Some(json!({
+            "handle": handle,
+            "ip_address": client_ip,
+            "failures": config.login_lockout_threshold,
+            "lockout_level": level,
+            "lockout_seconds": duration,
+        })),
+    )
+    .await;
+
+    Err(Errors::LoginLockedOut(duration))
+}
+
+/// 로그인에 성공하면 이 IP의 실패 횟수와 잠금 단계를 지운다
+pub async fn clear_login_failures(handle: &str, client_ip: Option<&str>) {
+    let Some(mut redis) = limiter() else {
+        return;
+    };
+
+    let keys = lockout_keys(handle, client_ip);
+    let result: redis::RedisResult<()> = redis.del(&[keys.failures, keys.level]).await;
+    if let Err(e) = result {
+        warn!("Failed to clear login failures for {}: {}", handle, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lockout_is_scoped_to_the_client_ip() {
+        let victim = lockout_keys("Alice", Some("203.0.113.7"));
+        let attacker = lockout_keys("alice ", Some("198.51.100.9"));
+        assert_ne!(victim.lockout, attacker.lockout);
+        assert_ne!(victim.failures, attacker.failures);
+        assert_eq!(
+            victim.lockout,
+            lockout_keys("alice", Some("203.0.113.7")).lockout
+        );
+    }
+}
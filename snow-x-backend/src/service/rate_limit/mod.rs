@@ -0,0 +1,15 @@
+//! 인증 경로의 요청 제한과 로그인 잠금 (Redis).
+//!
+//! 로그인, 비밀번호 재설정·인증 메일 요청, 핸들 중복 확인은 경로마다 정한 구간 안의 요청 횟수를
+//! 클라이언트 IP별, 요청 본문의 handle(email)별(2단계 인증은 대기 토큰의 사용자별)로 따로 센다.
+//! 한도를 넘으면 `Retry-After`와 함께 429로 답한다.
+//! 비밀번호나 2단계 인증 코드를 반복해서 틀린 계정은 그 클라이언트 IP에서만 잠그고,
+//! 다시 잠길 때마다 잠금 시간을 두 배로 늘린다. 여러 IP에서 나눠 시도하는 것은 handle별 요청 제한이 막는다.
+//! 실패 횟수는 두 번째 단계까지 마쳐 토큰을 받았을 때만 지운다.
+//! Redis에 닿지 못하면 로그인을 막지 않도록 제한 없이 통과시킨다.
+
+pub mod limiter;
+pub mod lockout;
+
+pub use limiter::{RateLimitRoute, check_rate_limit, init_rate_limiter};
+pub use lockout::{check_login_lockout, clear_login_failures, record_login_failure};
@@ -0,0 +1,85 @@
+//! 인스턴스 안의 실시간 연결에 이벤트를 나눠 준다.
+//!
+//! Redis 연결(`ConnectionManager`)에 push 수신자로 등록되어 `PSUBSCRIBE`로 받은 메시지를
+//! broadcast 채널로 넘긴다. 각 연결은 broadcast를 구독하고 자기 토픽만 골라 보낸다.
+
+use super::CHANNEL_PREFIX;
+use redis::aio::{AsyncPushSender, ConnectionManager, SendError};
+use redis::{Msg, PushInfo, RedisResult};
+use serde::Deserialize;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::debug;
+
+/// 느린 연결이 밀릴 수 있는 최대 메시지 수. 넘으면 그 연결은 다시 조회하라는 안내를 받는다.
+const HUB_CAPACITY: usize = 1024;
+
+/// 토픽과 함께 받은 이벤트 한 건. `payload`는 발행된 JSON 그대로다.
+#[derive(Debug)]
+pub struct RealtimeMessage {
+    pub topic: String,
+    pub event: String,
+    pub payload: String,
+}
+
+#[derive(Deserialize)]
+struct EventHeader {
+    event: String,
+}
+
+#[derive(Clone)]
+pub struct RealtimeHub {
+    sender: broadcast::Sender<Arc<RealtimeMessage>>,
+}
+
+impl Default for RealtimeHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RealtimeHub {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(HUB_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<RealtimeMessage>> {
+        self.sender.subscribe()
+    }
+
+    /// 모든 실시간 토픽을 구독한다. 재연결 시 다시 구독하는 것은 `ConnectionManager`가 맡는다.
+    pub async fn listen(&self, redis: &mut ConnectionManager) -> RedisResult<()> {
+        redis.psubscribe(format!("{}*", CHANNEL_PREFIX)).await
+    }
+
+    fn dispatch(&self, info: PushInfo) {
+        let Some(msg) = Msg::from_push_info(info) else {
+            return;
+        };
+        let Some(topic) = msg.get_channel_name().strip_prefix(CHANNEL_PREFIX) else {
+            return;
+        };
+        let Ok(payload) = msg.get_payload::<String>() else {
+            return;
+        };
+        let Ok(header) = serde_json::from_str::<EventHeader>(&payload) else {
+            debug!("Ignoring malformed realtime message on {}", topic);
+            return;
+        };
+
+        // 이 인스턴스에 연결이 없으면 받을 곳이 없을 뿐이다
+        let _ = self.sender.send(Arc::new(RealtimeMessage {
+            topic: topic.to_string(),
+            event: header.event,
+            payload,
+        }));
+    }
+}
+
+impl AsyncPushSender for RealtimeHub {
+    fn send(&self, info: PushInfo) -> Result<(), SendError> {
+        self.dispatch(info);
+        Ok(())
+    }
+}
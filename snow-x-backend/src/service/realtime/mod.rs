@@ -0,0 +1,119 @@
+//! 실시간 이벤트. 변경이 생긴 인스턴스가 Redis `PUBLISH`로 이벤트를 올리면, 모든 인스턴스의
+//! `hub::RealtimeHub`가 `PSUBSCRIBE`로 받아 그 인스턴스에 붙은 WebSocket/SSE 연결에 나눠 준다.
+//!
+//! 토픽마다 Redis 채널 `snowx:realtime:<topic>`을 쓴다.
+//!
+//! - `user:<user_id>`: 그 사용자의 새 알림
+//! - `tenant:<tenant_id>:ipam`: 그 테넌트의 랙 실장 변경, IP 할당 (연결할 때 그 테넌트에 속한 사용자)
+//! - `post:<post_id>`: 그 포스트의 댓글 작성·수정·삭제 (보고 있는 포스트만)
+
+pub mod hub;
+pub mod session;
+
+use crate::service::tenant::tenant_of_rack;
+use chrono::Utc;
+use redis::aio::ConnectionManager;
+use sea_orm::ConnectionTrait;
+use serde_json::{Value, json};
+use std::sync::OnceLock;
+use tracing::warn;
+use uuid::Uuid;
+
+pub use hub::RealtimeHub;
+
+/// Redis 채널 이름 앞부분
+pub const CHANNEL_PREFIX: &str = "snowx:realtime:";
+
+/// 이벤트 종류
+pub const EVENT_NOTIFICATION_CREATED: &str = "notification.created";
+pub const EVENT_RACK_ELEVATION_CHANGED: &str = "ipam.rack_elevation_changed";
+pub const EVENT_IP_ALLOCATED: &str = "ipam.ip_allocated";
+pub const EVENT_COMMENT_CREATED: &str = "comment.created";
+pub const EVENT_COMMENT_UPDATED: &str = "comment.updated";
+pub const EVENT_COMMENT_DELETED: &str = "comment.deleted";
+
+pub fn user_topic(user_id: Uuid) -> String {
+    format!("user:{}", user_id)
+}
+
+pub fn ipam_topic(tenant_id: Uuid) -> String {
+    format!("tenant:{}:ipam", tenant_id)
+}
+
+pub fn post_topic(post_id: Uuid) -> String {
+    format!("post:{}", post_id)
+}
+
+/// 발행에 쓰는 연결. 서버 시작 시 `init_realtime_publisher`로 정한다.
+static PUBLISHER: OnceLock<ConnectionManager> = OnceLock::new();
+
+pub fn init_realtime_publisher(redis: ConnectionManager) {
+    if PUBLISHER.set(redis).is_err() {
+        warn!("Realtime publisher is already initialized");
+    }
+}
+
+/// 이벤트를 발행한다. 실시간 전달은 부가 기능이므로 실패해도 경고만 남긴다.
+///
+/// 구독자가 바로 다시 조회할 수 있도록 트랜잭션을 커밋한 뒤에 호출한다.
+pub async fn publish_realtime_event(topic: &str, event: &str, data: Value) {
+    let Some(redis) = PUBLISHER.get() else {
+        return;
+    };
+
+    let message = json!({
+        "topic": topic,
+        "event": event,
+        "data": data,
+        "published_at": Utc::now(),
+    })
+    .to_string();
+
+    let mut redis = redis.clone();
+    if let Err(e) = redis::cmd("PUBLISH")
+        .arg(format!("{}{}", CHANNEL_PREFIX, topic))
+        .arg(message)
+        .query_async::<i64>(&mut redis)
+        .await
+    {
+        warn!(
+            "Failed to publish realtime event {} to {}: {}",
+            event, topic, e
+        );
+    }
+}
+
+/// 랙에 장비가 놓이거나 빠지면 바뀐 랙마다 그 랙 테넌트의 토픽으로 실장 변경 이벤트를 보낸다.
+/// 테넌트가 정해지지 않은 랙은 어느 테넌트에서도 보이지 않으므로 보내지 않는다.
+pub async fn publish_rack_elevation_changed<C>(
+    conn: &C,
+    rack_ids: impl IntoIterator<Item = Uuid>,
+    device_id: Uuid,
+    action: &str,
+) where
+    C: ConnectionTrait,
+{
+    let mut published = Vec::new();
+    for rack_id in rack_ids {
+        if published.contains(&rack_id) {
+            continue;
+        }
+        published.push(rack_id);
+        let tenant_id = match tenant_of_rack(conn, rack_id).await {
+            Ok(owner) => owner.tenant_id(),
+            Err(e) => {
+                warn!("Failed to find tenant of rack {}: {:?}", rack_id, e);
+                None
+            }
+        };
+        let Some(tenant_id) = tenant_id else {
+            continue;
+        };
+        publish_realtime_event(
+            &ipam_topic(tenant_id),
+            EVENT_RACK_ELEVATION_CHANGED,
+            json!({ "rack_id": rack_id, "device_id": device_id, "action": action }),
+        )
+        .await;
+    }
+}
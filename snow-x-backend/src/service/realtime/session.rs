@@ -0,0 +1,141 @@
+//! 실시간 연결 하나가 받을 토픽. 자기 알림과 연결할 때 속한 테넌트의 IPAM 변경은 항상 받고,
+//! 포스트 댓글 활동은 보고 있다고 알린 포스트만 받는다.
+
+use super::hub::RealtimeMessage;
+use super::{ipam_topic, user_topic};
+use crate::service::error::errors::{Errors, ServiceResult};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+/// 한 연결에서 동시에 볼 수 있는 최대 포스트 수
+pub const MAX_WATCHED_POSTS: usize = 20;
+
+/// 이 연결이 밀려서 놓친 이벤트가 있다는 안내. 받으면 화면을 다시 조회한다.
+pub const EVENT_RESYNC: &str = "resync";
+/// 보고 있는 포스트 목록이 바뀌었다는 응답
+pub const EVENT_WATCHING: &str = "watching";
+/// 잘못된 명령에 대한 응답
+pub const EVENT_ERROR: &str = "error";
+
+/// WebSocket으로 받는 명령
+#[derive(Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+enum SessionCommand {
+    WatchPost { post_id: Uuid },
+    UnwatchPost { post_id: Uuid },
+}
+
+pub struct RealtimeSession {
+    user_topic: String,
+    ipam_topics: BTreeSet<String>,
+    posts: BTreeSet<Uuid>,
+}
+
+/// 쉼표로 구분한 포스트 ID 목록을 읽는다
+pub fn parse_post_ids(posts: Option<&str>) -> ServiceResult<Vec<Uuid>> {
+    posts
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            Uuid::parse_str(id)
+                .map_err(|_| Errors::ValidationError(format!("Invalid post id '{}'", id)))
+        })
+        .collect()
+}
+
+impl RealtimeSession {
+    /// `tenant_ids`는 연결할 때 사용자가 속한 활성 테넌트
+    pub fn new(
+        user_id: Uuid,
+        tenant_ids: impl IntoIterator<Item = Uuid>,
+        posts: Vec<Uuid>,
+    ) -> ServiceResult<Self> {
+        let mut session = Self {
+            user_topic: user_topic(user_id),
+            ipam_topics: tenant_ids.into_iter().map(ipam_topic).collect(),
+            posts: BTreeSet::new(),
+        };
+        for post_id in posts {
+            session
+                .watch_post(post_id)
+                .map_err(Errors::ValidationError)?;
+        }
+        Ok(session)
+    }
+
+    fn watch_post(&mut self, post_id: Uuid) -> Result<(), String> {
+        if !self.posts.contains(&post_id) && self.posts.len() >= MAX_WATCHED_POSTS {
+            return Err(format!(
+                "Cannot watch more than {} posts on one connection",
+                MAX_WATCHED_POSTS
+            ));
+        }
+        self.posts.insert(post_id);
+        Ok(())
+    }
+
+    /// 이 연결로 보낼 메시지인지
+    pub fn wants(&self, message: &RealtimeMessage) -> bool {
+        if message.topic == self.user_topic || self.ipam_topics.contains(&message.topic) {
+            return true;
+        }
+        message
+            .topic
+            .strip_prefix("post:")
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .is_some_and(|post_id| self.posts.contains(&post_id))
+    }
+
+    /// 클라이언트가 보낸 명령을 처리하고 돌려줄 메시지를 만든다
+    pub fn handle_command(&mut self, text: &str) -> Value {
+        let result = match serde_json::from_str::<SessionCommand>(text) {
+            Ok(SessionCommand::WatchPost { post_id }) => self.watch_post(post_id),
+            Ok(SessionCommand::UnwatchPost { post_id }) => {
+                self.posts.remove(&post_id);
+                Ok(())
+            }
+            Err(e) => Err(format!("Invalid command: {}", e)),
+        };
+
+        match result {
+            Ok(()) => json!({
+                "event": EVENT_WATCHING,
+                "data": { "post_ids": self.posts },
+            }),
+            Err(message) => json!({
+                "event": EVENT_ERROR,
+                "data": { "message": message },
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(topic: String) -> RealtimeMessage {
+        RealtimeMessage {
+            topic,
+            event: "test".to_string(),
+            payload: "{}".to_string(),
+        }
+    }
+
+    #[test]
+    fn receives_ipam_events_only_for_own_tenants() {
+        let user_id = Uuid::new_v4();
+        let (mine, other) = (Uuid::new_v4(), Uuid::new_v4());
+        let session = RealtimeSession::new(user_id, [mine], Vec::new()).unwrap();
+
+        assert!(session.wants(&message(ipam_topic(mine))));
+        assert!(!session.wants(&message(ipam_topic(other))));
+        assert!(!session.wants(&message("ipam".to_string())));
+        assert!(session.wants(&message(user_topic(user_id))));
+        assert!(!session.wants(&message(user_topic(Uuid::new_v4()))));
+    }
+}
@@ -1,6 +1,9 @@
 use crate::dto::server_room::request::create_server_room::CreateServerRoomRequest;
 use crate::dto::server_room::response::server_room_info::ServerRoomInfoResponse;
 use crate::repository::server_room::create_server_room::repository_create_server_room;
+use crate::service::audit::{
+    ACTION_CREATE, AuditEntry, RESOURCE_SERVER_ROOM, record_audit_or_warn,
+};
 use crate::service::error::errors::ServiceResult;
 use sea_orm::DatabaseConnection;
 use uuid::Uuid;
@@ -27,6 +30,20 @@ pub async fn service_create_server_room(
     )
     .await?;
 
+    record_audit_or_warn(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_SERVER_ROOM,
+            resource_id: server_room.id,
+            resource_name: Some(&server_room.name),
+            action: ACTION_CREATE,
+            actor_id: Some(*created_by),
+            before: None,
+            after: Some(&server_room),
+        },
+    )
+    .await;
+
     Ok(ServerRoomInfoResponse {
         id: server_room.id,
         office_id: server_room.office_id,
@@ -1,4 +1,8 @@
 use crate::repository::server_room::delete_server_room::repository_delete_server_room;
+use crate::repository::server_room::get_server_room_by_id::repository_get_server_room_by_id;
+use crate::service::audit::{
+    ACTION_DELETE, AuditEntry, RESOURCE_SERVER_ROOM, record_audit_or_warn,
+};
 use crate::service::error::errors::{Errors, ServiceResult};
 use sea_orm::DatabaseConnection;
 use uuid::Uuid;
@@ -6,12 +10,28 @@ use uuid::Uuid;
 pub async fn service_delete_server_room(
     conn: &DatabaseConnection,
     server_room_id: &Uuid,
+    deleted_by: &Uuid,
 ) -> ServiceResult<()> {
+    let before = repository_get_server_room_by_id(conn, server_room_id).await?;
     let deleted = repository_delete_server_room(conn, server_room_id).await?;
 
-    if deleted {
-        Ok(())
-    } else {
-        Err(Errors::ServerRoomNotFound)
+    match before {
+        Some(before) if deleted => {
+            record_audit_or_warn(
+                conn,
+                AuditEntry {
+                    resource_type: RESOURCE_SERVER_ROOM,
+                    resource_id: before.id,
+                    resource_name: Some(&before.name),
+                    action: ACTION_DELETE,
+                    actor_id: Some(*deleted_by),
+                    before: Some(&before),
+                    after: None,
+                },
+            )
+            .await;
+            Ok(())
+        }
+        _ => Err(Errors::ServerRoomNotFound),
     }
 }
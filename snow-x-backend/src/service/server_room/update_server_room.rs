@@ -1,6 +1,10 @@
 use crate::dto::server_room::request::update_server_room::UpdateServerRoomRequest;
 use crate::dto::server_room::response::server_room_info::ServerRoomInfoResponse;
+use crate::repository::server_room::get_server_room_by_id::repository_get_server_room_by_id;
 use crate::repository::server_room::update_server_room::repository_update_server_room;
+use crate::service::audit::{
+    ACTION_UPDATE, AuditEntry, RESOURCE_SERVER_ROOM, record_audit_or_warn,
+};
 use crate::service::error::errors::{Errors, ServiceResult};
 use sea_orm::DatabaseConnection;
 use uuid::Uuid;
@@ -9,7 +13,12 @@ pub async fn service_update_server_room(
     conn: &DatabaseConnection,
     server_room_id: &Uuid,
     request: UpdateServerRoomRequest,
+    updated_by: &Uuid,
 ) -> ServiceResult<ServerRoomInfoResponse> {
+    let before = repository_get_server_room_by_id(conn, server_room_id)
+        .await?
+        .ok_or(Errors::ServerRoomNotFound)?;
+
     let server_room = repository_update_server_room(
         conn,
         server_room_id,
@@ -24,6 +33,20 @@ pub async fn service_update_server_room(
     .await?
     .ok_or(Errors::ServerRoomNotFound)?;
 
+    record_audit_or_warn(
+        conn,
+        AuditEntry {
+            resource_type: RESOURCE_SERVER_ROOM,
+            resource_id: server_room.id,
+            resource_name: Some(&server_room.name),
+            action: ACTION_UPDATE,
+            actor_id: Some(*updated_by),
+            before: Some(&before),
+            after: Some(&server_room),
+        },
+    )
+    .await;
+
     Ok(ServerRoomInfoResponse {
         id: server_room.id,
         office_id: server_room.office_id,
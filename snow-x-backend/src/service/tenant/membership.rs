@@ -0,0 +1,61 @@
+use crate::entity::{tenant_memberships, tenants};
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+/// 사용자가 속한 활성 테넌트와 소속 정보
+pub async fn get_active_memberships<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> ServiceResult<Vec<(tenant_memberships::Model, tenants::Model)>>
+where
+    C: ConnectionTrait,
+{
+    let rows = tenant_memberships::Entity::find()
+        .find_also_related(tenants::Entity)
+        .filter(tenant_memberships::Column::UserId.eq(user_id))
+        .filter(tenants::Column::IsActive.eq(true))
+        .order_by_asc(tenants::Column::Name)
+        .all(conn)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(membership, tenant)| tenant.map(|tenant| (membership, tenant)))
+        .collect())
+}
+
+/// 요청에 쓸 테넌트 소속을 정한다.
+///
+/// 테넌트를 지정하지 않았으면 소속 테넌트가 하나일 때만 그 테넌트를 쓰고,
+/// 여럿이면 `TenantRequired`를 돌려준다.
+pub async fn resolve_tenant_membership<C>(
+    conn: &C,
+    user_id: Uuid,
+    requested_tenant_id: Option<Uuid>,
+) -> ServiceResult<tenant_memberships::Model>
+where
+    C: ConnectionTrait,
+{
+    let mut memberships = get_active_memberships(conn, user_id).await?;
+
+    match requested_tenant_id {
+        Some(tenant_id) => memberships
+            .into_iter()
+            .find(|(membership, _)| membership.tenant_id == tenant_id)
+            .map(|(membership, _)| membership)
+            .ok_or_else(|| {
+                Errors::TenantAccessDenied(format!(
+                    "You are not a member of tenant {} or it is inactive",
+                    tenant_id
+                ))
+            }),
+        None => match memberships.len() {
+            0 => Err(Errors::TenantAccessDenied(
+                "You are not a member of any active tenant".to_string(),
+            )),
+            1 => Ok(memberships.remove(0).0),
+            _ => Err(Errors::TenantRequired),
+        },
+    }
+}
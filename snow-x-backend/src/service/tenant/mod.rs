@@ -0,0 +1,16 @@
+//! 테넌트 (사업부 단위의 데이터 격리).
+//!
+//! 사용자는 `tenant_memberships`로 여러 테넌트에 속하고 테넌트마다 역할이 다르다.
+//! IPAM 요청의 테넌트는 `X-Tenant-Id` 헤더, 없으면 액세스 토큰의 `tenant_id` 클레임으로 정한다.
+
+pub mod membership;
+pub mod ownership;
+pub mod tenants;
+
+pub use membership::resolve_tenant_membership;
+pub use ownership::*;
+pub use tenants::{
+    service_create_tenant, service_get_my_tenants, service_get_tenant_members,
+    service_issue_tenant_token, service_remove_tenant_member, service_update_tenant,
+    service_upsert_tenant_member,
+};
@@ -0,0 +1,254 @@
+//! 리소스가 속한 테넌트.
+//!
+//! 사무실·장비·연락처·장비 라이브러리·Custodian 정책·외부 API 연결은 `tenant_id` 열을 직접 갖고,
+//! 서버실·랙은 사무실을, IP 대역은 사무실(`ip_ranges.tenant_id`)을, IP 주소는 대역을 따라간다.
+
+use crate::entity::{
+    contacts, custodian_executions, custodian_policies, device_library, devices,
+    external_api_connections, ip_addresses, ip_ranges, office, racks, server_rooms,
+};
+use crate::service::audit::{
+    RESOURCE_CONTACT, RESOURCE_DEVICE, RESOURCE_DEVICE_LIBRARY, RESOURCE_IP_ADDRESS,
+    RESOURCE_IP_RANGE, RESOURCE_OFFICE, RESOURCE_RACK, RESOURCE_SERVER_ROOM,
+};
+use crate::service::error::errors::ServiceResult;
+use sea_orm::sea_query::{Expr, Query, SelectStatement};
+use sea_orm::{ConnectionTrait, EntityTrait, QuerySelect};
+use uuid::Uuid;
+
+/// 리소스의 테넌트 조회 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TenantOwner {
+    Tenant(Uuid),
+    /// 리소스는 있지만 테넌트가 정해지지 않음 (어느 테넌트에서도 보이지 않는다)
+    Unassigned,
+    /// 리소스가 없음
+    Missing,
+}
+
+impl TenantOwner {
+    fn from_row(row: Option<Option<Uuid>>) -> Self {
+        match row {
+            Some(Some(tenant_id)) => TenantOwner::Tenant(tenant_id),
+            Some(None) => TenantOwner::Unassigned,
+            None => TenantOwner::Missing,
+        }
+    }
+
+    pub fn tenant_id(&self) -> Option<Uuid> {
+        match self {
+            TenantOwner::Tenant(tenant_id) => Some(*tenant_id),
+            _ => None,
+        }
+    }
+}
+
+pub async fn tenant_of_office<C>(conn: &C, office_id: Uuid) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let row = office::Entity::find_by_id(office_id)
+        .select_only()
+        .column(office::Column::TenantId)
+        .into_tuple::<Option<Uuid>>()
+        .one(conn)
+        .await?;
+    Ok(TenantOwner::from_row(row))
+}
+
+pub async fn tenant_of_server_room<C>(conn: &C, server_room_id: Uuid) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let office_id = server_rooms::Entity::find_by_id(server_room_id)
+        .select_only()
+        .column(server_rooms::Column::OfficeId)
+        .into_tuple::<Uuid>()
+        .one(conn)
+        .await?;
+
+    match office_id {
+        Some(office_id) => tenant_of_office(conn, office_id).await,
+        None => Ok(TenantOwner::Missing),
+    }
+}
+
+pub async fn tenant_of_rack<C>(conn: &C, rack_id: Uuid) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let server_room_id = racks::Entity::find_by_id(rack_id)
+        .select_only()
+        .column(racks::Column::ServerRoomId)
+        .into_tuple::<Uuid>()
+        .one(conn)
+        .await?;
+
+    match server_room_id {
+        Some(server_room_id) => tenant_of_server_room(conn, server_room_id).await,
+        None => Ok(TenantOwner::Missing),
+    }
+}
+
+pub async fn tenant_of_device<C>(conn: &C, device_id: Uuid) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let row = devices::Entity::find_by_id(device_id)
+        .select_only()
+        .column(devices::Column::TenantId)
+        .into_tuple::<Option<Uuid>>()
+        .one(conn)
+        .await?;
+    Ok(TenantOwner::from_row(row))
+}
+
+pub async fn tenant_of_ip_range<C>(conn: &C, ip_range_id: Uuid) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    // ip_ranges.tenant_id는 사무실을 가리킨다
+    let office_id = ip_ranges::Entity::find_by_id(ip_range_id)
+        .select_only()
+        .column(ip_ranges::Column::TenantId)
+        .into_tuple::<Uuid>()
+        .one(conn)
+        .await?;
+
+    match office_id {
+        Some(office_id) => tenant_of_office(conn, office_id).await,
+        None => Ok(TenantOwner::Missing),
+    }
+}
+
+pub async fn tenant_of_ip_address<C>(conn: &C, ip_address_id: Uuid) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let ip_range_id = ip_addresses::Entity::find_by_id(ip_address_id)
+        .select_only()
+        .column(ip_addresses::Column::IpRangeId)
+        .into_tuple::<Uuid>()
+        .one(conn)
+        .await?;
+
+    match ip_range_id {
+        Some(ip_range_id) => tenant_of_ip_range(conn, ip_range_id).await,
+        None => Ok(TenantOwner::Missing),
+    }
+}
+
+pub async fn tenant_of_contact<C>(conn: &C, contact_id: Uuid) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let row = contacts::Entity::find_by_id(contact_id)
+        .select_only()
+        .column(contacts::Column::TenantId)
+        .into_tuple::<Option<Uuid>>()
+        .one(conn)
+        .await?;
+    Ok(TenantOwner::from_row(row))
+}
+
+pub async fn tenant_of_device_library<C>(conn: &C, library_id: Uuid) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let row = device_library::Entity::find_by_id(library_id)
+        .select_only()
+        .column(device_library::Column::TenantId)
+        .into_tuple::<Option<Uuid>>()
+        .one(conn)
+        .await?;
+    Ok(TenantOwner::from_row(row))
+}
+
+pub async fn tenant_of_custodian_policy<C>(conn: &C, policy_id: Uuid) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let row = custodian_policies::Entity::find_by_id(policy_id)
+        .select_only()
+        .column(custodian_policies::Column::TenantId)
+        .into_tuple::<Option<Uuid>>()
+        .one(conn)
+        .await?;
+    Ok(TenantOwner::from_row(row))
+}
+
+pub async fn tenant_of_custodian_execution<C>(
+    conn: &C,
+    execution_id: Uuid,
+) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let policy_id = custodian_executions::Entity::find_by_id(execution_id)
+        .select_only()
+        .column(custodian_executions::Column::PolicyId)
+        .into_tuple::<Uuid>()
+        .one(conn)
+        .await?;
+
+    match policy_id {
+        Some(policy_id) => tenant_of_custodian_policy(conn, policy_id).await,
+        None => Ok(TenantOwner::Missing),
+    }
+}
+
+pub async fn tenant_of_external_api_connection<C>(
+    conn: &C,
+    connection_id: i32,
+) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    let row = external_api_connections::Entity::find_by_id(connection_id)
+        .select_only()
+        .column(external_api_connections::Column::TenantId)
+        .into_tuple::<Option<Uuid>>()
+        .one(conn)
+        .await?;
+    Ok(TenantOwner::from_row(row))
+}
+
+/// 감사 로그의 리소스 종류(`RESOURCE_*`)로 테넌트를 찾는다
+pub async fn tenant_of_audit_resource<C>(
+    conn: &C,
+    resource_type: &str,
+    resource_id: Uuid,
+) -> ServiceResult<TenantOwner>
+where
+    C: ConnectionTrait,
+{
+    match resource_type {
+        RESOURCE_OFFICE => tenant_of_office(conn, resource_id).await,
+        RESOURCE_SERVER_ROOM => tenant_of_server_room(conn, resource_id).await,
+        RESOURCE_RACK => tenant_of_rack(conn, resource_id).await,
+        RESOURCE_DEVICE => tenant_of_device(conn, resource_id).await,
+        RESOURCE_IP_RANGE => tenant_of_ip_range(conn, resource_id).await,
+        RESOURCE_IP_ADDRESS => tenant_of_ip_address(conn, resource_id).await,
+        RESOURCE_CONTACT => tenant_of_contact(conn, resource_id).await,
+        RESOURCE_DEVICE_LIBRARY => tenant_of_device_library(conn, resource_id).await,
+        _ => Ok(TenantOwner::Missing),
+    }
+}
+
+/// 테넌트에 속한 사무실 ID 서브쿼리
+pub fn offices_in_tenant(tenant_id: Uuid) -> SelectStatement {
+    Query::select()
+        .column(office::Column::Id)
+        .from(office::Entity)
+        .and_where(Expr::col(office::Column::TenantId).eq(tenant_id))
+        .to_owned()
+}
+
+/// 테넌트에 속한 외부 API 연결 ID 서브쿼리
+pub fn external_api_connections_in_tenant(tenant_id: Uuid) -> SelectStatement {
+    Query::select()
+        .column(external_api_connections::Column::Id)
+        .from(external_api_connections::Entity)
+        .and_where(Expr::col(external_api_connections::Column::TenantId).eq(tenant_id))
+        .to_owned()
+}
@@ -0,0 +1,303 @@
+use super::membership::{get_active_memberships, resolve_tenant_membership};
+use crate::dto::tenant::request::{
+    CreateTenantRequest, UpdateTenantRequest, UpsertTenantMemberRequest,
+};
+use crate::dto::tenant::response::{
+    MyTenantListResponse, MyTenantResponse, TenantMemberListResponse, TenantMemberResponse,
+    TenantResponse, TenantTokenResponse,
+};
+use crate::entity::common::UserRole;
+use crate::entity::{tenant_memberships, tenants, users};
+use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
+use crate::service::auth::jwt::create_jwt_access_token_with_tenant;
+use crate::service::auth::role_check::require_admin;
+use crate::service::error::errors::{Errors, ServiceResult};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use tracing::info;
+use uuid::Uuid;
+
+const NAME_MAX_LEN: usize = 255;
+
+impl From<tenants::Model> for TenantResponse {
+    fn from(tenant: tenants::Model) -> Self {
+        Self {
+            id: tenant.id,
+            name: tenant.name,
+            description: tenant.description,
+            settings: tenant.settings,
+            created_by: tenant.created_by,
+            created_at: tenant.created_at,
+            updated_at: tenant.updated_at,
+            is_active: tenant.is_active,
+        }
+    }
+}
+
+fn validate_name(name: &str) -> ServiceResult<String> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > NAME_MAX_LEN {
+        return Err(Errors::ValidationError(format!(
+            "Tenant name must be 1-{} characters",
+            NAME_MAX_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+async fn find_tenant<C>(conn: &C, tenant_id: Uuid) -> ServiceResult<tenants::Model>
+where
+    C: ConnectionTrait,
+{
+    tenants::Entity::find_by_id(tenant_id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| Errors::NotFound("Tenant not found".to_string()))
+}
+
+/// 전체 Admin이거나 이 테넌트의 Admin인지 확인
+async fn require_tenant_admin<C>(conn: &C, user_id: Uuid, tenant_id: Uuid) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    if require_admin(conn, user_id).await.is_ok() {
+        return Ok(());
+    }
+
+    let membership = tenant_memberships::Entity::find()
+        .filter(tenant_memberships::Column::TenantId.eq(tenant_id))
+        .filter(tenant_memberships::Column::UserId.eq(user_id))
+        .one(conn)
+        .await?;
+
+    match membership {
+        Some(membership) if membership.role == UserRole::Admin => Ok(()),
+        _ => Err(Errors::PermissionDenied(
+            "Tenant admin role required".to_string(),
+        )),
+    }
+}
+
+/// 내가 속한 활성 테넌트 목록
+pub async fn service_get_my_tenants(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+) -> ServiceResult<MyTenantListResponse> {
+    let tenants = get_active_memberships(conn, user_id)
+        .await?
+        .into_iter()
+        .map(|(membership, tenant)| MyTenantResponse {
+            tenant: tenant.into(),
+            role: membership.role,
+        })
+        .collect();
+
+    Ok(MyTenantListResponse { tenants })
+}
+
+/// 테넌트가 정해진 액세스 토큰을 발급한다 (테넌트 전환)
+pub async fn service_issue_tenant_token(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+) -> ServiceResult<TenantTokenResponse> {
+    resolve_tenant_membership(conn, user_id, Some(tenant_id)).await?;
+
+    let access_token = create_jwt_access_token_with_tenant(&user_id, Some(tenant_id))
+        .map_err(|e| Errors::TokenCreationError(e.to_string()))?;
+
+    Ok(TenantTokenResponse {
+        tenant_id,
+        access_token,
+    })
+}
+
+/// 테넌트를 만든다 (전체 Admin 전용). 만든 사람은 이 테넌트의 Admin이 된다.
+pub async fn service_create_tenant(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    request: CreateTenantRequest,
+) -> ServiceResult<TenantResponse> {
+    require_admin(conn, user_id).await?;
+    let name = validate_name(&request.name)?;
+
+    let txn = conn.begin().await?;
+    let now = Utc::now();
+
+    let tenant = tenants::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        name: Set(name),
+        description: Set(request.description),
+        settings: Set(request.settings),
+        created_by: Set(user_id),
+        created_at: Set(now.into()),
+        updated_at: Set(now.into()),
+        is_active: Set(true),
+    }
+    .insert(&txn)
+    .await?;
+
+    tenant_memberships::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        tenant_id: Set(tenant.id),
+        user_id: Set(user_id),
+        role: Set(UserRole::Admin),
+        created_by: Set(Some(user_id)),
+        created_at: Set(now.into()),
+    }
+    .insert(&txn)
+    .await?;
+
+    txn.commit().await?;
+
+    info!(
+        "User {} created tenant {} ({})",
+        user_id, tenant.id, tenant.name
+    );
+
+    Ok(tenant.into())
+}
+
+/// 테넌트 정보를 바꾼다 (전체 Admin 또는 테넌트 Admin)
+pub async fn service_update_tenant(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    request: UpdateTenantRequest,
+) -> ServiceResult<TenantResponse> {
+    let tenant = find_tenant(conn, tenant_id).await?;
+    require_tenant_admin(conn, user_id, tenant_id).await?;
+
+    // 테넌트를 비활성화하면 구성원 모두가 데이터에 접근할 수 없으므로 전체 Admin만 할 수 있다
+    if request.is_active.is_some() {
+        require_admin(conn, user_id).await?;
+    }
+
+    let mut active: tenants::ActiveModel = tenant.into();
+    if let Some(name) = request.name {
+        active.name = Set(validate_name(&name)?);
+    }
+    if let Some(description) = request.description {
+        active.description = Set(Some(description));
+    }
+    if let Some(settings) = request.settings {
+        active.settings = Set(Some(settings));
+    }
+    if let Some(is_active) = request.is_active {
+        active.is_active = Set(is_active);
+    }
+    active.updated_at = Set(Utc::now().into());
+
+    Ok(active.update(conn).await?.into())
+}
+
+/// 테넌트 구성원 목록 (전체 Admin 또는 테넌트 Admin)
+pub async fn service_get_tenant_members(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+) -> ServiceResult<TenantMemberListResponse> {
+    find_tenant(conn, tenant_id).await?;
+    require_tenant_admin(conn, user_id, tenant_id).await?;
+
+    let members = tenant_memberships::Entity::find()
+        .find_also_related(users::Entity)
+        .filter(tenant_memberships::Column::TenantId.eq(tenant_id))
+        .order_by_asc(tenant_memberships::Column::CreatedAt)
+        .all(conn)
+        .await?
+        .into_iter()
+        .filter_map(|(membership, user)| {
+            user.map(|user| TenantMemberResponse {
+                user_id: user.id,
+                handle: user.handle,
+                name: user.name,
+                role: membership.role,
+                created_at: membership.created_at,
+            })
+        })
+        .collect();
+
+    Ok(TenantMemberListResponse { members })
+}
+
+/// 사용자를 테넌트에 추가하거나 역할을 바꾼다 (전체 Admin 또는 테넌트 Admin)
+pub async fn service_upsert_tenant_member(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    member_id: Uuid,
+    request: UpsertTenantMemberRequest,
+) -> ServiceResult<TenantMemberResponse> {
+    find_tenant(conn, tenant_id).await?;
+    require_tenant_admin(conn, user_id, tenant_id).await?;
+
+    let member = repository_find_user_by_uuid(conn, &member_id)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+
+    let existing = tenant_memberships::Entity::find()
+        .filter(tenant_memberships::Column::TenantId.eq(tenant_id))
+        .filter(tenant_memberships::Column::UserId.eq(member_id))
+        .one(conn)
+        .await?;
+
+    let membership = match existing {
+        Some(existing) => {
+            let mut active: tenant_memberships::ActiveModel = existing.into();
+            active.role = Set(request.role);
+            active.update(conn).await?
+        }
+        None => {
+            tenant_memberships::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                tenant_id: Set(tenant_id),
+                user_id: Set(member_id),
+                role: Set(request.role),
+                created_by: Set(Some(user_id)),
+                created_at: Set(Utc::now().into()),
+            }
+            .insert(conn)
+            .await?
+        }
+    };
+
+    info!(
+        "User {} set role {:?} for user {} in tenant {}",
+        user_id, membership.role, member_id, tenant_id
+    );
+
+    Ok(TenantMemberResponse {
+        user_id: member.id,
+        handle: member.handle,
+        name: member.name,
+        role: membership.role,
+        created_at: membership.created_at,
+    })
+}
+
+/// 사용자를 테넌트에서 뺀다 (전체 Admin 또는 테넌트 Admin)
+pub async fn service_remove_tenant_member(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    member_id: Uuid,
+) -> ServiceResult<()> {
+    find_tenant(conn, tenant_id).await?;
+    require_tenant_admin(conn, user_id, tenant_id).await?;
+
+    let result = tenant_memberships::Entity::delete_many()
+        .filter(tenant_memberships::Column::TenantId.eq(tenant_id))
+        .filter(tenant_memberships::Column::UserId.eq(member_id))
+        .exec(conn)
+        .await?;
+
+    if result.rows_affected == 0 {
+        return Err(Errors::NotFound("Tenant member not found".to_string()));
+    }
+
+    Ok(())
+}
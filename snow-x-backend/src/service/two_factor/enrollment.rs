@@ -0,0 +1,337 @@
+use super::policy::is_two_factor_required_for_role;
+use super::totp::{
+    generate_recovery_codes, generate_secret, hash_recovery_code, otpauth_uri, verify_code,
+};
+use crate::config::db_config::DbConfig;
+use crate::dto::two_factor::request::{ConfirmTwoFactorRequest, TwoFactorCodeRequest};
+use crate::dto::two_factor::response::{
+    TwoFactorEnrollmentResponse, TwoFactorRecoveryCodesResponse, TwoFactorStatusResponse,
+};
+use crate::entity::{user_recovery_codes, user_two_factor, users};
+use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
+use crate::service::error::errors::{Errors, ServiceResult};
+use chrono::Utc;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, Set,
+    TransactionTrait,
+};
+use tracing::info;
+use uuid::Uuid;
+
+pub(super) async fn find_two_factor<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> ServiceResult<Option<user_two_factor::Model>>
+where
+    C: ConnectionTrait,
+{
+    Ok(user_two_factor::Entity::find_by_id(user_id)
+        .one(conn)
+        .await?)
+}
+
+/// 새 비밀키를 발급한다. 확인 전의 이전 비밀키는 버린다
+pub(super) async fn start_enrollment<C>(
+    conn: &C,
+    user: &users::Model,
+) -> ServiceResult<TwoFactorEnrollmentResponse>
+where
+    C: ConnectionTrait,
+{
+    if find_two_factor(conn, user.id)
+        .await?
+        .is_some_and(|record| record.enabled_at.is_some())
+    {
+        return Err(Errors::TwoFactorAlreadyEnabled);
+    }
+
+    let secret = generate_secret();
+    let now = Utc::now();
+    let record = user_two_factor::ActiveModel {
+        user_id: Set(user.id),
+        secret: Set(secret.clone()),
+        enabled_at: Set(None),
+        last_used_step: Set(None),
+        created_at: Set(now.into()),
+        updated_at: Set(now.into()),
+    };
+    user_two_factor::Entity::insert(record)
+        .on_conflict(
+            OnConflict::column(user_two_factor::Column::UserId)
+                .update_columns([
+                    user_two_factor::Column::Secret,
+                    user_two_factor::Column::EnabledAt,
+                    user_two_factor::Column::LastUsedStep,
+                    user_two_factor::Column::CreatedAt,
+                    user_two_factor::Column::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_without_returning(conn)
+        .await?;
+
+    let issuer = &DbConfig::get().auth_two_factor_issuer;
+    Ok(TwoFactorEnrollmentResponse {
+        otpauth_uri: otpauth_uri(issuer, &user.handle, &secret),
+        secret,
+    })
+}
+
+/// 확인 코드가 맞으면 2단계 인증을 켜고 새 복구 코드를 돌려준다
+pub(super) async fn finish_enrollment<C>(
+    conn: &C,
+    user_id: Uuid,
+    code: &str,
+) -> ServiceResult<Vec<String>>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let record = find_two_factor(conn, user_id)
+        .await?
+        .ok_or(Errors::TwoFactorNotEnabled)?;
+    if record.enabled_at.is_some() {
+        return Err(Errors::TwoFactorAlreadyEnabled);
+    }
+
+    let now = Utc::now();
+    let step = verify_code(&record.secret, code, now.timestamp(), None)
+        .ok_or(Errors::TwoFactorInvalidCode)?;
+
+    let txn = conn.begin().await?;
+    let enabled = user_two_factor::Entity::update_many()
+        .col_expr(user_two_factor::Column::EnabledAt, Expr::value(now))
+        .col_expr(user_two_factor::Column::LastUsedStep, Expr::value(step))
+        .col_expr(user_two_factor::Column::UpdatedAt, Expr::value(now))
+        .filter(user_two_factor::Column::UserId.eq(user_id))
+        .filter(user_two_factor::Column::Secret.eq(record.secret))
+        .filter(user_two_factor::Column::EnabledAt.is_null())
+        .exec(&txn)
+        .await?;
+    // 그사이 다른 요청이 등록을 마쳤거나 비밀키를 다시 받았다
+    if enabled.rows_affected == 0 {
+        return Err(Errors::TwoFactorInvalidCode);
+    }
+    let recovery_codes = replace_recovery_codes(&txn, user_id).await?;
+    txn.commit().await?;
+
+    info!("Two-factor authentication enabled for user {}", user_id);
+    Ok(recovery_codes)
+}
+
+/// 이전 복구 코드를 모두 지우고 새로 만든다
+async fn replace_recovery_codes<C>(conn: &C, user_id: Uuid) -> ServiceResult<Vec<String>>
+where
+    C: ConnectionTrait,
+{
+    user_recovery_codes::Entity::delete_many()
+        .filter(user_recovery_codes::Column::UserId.eq(user_id))
+        .exec(conn)
+        .await?;
+
+    let codes = generate_recovery_codes();
+    let now = Utc::now();
+    let rows = codes.iter().map(|code| user_recovery_codes::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(user_id),
+        code_hash: Set(hash_recovery_code(code)),
+        used_at: Set(None),
+        created_at: Set(now.into()),
+    });
+    user_recovery_codes::Entity::insert_many(rows)
+        .exec_without_returning(conn)
+        .await?;
+
+    Ok(codes)
+}
+
+/// 인증 앱 코드나 복구 코드로 두 번째 인증을 확인한다.
+/// 받아들인 코드는 다시 쓸 수 없다 (TOTP는 시간 구간, 복구 코드는 `used_at`으로 막는다).
+pub(super) async fn verify_second_factor<C>(
+    conn: &C,
+    user_id: Uuid,
+    code: Option<&str>,
+    recovery_code: Option<&str>,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    let record = find_two_factor(conn, user_id)
+        .await?
+        .filter(|record| record.enabled_at.is_some())
+        .ok_or(Errors::TwoFactorNotEnabled)?;
+
+    let now = Utc::now();
+    let accepted = match (code, recovery_code) {
+        (Some(code), None) => {
+            let Some(step) =
+                verify_code(&record.secret, code, now.timestamp(), record.last_used_step)
+            else {
+                return Err(Errors::TwoFactorInvalidCode);
+            };
+            // 동시에 같은 코드가 들어와도 한 번만 통과하도록 조건부로 갱신한다
+            user_two_factor::Entity::update_many()
+                .col_expr(user_two_factor::Column::LastUsedStep, Expr::value(step))
+                .col_expr(user_two_factor::Column::UpdatedAt, Expr::value(now))
+                .filter(user_two_factor::Column::UserId.eq(user_id))
+                .filter(
+                    Condition::any()
+                        .add(user_two_factor::Column::LastUsedStep.is_null())
+                        .add(user_two_factor::Column::LastUsedStep.lt(step)),
+                )
+                .exec(conn)
+                .await?
+                .rows_affected
+                > 0
+        }
+        (None, Some(recovery_code)) => {
+            let used = user_recovery_codes::Entity::update_many()
+                .col_expr(user_recovery_codes::Column::UsedAt, Expr::value(now))
+                .filter(user_recovery_codes::Column::UserId.eq(user_id))
+                .filter(user_recovery_codes::Column::CodeHash.eq(hash_recovery_code(recovery_code)))
+                .filter(user_recovery_codes::Column::UsedAt.is_null())
+                .exec(conn)
+                .await?
+                .rows_affected
+                > 0;
+            if used {
+                info!("Recovery code used by user {}", user_id);
+            }
+            used
+        }
+        _ => {
+            return Err(Errors::ValidationError(
+                "Provide either code or recovery_code".to_string(),
+            ));
+        }
+    };
+
+    if accepted {
+        Ok(())
+    } else {
+        Err(Errors::TwoFactorInvalidCode)
+    }
+}
+
+/// 내 2단계 인증 상태
+pub async fn service_get_two_factor_status<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> ServiceResult<TwoFactorStatusResponse>
+where
+    C: ConnectionTrait,
+{
+    let user = repository_find_user_by_uuid(conn, &user_id)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+    let record = find_two_factor(conn, user_id).await?;
+    let required_by_role = is_two_factor_required_for_role(conn, &user.role).await?;
+
+    let recovery_codes_remaining = user_recovery_codes::Entity::find()
+        .filter(user_recovery_codes::Column::UserId.eq(user_id))
+        .filter(user_recovery_codes::Column::UsedAt.is_null())
+        .count(conn)
+        .await?;
+
+    let enabled_at = record.as_ref().and_then(|record| record.enabled_at);
+    Ok(TwoFactorStatusResponse {
+        enabled: enabled_at.is_some(),
+        enabled_at,
+        enrollment_pending: record.is_some() && enabled_at.is_none(),
+        required_by_role,
+        recovery_codes_remaining,
+    })
+}
+
+/// 2단계 인증 등록을 시작한다. 확인 코드를 보내기 전까지는 로그인에 쓰이지 않는다
+pub async fn service_enroll_two_factor<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> ServiceResult<TwoFactorEnrollmentResponse>
+where
+    C: ConnectionTrait,
+{
+    let user = repository_find_user_by_uuid(conn, &user_id)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+
+    start_enrollment(conn, &user).await
+}
+
+/// 첫 코드로 등록을 마친다. 복구 코드는 이 응답에서만 확인할 수 있다
+pub async fn service_confirm_two_factor<C>(
+    conn: &C,
+    user_id: Uuid,
+    request: ConfirmTwoFactorRequest,
+) -> ServiceResult<TwoFactorRecoveryCodesResponse>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let recovery_codes = finish_enrollment(conn, user_id, &request.code).await?;
+    Ok(TwoFactorRecoveryCodesResponse { recovery_codes })
+}
+
+/// 2단계 인증을 끈다. 역할 정책으로 강제된 사용자는 끌 수 없다
+pub async fn service_disable_two_factor<C>(
+    conn: &C,
+    user_id: Uuid,
+    request: TwoFactorCodeRequest,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let user = repository_find_user_by_uuid(conn, &user_id)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+    if is_two_factor_required_for_role(conn, &user.role).await? {
+        return Err(Errors::TwoFactorRequired(format!(
+            "Two-factor authentication is required for the {:?} role",
+            user.role
+        )));
+    }
+
+    verify_second_factor(
+        conn,
+        user_id,
+        request.code.as_deref(),
+        request.recovery_code.as_deref(),
+    )
+    .await?;
+
+    let txn = conn.begin().await?;
+    user_recovery_codes::Entity::delete_many()
+        .filter(user_recovery_codes::Column::UserId.eq(user_id))
+        .exec(&txn)
+        .await?;
+    user_two_factor::Entity::delete_by_id(user_id)
+        .exec(&txn)
+        .await?;
+    txn.commit().await?;
+
+    info!("Two-factor authentication disabled for user {}", user_id);
+    Ok(())
+}
+
+/// 복구 코드를 새로 만든다. 이전 코드는 모두 쓸 수 없게 된다
+pub async fn service_regenerate_recovery_codes<C>(
+    conn: &C,
+    user_id: Uuid,
+    request: TwoFactorCodeRequest,
+) -> ServiceResult<TwoFactorRecoveryCodesResponse>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    verify_second_factor(
+        conn,
+        user_id,
+        request.code.as_deref(),
+        request.recovery_code.as_deref(),
+    )
+    .await?;
+
+    let txn = conn.begin().await?;
+    let recovery_codes = replace_recovery_codes(&txn, user_id).await?;
+    txn.commit().await?;
+
+    Ok(TwoFactorRecoveryCodesResponse { recovery_codes })
+}
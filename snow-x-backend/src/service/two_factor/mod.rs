@@ -0,0 +1,20 @@
+//! TOTP 2단계 인증.
+//!
+//! 비밀번호 로그인(`/v0/auth/sign_in`)은 2단계 인증을 켠 사용자에게 토큰 대신 짧은 대기 토큰을 주고,
+//! `/v0/auth/sign_in/two_factor`에서 인증 앱 코드나 일회용 복구 코드를 받은 뒤에 토큰을 발급한다.
+//! Admin은 역할별로 2단계 인증을 강제할 수 있으며, 강제된 역할의 미등록 사용자는 로그인 중에 등록한다.
+//! Google/GitHub 로그인도 제공자 인증을 마친 뒤 같은 대기 토큰을 받아 `/v0/auth/sign_in/two_factor`를 거친다.
+
+pub mod enrollment;
+pub mod policy;
+pub mod sign_in;
+pub mod totp;
+
+pub use enrollment::{
+    service_confirm_two_factor, service_disable_two_factor, service_enroll_two_factor,
+    service_get_two_factor_status, service_regenerate_recovery_codes,
+};
+pub use policy::{service_get_two_factor_policies, service_update_two_factor_policy};
+pub use sign_in::{
+    begin_two_factor_sign_in, service_enroll_two_factor_at_sign_in, service_sign_in_two_factor,
+};
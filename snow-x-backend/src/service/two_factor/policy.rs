@@ -0,0 +1,104 @@
+use crate::dto::two_factor::request::UpdateTwoFactorRolePolicyRequest;
+use crate::dto::two_factor::response::{
+    TwoFactorRolePolicyListResponse, TwoFactorRolePolicyResponse,
+};
+use crate::entity::common::UserRole;
+use crate::entity::two_factor_role_policies;
+use crate::service::auth::role_check::require_admin;
+use crate::service::error::errors::ServiceResult;
+use chrono::Utc;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ConnectionTrait, EntityTrait, Iterable, Set};
+use tracing::info;
+use uuid::Uuid;
+
+/// 이 역할의 사용자에게 2단계 인증을 강제하는지. 정책이 없으면 강제하지 않는다
+pub async fn is_two_factor_required_for_role<C>(conn: &C, role: &UserRole) -> ServiceResult<bool>
+where
+    C: ConnectionTrait,
+{
+    let policy = two_factor_role_policies::Entity::find_by_id(role.clone())
+        .one(conn)
+        .await?;
+
+    Ok(policy.is_some_and(|policy| policy.required))
+}
+
+/// 모든 역할의 2단계 인증 정책 (Admin 전용)
+pub async fn service_get_two_factor_policies<C>(
+    conn: &C,
+    admin_id: Uuid,
+) -> ServiceResult<TwoFactorRolePolicyListResponse>
+where
+    C: ConnectionTrait,
+{
+    require_admin(conn, admin_id).await?;
+
+    let stored = two_factor_role_policies::Entity::find().all(conn).await?;
+    let policies = UserRole::iter()
+        .map(
+            |role| match stored.iter().find(|policy| policy.role == role) {
+                Some(policy) => TwoFactorRolePolicyResponse {
+                    role,
+                    required: policy.required,
+                    updated_by: policy.updated_by,
+                    updated_at: Some(policy.updated_at),
+                },
+                None => TwoFactorRolePolicyResponse {
+                    role,
+                    required: false,
+                    updated_by: None,
+                    updated_at: None,
+                },
+            },
+        )
+        .collect();
+
+    Ok(TwoFactorRolePolicyListResponse { policies })
+}
+
+/// 역할별 2단계 인증 강제 여부를 바꾼다 (Admin 전용).
+/// 강제해도 이미 발급된 세션은 그대로 두고, 다음 비밀번호 로그인부터 등록을 요구한다.
+pub async fn service_update_two_factor_policy<C>(
+    conn: &C,
+    admin_id: Uuid,
+    role: UserRole,
+    request: UpdateTwoFactorRolePolicyRequest,
+) -> ServiceResult<TwoFactorRolePolicyResponse>
+where
+    C: ConnectionTrait,
+{
+    require_admin(conn, admin_id).await?;
+
+    let policy = two_factor_role_policies::ActiveModel {
+        role: Set(role),
+        required: Set(request.required),
+        updated_by: Set(Some(admin_id)),
+        updated_at: Set(Utc::now().into()),
+    };
+
+    let policy = two_factor_role_policies::Entity::insert(policy)
+        .on_conflict(
+            OnConflict::column(two_factor_role_policies::Column::Role)
+                .update_columns([
+                    two_factor_role_policies::Column::Required,
+                    two_factor_role_policies::Column::UpdatedBy,
+                    two_factor_role_policies::Column::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(conn)
+        .await?;
+
+    info!(
+        "Two-factor policy for {:?} set to required={} by {}",
+        policy.role, policy.required, admin_id
+    );
+
+    Ok(TwoFactorRolePolicyResponse {
+        role: policy.role,
+        required: policy.required,
+        updated_by: policy.updated_by,
+        updated_at: Some(policy.updated_at),
+    })
+}
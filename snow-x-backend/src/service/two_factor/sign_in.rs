@@ -0,0 +1,131 @@
+use super::enrollment::{
+    find_two_factor, finish_enrollment, start_enrollment, verify_second_factor,
+};
+use super::policy::is_two_factor_required_for_role;
+use crate::dto::auth::internal::two_factor_challenge::TwoFactorChallengeClaims;
+use crate::dto::auth::response::sign_in::{TwoFactorChallengeResponse, TwoFactorSignInResponse};
+use crate::dto::two_factor::request::{TwoFactorChallengeRequest, TwoFactorSignInRequest};
+use crate::dto::two_factor::response::TwoFactorEnrollmentResponse;
+use crate::entity::users;
+use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
+use crate::service::auth::jwt::{
+    create_two_factor_challenge_token, decode_two_factor_challenge_token,
+};
+use crate::service::auth::sign_in::issue_sign_in_tokens;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::rate_limit::{check_login_lockout, clear_login_failures, record_login_failure};
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use tracing::debug;
+
+/// 비밀번호를 확인한 사용자에게 두 번째 단계가 필요하면 대기 토큰을 만든다.
+/// 2단계 인증을 켰거나, 역할 정책상 필요한데 아직 등록하지 않은 경우다.
+pub async fn begin_two_factor_sign_in<C>(
+    conn: &C,
+    user: &users::Model,
+) -> ServiceResult<Option<TwoFactorChallengeResponse>>
+where
+    C: ConnectionTrait,
+{
+    let enabled = find_two_factor(conn, user.id)
+        .await?
+        .is_some_and(|record| record.enabled_at.is_some());
+    let enroll = !enabled && is_two_factor_required_for_role(conn, &user.role).await?;
+    if !enabled && !enroll {
+        return Ok(None);
+    }
+
+    let (challenge_token, expires_at) = create_two_factor_challenge_token(&user.id, enroll)
+        .map_err(|e| Errors::TokenCreationError(e.to_string()))?;
+
+    Ok(Some(TwoFactorChallengeResponse {
+        two_factor_required: true,
+        enrollment_required: enroll,
+        challenge_token,
+        expires_at,
+    }))
+}
+
+fn decode_challenge(token: &str) -> ServiceResult<TwoFactorChallengeClaims> {
+    decode_two_factor_challenge_token(token)
+        .map(|data| data.claims)
+        .map_err(|e| {
+            debug!("Invalid two-factor challenge token: {}", e);
+            Errors::UserInvalidToken
+        })
+}
+
+/// 역할 정책 때문에 로그인 중에 2단계 인증을 등록한다
+pub async fn service_enroll_two_factor_at_sign_in<C>(
+    conn: &C,
+    request: TwoFactorChallengeRequest,
+) -> ServiceResult<TwoFactorEnrollmentResponse>
+where
+    C: ConnectionTrait,
+{
+    let claims = decode_challenge(&request.challenge_token)?;
+    if !claims.enroll {
+        return Err(Errors::TwoFactorAlreadyEnabled);
+    }
+
+    let user = repository_find_user_by_uuid(conn, &claims.sub)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+
+    start_enrollment(conn, &user).await
+}
+
+/// 로그인 두 번째 단계. 코드가 맞으면 토큰을 발급한다.
+/// 등록이 필요한 대기 토큰이면 첫 코드로 등록을 마치고 복구 코드도 함께 준다.
+/// 틀린 코드는 비밀번호 실패와 같은 계정 잠금에 센다 (대기 토큰의 사용자 기준).
+pub async fn service_sign_in_two_factor<C>(
+    conn: &C,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    request: TwoFactorSignInRequest,
+) -> ServiceResult<TwoFactorSignInResponse>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let claims = decode_challenge(&request.challenge_token)?;
+    let user = repository_find_user_by_uuid(conn, &claims.sub)
+        .await?
+        .ok_or(Errors::UserNotFound)?;
+    check_login_lockout(&user.handle, ip_address.as_deref()).await?;
+
+    let enabled = find_two_factor(conn, user.id)
+        .await?
+        .is_some_and(|record| record.enabled_at.is_some());
+
+    let verified = if claims.enroll && !enabled {
+        let code = request.code.as_deref().ok_or_else(|| {
+            Errors::ValidationError("code is required to finish enrollment".to_string())
+        })?;
+        finish_enrollment(conn, user.id, code).await.map(Some)
+    } else {
+        verify_second_factor(
+            conn,
+            user.id,
+            request.code.as_deref(),
+            request.recovery_code.as_deref(),
+        )
+        .await
+        .map(|_| None)
+    };
+    let recovery_codes = match verified {
+        Ok(recovery_codes) => recovery_codes,
+        Err(Errors::TwoFactorInvalidCode) => {
+            record_login_failure(conn, &user.handle, Some(user.id), ip_address.as_deref()).await?;
+            return Err(Errors::TwoFactorInvalidCode);
+        }
+        Err(e) => return Err(e),
+    };
+    clear_login_failures(&user.handle, ip_address.as_deref()).await;
+
+    let tokens = issue_sign_in_tokens(conn, user.id, user_agent, ip_address).await?;
+
+    Ok(TwoFactorSignInResponse {
+        access_token: tokens.access_token,
+        recovery_codes,
+        cookie_refresh_token: tokens.cookie_refresh_token,
+    })
+}
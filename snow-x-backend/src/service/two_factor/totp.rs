@@ -0,0 +1,221 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// RFC 6238 기본값. 대부분의 인증 앱이 이 값만 지원한다
+const PERIOD: i64 = 30;
+const DIGITS: u32 = 6;
+const SECRET_LEN: usize = 20;
+/// 시계 오차를 감안해 앞뒤로 한 구간씩 더 받아준다
+const SKEW_STEPS: i64 = 1;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// 새 TOTP 비밀키 (Base32, 패딩 없음)
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; SECRET_LEN];
+    OsRng.fill_bytes(&mut bytes);
+    BASE32_NOPAD.encode(&bytes)
+}
+
+/// 인증 앱 등록용 `otpauth://` URI (QR 코드 내용)
+pub fn otpauth_uri(issuer: &str, account: &str, secret: &str) -> String {
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        percent_encode(issuer),
+        percent_encode(account),
+        secret,
+        percent_encode(issuer),
+        DIGITS,
+        PERIOD
+    )
+}
+
+/// 현재 시각의 시간 구간
+pub fn current_step(unix_time: i64) -> i64 {
+    unix_time.div_euclid(PERIOD)
+}
+
+/// 코드가 맞으면 그 코드의 시간 구간을 돌려준다.
+/// `last_used_step` 이하의 구간은 이미 쓴 코드로 보고 거절한다.
+pub fn verify_code(
+    secret: &str,
+    code: &str,
+    unix_time: i64,
+    last_used_step: Option<i64>,
+) -> Option<i64> {
+    let code = code.trim();
+    if code.len() != DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let key = BASE32_NOPAD.decode(secret.as_bytes()).ok()?;
+    let now = current_step(unix_time);
+
+    (now - SKEW_STEPS..=now + SKEW_STEPS)
+        .filter(|step| last_used_step.is_none_or(|last| *step > last))
+        .find(|step| constant_time_eq(hotp(&key, *step).as_bytes(), code.as_bytes()))
+}
+
+/// 일회용 복구 코드 (`xxxxx-xxxxx`)
+pub fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let mut bytes = [0u8; 10];
+            OsRng.fill_bytes(&mut bytes);
+            let chars: String = bytes
+                .iter()
+                .map(|b| RECOVERY_CODE_ALPHABET[*b as usize % RECOVERY_CODE_ALPHABET.len()] as char)
+                .collect();
+            format!("{}-{}", &chars[..5], &chars[5..])
+        })
+        .collect()
+}
+
+/// 복구 코드 해시. 대소문자, 공백, `-`는 구분하지 않는다
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    format!("{:x}", Sha256::digest(normalized.as_bytes()))
+}
+
+fn hotp(key: &[u8], step: i64) -> String {
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(&(step as u64).to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        binary % 10u32.pow(DIGITS),
+        width = DIGITS as usize
+    )
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// RFC 4226/6238 시험용 키 "12345678901234567890"
+    const RFC_KEY: &[u8] = b"12345678901234567890";
+
+    fn rfc_secret() -> String {
+        BASE32_NOPAD.encode(RFC_KEY)
+    }
+
+    #[test]
+    fn hotp_matches_rfc4226_vectors() {
+        let expected = [
+            "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583",
+            "399871", "520489",
+        ];
+        for (counter, code) in expected.iter().enumerate() {
+            assert_eq!(hotp(RFC_KEY, counter as i64), *code, "counter {}", counter);
+        }
+    }
+
+    #[test]
+    fn verify_code_matches_rfc6238_sha1_vectors() {
+        // RFC 6238 부록 B의 8자리 값 중 뒤 6자리
+        let secret = rfc_secret();
+        for (time, code) in [
+            (59, "287082"),
+            (1111111109, "081804"),
+            (1111111111, "050471"),
+            (1234567890, "005924"),
+            (2000000000, "279037"),
+            (20000000000, "353130"),
+        ] {
+            assert_eq!(
+                verify_code(&secret, code, time, None),
+                Some(current_step(time)),
+                "time {}",
+                time
+            );
+        }
+    }
+
+    #[test]
+    fn verify_code_allows_one_step_of_skew() {
+        let secret = rfc_secret();
+        let step = current_step(1234567890);
+        let code = hotp(RFC_KEY, step);
+
+        assert_eq!(
+            verify_code(&secret, &code, 1234567890 + PERIOD, None),
+            Some(step)
+        );
+        assert_eq!(
+            verify_code(&secret, &code, 1234567890 - PERIOD, None),
+            Some(step)
+        );
+        assert_eq!(
+            verify_code(&secret, &code, 1234567890 + 2 * PERIOD, None),
+            None
+        );
+    }
+
+    #[test]
+    fn verify_code_rejects_reused_and_malformed_codes() {
+        let secret = rfc_secret();
+        let step = current_step(59);
+
+        assert_eq!(verify_code(&secret, "287082", 59, Some(step)), None);
+        assert_eq!(
+            verify_code(&secret, "287082", 59, Some(step - 1)),
+            Some(step)
+        );
+        assert_eq!(verify_code(&secret, " 287082 ", 59, None), Some(step));
+        for code in ["28708", "2870822", "28708a", ""] {
+            assert_eq!(verify_code(&secret, code, 59, None), None, "{:?}", code);
+        }
+        assert_eq!(verify_code("not base32!", "287082", 59, None), None);
+    }
+
+    #[test]
+    fn recovery_codes_hash_ignores_formatting() {
+        let codes = generate_recovery_codes();
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        assert!(
+            codes
+                .iter()
+                .all(|code| code.len() == 11 && &code[5..6] == "-")
+        );
+
+        assert_eq!(
+            hash_recovery_code("abcde-fghjk"),
+            hash_recovery_code(" ABCDE fghjk ")
+        );
+        assert_ne!(
+            hash_recovery_code("abcde-fghjk"),
+            hash_recovery_code("abcde-fghjm")
+        );
+    }
+}
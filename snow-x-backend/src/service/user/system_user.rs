@@ -0,0 +1,61 @@
+use crate::config::db_config::DbConfig;
+use crate::repository::user::create_system_user::repository_create_system_user;
+use crate::repository::user::find_system_user::repository_find_system_user;
+use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+use std::sync::OnceLock;
+use tracing::info;
+use uuid::Uuid;
+
+static SYSTEM_USER_ID: OnceLock<Uuid> = OnceLock::new();
+
+/// 설정한 `SYSTEM_USER_ID`나 서버가 만든 `is_system` 사용자만 믿는다.
+/// handle과 email은 누구나 가입하며 고를 수 있으므로 찾는 데 쓰지 않는다.
+async fn find_system_user(conn: &DatabaseConnection) -> ServiceResult<Option<Uuid>> {
+    if let Some(id) = DbConfig::get().system_user_id.as_ref()
+        && let Some(user) = repository_find_user_by_uuid(conn, id).await?
+    {
+        return Ok(Some(user.id));
+    }
+    Ok(repository_find_system_user(conn).await?.map(|user| user.id))
+}
+
+/// 자동 작업(예약 동기화 등)이 만든 데이터의 작성자 ID.
+///
+/// `SYSTEM_USER_ID`, `is_system` 사용자 순으로 찾고, 없으면 로그인할 수 없는 Staff
+/// 사용자를 만든다. 설정한 handle/email을 이미 다른 사용자가 쓰고 있으면 만들지 못하고
+/// 오류를 돌려준다. 한 번 찾으면 프로세스 동안 캐시한다.
+pub async fn service_resolve_system_user_id(conn: &DatabaseConnection) -> ServiceResult<Uuid> {
+    if let Some(id) = SYSTEM_USER_ID.get() {
+        return Ok(*id);
+    }
+
+    let id = match find_system_user(conn).await? {
+        Some(id) => id,
+        None => {
+            let config = DbConfig::get();
+            match repository_create_system_user(
+                conn,
+                config.system_user_id,
+                &config.system_user_name,
+                &config.system_user_handle,
+                &config.system_user_email,
+            )
+            .await
+            {
+                Ok(user) => {
+                    info!(
+                        "Created system user '{}' ({}) for automated writes",
+                        user.handle, user.id
+                    );
+                    user.id
+                }
+                // 다른 인스턴스가 먼저 만들었으면 그 사용자를 쓴다
+                Err(err) => find_system_user(conn).await?.ok_or(err)?,
+            }
+        }
+    };
+
+    Ok(*SYSTEM_USER_ID.get_or_init(|| id))
+}
@@ -0,0 +1,377 @@
+use super::subscription::find_subscription;
+use super::{
+    CHANNEL_WEBHOOK_SUBSCRIPTION, DELIVERY_FAILED, DELIVERY_PENDING, DELIVERY_SUCCESS, EVENT_PING,
+    enqueue_delivery, schedule_delivery,
+};
+use crate::connection::outbound::OutboundHttpClient;
+use crate::dto::webhook::response::{
+    WebhookDeliveryAttemptResponse, WebhookDeliveryDetailResponse, WebhookDeliveryListResponse,
+    WebhookDeliveryResponse,
+};
+use crate::entity::{
+    notifications_outbox, webhook_deliveries, webhook_delivery_attempts, webhook_subscriptions,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::notification::channel::{
+    DeliveryError, DeliveryFuture, NotificationChannel, WEBHOOK_TIMEOUT, check_webhook_status,
+};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::header::CONTENT_TYPE;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+use serde_json::json;
+use sha2::Sha256;
+use std::time::Instant;
+use tracing::error;
+use uuid::Uuid;
+
+pub const HEADER_EVENT: &str = "X-SnowX-Event";
+pub const HEADER_DELIVERY: &str = "X-SnowX-Delivery";
+pub const HEADER_TIMESTAMP: &str = "X-SnowX-Timestamp";
+pub const HEADER_SIGNATURE: &str = "X-SnowX-Signature";
+
+/// 시도 기록에 남기는 응답 본문 길이 (문자 수).
+/// 구독자가 읽을 수 있으므로 보내기 전에 주소가 공인 주소인지 확인한 응답만 남는다.
+const RESPONSE_BODY_MAX_CHARS: usize = 1024;
+
+/// `sha256=` + hex(HMAC-SHA256(secret, "{timestamp}.{body}"))
+///
+/// 받는 쪽은 같은 방식으로 계산해 비교하고, 타임스탬프가 너무 오래된 요청은 버리면 된다.
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    format!("sha256={:x}", mac.finalize().into_bytes())
+}
+
+/// 웹훅 구독 배달을 보내는 outbox 채널.
+///
+/// outbox 행의 `payload.delivery_id`로 배달을 찾아 구독 주소로 서명해 보내고,
+/// 시도마다 `webhook_delivery_attempts`에 기록한다. 보내기 직전에 구독 주소가 가리키는
+/// 주소를 모두 확인하고, 리다이렉트는 따라가지 않는다 (`OutboundHttpClient`).
+pub struct SubscriptionWebhookChannel {
+    conn: DatabaseConnection,
+    http_client: OutboundHttpClient,
+}
+
+impl SubscriptionWebhookChannel {
+    pub fn new(conn: DatabaseConnection, http_client: OutboundHttpClient) -> Self {
+        Self { conn, http_client }
+    }
+
+    async fn send(&self, notification: &notifications_outbox::Model) -> Result<(), DeliveryError> {
+        let delivery_id = notification
+            .payload
+            .as_ref()
+            .and_then(|payload| payload.get("delivery_id"))
+            .and_then(|id| id.as_str())
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .ok_or_else(|| {
+                DeliveryError::Permanent("payload.delivery_id is missing".to_string())
+            })?;
+
+        let db_error = |e: sea_orm::DbErr| DeliveryError::Retryable(e.to_string());
+        let delivery = webhook_deliveries::Entity::find_by_id(delivery_id)
+            .one(&self.conn)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| {
+                DeliveryError::Permanent(format!("Webhook delivery {} was deleted", delivery_id))
+            })?;
+        let subscription = webhook_subscriptions::Entity::find_by_id(delivery.subscription_id)
+            .one(&self.conn)
+            .await
+            .map_err(db_error)?
+            .filter(|subscription| subscription.is_active);
+        let Some(subscription) = subscription else {
+            let error = DeliveryError::Permanent(
+                "Webhook subscription was deleted or deactivated".to_string(),
+            );
+            self.finish(notification, delivery, None, Some(&error))
+                .await
+                .map_err(|e| DeliveryError::Retryable(format!("{:?}", e)))?;
+            return Err(error);
+        };
+
+        let body = delivery.payload.to_string();
+        let timestamp = Utc::now().timestamp();
+        let started = Instant::now();
+        let sent = match self.http_client.check_url(&subscription.url).await {
+            Ok(url) => self
+                .http_client
+                .client()
+                .post(url)
+                .timeout(WEBHOOK_TIMEOUT)
+                .header(CONTENT_TYPE, "application/json")
+                .header(HEADER_EVENT, &delivery.event_type)
+                .header(HEADER_DELIVERY, delivery.id.to_string())
+                .header(HEADER_TIMESTAMP, timestamp.to_string())
+                .header(
+                    HEADER_SIGNATURE,
+                    sign_payload(&subscription.secret, timestamp, &body),
+                )
+                .body(body)
+                .send()
+                .await
+                .map_err(|e| DeliveryError::Retryable(format!("Webhook request failed: {}", e))),
+            Err(message) => Err(DeliveryError::Permanent(message)),
+        };
+
+        let (status, response_body, result) = match sent {
+            Ok(response) => {
+                let status = response.status();
+                let response_body = response
+                    .text()
+                    .await
+                    .ok()
+                    .map(|text| text.chars().take(RESPONSE_BODY_MAX_CHARS).collect());
+                (
+                    Some(status.as_u16() as i32),
+                    response_body,
+                    check_webhook_status(status),
+                )
+            }
+            Err(error) => (None, None, Err(error)),
+        };
+        let duration = started.elapsed().as_millis().min(i32::MAX as u128) as i32;
+
+        let attempt = webhook_delivery_attempts::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            delivery_id: Set(delivery.id),
+            attempt: Set(delivery.attempt_count + 1),
+            request_url: Set(subscription.url.clone()),
+            response_status: Set(status),
+            response_body: Set(response_body),
+            error: Set(result.as_ref().err().map(|e| e.message().to_string())),
+            duration: Set(duration),
+            attempted_at: Set(Utc::now().into()),
+        };
+        let recorded = async {
+            attempt.insert(&self.conn).await?;
+            self.finish(notification, delivery, status, result.as_ref().err())
+                .await
+        }
+        .await;
+        if let Err(e) = recorded {
+            error!("Failed to record webhook delivery attempt: {:?}", e);
+        }
+
+        result
+    }
+
+    /// 시도 결과를 배달 행에 반영한다. outbox가 더 재시도하지 않으면 실패로 끝낸다.
+    async fn finish(
+        &self,
+        notification: &notifications_outbox::Model,
+        delivery: webhook_deliveries::Model,
+        response_status: Option<i32>,
+        error: Option<&DeliveryError>,
+    ) -> ServiceResult<()> {
+        let now = Utc::now();
+        let attempt_count = delivery.attempt_count + 1;
+        let mut active: webhook_deliveries::ActiveModel = delivery.into();
+        active.attempt_count = Set(attempt_count);
+        active.response_status = Set(response_status);
+        active.updated_at = Set(now.into());
+
+        match error {
+            None => {
+                active.status = Set(DELIVERY_SUCCESS.to_string());
+                active.last_error = Set(None);
+                active.delivered_at = Set(Some(now.into()));
+            }
+            Some(error) => {
+                let will_retry =
+                    error.is_retryable() && notification.retry_count < notification.max_retries;
+                active.status = Set(if will_retry {
+                    DELIVERY_PENDING
+                } else {
+                    DELIVERY_FAILED
+                }
+                .to_string());
+                active.last_error = Set(Some(error.message().to_string()));
+            }
+        }
+
+        active.update(&self.conn).await?;
+        Ok(())
+    }
+}
+
+impl NotificationChannel for SubscriptionWebhookChannel {
+    fn name(&self) -> &'static str {
+        CHANNEL_WEBHOOK_SUBSCRIPTION
+    }
+
+    fn deliver<'a>(&'a self, notification: &'a notifications_outbox::Model) -> DeliveryFuture<'a> {
+        Box::pin(self.send(notification))
+    }
+}
+
+fn to_response(model: &webhook_deliveries::Model) -> WebhookDeliveryResponse {
+    WebhookDeliveryResponse {
+        id: model.id,
+        subscription_id: model.subscription_id,
+        event_id: model.event_id,
+        event_type: model.event_type.clone(),
+        status: model.status.clone(),
+        attempt_count: model.attempt_count,
+        response_status: model.response_status,
+        last_error: model.last_error.clone(),
+        created_at: model.created_at.into(),
+        updated_at: model.updated_at.into(),
+        delivered_at: model.delivered_at.map(Into::into),
+    }
+}
+
+async fn find_delivery(
+    conn: &DatabaseConnection,
+    subscription_id: Uuid,
+    delivery_id: Uuid,
+) -> ServiceResult<webhook_deliveries::Model> {
+    webhook_deliveries::Entity::find_by_id(delivery_id)
+        .filter(webhook_deliveries::Column::SubscriptionId.eq(subscription_id))
+        .one(conn)
+        .await?
+        .ok_or_else(|| Errors::NotFound("Webhook delivery not found".to_string()))
+}
+
+pub async fn service_get_deliveries(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    subscription_id: Uuid,
+    status: Option<String>,
+    page: u64,
+    limit: u64,
+) -> ServiceResult<WebhookDeliveryListResponse> {
+    let subscription = find_subscription(conn, user_id, tenant_id, subscription_id).await?;
+    let page = page.max(1);
+    let limit = limit.clamp(1, 100);
+
+    let mut query = webhook_deliveries::Entity::find()
+        .filter(webhook_deliveries::Column::SubscriptionId.eq(subscription.id));
+    if let Some(status) = status {
+        query = query.filter(webhook_deliveries::Column::Status.eq(status));
+    }
+
+    let paginator = query
+        .order_by_desc(webhook_deliveries::Column::CreatedAt)
+        .paginate(conn, limit);
+    let total = paginator.num_items().await?;
+    let deliveries = paginator.fetch_page(page - 1).await?;
+
+    Ok(WebhookDeliveryListResponse {
+        deliveries: deliveries.iter().map(to_response).collect(),
+        total,
+        page,
+        limit,
+    })
+}
+
+pub async fn service_get_delivery(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    subscription_id: Uuid,
+    delivery_id: Uuid,
+) -> ServiceResult<WebhookDeliveryDetailResponse> {
+    let subscription = find_subscription(conn, user_id, tenant_id, subscription_id).await?;
+    let delivery = find_delivery(conn, subscription.id, delivery_id).await?;
+    let attempts = webhook_delivery_attempts::Entity::find()
+        .filter(webhook_delivery_attempts::Column::DeliveryId.eq(delivery.id))
+        .order_by_asc(webhook_delivery_attempts::Column::AttemptedAt)
+        .all(conn)
+        .await?;
+
+    Ok(WebhookDeliveryDetailResponse {
+        delivery: to_response(&delivery),
+        payload: delivery.payload,
+        attempts: attempts
+            .into_iter()
+            .map(|attempt| WebhookDeliveryAttemptResponse {
+                attempt: attempt.attempt,
+                request_url: attempt.request_url,
+                response_status: attempt.response_status,
+                response_body: attempt.response_body,
+                error: attempt.error,
+                duration_ms: attempt.duration,
+                attempted_at: attempt.attempted_at.into(),
+            })
+            .collect(),
+    })
+}
+
+/// 끝난 배달을 같은 본문으로 다시 보낸다. 재시도 횟수는 처음부터 다시 센다.
+pub async fn service_redeliver(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    subscription_id: Uuid,
+    delivery_id: Uuid,
+) -> ServiceResult<WebhookDeliveryResponse> {
+    let subscription = find_subscription(conn, user_id, tenant_id, subscription_id).await?;
+    if !subscription.is_active {
+        return Err(Errors::BadRequestError(
+            "Webhook subscription is not active".to_string(),
+        ));
+    }
+    let delivery = find_delivery(conn, subscription.id, delivery_id).await?;
+    if delivery.status == DELIVERY_PENDING {
+        return Err(Errors::BadRequestError(
+            "Webhook delivery is still pending".to_string(),
+        ));
+    }
+
+    let txn = conn.begin().await?;
+    let mut active: webhook_deliveries::ActiveModel = delivery.into();
+    active.status = Set(DELIVERY_PENDING.to_string());
+    active.updated_at = Set(Utc::now().into());
+    let delivery =
+        schedule_delivery(&txn, subscription.tenant_id, active.update(&txn).await?).await?;
+    txn.commit().await?;
+
+    Ok(to_response(&delivery))
+}
+
+/// 구독 주소로 ping 이벤트를 보낸다
+pub async fn service_send_ping(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    subscription_id: Uuid,
+) -> ServiceResult<WebhookDeliveryResponse> {
+    let subscription = find_subscription(conn, user_id, tenant_id, subscription_id).await?;
+    if !subscription.is_active {
+        return Err(Errors::BadRequestError(
+            "Webhook subscription is not active".to_string(),
+        ));
+    }
+
+    let event_id = Uuid::new_v4();
+    let payload = json!({
+        "id": event_id,
+        "type": EVENT_PING,
+        "created_at": Utc::now(),
+        "actor_id": user_id,
+        "resource": {
+            "type": "webhook_subscription",
+            "id": subscription.id,
+            "name": subscription.name,
+        },
+        "data": {
+            "event_types": subscription.event_types,
+        },
+    });
+
+    let txn = conn.begin().await?;
+    let delivery = enqueue_delivery(&txn, &subscription, event_id, EVENT_PING, payload).await?;
+    txn.commit().await?;
+
+    Ok(to_response(&delivery))
+}
@@ -0,0 +1,360 @@
+//! 웹훅 구독. 사용자가 등록한 주소로 IPAM·블로그 이벤트를 HMAC-SHA256으로 서명해 보낸다.
+//!
+//! 구독은 테넌트 구성원만 다룰 수 있고, IPAM 이벤트는 해당 리소스의 읽기 권한이 있어야 구독할 수 있다.
+//! 구독은 만든 테넌트에 속하고, IPAM 이벤트는 리소스가 속한 테넌트의 구독에만 간다.
+//!
+//! 이벤트가 생기면 구독마다 `webhook_deliveries` 행과 `notifications_outbox` 행을 함께 만들고,
+//! 알림 발송 태스크가 `delivery::SubscriptionWebhookChannel`로 보낸다. 재시도와 재시작 후
+//! 이어 보내기는 outbox가 맡는다.
+
+pub mod delivery;
+pub mod subscription;
+
+pub use delivery::{
+    service_get_deliveries, service_get_delivery, service_redeliver, service_send_ping,
+};
+pub use subscription::{
+    service_create_subscription, service_delete_subscription, service_get_subscription,
+    service_get_subscriptions, service_rotate_secret, service_update_subscription,
+};
+
+use crate::config::db_config::DbConfig;
+use crate::dto::audit::response::AuditFieldChange;
+use crate::entity::{webhook_deliveries, webhook_subscriptions};
+use crate::service::audit::diff_snapshots;
+use crate::service::auth::permission::{AccessScope, PermissionAction, PermissionResource};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::notification::{self, CreateNotificationParams};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    TransactionTrait,
+};
+use serde::Serialize;
+use serde_json::{Value, json};
+use tracing::warn;
+use uuid::Uuid;
+
+/// 구독할 수 있는 이벤트 종류
+pub const EVENT_RACK_CREATED: &str = "rack_created";
+pub const EVENT_RACK_UPDATED: &str = "rack_updated";
+pub const EVENT_DEVICE_CREATED: &str = "device_created";
+pub const EVENT_DEVICE_UPDATED: &str = "device_updated";
+pub const EVENT_DEVICE_DELETED: &str = "device_deleted";
+pub const EVENT_IP_RANGE_CREATED: &str = "ip_range_created";
+pub const EVENT_IP_RANGE_UPDATED: &str = "ip_range_updated";
+pub const EVENT_IP_ALLOCATED: &str = "ip_allocated";
+pub const EVENT_POST_CREATED: &str = "post_created";
+
+pub const EVENT_TYPES: [&str; 9] = [
+    EVENT_RACK_CREATED,
+    EVENT_RACK_UPDATED,
+    EVENT_DEVICE_CREATED,
+    EVENT_DEVICE_UPDATED,
+    EVENT_DEVICE_DELETED,
+    EVENT_IP_RANGE_CREATED,
+    EVENT_IP_RANGE_UPDATED,
+    EVENT_IP_ALLOCATED,
+    EVENT_POST_CREATED,
+];
+
+/// IPAM 외 이벤트의 리소스 종류 (IPAM은 감사 로그의 `RESOURCE_*`를 쓴다)
+pub const RESOURCE_POST: &str = "post";
+
+/// 연결 확인용. 구독 필터와 상관없이 ping 요청을 보낸 구독에만 간다.
+pub const EVENT_PING: &str = "ping";
+
+/// 배달 상태
+pub const DELIVERY_PENDING: &str = "pending";
+pub const DELIVERY_SUCCESS: &str = "success";
+pub const DELIVERY_FAILED: &str = "failed";
+
+/// 웹훅 구독 배달용 outbox 채널
+pub const CHANNEL_WEBHOOK_SUBSCRIPTION: &str = "webhook_subscription";
+
+/// 발행할 이벤트 한 건
+pub struct WebhookEvent<'a> {
+    pub event_type: &'static str,
+    pub resource_type: &'static str,
+    pub resource_id: Uuid,
+    pub resource_name: Option<&'a str>,
+    pub actor_id: Option<Uuid>,
+    /// 리소스가 속한 테넌트. 테넌트가 없는 IPAM 이벤트는 어느 구독에도 가지 않는다
+    pub tenant_id: Option<Uuid>,
+    pub data: Value,
+}
+
+/// 수정 이벤트에 담을 바뀐 필드 목록 (감사 로그와 같은 형식)
+pub fn changed_fields<T: Serialize>(before: &T, after: &T) -> Vec<AuditFieldChange> {
+    let before = serde_json::to_value(before).ok();
+    let after = serde_json::to_value(after).ok();
+    diff_snapshots(before.as_ref(), after.as_ref())
+}
+
+/// 구독이 이 이벤트를 받는지
+fn subscribes_to(subscription: &webhook_subscriptions::Model, event_type: &str) -> bool {
+    subscription
+        .event_types
+        .as_array()
+        .is_some_and(|types| types.iter().any(|t| t.as_str() == Some(event_type)))
+}
+
+/// 구독에 이 이벤트를 배달하는지. IPAM 이벤트는 같은 테넌트의 구독에만 간다
+fn delivers_to(
+    subscription: &webhook_subscriptions::Model,
+    event_type: &str,
+    tenant_id: Option<Uuid>,
+) -> bool {
+    subscribes_to(subscription, event_type)
+        && (event_resource(event_type).is_none()
+            || tenant_id.is_some_and(|tenant_id| subscription.tenant_id == Some(tenant_id)))
+}
+
+/// 배달 행과 이를 보낼 outbox 행을 만든다
+async fn enqueue_delivery<C>(
+    conn: &C,
+    subscription: &webhook_subscriptions::Model,
+    event_id: Uuid,
+    event_type: &str,
+    payload: Value,
+) -> ServiceResult<webhook_deliveries::Model>
+where
+    C: ConnectionTrait,
+{
+    let now = Utc::now();
+    let delivery = webhook_deliveries::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        subscription_id: Set(subscription.id),
+        event_id: Set(event_id),
+        event_type: Set(event_type.to_string()),
+        payload: Set(payload),
+        status: Set(DELIVERY_PENDING.to_string()),
+        attempt_count: Set(0),
+        response_status: Set(None),
+        last_error: Set(None),
+        notification_id: Set(None),
+        created_at: Set(now.into()),
+        updated_at: Set(now.into()),
+        delivered_at: Set(None),
+    }
+    .insert(conn)
+    .await?;
+
+    schedule_delivery(conn, subscription.tenant_id, delivery).await
+}
+
+/// 배달을 맡을 outbox 행을 구독의 테넌트로 새로 만들고 배달에 연결한다
+async fn schedule_delivery<C>(
+    conn: &C,
+    tenant_id: Option<Uuid>,
+    delivery: webhook_deliveries::Model,
+) -> ServiceResult<webhook_deliveries::Model>
+where
+    C: ConnectionTrait,
+{
+    let notification = notification::service_create_notification(
+        conn,
+        CreateNotificationParams {
+            tenant_id,
+            channel: CHANNEL_WEBHOOK_SUBSCRIPTION.to_string(),
+            category: Some(delivery.event_type.clone()),
+            title: None,
+            message: None,
+            payload: Some(json!({
+                "delivery_id": delivery.id,
+                "subscription_id": delivery.subscription_id,
+            })),
+            scheduled_at: None,
+            max_retries: Some(DbConfig::get().webhook_max_retries.max(0)),
+        },
+    )
+    .await?;
+
+    let mut active: webhook_deliveries::ActiveModel = delivery.into();
+    active.notification_id = Set(Some(notification.id));
+    Ok(active.update(conn).await?)
+}
+
+/// 이벤트를 구독 중인 활성 구독마다 배달을 예약한다. 예약한 배달 수를 돌려준다.
+///
+/// 호출한 트랜잭션 안에서 실행하면 본 작업이 롤백될 때 배달도 함께 사라진다.
+pub async fn publish_webhook_event<C>(conn: &C, event: WebhookEvent<'_>) -> ServiceResult<usize>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let mut query = webhook_subscriptions::Entity::find()
+        .filter(webhook_subscriptions::Column::IsActive.eq(true));
+    if event_resource(event.event_type).is_some() {
+        let Some(tenant_id) = event.tenant_id else {
+            return Ok(0);
+        };
+        query = query.filter(webhook_subscriptions::Column::TenantId.eq(tenant_id));
+    }
+    let subscriptions: Vec<_> = query
+        .all(conn)
+        .await?
+        .into_iter()
+        .filter(|subscription| delivers_to(subscription, event.event_type, event.tenant_id))
+        .collect();
+    if subscriptions.is_empty() {
+        return Ok(0);
+    }
+
+    let event_id = Uuid::new_v4();
+    let payload = json!({
+        "id": event_id,
+        "type": event.event_type,
+        "created_at": Utc::now(),
+        "actor_id": event.actor_id,
+        "resource": {
+            "type": event.resource_type,
+            "id": event.resource_id,
+            "name": event.resource_name,
+        },
+        "data": event.data,
+    });
+
+    // 배달 행과 outbox 행이 한쪽만 남지 않도록 묶는다
+    let txn = conn.begin().await?;
+    for subscription in &subscriptions {
+        enqueue_delivery(
+            &txn,
+            subscription,
+            event_id,
+            event.event_type,
+            payload.clone(),
+        )
+        .await?;
+    }
+    txn.commit().await?;
+
+    Ok(subscriptions.len())
+}
+
+/// 본 작업을 실패시키지 않도록 웹훅 발행 오류는 경고로만 남긴다
+pub async fn publish_webhook_event_or_warn<C>(conn: &C, event: WebhookEvent<'_>)
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let event_type = event.event_type;
+    let resource_id = event.resource_id;
+    if let Err(e) = publish_webhook_event(conn, event).await {
+        warn!(
+            "Failed to publish webhook event {} for {}: {:?}",
+            event_type, resource_id, e
+        );
+    }
+}
+
+/// 이벤트를 받는 데 읽기 권한이 필요한 리소스. 블로그 이벤트는 `None`
+fn event_resource(event_type: &str) -> Option<PermissionResource> {
+    match event_type {
+        EVENT_RACK_CREATED | EVENT_RACK_UPDATED => Some(PermissionResource::Rack),
+        EVENT_DEVICE_CREATED | EVENT_DEVICE_UPDATED | EVENT_DEVICE_DELETED => {
+            Some(PermissionResource::Device)
+        }
+        EVENT_IP_RANGE_CREATED | EVENT_IP_RANGE_UPDATED => Some(PermissionResource::IpRange),
+        EVENT_IP_ALLOCATED => Some(PermissionResource::IpAddress),
+        _ => None,
+    }
+}
+
+/// 구독하려는 이벤트의 리소스를 모두 읽을 수 있는지 확인한다
+fn require_event_access(scope: &AccessScope, event_types: &[String]) -> ServiceResult<()> {
+    for resource in event_types.iter().filter_map(|t| event_resource(t)) {
+        scope.require(resource, PermissionAction::Read)?;
+    }
+    Ok(())
+}
+
+/// 요청한 이벤트 종류 목록을 검사해 중복을 없앤다
+fn normalize_event_types(event_types: Vec<String>) -> ServiceResult<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::new();
+    for event_type in event_types {
+        let event_type = event_type.trim().to_string();
+        if !EVENT_TYPES.contains(&event_type.as_str()) {
+            return Err(Errors::ValidationError(format!(
+                "Unknown event type '{}'. Expected one of: {}",
+                event_type,
+                EVENT_TYPES.join(", ")
+            )));
+        }
+        if !normalized.contains(&event_type) {
+            normalized.push(event_type);
+        }
+    }
+    if normalized.is_empty() {
+        return Err(Errors::ValidationError(
+            "event_types must contain at least one event type".to_string(),
+        ));
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_ipam_event_requires_read_permission() {
+        for event_type in EVENT_TYPES {
+            assert_eq!(
+                event_resource(event_type).is_none(),
+                event_type == EVENT_POST_CREATED,
+                "{event_type}"
+            );
+        }
+        assert_eq!(
+            event_resource(EVENT_IP_ALLOCATED),
+            Some(PermissionResource::IpAddress)
+        );
+    }
+
+    fn subscription(tenant_id: Uuid, event_types: &[&str]) -> webhook_subscriptions::Model {
+        let now = Utc::now().into();
+        webhook_subscriptions::Model {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            tenant_id: Some(tenant_id),
+            name: "hook".to_string(),
+            url: "https://example.com/hook".to_string(),
+            description: None,
+            secret: "whsec_test".to_string(),
+            event_types: json!(event_types),
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn ipam_events_reach_only_subscriptions_of_the_same_tenant() {
+        let tenant_a = Uuid::new_v4();
+        let tenant_b = Uuid::new_v4();
+        let subscriber_a = subscription(tenant_a, &[EVENT_DEVICE_CREATED]);
+        let subscriber_b = subscription(tenant_b, &[EVENT_DEVICE_CREATED]);
+
+        assert!(delivers_to(
+            &subscriber_a,
+            EVENT_DEVICE_CREATED,
+            Some(tenant_a)
+        ));
+        assert!(!delivers_to(
+            &subscriber_b,
+            EVENT_DEVICE_CREATED,
+            Some(tenant_a)
+        ));
+        assert!(!delivers_to(&subscriber_a, EVENT_DEVICE_CREATED, None));
+        assert!(!delivers_to(
+            &subscriber_a,
+            EVENT_DEVICE_UPDATED,
+            Some(tenant_a)
+        ));
+    }
+
+    #[test]
+    fn blog_events_reach_every_tenant() {
+        let subscriber = subscription(Uuid::new_v4(), &[EVENT_POST_CREATED]);
+        assert!(delivers_to(&subscriber, EVENT_POST_CREATED, None));
+    }
+}
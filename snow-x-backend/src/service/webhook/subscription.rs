@@ -0,0 +1,241 @@
+use super::{normalize_event_types, require_event_access};
+use crate::config::db_config::DbConfig;
+use crate::connection::outbound::is_private_host;
+use crate::dto::webhook::request::{
+    CreateWebhookSubscriptionRequest, UpdateWebhookSubscriptionRequest,
+};
+use crate::dto::webhook::response::{
+    WebhookSubscriptionListResponse, WebhookSubscriptionResponse, WebhookSubscriptionSecretResponse,
+};
+use crate::entity::webhook_subscriptions;
+use crate::service::auth::permission::AccessScope;
+use crate::service::error::errors::{Errors, ServiceResult};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use chrono::Utc;
+use reqwest::Url;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+const NAME_MAX_LEN: usize = 100;
+const SECRET_PREFIX: &str = "whsec_";
+
+fn generate_secret() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}{}", SECRET_PREFIX, hex)
+}
+
+/// http(s) 주소인지, 내부망 주소가 아닌지 확인한다.
+/// `WEBHOOK_ALLOW_PRIVATE_URLS=true`면 내부망 주소도 허용한다.
+/// 이름이 가리키는 주소는 배달할 때마다 다시 확인한다 (`SubscriptionWebhookChannel`).
+fn validate_url(url: &str) -> ServiceResult<String> {
+    let url = url.trim();
+    let parsed = Url::parse(url)
+        .ok()
+        .filter(|parsed| matches!(parsed.scheme(), "http" | "https"))
+        .ok_or_else(|| Errors::ValidationError(format!("Invalid webhook URL '{}'", url)))?;
+
+    if !DbConfig::get().webhook_allow_private_urls && is_private_host(&parsed) {
+        return Err(Errors::ValidationError(
+            "Webhook URL must not point to a local or private address".to_string(),
+        ));
+    }
+
+    Ok(url.to_string())
+}
+
+fn validate_name(name: &str) -> ServiceResult<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Errors::ValidationError("name is required".to_string()));
+    }
+    if name.chars().count() > NAME_MAX_LEN {
+        return Err(Errors::ValidationError(format!(
+            "name must be at most {} characters",
+            NAME_MAX_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+pub(super) fn to_response(model: webhook_subscriptions::Model) -> WebhookSubscriptionResponse {
+    let event_types = model
+        .event_types
+        .as_array()
+        .map(|types| {
+            types
+                .iter()
+                .filter_map(|t| t.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    WebhookSubscriptionResponse {
+        id: model.id,
+        name: model.name,
+        url: model.url,
+        description: model.description,
+        event_types,
+        is_active: model.is_active,
+        created_at: model.created_at.into(),
+        updated_at: model.updated_at.into(),
+    }
+}
+
+fn to_secret_response(model: webhook_subscriptions::Model) -> WebhookSubscriptionSecretResponse {
+    let secret = model.secret.clone();
+    WebhookSubscriptionSecretResponse {
+        subscription: to_response(model),
+        secret,
+    }
+}
+
+/// 사용자가 지금 테넌트에서 만든 구독을 찾는다. 다른 사용자나 다른 테넌트의 구독은 없는 것으로 본다.
+pub(super) async fn find_subscription(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    id: Uuid,
+) -> ServiceResult<webhook_subscriptions::Model> {
+    webhook_subscriptions::Entity::find_by_id(id)
+        .filter(webhook_subscriptions::Column::UserId.eq(user_id))
+        .filter(webhook_subscriptions::Column::TenantId.eq(tenant_id))
+        .one(conn)
+        .await?
+        .ok_or_else(|| Errors::NotFound("Webhook subscription not found".to_string()))
+}
+
+pub async fn service_get_subscriptions(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    page: u64,
+    limit: u64,
+) -> ServiceResult<WebhookSubscriptionListResponse> {
+    let page = page.max(1);
+    let limit = limit.clamp(1, 100);
+
+    let paginator = webhook_subscriptions::Entity::find()
+        .filter(webhook_subscriptions::Column::UserId.eq(user_id))
+        .filter(webhook_subscriptions::Column::TenantId.eq(tenant_id))
+        .order_by_desc(webhook_subscriptions::Column::CreatedAt)
+        .paginate(conn, limit);
+    let total = paginator.num_items().await?;
+    let subscriptions = paginator.fetch_page(page - 1).await?;
+
+    Ok(WebhookSubscriptionListResponse {
+        subscriptions: subscriptions.into_iter().map(to_response).collect(),
+        total,
+        page,
+        limit,
+    })
+}
+
+pub async fn service_get_subscription(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    id: Uuid,
+) -> ServiceResult<WebhookSubscriptionResponse> {
+    find_subscription(conn, user_id, tenant_id, id)
+        .await
+        .map(to_response)
+}
+
+/// 지금 테넌트에 구독을 만든다. 이벤트 종류마다 해당 리소스의 읽기 권한이 있어야 한다
+pub async fn service_create_subscription(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    scope: &AccessScope,
+    request: CreateWebhookSubscriptionRequest,
+) -> ServiceResult<WebhookSubscriptionSecretResponse> {
+    let name = validate_name(&request.name)?;
+    let url = validate_url(&request.url)?;
+    let event_types = normalize_event_types(request.event_types)?;
+    require_event_access(scope, &event_types)?;
+
+    let now = Utc::now();
+    let created = webhook_subscriptions::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(user_id),
+        tenant_id: Set(Some(scope.tenant_id)),
+        name: Set(name),
+        url: Set(url),
+        description: Set(request.description.filter(|d| !d.trim().is_empty())),
+        secret: Set(generate_secret()),
+        event_types: Set(json!(event_types)),
+        is_active: Set(request.is_active.unwrap_or(true)),
+        created_at: Set(now.into()),
+        updated_at: Set(now.into()),
+    }
+    .insert(conn)
+    .await?;
+
+    Ok(to_secret_response(created))
+}
+
+pub async fn service_update_subscription(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    scope: &AccessScope,
+    id: Uuid,
+    request: UpdateWebhookSubscriptionRequest,
+) -> ServiceResult<WebhookSubscriptionResponse> {
+    let existing = find_subscription(conn, user_id, scope.tenant_id, id).await?;
+    let mut active: webhook_subscriptions::ActiveModel = existing.into();
+
+    if let Some(name) = request.name {
+        active.name = Set(validate_name(&name)?);
+    }
+    if let Some(url) = request.url {
+        active.url = Set(validate_url(&url)?);
+    }
+    if let Some(description) = request.description {
+        active.description = Set(Some(description).filter(|d| !d.trim().is_empty()));
+    }
+    if let Some(event_types) = request.event_types {
+        let event_types = normalize_event_types(event_types)?;
+        require_event_access(scope, &event_types)?;
+        active.event_types = Set(json!(event_types));
+    }
+    if let Some(is_active) = request.is_active {
+        active.is_active = Set(is_active);
+    }
+    active.updated_at = Set(Utc::now().into());
+
+    Ok(to_response(active.update(conn).await?))
+}
+
+/// 구독과 배달 기록을 삭제한다. 아직 보내지 않은 배달은 발송 시 실패로 끝난다.
+pub async fn service_delete_subscription(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    id: Uuid,
+) -> ServiceResult<()> {
+    let existing = find_subscription(conn, user_id, tenant_id, id).await?;
+    webhook_subscriptions::Entity::delete_by_id(existing.id)
+        .exec(conn)
+        .await?;
+    Ok(())
+}
+
+/// 서명 키를 새로 발급한다. 이전 키로 서명된 요청은 더 이상 보내지 않는다.
+pub async fn service_rotate_secret(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    tenant_id: Uuid,
+    id: Uuid,
+) -> ServiceResult<WebhookSubscriptionSecretResponse> {
+    let existing = find_subscription(conn, user_id, tenant_id, id).await?;
+    let mut active: webhook_subscriptions::ActiveModel = existing.into();
+    active.secret = Set(generate_secret());
+    active.updated_at = Set(Utc::now().into());
+
+    Ok(to_secret_response(active.update(conn).await?))
+}
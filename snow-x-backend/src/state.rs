@@ -1,5 +1,7 @@
 use crate::connection::cloudflare_r2::R2Client;
 use crate::connection::meilisearch::MeilisearchClient;
+use crate::connection::outbound::OutboundHttpClient;
+use crate::service::realtime::RealtimeHub;
 use redis::aio::ConnectionManager;
 use reqwest::Client;
 use sea_orm::DatabaseConnection;
@@ -10,5 +12,8 @@ pub struct AppState {
     pub cloudflare_r2: R2Client,
     pub redis: ConnectionManager,
     pub http_client: Client,
+    /// 외부 API 연결로 보내는 클라이언트 (내부망 주소 차단, 리다이렉트 안 따라감)
+    pub external_api_client: OutboundHttpClient,
     pub meilisearch: MeilisearchClient,
+    pub realtime: RealtimeHub,
 }
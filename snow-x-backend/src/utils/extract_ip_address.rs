@@ -1,16 +1,166 @@
+use crate::config::db_config::DbConfig;
+use crate::utils::ip_math::{IpFamily, IpNetwork};
 use axum::http::HeaderMap;
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Once;
+use tracing::warn;
 
+/// 클라이언트 IP를 담는 전달 헤더
+const FORWARDING_HEADERS: [&str; 2] = ["CF-Connecting-IP", "X-Forwarded-For"];
+
+static UNTRUSTED_FORWARDING_WARNING: Once = Once::new();
+
+/// 쉼표로 구분한 프록시 주소·대역 목록 (`10.0.0.0/8, 127.0.0.1`). 잘못된 항목은 건너뛴다
+pub fn parse_trusted_proxies(value: &str) -> Vec<IpNetwork> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let (addr, prefix) = entry.split_once('/').unwrap_or((entry, ""));
+            let network = addr.trim().parse::<IpAddr>().ok().and_then(|addr| {
+                let prefix = match prefix.trim() {
+                    "" => IpFamily::of(&addr).bits(),
+                    prefix => prefix.parse().ok()?,
+                };
+                IpNetwork::new(addr, prefix).ok()
+            });
+            if network.is_none() {
+                warn!("Ignoring invalid TRUSTED_PROXIES entry '{}'", entry);
+            }
+            network
+        })
+        .collect()
+}
+
+/// 클라이언트 IP. 연결한 상대가 믿는 프록시일 때만 `CF-Connecting-IP`, `X-Forwarded-For`를 읽는다.
 pub fn extract_ip_address(headers: &HeaderMap, addr: SocketAddr) -> String {
-    headers
+    let trusted_proxies = &DbConfig::get().trusted_proxies;
+    if trusted_proxies.is_empty() {
+        warn_untrusted_forwarding(headers, addr.ip());
+    }
+    client_ip(headers, addr.ip(), trusted_proxies).to_string()
+}
+
+/// `TRUSTED_PROXIES` 없이 프록시 뒤에서 돌리면 모든 요청이 프록시 주소 하나로 보여
+/// 요청 제한과 로그인 잠금이 사용자 전체에 걸린다. 처음 본 전달 헤더에서 한 번만 알린다
+fn warn_untrusted_forwarding(headers: &HeaderMap, peer: IpAddr) {
+    if !FORWARDING_HEADERS
+        .iter()
+        .any(|name| headers.contains_key(*name))
+    {
+        return;
+    }
+    UNTRUSTED_FORWARDING_WARNING.call_once(|| {
+        warn!(
+            "Ignoring {} headers from {} because TRUSTED_PROXIES is empty. \
+             If the server runs behind a proxy or load balancer, set TRUSTED_PROXIES to its address range",
+            FORWARDING_HEADERS.join("/"),
+            peer
+        );
+    });
+}
+
+fn client_ip(headers: &HeaderMap, peer: IpAddr, trusted_proxies: &[IpNetwork]) -> IpAddr {
+    let is_trusted = |ip: &IpAddr| trusted_proxies.iter().any(|proxy| proxy.contains_ip(ip));
+    if !is_trusted(&peer) {
+        return peer;
+    }
+
+    if let Some(ip) = headers
         .get("CF-Connecting-IP")
         .and_then(|v| v.to_str().ok())
-        .map(ToString::to_string)
-        .or_else(|| {
-            headers
-                .get("X-Forwarded-For")
-                .and_then(|v| v.to_str().ok())
-                .map(|s| s.split(',').next().unwrap_or("").trim().to_string())
-        })
-        .unwrap_or_else(|| addr.ip().to_string())
+        .and_then(|v| v.trim().parse().ok())
+    {
+        return ip;
+    }
+
+    // 뒤에서부터 믿는 프록시를 건너뛰고 처음 만나는 주소가 클라이언트다.
+    // 앞쪽 항목은 클라이언트가 마음대로 넣을 수 있다.
+    let mut client = peer;
+    let forwarded = headers
+        .get_all("X-Forwarded-For")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .collect::<Vec<_>>();
+    for entry in forwarded.iter().rev() {
+        let Ok(ip) = entry.trim().parse::<IpAddr>() else {
+            break;
+        };
+        client = ip;
+        if !is_trusted(&ip) {
+            break;
+        }
+    }
+    client
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn ignores_forwarding_headers_from_untrusted_peers() {
+        let proxies = parse_trusted_proxies("10.0.0.0/8");
+        let spoofed = headers(&[
+            ("CF-Connecting-IP", "1.2.3.4"),
+            ("X-Forwarded-For", "5.6.7.8"),
+        ]);
+        assert_eq!(
+            client_ip(&spoofed, ip("203.0.113.7"), &proxies),
+            ip("203.0.113.7")
+        );
+        assert_eq!(
+            client_ip(&spoofed, ip("203.0.113.7"), &[]),
+            ip("203.0.113.7")
+        );
+    }
+
+    #[test]
+    fn reads_forwarding_headers_from_trusted_proxies() {
+        let proxies = parse_trusted_proxies("10.0.0.0/8, 192.168.1.1");
+        assert_eq!(
+            client_ip(
+                &headers(&[("CF-Connecting-IP", "1.2.3.4")]),
+                ip("10.1.1.1"),
+                &proxies
+            ),
+            ip("1.2.3.4")
+        );
+        // 클라이언트가 넣은 앞쪽 항목이 아니라, 믿는 프록시 바로 앞 주소를 쓴다
+        assert_eq!(
+            client_ip(
+                &headers(&[("X-Forwarded-For", "9.9.9.9, 5.6.7.8, 10.2.2.2")]),
+                ip("192.168.1.1"),
+                &proxies
+            ),
+            ip("5.6.7.8")
+        );
+        assert_eq!(
+            client_ip(&headers(&[]), ip("10.1.1.1"), &proxies),
+            ip("10.1.1.1")
+        );
+    }
+
+    #[test]
+    fn parses_trusted_proxy_list() {
+        let proxies = parse_trusted_proxies("10.0.0.0/8, 127.0.0.1, ::1, bogus, 10.0.0.0/99");
+        assert_eq!(proxies.len(), 3);
+        assert!(proxies[1].contains_ip(&ip("127.0.0.1")));
+        assert!(!proxies[1].contains_ip(&ip("127.0.0.2")));
+        assert!(proxies[2].contains_ip(&ip("::1")));
+    }
 }
@@ -0,0 +1,504 @@
+use crate::service::error::errors::{Errors, ServiceResult};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+// IPv4/IPv6 공용 주소 계산 모듈
+// 모든 주소는 u128 정수로 변환하여 계산하고, 패밀리(IpFamily)로 비트 폭을 구분한다.
+
+pub const IPV4_MIN_PREFIX: u8 = 8;
+pub const IPV6_MIN_PREFIX: u8 = 48;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpFamily {
+    V4,
+    V6,
+}
+
+impl IpFamily {
+    pub fn from_version(version: i32) -> ServiceResult<Self> {
+        match version {
+            4 => Ok(IpFamily::V4),
+            6 => Ok(IpFamily::V6),
+            _ => Err(Errors::BadRequestError(
+                "IP version must be 4 or 6".to_string(),
+            )),
+        }
+    }
+
+    pub fn of(addr: &IpAddr) -> Self {
+        match addr {
+            IpAddr::V4(_) => IpFamily::V4,
+            IpAddr::V6(_) => IpFamily::V6,
+        }
+    }
+
+    pub fn version(self) -> i32 {
+        match self {
+            IpFamily::V4 => 4,
+            IpFamily::V6 => 6,
+        }
+    }
+
+    pub fn bits(self) -> u8 {
+        match self {
+            IpFamily::V4 => 32,
+            IpFamily::V6 => 128,
+        }
+    }
+
+    pub fn min_prefix(self) -> u8 {
+        match self {
+            IpFamily::V4 => IPV4_MIN_PREFIX,
+            IpFamily::V6 => IPV6_MIN_PREFIX,
+        }
+    }
+
+    /// 패밀리별 허용 범위(IPv4 /8~/32, IPv6 /48~/128) 안의 prefix 길이인지 검사
+    pub fn validate_prefix(self, prefix: i32) -> ServiceResult<u8> {
+        let min = self.min_prefix() as i32;
+        let max = self.bits() as i32;
+
+        if prefix < min || prefix > max {
+            return Err(Errors::BadRequestError(format!(
+                "Subnet mask must be between {} and {} for IPv{}",
+                min,
+                max,
+                self.version()
+            )));
+        }
+
+        Ok(prefix as u8)
+    }
+
+    fn full_mask(self) -> u128 {
+        match self {
+            IpFamily::V4 => u32::MAX as u128,
+            IpFamily::V6 => u128::MAX,
+        }
+    }
+}
+
+pub fn parse_ip(value: &str) -> ServiceResult<IpAddr> {
+    value
+        .trim()
+        .parse::<IpAddr>()
+        .map_err(|_| Errors::BadRequestError(format!("Invalid IP address: {}", value)))
+}
+
+pub fn ip_to_number(addr: &IpAddr) -> u128 {
+    match addr {
+        IpAddr::V4(v4) => u32::from(*v4) as u128,
+        IpAddr::V6(v6) => u128::from(*v6),
+    }
+}
+
+pub fn number_to_ip(value: u128, family: IpFamily) -> IpAddr {
+    match family {
+        IpFamily::V4 => IpAddr::V4(Ipv4Addr::from(value as u32)),
+        IpFamily::V6 => IpAddr::V6(Ipv6Addr::from(value)),
+    }
+}
+
+/// u128 개수를 i64 응답 필드로 변환 (IPv6 대역은 i64 범위를 넘을 수 있어 포화 처리)
+pub fn saturating_i64(value: u128) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpNetwork {
+    family: IpFamily,
+    network: u128,
+    prefix: u8,
+}
+
+impl IpNetwork {
+    /// 주소와 prefix로 네트워크를 만든다. 호스트 비트는 0으로 정규화된다.
+    pub fn new(addr: IpAddr, prefix: u8) -> ServiceResult<Self> {
+        let family = IpFamily::of(&addr);
+        if prefix > family.bits() {
+            return Err(Errors::BadRequestError(format!(
+                "Invalid prefix length /{} for IPv{}",
+                prefix,
+                family.version()
+            )));
+        }
+
+        Ok(Self {
+            family,
+            network: ip_to_number(&addr) & prefix_mask(family, prefix),
+            prefix,
+        })
+    }
+
+    /// 문자열 주소/서브넷 마스크/IP 버전을 함께 검증하여 네트워크를 만든다.
+    pub fn parse(address: &str, subnet_mask: i32, ip_version: i32) -> ServiceResult<Self> {
+        let family = IpFamily::from_version(ip_version)?;
+        let addr = parse_ip(address)?;
+
+        if IpFamily::of(&addr) != family {
+            return Err(Errors::BadRequestError(format!(
+                "Network address {} is not an IPv{} address",
+                address,
+                family.version()
+            )));
+        }
+
+        let prefix = family.validate_prefix(subnet_mask)?;
+        Self::new(addr, prefix)
+    }
+
+    /// 호스트 비트가 설정되지 않은(prefix 경계에 정렬된) 주소인지 검사
+    pub fn is_aligned(addr: &IpAddr, prefix: u8) -> bool {
+        let family = IpFamily::of(addr);
+        prefix <= family.bits() && ip_to_number(addr) & !prefix_mask(family, prefix) == 0
+    }
+
+    pub fn family(&self) -> IpFamily {
+        self.family
+    }
+
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    pub fn network_ip(&self) -> IpAddr {
+        number_to_ip(self.network, self.family)
+    }
+
+    pub fn first(&self) -> u128 {
+        self.network
+    }
+
+    pub fn last(&self) -> u128 {
+        self.network | (!prefix_mask(self.family, self.prefix) & self.family.full_mask())
+    }
+
+    /// 호스트에 할당 가능한 주소 구간 (양 끝 포함)
+    /// IPv4는 /30 이하에서 네트워크/브로드캐스트 주소를 제외하고, /31(RFC 3021)과 /32는 전체를 사용한다.
+    /// IPv6에는 브로드캐스트가 없으므로 네트워크 주소(Subnet-Router anycast)만 제외한다.
+    pub fn usable_bounds(&self) -> (u128, u128) {
+        match self.family {
+            IpFamily::V4 if self.prefix <= 30 => (self.first() + 1, self.last() - 1),
+            IpFamily::V6 if self.prefix < 128 => (self.first() + 1, self.last()),
+            _ => (self.first(), self.last()),
+        }
+    }
+
+    /// 할당 가능한 호스트 수
+    pub fn host_capacity(&self) -> u128 {
+        let (start, end) = self.usable_bounds();
+        end - start + 1
+    }
+
+    pub fn contains(&self, value: u128) -> bool {
+        value >= self.first() && value <= self.last()
+    }
+
+    pub fn contains_ip(&self, addr: &IpAddr) -> bool {
+        IpFamily::of(addr) == self.family && self.contains(ip_to_number(addr))
+    }
+
+    /// other 네트워크가 이 네트워크 안에 완전히 포함되는지 (같은 네트워크 포함)
+    pub fn contains_network(&self, other: &IpNetwork) -> bool {
+        self.family == other.family && self.first() <= other.first() && other.last() <= self.last()
+    }
+
+    /// other 네트워크가 이 네트워크의 하위 대역인지 (같은 네트워크 제외)
+    pub fn is_supernet_of(&self, other: &IpNetwork) -> bool {
+        self.prefix < other.prefix && self.contains_network(other)
+    }
+
+    /// 이 네트워크를 prefix 크기로 나눈 하위 네트워크를 주소 순으로 순회한다.
+    pub fn subnets(&self, prefix: u8) -> impl Iterator<Item = IpNetwork> + use<> {
+        let family = self.family;
+        let last = self.last();
+        // prefix가 범위를 벗어나면 아래에서 빈 순회가 되므로 빼기만 넘치지 않게 한다
+        let step = 1u128
+            .checked_shl(family.bits().saturating_sub(prefix) as u32)
+            .unwrap_or(u128::MAX);
+        let mut next = (prefix >= self.prefix && prefix <= family.bits()).then_some(self.network);
+
+        std::iter::from_fn(move || {
+            let network = next?;
+            next = network.checked_add(step).filter(|n| *n <= last);
+            Some(IpNetwork {
+                family,
+                network,
+                prefix,
+            })
+        })
+    }
+
+    pub fn overlaps(&self, other: &IpNetwork) -> bool {
+        self.family == other.family && self.first() <= other.last() && other.first() <= self.last()
+    }
+}
+
+fn prefix_mask(family: IpFamily, prefix: u8) -> u128 {
+    let host_bits = (family.bits() - prefix) as u32;
+    let mask = u128::MAX.checked_shl(host_bits).unwrap_or(0);
+    mask & family.full_mask()
+}
+
+/// [start, end] 구간에서 occupied 구간들을 제외한 빈 구간 목록을 오름차순으로 반환한다.
+/// 점유 목록 크기에만 비례하므로 큰 IPv6 대역에서도 주소를 하나씩 열거하지 않는다.
+pub fn free_intervals(start: u128, end: u128, occupied: &[(u128, u128)]) -> Vec<(u128, u128)> {
+    let mut sorted: Vec<(u128, u128)> = occupied
+        .iter()
+        .copied()
+        .filter(|(s, e)| s <= e && *e >= start && *s <= end)
+        .collect();
+    sorted.sort_unstable();
+
+    let mut free = Vec::new();
+    let mut cursor = Some(start);
+
+    for (s, e) in sorted {
+        let Some(current) = cursor else { break };
+        if s > current {
+            free.push((current, s - 1));
+        }
+        if e >= current {
+            cursor = e.checked_add(1);
+        }
+    }
+
+    if let Some(current) = cursor
+        && current <= end
+    {
+        free.push((current, end));
+    }
+
+    free
+}
+
+/// 연속된 count개의 빈 주소가 시작되는 가장 낮은 위치를 찾는다.
+pub fn find_free_run(
+    start: u128,
+    end: u128,
+    occupied: &[(u128, u128)],
+    count: u128,
+) -> Option<u128> {
+    free_intervals(start, end, occupied)
+        .into_iter()
+        .find(|(s, e)| e - s + 1 >= count)
+        .map(|(s, _)| s)
+}
+
+/// [start, end] 구간을 정렬된 최대 크기의 CIDR 블록 목록으로 분해한다.
+pub fn cidr_blocks(start: u128, end: u128, family: IpFamily) -> Vec<IpNetwork> {
+    let bits = family.bits() as u32;
+    let mut blocks = Vec::new();
+    let mut cursor = start;
+
+    loop {
+        // 정렬 조건: cursor의 하위 0 비트 수만큼의 블록까지 가능
+        let mut host_bits = cursor.trailing_zeros().min(bits);
+        // 블록이 end를 넘지 않도록 축소
+        while host_bits > 0 && block_last(cursor, host_bits) > end {
+            host_bits -= 1;
+        }
+
+        blocks.push(IpNetwork {
+            family,
+            network: cursor,
+            prefix: (bits - host_bits) as u8,
+        });
+
+        let last = block_last(cursor, host_bits);
+        if last >= end {
+            break;
+        }
+        cursor = last + 1;
+    }
+
+    blocks
+}
+
+fn block_last(network: u128, host_bits: u32) -> u128 {
+    network
+        | 1u128
+            .checked_shl(host_bits)
+            .map_or(u128::MAX, |size| size - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn net(cidr: &str) -> IpNetwork {
+        let (addr, prefix) = cidr.split_once('/').unwrap();
+        IpNetwork::new(addr.parse().unwrap(), prefix.parse().unwrap()).unwrap()
+    }
+
+    fn num(addr: &str) -> u128 {
+        ip_to_number(&addr.parse().unwrap())
+    }
+
+    #[test]
+    fn validates_prefix_per_family() {
+        assert_eq!(IpFamily::V4.validate_prefix(24).unwrap(), 24);
+        assert_eq!(IpFamily::V6.validate_prefix(64).unwrap(), 64);
+        assert!(IpFamily::V4.validate_prefix(7).is_err());
+        assert!(IpFamily::V4.validate_prefix(33).is_err());
+        assert!(IpFamily::V6.validate_prefix(47).is_err());
+        assert!(IpFamily::V6.validate_prefix(129).is_err());
+    }
+
+    #[test]
+    fn parse_checks_family_and_normalizes_host_bits() {
+        let network = IpNetwork::parse("2001:db8::", 64, 6).unwrap();
+        assert_eq!(
+            network.network_ip(),
+            "2001:db8::".parse::<IpAddr>().unwrap()
+        );
+        assert!(IpNetwork::parse("10.0.0.0", 24, 6).is_err());
+        assert!(IpNetwork::parse("2001:db8::", 64, 4).is_err());
+        assert!(IpNetwork::parse("not-an-ip", 24, 4).is_err());
+
+        assert_eq!(
+            net("10.1.2.3/24").network_ip(),
+            "10.1.2.0".parse::<IpAddr>().unwrap()
+        );
+        assert!(IpNetwork::is_aligned(&"10.1.2.0".parse().unwrap(), 24));
+        assert!(!IpNetwork::is_aligned(&"10.1.2.3".parse().unwrap(), 24));
+        assert!(IpNetwork::new("10.0.0.0".parse().unwrap(), 33).is_err());
+    }
+
+    #[test]
+    fn ipv4_usable_bounds() {
+        let network = net("192.168.1.0/24");
+        assert_eq!(
+            network.usable_bounds(),
+            (num("192.168.1.1"), num("192.168.1.254"))
+        );
+        assert_eq!(network.host_capacity(), 254);
+        // /31은 두 주소를 모두, /32는 한 주소를 쓴다
+        assert_eq!(net("10.0.0.0/31").host_capacity(), 2);
+        assert_eq!(
+            net("10.0.0.5/32").usable_bounds(),
+            (num("10.0.0.5"), num("10.0.0.5"))
+        );
+    }
+
+    #[test]
+    fn ipv6_usable_bounds() {
+        let network = net("2001:db8::/64");
+        assert_eq!(network.last(), num("2001:db8::ffff:ffff:ffff:ffff"));
+        // 브로드캐스트가 없으므로 마지막 주소까지 쓴다
+        assert_eq!(
+            network.usable_bounds(),
+            (num("2001:db8::1"), num("2001:db8::ffff:ffff:ffff:ffff"))
+        );
+        assert_eq!(network.host_capacity(), (1u128 << 64) - 1);
+        assert_eq!(saturating_i64(network.host_capacity()), i64::MAX);
+        assert_eq!(net("2001:db8::1/128").host_capacity(), 1);
+        assert_eq!(net("::/0").last(), u128::MAX);
+    }
+
+    #[test]
+    fn containment_respects_family() {
+        let v4 = net("10.0.0.0/8");
+        assert!(v4.contains_ip(&"10.255.0.1".parse().unwrap()));
+        assert!(!v4.contains_ip(&"11.0.0.1".parse().unwrap()));
+        // 같은 숫자라도 다른 패밀리는 포함하지 않는다
+        assert!(!v4.contains_ip(&"::a00:1".parse().unwrap()));
+
+        assert!(v4.contains_network(&net("10.1.0.0/16")));
+        assert!(v4.contains_network(&v4));
+        assert!(v4.is_supernet_of(&net("10.1.0.0/16")));
+        assert!(!v4.is_supernet_of(&v4));
+        assert!(v4.overlaps(&net("10.200.0.0/16")));
+        assert!(!v4.overlaps(&net("11.0.0.0/8")));
+        assert!(!net("2001:db8::/32").overlaps(&net("2001:db9::/32")));
+    }
+
+    #[test]
+    fn free_intervals_skip_occupied_ranges() {
+        assert_eq!(free_intervals(0, 99, &[]), vec![(0, 99)]);
+        assert_eq!(
+            free_intervals(0, 99, &[(40, 49), (10, 19), (15, 25), (200, 300)]),
+            vec![(0, 9), (26, 39), (50, 99)]
+        );
+        assert_eq!(free_intervals(0, 99, &[(0, 99)]), vec![]);
+        assert_eq!(free_intervals(10, 20, &[(0, 12), (18, 30)]), vec![(13, 17)]);
+        // 끝이 u128::MAX여도 넘치지 않는다
+        assert_eq!(
+            free_intervals(u128::MAX - 5, u128::MAX, &[(u128::MAX - 2, u128::MAX)]),
+            vec![(u128::MAX - 5, u128::MAX - 3)]
+        );
+
+        assert_eq!(find_free_run(0, 99, &[(5, 9), (12, 20)], 6), Some(21));
+        assert_eq!(find_free_run(0, 99, &[(5, 9), (12, 20)], 2), Some(0));
+        assert_eq!(find_free_run(0, 9, &[(2, 9)], 3), None);
+    }
+
+    #[test]
+    fn cidr_blocks_cover_ranges_with_largest_aligned_blocks() {
+        let blocks = |start: &str, end: &str, family| -> Vec<String> {
+            cidr_blocks(num(start), num(end), family)
+                .iter()
+                .map(|b| format!("{}/{}", b.network_ip(), b.prefix()))
+                .collect()
+        };
+
+        assert_eq!(
+            blocks("10.0.0.0", "10.0.0.255", IpFamily::V4),
+            ["10.0.0.0/24"]
+        );
+        assert_eq!(
+            blocks("10.0.0.1", "10.0.0.6", IpFamily::V4),
+            ["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/31", "10.0.0.6/32"]
+        );
+        assert_eq!(
+            blocks("10.0.1.0", "10.0.2.127", IpFamily::V4),
+            ["10.0.1.0/24", "10.0.2.0/25"]
+        );
+        assert_eq!(
+            blocks("2001:db8::", "2001:db8::ffff:ffff:ffff:ffff", IpFamily::V6),
+            ["2001:db8::/64"]
+        );
+        assert_eq!(
+            blocks("0.0.0.0", "255.255.255.255", IpFamily::V4),
+            ["0.0.0.0/0"]
+        );
+        assert_eq!(
+            blocks(
+                "::",
+                &number_to_ip(u128::MAX, IpFamily::V6).to_string(),
+                IpFamily::V6
+            ),
+            ["::/0"]
+        );
+    }
+
+    #[test]
+    fn subnets_carve_in_address_order() {
+        let subnets: Vec<String> = net("10.0.0.0/24")
+            .subnets(26)
+            .map(|s| format!("{}/{}", s.network_ip(), s.prefix()))
+            .collect();
+        assert_eq!(
+            subnets,
+            [
+                "10.0.0.0/26",
+                "10.0.0.64/26",
+                "10.0.0.128/26",
+                "10.0.0.192/26"
+            ]
+        );
+
+        assert_eq!(net("10.0.0.0/24").subnets(24).count(), 1);
+        // 더 큰 prefix나 범위를 넘는 prefix는 나눌 수 없다
+        assert_eq!(net("10.0.0.0/24").subnets(16).count(), 0);
+        assert_eq!(net("10.0.0.0/24").subnets(33).count(), 0);
+
+        let mut v6 = net("2001:db8::/48").subnets(64);
+        assert_eq!(v6.next(), Some(net("2001:db8::/64")));
+        assert_eq!(v6.next(), Some(net("2001:db8:0:1::/64")));
+        assert_eq!(
+            net("2001:db8::/48").subnets(64).nth(65535),
+            Some(net("2001:db8:0:ffff::/64"))
+        );
+        assert_eq!(net("::/0").subnets(1).count(), 2);
+    }
+}
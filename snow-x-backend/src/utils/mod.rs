@@ -4,4 +4,5 @@ pub mod extract_user_agent;
 pub mod hashtag_normalizer;
 pub mod image_processor;
 pub mod image_validator;
+pub mod ip_math;
 pub mod logger;
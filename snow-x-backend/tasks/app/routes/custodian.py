@@ -71,3 +71,30 @@ async def get_task_status(task_id: str):
         "result": task.result if task.ready() else None,
         "error": str(task.info) if task.failed() else None
     }
+
+
+class RevokeTaskResponse(BaseModel):
+    task_id: str
+    status: str
+
+
+@router.post("/revoke/{task_id}", response_model=RevokeTaskResponse)
+async def revoke_task(task_id: str):
+    """
+    Revoke a queued or running Celery task.
+
+    Args:
+        task_id: The Celery task ID
+
+    Returns:
+        The revoked task ID
+    """
+    from app.core.celery_app import celery_app
+
+    try:
+        # 대기 중이면 실행되지 않고, 이미 실행 중이면 워커 프로세스를 종료한다
+        celery_app.control.revoke(task_id, terminate=True)
+    except Exception as e:
+        raise HTTPException(status_code=500, detail=f"Failed to revoke task: {str(e)}")
+
+    return RevokeTaskResponse(task_id=task_id, status="revoked")
@@ -29,7 +29,7 @@ export interface ExecutePolicyRequest {
 export interface CustodianExecution {
 	id: string;
 	policy_id: string;
-	status: 'pending' | 'running' | 'completed' | 'failed';
+	status: 'pending' | 'running' | 'completed' | 'failed' | 'cancelled';
 	dry_run: boolean;
 	task_id?: string;
 	output?: string;
@@ -53,7 +53,7 @@ export interface TaskStatusResponse {
 export interface ExecutionResult {
 	id: string;
 	policy_id: string;
-	status: 'pending' | 'running' | 'completed' | 'failed';
+	status: 'pending' | 'running' | 'completed' | 'failed' | 'cancelled';
 	dry_run: boolean;
 	output?: string;
 	error?: string;
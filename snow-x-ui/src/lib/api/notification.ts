@@ -70,7 +70,64 @@ export interface UpdateNotificationStatusRequest {
 	last_error?: string;
 }
 
+// 내 알림함 항목 (발송 대기열의 Notification과 달리 사용자별 읽음 여부를 가진다)
+export interface InboxNotification {
+	id: string;
+	category: string;
+	title?: string;
+	message?: string;
+	payload?: NotificationPayload | null;
+	link?: string;
+	resource_type?: string;
+	resource_id?: string;
+	actor_id?: string;
+	is_read: boolean;
+	read_at?: string;
+	created_at: string;
+}
+
+export interface InboxNotificationListResponse {
+	notifications: InboxNotification[];
+	total: number;
+	unread_count: number;
+	page: number;
+	limit: number;
+}
+
+export interface InboxListParams {
+	page?: number;
+	limit?: number;
+	unread_only?: boolean;
+	category?: string;
+}
+
 export const notificationApi = {
+	async getInbox(params?: InboxListParams): Promise<InboxNotificationListResponse> {
+		const searchParams = new URLSearchParams();
+		if (params?.page) searchParams.append('page', params.page.toString());
+		if (params?.limit) searchParams.append('limit', params.limit.toString());
+		if (params?.unread_only) searchParams.append('unread_only', 'true');
+		if (params?.category) searchParams.append('category', params.category);
+
+		const url = `v0/notifications${searchParams.toString() ? `?${searchParams.toString()}` : ''}`;
+		return await privateApi.get(url).json<InboxNotificationListResponse>();
+	},
+
+	async getUnreadCount(): Promise<number> {
+		const response = await privateApi.get('v0/notifications/unread-count').json<{ unread_count: number }>();
+		return response.unread_count;
+	},
+
+	async markRead(id: string): Promise<InboxNotification> {
+		return await privateApi.post(`v0/notifications/${id}/read`).json<InboxNotification>();
+	},
+
+	async markAllRead(category?: string): Promise<{ updated: number }> {
+		const url = `v0/notifications/read-all${category ? `?category=${encodeURIComponent(category)}` : ''}`;
+		return await privateApi.post(url).json<{ updated: number }>();
+	},
+
+	// 발송 대기열 (관리자 전용)
 	async getNotifications(params?: NotificationListParams): Promise<NotificationListResponse> {
 		const searchParams = new URLSearchParams();
 		if (params?.page) searchParams.append('page', params.page.toString());
@@ -78,15 +135,15 @@ export const notificationApi = {
 		if (params?.status) searchParams.append('status', params.status);
 		if (params?.channel) searchParams.append('channel', params.channel);
 
-		const url = `v0/notifications${searchParams.toString() ? `?${searchParams.toString()}` : ''}`;
+		const url = `v0/notifications/outbox${searchParams.toString() ? `?${searchParams.toString()}` : ''}`;
 		return await privateApi.get(url).json<NotificationListResponse>();
 	},
 
 	async createNotification(data: CreateNotificationRequest): Promise<Notification> {
-		return await privateApi.post('v0/notifications', { json: data }).json<Notification>();
+		return await privateApi.post('v0/notifications/outbox', { json: data }).json<Notification>();
 	},
 
 	async updateNotificationStatus(id: string, data: UpdateNotificationStatusRequest): Promise<Notification> {
-		return await privateApi.patch(`v0/notifications/${id}`, { json: data }).json<Notification>();
+		return await privateApi.patch(`v0/notifications/outbox/${id}`, { json: data }).json<Notification>();
 	}
 };
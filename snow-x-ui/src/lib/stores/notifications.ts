@@ -1,13 +1,13 @@
-import { writable } from 'svelte/store';
+import { get, writable } from 'svelte/store';
 import { browser } from '$app/environment';
 import {
 	notificationApi,
-	type Notification,
-	type NotificationListResponse
+	type InboxNotification,
+	type InboxNotificationListResponse
 } from '$lib/api/notification';
 
 function createNotificationStore() {
-	const notifications = writable<Notification[]>([]);
+	const notifications = writable<InboxNotification[]>([]);
 	const unreadCount = writable(0);
 	const isLoading = writable(false);
 	const error = writable<string | null>(null);
@@ -22,13 +22,12 @@ function createNotificationStore() {
 		}
 		error.set(null);
 		try {
-			const response: NotificationListResponse = await notificationApi.getNotifications({
+			const response: InboxNotificationListResponse = await notificationApi.getInbox({
 				page: 1,
-				limit: 20,
-				channel: 'web'
+				limit: 20
 			});
 			notifications.set(response.notifications);
-			unreadCount.set(response.notifications.filter((notification) => notification.status !== 'done').length);
+			unreadCount.set(response.unread_count);
 		} catch (err) {
 			console.error('Failed to load notifications:', err);
 			error.set('알림을 불러오지 못했습니다.');
@@ -39,27 +38,41 @@ function createNotificationStore() {
 		}
 	}
 
-	async function markAsRead(id: string, options?: { skipReload?: boolean }) {
+	async function markAsRead(id: string) {
 		try {
-			await notificationApi.updateNotificationStatus(id, { status: 'done' });
-			if (!options?.skipReload) {
-				await load();
-			}
+			await notificationApi.markRead(id);
+			await load();
 		} catch (err) {
 			console.error('Failed to mark notification as read:', err);
 		}
 	}
 
-	async function markAllAsRead(current: Notification[]) {
-		await Promise.all(current.map((notification) => markAsRead(notification.id, { skipReload: true })));
-		await load();
+	async function markAllAsRead() {
+		try {
+			await notificationApi.markAllRead();
+			await load();
+		} catch (err) {
+			console.error('Failed to mark all notifications as read:', err);
+		}
+	}
+
+	// 주기적으로는 안 읽은 수만 확인하고, 수가 바뀌었을 때만 목록을 다시 불러온다
+	async function refreshUnreadCount() {
+		try {
+			const count = await notificationApi.getUnreadCount();
+			if (count !== get(unreadCount)) {
+				await load({ skipSpinner: true });
+			}
+		} catch (err) {
+			console.error('Failed to load unread notification count:', err);
+		}
 	}
 
 	function tick() {
 		if (!isVisible) {
 			return;
 		}
-		load({ skipSpinner: true });
+		refreshUnreadCount();
 	}
 
 	function handleVisibilityChange() {
@@ -100,6 +113,7 @@ function createNotificationStore() {
 		isLoading,
 		error,
 		load,
+		refreshUnreadCount,
 		markAsRead,
 		markAllAsRead,
 		startPolling,